@@ -6,6 +6,20 @@ pub const AFTER_FAILED_AUTOMATION_REPLY_ID: u64 = 3;
 pub const AFTER_DELEGATION_REPLY_ID: u64 = 4;
 pub const AFTER_ORDER_MIGRATION_REPLY_ID: u64 = 5;
 pub const FAIL_SILENTLY_REPLY_ID: u64 = 6;
+pub const AFTER_QUEUED_REFUND_REPLY_ID: u64 = 7;
+
+pub const DEFAULT_IBC_TRANSFER_TIMEOUT_SECONDS: u64 = 600;
+
+pub const MODEL_ADJUSTMENT_TTL_SECONDS: u64 = 60 * 60 * 25;
+
+pub const PROGRESS_UPDATE_INTERVAL_SECONDS: u64 = 60 * 60;
+
+pub const ESCROW_RELEASE_COUNTDOWN_SECONDS: u64 = 60 * 60 * 24 * 3;
+
+pub const MAX_PAGE_LIMIT: u16 = 1000;
+
+pub const MAX_IMMEDIATE_REFUNDS: usize = 10;
+pub const DEFAULT_REFUND_QUEUE_LIMIT: u16 = 20;
 
 // pub const SWAP_FEE_RATE: &str = "0.0015";
 
@@ -26,3 +40,8 @@ pub const TEN_DECIMAL: Decimal = Decimal::new(Uint128::new(10000000000000000000)
 
 pub const PAIR_CONTRACT_ADDRESS: &str = "pair-contract";
 pub const EXCHANGE_CONTRACT_ADDRESS: &str = "swap-contract";
+
+pub const BASE_EXECUTION_GAS_ESTIMATE: u64 = 150_000;
+pub const MESSAGE_GAS_ESTIMATE: u64 = 40_000;
+pub const SUBMESSAGE_GAS_ESTIMATE: u64 = 60_000;
+pub const DESTINATION_CALLBACK_GAS_ESTIMATE: u64 = 100_000;
@@ -1,40 +1,152 @@
 use crate::constants::{
     AFTER_DELEGATION_REPLY_ID, AFTER_FAILED_AUTOMATION_REPLY_ID, AFTER_LIMIT_ORDER_PLACED_REPLY_ID,
-    AFTER_SWAP_REPLY_ID, FAIL_SILENTLY_REPLY_ID,
+    AFTER_QUEUED_REFUND_REPLY_ID, AFTER_SWAP_REPLY_ID, FAIL_SILENTLY_REPLY_ID,
 };
 use crate::error::ContractError;
+use crate::handlers::accept_admin::accept_admin_handler;
+use crate::handlers::accept_application::accept_application_handler;
+use crate::handlers::add_allowed_denom::add_allowed_denom_handler;
+use crate::handlers::add_exchange::add_exchange_handler;
+use crate::handlers::add_fee_exemption::add_fee_exemption_handler;
+use crate::handlers::add_funding_denom::add_funding_denom_handler;
+use crate::handlers::add_model_provider::add_model_provider_handler;
+use crate::handlers::apply_to_bounty::apply_to_bounty_handler;
+use crate::handlers::assign_bounty::assign_bounty_handler;
+use crate::handlers::bulk_update_slippage::bulk_update_slippage_handler;
+use crate::handlers::cancel_all_bounties::cancel_all_bounties_handler;
 use crate::handlers::cancel_bounty::cancel_bounty_handler;
+use crate::handlers::cancel_scheduled_config_update::cancel_scheduled_config_update_handler;
+use crate::handlers::cancel_scheduled_payment::cancel_scheduled_payment_handler;
+use crate::handlers::claim_vested::claim_vested_handler;
+use crate::handlers::complete_checklist_item::complete_checklist_item_handler;
+use crate::handlers::contribute_to_round::contribute_to_round_handler;
 use crate::handlers::create_bounty::{create_bounty_handler, save_price_trigger};
+use crate::handlers::create_bounty_from_template::create_bounty_from_template_handler;
+use crate::handlers::create_funding_round::create_funding_round_handler;
+use crate::handlers::delete_bounty_template::delete_bounty_template_handler;
 use crate::handlers::deposit::deposit_handler;
 use crate::handlers::disburse_escrow::disburse_escrow_handler;
 use crate::handlers::disburse_funds::disburse_funds_handler;
+use crate::handlers::execute_scheduled_payment::execute_scheduled_payment_handler;
 use crate::handlers::execute_trigger::execute_trigger_handler;
+use crate::handlers::execute_triggers::execute_triggers_handler;
+use crate::handlers::finalize_round::finalize_round_handler;
+use crate::handlers::get_admin_actions::get_admin_actions_handler;
+use crate::handlers::get_allowed_denoms::get_allowed_denoms_handler;
+use crate::handlers::get_applications::get_applications_handler;
+use crate::handlers::get_assignees::get_assignees_handler;
+use crate::handlers::get_balance_trigger_ids::get_balance_trigger_ids_handler;
+use crate::handlers::get_block_trigger_ids::get_block_trigger_ids_handler;
+use crate::handlers::get_bounty_balances::get_bounty_balances_handler;
+use crate::handlers::get_bounty_snapshots::get_bounty_snapshots_handler;
+use crate::handlers::get_bounty_template::get_bounty_template_handler;
+use crate::handlers::get_callback_results::get_callback_results_handler;
+use crate::handlers::get_checklist::get_checklist_handler;
 use crate::handlers::get_config::get_config_handler;
+use crate::handlers::get_conversion_report::get_conversion_report_handler;
+use crate::handlers::get_custom_swap_fees::get_custom_swap_fees_handler;
+use crate::handlers::get_cw20_whitelist::get_cw20_whitelist_handler;
 use crate::handlers::get_disburse_escrow_tasks::get_disburse_escrow_tasks_handler;
+use crate::handlers::get_dispute::get_dispute_handler;
+use crate::handlers::get_due_scheduled_payments::get_due_scheduled_payments_handler;
+use crate::handlers::get_escrow_epochs::get_escrow_epochs_handler;
+use crate::handlers::get_escrow_tranches::get_escrow_tranches_handler;
+use crate::handlers::get_event_summary::get_event_summary_handler;
 use crate::handlers::get_events::get_events_handler;
 use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+use crate::handlers::get_exchanges::get_exchanges_handler;
+use crate::handlers::get_executor_stats::get_executor_stats_handler;
+use crate::handlers::get_fee_exemptions::get_fee_exemptions_handler;
+use crate::handlers::get_funding_round::get_funding_round_handler;
+use crate::handlers::get_gas_usage_report::get_gas_usage_report_handler;
+use crate::handlers::get_model_adjustment::get_model_adjustment_handler;
+use crate::handlers::get_model_providers::get_model_providers_handler;
+use crate::handlers::get_owner_digest::get_owner_digest_handler;
 use crate::handlers::get_pairs::get_pairs_handler;
+use crate::handlers::get_progress_updates::get_progress_updates_handler;
+use crate::handlers::get_referrer_stats::get_referrer_stats_handler;
+use crate::handlers::get_roles::get_roles_handler;
+use crate::handlers::get_round_contributions::get_round_contributions_handler;
+use crate::handlers::get_scheduled_config_updates::get_scheduled_config_updates_handler;
+use crate::handlers::get_scheduled_payment::get_scheduled_payment_handler;
+use crate::handlers::get_scheduled_payments_by_owner::get_scheduled_payments_by_owner_handler;
 use crate::handlers::get_time_trigger_ids::get_time_trigger_ids_handler;
+use crate::handlers::get_top_assignees::get_top_assignees_handler;
+use crate::handlers::get_trigger::get_trigger_handler;
 use crate::handlers::get_trigger_id_by_fin_limit_order_idx::get_trigger_id_by_fin_limit_order_idx_handler;
+use crate::handlers::get_triggers_due::get_triggers_due_handler;
+use crate::handlers::get_update_permissions::get_update_permissions_handler;
 use crate::handlers::get_bounty::get_bounty_handler;
+use crate::handlers::get_bounty_with_key::get_bounty_with_key_handler;
 use crate::handlers::get_bounty_performance::get_bounty_performance_handler;
 use crate::handlers::get_bounties::get_bounties_handler;
 use crate::handlers::get_bounties_by_address::get_bounties_by_address_handler;
+use crate::handlers::get_bounties_by_status::get_bounties_by_status_handler;
+use crate::handlers::get_bounties_by_tag::get_bounties_by_tag_handler;
+use crate::handlers::grant_bounty_operator::grant_bounty_operator_handler;
+use crate::handlers::grant_role::grant_role_handler;
 use crate::handlers::handle_failed_automation::handle_failed_automation_handler;
 use crate::handlers::instantiate::instantiate_handler;
 use crate::handlers::migrate::migrate_handler;
+use crate::handlers::pause_bounty::pause_bounty_handler;
+use crate::handlers::post_progress_update::post_progress_update_handler;
+use crate::handlers::probe_trigger::probe_trigger_handler;
+use crate::handlers::process_refund_queue::{
+    handle_queued_refund_result, process_refund_queue_handler,
+};
+use crate::handlers::propose_new_admin::propose_new_admin_handler;
+use crate::handlers::prune_events::prune_events_handler;
+use crate::handlers::raise_dispute::raise_dispute_handler;
+use crate::handlers::refund_failed_ibc_transfers::refund_failed_ibc_transfers_handler;
+use crate::handlers::register_referrer::register_referrer_handler;
+use crate::handlers::remove_allowed_denom::remove_allowed_denom_handler;
+use crate::handlers::remove_custom_swap_fee::remove_custom_swap_fee_handler;
+use crate::handlers::remove_cw20_token::remove_cw20_token_handler;
+use crate::handlers::remove_exchange::remove_exchange_handler;
+use crate::handlers::remove_fee_exemption::remove_fee_exemption_handler;
+use crate::handlers::remove_model_provider::remove_model_provider_handler;
+use crate::handlers::report_executor::report_executor_handler;
+use crate::handlers::reprice_trigger::reprice_trigger_handler;
+use crate::handlers::request_escrow_release::request_escrow_release_handler;
+use crate::handlers::resolve_dispute::resolve_dispute_handler;
+use crate::handlers::resume_bounty::resume_bounty_handler;
+use crate::handlers::retry_failed_ibc_transfers::retry_failed_ibc_transfers_handler;
+use crate::handlers::revoke_bounty_operator::revoke_bounty_operator_handler;
+use crate::handlers::revoke_role::revoke_role_handler;
+use crate::handlers::save_bounty_template::save_bounty_template_handler;
+use crate::handlers::schedule_config_update::schedule_config_update_handler;
+use crate::handlers::schedule_payment::schedule_payment_handler;
+use crate::handlers::set_bounty_snapshots::set_bounty_snapshots_handler;
+use crate::handlers::set_checklist::set_checklist_handler;
+use crate::handlers::set_circuit_breakers::set_circuit_breakers_handler;
+use crate::handlers::set_custom_swap_fee::set_custom_swap_fee_handler;
+use crate::handlers::set_escrow_tranches::set_escrow_tranches_handler;
+use crate::handlers::set_pair_exchange::set_pair_exchange_handler;
+use crate::handlers::set_paused::set_paused_handler;
+use crate::handlers::set_payout_address::set_payout_address_handler;
+use crate::handlers::set_update_permissions::set_update_permissions_handler;
+use crate::handlers::set_viewing_key::set_viewing_key_handler;
+use crate::handlers::simulate_execution::simulate_execution_handler;
+use crate::handlers::submit_model_adjustment::submit_model_adjustment_handler;
+use crate::handlers::submit_work::submit_work_handler;
+use crate::handlers::unassign_bounty::unassign_bounty_handler;
 use crate::handlers::update_config::update_config_handler;
 use crate::handlers::update_swap_adjustment_handler::update_swap_adjustment_handler;
 use crate::handlers::update_bounty::update_bounty_handler;
+use crate::handlers::whitelist_cw20_token::whitelist_cw20_token_handler;
+use crate::handlers::withdraw::withdraw_handler;
+use crate::handlers::withdraw_application::withdraw_application_handler;
 use crate::handlers::z_delegate::{log_delegation_result, z_delegate_handler};
+use crate::helpers::config::apply_due_scheduled_config_updates;
+use crate::helpers::cw20::parse_cw20_receive_msg;
+use crate::helpers::validation::assert_sender_has_role;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use cosmwasm_std::from_json;
+use crate::types::role::Role;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
     StdResult,
 };
-use shared::cw20::from_cw20_receive_msg;
 
 pub const CONTRACT_NAME: &str = "crates.io:calc-dca";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -61,6 +173,8 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    apply_due_scheduled_config_updates(deps.storage, env.block.time)?;
+
     match msg {
         ExecuteMsg::CreateBounty {
             owner,
@@ -70,6 +184,24 @@ pub fn execute(
             target_denom,
             route,
             slippage_tolerance,
+            exact_receive_amount,
+            expires_at,
+            disbursement_schedule,
+            private_metadata,
+            referral_code,
+            executor_slippage_override_cap,
+            fallback_routes,
+            tags,
+            metadata_uri,
+            metadata_hash,
+            minimum_price,
+            maximum_price,
+            escrow_available_after,
+            min_adjustment,
+            max_adjustment,
+            lock_period,
+            balance_threshold,
+            target_block_height,
             // minimum_receive_amount,
            // swap_amount,
             // time_interval,
@@ -88,6 +220,24 @@ pub fn execute(
             target_denom,
             route,
             slippage_tolerance,
+            exact_receive_amount,
+            expires_at,
+            disbursement_schedule,
+            private_metadata.unwrap_or(false),
+            referral_code,
+            executor_slippage_override_cap,
+            fallback_routes.unwrap_or_default(),
+            tags.unwrap_or_default(),
+            metadata_uri,
+            minimum_price,
+            maximum_price,
+            escrow_available_after,
+            metadata_hash,
+            min_adjustment,
+            max_adjustment,
+            lock_period,
+            balance_threshold,
+            target_block_height,
            // minimum_receive_amount,
            // swap_amount,
            // time_interval,
@@ -100,12 +250,18 @@ pub fn execute(
             vault_id,
             label,
             bounty_description,
+            tags,
+            metadata_uri,
+            metadata_hash,
             destinations,
             slippage_tolerance,
            // minimum_receive_amount,
            // time_interval,
            // swap_adjustment_strategy,
            // swap_amount,
+            target_denom,
+            route,
+            escrow_level,
         } => update_bounty_handler(
             deps,
             env,
@@ -113,22 +269,78 @@ pub fn execute(
             bounty_id,
             label,
             bounty_description,
+            tags,
+            metadata_uri,
             destinations,
             slippage_tolerance,
            // minimum_receive_amount,
            // time_interval,
             // swap_adjustment_strategy,
             // swap_amount,
+            target_denom,
+            route,
+            metadata_hash,
+            escrow_level,
         ),
-        ExecuteMsg::CancelBounty { bounty_id } => cancel_bounty_handler(deps, env, info, bounty_id),
-        ExecuteMsg::ExecuteTrigger { trigger_id, route } => {
-            execute_trigger_handler(deps, env, trigger_id, route)
+        ExecuteMsg::BulkUpdateSlippage {
+            bounty_ids,
+            slippage_tolerance,
+        } => bulk_update_slippage_handler(deps, env, info, bounty_ids, slippage_tolerance),
+        ExecuteMsg::CancelBounty {
+            bounty_id,
+            reason,
+            note,
+        } => cancel_bounty_handler(deps, env, info, bounty_id, reason, note),
+        ExecuteMsg::CancelAllBounties {
+            status,
+            start_after,
+            limit,
+        } => cancel_all_bounties_handler(deps, env, info, status, start_after, limit),
+        ExecuteMsg::ProcessRefundQueue { limit } => process_refund_queue_handler(deps, limit),
+        ExecuteMsg::PauseBounty { bounty_id } => pause_bounty_handler(deps, env, info, bounty_id),
+        ExecuteMsg::ResumeBounty { bounty_id } => {
+            resume_bounty_handler(deps, env, info, bounty_id)
         }
+        ExecuteMsg::RepriceTrigger {
+            bounty_id,
+            new_target_price,
+        } => reprice_trigger_handler(deps, env, info, bounty_id, new_target_price),
+        ExecuteMsg::AddFundingDenom {
+            bounty_id,
+            denom,
+            swap_amount,
+            route,
+        } => add_funding_denom_handler(deps, info, bounty_id, denom, swap_amount, route),
+        ExecuteMsg::ExecuteTrigger {
+            trigger_id,
+            route,
+            slippage_override,
+            on_behalf_of,
+        } => {
+            if slippage_override.is_some() {
+                assert_sender_has_role(deps.storage, &env, &info.sender, Role::Executor)?;
+            }
+            execute_trigger_handler(
+                deps,
+                env,
+                info,
+                trigger_id,
+                route,
+                slippage_override,
+                on_behalf_of,
+            )
+        }
+        ExecuteMsg::ExecuteTriggers { trigger_ids, route } => {
+            execute_triggers_handler(deps, env, info, trigger_ids, route)
+        }
+        ExecuteMsg::ProbeTrigger { trigger_id } => probe_trigger_handler(deps, env, trigger_id),
         ExecuteMsg::Deposit { address, bounty_id } => {
             deposit_handler(deps, env, info, address, bounty_id)
         }
+        ExecuteMsg::Withdraw { bounty_id, amount } => {
+            withdraw_handler(deps, env, info, bounty_id, amount)
+        }
         ExecuteMsg::UpdateConfig {
-            executors,
             fee_collectors,
            // default_swap_fee_percent,
            // weighted_scale_swap_fee_percent,
@@ -139,10 +351,26 @@ pub fn execute(
             // twap_period,
             default_slippage_tolerance,
             exchange_contract_address,
+            arbiter,
+            fee_grant,
+            referral_fee_percent,
+            executor_reward_percent,
+            minimum_deposit,
+            minimum_swap_amount,
+            event_retention_blocks,
+            max_bounty_balance,
+            max_total_balance_per_owner,
+            exchange_adapters,
+            auto_route_safety_margin,
+            price_oracle_address,
+            rounding_mode,
+            address_prefix,
+            slippage_retry_interval_seconds,
+            max_slippage_retries,
         } => update_config_handler(
             deps,
+            env,
             info,
-            executors,
             fee_collectors,
            // default_swap_fee_percent,
            // weighted_scale_swap_fee_percent,
@@ -153,13 +381,41 @@ pub fn execute(
            // twap_period,
             default_slippage_tolerance,
             exchange_contract_address,
+            arbiter,
+            fee_grant,
+            referral_fee_percent,
+            executor_reward_percent,
+            minimum_deposit,
+            minimum_swap_amount,
+            event_retention_blocks,
+            max_bounty_balance,
+            max_total_balance_per_owner,
+            exchange_adapters,
+            auto_route_safety_margin,
+            price_oracle_address,
+            rounding_mode,
+            address_prefix,
+            slippage_retry_interval_seconds,
+            max_slippage_retries,
         ),
         // ExecuteMsg::UpdateSwapAdjustment { strategy, value } => {
            // update_swap_adjustment_handler(deps, env, info, strategy, value)
        // }
+        ExecuteMsg::ScheduleConfigUpdate { at, changes } => {
+            schedule_config_update_handler(deps, env, info, at, changes)
+        }
+        ExecuteMsg::CancelScheduledConfigUpdate {
+            scheduled_config_update_id,
+        } => cancel_scheduled_config_update_handler(deps, env, info, scheduled_config_update_id),
         ExecuteMsg::DisburseEscrow { bounty_id } => {
             disburse_escrow_handler(deps, env, info, bounty_id)
         }
+        ExecuteMsg::RetryFailedIbcTransfers { bounty_id } => {
+            retry_failed_ibc_transfers_handler(deps, env, info, bounty_id)
+        }
+        ExecuteMsg::RefundFailedIbcTransfers { bounty_id } => {
+            refund_failed_ibc_transfers_handler(deps, info, bounty_id)
+        }
         ExecuteMsg::ZDelegate {
             delegator_address,
             validator_address,
@@ -170,17 +426,190 @@ pub fn execute(
             delegator_address,
             validator_address,
         ),
+        ExecuteMsg::AssignBounty {
+            bounty_id,
+            assignee,
+            weight,
+        } => assign_bounty_handler(deps, env, info, bounty_id, assignee, weight),
+        ExecuteMsg::UnassignBounty {
+            bounty_id,
+            assignee,
+        } => unassign_bounty_handler(deps, env, info, bounty_id, assignee),
+        ExecuteMsg::SubmitWork {
+            bounty_id,
+            submission,
+        } => submit_work_handler(deps, env, info, bounty_id, submission),
+        ExecuteMsg::RequestEscrowRelease { bounty_id } => {
+            request_escrow_release_handler(deps, env, info, bounty_id)
+        }
+        ExecuteMsg::PostProgressUpdate {
+            bounty_id,
+            message_hash,
+            uri,
+        } => post_progress_update_handler(deps, env, info, bounty_id, message_hash, uri),
+        ExecuteMsg::SetPayoutAddress { bounty_id, address } => {
+            set_payout_address_handler(deps, env, info, bounty_id, address)
+        }
+        ExecuteMsg::ApplyToBounty { bounty_id, message } => {
+            apply_to_bounty_handler(deps, env, info, bounty_id, message)
+        }
+        ExecuteMsg::WithdrawApplication { bounty_id } => {
+            withdraw_application_handler(deps, env, info, bounty_id)
+        }
+        ExecuteMsg::RegisterReferrer { code, address } => {
+            register_referrer_handler(deps, info, code, address)
+        }
+        ExecuteMsg::SaveBountyTemplate { label, params } => {
+            save_bounty_template_handler(deps, info, label, params)
+        }
+        ExecuteMsg::DeleteBountyTemplate { template_id } => {
+            delete_bounty_template_handler(deps, info, template_id)
+        }
+        ExecuteMsg::CreateBountyFromTemplate {
+            template_id,
+            overrides,
+        } => create_bounty_from_template_handler(
+            deps,
+            env,
+            info,
+            template_id,
+            overrides.unwrap_or_default(),
+        ),
+        ExecuteMsg::SetViewingKey { bounty_id, key } => {
+            set_viewing_key_handler(deps, info, bounty_id, key)
+        }
+        ExecuteMsg::AcceptApplication {
+            bounty_id,
+            applicant,
+        } => accept_application_handler(deps, env, info, bounty_id, applicant),
+        ExecuteMsg::ClaimVested { bounty_id } => claim_vested_handler(deps, env, info, bounty_id),
+        ExecuteMsg::SetChecklist { bounty_id, items } => {
+            set_checklist_handler(deps, info, bounty_id, items)
+        }
+        ExecuteMsg::SetBountySnapshots { bounty_id, enabled } => {
+            set_bounty_snapshots_handler(deps, info, bounty_id, enabled)
+        }
+        ExecuteMsg::CompleteChecklistItem {
+            bounty_id,
+            item_index,
+        } => complete_checklist_item_handler(deps, env, info, bounty_id, item_index),
+        ExecuteMsg::SetEscrowTranches {
+            bounty_id,
+            tranches,
+        } => set_escrow_tranches_handler(deps, info, bounty_id, tranches),
+        ExecuteMsg::CreateFundingRound {
+            bounty_ids,
+            formula,
+            ends_at,
+        } => create_funding_round_handler(deps, env, info, bounty_ids, formula, ends_at),
+        ExecuteMsg::ContributeToRound {
+            round_id,
+            bounty_id,
+        } => contribute_to_round_handler(deps, env, info, round_id, bounty_id),
+        ExecuteMsg::FinalizeRound { round_id } => finalize_round_handler(deps, env, round_id),
+        ExecuteMsg::RaiseDispute { bounty_id, reason } => {
+            raise_dispute_handler(deps, env, info, bounty_id, reason)
+        }
+        ExecuteMsg::ResolveDispute { bounty_id, ruling } => {
+            resolve_dispute_handler(deps, env, info, bounty_id, ruling)
+        }
+        ExecuteMsg::SetCustomSwapFee {
+            denom,
+            swap_fee_percent,
+        } => set_custom_swap_fee_handler(deps, env, info, denom, swap_fee_percent),
+        ExecuteMsg::RemoveCustomSwapFee { denom } => {
+            remove_custom_swap_fee_handler(deps, env, info, denom)
+        }
+        ExecuteMsg::AddFeeExemption { address } => {
+            add_fee_exemption_handler(deps, env, info, address)
+        }
+        ExecuteMsg::RemoveFeeExemption { address } => {
+            remove_fee_exemption_handler(deps, env, info, address)
+        }
+        ExecuteMsg::WhitelistCw20Token { token } => {
+            whitelist_cw20_token_handler(deps, env, info, token)
+        }
+        ExecuteMsg::RemoveCw20Token { token } => remove_cw20_token_handler(deps, env, info, token),
+        ExecuteMsg::AddAllowedDenom { denom } => add_allowed_denom_handler(deps, env, info, denom),
+        ExecuteMsg::RemoveAllowedDenom { denom } => {
+            remove_allowed_denom_handler(deps, env, info, denom)
+        }
+        ExecuteMsg::PruneEvents {
+            resource_id,
+            before_block,
+        } => prune_events_handler(deps, env, info, resource_id, before_block),
+        ExecuteMsg::ReportExecutor { address, reason } => {
+            report_executor_handler(deps, env, info, address, reason)
+        }
+        ExecuteMsg::GrantRole { address, role } => {
+            grant_role_handler(deps, env, info, address, role)
+        }
+        ExecuteMsg::RevokeRole { address, role } => {
+            revoke_role_handler(deps, env, info, address, role)
+        }
+        ExecuteMsg::SetPaused { paused } => set_paused_handler(deps, env, info, paused),
+        ExecuteMsg::AddModelProvider { provider } => {
+            add_model_provider_handler(deps, env, info, provider)
+        }
+        ExecuteMsg::RemoveModelProvider { provider } => {
+            remove_model_provider_handler(deps, env, info, provider)
+        }
+        ExecuteMsg::AddExchange { address, weight } => {
+            add_exchange_handler(deps, env, info, address, weight)
+        }
+        ExecuteMsg::RemoveExchange { address } => remove_exchange_handler(deps, env, info, address),
+        ExecuteMsg::SetPairExchange {
+            swap_denom,
+            target_denom,
+            exchange,
+        } => set_pair_exchange_handler(deps, env, info, swap_denom, target_denom, exchange),
+        ExecuteMsg::SubmitModelAdjustment { model_id, value } => {
+            submit_model_adjustment_handler(deps, env, info, model_id, value)
+        }
+        ExecuteMsg::SetCircuitBreakers {
+            create_paused,
+            execute_paused,
+            deposit_paused,
+            disburse_paused,
+        } => set_circuit_breakers_handler(
+            deps,
+            env,
+            info,
+            create_paused,
+            execute_paused,
+            deposit_paused,
+            disburse_paused,
+        ),
+        ExecuteMsg::ProposeNewAdmin { address } => {
+            propose_new_admin_handler(deps, env, info, address)
+        }
+        ExecuteMsg::AcceptAdmin {} => accept_admin_handler(deps, env, info),
+        ExecuteMsg::SetUpdatePermissions {
+            bounty_id,
+            delegate,
+            fields,
+        } => set_update_permissions_handler(deps, info, bounty_id, delegate, fields),
+        ExecuteMsg::GrantBountyOperator {
+            bounty_id,
+            operator,
+            permissions,
+        } => grant_bounty_operator_handler(deps, info, bounty_id, operator, permissions),
+        ExecuteMsg::RevokeBountyOperator {
+            bounty_id,
+            operator,
+        } => revoke_bounty_operator_handler(deps, info, bounty_id, operator),
+        ExecuteMsg::SchedulePayment { to, amount, at } => {
+            schedule_payment_handler(deps, env, info, to, amount, at)
+        }
+        ExecuteMsg::ExecuteScheduledPayment { payment_id } => {
+            execute_scheduled_payment_handler(deps, env, info, payment_id)
+        }
+        ExecuteMsg::CancelScheduledPayment { payment_id } => {
+            cancel_scheduled_payment_handler(deps, info, payment_id)
+        }
         ExecuteMsg::Receive(receive_msg) => {
-            let info = from_cw20_receive_msg(&deps.as_ref(), info, receive_msg.clone())?;
-            let msg = from_json(receive_msg.msg)?;
-            match msg {
-                ExecuteMsg::Receive(_) => {
-                    Err(ContractError::Std(cosmwasm_std::StdError::GenericErr {
-                        msg: "nested receive not allowed".to_string(),
-                    }))
-                }
-                _ => execute(deps, env, info, msg),
-            }
+            let (info, msg) = parse_cw20_receive_msg(deps.as_ref(), info, receive_msg)?;
+            execute(deps, env, info, msg)
         }
     }
 }
@@ -188,11 +617,12 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, ContractError> {
     match reply.id {
-        AFTER_LIMIT_ORDER_PLACED_REPLY_ID => save_price_trigger(deps, reply),
+        AFTER_LIMIT_ORDER_PLACED_REPLY_ID => save_price_trigger(deps, env, reply),
         // AFTER_SWAP_REPLY_ID => disburse_funds_handler(deps, &env, reply),
         AFTER_FAILED_AUTOMATION_REPLY_ID => handle_failed_automation_handler(deps, env, reply),
         AFTER_DELEGATION_REPLY_ID => log_delegation_result(reply),
         FAIL_SILENTLY_REPLY_ID => Ok(Response::new()),
+        AFTER_QUEUED_REFUND_REPLY_ID => handle_queued_refund_result(deps, reply),
         id => Err(ContractError::CustomError {
             val: format!("unhandled DCA contract reply id: {}", id),
         }),
@@ -208,6 +638,18 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetTimeTriggerIds { limit } => {
             to_json_binary(&get_time_trigger_ids_handler(deps, env, limit)?)
         }
+        QueryMsg::GetBalanceTriggerIds { limit } => {
+            to_json_binary(&get_balance_trigger_ids_handler(deps, limit)?)
+        }
+        QueryMsg::GetBlockTriggerIds { limit } => {
+            to_json_binary(&get_block_trigger_ids_handler(deps, env, limit)?)
+        }
+        QueryMsg::GetTrigger { bounty_id } => {
+            to_json_binary(&get_trigger_handler(deps, bounty_id)?)
+        }
+        QueryMsg::GetTriggersDue { from, to, limit } => {
+            to_json_binary(&get_triggers_due_handler(deps, from, to, limit)?)
+        }
         QueryMsg::GetTriggerIdByFinLimitOrderIdx { order_idx } => to_json_binary(
             &get_trigger_id_by_fin_limit_order_idx_handler(deps, order_idx)?,
         ),
@@ -215,20 +657,71 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
             reverse,
-        } => to_json_binary(&get_bounties_handler(deps, start_after, limit, reverse)?),
+            fields,
+        } => to_json_binary(&get_bounties_handler(
+            deps,
+            start_after,
+            limit,
+            reverse,
+            fields,
+        )?),
         QueryMsg::GetBountiesByAddress {
             address,
             status,
             start_after,
             limit,
+            reverse,
+            fields,
         } => to_json_binary(&get_bounties_by_address_handler(
             deps,
             address,
             status,
             start_after,
             limit,
+            reverse,
+            fields,
+        )?),
+        QueryMsg::GetOwnerDigest { address } => {
+            to_json_binary(&get_owner_digest_handler(deps, address)?)
+        }
+        QueryMsg::GetBountiesByStatus {
+            status,
+            start_after,
+            limit,
+            reverse,
+        } => to_json_binary(&get_bounties_by_status_handler(
+            deps,
+            status,
+            start_after,
+            limit,
+            reverse,
+        )?),
+        QueryMsg::GetBountiesByTag {
+            tag,
+            start_after,
+            limit,
+            reverse,
+        } => to_json_binary(&get_bounties_by_tag_handler(
+            deps,
+            tag,
+            start_after,
+            limit,
+            reverse,
         )?),
         QueryMsg::GetBounty { bounty_id } => to_json_binary(&get_bounty_handler(deps, bounty_id)?),
+        QueryMsg::GetBountyWithKey { bounty_id, key } => {
+            to_json_binary(&get_bounty_with_key_handler(deps, bounty_id, key)?)
+        }
+        QueryMsg::GetCallbackResults {
+            bounty_id,
+            key,
+            execution_height,
+        } => to_json_binary(&get_callback_results_handler(
+            deps,
+            bounty_id,
+            key,
+            execution_height,
+        )?),
         QueryMsg::GetEventsByResourceId {
             resource_id,
             start_after,
@@ -241,17 +734,153 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             limit,
             reverse,
         )?),
+        QueryMsg::GetEventSummary { resource_id } => {
+            to_json_binary(&get_event_summary_handler(deps, resource_id)?)
+        }
         QueryMsg::GetEvents {
             start_after,
             limit,
             reverse,
-        } => to_json_binary(&get_events_handler(deps, start_after, limit, reverse)?),
-        QueryMsg::GetConfig {} => to_json_binary(&get_config_handler(deps)?),
-        QueryMsg::GetVaultPerformance { vault_id } => {
-            to_json_binary(&get_bounty_performance_handler(deps, bounty_id)?)
+            order_by,
+            after_timestamp,
+        } => to_json_binary(&get_events_handler(
+            deps,
+            start_after,
+            limit,
+            reverse,
+            order_by,
+            after_timestamp,
+        )?),
+        QueryMsg::GetConfig {} => to_json_binary(&get_config_handler(deps, env)?),
+        QueryMsg::GetCustomSwapFees {} => to_json_binary(&get_custom_swap_fees_handler(deps)?),
+        QueryMsg::GetFeeExemptions {} => to_json_binary(&get_fee_exemptions_handler(deps)?),
+        QueryMsg::GetCw20Whitelist {} => to_json_binary(&get_cw20_whitelist_handler(deps)?),
+        QueryMsg::GetAllowedDenoms {} => to_json_binary(&get_allowed_denoms_handler(deps)?),
+        QueryMsg::GetScheduledConfigUpdates {} => {
+            to_json_binary(&get_scheduled_config_updates_handler(deps, env)?)
+        }
+        QueryMsg::GetAdminActions {
+            start_after,
+            limit,
+            reverse,
+        } => to_json_binary(&get_admin_actions_handler(
+            deps,
+            start_after,
+            limit,
+            reverse,
+        )?),
+        QueryMsg::GetBountyPerformance {
+            bounty_id,
+            at_price,
+        } => to_json_binary(&get_bounty_performance_handler(deps, bounty_id, at_price)?),
+        QueryMsg::GetConversionReport { bounty_id } => {
+            to_json_binary(&get_conversion_report_handler(deps, bounty_id)?)
+        }
+        QueryMsg::GetGasUsageReport { bounty_id } => {
+            to_json_binary(&get_gas_usage_report_handler(deps, bounty_id)?)
+        }
+        QueryMsg::GetBountyBalances { bounty_id } => {
+            to_json_binary(&get_bounty_balances_handler(deps, bounty_id)?)
+        }
+        QueryMsg::GetBountySnapshots {
+            bounty_id,
+            start_after,
+            limit,
+            reverse,
+        } => to_json_binary(&get_bounty_snapshots_handler(
+            deps,
+            bounty_id,
+            start_after,
+            limit,
+            reverse,
+        )?),
+        QueryMsg::SimulateExecution { bounty_id } => {
+            to_json_binary(&simulate_execution_handler(deps, env, bounty_id)?)
         }
         QueryMsg::GetDisburseEscrowTasks { limit } => {
             to_json_binary(&get_disburse_escrow_tasks_handler(deps, env, limit)?)
         }
+        QueryMsg::GetAssignees { bounty_id } => {
+            to_json_binary(&get_assignees_handler(deps, bounty_id)?)
+        }
+        QueryMsg::GetEscrowEpochs { bounty_id } => {
+            to_json_binary(&get_escrow_epochs_handler(deps, bounty_id)?)
+        }
+        QueryMsg::GetEscrowTranches { bounty_id } => {
+            to_json_binary(&get_escrow_tranches_handler(deps, bounty_id)?)
+        }
+        QueryMsg::GetChecklist { bounty_id } => {
+            to_json_binary(&get_checklist_handler(deps, bounty_id)?)
+        }
+        QueryMsg::GetFundingRound { round_id } => {
+            to_json_binary(&get_funding_round_handler(deps, round_id)?)
+        }
+        QueryMsg::GetTopAssignees {
+            period_start,
+            period_end,
+            limit,
+        } => to_json_binary(&get_top_assignees_handler(
+            deps,
+            period_start,
+            period_end,
+            limit,
+        )?),
+        QueryMsg::GetRoundContributions {
+            round_id,
+            bounty_id,
+        } => to_json_binary(&get_round_contributions_handler(deps, round_id, bounty_id)?),
+        QueryMsg::GetApplications { bounty_id } => {
+            to_json_binary(&get_applications_handler(deps, bounty_id)?)
+        }
+        QueryMsg::GetProgressUpdates {
+            bounty_id,
+            start_after,
+            limit,
+            reverse,
+        } => to_json_binary(&get_progress_updates_handler(
+            deps,
+            bounty_id,
+            start_after,
+            limit,
+            reverse,
+        )?),
+        QueryMsg::GetReferrerStats { address } => {
+            to_json_binary(&get_referrer_stats_handler(deps, address)?)
+        }
+        QueryMsg::GetExecutorStats { address } => {
+            to_json_binary(&get_executor_stats_handler(deps, address)?)
+        }
+        QueryMsg::GetRoles { address } => to_json_binary(&get_roles_handler(deps, address)?),
+        QueryMsg::GetModelAdjustment { model_id } => {
+            to_json_binary(&get_model_adjustment_handler(deps, env, model_id)?)
+        }
+        QueryMsg::GetModelProviders {} => to_json_binary(&get_model_providers_handler(deps)?),
+        QueryMsg::GetExchanges {} => to_json_binary(&get_exchanges_handler(deps)?),
+        QueryMsg::GetDispute { bounty_id } => to_json_binary(&get_dispute_handler(deps, bounty_id)?),
+        QueryMsg::GetBountyTemplate { template_id } => {
+            to_json_binary(&get_bounty_template_handler(deps, template_id)?)
+        }
+        QueryMsg::GetUpdatePermissions {
+            bounty_id,
+            delegate,
+        } => to_json_binary(&get_update_permissions_handler(deps, bounty_id, delegate)?),
+        QueryMsg::GetScheduledPayment { payment_id } => {
+            to_json_binary(&get_scheduled_payment_handler(deps, payment_id)?)
+        }
+        QueryMsg::GetScheduledPaymentsByOwner {
+            owner,
+            start_after,
+            limit,
+            reverse,
+        } => to_json_binary(&get_scheduled_payments_by_owner_handler(
+            deps,
+            owner,
+            start_after,
+            limit,
+            reverse,
+        )?),
+        QueryMsg::GetDueScheduledPayments { limit } => {
+            to_json_binary(&get_due_scheduled_payments_handler(deps, env, limit)?)
+        }
     }
 }
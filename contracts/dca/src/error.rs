@@ -1,4 +1,6 @@
-use cosmwasm_std::{CheckedMultiplyRatioError, OverflowError, StdError};
+use cosmwasm_std::{
+    CheckedMultiplyRatioError, Decimal, OverflowError, StdError, Timestamp, Uint128,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -11,6 +13,110 @@ pub enum ContractError {
 
     #[error("Error: {val}")]
     CustomError { val: String },
+
+    #[error("Error: nested cw20 receive messages are not allowed")]
+    NestedCw20Receive {},
+
+    #[error("Error: unknown cw20 hook message: {reason}")]
+    UnknownCw20HookMessage { reason: String },
+
+    #[error("Error: cw20 send of token {token} must have a non-zero amount")]
+    ZeroAmountCw20Send { token: String },
+
+    #[error("Error: cw20 token {token} is not whitelisted")]
+    Cw20TokenNotWhitelisted { token: String },
+
+    #[error("Error: received {received} denoms but required exactly 1")]
+    WrongNumberOfDenoms { received: usize },
+
+    #[error("Error: contract is paused")]
+    ContractPaused {},
+
+    #[error("Error: bounty creation is paused")]
+    CreatePaused {},
+
+    #[error("Error: execution is paused")]
+    ExecutePaused {},
+
+    #[error("Error: deposits are paused")]
+    DepositPaused {},
+
+    #[error("Error: disbursements are paused")]
+    DisbursePaused {},
+
+    #[error("Error: bounty is already cancelled")]
+    BountyCancelled {},
+
+    #[error("Error: bounty is already completed")]
+    BountyCompleted {},
+
+    #[error("Error: bounty is not active")]
+    BountyNotActive {},
+
+    #[error("Error: bounty is not paused")]
+    BountyNotPaused {},
+
+    #[error("Error: bounty is locked until {locked_until}")]
+    BountyLocked { locked_until: Timestamp },
+
+    #[error("Error: bounty with id {bounty_id} has an open dispute")]
+    BountyHasOpenDispute { bounty_id: Uint128 },
+
+    #[error("Error: denom {denom} is not an allowed denom")]
+    DenomNotAllowed { denom: String },
+
+    #[error("Error: received asset with denom {received}, but needed {expected}")]
+    WrongDenom { expected: String, received: String },
+
+    #[error("Error: swapping {swap_denom} to {target_denom} not supported")]
+    PairNotSupported {
+        swap_denom: String,
+        target_denom: String,
+    },
+
+    #[error("Error: slippage tolerance must be less than or equal to 1")]
+    SlippageToleranceTooHigh {},
+
+    #[error("Error: bounty with id {bounty_id} does not permit slippage overrides")]
+    SlippageOverrideNotPermitted { bounty_id: Uint128 },
+
+    #[error("Error: slippage override {slippage} exceeds the cap of {cap} for bounty with id {bounty_id}")]
+    SlippageOverrideTooHigh {
+        bounty_id: Uint128,
+        slippage: Decimal,
+        cap: Decimal,
+    },
+
+    #[error("Error: no more than {max} destinations can be provided")]
+    TooManyDestinations { max: usize },
+
+    #[error("Error: no more than {max} fee collectors are allowed")]
+    TooManyFeeCollectors { max: usize },
+
+    #[error("Error: no more than {max} triggers can be executed at once")]
+    TooManyTriggers { max: usize },
+
+    #[error("Error: no more than {max} fallback routes can be provided")]
+    TooManyFallbackRoutes { max: usize },
+
+    #[error("Error: no more than {max} bounties can be updated at once")]
+    TooManyBounties { max: usize },
+
+    #[error("Error: limit cannot be greater than {max}.")]
+    PageLimitTooLarge { max: u16 },
+
+    #[error("Error: {label} address {address} is invalid")]
+    InvalidAddress { label: String, address: String },
+
+    #[error("Error: {label} address {address} does not have the expected prefix {expected}")]
+    UnexpectedAddressPrefix {
+        label: String,
+        address: String,
+        expected: String,
+    },
+
+    #[error("Error: progress updates are rate limited, next update allowed at {next_allowed_at}")]
+    ProgressUpdateRateLimited { next_allowed_at: Timestamp },
 }
 
 impl From<OverflowError> for ContractError {
@@ -32,7 +138,46 @@ impl From<ContractError> for StdError {
         match from {
             ContractError::Std(err) => err,
             ContractError::CustomError { val } => StdError::generic_err(val),
-            _ => StdError::generic_err(format!("{:#?}", from)),
+            ContractError::Unauthorized {}
+            | ContractError::NestedCw20Receive {}
+            | ContractError::UnknownCw20HookMessage { .. }
+            | ContractError::ZeroAmountCw20Send { .. }
+            | ContractError::Cw20TokenNotWhitelisted { .. } => {
+                StdError::generic_err(format!("{:#?}", from))
+            }
+            _ => StdError::generic_err(from.to_string().trim_start_matches("Error: ")),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_errors_keep_the_custom_error_display_format() {
+        let err = ContractError::WrongDenom {
+            expected: "uusk".to_string(),
+            received: "ukuji".to_string(),
+        };
+
+        assert_eq!(
+            err.to_string(),
+            ContractError::CustomError {
+                val: "received asset with denom ukuji, but needed uusk".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn typed_errors_convert_to_the_same_std_error_as_custom_errors() {
+        assert_eq!(
+            StdError::from(ContractError::PageLimitTooLarge { max: 1000 }).to_string(),
+            StdError::from(ContractError::CustomError {
+                val: "limit cannot be greater than 1000.".to_string()
+            })
+            .to_string()
+        );
+    }
+}
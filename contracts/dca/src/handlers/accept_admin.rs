@@ -0,0 +1,149 @@
+use crate::{
+    error::ContractError,
+    state::{
+        admin_actions::create_admin_action,
+        config::{get_config, update_config},
+        roles::{grant_role, revoke_role},
+    },
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        config::Config,
+        role::Role,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn accept_admin_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = get_config(deps.storage)?;
+
+    if config.pending_admin != Some(info.sender.clone()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let previous_admin = config.admin.clone();
+
+    update_config(
+        deps.storage,
+        Config {
+            admin: info.sender.clone(),
+            pending_admin: None,
+            ..config
+        },
+    )?;
+
+    revoke_role(deps.storage, &previous_admin, &Role::Admin)?;
+    grant_role(deps.storage, &info.sender, &Role::Admin)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender.clone(),
+            env.block,
+            AdminActionData::AdminTransferAccepted {
+                previous_admin: previous_admin.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("accept_admin", "true")
+        .add_attribute("previous_admin", previous_admin)
+        .add_attribute("admin", info.sender))
+}
+
+#[cfg(test)]
+mod accept_admin_tests {
+    use super::*;
+    use crate::handlers::propose_new_admin::propose_new_admin_handler;
+    use crate::state::roles::get_roles;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn with_no_pending_admin_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err =
+            accept_admin_handler(deps.as_mut(), env, mock_info("new-admin", &[])).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_sender_that_is_not_pending_admin_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        propose_new_admin_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("new-admin"),
+        )
+        .unwrap();
+
+        let err = accept_admin_handler(deps.as_mut(), env, mock_info("stranger", &[])).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn transfers_admin_to_pending_admin() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        propose_new_admin_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("new-admin"),
+        )
+        .unwrap();
+
+        accept_admin_handler(deps.as_mut(), env, mock_info("new-admin", &[])).unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.admin, Addr::unchecked("new-admin"));
+        assert_eq!(config.pending_admin, None);
+    }
+
+    #[test]
+    fn moves_admin_role_to_pending_admin() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        propose_new_admin_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("new-admin"),
+        )
+        .unwrap();
+
+        accept_admin_handler(deps.as_mut(), env, mock_info("new-admin", &[])).unwrap();
+
+        assert_eq!(
+            get_roles(deps.as_ref().storage, &Addr::unchecked("new-admin")).unwrap(),
+            vec![Role::Admin]
+        );
+        assert!(get_roles(deps.as_ref().storage, &Addr::unchecked(ADMIN))
+            .unwrap()
+            .is_empty());
+    }
+}
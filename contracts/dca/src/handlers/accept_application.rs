@@ -0,0 +1,181 @@
+use crate::{
+    error::ContractError,
+    helpers::address::normalize_address,
+    helpers::validation::{
+        assert_assignees_limit_is_not_breached, assert_bounty_is_not_cancelled,
+        asset_sender_is_bounty_owner,
+    },
+    state::{
+        applications::{delete_application, get_application},
+        assignees::{get_assignees, save_assignee},
+        bounties::get_bounty,
+        events::create_event,
+    },
+    types::{
+        assignee::Assignee,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn accept_application_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    applicant: Addr,
+) -> Result<Response, ContractError> {
+    let applicant = normalize_address(&applicant);
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+
+    if get_application(deps.storage, bounty.id, &applicant)?.is_none() {
+        return Err(ContractError::CustomError {
+            val: format!("{} has not applied to this bounty", applicant),
+        });
+    }
+
+    save_assignee(
+        deps.storage,
+        bounty.id,
+        &Assignee {
+            address: applicant.clone(),
+            weight: Decimal::one(),
+            submission: None,
+            submitted_at: None,
+            payout_address: None,
+        },
+    )?;
+
+    assert_assignees_limit_is_not_breached(&get_assignees(deps.storage, bounty.id)?)?;
+
+    delete_application(deps.storage, bounty.id, &applicant);
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyApplicationAccepted {
+                applicant: applicant.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("accept_application", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("applicant", applicant))
+}
+
+#[cfg(test)]
+mod accept_application_tests {
+    use super::*;
+    use crate::handlers::apply_to_bounty::apply_to_bounty_handler;
+    use crate::state::assignees::get_assignee;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = accept_application_handler(
+            deps.as_mut(),
+            env,
+            mock_info("not-owner", &[]),
+            bounty.id,
+            Addr::unchecked("applicant"),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn without_application_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = accept_application_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("applicant"),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: applicant has not applied to this bounty"
+        );
+    }
+
+    #[test]
+    fn assigns_applicant_and_removes_application() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        apply_to_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("applicant", &[]),
+            bounty.id,
+            "I can do this".to_string(),
+        )
+        .unwrap();
+
+        accept_application_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("applicant"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_assignee(
+                deps.as_ref().storage,
+                bounty.id,
+                &Addr::unchecked("applicant")
+            )
+            .unwrap(),
+            Some(Assignee {
+                address: Addr::unchecked("applicant"),
+                weight: Decimal::one(),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            })
+        );
+        assert_eq!(
+            get_application(
+                deps.as_ref().storage,
+                bounty.id,
+                &Addr::unchecked("applicant")
+            )
+            .unwrap(),
+            None
+        );
+    }
+}
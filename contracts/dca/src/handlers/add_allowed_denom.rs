@@ -0,0 +1,83 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_has_role,
+    state::{admin_actions::create_admin_action, allowed_denoms::save_allowed_denom},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        denom::Denom,
+        role::Role,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn add_allowed_denom_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: Denom,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    save_allowed_denom(deps.storage, &denom)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::AllowedDenomAdded {
+                denom: denom.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("add_allowed_denom", "true")
+        .add_attribute("denom", denom.to_string()))
+}
+
+#[cfg(test)]
+mod add_allowed_denom_tests {
+    use super::*;
+    use crate::state::allowed_denoms::is_denom_allowed;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = add_allowed_denom_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Denom::new(DENOM_UKUJI).unwrap(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn adds_allowed_denom() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        add_allowed_denom_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Denom::new(DENOM_UKUJI).unwrap(),
+        )
+        .unwrap();
+
+        assert!(is_denom_allowed(deps.as_ref().storage, DENOM_UKUJI));
+        assert!(!is_denom_allowed(deps.as_ref().storage, DENOM_UUSK));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        address::normalize_address,
+        validation::{assert_exchange_weight_is_greater_than_zero, assert_sender_has_role},
+    },
+    state::{admin_actions::create_admin_action, exchanges::save_exchange},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        exchange::Exchange,
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn add_exchange_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+    weight: u64,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    deps.api.addr_validate(address.as_ref())?;
+    assert_exchange_weight_is_greater_than_zero(weight)?;
+
+    save_exchange(
+        deps.storage,
+        &Exchange {
+            address: address.clone(),
+            weight,
+        },
+    )?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ExchangeAdded {
+                address: address.clone(),
+                weight,
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("add_exchange", "true")
+        .add_attribute("exchange", address)
+        .add_attribute("weight", weight.to_string()))
+}
+
+#[cfg(test)]
+mod add_exchange_tests {
+    use super::*;
+    use crate::state::exchanges::get_exchanges;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = add_exchange_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Addr::unchecked("astroport"),
+            1,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_zero_weight_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = add_exchange_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("astroport"),
+            0,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: exchange weight must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn registers_exchange_with_weight() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        add_exchange_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("astroport"),
+            3,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_exchanges(deps.as_ref().storage).unwrap(),
+            vec![Exchange {
+                address: Addr::unchecked("astroport"),
+                weight: 3,
+            }]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{admin_actions::create_admin_action, fee_exemptions::save_fee_exemption},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn add_fee_exemption_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::FeeManager)?;
+
+    deps.api.addr_validate(address.as_ref())?;
+
+    save_fee_exemption(deps.storage, &address)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::FeeExemptionAdded {
+                address: address.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("add_fee_exemption", "true")
+        .add_attribute("address", address))
+}
+
+#[cfg(test)]
+mod add_fee_exemption_tests {
+    use super::*;
+    use crate::state::fee_exemptions::is_fee_exempt;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = add_fee_exemption_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Addr::unchecked("dao"),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn exempts_address_from_fees() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        add_fee_exemption_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("dao"),
+        )
+        .unwrap();
+
+        assert!(is_fee_exempt(
+            deps.as_ref().storage,
+            &Addr::unchecked("dao")
+        ));
+    }
+}
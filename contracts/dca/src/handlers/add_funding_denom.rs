@@ -0,0 +1,166 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_is_not_cancelled, assert_bounty_is_not_completed, assert_denom_is_allowed,
+        assert_route_exists_for_denoms, assert_swap_amount_meets_minimum_swap_amount,
+        asset_sender_is_bounty_owner,
+    },
+    state::{
+        bounties::get_bounty,
+        denom_balances::{get_denom_balance, save_denom_balance},
+    },
+    types::{denom::Denom, denom_balance::DenomBalance},
+};
+use cosmwasm_std::{Binary, Coin, DepsMut, MessageInfo, Response, Uint128};
+
+pub fn add_funding_denom_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    denom: Denom,
+    swap_amount: Uint128,
+    route: Option<Binary>,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_bounty_is_not_completed(&bounty)?;
+
+    if denom.to_string() == bounty.get_swap_denom() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "{} is already the swap denom of bounty {}",
+                denom, bounty.id
+            ),
+        });
+    }
+
+    if get_denom_balance(deps.storage, bounty.id, &denom.to_string())?.is_some() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "{} is already a funding denom of bounty {}",
+                denom, bounty.id
+            ),
+        });
+    }
+
+    assert_denom_is_allowed(deps.storage, &denom.to_string())?;
+    assert_swap_amount_meets_minimum_swap_amount(
+        deps.storage,
+        &Coin::new(swap_amount.into(), denom.to_string()),
+    )?;
+    assert_route_exists_for_denoms(
+        deps.as_ref(),
+        denom.to_string(),
+        bounty.target_denom.to_string(),
+        route.clone(),
+    )?;
+
+    save_denom_balance(
+        deps.storage,
+        bounty.id,
+        &DenomBalance {
+            balance: Coin::new(0, denom.to_string()),
+            deposited_amount: Coin::new(0, denom.to_string()),
+            swap_amount,
+            route,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("add_funding_denom", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("denom", denom.to_string())
+        .add_attribute("swap_amount", swap_amount))
+}
+
+#[cfg(test)]
+mod add_funding_denom_tests {
+    use super::*;
+    use crate::constants::ONE_HUNDRED;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = add_funding_denom_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            bounty.id,
+            Denom::new("uatom").unwrap(),
+            ONE_HUNDRED,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_swap_denom_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = add_funding_denom_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            Denom::new(DENOM_UKUJI).unwrap(),
+            ONE_HUNDRED,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: {} is already the swap denom of bounty {}",
+                DENOM_UKUJI, bounty.id
+            )
+        );
+    }
+
+    #[test]
+    fn saves_empty_denom_balance() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        add_funding_denom_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            Denom::new("uatom").unwrap(),
+            ONE_HUNDRED,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_denom_balance(deps.as_ref().storage, bounty.id, "uatom").unwrap(),
+            Some(DenomBalance {
+                balance: Coin::new(0, "uatom"),
+                deposited_amount: Coin::new(0, "uatom"),
+                swap_amount: ONE_HUNDRED,
+                route: None,
+            })
+        );
+    }
+}
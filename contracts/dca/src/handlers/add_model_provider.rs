@@ -0,0 +1,88 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{admin_actions::create_admin_action, model_adjustments::save_model_provider},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn add_model_provider_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    provider: Addr,
+) -> Result<Response, ContractError> {
+    let provider = normalize_address(&provider);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    deps.api.addr_validate(provider.as_ref())?;
+
+    save_model_provider(deps.storage, &provider)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ModelProviderAdded {
+                provider: provider.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("add_model_provider", "true")
+        .add_attribute("provider", provider))
+}
+
+#[cfg(test)]
+mod add_model_provider_tests {
+    use super::*;
+    use crate::state::model_adjustments::is_model_provider;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = add_model_provider_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Addr::unchecked("provider"),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn whitelists_model_provider() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        add_model_provider_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("provider"),
+        )
+        .unwrap();
+
+        assert!(is_model_provider(
+            deps.as_ref().storage,
+            &Addr::unchecked("provider")
+        ));
+    }
+}
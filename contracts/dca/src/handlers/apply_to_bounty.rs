@@ -0,0 +1,215 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_application_message_is_no_longer_than_1000_characters,
+        assert_bounty_is_not_cancelled,
+    },
+    state::{
+        applications::{get_application, save_application},
+        assignees::get_assignee,
+        bounties::get_bounty,
+        events::create_event,
+    },
+    types::{
+        application::Application,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn apply_to_bounty_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    message: String,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_application_message_is_no_longer_than_1000_characters(&message)?;
+
+    if info.sender == bounty.owner {
+        return Err(ContractError::CustomError {
+            val: String::from("bounty owner cannot apply to their own bounty"),
+        });
+    }
+
+    if get_assignee(deps.storage, bounty.id, &info.sender)?.is_some() {
+        return Err(ContractError::CustomError {
+            val: format!("{} is already assigned to this bounty", info.sender),
+        });
+    }
+
+    if get_application(deps.storage, bounty.id, &info.sender)?.is_some() {
+        return Err(ContractError::CustomError {
+            val: format!("{} has already applied to this bounty", info.sender),
+        });
+    }
+
+    save_application(
+        deps.storage,
+        bounty.id,
+        &Application {
+            applicant: info.sender.clone(),
+            message: message.clone(),
+            applied_at: env.block.time,
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyApplicationSubmitted {
+                applicant: info.sender.clone(),
+                message,
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("apply_to_bounty", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("applicant", info.sender))
+}
+
+#[cfg(test)]
+mod apply_to_bounty_tests {
+    use super::*;
+    use crate::state::assignees::save_assignee;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::assignee::Assignee;
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Decimal};
+
+    #[test]
+    fn with_owner_as_applicant_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = apply_to_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            "I can do this".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: bounty owner cannot apply to their own bounty"
+        );
+    }
+
+    #[test]
+    fn with_existing_assignee_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("applicant"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        let err = apply_to_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info("applicant", &[]),
+            bounty.id,
+            "I can do this".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: applicant is already assigned to this bounty"
+        );
+    }
+
+    #[test]
+    fn saves_application() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        apply_to_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("applicant", &[]),
+            bounty.id,
+            "I can do this".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_application(
+                deps.as_ref().storage,
+                bounty.id,
+                &Addr::unchecked("applicant")
+            )
+            .unwrap(),
+            Some(Application {
+                applicant: Addr::unchecked("applicant"),
+                message: "I can do this".to_string(),
+                applied_at: env.block.time,
+            })
+        );
+    }
+
+    #[test]
+    fn when_already_applied_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        apply_to_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("applicant", &[]),
+            bounty.id,
+            "I can do this".to_string(),
+        )
+        .unwrap();
+
+        let err = apply_to_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info("applicant", &[]),
+            bounty.id,
+            "I can really do this".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: applicant has already applied to this bounty"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use crate::{
+    constants::FAIL_SILENTLY_REPLY_ID,
+    error::ContractError,
+    helpers::validation::{
+        assert_address_is_valid, assert_assignee_weight_is_greater_than_zero,
+        assert_assignees_limit_is_not_breached, assert_bounty_is_not_cancelled,
+        asset_sender_is_bounty_owner,
+    },
+    helpers::{address::normalize_address, fee_grant::create_fee_grant_message},
+    state::{
+        assignees::{get_assignee, get_assignees, save_assignee},
+        bounties::get_bounty,
+        config::get_config,
+        events::create_event,
+    },
+    types::{
+        assignee::Assignee,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128};
+
+pub fn assign_bounty_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    assignee: Addr,
+    weight: Decimal,
+) -> Result<Response, ContractError> {
+    let assignee = normalize_address(&assignee);
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_address_is_valid(deps.as_ref(), &assignee, "assignee")?;
+    assert_assignee_weight_is_greater_than_zero(weight)?;
+
+    let existing_assignee = get_assignee(deps.storage, bounty.id, &assignee)?;
+
+    let new_assignee = Assignee {
+        address: assignee.clone(),
+        weight,
+        submission: existing_assignee
+            .clone()
+            .and_then(|assignee| assignee.submission),
+        submitted_at: existing_assignee
+            .clone()
+            .and_then(|assignee| assignee.submitted_at),
+        payout_address: existing_assignee
+            .clone()
+            .and_then(|assignee| assignee.payout_address),
+    };
+
+    save_assignee(deps.storage, bounty.id, &new_assignee)?;
+
+    assert_assignees_limit_is_not_breached(&get_assignees(deps.storage, bounty.id)?)?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyAssigned {
+                assignee: assignee.clone(),
+                weight,
+            },
+        ),
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("assign_bounty", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("assignee", assignee.clone())
+        .add_attribute("weight", weight.to_string());
+
+    if let Some(fee_grant) = get_config(deps.storage)?.fee_grant {
+        if existing_assignee.is_none() && fee_grant.partners.contains(&bounty.owner) {
+            response = response
+                .add_attribute("fee_grant_issued", "true")
+                .add_submessage(SubMsg::reply_on_error(
+                    create_fee_grant_message(env.contract.address, assignee, fee_grant.spend_limit),
+                    FAIL_SILENTLY_REPLY_ID,
+                ));
+        }
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod assign_bounty_tests {
+    use super::*;
+    use crate::state::assignees::get_assignees;
+    use crate::state::config::update_config;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI, USER};
+    use crate::types::bounty::{Bounty, BountyStatus};
+    use crate::types::config::Config;
+    use crate::types::fee_grant_config::FeeGrantConfig;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = assign_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info("not-owner", &[]),
+            bounty.id,
+            Addr::unchecked("assignee"),
+            Decimal::percent(100),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_cancelled_bounty_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Cancelled,
+                ..Bounty::default()
+            },
+        );
+
+        let err = assign_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("assignee"),
+            Decimal::percent(100),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: bounty is already cancelled");
+    }
+
+    #[test]
+    fn with_zero_weight_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = assign_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("assignee"),
+            Decimal::zero(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: assignee weight must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_more_than_10_assignees_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        for i in 0..10 {
+            assign_bounty_handler(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(USER, &[]),
+                bounty.id,
+                Addr::unchecked(format!("assignee-{}", i)),
+                Decimal::percent(10),
+            )
+            .unwrap();
+        }
+
+        let err = assign_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("assignee-10"),
+            Decimal::percent(10),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: no more than 10 assignees can be added to a bounty"
+        );
+    }
+
+    #[test]
+    fn saves_assignee_with_weight() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        assign_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("assignee"),
+            Decimal::percent(40),
+        )
+        .unwrap();
+
+        let assignees = get_assignees(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(
+            assignees,
+            vec![Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(40),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn with_partner_owner_issues_fee_grant_to_assignee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                fee_grant: Some(FeeGrantConfig {
+                    partners: vec![Addr::unchecked(USER)],
+                    spend_limit: Coin::new(100000, DENOM_UKUJI),
+                }),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let response = assign_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("assignee"),
+            Decimal::percent(40),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_on_error(
+                create_fee_grant_message(
+                    env.contract.address,
+                    Addr::unchecked("assignee"),
+                    Coin::new(100000, DENOM_UKUJI),
+                ),
+                FAIL_SILENTLY_REPLY_ID,
+            )]
+        );
+    }
+
+    #[test]
+    fn with_non_partner_owner_does_not_issue_fee_grant() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                fee_grant: Some(FeeGrantConfig {
+                    partners: vec![Addr::unchecked("partner")],
+                    spend_limit: Coin::new(100000, DENOM_UKUJI),
+                }),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let response = assign_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("assignee"),
+            Decimal::percent(40),
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_is_not_cancelled, assert_no_more_than_50_bounty_ids,
+        assert_slippage_tolerance_is_less_than_or_equal_to_one, asset_sender_is_bounty_owner,
+    },
+    state::{
+        bounties::{get_bounty, update_bounty},
+        events::create_event,
+    },
+    types::{
+        bounty::Bounty,
+        event::{EventBuilder, EventData},
+        update::FieldUpdate,
+    },
+};
+use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn bulk_update_slippage_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_ids: Vec<Uint128>,
+    slippage_tolerance: Decimal,
+) -> Result<Response, ContractError> {
+    assert_no_more_than_50_bounty_ids(&bounty_ids)?;
+    assert_slippage_tolerance_is_less_than_or_equal_to_one(slippage_tolerance)?;
+
+    let mut response = Response::new()
+        .add_attribute("bulk_update_slippage", "true")
+        .add_attribute("owner", info.sender.clone())
+        .add_attribute("slippage_tolerance", slippage_tolerance.to_string());
+
+    for bounty_id in bounty_ids {
+        let result = get_bounty(deps.storage, bounty_id)
+            .map_err(ContractError::from)
+            .and_then(|bounty| {
+                asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender.clone())?;
+                assert_bounty_is_not_cancelled(&bounty)?;
+                Ok(bounty)
+            });
+
+        match result {
+            Ok(bounty) => {
+                update_bounty(
+                    deps.storage,
+                    Bounty {
+                        slippage_tolerance,
+                        ..bounty.clone()
+                    },
+                )?;
+
+                create_event(
+                    deps.storage,
+                    EventBuilder::new(
+                        bounty.id,
+                        env.block.clone(),
+                        EventData::BountyUpdated {
+                            updates: vec![FieldUpdate::SlippageTolerance {
+                                old: bounty.slippage_tolerance,
+                                new: slippage_tolerance,
+                            }],
+                        },
+                    ),
+                )?;
+
+                response = response.add_attribute(format!("bounty_{}", bounty_id), "updated");
+            }
+            Err(err) => {
+                response = response.add_attribute(format!("bounty_{}", bounty_id), err.to_string());
+            }
+        }
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod bulk_update_slippage_tests {
+    use super::*;
+    use crate::handlers::get_bounty::get_bounty_handler;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::BountyStatus;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Attribute};
+
+    #[test]
+    fn with_slippage_tolerance_larger_than_one_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = bulk_update_slippage_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vec![bounty.id],
+            Decimal::percent(101),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: slippage tolerance must be less than or equal to 1"
+        );
+    }
+
+    #[test]
+    fn with_more_than_50_bounty_ids_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = bulk_update_slippage_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            (1..=51).map(Uint128::new).collect(),
+            Decimal::percent(2),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: no more than 50 bounties can be updated at once"
+        );
+    }
+
+    #[test]
+    fn updates_owned_bounties_and_reports_each_id() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let owned_bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let other_bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                owner: Addr::unchecked("someone-else"),
+                ..Bounty::default()
+            },
+        );
+
+        let cancelled_bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Cancelled,
+                ..Bounty::default()
+            },
+        );
+
+        let response = bulk_update_slippage_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vec![owned_bounty.id, other_bounty.id, cancelled_bounty.id],
+            Decimal::percent(4),
+        )
+        .unwrap();
+
+        assert!(response.attributes.contains(&Attribute::new(
+            format!("bounty_{}", owned_bounty.id),
+            "updated"
+        )));
+        assert!(response.attributes.contains(&Attribute::new(
+            format!("bounty_{}", other_bounty.id),
+            "Unauthorized"
+        )));
+        assert!(response.attributes.contains(&Attribute::new(
+            format!("bounty_{}", cancelled_bounty.id),
+            "Error: bounty is already cancelled"
+        )));
+
+        assert_eq!(
+            get_bounty_handler(deps.as_ref(), owned_bounty.id)
+                .unwrap()
+                .bounty
+                .slippage_tolerance,
+            Decimal::percent(4)
+        );
+        assert_eq!(
+            get_bounty_handler(deps.as_ref(), other_bounty.id)
+                .unwrap()
+                .bounty
+                .slippage_tolerance,
+            other_bounty.slippage_tolerance
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{
+    error::ContractError,
+    handlers::cancel_bounty::cancel_bounty_handler,
+    helpers::validation::{assert_bounty_is_not_locked, assert_page_limit_is_valid},
+    state::bounties::get_bounties_by_address,
+    types::bounty::BountyStatus,
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn cancel_all_bounties_handler(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    status: Option<BountyStatus>,
+    start_after: Option<Uint128>,
+    limit: Option<u16>,
+) -> Result<Response, ContractError> {
+    assert_page_limit_is_valid(limit)?;
+
+    let bounties = get_bounties_by_address(
+        deps.storage,
+        info.sender.clone(),
+        status,
+        start_after,
+        limit,
+        None,
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("cancel_all_bounties", "true")
+        .add_attribute("owner", info.sender.clone());
+
+    for bounty in &bounties {
+        if bounty.is_cancelled() {
+            continue;
+        }
+
+        // cancel_bounty_handler writes state before it can fail, so skip bounties
+        // that cannot be cancelled up front and fail the page on anything else.
+        if let Err(err) = assert_bounty_is_not_locked(bounty, env.block.time) {
+            response = response.add_attribute(format!("bounty_{}", bounty.id), err.to_string());
+            continue;
+        }
+
+        let cancel_response = cancel_bounty_handler(
+            deps.branch(),
+            env.clone(),
+            info.clone(),
+            bounty.id,
+            None,
+            None,
+        )?;
+
+        response = response
+            .add_attribute(format!("bounty_{}", bounty.id), "cancelled")
+            .add_attributes(cancel_response.attributes)
+            .add_events(cancel_response.events)
+            .add_submessages(cancel_response.messages);
+    }
+
+    if let Some(last_bounty) = bounties.last() {
+        response = response.add_attribute("next_start_after", last_bounty.id);
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod cancel_all_bounties_tests {
+    use super::*;
+    use crate::handlers::get_bounty::get_bounty_handler;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Attribute, BankMsg, SubMsg};
+
+    #[test]
+    fn with_limit_too_large_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = cancel_all_bounties_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            None,
+            None,
+            Some(1001),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: limit cannot be greater than 1000.");
+    }
+
+    #[test]
+    fn cancels_owned_bounties_and_refunds_balances() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let owned_bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let other_bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                owner: Addr::unchecked("someone-else"),
+                ..Bounty::default()
+            },
+        );
+
+        let response =
+            cancel_all_bounties_handler(deps.as_mut(), env, mock_info(USER, &[]), None, None, None)
+                .unwrap();
+
+        assert!(response.attributes.contains(&Attribute::new(
+            format!("bounty_{}", owned_bounty.id),
+            "cancelled"
+        )));
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: owned_bounty.owner.to_string(),
+            amount: vec![owned_bounty.balance.clone()],
+        })));
+
+        assert_eq!(
+            get_bounty_handler(deps.as_ref(), owned_bounty.id)
+                .unwrap()
+                .bounty
+                .status,
+            BountyStatus::Cancelled
+        );
+        assert_eq!(
+            get_bounty_handler(deps.as_ref(), other_bounty.id)
+                .unwrap()
+                .bounty
+                .status,
+            other_bounty.status
+        );
+    }
+
+    #[test]
+    fn skips_locked_bounties_without_modifying_them() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let locked_bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                created_at: env.block.time,
+                lock_period: Some(100),
+                ..Bounty::default()
+            },
+        );
+
+        let unlocked_bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let response =
+            cancel_all_bounties_handler(deps.as_mut(), env, mock_info(USER, &[]), None, None, None)
+                .unwrap();
+
+        assert!(response.attributes.contains(&Attribute::new(
+            format!("bounty_{}", unlocked_bounty.id),
+            "cancelled"
+        )));
+        assert!(response.attributes.contains(&Attribute::new(
+            "refunded_amount",
+            unlocked_bounty.balance.to_string()
+        )));
+        assert!(!response.attributes.contains(&Attribute::new(
+            format!("bounty_{}", locked_bounty.id),
+            "cancelled"
+        )));
+        let bounty = get_bounty_handler(deps.as_ref(), locked_bounty.id)
+            .unwrap()
+            .bounty;
+
+        assert_eq!(bounty.status, locked_bounty.status);
+        assert_eq!(bounty.balance, locked_bounty.balance);
+    }
+
+    #[test]
+    fn with_status_only_cancels_matching_bounties() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let active_bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Active,
+                ..Bounty::default()
+            },
+        );
+
+        let paused_bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Paused,
+                ..Bounty::default()
+            },
+        );
+
+        cancel_all_bounties_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Some(BountyStatus::Paused),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_bounty_handler(deps.as_ref(), active_bounty.id)
+                .unwrap()
+                .bounty
+                .status,
+            BountyStatus::Active
+        );
+        assert_eq!(
+            get_bounty_handler(deps.as_ref(), paused_bounty.id)
+                .unwrap()
+                .bounty
+                .status,
+            BountyStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn with_limit_returns_continuation_cursor() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let first_bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+        let second_bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let response = cancel_all_bounties_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            None,
+            None,
+            Some(1),
+        )
+        .unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("next_start_after", first_bounty.id)));
+        assert_eq!(
+            get_bounty_handler(deps.as_ref(), second_bounty.id)
+                .unwrap()
+                .bounty
+                .status,
+            second_bounty.status
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_has_role,
+    state::{
+        admin_actions::create_admin_action,
+        scheduled_config_updates::{delete_scheduled_config_update, get_scheduled_config_update},
+    },
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn cancel_scheduled_config_update_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    scheduled_config_update_id: u64,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    let scheduled_config_update =
+        get_scheduled_config_update(deps.storage, scheduled_config_update_id)?;
+
+    delete_scheduled_config_update(deps.storage, scheduled_config_update.id)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ConfigUpdateCancelled {
+                scheduled_config_update_id: scheduled_config_update.id,
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("cancel_scheduled_config_update", "true")
+        .add_attribute(
+            "scheduled_config_update_id",
+            scheduled_config_update.id.to_string(),
+        ))
+}
+
+#[cfg(test)]
+mod cancel_scheduled_config_update_tests {
+    use super::*;
+    use crate::{
+        state::scheduled_config_updates::{
+            get_scheduled_config_updates, save_scheduled_config_update,
+        },
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{ADMIN, USER},
+        },
+        types::scheduled_config_update::ConfigChanges,
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let scheduled_config_update = save_scheduled_config_update(
+            deps.as_mut().storage,
+            env.block.time,
+            env.block.time.plus_seconds(10),
+            ConfigChanges::default(),
+        )
+        .unwrap();
+
+        let err = cancel_scheduled_config_update_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            scheduled_config_update.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn deletes_scheduled_config_update() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let scheduled_config_update = save_scheduled_config_update(
+            deps.as_mut().storage,
+            env.block.time,
+            env.block.time.plus_seconds(10),
+            ConfigChanges::default(),
+        )
+        .unwrap();
+
+        cancel_scheduled_config_update_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            scheduled_config_update.id,
+        )
+        .unwrap();
+
+        assert!(get_scheduled_config_updates(deps.as_ref().storage)
+            .unwrap()
+            .is_empty());
+    }
+}
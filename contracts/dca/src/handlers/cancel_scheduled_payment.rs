@@ -0,0 +1,95 @@
+use crate::{
+    error::ContractError,
+    state::scheduled_payments::{delete_scheduled_payment, get_scheduled_payment},
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn cancel_scheduled_payment_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    payment_id: u64,
+) -> Result<Response, ContractError> {
+    let scheduled_payment = get_scheduled_payment(deps.storage, payment_id)?;
+
+    if info.sender != scheduled_payment.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    delete_scheduled_payment(deps.storage, scheduled_payment.id)?;
+
+    Ok(Response::new()
+        .add_attribute("cancel_scheduled_payment", "true")
+        .add_attribute("payment_id", scheduled_payment.id.to_string())
+        .add_attribute("refunded", scheduled_payment.amount.to_string())
+        .add_message(scheduled_payment.refund_msg()))
+}
+
+#[cfg(test)]
+mod cancel_scheduled_payment_tests {
+    use super::*;
+    use crate::{
+        state::scheduled_payments::save_scheduled_payment,
+        tests::mocks::{DENOM_UKUJI, USER},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, BankMsg, Coin, SubMsg,
+    };
+
+    #[test]
+    fn with_non_owner_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let scheduled_payment = save_scheduled_payment(
+            deps.as_mut().storage,
+            Addr::unchecked(USER),
+            Addr::unchecked("recipient"),
+            Coin::new(1000, DENOM_UKUJI),
+            env.block.time.plus_seconds(10),
+            env.block.time,
+        )
+        .unwrap();
+
+        let err = cancel_scheduled_payment_handler(
+            deps.as_mut(),
+            mock_info("recipient", &[]),
+            scheduled_payment.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn refunds_owner_and_deletes_payment() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let scheduled_payment = save_scheduled_payment(
+            deps.as_mut().storage,
+            Addr::unchecked(USER),
+            Addr::unchecked("recipient"),
+            Coin::new(1000, DENOM_UKUJI),
+            env.block.time.plus_seconds(10),
+            env.block.time,
+        )
+        .unwrap();
+
+        let response = cancel_scheduled_payment_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            scheduled_payment.id,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: USER.to_string(),
+                amount: vec![Coin::new(1000, DENOM_UKUJI)],
+            })]
+        );
+        assert!(get_scheduled_payment(deps.as_ref().storage, scheduled_payment.id).is_err());
+    }
+}
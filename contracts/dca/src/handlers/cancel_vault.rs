@@ -1,37 +1,104 @@
-use crate::constants::FAIL_SILENTLY_REPLY_ID;
+use crate::constants::{FAIL_SILENTLY_REPLY_ID, MAX_IMMEDIATE_REFUNDS};
 use crate::error::ContractError;
+use crate::helpers::cleanup::clear_terminal_bounty_state;
 use crate::helpers::validation::{
-    assert_sender_is_admin_or_bounty_owner, assert_bounty_is_not_cancelled,
+    assert_bounty_is_not_cancelled, assert_bounty_is_not_locked,
+    assert_cancel_note_is_no_longer_than_280_characters, assert_sender_is_admin_or_bounty_owner,
 };
+use crate::state::admin_actions::create_admin_action;
 use crate::state::config::get_config;
+use crate::state::denom_balances::{delete_denom_balance, get_denom_balances};
 use crate::state::disburse_escrow_tasks::save_disburse_escrow_task;
 use crate::state::events::create_event;
-use crate::state::triggers::delete_trigger;
+use crate::state::refund_queue::enqueue_refund;
+use crate::state::roles::has_role;
 use crate::state::bounties::{get_bounty, update_bounty};
+use crate::types::admin_action::{AdminActionBuilder, AdminActionData};
+use crate::types::authz::OperatorPermission;
+use crate::types::cancel_reason::CancelReason;
 use crate::types::event::{EventBuilder, EventData};
+use crate::types::queued_refund::QueuedRefund;
+use crate::types::role::Role;
 use crate::types::trigger::TriggerConfiguration;
 use crate::types::bounty::{Bounty, BountyStatus};
-use cosmwasm_std::{to_json_binary, BankMsg, DepsMut, Response, Uint128, WasmMsg};
+use cosmwasm_std::{to_json_binary, Coin, DepsMut, Response, Uint128, WasmMsg};
 use cosmwasm_std::{Env, MessageInfo, SubMsg};
 use exchange::msg::ExecuteMsg;
 use shared::coin::empty_of;
+use shared::cw20::into_transfer_msg;
 
 pub fn cancel_bounty_handler(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     bounty_id: Uint128,
+    reason: Option<CancelReason>,
+    note: Option<String>,
 ) -> Result<Response, ContractError> {
     let bounty = get_bounty(deps.storage, bounty_id)?;
 
-    assert_sender_is_admin_or_bounty_owner(deps.storage, bounty.owner.clone(), info.sender)?;
+    assert_sender_is_admin_or_bounty_owner(
+        deps.storage,
+        &bounty,
+        &info.sender,
+        OperatorPermission::Cancel,
+    )?;
     assert_bounty_is_not_cancelled(&bounty)?;
+    assert_bounty_is_not_locked(&bounty, env.block.time)?;
+
+    if let Some(note) = &note {
+        assert_cancel_note_is_no_longer_than_280_characters(note)?;
+    }
+
+    if has_role(deps.storage, &info.sender, &Role::Admin)? {
+        create_admin_action(
+            deps.storage,
+            AdminActionBuilder::new(
+                info.sender,
+                env.block.clone(),
+                AdminActionData::BountyCancelled {
+                    bounty_id: bounty.id,
+                },
+            ),
+        )?;
+    }
 
     create_event(
         deps.storage,
-        EventBuilder::new(bounty.id, env.block.clone(), EventData::BountyCancelled {}),
+        EventBuilder::new(
+            bounty.id,
+            env.block.clone(),
+            EventData::BountyCancelled {
+                reason: reason.clone(),
+                note: note.clone(),
+            },
+        ),
     )?;
 
+    let cancel_response = cancel_bounty(deps, &env, bounty.clone(), reason.clone(), note)?;
+
+    Ok(Response::new()
+        .add_attribute("cancel_bounty", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("owner", bounty.owner)
+        .add_attributes(cancel_response.attributes)
+        .add_attribute(
+            "cancel_reason",
+            reason.map_or("none".to_string(), |reason| format!("{:?}", reason)),
+        )
+        .add_submessages(cancel_response.messages))
+}
+
+/// Refunds every balance held for the bounty, retracts any open limit order
+/// and moves the bounty to Cancelled. Shared by owner cancellation and expiry,
+/// callers are responsible for authorisation and the event they record.
+pub fn cancel_bounty(
+    deps: DepsMut,
+    env: &Env,
+    bounty: Bounty,
+    reason: Option<CancelReason>,
+    note: Option<String>,
+) -> Result<Response, ContractError> {
     if bounty.escrowed_amount.amount > Uint128::zero() {
         save_disburse_escrow_task(
             deps.storage,
@@ -41,19 +108,50 @@ pub fn cancel_bounty_handler(
     };
 
     let mut submessages = Vec::<SubMsg>::new();
+    let mut refunds = Vec::<Coin>::new();
 
     if bounty.balance.amount > Uint128::zero() {
-        submessages.push(SubMsg::new(BankMsg::Send {
-            to_address: bounty.destination.to_string(),
-            amount: vec![bounty.balance.clone()],
-        }));
+        refunds.push(bounty.balance.clone());
+    }
+
+    for denom_balance in get_denom_balances(deps.storage, bounty.id)? {
+        if denom_balance.balance.amount > Uint128::zero() {
+            refunds.push(denom_balance.balance.clone());
+        }
+
+        delete_denom_balance(deps.storage, bounty.id, &denom_balance.balance.denom)?;
+    }
+
+    let queued_refunds = refunds.len().saturating_sub(MAX_IMMEDIATE_REFUNDS);
+
+    for (index, refund) in refunds.into_iter().enumerate() {
+        if index < MAX_IMMEDIATE_REFUNDS {
+            submessages.push(SubMsg::new(into_transfer_msg(
+                deps.api,
+                bounty.owner.as_ref(),
+                refund,
+            )?));
+        } else {
+            enqueue_refund(
+                deps.storage,
+                QueuedRefund {
+                    id: 0,
+                    bounty_id: bounty.id,
+                    recipient: bounty.owner.clone(),
+                    amount: refund,
+                    queued_at: env.block.time,
+                },
+            )?;
+        }
     }
 
-    update_bounty(
+    let cancelled_bounty = update_bounty(
         deps.storage,
         Bounty {
             status: BountyStatus::Cancelled,
             balance: empty_of(bounty.balance.clone()),
+            cancel_reason: reason,
+            cancel_note: note,
             ..bounty.clone()
         },
     )?;
@@ -88,29 +186,34 @@ pub fn cancel_bounty_handler(
         ));
     };
 
-    delete_trigger(deps.storage, bounty.id)?;
+    clear_terminal_bounty_state(deps.storage, &cancelled_bounty)?;
 
     Ok(Response::new()
-        .add_attribute("cancel_bounty", "true")
-        .add_attribute("bounty_id", bounty.id)
-        .add_attribute("owner", bounty.owner)
         .add_attribute("refunded_amount", bounty.balance.to_string())
+        .add_attribute("queued_refunds", queued_refunds.to_string())
         .add_submessages(submessages))
 }
 
 #[cfg(test)]
 mod cancel_bounty_tests {
     use super::*;
-    use crate::constants::ONE;
+    use crate::constants::{ONE, TEN};
     use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
     use crate::handlers::get_vault::get_bounty_handler;
+    use crate::state::admin_actions::get_admin_actions;
+    use crate::state::denom_balances::save_denom_balance;
     use crate::state::disburse_escrow_tasks::get_disburse_escrow_tasks;
+    use crate::state::refund_queue::get_queued_refunds;
     use crate::tests::helpers::{instantiate_contract, setup_bounty};
     use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI};
+    use crate::types::admin_action::AdminAction;
+    use crate::types::cancel_reason::CancelReason;
+    use crate::types::denom_balance::DenomBalance;
     use crate::types::event::{EventBuilder, EventData};
     use crate::types::bounty::{Bounty, BountyStatus};
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{BankMsg, Coin, Decimal, SubMsg, Uint128};
+    use cosmwasm_std::{Addr, Attribute, BankMsg, Coin, Decimal, SubMsg, Uint128};
+    use cw20::Cw20ExecuteMsg;
 
     #[test]
     fn should_return_balance_to_owner() {
@@ -122,7 +225,7 @@ mod cancel_bounty_tests {
 
         let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
 
-        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id).unwrap();
+        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
 
         assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
             to_address: bounty.owner.to_string(),
@@ -130,6 +233,111 @@ mod cancel_bounty_tests {
         })));
     }
 
+    #[test]
+    fn should_return_additional_denom_balances_to_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(TEN.into(), "uatom"),
+                deposited_amount: Coin::new(TEN.into(), "uatom"),
+                swap_amount: ONE,
+                route: None,
+            },
+        )
+        .unwrap();
+
+        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: bounty.owner.to_string(),
+            amount: vec![Coin::new(TEN.into(), "uatom")],
+        })));
+        assert!(get_denom_balances(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn with_many_denom_balances_should_queue_overflow_refunds() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        for i in 0..MAX_IMMEDIATE_REFUNDS {
+            let denom = format!("udenom{}", i);
+
+            save_denom_balance(
+                deps.as_mut().storage,
+                bounty.id,
+                &DenomBalance {
+                    balance: Coin::new(TEN.into(), denom.clone()),
+                    deposited_amount: Coin::new(TEN.into(), denom),
+                    swap_amount: ONE,
+                    route: None,
+                },
+            )
+            .unwrap();
+        }
+
+        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
+
+        let queued_refunds = get_queued_refunds(deps.as_ref().storage, 10).unwrap();
+
+        assert_eq!(response.messages.len(), MAX_IMMEDIATE_REFUNDS);
+        assert_eq!(queued_refunds.len(), 1);
+        assert_eq!(queued_refunds[0].recipient, bounty.owner);
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("queued_refunds", "1")));
+        assert_eq!(
+            get_bounty(deps.as_ref().storage, bounty.id).unwrap().status,
+            BountyStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn with_cw20_balance_should_transfer_balance_to_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                balance: Coin::new(TEN.into(), "cw20tokenaddress"),
+                ..Bounty::default()
+            },
+        );
+
+        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
+            contract_addr: "cw20tokenaddress".to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: bounty.owner.to_string(),
+                amount: bounty.balance.amount,
+            })
+            .unwrap(),
+            funds: vec![],
+        })));
+    }
+
     #[test]
     fn with_price_trigger_should_return_balance_to_owner() {
         let mut deps = mock_dependencies();
@@ -150,7 +358,7 @@ mod cancel_bounty_tests {
             },
         );
 
-        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id).unwrap();
+        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
 
         assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
             to_address: bounty.owner.to_string(),
@@ -158,6 +366,56 @@ mod cancel_bounty_tests {
         })));
     }
 
+    #[test]
+    fn when_cancelled_by_admin_should_record_admin_action() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        cancel_bounty_handler(deps.as_mut(), env.clone(), info, bounty.id, None, None).unwrap();
+
+        assert_eq!(
+            get_admin_actions(deps.as_ref().storage, None, None, None).unwrap(),
+            vec![AdminAction {
+                id: 1,
+                admin: Addr::unchecked(ADMIN),
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                data: AdminActionData::BountyCancelled {
+                    bounty_id: bounty.id,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn when_cancelled_by_owner_should_not_record_admin_action() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        cancel_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info(bounty.owner.as_ref(), &[]),
+            bounty.id,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(get_admin_actions(deps.as_ref().storage, None, None, None)
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn should_publish_bounty_cancelled_event() {
         let mut deps = mock_dependencies();
@@ -168,17 +426,91 @@ mod cancel_bounty_tests {
 
         let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
 
-        cancel_bounty_handler(deps.as_mut(), env.clone(), info, bounty.id).unwrap();
+        cancel_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            bounty.id,
+            Some(CancelReason::Abandoned),
+            Some("no longer needed".to_string()),
+        )
+        .unwrap();
 
         let events = get_events_by_resource_id_handler(deps.as_ref(), bounty.id, None, None, None)
             .unwrap()
             .events;
 
         assert!(events.contains(
-            &EventBuilder::new(bounty.id, env.block, EventData::BountyCancelled {}).build(1)
+            &EventBuilder::new(
+                bounty.id,
+                env.block,
+                EventData::BountyCancelled {
+                    reason: Some(CancelReason::Abandoned),
+                    note: Some("no longer needed".to_string()),
+                }
+            )
+            .build(1)
         ));
     }
 
+    #[test]
+    fn should_save_cancel_reason_and_note_on_bounty() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        cancel_bounty_handler(
+            deps.as_mut(),
+            env,
+            info,
+            bounty.id,
+            Some(CancelReason::CompletedEarly),
+            Some("work delivered ahead of schedule".to_string()),
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty_handler(deps.as_ref(), bounty.id).unwrap().bounty;
+
+        assert_eq!(
+            updated_bounty.cancel_reason,
+            Some(CancelReason::CompletedEarly)
+        );
+        assert_eq!(
+            updated_bounty.cancel_note,
+            Some("work delivered ahead of schedule".to_string())
+        );
+    }
+
+    #[test]
+    fn with_note_longer_than_280_characters_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = cancel_bounty_handler(
+            deps.as_mut(),
+            env,
+            info,
+            bounty.id,
+            Some(CancelReason::Other),
+            Some("a".repeat(281)),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Cancellation note cannot be longer than 280 characters"
+        );
+    }
+
     #[test]
     fn when_bounty_has_time_trigger_should_cancel_bounty() {
         let mut deps = mock_dependencies();
@@ -189,7 +521,7 @@ mod cancel_bounty_tests {
 
         let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
 
-        cancel_bounty_handler(deps.as_mut(), env, info, bounty.id).unwrap();
+        cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
 
         let updated_bounty = get_bounty_handler(deps.as_ref(), bounty.id).unwrap().bounty;
 
@@ -207,7 +539,7 @@ mod cancel_bounty_tests {
 
         let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
 
-        cancel_bounty_handler(deps.as_mut(), env, info, bounty.id).unwrap();
+        cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
 
         let updated_bounty = get_bounty_handler(deps.as_ref(), bounty.id).unwrap().bounty;
 
@@ -232,11 +564,67 @@ mod cancel_bounty_tests {
             },
         );
 
-        let err = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id).unwrap_err();
+        let err = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap_err();
 
         assert_eq!(err.to_string(), "Error: Bounty is already cancelled");
     }
 
+    #[test]
+    fn within_lock_period_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                created_at: env.block.time,
+                lock_period: Some(100),
+                ..Bounty::default()
+            },
+        );
+
+        let err = cancel_bounty_handler(deps.as_mut(), env.clone(), info, bounty.id, None, None)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: bounty is locked until {}", env.block.time.plus_seconds(100))
+        );
+    }
+
+    #[test]
+    fn after_lock_period_should_cancel_bounty() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                created_at: env.block.time,
+                lock_period: Some(100),
+                ..Bounty::default()
+            },
+        );
+
+        env.block.time = env.block.time.plus_seconds(100);
+
+        cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
+
+        let updated_bounty = get_bounty_handler(deps.as_ref(), bounty.id)
+            .unwrap()
+            .bounty;
+
+        assert_eq!(updated_bounty.status, BountyStatus::Cancelled);
+    }
+
     #[test]
     fn for_bounty_with_different_owner_should_fail() {
         let mut deps = mock_dependencies();
@@ -252,6 +640,8 @@ mod cancel_bounty_tests {
             env,
             mock_info("not-the-owner", &[]),
             bounty.id,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -268,7 +658,7 @@ mod cancel_bounty_tests {
 
         let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
 
-        cancel_bounty_handler(deps.as_mut(), env, info, bounty.id).unwrap();
+        cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
 
         let updated_bounty = get_bounty_handler(deps.as_ref(), bounty.id).unwrap().bounty;
 
@@ -293,7 +683,7 @@ mod cancel_bounty_tests {
             },
         );
 
-        cancel_bounty_handler(deps.as_mut(), env.clone(), info, bounty.id).unwrap();
+        cancel_bounty_handler(deps.as_mut(), env.clone(), info, bounty.id, None, None).unwrap();
 
         let disburse_escrow_tasks_before = get_disburse_escrow_tasks(
             deps.as_ref().storage,
@@ -340,7 +730,7 @@ mod cancel_bounty_tests {
             },
         );
 
-        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id).unwrap();
+        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
@@ -383,7 +773,7 @@ mod cancel_bounty_tests {
             },
         );
 
-        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id).unwrap();
+        let response = cancel_bounty_handler(deps.as_mut(), env, info, bounty.id, None, None).unwrap();
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
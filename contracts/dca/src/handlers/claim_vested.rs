@@ -0,0 +1,334 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        cleanup::clear_terminal_bounty_state, validation::assert_bounty_has_no_open_dispute,
+    },
+    state::{
+        assignees::{get_assignee, get_assignees},
+        bounties::{get_bounty, update_bounty},
+        claims::{add_claimed_amount, get_claimed_amount, get_total_claimed_amount},
+        events::create_event,
+    },
+    types::{
+        bounty::{Bounty, BountyStatus},
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{Coin, Decimal, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128};
+use shared::{coin::subtract, cw20::into_transfer_msg};
+
+pub fn claim_vested_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    let assignee = get_assignee(deps.storage, bounty.id, &info.sender)?
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let disbursement_schedule =
+        bounty
+            .disbursement_schedule
+            .clone()
+            .ok_or(ContractError::CustomError {
+                val: format!(
+                    "bounty with id {} does not have a linear disbursement schedule",
+                    bounty.id
+                ),
+            })?;
+
+    assert_bounty_has_no_open_dispute(deps.storage, bounty.id)?;
+
+    let total_escrow =
+        bounty.escrowed_amount.amount + get_total_claimed_amount(deps.storage, bounty.id)?;
+
+    let total_weight = get_assignees(deps.storage, bounty.id)?
+        .iter()
+        .fold(Decimal::zero(), |acc, assignee| acc + assignee.weight);
+
+    let assignee_share = Decimal::from_ratio(assignee.weight.atomics(), total_weight.atomics());
+
+    let vested_amount =
+        total_escrow * assignee_share * disbursement_schedule.vested_ratio(env.block.time);
+
+    let claimable_amount = vested_amount.saturating_sub(get_claimed_amount(
+        deps.storage,
+        bounty.id,
+        &assignee.address,
+    )?);
+
+    if claimable_amount.is_zero() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "no vested escrow is available to claim from bounty with id {}",
+                bounty.id
+            ),
+        });
+    }
+
+    let amount_claimed = Coin::new(
+        claimable_amount.into(),
+        bounty.escrowed_amount.denom.clone(),
+    );
+
+    add_claimed_amount(deps.storage, bounty.id, &assignee.address, claimable_amount)?;
+
+    let escrowed_amount = subtract(&bounty.escrowed_amount, &amount_claimed)?;
+
+    let updated_bounty = update_bounty(
+        deps.storage,
+        Bounty {
+            status: if bounty.is_inactive()
+                && bounty.balance.amount.is_zero()
+                && escrowed_amount.amount.is_zero()
+            {
+                BountyStatus::Completed
+            } else {
+                bounty.status.clone()
+            },
+            escrowed_amount,
+            ..bounty.clone()
+        },
+    )?;
+
+    if updated_bounty.is_completed() {
+        clear_terminal_bounty_state(deps.storage, &updated_bounty)?;
+    }
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyEscrowClaimed {
+                assignee: assignee.address.clone(),
+                amount_claimed: amount_claimed.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("claim_vested", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("assignee", assignee.address.clone())
+        .add_attribute("amount_claimed", amount_claimed.to_string())
+        .add_submessage(SubMsg::new(into_transfer_msg(
+            deps.api,
+            assignee.get_payout_address().as_ref(),
+            amount_claimed,
+        )?)))
+}
+
+#[cfg(test)]
+mod claim_vested_tests {
+    use super::*;
+    use crate::constants::TEN;
+    use crate::state::assignees::save_assignee;
+    use crate::state::claims::get_claimed_amount;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UUSK};
+    use crate::types::assignee::Assignee;
+    use crate::types::disbursement_schedule::DisbursementSchedule;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, BankMsg, Decimal};
+
+    const ASSIGNEE: &str = "assignee";
+
+    fn setup_vesting_bounty(mut deps: DepsMut, env: Env) -> Bounty {
+        let bounty = setup_bounty(
+            deps.branch(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                disbursement_schedule: Some(DisbursementSchedule::Linear {
+                    start: env.block.time.minus_seconds(50),
+                    end: env.block.time.plus_seconds(50),
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        save_assignee(
+            deps.storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked(ASSIGNEE),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        bounty
+    }
+
+    #[test]
+    fn with_sender_that_is_not_assignee_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_vesting_bounty(deps.as_mut(), env.clone());
+
+        let err = claim_vested_handler(deps.as_mut(), env, mock_info("stranger", &[]), bounty.id)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn without_linear_disbursement_schedule_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_vesting_bounty(deps.as_mut(), env.clone());
+
+        update_bounty(
+            deps.as_mut().storage,
+            Bounty {
+                disbursement_schedule: None,
+                ..bounty.clone()
+            },
+        )
+        .unwrap();
+
+        let err = claim_vested_handler(deps.as_mut(), env, mock_info(ASSIGNEE, &[]), bounty.id)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: bounty with id {} does not have a linear disbursement schedule",
+                bounty.id
+            )
+        );
+    }
+
+    #[test]
+    fn sends_vested_portion_of_escrow_to_assignee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_vesting_bounty(deps.as_mut(), env.clone());
+
+        let response =
+            claim_vested_handler(deps.as_mut(), env, mock_info(ASSIGNEE, &[]), bounty.id).unwrap();
+
+        let vested_amount = TEN * Decimal::percent(50);
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: ASSIGNEE.to_string(),
+                amount: vec![Coin::new(vested_amount.into(), DENOM_UUSK)],
+            })]
+        );
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.escrowed_amount.amount, TEN - vested_amount);
+        assert_eq!(
+            get_claimed_amount(deps.as_ref().storage, bounty.id, &Addr::unchecked(ASSIGNEE))
+                .unwrap(),
+            vested_amount
+        );
+    }
+
+    #[test]
+    fn splits_vested_escrow_by_normalised_assignee_weight() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_vesting_bounty(deps.as_mut(), env.clone());
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("other-assignee"),
+                weight: Decimal::percent(300),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        claim_vested_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ASSIGNEE, &[]),
+            bounty.id,
+        )
+        .unwrap();
+
+        claim_vested_handler(
+            deps.as_mut(),
+            env,
+            mock_info("other-assignee", &[]),
+            bounty.id,
+        )
+        .unwrap();
+
+        let vested_amount = TEN * Decimal::percent(50);
+
+        assert_eq!(
+            get_claimed_amount(deps.as_ref().storage, bounty.id, &Addr::unchecked(ASSIGNEE))
+                .unwrap(),
+            vested_amount * Decimal::percent(25)
+        );
+        assert_eq!(
+            get_claimed_amount(
+                deps.as_ref().storage,
+                bounty.id,
+                &Addr::unchecked("other-assignee")
+            )
+            .unwrap(),
+            vested_amount * Decimal::percent(75)
+        );
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.escrowed_amount.amount, TEN - vested_amount);
+    }
+
+    #[test]
+    fn with_nothing_newly_vested_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_vesting_bounty(deps.as_mut(), env.clone());
+
+        claim_vested_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ASSIGNEE, &[]),
+            bounty.id,
+        )
+        .unwrap();
+
+        let err = claim_vested_handler(deps.as_mut(), env, mock_info(ASSIGNEE, &[]), bounty.id)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: no vested escrow is available to claim from bounty with id {}",
+                bounty.id
+            )
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_has_no_open_dispute, assert_bounty_is_not_cancelled,
+        asset_sender_is_bounty_owner,
+    },
+    state::{
+        assignees::get_assignees,
+        bounties::{get_bounty, update_bounty},
+        checklists::{get_checklist, save_checklist},
+        claims::{add_claimed_amount, get_total_claimed_amount},
+        events::create_event,
+    },
+    types::{
+        bounty::Bounty,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128};
+use shared::{coin::subtract, cw20::into_transfer_msg};
+
+pub fn complete_checklist_item_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    item_index: u32,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_bounty_has_no_open_dispute(deps.storage, bounty.id)?;
+
+    let mut checklist = get_checklist(deps.storage, bounty.id)?;
+
+    let item = checklist
+        .get_mut(item_index as usize)
+        .ok_or(ContractError::CustomError {
+            val: format!(
+                "bounty with id {} has no checklist item at index {}",
+                bounty.id, item_index
+            ),
+        })?;
+
+    if item.is_completed() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "checklist item at index {} of bounty with id {} is already completed",
+                item_index, bounty.id
+            ),
+        });
+    }
+
+    let assignees = get_assignees(deps.storage, bounty.id)?;
+
+    if assignees.is_empty() {
+        return Err(ContractError::CustomError {
+            val: format!("bounty with id {} has no assignees", bounty.id),
+        });
+    }
+
+    item.completed_at = Some(env.block.time);
+
+    let total_escrow =
+        bounty.escrowed_amount.amount + get_total_claimed_amount(deps.storage, bounty.id)?;
+
+    let item_amount = total_escrow * item.weight;
+
+    let mut sub_msgs = Vec::<SubMsg>::new();
+    let mut amount_disbursed = Uint128::zero();
+
+    for assignee in assignees {
+        let share = item_amount * assignee.weight;
+
+        if share.is_zero() {
+            continue;
+        }
+
+        add_claimed_amount(deps.storage, bounty.id, &assignee.address, share)?;
+
+        sub_msgs.push(SubMsg::new(into_transfer_msg(
+            deps.api,
+            assignee.get_payout_address().as_ref(),
+            Coin::new(share.into(), bounty.escrowed_amount.denom.clone()),
+        )?));
+
+        amount_disbursed += share;
+    }
+
+    let amount_disbursed = Coin::new(
+        amount_disbursed.into(),
+        bounty.escrowed_amount.denom.clone(),
+    );
+
+    save_checklist(deps.storage, bounty.id, checklist)?;
+
+    update_bounty(
+        deps.storage,
+        Bounty {
+            escrowed_amount: subtract(&bounty.escrowed_amount, &amount_disbursed)?,
+            ..bounty.clone()
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyChecklistItemCompleted {
+                item_index,
+                amount_disbursed: amount_disbursed.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("complete_checklist_item", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("item_index", item_index.to_string())
+        .add_attribute("amount_disbursed", amount_disbursed.to_string())
+        .add_submessages(sub_msgs))
+}
+
+#[cfg(test)]
+mod complete_checklist_item_tests {
+    use super::*;
+    use crate::constants::TEN;
+    use crate::state::assignees::save_assignee;
+    use crate::state::claims::get_claimed_amount;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UUSK, USER};
+    use crate::types::assignee::Assignee;
+    use crate::types::checklist::ChecklistItem;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, BankMsg, Decimal};
+
+    const ASSIGNEE: &str = "assignee";
+
+    fn setup_checklist_bounty(mut deps: DepsMut, env: Env) -> Bounty {
+        let bounty = setup_bounty(
+            deps.branch(),
+            env,
+            Bounty {
+                escrowed_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        save_assignee(
+            deps.storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked(ASSIGNEE),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        save_checklist(
+            deps.storage,
+            bounty.id,
+            vec![
+                ChecklistItem {
+                    description: "design".to_string(),
+                    weight: Decimal::percent(40),
+                    completed_at: None,
+                },
+                ChecklistItem {
+                    description: "build".to_string(),
+                    weight: Decimal::percent(60),
+                    completed_at: None,
+                },
+            ],
+        )
+        .unwrap();
+
+        bounty
+    }
+
+    #[test]
+    fn with_sender_that_is_not_the_owner_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_checklist_bounty(deps.as_mut(), env.clone());
+
+        let err = complete_checklist_item_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ASSIGNEE, &[]),
+            bounty.id,
+            0,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn disburses_item_share_of_escrow_to_assignee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_checklist_bounty(deps.as_mut(), env.clone());
+
+        let response = complete_checklist_item_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            bounty.id,
+            0,
+        )
+        .unwrap();
+
+        let expected_amount = TEN * Decimal::percent(40);
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: ASSIGNEE.to_string(),
+                amount: vec![Coin::new(expected_amount.into(), DENOM_UUSK)],
+            })]
+        );
+        assert_eq!(
+            get_claimed_amount(deps.as_ref().storage, bounty.id, &Addr::unchecked(ASSIGNEE))
+                .unwrap(),
+            expected_amount
+        );
+        assert_eq!(
+            get_bounty(deps.as_ref().storage, bounty.id)
+                .unwrap()
+                .escrowed_amount
+                .amount,
+            TEN - expected_amount
+        );
+        assert_eq!(
+            get_checklist(deps.as_ref().storage, bounty.id).unwrap()[0].completed_at,
+            Some(env.block.time)
+        );
+    }
+
+    #[test]
+    fn with_completed_item_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_checklist_bounty(deps.as_mut(), env.clone());
+
+        complete_checklist_item_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            bounty.id,
+            1,
+        )
+        .unwrap();
+
+        let err =
+            complete_checklist_item_handler(deps.as_mut(), env, mock_info(USER, &[]), bounty.id, 1)
+                .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: checklist item at index 1 of bounty with id {} is already completed",
+                bounty.id
+            )
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_is_not_cancelled, assert_contract_is_not_paused,
+        assert_deposited_denom_matches_send_denom, assert_exactly_one_asset,
+    },
+    state::{
+        bounties::{get_bounty, update_bounty},
+        events::create_event,
+        funding_rounds::{add_round_contribution, get_funding_round},
+    },
+    types::{
+        bounty::Bounty,
+        denom::Denom,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use shared::coin::add;
+
+pub fn contribute_to_round_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    round_id: u64,
+    bounty_id: Uint128,
+) -> Result<Response, ContractError> {
+    assert_contract_is_not_paused(deps.storage)?;
+    assert_exactly_one_asset(info.funds.clone())?;
+
+    let funding_round = get_funding_round(deps.storage, round_id)?;
+
+    if funding_round.is_finalized() || funding_round.has_ended(env.block.time) {
+        return Err(ContractError::CustomError {
+            val: format!("funding round {} is closed", round_id),
+        });
+    }
+
+    if !funding_round.bounty_ids.contains(&bounty_id) {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "bounty with id {} is not part of funding round {}",
+                bounty_id, round_id
+            ),
+        });
+    }
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_deposited_denom_matches_send_denom(
+        &Denom::new(info.funds[0].denom.clone())?,
+        &Denom::new(bounty.get_swap_denom())?,
+    )?;
+
+    let contribution = info.funds[0].clone();
+
+    add_round_contribution(
+        deps.storage,
+        round_id,
+        bounty.id,
+        info.sender.clone(),
+        contribution.amount,
+    )?;
+
+    update_bounty(
+        deps.storage,
+        Bounty {
+            balance: add(bounty.balance.clone(), contribution.clone())?,
+            deposited_amount: add(bounty.deposited_amount.clone(), contribution.clone())?,
+            ..bounty.clone()
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyFundsDeposited {
+                amount: contribution.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("contribute_to_round", "true")
+        .add_attribute("round_id", round_id.to_string())
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("contributor", info.sender)
+        .add_attribute("amount", contribution.to_string()))
+}
+
+#[cfg(test)]
+mod contribute_to_round_tests {
+    use super::*;
+    use crate::handlers::create_funding_round::create_funding_round_handler;
+    use crate::state::funding_rounds::get_round_contributions;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI, USER};
+    use crate::types::funding_round::{MatchingFormula, RoundContribution};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Coin};
+
+    #[test]
+    fn after_round_has_ended_fails() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        create_funding_round_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[Coin::new(100, DENOM_UKUJI)]),
+            vec![bounty.id],
+            MatchingFormula::Quadratic,
+            env.block.time.plus_seconds(100),
+        )
+        .unwrap();
+
+        env.block.time = env.block.time.plus_seconds(100);
+
+        let err = contribute_to_round_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[Coin::new(10, DENOM_UKUJI)]),
+            1,
+            bounty.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: funding round 1 is closed");
+    }
+
+    #[test]
+    fn records_contribution_and_funds_bounty() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        create_funding_round_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[Coin::new(100, DENOM_UKUJI)]),
+            vec![bounty.id],
+            MatchingFormula::Quadratic,
+            env.block.time.plus_seconds(100),
+        )
+        .unwrap();
+
+        contribute_to_round_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[Coin::new(10, DENOM_UKUJI)]),
+            1,
+            bounty.id,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_round_contributions(deps.as_ref().storage, 1, bounty.id).unwrap(),
+            vec![RoundContribution {
+                contributor: Addr::unchecked(USER),
+                amount: Uint128::new(10),
+            }]
+        );
+        assert_eq!(
+            get_bounty(deps.as_ref().storage, bounty.id)
+                .unwrap()
+                .balance,
+            add(bounty.balance, Coin::new(10, DENOM_UKUJI)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    error::ContractError, handlers::create_bounty::create_bounty_handler,
+    state::templates::get_bounty_template, types::template::BountyTemplateOverrides,
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn create_bounty_from_template_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    template_id: u64,
+    overrides: BountyTemplateOverrides,
+) -> Result<Response, ContractError> {
+    let template = get_bounty_template(deps.storage, template_id)?;
+
+    if template.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let params = template.params;
+
+    Ok(create_bounty_handler(
+        deps,
+        env,
+        &info,
+        info.sender.clone(),
+        Some(overrides.label.unwrap_or(template.label)),
+        overrides.bounty_description.or(params.bounty_description),
+        overrides.destinations.unwrap_or(params.destinations),
+        params.target_denom,
+        params.route,
+        overrides.slippage_tolerance.or(params.slippage_tolerance),
+        overrides
+            .exact_receive_amount
+            .or(params.exact_receive_amount),
+        overrides.expires_at,
+        params.disbursement_schedule,
+        params.private_metadata,
+        overrides.referral_code,
+        params.executor_slippage_override_cap,
+        params.fallback_routes,
+        params.tags,
+        params.metadata_uri,
+        params.minimum_price,
+        params.maximum_price,
+        None,
+        None,
+        None,
+        None,
+    )?
+    .add_attribute("template_id", template.id.to_string()))
+}
+
+#[cfg(test)]
+mod create_bounty_from_template_tests {
+    use super::*;
+    use crate::state::templates::save_bounty_template;
+    use crate::tests::mocks::{DENOM_UKUJI, USER};
+    use crate::types::denom::Denom;
+    use crate::types::template::BountyTemplateParams;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Coin};
+
+    #[test]
+    fn with_unknown_template_fails() {
+        let mut deps = mock_dependencies();
+
+        assert!(create_bounty_from_template_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]),
+            1,
+            BountyTemplateOverrides::default(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        let template = save_bounty_template(
+            deps.as_mut().storage,
+            Addr::unchecked(USER),
+            "template".to_string(),
+            BountyTemplateParams {
+                bounty_description: None,
+                destinations: vec![],
+                target_denom: Denom::new("uusk").unwrap(),
+                route: None,
+                slippage_tolerance: None,
+                exact_receive_amount: None,
+                disbursement_schedule: None,
+                private_metadata: false,
+                executor_slippage_override_cap: None,
+                fallback_routes: vec![],
+                tags: vec![],
+                metadata_uri: None,
+                minimum_price: None,
+                maximum_price: None,
+            },
+        )
+        .unwrap();
+
+        let err = create_bounty_from_template_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not-owner", &[Coin::new(10000, DENOM_UKUJI)]),
+            template.id,
+            BountyTemplateOverrides::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+}
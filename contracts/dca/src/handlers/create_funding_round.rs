@@ -0,0 +1,164 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_is_not_cancelled, assert_contract_is_not_paused,
+        assert_deposited_denom_matches_send_denom, assert_exactly_one_asset,
+        assert_expiry_is_in_future, assert_funding_round_bounty_ids_are_valid,
+    },
+    state::{bounties::get_bounty, funding_rounds::save_funding_round},
+    types::{
+        denom::Denom,
+        funding_round::{FundingRound, MatchingFormula},
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Timestamp, Uint128};
+
+pub fn create_funding_round_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_ids: Vec<Uint128>,
+    formula: MatchingFormula,
+    ends_at: Timestamp,
+) -> Result<Response, ContractError> {
+    assert_contract_is_not_paused(deps.storage)?;
+    assert_exactly_one_asset(info.funds.clone())?;
+    assert_funding_round_bounty_ids_are_valid(&bounty_ids)?;
+    assert_expiry_is_in_future(env.block.time, ends_at)?;
+
+    let matching_pool = info.funds[0].clone();
+
+    for bounty_id in &bounty_ids {
+        let bounty = get_bounty(deps.storage, *bounty_id)?;
+
+        assert_bounty_is_not_cancelled(&bounty)?;
+        assert_deposited_denom_matches_send_denom(
+            &Denom::new(matching_pool.denom.clone())?,
+            &Denom::new(bounty.get_swap_denom())?,
+        )?;
+    }
+
+    let funding_round = save_funding_round(
+        deps.storage,
+        FundingRound {
+            id: 0,
+            sponsor: info.sender,
+            matching_pool,
+            formula,
+            bounty_ids,
+            created_at: env.block.time,
+            ends_at,
+            finalized_at: None,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("create_funding_round", "true")
+        .add_attribute("round_id", funding_round.id.to_string())
+        .add_attribute("sponsor", funding_round.sponsor)
+        .add_attribute("matching_pool", funding_round.matching_pool.to_string()))
+}
+
+#[cfg(test)]
+mod create_funding_round_tests {
+    use super::*;
+    use crate::state::funding_rounds::get_funding_round;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Coin};
+
+    const SPONSOR: &str = "sponsor";
+
+    #[test]
+    fn with_duplicate_bounty_ids_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = create_funding_round_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(SPONSOR, &[Coin::new(100, DENOM_UKUJI)]),
+            vec![bounty.id, bounty.id],
+            MatchingFormula::Quadratic,
+            env.block.time.plus_seconds(100),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: bounty with id {} is included more than once",
+                bounty.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_matching_pool_denom_not_matching_bounty_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = create_funding_round_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(SPONSOR, &[Coin::new(100, DENOM_UUSK)]),
+            vec![bounty.id],
+            MatchingFormula::Quadratic,
+            env.block.time.plus_seconds(100),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: received asset with denom {}, but needed {}",
+                DENOM_UUSK,
+                bounty.get_swap_denom()
+            )
+        );
+    }
+
+    #[test]
+    fn saves_funding_round() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        create_funding_round_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(SPONSOR, &[Coin::new(100, DENOM_UKUJI)]),
+            vec![bounty.id],
+            MatchingFormula::Quadratic,
+            env.block.time.plus_seconds(100),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_funding_round(deps.as_ref().storage, 1).unwrap(),
+            FundingRound {
+                id: 1,
+                sponsor: Addr::unchecked(SPONSOR),
+                matching_pool: Coin::new(100, DENOM_UKUJI),
+                formula: MatchingFormula::Quadratic,
+                bounty_ids: vec![bounty.id],
+                created_at: env.block.time,
+                ends_at: env.block.time.plus_seconds(100),
+                finalized_at: None,
+            }
+        );
+    }
+}
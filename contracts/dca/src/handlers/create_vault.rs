@@ -1,25 +1,44 @@
 use crate::constants::{AFTER_LIMIT_ORDER_PLACED_REPLY_ID, TWO_MICRONS};
 use crate::error::ContractError;
-use crate::helpers::message::get_attribute_in_event;
+use crate::helpers::address::normalize_address;
+use crate::helpers::message::find_attribute_in_wasm_events;
+use crate::helpers::price::get_required_swap_amount;
 use crate::helpers::validation::{
-    assert_address_is_valid, assert_contract_destination_callbacks_are_valid,
-    assert_contract_is_not_paused, assert_destination_allocations_add_up_to_one,
-    assert_destination_callback_addresses_are_valid, assert_destinations_limit_is_not_breached,
-    assert_exactly_one_asset, assert_label_is_no_longer_than_100_characters,
-    assert_no_destination_allocations_are_zero, assert_route_exists_for_denoms,
-    assert_slippage_tolerance_is_less_than_or_equal_to_one,
+    assert_address_is_valid, assert_bounty_balance_is_within_cap,
+    assert_compound_destinations_are_valid,
+    assert_contract_destination_callbacks_are_valid,
+    assert_contract_is_not_paused, assert_create_is_not_paused, assert_denom_is_allowed,
+    assert_deposit_covers_exact_receive_amount,
+    assert_deposit_meets_minimum_deposit,
+    assert_description_is_no_longer_than_1000_characters,
+    assert_destination_allocations_add_up_to_one,
+    assert_destination_callback_addresses_are_valid, assert_destination_gas_limits_are_valid,
+    assert_destinations_limit_is_not_breached,
+    assert_disbursement_schedule_is_valid, assert_exactly_one_asset, assert_expiry_is_in_future,
+    assert_ibc_destinations_are_valid,
+    assert_label_is_no_longer_than_100_characters, assert_metadata_hash_has_metadata_uri,
+    assert_metadata_uri_is_valid,
+    assert_no_more_than_5_fallback_routes, assert_no_more_than_one_start_trigger,
+    assert_no_destination_allocations_are_zero,
+    assert_owner_balance_is_within_cap, assert_price_bounds_are_valid,
+    assert_route_exists_for_denoms, assert_slippage_tolerance_is_less_than_or_equal_to_one,
     assert_swap_adjustment_and_performance_assessment_strategies_are_compatible,
-    assert_swap_adjustment_strategy_params_are_valid, assert_target_start_time_is_not_in_the_past,
-    assert_time_interval_is_valid, assert_weighted_scale_multiplier_is_no_more_than_10,
+    assert_swap_adjustment_bounds_are_valid,
+    assert_swap_adjustment_strategy_params_are_valid,
+    assert_swap_amount_meets_minimum_swap_amount, assert_tags_are_valid,
+    assert_target_height_is_in_future, assert_target_start_time_is_not_in_the_past, assert_time_interval_is_valid, assert_weighted_scale_multiplier_is_no_more_than_10,
 };
 use crate::helpers::vault::get_risk_weighted_average_model_id;
 use crate::msg::ExecuteMsg;
-use crate::state::cache::BOUNTY_ID_CACHE;
+use crate::state::cache::{BOUNTY_ID_CACHE, LIMIT_ORDER_TARGET_PRICE_CACHE};
 use crate::state::config::get_config;
 use crate::state::events::create_event;
-use crate::state::triggers::save_trigger;
-use crate::state::vaults::{save_bounty, update_bounty};
+use crate::state::referrals::{add_referred_bounty, get_referrer};
+use crate::state::triggers::{delete_trigger, save_trigger};
+use crate::state::vaults::{get_bounty, save_bounty, update_bounty};
+use crate::types::denom::Denom;
 use crate::types::destination::Destination;
+use crate::types::disbursement_schedule::DisbursementSchedule;
 use crate::types::event::{EventBuilder, EventData};
 use crate::types::performance_assessment_strategy::{
     PerformanceAssessmentStrategy, PerformanceAssessmentStrategyParams,
@@ -28,11 +47,15 @@ use crate::types::swap_adjustment_strategy::{
     SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
 use crate::types::time_interval::TimeInterval;
-use crate::types::trigger::{Trigger, TriggerConfiguration};
+use crate::types::trigger::{BalanceThreshold, Trigger, TriggerConfiguration};
 use crate::types::bounty::{Bounty, BountyBuilder, BountyStatus};
 use cosmwasm_std::{to_json_binary, Addr, Binary, Coin, Decimal, Reply, SubMsg, WasmMsg};
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Timestamp, Uint128, Uint64};
-use exchange::msg::ExecuteMsg as ExchangeExecuteMsg;
+use cosmwasm_std::{
+    DepsMut, Env, HexBinary, MessageInfo, Response, Timestamp, Uint128, Uint64,
+};
+use exchange::msg::{ExecuteMsg as ExchangeExecuteMsg, Order, QueryMsg as ExchangeQueryMsg};
+use shared::coin::empty_of;
+use shared::cw20::into_transfer_msg;
 
 pub fn create_bounty_handler(
     // Edit
@@ -44,27 +67,65 @@ pub fn create_bounty_handler(
     bounty_description: Option<String>,
     status: Option<BountyStatus>, 
     mut destinations: Vec<Destination>,
-    target_denom: String,
+    target_denom: Denom,
     route: Option<Binary>,
     slippage_tolerance: Option<Decimal>,
+    exact_receive_amount: Option<Uint128>,
+    expires_at: Option<Timestamp>,
+    disbursement_schedule: Option<DisbursementSchedule>,
+    private_metadata: bool,
+    referral_code: Option<String>,
+    executor_slippage_override_cap: Option<Decimal>,
+    fallback_routes: Vec<Binary>,
+    tags: Vec<String>,
+    metadata_uri: Option<String>,
+    minimum_price: Option<Decimal>,
+    maximum_price: Option<Decimal>,
+    escrow_available_after: Option<Timestamp>,
+    metadata_hash: Option<[u8; 32]>,
+    min_adjustment: Option<Decimal>,
+    max_adjustment: Option<Decimal>,
+    lock_period: Option<u64>,
+    balance_threshold: Option<BalanceThreshold>,
+    target_block_height: Option<u64>,
    // minimum_receive_amount: Option<Uint128>,
     time_interval: TimeInterval,
     target_start_time_utc_seconds: Option<Uint64>,
    // target_receive_amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
+    let owner = normalize_address(&owner);
+
     assert_contract_is_not_paused(deps.storage)?;
+    assert_create_is_not_paused(deps.storage)?;
     assert_address_is_valid(deps.as_ref(), &owner, "owner")?;
     assert_exactly_one_asset(info.funds.clone())?;
+    assert_denom_is_allowed(deps.storage, &info.funds[0].denom)?;
+    assert_denom_is_allowed(deps.storage, &target_denom.to_string())?;
+    assert_deposit_meets_minimum_deposit(deps.storage, &info.funds[0])?;
+    assert_bounty_balance_is_within_cap(deps.storage, &info.funds[0])?;
+    assert_owner_balance_is_within_cap(deps.storage, &owner, &info.funds[0])?;
+    assert_swap_amount_meets_minimum_swap_amount(deps.storage, &info.funds[0])?;
     assert_destinations_limit_is_not_breached(&destinations)?;
     assert_time_interval_is_valid(&time_interval)?;
 
     assert_route_exists_for_denoms(
         deps.as_ref(),
         info.funds[0].denom.clone(),
-        target_denom.clone(),
+        target_denom.to_string(),
         route.clone(),
     )?;
 
+    assert_no_more_than_5_fallback_routes(&fallback_routes)?;
+
+    for fallback_route in &fallback_routes {
+        assert_route_exists_for_denoms(
+            deps.as_ref(),
+            info.funds[0].denom.clone(),
+            target_denom.to_string(),
+            Some(fallback_route.clone()),
+        )?;
+    }
+
    // assert_swap_adjustment_and_performance_assessment_strategies_are_compatible(
     //    &swap_adjustment_strategy_params,
      //   &performance_assessment_strategy_params,
@@ -74,14 +135,57 @@ pub fn create_bounty_handler(
         assert_label_is_no_longer_than_100_characters(&label)?;
     }
 
+    if let Some(bounty_description) = &bounty_description {
+        assert_description_is_no_longer_than_1000_characters(bounty_description)?;
+    }
+
+    assert_tags_are_valid(&tags)?;
+
+    if let Some(balance_threshold) = &balance_threshold {
+        assert_address_is_valid(
+            deps.as_ref(),
+            &balance_threshold.account,
+            "balance threshold account",
+        )?;
+    }
+
+    if let Some(metadata_uri) = &metadata_uri {
+        assert_metadata_uri_is_valid(metadata_uri)?;
+    }
+
+    assert_metadata_hash_has_metadata_uri(&metadata_uri, &metadata_hash)?;
+
   //  if let Some(swap_adjustment_strategy_params) = &swap_adjustment_strategy_params {
       //  assert_swap_adjustment_strategy_params_are_valid(swap_adjustment_strategy_params)?;
    // }
 
+    assert_price_bounds_are_valid(minimum_price, maximum_price)?;
+    assert_swap_adjustment_bounds_are_valid(min_adjustment, max_adjustment)?;
+
     if let Some(slippage_tolerance) = slippage_tolerance {
         assert_slippage_tolerance_is_less_than_or_equal_to_one(slippage_tolerance)?;
     }
 
+    if let Some(executor_slippage_override_cap) = executor_slippage_override_cap {
+        assert_slippage_tolerance_is_less_than_or_equal_to_one(executor_slippage_override_cap)?;
+    }
+
+    if let Some(expires_at) = expires_at {
+        assert_expiry_is_in_future(env.block.time, expires_at)?;
+    }
+
+    if let Some(disbursement_schedule) = &disbursement_schedule {
+        assert_disbursement_schedule_is_valid(disbursement_schedule)?;
+    }
+
+    let referrer = referral_code
+        .map(|code| {
+            get_referrer(deps.storage, &code)?.ok_or(ContractError::CustomError {
+                val: format!("referral code {} is not registered", code),
+            })
+        })
+        .transpose()?;
+
     if let Some(target_time) = target_start_time_utc_seconds {
         assert_target_start_time_is_not_in_the_past(
             env.block.time,
@@ -89,21 +193,67 @@ pub fn create_bounty_handler(
         )?;
     }
 
+    if let Some(target_height) = target_block_height {
+        assert_target_height_is_in_future(env.block.height, target_height)?;
+    }
+
+    assert_no_more_than_one_start_trigger(
+        target_start_time_utc_seconds,
+        target_receive_amount,
+        target_block_height,
+        &balance_threshold,
+    )?;
+
     if destinations.is_empty() {
         destinations.push(Destination {
             allocation: Decimal::percent(100),
             address: owner.clone(),
             msg: None,
+            condition: None,
+            ibc: None,
+            gas_limit: None,
         });
     }
 
     assert_destination_callback_addresses_are_valid(deps.as_ref(), &destinations)?;
+    assert_destination_gas_limits_are_valid(&destinations)?;
     assert_contract_destination_callbacks_are_valid(&destinations, &env.contract.address)?;
+    assert_compound_destinations_are_valid(
+        deps.storage,
+        &destinations,
+        &env.contract.address,
+        &owner,
+        &target_denom,
+    )?;
+    assert_ibc_destinations_are_valid(deps.as_ref(), &destinations, &target_denom)?;
     assert_no_destination_allocations_are_zero(&destinations)?;
     assert_destination_allocations_add_up_to_one(&destinations)?;
 
     let config = get_config(deps.storage)?;
 
+    if let Some(exact_receive_amount) = exact_receive_amount {
+        let exact_receive_amount = Coin::new(exact_receive_amount.into(), target_denom.clone());
+
+        let required_deposit_amount = if info.funds[0].denom == exact_receive_amount.denom {
+            exact_receive_amount.amount
+        } else {
+            get_required_swap_amount(
+                &deps.querier,
+                config.exchange_contract_address.clone(),
+                info.funds[0].clone(),
+                exact_receive_amount.clone(),
+                slippage_tolerance.unwrap_or(config.default_slippage_tolerance),
+                route.clone(),
+            )?
+        };
+
+        assert_deposit_covers_exact_receive_amount(
+            &info.funds[0],
+            required_deposit_amount,
+            &exact_receive_amount,
+        )?;
+    }
+
    // let swap_denom = info.funds[0].denom.clone();
 
   //  let swap_adjustment_strategy = match swap_adjustment_strategy_params {
@@ -159,7 +309,6 @@ pub fn create_bounty_handler(
         destinations,
         created_at: env.block.time,
         status: BountyStatus::Scheduled,
-        bounty_description, 
         target_denom: target_denom.clone(),
         route,
         slippage_tolerance: slippage_tolerance.unwrap_or(config.default_slippage_tolerance),
@@ -171,10 +320,33 @@ pub fn create_bounty_handler(
         deposited_amount: info.funds[0].clone(),
         received_amount: Coin::new(0, target_denom.clone()),
         escrowed_amount: Coin::new(0, target_denom),
+        exact_receive_amount,
+        expires_at,
+        disbursement_schedule,
+        cancel_reason: None,
+        cancel_note: None,
+        private_metadata,
+        referrer,
+        executor_slippage_override_cap,
+        fallback_routes,
+        description: bounty_description,
+        tags,
+        metadata_uri,
+        minimum_price,
+        maximum_price,
+        escrow_available_after,
+        metadata_hash,
+        min_adjustment,
+        max_adjustment,
+        lock_period,
     };
 
     let bounty = save_bounty(deps.storage, bounty_builder)?;
 
+    if let Some(referrer) = &bounty.referrer {
+        add_referred_bounty(deps.storage, referrer)?;
+    }
+
     BOUNTY_ID_CACHE.save(deps.storage, &bounty.id)?;
 
     create_event(
@@ -203,6 +375,59 @@ pub fn create_bounty_handler(
         .add_attribute("owner", bounty.owner.clone())
         .add_attribute("deposited_amount", bounty.balance.to_string());
 
+    if let Some(exact_receive_amount) = bounty.exact_receive_amount {
+        response = response.add_attribute("exact_receive_amount", exact_receive_amount);
+    }
+
+    if let Some(metadata_uri) = &bounty.metadata_uri {
+        response = response.add_attribute("metadata_uri", metadata_uri);
+    }
+
+    if let Some(metadata_hash) = bounty.metadata_hash {
+        response =
+            response.add_attribute("metadata_hash", HexBinary::from(metadata_hash).to_hex());
+    }
+
+    if let Some(locked_until) = bounty.locked_until() {
+        response = response.add_attribute("locked_until", locked_until.to_string());
+    }
+
+    if let Some(BalanceThreshold {
+        account,
+        denom,
+        threshold,
+    }) = balance_threshold
+    {
+        save_trigger(
+            deps.storage,
+            Trigger {
+                bounty_id: bounty.id,
+                configuration: TriggerConfiguration::BalanceAbove {
+                    account,
+                    denom,
+                    threshold,
+                    fired: false,
+                },
+                retry_count: 0,
+            },
+        )?;
+
+        return Ok(response);
+    }
+
+    if let Some(target_height) = target_block_height {
+        save_trigger(
+            deps.storage,
+            Trigger {
+                bounty_id: bounty.id,
+                configuration: TriggerConfiguration::BlockHeight { target_height },
+                retry_count: 0,
+            },
+        )?;
+
+        return Ok(response.add_attribute("target_block_height", target_height.to_string()));
+    }
+
 
 // Change target_start_time_utc_seconds to something else
     
@@ -218,6 +443,7 @@ pub fn create_bounty_handler(
                             None => env.block.time,
                         },
                     },
+                    retry_count: 0,
                 },
             )?;
 
@@ -227,6 +453,8 @@ pub fn create_bounty_handler(
                     msg: to_json_binary(&ExecuteMsg::ExecuteTrigger {
                         trigger_id: bounty.id,
                         route: bounty.route,
+                        slippage_override: None,
+                        on_behalf_of: None,
                     })
                     .unwrap(),
                     funds: vec![],
@@ -253,12 +481,14 @@ pub fn create_bounty_handler(
 
             let target_price = Decimal::from_ratio(swap_amount, target_receive_amount);
 
-            Ok(response.add_submessage(SubMsg::reply_on_success(
+            LIMIT_ORDER_TARGET_PRICE_CACHE.save(deps.storage, &target_price)?;
+
+            Ok(response.add_submessage(SubMsg::reply_always(
                 WasmMsg::Execute {
                     contract_addr: config.exchange_contract_address.to_string(),
                     msg: to_json_binary(&ExchangeExecuteMsg::SubmitOrder {
                         target_price: target_price.into(),
-                        target_denom: bounty.target_denom.clone(),
+                        target_denom: bounty.target_denom.to_string(),
                     })
                     .unwrap(),
                     funds: vec![Coin::new(TWO_MICRONS.into(), bounty.get_swap_denom())],
@@ -274,34 +504,133 @@ pub fn create_bounty_handler(
     }
 }
 
-// pub fn save_price_trigger(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
-   //  let submit_order_response = reply.result.into_result().unwrap();
+pub fn save_price_trigger(
+    deps: DepsMut,
+    env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let bounty_id = BOUNTY_ID_CACHE.load(deps.storage)?;
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    let submit_order_response = match reply.result.into_result() {
+        Ok(response) => response,
+        Err(error) => {
+            let balance = Coin::new(
+                (bounty.balance.amount + TWO_MICRONS).into(),
+                bounty.balance.denom.clone(),
+            );
+
+            return refund_unconfirmed_limit_order(
+                deps,
+                env,
+                Bounty { balance, ..bounty },
+                format!("order submission failed: {}", error),
+            );
+        }
+    };
+
+    let order_idx =
+        find_attribute_in_wasm_events(&submit_order_response.events, &["order_idx", "order_id"])
+            .and_then(|order_idx| order_idx.parse::<Uint128>().ok());
+
+    let order_idx = match order_idx {
+        Some(order_idx) => order_idx,
+        None => {
+            return refund_unconfirmed_limit_order(
+                deps,
+                env,
+                bounty,
+                "unable to find order idx in submit order events".to_string(),
+            )
+        }
+    };
+
+    let config = get_config(deps.storage)?;
+
+    if deps
+        .querier
+        .query_wasm_smart::<Order>(
+            config.exchange_contract_address,
+            &ExchangeQueryMsg::GetOrder {
+                order_idx,
+                denoms: bounty.denoms(),
+            },
+        )
+        .is_err()
+    {
+        return refund_unconfirmed_limit_order(
+            deps,
+            env,
+            bounty,
+            format!("unable to confirm order {} with exchange", order_idx),
+        );
+    }
 
- //   let order_idx = get_attribute_in_event(&submit_order_response.events, "wasm", "order_idx")?
-   //     .parse::<Uint128>()
-     //   .expect("the order id of the submitted order");
+    let target_price =
+        find_attribute_in_wasm_events(&submit_order_response.events, &["target_price"])
+            .and_then(|target_price| target_price.parse::<Decimal>().ok())
+            .map_or_else(|| LIMIT_ORDER_TARGET_PRICE_CACHE.load(deps.storage), Ok)?;
 
-   // let target_price =
-     //   get_attribute_in_event(&submit_order_response.events, "wasm", "target_price")?
-       //     .parse::<Decimal>()
-         //   .expect("the target price of the submitted order");
+    save_trigger(
+        deps.storage,
+        Trigger {
+            bounty_id,
+            configuration: TriggerConfiguration::Price {
+                order_idx,
+                target_price,
+            },
+            retry_count: 0,
+        },
+    )?;
 
-    //let bounty_id = BOUNTY_ID_CACHE.load(deps.storage)?;
+    Ok(Response::new()
+        .add_attribute("save_price_trigger", "true")
+        .add_attribute("order_idx", order_idx))
+}
 
-    //save_trigger(
-      //  deps.storage,
-        //Trigger {
-          //  bounty_id,
-            //configuration: TriggerConfiguration::Price {
-             //   order_idx,
-               // target_price,
-           // },
-       // },
-   // )?;
+fn refund_unconfirmed_limit_order(
+    deps: DepsMut,
+    env: Env,
+    bounty: Bounty,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let mut response = Response::new()
+        .add_attribute("save_price_trigger", "false")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("reason", reason.clone());
+
+    if bounty.balance.amount > Uint128::zero() {
+        response = response.add_message(into_transfer_msg(
+            deps.api,
+            bounty.owner.as_ref(),
+            bounty.balance.clone(),
+        )?);
+    }
+
+    update_bounty(
+        deps.storage,
+        Bounty {
+            status: BountyStatus::Scheduled,
+            balance: empty_of(bounty.balance.clone()),
+            ..bounty.clone()
+        },
+    )?;
+
+    delete_trigger(deps.storage, bounty.id)?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyLimitOrderFailed {
+                amount_refunded: bounty.balance,
+                reason,
+            },
+        ),
+    )?;
 
-   // Ok(Response::new()
-     //   .add_attribute("save_price_trigger", "true")
-     //   .add_attribute("order_idx", order_idx))
+    Ok(response)
 }
 
 #[cfg(test)]
@@ -309,15 +638,16 @@ mod create_bounty_tests {
     use super::*;
     use crate::constants::{ONE, TEN};
     use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
-    use crate::handlers::get_vault::get_vault_handler;
+    use crate::handlers::get_bounty::get_bounty_handler;
     use crate::msg::ExecuteMsg;
     use crate::state::config::{get_config, update_config};
-    use crate::tests::helpers::instantiate_contract;
+    use crate::state::referrals::{get_referred_bounties, save_referrer};
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
     use crate::tests::mocks::{
         calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK, USER, VALIDATOR,
     };
     use crate::types::config::Config;
-    use crate::types::destination::Destination;
+    use crate::types::destination::{Destination, IbcDestination};
     use crate::types::event::{EventBuilder, EventData};
     use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
     use crate::types::time_interval::TimeInterval;
@@ -345,7 +675,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -355,6 +685,8 @@ mod create_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -365,13 +697,10 @@ mod create_bounty_tests {
     }
 
     #[test]
-    fn with_multiple_assets_fails() {
+    fn with_deposit_too_small_for_exact_receive_amount_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(
-            USER,
-            &[Coin::new(10000, DENOM_UKUJI), Coin::new(10000, DENOM_UUSK)],
-        );
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
@@ -381,205 +710,910 @@ mod create_bounty_tests {
             &info,
             info.sender.clone(),
             None,
+            None,
+            None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UUSK).unwrap(),
             None,
             None,
+            Some(Uint128::new(9500)),
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
             None,
-            Uint128::new(10000),
-            TimeInterval::Daily,
             None,
             None,
             None,
+            TimeInterval::Daily,
             None,
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: received 2 denoms but required exactly 1"
+            "Error: deposit of 10000ukuji cannot guarantee a receive amount of 9500uusk, at least 10204ukuji is required"
         );
     }
 
     #[test]
-    fn with_non_existent_pair_fails() {
+    fn with_exact_receive_amount_saves_exact_receive_amount() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
-        deps.querier.update_wasm(|_| {
-            SystemResult::Ok(ContractResult::Ok(
-                to_json_binary::<Vec<Pair>>(&vec![]).unwrap(),
-            ))
-        });
-
-        let err = create_bounty_handler(
+        create_bounty_handler(
             deps.as_mut(),
             env,
             &info,
             info.sender.clone(),
             None,
+            None,
+            None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UUSK).unwrap(),
             None,
             None,
+            Some(Uint128::new(9000)),
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
             None,
-            Uint128::new(100000),
-            TimeInterval::Daily,
             None,
             None,
             None,
             None,
+            TimeInterval::Daily,
+            None,
         )
-        .unwrap_err();
+        .unwrap();
 
-        assert_eq!(
-            err.to_string(),
-            format!(
-                "Error: swapping {} to {} not supported",
-                DENOM_UUSK, DENOM_UKUJI
-            )
-        );
+        let bounty = get_bounty_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .bounty;
+
+        assert_eq!(bounty.exact_receive_amount, Some(Uint128::new(9000)));
     }
 
     #[test]
-    fn with_destination_allocations_less_than_100_percent_fails() {
+    fn with_block_height_and_start_time_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let admin_info = mock_info(ADMIN, &[]);
-
-        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
 
-        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
         let err = create_bounty_handler(
             deps.as_mut(),
-            env,
-            &user_info,
-            user_info.sender.clone(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
             None,
-            vec![Destination {
-                allocation: Decimal::percent(50),
-                address: Addr::unchecked(USER),
-                msg: None,
-            }],
-            DENOM_UKUJI.to_string(),
             None,
             None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
             None,
-            Uint128::new(100000),
-            TimeInterval::Daily,
             None,
             None,
             None,
             None,
-        )
-        .unwrap_err();
-
-        assert_eq!(
-            err.to_string(),
-            "Error: destination allocations must add up to 1"
-        );
-    }
-
-    #[test]
-    fn with_destination_allocation_equal_to_zero_fails() {
-        let mut deps = calc_mock_dependencies();
-        let env = mock_env();
-        let admin_info = mock_info(ADMIN, &[]);
-
-        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
-
-        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
-
-        let err = create_bounty_handler(
-            deps.as_mut(),
-            env,
-            &user_info,
-            user_info.sender.clone(),
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
             None,
-            vec![
-                Destination {
-                    allocation: Decimal::percent(100),
-                    address: Addr::unchecked(USER),
-                    msg: None,
-                },
-                Destination {
-                    allocation: Decimal::percent(0),
-                    address: Addr::unchecked("other"),
-                    msg: None,
-                },
-            ],
-            DENOM_UKUJI.to_string(),
             None,
             None,
             None,
-            Uint128::new(100000),
-            TimeInterval::Daily,
             None,
             None,
             None,
             None,
+            Some(env.block.height + 10),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: all destination allocations must be greater than 0"
+            "Error: only one of target_start_time_utc_seconds, target_receive_amount, target_block_height and balance_threshold can be provided"
         );
     }
 
     #[test]
-    fn with_more_than_10_destination_allocations_fails() {
+    fn with_balance_threshold_and_start_time_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
         let err = create_bounty_handler(
             deps.as_mut(),
-            env,
+            env.clone(),
             &info,
             info.sender.clone(),
             None,
-            (0..20)
-                .map(|i| Destination {
-                    allocation: Decimal::percent(5),
-                    address: Addr::unchecked(format!("destination-{}", i)),
-                    msg: None,
-                })
-                .collect(),
-            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
             None,
             None,
             None,
-            Uint128::new(100000),
-            TimeInterval::Daily,
             None,
             None,
             None,
             None,
+            None,
+            Some(BalanceThreshold {
+                account: Addr::unchecked(USER),
+                denom: DENOM_UKUJI.to_string(),
+                threshold: Uint128::new(100),
+            }),
+            None,
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: no more than 10 destinations can be provided"
+            "Error: only one of target_start_time_utc_seconds, target_receive_amount, target_block_height and balance_threshold can be provided"
         );
     }
 
     #[test]
-    fn with_too_high_weighted_scale_multiplier_fails() {
+    fn with_block_height_in_the_past_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let admin_info = mock_info(ADMIN, &[]);
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
-
-        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            None,
+            None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(env.block.height),
+            TimeInterval::Daily,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: target_block_height must be some block in the future"
+        );
+    }
+
+    #[test]
+    fn with_expiry_in_the_past_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            None,
+            None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            Some(env.block.time.minus_seconds(10)),
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            TimeInterval::Daily,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: expires_at must be some time in the future"
+        );
+    }
+
+    #[test]
+    fn with_expiry_saves_expiry() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        create_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            None,
+            None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            Some(env.block.time.plus_seconds(10)),
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            TimeInterval::Daily,
+            None,
+        )
+        .unwrap();
+
+        let bounty = get_bounty_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .bounty;
+
+        assert_eq!(bounty.expires_at, Some(env.block.time.plus_seconds(10)));
+    }
+
+    #[test]
+    fn with_disbursement_schedule_ending_before_it_starts_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            None,
+            None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            Some(DisbursementSchedule::Linear {
+                start: env.block.time.plus_seconds(10),
+                end: env.block.time,
+            }),
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            TimeInterval::Daily,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: disbursement schedule must end after it starts"
+        );
+    }
+
+    #[test]
+    fn with_disbursement_schedule_saves_disbursement_schedule() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let disbursement_schedule = DisbursementSchedule::Linear {
+            start: env.block.time,
+            end: env.block.time.plus_seconds(100),
+        };
+
+        create_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            None,
+            None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            Some(disbursement_schedule.clone()),
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            TimeInterval::Daily,
+            None,
+        )
+        .unwrap();
+
+        let bounty = get_bounty_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .bounty;
+
+        assert_eq!(bounty.disbursement_schedule, Some(disbursement_schedule));
+    }
+
+    #[test]
+    fn with_invalid_ibc_channel_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            None,
+            None,
+            vec![Destination {
+                ibc: Some(IbcDestination {
+                    channel_id: "transfer".to_string(),
+                    remote_address: "osmo1remote".to_string(),
+                    timeout_seconds: None,
+                }),
+                ..Destination::default()
+            }],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            TimeInterval::Daily,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: ibc channel id transfer is invalid");
+    }
+
+    #[test]
+    fn with_unregistered_referral_code_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            None,
+            None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            Some("unknown".to_string()),
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            TimeInterval::Daily,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: referral code unknown is not registered"
+        );
+    }
+
+    #[test]
+    fn with_referral_code_saves_referrer() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let referrer = Addr::unchecked("referrer");
+
+        save_referrer(deps.as_mut().storage, "prismo", &referrer).unwrap();
+
+        create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            None,
+            None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            Some("prismo".to_string()),
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            TimeInterval::Daily,
+            None,
+        )
+        .unwrap();
+
+        let bounty = get_bounty_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .bounty;
+
+        assert_eq!(bounty.referrer, Some(referrer.clone()));
+        assert_eq!(
+            get_referred_bounties(deps.as_ref().storage, &referrer).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn with_multiple_assets_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(
+            USER,
+            &[Coin::new(10000, DENOM_UKUJI), Coin::new(10000, DENOM_UUSK)],
+        );
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            Denom::new(DENOM_UKUJI).unwrap(),
+            None,
+            None,
+            None,
+            Uint128::new(10000),
+            TimeInterval::Daily,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: received 2 denoms but required exactly 1"
+        );
+    }
+
+    #[test]
+    fn with_non_existent_pair_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        deps.querier.update_wasm(|_| {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary::<Vec<Pair>>(&vec![]).unwrap(),
+            ))
+        });
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            Denom::new(DENOM_UKUJI).unwrap(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: swapping {} to {} not supported",
+                DENOM_UUSK, DENOM_UKUJI
+            )
+        );
+    }
+
+    #[test]
+    fn with_destination_allocations_less_than_100_percent_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![Destination {
+                allocation: Decimal::percent(50),
+                address: Addr::unchecked(USER),
+                msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
+            }],
+            Denom::new(DENOM_UKUJI).unwrap(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: destination allocations must add up to 1"
+        );
+    }
+
+    #[test]
+    fn with_destination_allocation_equal_to_zero_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![
+                Destination {
+                    allocation: Decimal::percent(100),
+                    address: Addr::unchecked(USER),
+                    msg: None,
+                    condition: None,
+                    ibc: None,
+                    gas_limit: None,
+                },
+                Destination {
+                    allocation: Decimal::percent(0),
+                    address: Addr::unchecked("other"),
+                    msg: None,
+                    condition: None,
+                    ibc: None,
+                    gas_limit: None,
+                },
+            ],
+            Denom::new(DENOM_UKUJI).unwrap(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: all destination allocations must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_compound_destination_into_bounty_of_another_owner_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let other_bounty = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                owner: Addr::unchecked("other"),
+                balance: Coin::new(0, DENOM_UKUJI),
+                ..Bounty::default()
+            },
+        );
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![Destination {
+                allocation: Decimal::percent(100),
+                address: env.contract.address.clone(),
+                msg: Some(
+                    to_json_binary(&ExecuteMsg::Deposit {
+                        address: user_info.sender.clone(),
+                        bounty_id: other_bounty.id,
+                    })
+                    .unwrap(),
+                ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
+            }],
+            Denom::new(DENOM_UKUJI).unwrap(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: bounty {} is not owned by {}", other_bounty.id, USER)
+        );
+    }
+
+    #[test]
+    fn with_more_than_10_destination_allocations_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            (0..20)
+                .map(|i| Destination {
+                    allocation: Decimal::percent(5),
+                    address: Addr::unchecked(format!("destination-{}", i)),
+                    msg: None,
+                    condition: None,
+                    ibc: None,
+                    gas_limit: None,
+                })
+                .collect(),
+            Denom::new(DENOM_UKUJI).unwrap(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: no more than 10 destinations can be provided"
+        );
+    }
+
+    #[test]
+    fn with_too_high_weighted_scale_multiplier_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
 
         let err = create_bounty_handler(
             deps.as_mut(),
@@ -588,7 +1622,7 @@ mod create_bounty_tests {
             user_info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -596,12 +1630,24 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             None,
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
             None,
             Some(SwapAdjustmentStrategyParams::WeightedScale {
                 base_receive_amount: Uint128::new(100000),
                 multiplier: Decimal::percent(1100),
                 increase_only: false,
+                max_swap_amount: None,
+                min_swap_amount: None,
             }),
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -637,7 +1683,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -645,6 +1691,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             None,
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -653,6 +1709,116 @@ mod create_bounty_tests {
         assert_eq!(err.to_string(), "Error: contract is paused")
     }
 
+    #[test]
+    fn with_deposit_below_minimum_deposit_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                minimum_deposit: vec![Coin::new(20000, DENOM_UUSK)],
+                ..config
+            },
+        )
+        .unwrap();
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            Denom::new(DENOM_UKUJI).unwrap(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: deposit of 10000uusk is less than the minimum deposit of 20000uusk"
+        )
+    }
+
+    #[test]
+    fn with_deposit_below_minimum_swap_amount_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                minimum_swap_amount: Uint128::new(20000),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let err = create_bounty_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            Denom::new(DENOM_UKUJI).unwrap(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: swap amount of 10000uusk is less than the minimum swap amount of 20000uusk"
+        )
+    }
+
     #[test]
     fn with_time_trigger_with_target_time_in_the_past_fails() {
         let mut deps = calc_mock_dependencies();
@@ -670,7 +1836,7 @@ mod create_bounty_tests {
             user_info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -678,6 +1844,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.minus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -704,7 +1880,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -712,6 +1888,16 @@ mod create_bounty_tests {
             TimeInterval::Custom { seconds: 23 },
             None,
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -740,7 +1926,7 @@ mod create_bounty_tests {
             info.sender,
             None,
             vec![],
-            pair.denoms[1].clone(),
+            Denom::new(pair.denoms[1].clone()).unwrap(),
             None,
             None,
             None,
@@ -748,6 +1934,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.seconds().into()),
             Some(Uint128::new(872316)),
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -777,16 +1973,26 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
-            None,
+            Denom::new(DENOM_UKUJI).unwrap(),
+            None,
+            None,
+            None,
+            swap_amount,
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
             None,
             None,
-            swap_amount,
-            TimeInterval::Daily,
-            Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -814,7 +2020,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -822,8 +2028,18 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
             None,
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -851,7 +2067,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -859,12 +2075,24 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
             None,
             Some(SwapAdjustmentStrategyParams::WeightedScale {
                 base_receive_amount: Uint128::new(232231),
                 multiplier: Decimal::percent(1001),
                 increase_only: false,
+                max_swap_amount: None,
+                min_swap_amount: None,
             }),
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -892,7 +2120,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             Some(Decimal::percent(150)),
             None,
@@ -900,6 +2128,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             None,
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -929,7 +2167,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -937,6 +2175,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -963,7 +2211,7 @@ mod create_bounty_tests {
                 slippage_tolerance: config.default_slippage_tolerance,
                 route: None,
                 swap_amount,
-                target_denom: DENOM_UKUJI.to_string(),
+                target_denom: Denom::new(DENOM_UKUJI).unwrap(),
                 started_at: None,
                 deposited_amount: info.funds[0].clone(),
                 escrow_level: Decimal::zero(),
@@ -997,7 +2245,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1005,6 +2253,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             None,
             Some(ONE / TWO_MICRONS),
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -1033,7 +2291,7 @@ mod create_bounty_tests {
                 ),
                 slippage_tolerance: config.default_slippage_tolerance,
                 swap_amount,
-                target_denom: DENOM_UKUJI.to_string(),
+                target_denom: Denom::new(DENOM_UKUJI).unwrap(),
                 route: None,
                 started_at: None,
                 deposited_amount: Coin::new(
@@ -1068,7 +2326,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1076,6 +2334,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -1116,7 +2384,7 @@ mod create_bounty_tests {
             owner,
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1124,6 +2392,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -1157,6 +2435,9 @@ mod create_bounty_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 allocation: Decimal::percent(50),
@@ -1168,6 +2449,9 @@ mod create_bounty_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -1178,7 +2462,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             destinations.clone(),
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1186,6 +2470,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -1215,7 +2509,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1223,8 +2517,18 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
         )
         .unwrap();
 
@@ -1255,7 +2559,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1263,12 +2567,24 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
             None,
             Some(SwapAdjustmentStrategyParams::WeightedScale {
                 base_receive_amount: Uint128::new(100000),
                 multiplier: Decimal::percent(200),
                 increase_only: false,
+                max_swap_amount: None,
+                min_swap_amount: None,
             }),
+            None,
+            None,
         )
         .unwrap();
 
@@ -1296,7 +2612,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1304,8 +2620,18 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
         )
         .unwrap();
 
@@ -1339,7 +2665,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1347,8 +2673,18 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
         )
         .unwrap();
 
@@ -1384,7 +2720,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1392,8 +2728,18 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             None,
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
         )
         .unwrap();
 
@@ -1405,6 +2751,8 @@ mod create_bounty_tests {
                 msg: to_json_binary(&ExecuteMsg::ExecuteTrigger {
                     trigger_id: Uint128::one(),
                     route: None,
+                    slippage_override: None,
+                    on_behalf_of: None,
                 })
                 .unwrap()
             })
@@ -1431,7 +2779,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            pair.denoms[0].to_string(),
+            Denom::new(pair.denoms[0].to_string()).unwrap(),
             None,
             None,
             None,
@@ -1439,6 +2787,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             None,
             Some(ONE / TWO_MICRONS),
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -1448,7 +2806,7 @@ mod create_bounty_tests {
 
         assert_eq!(
             response.messages.first().unwrap(),
-            &SubMsg::reply_on_success(
+            &SubMsg::reply_always(
                 WasmMsg::Execute {
                     contract_addr: config.exchange_contract_address.to_string(),
                     funds: vec![Coin::new(TWO_MICRONS.into(), info.funds[0].denom.clone())],
@@ -1480,7 +2838,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1488,8 +2846,18 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
         )
         .unwrap();
 
@@ -1522,7 +2890,7 @@ mod create_bounty_tests {
             info.sender.clone(),
             None,
             vec![],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1530,6 +2898,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -1567,8 +2945,11 @@ mod create_bounty_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             }],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1576,6 +2957,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -1613,8 +3004,11 @@ mod create_bounty_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             }],
-            DENOM_UKUJI.to_string(),
+            Denom::new(DENOM_UKUJI).unwrap(),
             None,
             None,
             None,
@@ -1622,6 +3016,16 @@ mod create_bounty_tests {
             TimeInterval::Daily,
             Some(env.block.time.plus_seconds(10).seconds().into()),
             None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         )
@@ -1643,6 +3047,9 @@ mod create_bounty_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             }]
         );
     }
@@ -1652,22 +3059,37 @@ mod create_bounty_tests {
 mod save_limit_order_id_tests {
     use super::save_price_trigger;
     use crate::{
-        state::{cache::BOUNTY_ID_CACHE, triggers::get_trigger},
-        types::trigger::{Trigger, TriggerConfiguration},
+        constants::TWO_MICRONS,
+        state::{
+            cache::{BOUNTY_ID_CACHE, LIMIT_ORDER_TARGET_PRICE_CACHE},
+            triggers::get_trigger,
+            vaults::get_bounty,
+        },
+        tests::{
+            helpers::{instantiate_contract, setup_bounty},
+            mocks::{calc_mock_dependencies, ADMIN, USER},
+        },
+        types::{
+            trigger::{Trigger, TriggerConfiguration},
+            vault::{Bounty, BountyStatus},
+        },
     };
     use cosmwasm_std::{
-        testing::mock_dependencies, Decimal, Event, Reply, SubMsgResponse, SubMsgResult, Uint128,
+        testing::{mock_dependencies, mock_env, mock_info},
+        BankMsg, Coin, Decimal, Event, Reply, SubMsg, SubMsgResponse, SubMsgResult, Uint128,
     };
 
     #[test]
     fn should_save_limit_order_id() {
-        let mut deps = mock_dependencies();
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
 
-        let bounty_id = Uint128::one();
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
         let order_idx = Uint128::new(67);
 
         BOUNTY_ID_CACHE
-            .save(deps.as_mut().storage, &bounty_id)
+            .save(deps.as_mut().storage, &bounty.id)
             .unwrap();
 
         let reply = Reply {
@@ -1680,19 +3102,159 @@ mod save_limit_order_id_tests {
             }),
         };
 
-        save_price_trigger(deps.as_mut(), reply).unwrap();
+        save_price_trigger(deps.as_mut(), mock_env(), reply).unwrap();
 
-        let trigger = get_trigger(deps.as_ref().storage, bounty_id).unwrap();
+        let trigger = get_trigger(deps.as_ref().storage, bounty.id).unwrap();
 
         assert_eq!(
             trigger,
             Some(Trigger {
-                bounty_id,
+                bounty_id: bounty.id,
                 configuration: TriggerConfiguration::Price {
                     target_price: Decimal::percent(200),
                     order_idx,
                 },
+                retry_count: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_cached_target_price() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+        let order_idx = Uint128::new(67);
+
+        BOUNTY_ID_CACHE
+            .save(deps.as_mut().storage, &bounty.id)
+            .unwrap();
+
+        LIMIT_ORDER_TARGET_PRICE_CACHE
+            .save(deps.as_mut().storage, &Decimal::percent(150))
+            .unwrap();
+
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![Event::new("wasm-submit_order")
+                    .add_attribute("order_id", order_idx.to_string())],
+                data: None,
+            }),
+        };
+
+        save_price_trigger(deps.as_mut(), mock_env(), reply).unwrap();
+
+        let trigger = get_trigger(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(
+            trigger,
+            Some(Trigger {
+                bounty_id: bounty.id,
+                configuration: TriggerConfiguration::Price {
+                    target_price: Decimal::percent(150),
+                    order_idx,
+                },
+                retry_count: 0,
             })
         );
     }
+
+    #[test]
+    fn with_missing_order_idx_refunds_and_schedules_bounty() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        BOUNTY_ID_CACHE
+            .save(deps.as_mut().storage, &bounty.id)
+            .unwrap();
+
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![Event::new("wasm").add_attribute("order_idx", "not a number")],
+                data: None,
+            }),
+        };
+
+        let response = save_price_trigger(deps.as_mut(), mock_env(), reply).unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: USER.to_string(),
+            amount: vec![bounty.balance.clone()],
+        })));
+        assert_eq!(updated_bounty.status, BountyStatus::Scheduled);
+        assert!(updated_bounty.balance.amount.is_zero());
+        assert_eq!(get_trigger(deps.as_ref().storage, bounty.id).unwrap(), None);
+    }
+
+    #[test]
+    fn with_unconfirmed_order_refunds_and_schedules_bounty() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        BOUNTY_ID_CACHE
+            .save(deps.as_mut().storage, &bounty.id)
+            .unwrap();
+
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![Event::new("wasm").add_attribute("order_idx", "67")],
+                data: None,
+            }),
+        };
+
+        let response = save_price_trigger(deps.as_mut(), mock_env(), reply).unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: USER.to_string(),
+            amount: vec![bounty.balance.clone()],
+        })));
+        assert_eq!(updated_bounty.status, BountyStatus::Scheduled);
+    }
+
+    #[test]
+    fn with_failed_order_submission_refunds_balance_and_order_funds() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        BOUNTY_ID_CACHE
+            .save(deps.as_mut().storage, &bounty.id)
+            .unwrap();
+
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Err("order book is closed".to_string()),
+        };
+
+        let response = save_price_trigger(deps.as_mut(), mock_env(), reply).unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: USER.to_string(),
+            amount: vec![Coin::new(
+                (bounty.balance.amount + TWO_MICRONS).into(),
+                bounty.balance.denom.clone()
+            )],
+        })));
+        assert_eq!(updated_bounty.status, BountyStatus::Scheduled);
+        assert!(updated_bounty.balance.amount.is_zero());
+    }
 }
@@ -0,0 +1,84 @@
+use crate::{
+    error::ContractError,
+    state::templates::{delete_bounty_template, get_bounty_template},
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn delete_bounty_template_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    template_id: u64,
+) -> Result<Response, ContractError> {
+    let template = get_bounty_template(deps.storage, template_id)?;
+
+    if template.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    delete_bounty_template(deps.storage, template.id);
+
+    Ok(Response::new()
+        .add_attribute("delete_bounty_template", "true")
+        .add_attribute("template_id", template.id.to_string()))
+}
+
+#[cfg(test)]
+mod delete_bounty_template_tests {
+    use super::*;
+    use crate::state::templates::save_bounty_template;
+    use crate::tests::mocks::USER;
+    use crate::types::denom::Denom;
+    use crate::types::template::BountyTemplateParams;
+    use cosmwasm_std::testing::{mock_dependencies, mock_info};
+    use cosmwasm_std::Addr;
+
+    fn save_template(deps: DepsMut) -> u64 {
+        save_bounty_template(
+            deps.storage,
+            Addr::unchecked(USER),
+            "template".to_string(),
+            BountyTemplateParams {
+                bounty_description: None,
+                destinations: vec![],
+                target_denom: Denom::new("uusk").unwrap(),
+                route: None,
+                slippage_tolerance: None,
+                exact_receive_amount: None,
+                disbursement_schedule: None,
+                private_metadata: false,
+                executor_slippage_override_cap: None,
+                fallback_routes: vec![],
+                tags: vec![],
+                metadata_uri: None,
+                minimum_price: None,
+                maximum_price: None,
+            },
+        )
+        .unwrap()
+        .id
+    }
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        let template_id = save_template(deps.as_mut());
+
+        let err =
+            delete_bounty_template_handler(deps.as_mut(), mock_info("not-owner", &[]), template_id)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn deletes_template() {
+        let mut deps = mock_dependencies();
+
+        let template_id = save_template(deps.as_mut());
+
+        delete_bounty_template_handler(deps.as_mut(), mock_info(USER, &[]), template_id).unwrap();
+
+        assert!(get_bounty_template(&deps.storage, template_id).is_err());
+    }
+}
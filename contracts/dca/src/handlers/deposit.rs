@@ -1,13 +1,22 @@
 use crate::error::ContractError;
+use crate::helpers::address::normalize_address;
 use crate::helpers::time::get_next_target_time;
 use crate::helpers::validation::{
-    assert_contract_is_not_paused, assert_deposited_denom_matches_send_denom,
-    assert_exactly_one_asset, assert_vault_is_not_cancelled,
+    assert_bounty_balance_is_within_cap, assert_bounty_is_not_completed,
+    assert_contract_is_not_paused, assert_denom_is_allowed, assert_deposit_is_not_paused,
+    assert_deposit_meets_minimum_deposit, assert_deposited_denom_matches_send_denom,
+    assert_exactly_one_asset, assert_owner_balance_is_within_cap,
+    assert_sender_is_admin_or_bounty_owner,
+    assert_vault_is_not_cancelled,
 };
-use crate::helpers::vault::get_risk_weighted_average_model_id;
+use crate::helpers::vault::{apply_denom_balance, get_risk_weighted_average_model_id};
+use crate::state::denom_balances::{delete_denom_balance, get_denom_balance, save_denom_balance};
 use crate::state::events::create_event;
 use crate::state::triggers::save_trigger;
 use crate::state::vaults::{get_bounty, update_bounty};
+use crate::types::authz::OperatorPermission;
+use crate::types::denom::Denom;
+use crate::types::denom_balance::DenomBalance;
 use crate::types::event::{EventBuilder, EventData};
 use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
 use crate::types::trigger::{Trigger, TriggerConfiguration};
@@ -24,9 +33,14 @@ pub fn deposit_handler(
     address: Addr,
     vault_id: Uint128,
 ) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
     assert_contract_is_not_paused(deps.storage)?;
+    assert_deposit_is_not_paused(deps.storage)?;
     deps.api.addr_validate(address.as_str())?;
     assert_exactly_one_asset(info.funds.clone())?;
+    assert_denom_is_allowed(deps.storage, &info.funds[0].denom)?;
+    assert_deposit_meets_minimum_deposit(deps.storage, &info.funds[0])?;
 
     let bounty = get_bounty(deps.storage, bounty_id)?;
 
@@ -39,15 +53,72 @@ pub fn deposit_handler(
         });
     }
 
+    if info.sender != env.contract.address {
+        assert_sender_is_admin_or_bounty_owner(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            OperatorPermission::TopUp,
+        )?;
+    }
     assert_bounty_is_not_cancelled(&bounty)?;
+    assert_bounty_is_not_completed(&bounty)?;
+    assert_owner_balance_is_within_cap(deps.storage, &bounty.owner, &info.funds[0])?;
+
+    let bounty = match get_denom_balance(deps.storage, bounty.id, &info.funds[0].denom)? {
+        Some(denom_balance) if bounty.balance.amount.is_zero() => {
+            delete_denom_balance(deps.storage, bounty.id, &info.funds[0].denom)?;
+            apply_denom_balance(bounty, denom_balance)
+        }
+        Some(denom_balance) => {
+            assert_bounty_balance_is_within_cap(
+                deps.storage,
+                &add(denom_balance.balance.clone(), info.funds[0].clone())?,
+            )?;
+
+            save_denom_balance(
+                deps.storage,
+                bounty.id,
+                &DenomBalance {
+                    balance: add(denom_balance.balance.clone(), info.funds[0].clone())?,
+                    deposited_amount: add(
+                        denom_balance.deposited_amount.clone(),
+                        info.funds[0].clone(),
+                    )?,
+                    ..denom_balance
+                },
+            )?;
+
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    bounty.id,
+                    env.block,
+                    EventData::BountyFundsDeposited {
+                        amount: info.funds[0].clone(),
+                    },
+                ),
+            )?;
+
+            return Ok(Response::new()
+                .add_attribute("deposit", "true")
+                .add_attribute("bounty_id", bounty.id)
+                .add_attribute("owner", bounty.owner)
+                .add_attribute("deposited_amount", info.funds[0].amount));
+        }
+        None => bounty,
+    };
+
     assert_deposited_denom_matches_send_denom(
-        info.funds[0].denom.clone(),
-        bounty.balance.denom.clone(),
+        &Denom::new(info.funds[0].denom.clone())?,
+        &Denom::new(bounty.get_swap_denom())?,
     )?;
 
     let bounty_was_inactive = bounty.is_inactive();
     let new_balance = add(bounty.balance.clone(), info.funds[0].clone())?;
 
+    assert_bounty_balance_is_within_cap(deps.storage, &new_balance)?;
+
     let bounty = update_bounty(
         deps.storage,
         Bounty {
@@ -104,6 +175,7 @@ pub fn deposit_handler(
                         bounty.time_interval,
                     ),
                 },
+                retry_count: 0,
             },
         )?;
     };
@@ -121,6 +193,8 @@ mod deposit_tests {
     use crate::constants::{ONE, ONE_HUNDRED, TEN};
     use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
     use crate::handlers::get_vault::get_vault_handler;
+    use crate::state::allowed_denoms::save_allowed_denom;
+    use crate::state::authz::save_operator_permissions;
     use crate::state::config::{get_config, update_config};
     use crate::tests::helpers::{instantiate_contract, setup_vault};
     use crate::tests::mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK, USER};
@@ -162,6 +236,176 @@ mod deposit_tests {
         assert_eq!(updated_bounty.balance, deposit_amount);
     }
 
+    #[test]
+    fn with_funding_denom_updates_the_denom_balance() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let deposit_amount = Coin::new(TEN.into(), "uatom");
+        let info = mock_info(ADMIN, &[deposit_amount.clone()]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(0, "uatom"),
+                deposited_amount: Coin::new(0, "uatom"),
+                swap_amount: ONE,
+                route: None,
+            },
+        )
+        .unwrap();
+
+        deposit_handler(deps.as_mut(), env, info, bounty.owner, bounty.id).unwrap();
+
+        let updated_bounty = get_bounty_handler(deps.as_ref(), bounty.id).unwrap().bounty;
+        let denom_balance = get_denom_balance(deps.as_ref().storage, bounty.id, "uatom")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(updated_bounty.balance, bounty.balance);
+        assert_eq!(denom_balance.balance, deposit_amount);
+        assert_eq!(denom_balance.deposited_amount, deposit_amount);
+    }
+
+    #[test]
+    fn with_funding_denom_and_empty_balance_promotes_denom_balance() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let deposit_amount = Coin::new(TEN.into(), "uatom");
+        let info = mock_info(ADMIN, &[deposit_amount.clone()]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Inactive,
+                balance: Coin::new(0, DENOM_UKUJI),
+                ..Bounty::default()
+            },
+        );
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(0, "uatom"),
+                deposited_amount: Coin::new(0, "uatom"),
+                swap_amount: ONE,
+                route: None,
+            },
+        )
+        .unwrap();
+
+        deposit_handler(deps.as_mut(), env, info, bounty.owner, bounty.id).unwrap();
+
+        let updated_bounty = get_bounty_handler(deps.as_ref(), bounty.id).unwrap().bounty;
+
+        assert_eq!(updated_bounty.balance, deposit_amount);
+        assert_eq!(updated_bounty.swap_amount, ONE);
+        assert_eq!(updated_bounty.status, BountyStatus::Active);
+        assert_eq!(
+            get_denom_balance(deps.as_ref().storage, bounty.id, "uatom").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn with_sender_that_is_not_owner_or_operator_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = deposit_handler(
+            deps.as_mut(),
+            env,
+            mock_info("stranger", &[Coin::new(TEN.into(), DENOM_UKUJI)]),
+            bounty.owner,
+            bounty.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_contract_as_sender_updates_the_bounty_balance() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let deposit_amount = Coin::new(TEN.into(), DENOM_UKUJI);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                balance: Coin::new(0, DENOM_UKUJI),
+                ..Bounty::default()
+            },
+        );
+
+        deposit_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(env.contract.address.as_str(), &[deposit_amount.clone()]),
+            bounty.owner,
+            bounty.id,
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty_handler(deps.as_ref(), bounty.id).unwrap().bounty;
+
+        assert_eq!(updated_bounty.balance, deposit_amount);
+    }
+
+    #[test]
+    fn with_top_up_operator_updates_the_bounty_balance() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let deposit_amount = Coin::new(TEN.into(), DENOM_UKUJI);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                balance: Coin::new(0, DENOM_UKUJI),
+                ..Bounty::default()
+            },
+        );
+
+        save_operator_permissions(
+            deps.as_mut().storage,
+            bounty.id,
+            &Addr::unchecked("operator"),
+            &vec![OperatorPermission::TopUp],
+        )
+        .unwrap();
+
+        deposit_handler(
+            deps.as_mut(),
+            env,
+            mock_info("operator", &[deposit_amount.clone()]),
+            bounty.owner,
+            bounty.id,
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty_handler(deps.as_ref(), bounty.id).unwrap().bounty;
+
+        assert_eq!(updated_bounty.balance, deposit_amount);
+    }
+
     #[test]
     fn publishes_deposit_event() {
         let mut deps = mock_dependencies();
@@ -326,6 +570,29 @@ mod deposit_tests {
         assert_eq!(err.to_string(), "Error: bounty is already cancelled");
     }
 
+    #[test]
+    fn for_completed_bounty_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let deposit_amount = Coin::new(TEN.into(), DENOM_UKUJI);
+        let info = mock_info(ADMIN, &[deposit_amount]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Completed,
+                ..Bounty::default()
+            },
+        );
+
+        let err = deposit_handler(deps.as_mut(), env, info, bounty.owner, bounty.id).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: bounty is already completed");
+    }
+
     #[test]
     fn with_incorrect_owner_address_should_fail() {
         let mut deps = mock_dependencies();
@@ -392,6 +659,32 @@ mod deposit_tests {
         );
     }
 
+    #[test]
+    fn with_denom_that_is_not_allowed_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_allowed_denom(deps.as_mut().storage, &Denom::new(DENOM_UUSK).unwrap()).unwrap();
+
+        let err = deposit_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[Coin::new(ONE.into(), DENOM_UKUJI)]),
+            bounty.owner.clone(),
+            bounty.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: denom {} is not an allowed denom", DENOM_UKUJI)
+        );
+    }
+
     #[test]
     fn with_multiple_assets_should_fail() {
         let mut deps = mock_dependencies();
@@ -438,6 +731,96 @@ mod deposit_tests {
         assert_eq!(err.to_string(), "Error: contract is paused");
     }
 
+    #[test]
+    fn with_deposit_below_minimum_deposit_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[Coin::new(ONE.into(), DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                minimum_deposit: vec![Coin::new(TEN.into(), DENOM_UKUJI)],
+                ..config
+            },
+        )
+        .unwrap();
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = deposit_handler(deps.as_mut(), env, info, bounty.owner, bounty.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: deposit of 1000000ukuji is less than the minimum deposit of 10000000ukuji"
+        );
+    }
+
+    #[test]
+    fn with_deposit_above_max_bounty_balance_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[Coin::new(ONE.into(), DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                max_bounty_balance: vec![Coin::new(ONE.into(), DENOM_UKUJI)],
+                ..config
+            },
+        )
+        .unwrap();
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = deposit_handler(deps.as_mut(), env, info, bounty.owner, bounty.id).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("would exceed the maximum bounty balance of 1000000ukuji"));
+    }
+
+    #[test]
+    fn with_deposit_above_max_total_balance_per_owner_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[Coin::new(ONE.into(), DENOM_UKUJI)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                max_total_balance_per_owner: vec![Coin::new(
+                    (bounty.balance.amount + ONE - Uint128::one()).into(),
+                    DENOM_UKUJI,
+                )],
+                ..config
+            },
+        )
+        .unwrap();
+
+        let err = deposit_handler(deps.as_mut(), env, info, bounty.owner, bounty.id).unwrap_err();
+
+        assert!(err.to_string().contains(&format!(
+            "total balance of {}ukuji for owner {} would exceed the maximum",
+            bounty.balance.amount + ONE,
+            bounty.owner
+        )));
+    }
+
     #[test]
     fn with_risk_weighted_average_strategy_should_update_model_id() {
         let mut deps = mock_dependencies();
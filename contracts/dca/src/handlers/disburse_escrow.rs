@@ -1,25 +1,39 @@
 use crate::{
     error::ContractError,
     helpers::{
-        disbursement::get_disbursement_messages,
-        fees::{get_fee_messages, get_performance_fee},
-        price::get_twap_to_now,
-        validation::assert_sender_is_executor,
+        cleanup::clear_terminal_bounty_state,
+        disbursement::{get_disbursement_messages, reconcile_exact_receive_amount},
+        fees::{get_fee_messages, take_performance_fee},
+        price::get_twap_to_now_with_fallback,
+        validation::{
+            assert_bounty_has_no_open_dispute, assert_disburse_is_not_paused,
+            assert_sender_has_role,
+        },
     },
     state::{
+        assignees::get_assignees,
         cache::BOUNTY_ID_CACHE,
+        completions::record_completion,
         config::get_config,
-        disburse_escrow_tasks::{delete_disburse_escrow_task, get_disburse_escrow_task_due_date},
+        disburse_escrow_tasks::{
+            delete_disburse_escrow_task, get_disburse_escrow_task_due_date,
+            save_disburse_escrow_task,
+        },
+        escrow_epochs::release_from_escrow_epochs,
+        escrow_tranches::{get_escrow_tranche_schedule, save_escrow_tranche_schedule},
         events::create_event,
         bounties::{get_bounty, update_bounty},
     },
     types::{
+        destination::DisbursementOutcome,
         event::{EventBuilder, EventData},
-        bounty::Bounty,
+        bounty::{Bounty, BountyStatus},
+        escrow_tranche::EscrowTrancheSchedule,
+        role::Role,
     },
 };
 use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, Uint128};
-use shared::coin::{empty_of, subtract};
+use shared::coin::subtract;
 
 pub fn disburse_escrow_handler(
     deps: DepsMut,
@@ -27,11 +41,15 @@ pub fn disburse_escrow_handler(
     info: MessageInfo,
     bounty_id: Uint128,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_executor(deps.storage, &env, &info.sender)?;
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Executor)?;
 
     let bounty = get_bounty(deps.storage, bounty_id)?;
 
-    let response = Response::new()
+    if !bounty.is_cancelled() {
+        assert_disburse_is_not_paused(deps.storage)?;
+    }
+
+    let mut response = Response::new()
         .add_attribute("disburse_escrow", "true")
         .add_attribute("bounty_id", bounty.id)
         .add_attribute("owner", bounty.owner.clone());
@@ -48,7 +66,19 @@ pub fn disburse_escrow_handler(
             ));
     }
 
-    let due_date = get_disburse_escrow_task_due_date(deps.storage, bounty.id)?;
+    assert_bounty_has_no_open_dispute(deps.storage, bounty.id)?;
+
+    if bounty.disbursement_schedule.is_some() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "bounty with id {} disburses escrow on a linear schedule and must be claimed",
+                bounty.id
+            ),
+        });
+    }
+
+    let due_date = get_disburse_escrow_task_due_date(deps.storage, bounty.id)?
+        .max(bounty.escrow_available_after);
 
     if let Some(due_date) = due_date {
         if env.block.time < due_date {
@@ -64,22 +94,95 @@ pub fn disburse_escrow_handler(
 
     let config = get_config(deps.storage)?;
 
-    let current_price = get_twap_to_now(
+    let (current_price, price_oracle_address) = get_twap_to_now_with_fallback(
         &deps.querier,
-        config.exchange_contract_address.clone(),
+        &config,
         bounty.get_swap_denom(),
-        bounty.target_denom.clone(),
-        config.twap_period,
+        bounty.target_denom.to_string(),
         bounty.route.clone(),
     )?;
 
-    let performance_fee = get_performance_fee(&bounty, current_price)?;
-    let amount_to_disburse = subtract(&bounty.escrowed_amount, &performance_fee)?;
+    if let Some(price_oracle_address) = price_oracle_address {
+        create_event(
+            deps.storage,
+            EventBuilder::new(
+                bounty.id,
+                env.block.clone(),
+                EventData::BountyPriceOracleUsed {
+                    oracle: price_oracle_address.clone(),
+                    price: current_price,
+                },
+            ),
+        )?;
+
+        response = response.add_attribute("price_oracle", price_oracle_address);
+    }
+
+    let tranche_schedule = if bounty.is_cancelled() {
+        None
+    } else {
+        get_escrow_tranche_schedule(deps.storage, bounty.id)?
+    };
+
+    let (escrow_to_release, next_tranche_due_date) = match tranche_schedule {
+        Some(schedule) => {
+            let schedule = schedule.start(
+                due_date.unwrap_or(env.block.time),
+                bounty.escrowed_amount.amount,
+            );
+            let due_tranches = schedule.due_tranches(env.block.time);
+
+            if due_tranches <= schedule.released_tranches {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "No escrow tranche is due to be disbursed until {:?}",
+                        schedule.next_due_date()
+                    ),
+                });
+            }
+
+            let release_amount =
+                schedule.release_amount(due_tranches, bounty.escrowed_amount.amount);
+
+            let schedule = EscrowTrancheSchedule {
+                released_tranches: due_tranches,
+                ..schedule
+            };
+
+            save_escrow_tranche_schedule(deps.storage, bounty.id, &schedule)?;
+
+            (
+                Coin::new(release_amount.into(), bounty.escrowed_amount.denom.clone()),
+                schedule.next_due_date(),
+            )
+        }
+        None => (bounty.escrowed_amount.clone(), None),
+    };
+
+    let performance_fee = take_performance_fee(
+        deps.storage,
+        &config,
+        &bounty,
+        &escrow_to_release,
+        current_price,
+    )?;
+    let amount_to_disburse = subtract(&escrow_to_release, &performance_fee)?;
+    let remaining_escrow = subtract(&bounty.escrowed_amount, &escrow_to_release)?;
+    let released_escrow_epochs =
+        release_from_escrow_epochs(deps.storage, bounty.id, escrow_to_release.amount)?;
 
     let bounty = update_bounty(
         deps.storage,
         Bounty {
-            escrowed_amount: empty_of(bounty.escrowed_amount),
+            escrowed_amount: remaining_escrow.clone(),
+            status: if remaining_escrow.amount.is_zero()
+                && bounty.is_inactive()
+                && bounty.balance.amount.is_zero()
+            {
+                BountyStatus::Completed
+            } else {
+                bounty.status
+            },
             ..bounty
         },
     )?;
@@ -96,23 +199,66 @@ pub fn disburse_escrow_handler(
         ),
     )?;
 
-    delete_disburse_escrow_task(deps.storage, bounty.id)?;
+    if let Some(next_tranche_due_date) = next_tranche_due_date {
+        save_disburse_escrow_task(deps.storage, bounty.id, next_tranche_due_date)?;
+    } else {
+        delete_disburse_escrow_task(deps.storage, bounty.id)?;
+    }
+
+    if !bounty.is_cancelled() && next_tranche_due_date.is_none() {
+        for assignee in get_assignees(deps.storage, bounty.id)? {
+            record_completion(deps.storage, bounty.id, &assignee.address, env.block.time)?;
+        }
+    }
 
     BOUNTY_ID_CACHE.save(deps.storage, &bounty.id)?;
 
+    let executor = if info.sender == env.contract.address {
+        None
+    } else {
+        Some(info.sender)
+    };
+
+    let disbursement_messages = get_disbursement_messages(
+        deps.api,
+        deps.storage,
+        env.block.time,
+        &bounty,
+        amount_to_disburse.amount,
+        &if bounty.is_cancelled() {
+            DisbursementOutcome::Skipped
+        } else {
+            DisbursementOutcome::Succeeded {
+                received: amount_to_disburse.amount,
+            }
+        },
+    )?;
+
+    if bounty.is_completed() {
+        reconcile_exact_receive_amount(deps.storage, &env.block, &bounty)?;
+    }
+
+    if bounty.is_completed() || bounty.is_cancelled() {
+        clear_terminal_bounty_state(deps.storage, &bounty)?;
+    }
+
+    if !released_escrow_epochs.is_empty() {
+        response = response.add_attribute(
+            "released_escrow_epochs",
+            format!("{:?}", released_escrow_epochs),
+        );
+    }
+
     Ok(response
-        .add_submessages(get_disbursement_messages(
-            deps.api,
-            deps.storage,
-            &bounty,
-            amount_to_disburse.amount,
-        )?)
+        .add_submessages(disbursement_messages)
         .add_submessages(get_fee_messages(
             deps.as_ref(),
             env,
             vec![performance_fee.amount],
-            bounty.target_denom.clone(),
+            bounty.target_denom.to_string(),
             true,
+            bounty.referrer.clone(),
+            executor,
         )?)
         .add_attribute("performance_fee", format!("{:?}", performance_fee))
         .add_attribute("escrow_disbursed", format!("{:?}", amount_to_disburse)))
@@ -124,9 +270,14 @@ mod disburse_escrow_tests {
     use crate::{
         constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, ONE, TEN, TEN_DECIMAL},
         handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
+        helpers::fees::get_executor_reward,
         state::{
+            assignees::save_assignee,
+            completions::get_completion_counts,
             config::get_config,
             disburse_escrow_tasks::{get_disburse_escrow_tasks, save_disburse_escrow_task},
+            disputes::save_dispute,
+            escrow_tranches::save_escrow_tranche_schedule,
             bounties::get_bounty,
         },
         tests::{
@@ -134,8 +285,13 @@ mod disburse_escrow_tests {
             mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK},
         },
         types::{
+            assignee::Assignee,
             destination::Destination,
+            disbursement_schedule::DisbursementSchedule,
+            dispute::Dispute,
+            escrow_tranche::EscrowTranche,
             event::{Event, EventData},
+            fee_collector::FeeCollector,
             performance_assessment_strategy::PerformanceAssessmentStrategy,
             swap_adjustment_strategy::SwapAdjustmentStrategy,
             bounty::{Bounty, BountyStatus},
@@ -143,7 +299,7 @@ mod disburse_escrow_tests {
     };
     use cosmwasm_std::{
         testing::{mock_env, mock_info},
-        BankMsg, Coin, Decimal, SubMsg, Uint128,
+        Addr, Attribute, BankMsg, Coin, Decimal, SubMsg, Uint128,
     };
     use shared::coin::add_to;
 
@@ -178,6 +334,106 @@ mod disburse_escrow_tests {
             .contains("Error: Escrow is not available to be disbursed"));
     }
 
+    #[test]
+    fn when_escrow_cliff_has_not_passed_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                escrow_available_after: Some(env.block.time.plus_seconds(100)),
+                ..Bounty::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            bounty.id,
+            env.block.time.minus_seconds(10),
+        )
+        .unwrap();
+
+        let err = disburse_escrow_handler(deps.as_mut(), env, info, bounty.id).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("Error: Escrow is not available to be disbursed"));
+    }
+
+    #[test]
+    fn when_bounty_has_open_dispute_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        save_dispute(
+            deps.as_mut().storage,
+            &Dispute {
+                bounty_id: bounty.id,
+                raised_by: bounty.owner.clone(),
+                reason: "work was not delivered".to_string(),
+                raised_at: env.block.time,
+            },
+        )
+        .unwrap();
+
+        let err = disburse_escrow_handler(deps.as_mut(), env, info, bounty.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: bounty with id {} has an open dispute", bounty.id)
+        );
+    }
+
+    #[test]
+    fn when_bounty_has_linear_disbursement_schedule_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                disbursement_schedule: Some(DisbursementSchedule::Linear {
+                    start: env.block.time,
+                    end: env.block.time.plus_seconds(100),
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        let err = disburse_escrow_handler(deps.as_mut(), env, info, bounty.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: bounty with id {} disburses escrow on a linear schedule and must be claimed",
+                bounty.id
+            )
+        );
+    }
+
     #[test]
     fn caches_bounty_id_for_after_automation_handler() {
         let mut deps = calc_mock_dependencies();
@@ -330,13 +586,29 @@ mod disburse_escrow_tests {
 
         let response = disburse_escrow_handler(deps.as_mut(), env, info, bounty.id).unwrap();
 
+        let executor_reward = get_executor_reward(&config, bounty.escrowed_amount.amount);
+        let FeeCollector::Address {
+            address: fee_collector,
+            ..
+        } = config.fee_collectors[0].clone()
+        else {
+            panic!("expected an address fee collector");
+        };
+
         assert_eq!(
             response.messages.first().unwrap(),
             &SubMsg::new(BankMsg::Send {
-                to_address: config.fee_collectors[0].address.to_string(),
-                amount: vec![bounty.escrowed_amount]
+                to_address: fee_collector,
+                amount: vec![Coin::new(
+                    (bounty.escrowed_amount.amount - executor_reward).into(),
+                    DENOM_UUSK
+                )]
             })
         );
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: ADMIN.to_string(),
+            amount: vec![Coin::new(executor_reward.into(), DENOM_UUSK)]
+        })));
     }
 
     #[test]
@@ -436,6 +708,42 @@ mod disburse_escrow_tests {
         );
     }
 
+    #[test]
+    fn with_empty_balance_sets_bounty_to_completed() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Inactive,
+                balance: Coin::new(0, DENOM_UKUJI),
+                swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                received_amount: Coin::new((TEN + ONE).into(), DENOM_UUSK),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(((TEN + ONE) * Decimal::percent(5)).into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Bounty::default()
+            },
+        );
+
+        disburse_escrow_handler(deps.as_mut(), env, info, bounty.id).unwrap();
+
+        let bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(bounty.status, BountyStatus::Completed);
+    }
+
     #[test]
     fn deletes_disburse_escrow_task() {
         let mut deps = calc_mock_dependencies();
@@ -482,4 +790,99 @@ mod disburse_escrow_tests {
         assert_eq!(disburse_escrow_tasks_before.len(), 1);
         assert_eq!(disburse_escrow_tasks_after.len(), 0);
     }
+
+    #[test]
+    fn with_escrow_tranches_releases_due_tranche_and_reschedules_task() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Inactive,
+                balance: Coin::new(0, DENOM_UKUJI),
+                escrowed_amount: Coin::new(1000, DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        let due_date = env.block.time.minus_seconds(10);
+
+        save_disburse_escrow_task(deps.as_mut().storage, bounty.id, due_date).unwrap();
+
+        save_escrow_tranche_schedule(
+            deps.as_mut().storage,
+            bounty.id,
+            &EscrowTrancheSchedule::new(vec![
+                EscrowTranche {
+                    allocation: Decimal::percent(50),
+                    delay_seconds: 0,
+                },
+                EscrowTranche {
+                    allocation: Decimal::percent(50),
+                    delay_seconds: 30 * 24 * 60 * 60,
+                },
+            ]),
+        )
+        .unwrap();
+
+        let response = disburse_escrow_handler(deps.as_mut(), env, info, bounty.id).unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert!(response.attributes.contains(&Attribute::new(
+            "escrow_disbursed",
+            format!("{:?}", Coin::new(500, DENOM_UUSK))
+        )));
+        assert_eq!(updated_bounty.escrowed_amount, Coin::new(500, DENOM_UUSK));
+        assert_eq!(updated_bounty.status, BountyStatus::Inactive);
+        assert_eq!(
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, bounty.id).unwrap(),
+            Some(due_date.plus_seconds(30 * 24 * 60 * 60))
+        );
+    }
+
+    #[test]
+    fn records_completion_for_assignees() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Inactive,
+                escrowed_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        disburse_escrow_handler(deps.as_mut(), env.clone(), info, bounty.id).unwrap();
+
+        assert_eq!(
+            get_completion_counts(deps.as_ref().storage, env.block.time, env.block.time)
+                .unwrap(),
+            vec![(Addr::unchecked("assignee"), 1)]
+        );
+    }
 }
@@ -1,22 +1,39 @@
+use crate::constants::AFTER_SWAP_REPLY_ID;
 use crate::error::ContractError;
-use crate::helpers::disbursement::get_disbursement_messages;
-use crate::helpers::fees::{get_automation_fee_rate, get_fee_messages, get_swap_fee_rate};
+use crate::helpers::cleanup::clear_terminal_bounty_state;
+use crate::helpers::disbursement::{get_disbursement_messages, reconcile_exact_receive_amount};
+use crate::helpers::fees::{
+    get_automation_fee_rate, get_fee_amount, get_fee_messages, get_referral_fee, get_swap_fee_rate,
+};
 use crate::helpers::math::checked_mul;
+use crate::helpers::vault::promote_next_denom_balance;
 use crate::msg::ExecuteMsg;
-use crate::state::cache::{SWAP_CACHE, VAULT_ID_CACHE};
+use crate::state::cache::{
+    SwapRetryCache, EXECUTOR_CACHE, SWAP_CACHE, SWAP_RETRY_CACHE, TWAP_PRICE_CACHE, VAULT_ID_CACHE,
+};
+use crate::state::conversion_reports::record_conversion;
+use crate::state::config::get_config;
+use crate::state::escrow_epochs::add_to_current_escrow_epoch;
 use crate::state::events::create_event;
+use crate::state::executor_stats::record_failed_sub_msg;
+use crate::state::gas_usage_reports::record_execution_receipt;
+use crate::state::referrals::add_referrer_earnings;
 use crate::state::triggers::delete_trigger;
 use crate::state::vaults::{get_vault, update_vault};
+use crate::types::destination::DisbursementOutcome;
 use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
+use crate::types::execution_receipt::ExecutionReceipt;
 use crate::types::vault::{Vault, VaultStatus};
-use cosmwasm_std::{to_json_binary, SubMsg, SubMsgResult, Uint128, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, Decimal, SubMsg, SubMsgResult, Uint128, WasmMsg};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{Attribute, Coin, DepsMut, Env, Reply, Response};
+use exchange::msg::ExecuteMsg as ExchangeExecuteMsg;
 use shared::balance::query_balance;
 use shared::coin::{add_to, subtract};
+use shared::cw20::into_execute_msg;
 
 pub fn disburse_funds_handler(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: &Env,
     reply: Reply,
 ) -> Result<Response, ContractError> {
@@ -48,67 +65,62 @@ pub fn disburse_funds_handler(
             let coin_received =
                 subtract(&receive_denom_balance, &swap_cache.receive_denom_balance)?;
 
-            let swap_fee_rate = get_swap_fee_rate(deps.storage, &vault.swap_adjustment_strategy)?;
-            let automation_fee_rate = get_automation_fee_rate(deps.storage, &vault)?;
-
-            let swap_fee = checked_mul(coin_received.amount, swap_fee_rate)?;
-            let total_after_swap_fee = coin_received.amount - swap_fee;
-            let automation_fee = checked_mul(total_after_swap_fee, automation_fee_rate)?;
-            let total_fee = swap_fee + automation_fee;
-            let total_after_all_fees = coin_received.amount - total_fee;
-
-            sub_msgs.append(&mut get_fee_messages(
-                deps.as_ref(),
-                env.clone(),
-                vec![swap_fee, automation_fee],
-                coin_received.denom.clone(),
-                false,
-            )?);
+            let executor = EXECUTOR_CACHE.may_load(deps.storage)?;
 
-            let amount_to_escrow = total_after_all_fees * vault.escrow_level;
-            let total_after_escrow = total_after_all_fees - amount_to_escrow;
-
-            vault.balance.amount -= coin_sent.amount;
-
-            vault = update_vault(
-                deps.storage,
-                Vault {
-                    swapped_amount: add_to(&vault.swapped_amount, coin_sent.amount),
-                    received_amount: add_to(&vault.received_amount, total_after_all_fees),
-                    escrowed_amount: add_to(&vault.escrowed_amount, amount_to_escrow),
-                    status: if vault.balance.amount.is_zero() {
-                        VaultStatus::Inactive
-                    } else {
-                        vault.status
-                    },
-                    ..vault
-                },
+            let (updated_vault, mut swap_attributes, mut swap_sub_msgs) = disburse_swap_proceeds(
+                deps.branch(),
+                env,
+                vault,
+                coin_sent,
+                coin_received,
+                executor,
             )?;
 
-            sub_msgs.append(
-                &mut get_disbursement_messages(deps.api, deps.storage, &vault, total_after_escrow)?
-                    .into(),
-            );
+            vault = updated_vault;
+            attributes.append(&mut swap_attributes);
+            sub_msgs.append(&mut swap_sub_msgs);
+        }
+        SubMsgResult::Err(error) => {
+            if is_routing_error(&error) {
+                if let Some(swap_retry) = SWAP_RETRY_CACHE.may_load(deps.storage)? {
+                    if let Some(fallback_route) =
+                        vault.fallback_routes.get(swap_retry.fallback_routes_tried)
+                    {
+                        let config = get_config(deps.storage)?;
+
+                        SWAP_RETRY_CACHE.save(
+                            deps.storage,
+                            &SwapRetryCache {
+                                fallback_routes_tried: swap_retry.fallback_routes_tried + 1,
+                                ..swap_retry.clone()
+                            },
+                        )?;
+
+                        return Ok(Response::new()
+                            .add_attribute(
+                                "fallback_route",
+                                (swap_retry.fallback_routes_tried + 1).to_string(),
+                            )
+                            .add_submessage(SubMsg::reply_always(
+                                into_execute_msg(
+                                    deps.api,
+                                    config.exchange_contract_address,
+                                    to_json_binary(&ExchangeExecuteMsg::Swap {
+                                        minimum_receive_amount: swap_retry.minimum_receive_amount,
+                                        route: Some(fallback_route.clone()),
+                                    })?,
+                                    swap_retry.swap_amount,
+                                )?,
+                                AFTER_SWAP_REPLY_ID,
+                            )));
+                    }
+                }
+            }
 
-            create_event(
-                deps.storage,
-                EventBuilder::new(
-                    vault.id,
-                    env.block.clone(),
-                    EventData::DcaVaultExecutionCompleted {
-                        sent: coin_sent.clone(),
-                        received: coin_received.clone(),
-                        fee: Coin::new(total_fee.into(), coin_received.denom.clone()),
-                    },
-                ),
-            )?;
+            if let Some(executor) = EXECUTOR_CACHE.may_load(deps.storage)? {
+                record_failed_sub_msg(deps.storage, &executor)?;
+            }
 
-            attributes.push(Attribute::new("disburse_funds", "true"));
-            attributes.push(Attribute::new("swapped_amount", coin_sent.to_string()));
-            attributes.push(Attribute::new("received_amount", coin_received.to_string()));
-            attributes.push(Attribute::new("fee_amount", total_fee.to_string()));
-        }
-        SubMsgResult::Err(_) => {
             create_event(
                 deps.storage,
                 EventBuilder::new(
@@ -127,6 +139,269 @@ pub fn disburse_funds_handler(
         }
     }
 
+    let (sub_msgs, receipt_attributes) = complete_execution(deps, env, &vault, sub_msgs)?;
+
+    Ok(Response::new()
+        .add_attributes(attributes)
+        .add_attributes(receipt_attributes)
+        .add_submessages(sub_msgs))
+}
+
+pub fn disburse_simulated_swap(
+    mut deps: DepsMut,
+    env: &Env,
+    response: Response,
+    vault: Vault,
+    coin_sent: Coin,
+    coin_received: Coin,
+    executor: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let (vault, attributes, sub_msgs) = disburse_swap_proceeds(
+        deps.branch(),
+        env,
+        vault,
+        coin_sent,
+        coin_received,
+        executor,
+    )?;
+
+    let (sub_msgs, receipt_attributes) = complete_execution(deps, env, &vault, sub_msgs)?;
+
+    Ok(response
+        .add_attribute("simulated_swap", "true")
+        .add_attributes(attributes)
+        .add_attributes(receipt_attributes)
+        .add_submessages(sub_msgs))
+}
+
+fn disburse_swap_proceeds(
+    deps: DepsMut,
+    env: &Env,
+    mut vault: Vault,
+    coin_sent: Coin,
+    coin_received: Coin,
+    executor: Option<Addr>,
+) -> Result<(Vault, Vec<Attribute>, Vec<SubMsg>), ContractError> {
+    let mut sub_msgs = Vec::<SubMsg>::new();
+
+    let swap_fee_rate = get_swap_fee_rate(
+        deps.storage,
+        &coin_received.denom,
+        &vault.swap_adjustment_strategy,
+    )?;
+    let automation_fee_rate = get_automation_fee_rate(deps.storage, &vault)?;
+    let config = get_config(deps.storage)?;
+
+    let swap_fee = get_fee_amount(&config, coin_received.amount, swap_fee_rate)?;
+    let total_after_swap_fee = coin_received.amount - swap_fee;
+    let automation_fee = get_fee_amount(&config, total_after_swap_fee, automation_fee_rate)?;
+    let total_fee = swap_fee + automation_fee;
+    let total_after_all_fees = coin_received.amount - total_fee;
+
+    sub_msgs.append(&mut get_fee_messages(
+        deps.as_ref(),
+        env.clone(),
+        vec![swap_fee, automation_fee],
+        coin_received.denom.clone(),
+        false,
+        vault.referrer.clone(),
+        executor,
+    )?);
+
+    if let Some(referrer) = &vault.referrer {
+        add_referrer_earnings(
+            deps.storage,
+            referrer,
+            &Coin::new(
+                (get_referral_fee(&config, swap_fee) + get_referral_fee(&config, automation_fee))
+                    .into(),
+                coin_received.denom.clone(),
+            ),
+        )?;
+    }
+
+    let amount_to_escrow = total_after_all_fees * vault.escrow_level;
+    let total_after_escrow = total_after_all_fees - amount_to_escrow;
+
+    vault.balance.amount -= coin_sent.amount;
+    vault = promote_next_denom_balance(deps.storage, vault)?;
+
+    let escrowed_amount = add_to(&vault.escrowed_amount, amount_to_escrow);
+    add_to_current_escrow_epoch(deps.storage, vault.id, amount_to_escrow)?;
+
+    vault = update_vault(
+        deps.storage,
+        Vault {
+            swapped_amount: add_to(&vault.swapped_amount, coin_sent.amount),
+            received_amount: add_to(&vault.received_amount, total_after_all_fees),
+            status: if !vault.balance.amount.is_zero() {
+                vault.status
+            } else if escrowed_amount.amount.is_zero() {
+                VaultStatus::Completed
+            } else {
+                VaultStatus::Inactive
+            },
+            escrowed_amount,
+            ..vault
+        },
+    )?;
+
+    let twap_received = match TWAP_PRICE_CACHE.may_load(deps.storage)? {
+        Some(twap_price) if !twap_price.is_zero() => {
+            checked_mul(coin_sent.amount, Decimal::one() / twap_price)?
+        }
+        _ => Uint128::zero(),
+    };
+
+    record_conversion(
+        deps.storage,
+        vault.id,
+        coin_sent.amount,
+        coin_received.amount,
+        total_fee,
+        twap_received,
+    )?;
+
+    sub_msgs.append(
+        &mut get_disbursement_messages(
+            deps.api,
+            deps.storage,
+            env.block.time,
+            &vault,
+            total_after_escrow,
+            &DisbursementOutcome::Succeeded {
+                received: total_after_escrow,
+            },
+        )?
+        .into(),
+    );
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            vault.id,
+            env.block.clone(),
+            EventData::DcaVaultExecutionCompleted {
+                sent: coin_sent.clone(),
+                received: coin_received.clone(),
+                fee: Coin::new(total_fee.into(), coin_received.denom.clone()),
+            },
+        ),
+    )?;
+
+    let attributes = vec![
+        Attribute::new("disburse_funds", "true"),
+        Attribute::new("swapped_amount", coin_sent.to_string()),
+        Attribute::new("received_amount", coin_received.to_string()),
+        Attribute::new("fee_amount", total_fee.to_string()),
+    ];
+
+    Ok((vault, attributes, sub_msgs))
+}
+
+pub fn disburse_direct_payout(
+    deps: DepsMut,
+    env: &Env,
+    response: Response,
+    mut vault: Vault,
+    payout: Coin,
+    executor: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let config = get_config(deps.storage)?;
+    let automation_fee_rate = get_automation_fee_rate(deps.storage, &vault)?;
+    let automation_fee = get_fee_amount(&config, payout.amount, automation_fee_rate)?;
+    let total_after_fees = payout.amount - automation_fee;
+
+    let mut sub_msgs = get_fee_messages(
+        deps.as_ref(),
+        env.clone(),
+        vec![automation_fee],
+        payout.denom.clone(),
+        false,
+        vault.referrer.clone(),
+        executor,
+    )?;
+
+    if let Some(referrer) = &vault.referrer {
+        add_referrer_earnings(
+            deps.storage,
+            referrer,
+            &Coin::new(
+                get_referral_fee(&config, automation_fee).into(),
+                payout.denom.clone(),
+            ),
+        )?;
+    }
+
+    let amount_to_escrow = total_after_fees * vault.escrow_level;
+    let total_after_escrow = total_after_fees - amount_to_escrow;
+
+    vault.balance.amount -= payout.amount;
+    vault = promote_next_denom_balance(deps.storage, vault)?;
+
+    let escrowed_amount = add_to(&vault.escrowed_amount, amount_to_escrow);
+    add_to_current_escrow_epoch(deps.storage, vault.id, amount_to_escrow)?;
+
+    vault = update_vault(
+        deps.storage,
+        Vault {
+            swapped_amount: add_to(&vault.swapped_amount, payout.amount),
+            received_amount: add_to(&vault.received_amount, total_after_fees),
+            status: if !vault.balance.amount.is_zero() {
+                vault.status
+            } else if escrowed_amount.amount.is_zero() {
+                VaultStatus::Completed
+            } else {
+                VaultStatus::Inactive
+            },
+            escrowed_amount,
+            ..vault
+        },
+    )?;
+
+    sub_msgs.append(
+        &mut get_disbursement_messages(
+            deps.api,
+            deps.storage,
+            env.block.time,
+            &vault,
+            total_after_escrow,
+            &DisbursementOutcome::Succeeded {
+                received: total_after_escrow,
+            },
+        )?
+        .into(),
+    );
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            vault.id,
+            env.block.clone(),
+            EventData::BountyExecutionCompleted {
+                sent: payout.clone(),
+                received: payout.clone(),
+                fee: Coin::new(automation_fee.into(), payout.denom.clone()),
+            },
+        ),
+    )?;
+
+    let (sub_msgs, receipt_attributes) = complete_execution(deps, env, &vault, sub_msgs)?;
+
+    Ok(response
+        .add_attributes(receipt_attributes)
+        .add_attribute("direct_payout", "true")
+        .add_attribute("paid_out_amount", payout.to_string())
+        .add_attribute("fee_amount", automation_fee.to_string())
+        .add_submessages(sub_msgs))
+}
+
+fn complete_execution(
+    deps: DepsMut,
+    env: &Env,
+    vault: &Vault,
+    mut sub_msgs: Vec<SubMsg>,
+) -> Result<(Vec<SubMsg>, Vec<Attribute>), ContractError> {
     if vault.should_not_continue() {
         if vault.escrowed_amount.amount > Uint128::zero() {
             sub_msgs.push(SubMsg::new(WasmMsg::Execute {
@@ -136,12 +411,38 @@ pub fn disburse_funds_handler(
             }));
         }
 
-        delete_trigger(deps.storage, vault.id)?;
+        if vault.is_completed() {
+            reconcile_exact_receive_amount(deps.storage, &env.block, vault)?;
+            clear_terminal_bounty_state(deps.storage, vault)?;
+        } else {
+            delete_trigger(deps.storage, vault.id)?;
+        }
     }
 
-    Ok(Response::new()
-        .add_attributes(attributes)
-        .add_submessages(sub_msgs))
+    let receipt = ExecutionReceipt::new(env.block.time, &sub_msgs, vault.destinations);
+    record_execution_receipt(deps.storage, vault.id, &receipt)?;
+
+    Ok((sub_msgs, receipt_attributes(&receipt)))
+}
+
+fn receipt_attributes(receipt: &ExecutionReceipt) -> Vec<Attribute> {
+    vec![
+        Attribute::new("message_count", receipt.message_count.to_string()),
+        Attribute::new("submessage_count", receipt.submessage_count.to_string()),
+        Attribute::new(
+            "destination_callback_count",
+            receipt.destination_callback_count.to_string(),
+        ),
+        Attribute::new("estimated_gas", receipt.estimated_gas().to_string()),
+        Attribute::new("execution_weight", format!("{:?}", receipt.weight())),
+    ]
+}
+
+fn is_routing_error(error: &str) -> bool {
+    let error = error.to_lowercase();
+    ["route", "pool", "liquidity"]
+        .iter()
+        .any(|keyword| error.contains(keyword))
 }
 
 #[cfg(test)]
@@ -154,6 +455,7 @@ mod disburse_funds_tests {
         state::{
             cache::{SwapCache, SWAP_CACHE},
             config::get_config,
+            gas_usage_reports::get_gas_usage_report,
             swap_adjustments::update_swap_adjustment,
             vaults::get_vault,
         },
@@ -294,9 +596,16 @@ mod disburse_funds_tests {
 
         let config = get_config(&deps.storage).unwrap();
         let swap_fee = config.default_swap_fee_percent * receive_amount;
+        let FeeCollector::Address {
+            address: fee_collector,
+            ..
+        } = config.fee_collectors[0].clone()
+        else {
+            panic!("expected an address fee collector");
+        };
 
         assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
-            to_address: config.fee_collectors[0].address.to_string(),
+            to_address: fee_collector,
             amount: vec![Coin::new(swap_fee.into(), vault.target_denom)]
         })));
     }
@@ -311,11 +620,11 @@ mod disburse_funds_tests {
             env.clone(),
             mock_info(ADMIN, &[]),
             vec![
-                FeeCollector {
+                FeeCollector::Address {
                     address: "fee_collector_1".to_string(),
                     allocation: Decimal::percent(20),
                 },
-                FeeCollector {
+                FeeCollector::Address {
                     address: "fee_collector_2".to_string(),
                     allocation: Decimal::percent(80),
                 },
@@ -369,22 +678,26 @@ mod disburse_funds_tests {
         );
 
         for fee_collector in config.fee_collectors.iter() {
+            let FeeCollector::Address {
+                address,
+                allocation,
+            } = fee_collector
+            else {
+                panic!("expected an address fee collector");
+            };
+
             assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
-                to_address: fee_collector.address.to_string(),
+                to_address: address.to_string(),
                 amount: vec![Coin::new(
-                    checked_mul(swap_fee, fee_collector.allocation)
-                        .unwrap()
-                        .into(),
+                    checked_mul(swap_fee, *allocation).unwrap().into(),
                     vault.target_denom.clone()
                 )]
             })));
 
             assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
-                to_address: fee_collector.address.to_string(),
+                to_address: address.to_string(),
                 amount: vec![Coin::new(
-                    checked_mul(automation_fee, fee_collector.allocation)
-                        .unwrap()
-                        .into(),
+                    checked_mul(automation_fee, *allocation).unwrap().into(),
                     vault.target_denom.clone()
                 )]
             })));
@@ -442,6 +755,60 @@ mod disburse_funds_tests {
         );
     }
 
+    #[test]
+    fn with_successful_swap_records_execution_receipt() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let receive_amount = Uint128::new(234312312);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(receive_amount.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let report = get_gas_usage_report(&deps.storage, vault.id).unwrap();
+        let receipt = report.last_receipt.clone().unwrap();
+
+        assert_eq!(report.executions, 1);
+        assert_eq!(receipt.message_count, response.messages.len() as u32);
+        assert_eq!(report.total_estimated_gas, receipt.estimated_gas());
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("estimated_gas", receipt.estimated_gas().to_string())));
+    }
+
     #[test]
     fn with_successful_swap_adjusts_swapped_amount_stat() {
         let mut deps = mock_dependencies();
@@ -740,11 +1107,18 @@ mod disburse_funds_tests {
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
-        let inverted_fee_rate =
-            Decimal::one() - (config.default_swap_fee_percent + config.automation_fee_percent);
-        let received_amount =
-            updated_vault.received_amount.amount * (Decimal::one() / inverted_fee_rate);
-        let fee = received_amount - updated_vault.received_amount.amount + Uint128::one(); // rounding
+        let swap_fee_rate = get_swap_fee_rate(
+            deps.as_ref().storage,
+            &vault.target_denom,
+            &vault.swap_adjustment_strategy,
+        )
+        .unwrap();
+        let automation_fee_rate = get_automation_fee_rate(deps.as_ref().storage, &vault).unwrap();
+
+        let swap_fee = get_fee_amount(&config, receive_amount, swap_fee_rate).unwrap();
+        let automation_fee =
+            get_fee_amount(&config, receive_amount - swap_fee, automation_fee_rate).unwrap();
+        let fee = swap_fee + automation_fee;
 
         assert!(events.contains(&Event {
             id: 1,
@@ -884,6 +1258,125 @@ mod disburse_funds_tests {
         assert_eq!(updated_vault.balance, balance);
     }
 
+    #[test]
+    fn with_routing_failure_retries_swap_with_next_fallback_route() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let fallback_route = to_json_binary(&"fallback-route").unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                fallback_routes: vec![fallback_route.clone()],
+                ..Vault::default()
+            },
+        );
+
+        let swap_retry = SwapRetryCache {
+            swap_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+            minimum_receive_amount: Coin::new(0, DENOM_UUSK),
+            fallback_routes_tried: 0,
+        };
+
+        SWAP_RETRY_CACHE
+            .save(deps.as_mut().storage, &swap_retry)
+            .unwrap();
+
+        let reply = Reply {
+            id: AFTER_SWAP_REPLY_ID,
+            result: SubMsgResult::Err("no liquidity in pool".to_string()),
+        };
+
+        let response = disburse_funds_handler(deps.as_mut(), &env, reply).unwrap();
+
+        let config = get_config(&deps.storage).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_always(
+                into_execute_msg(
+                    &deps.api,
+                    config.exchange_contract_address,
+                    to_json_binary(&ExchangeExecuteMsg::Swap {
+                        minimum_receive_amount: swap_retry.minimum_receive_amount,
+                        route: Some(fallback_route),
+                    })
+                    .unwrap(),
+                    swap_retry.swap_amount,
+                )
+                .unwrap(),
+                AFTER_SWAP_REPLY_ID,
+            )]
+        );
+        assert_eq!(
+            SWAP_RETRY_CACHE
+                .load(&deps.storage)
+                .unwrap()
+                .fallback_routes_tried,
+            1
+        );
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn with_routing_failure_and_no_fallback_routes_left_publishes_skipped_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                fallback_routes: vec![to_json_binary(&"fallback-route").unwrap()],
+                ..Vault::default()
+            },
+        );
+
+        SWAP_RETRY_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapRetryCache {
+                    swap_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+                    minimum_receive_amount: Coin::new(0, DENOM_UUSK),
+                    fallback_routes_tried: 1,
+                },
+            )
+            .unwrap();
+
+        let reply = Reply {
+            id: AFTER_SWAP_REPLY_ID,
+            result: SubMsgResult::Err("no route found".to_string()),
+        };
+
+        let response = disburse_funds_handler(deps.as_mut(), &env, reply).unwrap();
+
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultExecutionSkipped {
+                    reason: ExecutionSkippedReason::SlippageToleranceExceeded
+                }
+            )
+            .build(1)
+        ));
+    }
+
     #[test]
     fn with_failed_swap_publishes_skipped_event_with_slippage_failure() {
         let mut deps = mock_dependencies();
@@ -954,7 +1447,7 @@ mod disburse_funds_tests {
     }
 
     #[test]
-    fn with_insufficient_remaining_funds_sets_vault_to_inactive() {
+    fn with_insufficient_remaining_funds_and_no_escrow_sets_vault_to_completed() {
         let mut deps = mock_dependencies();
         let env = mock_env();
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
@@ -998,7 +1491,7 @@ mod disburse_funds_tests {
         .unwrap();
 
         let vault = get_vault(&deps.storage, vault.id).unwrap();
-        assert_eq!(vault.status, VaultStatus::Inactive);
+        assert_eq!(vault.status, VaultStatus::Completed);
     }
 
     #[test]
@@ -1071,6 +1564,7 @@ mod disburse_funds_tests {
             Vault {
                 balance: Coin::new(49999, DENOM_UKUJI),
                 swap_amount: ONE,
+                escrow_level: Decimal::percent(5),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
                 performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
                 ..Vault::default()
@@ -0,0 +1,400 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        cleanup::clear_terminal_bounty_state,
+        disbursement::{get_disbursement_destinations, get_split_transfer_messages},
+        fees::{get_fee_messages, take_performance_fee},
+        price::get_twap_to_now_with_fallback,
+        validation::assert_bounty_has_no_open_dispute,
+    },
+    state::{
+        assignees::get_assignees,
+        bounties::update_bounty,
+        completions::record_completion,
+        config::get_config,
+        denom_balances::{delete_denom_balance, get_denom_balances},
+        events::create_event,
+    },
+    types::{
+        bounty::{Bounty, BountyStatus},
+        destination::{Destination, DisbursementOutcome},
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{Addr, Coin, DepsMut, Env, Response, SubMsg};
+use shared::coin::{empty_of, subtract};
+
+pub fn execute_escrow_accept_handler(
+    deps: DepsMut,
+    env: Env,
+    mut response: Response,
+    bounty: Bounty,
+    destinations: Vec<Destination>,
+    executor: Option<Addr>,
+) -> Result<Response, ContractError> {
+    assert_bounty_has_no_open_dispute(deps.storage, bounty.id)?;
+
+    let performance_fee = if bounty.escrowed_amount.amount.is_zero() {
+        Coin::new(0, bounty.target_denom.clone())
+    } else {
+        let config = get_config(deps.storage)?;
+
+        let (current_price, price_oracle_address) = get_twap_to_now_with_fallback(
+            &deps.querier,
+            &config,
+            bounty.get_swap_denom(),
+            bounty.target_denom.to_string(),
+            bounty.route.clone(),
+        )?;
+
+        if let Some(price_oracle_address) = price_oracle_address {
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    bounty.id,
+                    env.block.clone(),
+                    EventData::BountyPriceOracleUsed {
+                        oracle: price_oracle_address.clone(),
+                        price: current_price,
+                    },
+                ),
+            )?;
+
+            response = response.add_attribute("price_oracle", price_oracle_address);
+        }
+
+        take_performance_fee(
+            deps.storage,
+            &config,
+            &bounty,
+            &bounty.escrowed_amount,
+            current_price,
+        )?
+    };
+
+    let escrow_to_disburse = subtract(&bounty.escrowed_amount, &performance_fee)?;
+
+    let destinations = if destinations.is_empty() {
+        get_disbursement_destinations(
+            deps.storage,
+            &bounty,
+            &DisbursementOutcome::Succeeded {
+                received: escrow_to_disburse.amount,
+            },
+        )?
+    } else {
+        destinations
+    };
+
+    let mut amount_disbursed = vec![escrow_to_disburse, bounty.balance.clone()];
+
+    for denom_balance in get_denom_balances(deps.storage, bounty.id)? {
+        delete_denom_balance(deps.storage, bounty.id, &denom_balance.balance.denom)?;
+        amount_disbursed.push(denom_balance.balance);
+    }
+
+    let amount_disbursed = amount_disbursed
+        .into_iter()
+        .filter(|amount| !amount.amount.is_zero())
+        .collect::<Vec<Coin>>();
+
+    let mut messages = Vec::<SubMsg>::new();
+
+    for amount in &amount_disbursed {
+        messages.append(&mut get_split_transfer_messages(
+            deps.api,
+            &destinations,
+            amount,
+        )?);
+    }
+
+    for assignee in get_assignees(deps.storage, bounty.id)? {
+        record_completion(deps.storage, bounty.id, &assignee.address, env.block.time)?;
+    }
+
+    let bounty = update_bounty(
+        deps.storage,
+        Bounty {
+            status: BountyStatus::Completed,
+            balance: empty_of(bounty.balance.clone()),
+            escrowed_amount: empty_of(bounty.escrowed_amount.clone()),
+            ..bounty
+        },
+    )?;
+
+    clear_terminal_bounty_state(deps.storage, &bounty)?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block.clone(),
+            EventData::BountyEscrowAccepted {
+                amount_disbursed: amount_disbursed.clone(),
+            },
+        ),
+    )?;
+
+    Ok(response
+        .add_attribute("escrow_accepted", "true")
+        .add_attribute("amount_disbursed", format!("{:?}", amount_disbursed))
+        .add_attribute("performance_fee", format!("{:?}", performance_fee))
+        .add_submessages(messages)
+        .add_submessages(get_fee_messages(
+            deps.as_ref(),
+            env,
+            vec![performance_fee.amount],
+            bounty.target_denom.to_string(),
+            true,
+            bounty.referrer.clone(),
+            executor,
+        )?))
+}
+
+#[cfg(test)]
+mod execute_escrow_accept_tests {
+    use super::*;
+    use crate::constants::{ONE, TEN, TEN_DECIMAL};
+    use crate::helpers::fees::get_executor_reward;
+    use crate::state::assignees::save_assignee;
+    use crate::state::bounties::get_bounty;
+    use crate::state::config::get_config;
+    use crate::state::denom_balances::save_denom_balance;
+    use crate::state::disputes::save_dispute;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK, USER};
+    use crate::types::assignee::Assignee;
+    use crate::types::denom_balance::DenomBalance;
+    use crate::types::dispute::Dispute;
+    use crate::types::fee_collector::FeeCollector;
+    use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategy;
+    use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{BankMsg, Decimal};
+
+    const ASSIGNEE: &str = "assignee";
+
+    #[test]
+    fn with_open_dispute_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_dispute(
+            deps.as_mut().storage,
+            &Dispute {
+                bounty_id: bounty.id,
+                raised_by: Addr::unchecked(USER),
+                reason: "work was not delivered".to_string(),
+                raised_at: env.block.time,
+            },
+        )
+        .unwrap();
+
+        let err = execute_escrow_accept_handler(
+            deps.as_mut(),
+            env,
+            Response::new(),
+            bounty,
+            vec![],
+            None,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("open dispute"));
+    }
+
+    #[test]
+    fn pays_escrow_and_balance_to_assignees() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                balance: Coin::new(ONE.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked(ASSIGNEE),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        let response = execute_escrow_accept_handler(
+            deps.as_mut(),
+            env,
+            Response::new(),
+            bounty.clone(),
+            vec![],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: ASSIGNEE.to_string(),
+                    amount: vec![Coin::new(TEN.into(), DENOM_UUSK)],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: ASSIGNEE.to_string(),
+                    amount: vec![Coin::new(ONE.into(), DENOM_UKUJI)],
+                }),
+            ]
+        );
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.status, BountyStatus::Completed);
+        assert!(updated_bounty.balance.amount.is_zero());
+        assert!(updated_bounty.escrowed_amount.amount.is_zero());
+    }
+
+    #[test]
+    fn pays_denom_balances_to_assignees() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                balance: Coin::new(0, DENOM_UKUJI),
+                escrowed_amount: Coin::new(0, DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(TEN.into(), "uatom"),
+                deposited_amount: Coin::new(TEN.into(), "uatom"),
+                swap_amount: ONE,
+                route: None,
+            },
+        )
+        .unwrap();
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked(ASSIGNEE),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        let response = execute_escrow_accept_handler(
+            deps.as_mut(),
+            env,
+            Response::new(),
+            bounty.clone(),
+            vec![],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: ASSIGNEE.to_string(),
+                amount: vec![Coin::new(TEN.into(), "uatom")],
+            })]
+        );
+        assert!(get_denom_balances(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn sends_performance_fee_to_fee_collector() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                balance: Coin::new(0, DENOM_UKUJI),
+                swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new((ONE * Decimal::percent(5)).into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Bounty::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&TEN_DECIMAL);
+
+        let config = get_config(&deps.storage).unwrap();
+
+        let response = execute_escrow_accept_handler(
+            deps.as_mut(),
+            env,
+            Response::new(),
+            bounty.clone(),
+            vec![],
+            Some(Addr::unchecked(ADMIN)),
+        )
+        .unwrap();
+
+        let executor_reward = get_executor_reward(&config, bounty.escrowed_amount.amount);
+        let FeeCollector::Address {
+            address: fee_collector,
+            ..
+        } = config.fee_collectors[0].clone()
+        else {
+            panic!("expected an address fee collector");
+        };
+
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::new(BankMsg::Send {
+                to_address: fee_collector,
+                amount: vec![Coin::new(
+                    (bounty.escrowed_amount.amount - executor_reward).into(),
+                    DENOM_UUSK
+                )]
+            })
+        );
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: ADMIN.to_string(),
+            amount: vec![Coin::new(executor_reward.into(), DENOM_UUSK)]
+        })));
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        cleanup::clear_terminal_bounty_state, disbursement::get_split_transfer_messages,
+        validation::assert_bounty_has_no_open_dispute,
+    },
+    state::{
+        bounties::update_bounty,
+        denom_balances::{delete_denom_balance, get_denom_balances},
+        events::create_event,
+    },
+    types::{
+        bounty::{Bounty, BountyStatus},
+        destination::Destination,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{Coin, Decimal, DepsMut, Env, Response, SubMsg};
+use shared::coin::empty_of;
+
+pub fn execute_escrow_reject_handler(
+    deps: DepsMut,
+    env: Env,
+    response: Response,
+    bounty: Bounty,
+    destinations: Vec<Destination>,
+) -> Result<Response, ContractError> {
+    assert_bounty_has_no_open_dispute(deps.storage, bounty.id)?;
+
+    let destinations = if destinations.is_empty() {
+        vec![Destination {
+            allocation: Decimal::one(),
+            address: bounty.owner.clone(),
+            msg: None,
+            condition: None,
+            ibc: None,
+            gas_limit: None,
+        }]
+    } else {
+        destinations
+    };
+
+    let mut amount_refunded = vec![bounty.balance.clone(), bounty.escrowed_amount.clone()];
+
+    for denom_balance in get_denom_balances(deps.storage, bounty.id)? {
+        delete_denom_balance(deps.storage, bounty.id, &denom_balance.balance.denom)?;
+        amount_refunded.push(denom_balance.balance);
+    }
+
+    let amount_refunded = amount_refunded
+        .into_iter()
+        .filter(|amount| !amount.amount.is_zero())
+        .collect::<Vec<Coin>>();
+
+    let mut messages = Vec::<SubMsg>::new();
+
+    for amount in &amount_refunded {
+        messages.append(&mut get_split_transfer_messages(
+            deps.api,
+            &destinations,
+            amount,
+        )?);
+    }
+
+    let bounty = update_bounty(
+        deps.storage,
+        Bounty {
+            status: BountyStatus::Rejected,
+            balance: empty_of(bounty.balance.clone()),
+            escrowed_amount: empty_of(bounty.escrowed_amount.clone()),
+            ..bounty
+        },
+    )?;
+
+    clear_terminal_bounty_state(deps.storage, &bounty)?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyEscrowRejected {
+                amount_refunded: amount_refunded.clone(),
+            },
+        ),
+    )?;
+
+    Ok(response
+        .add_attribute("escrow_rejected", "true")
+        .add_attribute("amount_refunded", format!("{:?}", amount_refunded))
+        .add_submessages(messages))
+}
+
+#[cfg(test)]
+mod execute_escrow_reject_tests {
+    use super::*;
+    use crate::constants::{ONE, TEN};
+    use crate::state::bounties::get_bounty;
+    use crate::state::denom_balances::save_denom_balance;
+    use crate::state::disburse_escrow_tasks::{
+        get_disburse_escrow_task_due_date, save_disburse_escrow_task,
+    };
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK, USER};
+    use crate::types::denom_balance::DenomBalance;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, BankMsg};
+
+    #[test]
+    fn refunds_balance_and_escrow_to_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                balance: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        let response = execute_escrow_reject_handler(
+            deps.as_mut(),
+            env,
+            Response::new(),
+            bounty.clone(),
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: USER.to_string(),
+                    amount: vec![Coin::new(TEN.into(), DENOM_UKUJI)],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: USER.to_string(),
+                    amount: vec![Coin::new(ONE.into(), DENOM_UUSK)],
+                }),
+            ]
+        );
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.status, BountyStatus::Rejected);
+        assert!(updated_bounty.balance.amount.is_zero());
+    }
+
+    #[test]
+    fn refunds_to_trigger_destinations_when_provided() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                balance: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(0, DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        let response = execute_escrow_reject_handler(
+            deps.as_mut(),
+            env,
+            Response::new(),
+            bounty,
+            vec![Destination {
+                allocation: Decimal::one(),
+                address: Addr::unchecked("treasury"),
+                msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
+            }],
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "treasury".to_string(),
+                amount: vec![Coin::new(TEN.into(), DENOM_UKUJI)],
+            })]
+        );
+    }
+
+    #[test]
+    fn refunds_denom_balances_to_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                balance: Coin::new(0, DENOM_UKUJI),
+                escrowed_amount: Coin::new(0, DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(TEN.into(), "uatom"),
+                deposited_amount: Coin::new(TEN.into(), "uatom"),
+                swap_amount: ONE,
+                route: None,
+            },
+        )
+        .unwrap();
+
+        let response = execute_escrow_reject_handler(
+            deps.as_mut(),
+            env,
+            Response::new(),
+            bounty.clone(),
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: USER.to_string(),
+                amount: vec![Coin::new(TEN.into(), "uatom")],
+            })]
+        );
+        assert!(get_denom_balances(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn clears_terminal_bounty_state() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            bounty.id,
+            env.block.time.plus_seconds(10),
+        )
+        .unwrap();
+
+        execute_escrow_reject_handler(deps.as_mut(), env, Response::new(), bounty.clone(), vec![])
+            .unwrap();
+
+        assert_eq!(
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, bounty.id).unwrap(),
+            None
+        );
+    }
+}
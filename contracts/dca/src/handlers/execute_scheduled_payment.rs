@@ -0,0 +1,149 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_has_role,
+    state::scheduled_payments::{delete_scheduled_payment, get_scheduled_payment},
+    types::role::Role,
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn execute_scheduled_payment_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    payment_id: u64,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Executor)?;
+
+    let scheduled_payment = get_scheduled_payment(deps.storage, payment_id)?;
+
+    if !scheduled_payment.is_due(env.block.time) {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "scheduled payment with id {} is not due until {}",
+                scheduled_payment.id, scheduled_payment.at
+            ),
+        });
+    }
+
+    delete_scheduled_payment(deps.storage, scheduled_payment.id)?;
+
+    Ok(Response::new()
+        .add_attribute("execute_scheduled_payment", "true")
+        .add_attribute("payment_id", scheduled_payment.id.to_string())
+        .add_attribute("to", scheduled_payment.to.clone())
+        .add_attribute("amount", scheduled_payment.amount.to_string())
+        .add_message(scheduled_payment.payment_msg()))
+}
+
+#[cfg(test)]
+mod execute_scheduled_payment_tests {
+    use super::*;
+    use crate::{
+        state::scheduled_payments::save_scheduled_payment,
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{ADMIN, DENOM_UKUJI, USER},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, BankMsg, Coin, SubMsg,
+    };
+
+    #[test]
+    fn with_non_executor_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let scheduled_payment = save_scheduled_payment(
+            deps.as_mut().storage,
+            Addr::unchecked(USER),
+            Addr::unchecked("recipient"),
+            Coin::new(1000, DENOM_UKUJI),
+            env.block.time,
+            env.block.time,
+        )
+        .unwrap();
+
+        let err = execute_scheduled_payment_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            scheduled_payment.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_payment_not_yet_due_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let scheduled_payment = save_scheduled_payment(
+            deps.as_mut().storage,
+            Addr::unchecked(USER),
+            Addr::unchecked("recipient"),
+            Coin::new(1000, DENOM_UKUJI),
+            env.block.time.plus_seconds(10),
+            env.block.time,
+        )
+        .unwrap();
+
+        let err = execute_scheduled_payment_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            scheduled_payment.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: scheduled payment with id 1 is not due until {}",
+                env.block.time.plus_seconds(10)
+            )
+        );
+    }
+
+    #[test]
+    fn sends_payment_and_deletes_it() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let scheduled_payment = save_scheduled_payment(
+            deps.as_mut().storage,
+            Addr::unchecked(USER),
+            Addr::unchecked("recipient"),
+            Coin::new(1000, DENOM_UKUJI),
+            env.block.time,
+            env.block.time,
+        )
+        .unwrap();
+
+        let response = execute_scheduled_payment_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            scheduled_payment.id,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "recipient".to_string(),
+                amount: vec![Coin::new(1000, DENOM_UKUJI)],
+            })]
+        );
+        assert!(get_scheduled_payment(deps.as_ref().storage, scheduled_payment.id).is_err());
+    }
+}
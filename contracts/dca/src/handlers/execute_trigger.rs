@@ -1,25 +1,46 @@
 use crate::constants::AFTER_SWAP_REPLY_ID;
 use crate::error::ContractError;
-// use crate::helpers::price::{get_expected_receive_amount, get_slippage, get_twap_to_now};
+use crate::handlers::cancel_bounty::cancel_bounty;
+use crate::handlers::disburse_funds::{disburse_direct_payout, disburse_simulated_swap};
+use crate::handlers::execute_escrow_accept::execute_escrow_accept_handler;
+use crate::handlers::execute_escrow_reject::execute_escrow_reject_handler;
+use crate::helpers::price::{
+    get_best_quote, get_expected_receive_amount, get_slippage_from_expected_receive_amount,
+    get_twap_to_now,
+};
+use crate::helpers::exchange::get_exchange_for_pair;
+use crate::helpers::math::checked_mul;
 use crate::helpers::time::get_next_target_time;
-use crate::helpers::validation::{assert_contract_is_not_paused, assert_target_time_is_in_past};
-// use crate::helpers::vault::{get_swap_amount, simulate_standard_dca_execution};
+use crate::helpers::validation::{
+    assert_balance_has_dropped_to_threshold, assert_balance_is_above_threshold,
+    assert_contract_is_not_paused, assert_execute_is_not_paused,
+    assert_slippage_override_is_within_cap, assert_target_height_is_in_past,
+    assert_target_time_is_in_past,
+};
+use crate::helpers::vault::{
+    get_clamped_swap_adjustment, get_swap_amount, simulate_standard_dca_execution,
+};
 use crate::msg::ExecuteMsg;
-use crate::state::cache::{SwapCache, SWAP_CACHE, BOUNTY_ID_CACHE};
+use crate::state::bounty_snapshots::record_bounty_snapshot_if_due;
+use crate::state::cache::{
+    SwapCache, SwapRetryCache, BOUNTY_ID_CACHE, EXECUTOR_CACHE, SWAP_CACHE, SWAP_RETRY_CACHE,
+    TWAP_PRICE_CACHE,
+};
 use crate::state::config::get_config;
 use crate::state::events::create_event;
-use crate::state::triggers::{delete_trigger, save_trigger};
-use crate::state::vaults::{get_bounty, update_bounty};
+use crate::state::executor_stats::{record_execution_skipped, record_trigger_executed};
+use crate::state::triggers::{delete_trigger, get_trigger, save_trigger};
+use crate::state::vaults::get_bounty;
 use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
-// use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
+use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
 use crate::types::trigger::{Trigger, TriggerConfiguration};
-use crate::types::vault::{Bounty, BountyStatus};
-use cosmwasm_std::{to_json_binary, Binary, Coin, Decimal, SubMsg, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Coin, Decimal, SubMsg, WasmMsg};
 #[cfg(not(feature = "library"))]
-use cosmwasm_std::{DepsMut, Env, Response, Uint128};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
 use exchange::msg::{ExecuteMsg as ExchangeExecuteMsg, Order, QueryMsg as ExchangeQueryMsg};
 use shared::balance::query_balance;
 use shared::cw20::into_execute_msg;
+use std::cmp::max;
 
 // We want a few triggers. 
 // 1) Escrow Trigger --> a. Rejected Bounty --> Trigger Rejection and refund the Bounty Issuer. b.Completed Bounty --> Trigger Completion and send pay to Bounty Assignee
@@ -28,20 +49,71 @@ use shared::cw20::into_execute_msg;
 
 
 pub fn execute_trigger_handler(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    trigger_id: Uint128,
+    route: Option<Binary>,
+    slippage_override: Option<Decimal>,
+    on_behalf_of: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let executor = if info.sender == env.contract.address {
+        on_behalf_of
+    } else if on_behalf_of.is_some() {
+        return Err(ContractError::Unauthorized {});
+    } else {
+        Some(info.sender)
+    };
+
+    let response = execute_trigger(
+        deps.branch(),
+        env,
+        executor.clone(),
+        trigger_id,
+        route,
+        slippage_override,
+    )?;
+
+    if let Some(executor) = executor {
+        if response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "execution_skipped")
+        {
+            record_execution_skipped(deps.storage, &executor)?;
+        } else {
+            record_trigger_executed(deps.storage, &executor)?;
+        }
+    }
+
+    Ok(response)
+}
+
+fn execute_trigger(
     deps: DepsMut,
     env: Env,
+    executor: Option<Addr>,
     trigger_id: Uint128,
     mut route: Option<Binary>,
+    slippage_override: Option<Decimal>,
 ) -> Result<Response, ContractError> {
     assert_contract_is_not_paused(deps.storage)?;
+    assert_execute_is_not_paused(deps.storage)?;
 
     let mut bounty = get_bounty(deps.storage, trigger_id)?;
 
+    if let Some(slippage_override) = slippage_override {
+        assert_slippage_override_is_within_cap(&bounty, slippage_override)?;
+    }
+
     let mut response = Response::new()
         .add_attribute("execute_trigger", "true")
         .add_attribute("bounty_id", bounty.id)
         .add_attribute("owner", bounty.owner.clone());
 
+    let retry_count =
+        get_trigger(deps.storage, bounty.id)?.map_or(0, |trigger| trigger.retry_count);
+
     delete_trigger(deps.storage, bounty.id)?;
 
     if bounty.is_cancelled() {
@@ -53,6 +125,15 @@ pub fn execute_trigger_handler(
         });
     }
 
+    if bounty.is_paused() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "bounty with id {} is paused, and is not available for execution",
+                bounty.id
+            ),
+        });
+    }
+
     // If there is no trigger then the bounty is not valid. We need to have a way for a trigger to be set on creation and then they can update the trigger if it changes.
     if bounty.trigger.is_none() {
         return Err(ContractError::CustomError {
@@ -63,10 +144,59 @@ pub fn execute_trigger_handler(
         });
     }
 
+    record_bounty_snapshot_if_due(deps.storage, &bounty, env.block.time)?;
+
+    if bounty.is_expired(env.block.time) {
+        create_event(
+            deps.storage,
+            EventBuilder::new(bounty.id, env.block.clone(), EventData::BountyExpired {}),
+        )?;
+
+        let cancel_response = cancel_bounty(deps, &env, bounty, None, None)?;
+
+        return Ok(response
+            .add_attribute("expired", "true")
+            .add_attributes(cancel_response.attributes)
+            .add_submessages(cancel_response.messages));
+    }
+
     match bounty.trigger {
         Some(TriggerConfiguration::Time { target_time }) => {
             assert_target_time_is_in_past(env.block.time, target_time)?;
         }
+        Some(TriggerConfiguration::BlockHeight { target_height }) => {
+            assert_target_height_is_in_past(env.block.height, target_height)?;
+        }
+        Some(TriggerConfiguration::BalanceAbove {
+            ref account,
+            ref denom,
+            threshold,
+            fired,
+        }) => {
+            let balance = query_balance(deps.api, &deps.querier, denom, account)?;
+
+            if fired {
+                assert_balance_has_dropped_to_threshold(balance, threshold)?;
+
+                save_trigger(
+                    deps.storage,
+                    Trigger {
+                        bounty_id: bounty.id,
+                        configuration: TriggerConfiguration::BalanceAbove {
+                            account: account.clone(),
+                            denom: denom.clone(),
+                            threshold,
+                            fired: false,
+                        },
+                        retry_count,
+                    },
+                )?;
+
+                return Ok(response.add_attribute("execution_skipped", "balance_trigger_rearmed"));
+            }
+
+            assert_balance_is_above_threshold(balance, threshold)?;
+        }
         Some(TriggerConfiguration::Price { order_idx, .. }) => {
             let config = get_config(deps.storage)?;
 
@@ -94,6 +224,31 @@ pub fn execute_trigger_handler(
                 funds: vec![],
             }));
         }
+        Some(TriggerConfiguration::EscrowAccept {
+            target_time,
+            ref destinations,
+            ..
+        }) => {
+            assert_target_time_is_in_past(env.block.time, target_time)?;
+            let destinations = destinations.clone();
+            return execute_escrow_accept_handler(
+                deps,
+                env,
+                response,
+                bounty,
+                destinations,
+                executor,
+            );
+        }
+        Some(TriggerConfiguration::EscrowReject {
+            target_time,
+            ref destinations,
+            ..
+        }) => {
+            assert_target_time_is_in_past(env.block.time, target_time)?;
+            let destinations = destinations.clone();
+            return execute_escrow_reject_handler(deps, env, response, bounty, destinations);
+        }
         _ => {
             return Err(ContractError::CustomError {
                 val: format!(
@@ -117,12 +272,45 @@ pub fn execute_trigger_handler(
 
     let config = get_config(deps.storage)?;
 
+    let swap_adjustment = get_clamped_swap_adjustment(&deps.as_ref(), &env, &vault)?;
     let adjusted_swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault)?;
 
-    if route.is_some() {
+    let pair_exchange_address = if vault.is_direct_payout() {
+        config.exchange_contract_address.clone()
+    } else {
+        get_exchange_for_pair(
+            deps.storage,
+            config.exchange_contract_address.clone(),
+            vault.get_swap_denom(),
+            vault.target_denom.to_string(),
+        )?
+    };
+
+    let best_quote = if vault.route.is_none() && route.is_none() && !vault.is_direct_payout() {
+        Some(get_best_quote(
+            &deps.querier,
+            [
+                vec![pair_exchange_address.clone()],
+                config.exchange_adapters.clone(),
+            ]
+            .concat(),
+            adjusted_swap_amount.clone(),
+            vault.target_denom.to_string(),
+        )?)
+    } else {
+        None
+    };
+
+    let exchange_contract_address = best_quote
+        .as_ref()
+        .map_or(pair_exchange_address.clone(), |(exchange_adapter, _)| {
+            exchange_adapter.clone()
+        });
+
+    if route.is_some() && !vault.is_direct_payout() {
         let expected_receive_amount_new_route = get_expected_receive_amount(
             &deps.querier,
-            config.exchange_contract_address.clone(),
+            pair_exchange_address.clone(),
             adjusted_swap_amount.clone(),
             vault.target_denom.clone(),
             route.clone(),
@@ -136,7 +324,7 @@ pub fn execute_trigger_handler(
 
         let expected_receive_amount_old_route = get_expected_receive_amount(
             &deps.querier,
-            config.exchange_contract_address.clone(),
+            pair_exchange_address.clone(),
             adjusted_swap_amount.clone(),
             vault.target_denom.clone(),
             vault.route.clone(),
@@ -153,14 +341,18 @@ pub fn execute_trigger_handler(
         route = vault.route.clone();
     }
 
-    let twap_price = get_twap_to_now(
-        &deps.querier,
-        config.exchange_contract_address.clone(),
-        vault.get_swap_denom(),
-        vault.target_denom.clone(),
-        config.twap_period,
-        route.clone(),
-    )?;
+    let twap_price = if vault.is_direct_payout() {
+        Decimal::one()
+    } else {
+        get_twap_to_now(
+            &deps.querier,
+            exchange_contract_address.clone(),
+            vault.get_swap_denom(),
+            vault.target_denom.clone(),
+            config.twap_period,
+            route.clone(),
+        )?
+    };
 
     create_event(
         deps.storage,
@@ -168,15 +360,24 @@ pub fn execute_trigger_handler(
             vault.id,
             env.block.to_owned(),
             EventData::DcaVaultExecutionTriggered {
-                base_denom: vault.target_denom.clone(),
+                base_denom: vault.target_denom.to_string(),
                 quote_denom: vault.get_swap_denom(),
                 asset_price: twap_price,
+                slippage_override,
+                swap_adjustment: Some(swap_adjustment),
+                exchange_adapter: (exchange_contract_address != config.exchange_contract_address)
+                    .then(|| exchange_contract_address.clone()),
+                route: route.clone(),
             },
         ),
     )?;
 
     response = response.add_attribute("twap_price", twap_price.to_string());
 
+    if let Some(slippage_override) = slippage_override {
+        response = response.add_attribute("slippage_override", slippage_override.to_string());
+    }
+
     if let Some(SwapAdjustmentStrategy::RiskWeightedAverage { .. }) = vault.swap_adjustment_strategy
     {
         (vault, response) = simulate_standard_dca_execution(
@@ -197,18 +398,34 @@ pub fn execute_trigger_handler(
             },
         );
 
+    let next_target_time = get_next_target_time(
+        env.block.time,
+        vault.started_at.unwrap_or(env.block.time),
+        vault.time_interval.clone(),
+    );
+
     if should_execute_again {
         save_trigger(
             deps.storage,
             Trigger {
                 vault_id: vault.id,
-                configuration: TriggerConfiguration::Time {
-                    target_time: get_next_target_time(
-                        env.block.time,
-                        vault.started_at.unwrap_or(env.block.time),
-                        vault.time_interval.clone(),
-                    ),
+                configuration: match vault.trigger.clone() {
+                    Some(TriggerConfiguration::BalanceAbove {
+                        account,
+                        denom,
+                        threshold,
+                        ..
+                    }) => TriggerConfiguration::BalanceAbove {
+                        account,
+                        denom,
+                        threshold,
+                        fired: true,
+                    },
+                    _ => TriggerConfiguration::Time {
+                        target_time: next_target_time,
+                    },
                 },
+                retry_count: 0,
             },
         )?;
     } else {
@@ -223,7 +440,7 @@ pub fn execute_trigger_handler(
         return Ok(response.add_attribute("execution_skipped", "vault_should_not_continue"));
     }
 
-    if vault.is_inactive() {
+    if vault.is_inactive() || vault.is_completed() {
         return Ok(response.add_attribute("execution_skipped", "vault_is_inactive"));
     }
 
@@ -244,13 +461,18 @@ pub fn execute_trigger_handler(
             .add_attribute("twap_price", twap_price.to_string()));
     }
 
+    if vault.is_direct_payout() {
+
+        return disburse_direct_payout(deps, &env, response, vault, adjusted_swap_amount, executor);
+    }
+
     if vault.price_threshold_exceeded(twap_price)? {
         create_event(
             deps.storage,
             EventBuilder::new(
                 vault.id,
                 env.block,
-                EventData::DcaVaultExecutionSkipped {
+                EventData::BountyExecutionSkipped {
                     reason: ExecutionSkippedReason::PriceThresholdExceeded { price: twap_price },
                 },
             ),
@@ -261,33 +483,75 @@ pub fn execute_trigger_handler(
             .add_attribute("twap_price", twap_price.to_string()));
     };
 
-    let get_slippage_result = get_slippage(
+    let adjusted_minimum_receive_amount =
+        vault
+            .minimum_receive_amount
+            .map_or(Uint128::zero(), |minimum_receive_amount| {
+                Decimal::from_ratio(adjusted_swap_amount.amount, vault.swap_amount)
+                    * minimum_receive_amount
+            });
+
+    let expected_receive_amount_result = get_expected_receive_amount(
         &deps.querier,
-        config.exchange_contract_address.clone(),
+        exchange_contract_address.clone(),
         adjusted_swap_amount.clone(),
         vault.target_denom.clone(),
-        twap_price,
         route.clone(),
     );
 
-    match get_slippage_result {
-        Ok(slippage) => {
-            if slippage > vault.slippage_tolerance {
+    match expected_receive_amount_result {
+        Ok(expected_receive_amount) => {
+            let slippage = get_slippage_from_expected_receive_amount(
+                &adjusted_swap_amount,
+                &expected_receive_amount,
+                twap_price,
+            );
+
+            if slippage > slippage_override.unwrap_or(vault.slippage_tolerance)
+                || expected_receive_amount.amount < adjusted_minimum_receive_amount
+            {
                 create_event(
                     deps.storage,
                     EventBuilder::new(
                         vault.id,
-                        env.block,
-                        EventData::DcaVaultExecutionSkipped {
+                        env.block.clone(),
+                        EventData::BountyExecutionSkipped {
                             reason: ExecutionSkippedReason::SlippageToleranceExceeded,
                         },
                     ),
                 )?;
 
+                let retry_target_time = env
+                    .block
+                    .time
+                    .plus_seconds(config.slippage_retry_interval_seconds);
+
+                if retry_count < config.max_slippage_retries && retry_target_time < next_target_time
+                {
+                    save_trigger(
+                        deps.storage,
+                        Trigger {
+                            vault_id: vault.id,
+                            configuration: TriggerConfiguration::Time {
+                                target_time: retry_target_time,
+                            },
+                            retry_count: retry_count + 1,
+                        },
+                    )?;
+
+                    response = response
+                        .add_attribute("retry_count", (retry_count + 1).to_string())
+                        .add_attribute("retry_target_time", retry_target_time.to_string());
+                }
+
                 return Ok(response
                     .add_attribute("execution_skipped", "slippage_tolerance_exceeded")
                     .add_attribute("twap_price", twap_price.to_string())
-                    .add_attribute("slippage", slippage.to_string()));
+                    .add_attribute("slippage", slippage.to_string())
+                    .add_attribute(
+                        "expected_receive_amount",
+                        expected_receive_amount.amount.to_string(),
+                    ));
             }
         }
         Err(_) => {
@@ -307,6 +571,24 @@ pub fn execute_trigger_handler(
     }
 
     VAULT_ID_CACHE.save(deps.storage, &vault.id)?;
+    TWAP_PRICE_CACHE.save(deps.storage, &twap_price)?;
+
+    if config.simulation_mode {
+        let simulated_receive_amount = Coin::new(
+            checked_mul(adjusted_swap_amount.amount, Decimal::one() / twap_price)?.into(),
+            vault.target_denom.to_string(),
+        );
+
+        return disburse_simulated_swap(
+            deps,
+            &env,
+            response,
+            vault,
+            adjusted_swap_amount,
+            simulated_receive_amount,
+            executor,
+        );
+    }
 
     SWAP_CACHE.save(
         deps.storage,
@@ -326,23 +608,40 @@ pub fn execute_trigger_handler(
         },
     )?;
 
-    let adjusted_minimum_receive_amount =
-        vault
-            .minimum_receive_amount
-            .map_or(Uint128::zero(), |minimum_receive_amount| {
-                Decimal::from_ratio(adjusted_swap_amount.amount, vault.swap_amount)
-                    * minimum_receive_amount
-            });
+    let minimum_receive_amount = Coin {
+        amount: best_quote.as_ref().map_or(
+            adjusted_minimum_receive_amount,
+            |(_, expected_receive_amount)| {
+                max(
+                    adjusted_minimum_receive_amount,
+                    expected_receive_amount.amount
+                        * (Decimal::one() - config.auto_route_safety_margin),
+                )
+            },
+        ),
+        denom: vault.target_denom.into(),
+    };
+
+    SWAP_RETRY_CACHE.save(
+        deps.storage,
+        &SwapRetryCache {
+            swap_amount: adjusted_swap_amount.clone(),
+            minimum_receive_amount: minimum_receive_amount.clone(),
+            fallback_routes_tried: 0,
+        },
+    )?;
+
+    match executor {
+        Some(executor) => EXECUTOR_CACHE.save(deps.storage, &executor)?,
+        None => EXECUTOR_CACHE.remove(deps.storage),
+    }
 
     Ok(response.add_submessage(SubMsg::reply_always(
         into_execute_msg(
             deps.api,
-            config.exchange_contract_address.clone(),
+            exchange_contract_address,
             to_json_binary(&ExchangeExecuteMsg::Swap {
-                minimum_receive_amount: Coin {
-                    amount: adjusted_minimum_receive_amount,
-                    denom: vault.target_denom,
-                },
+                minimum_receive_amount,
                 route,
             })?,
             adjusted_swap_amount,
@@ -355,27 +654,36 @@ pub fn execute_trigger_handler(
 mod execute_trigger_tests {
     use super::*;
     use crate::constants::{
-        AFTER_SWAP_REPLY_ID, HALF_DECIMAL, ONE, ONE_MICRON, TEN, THREE_DECIMAL, TWO_MICRONS,
+        AFTER_SWAP_REPLY_ID, FAIL_SILENTLY_REPLY_ID, HALF_DECIMAL, ONE, ONE_MICRON, TEN,
+        THREE_DECIMAL, TWO_MICRONS,
     };
+    use crate::handlers::create_vault::create_bounty_handler;
     use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
-    use crate::helpers::fees::{get_automation_fee_rate, get_swap_fee_rate};
-    use crate::helpers::vault::get_swap_amount;
+    use crate::helpers::fees::{get_automation_fee_rate, get_fee_amount, get_swap_fee_rate};
     use crate::msg::ExecuteMsg;
     use crate::state::config::update_config;
+    use crate::state::denom_balances::{get_denom_balances, save_denom_balance};
+    use crate::state::destination_payouts::{add_destination_payouts, get_destination_payouts};
+    use crate::state::executor_stats::get_executor_stats;
     use crate::state::swap_adjustments::update_swap_adjustment;
-    use crate::state::triggers::delete_trigger;
+    use crate::state::triggers::{delete_trigger, get_trigger};
     use crate::state::vaults::get_vault;
     use crate::tests::helpers::{instantiate_contract, setup_vault};
-    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK, USER};
     use crate::types::config::Config;
+    use crate::types::denom::Denom;
+    use crate::types::denom_balance::DenomBalance;
     use crate::types::event::{Event, EventData, ExecutionSkippedReason};
     use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategy;
     use crate::types::position_type::PositionType;
-    use crate::types::swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy};
+    use crate::types::swap_adjustment_strategy::BaseDenom;
+    use crate::types::time_interval::TimeInterval;
     use crate::types::trigger::TriggerConfiguration;
     use crate::types::vault::{Vault, VaultStatus};
     use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{to_json_binary, Coin, Decimal, SubMsg, Uint128, WasmMsg};
+    use cosmwasm_std::{
+        to_json_binary, Addr, Attribute, BankMsg, Coin, Decimal, SubMsg, Uint128, WasmMsg,
+    };
 
     #[test]
     fn when_contract_is_paused_should_fail() {
@@ -396,7 +704,16 @@ mod execute_trigger_tests {
         )
         .unwrap();
 
-        let err = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap_err();
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
 
         assert_eq!(err.to_string(), "Error: contract is paused");
     }
@@ -418,7 +735,16 @@ mod execute_trigger_tests {
             },
         );
 
-        let err = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap_err();
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -443,7 +769,16 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap_err();
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -470,7 +805,16 @@ mod execute_trigger_tests {
 
         delete_trigger(deps.as_mut().storage, vault.id).unwrap();
 
-        let err = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap_err();
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -490,7 +834,16 @@ mod execute_trigger_tests {
 
         env.block.time = env.block.time.minus_seconds(10);
 
-        let err = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap_err();
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -499,7 +852,7 @@ mod execute_trigger_tests {
     }
 
     #[test]
-    fn should_make_scheduled_vault_active() {
+    fn when_block_trigger_is_not_ready_to_fire_should_fail() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -510,118 +863,201 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                status: VaultStatus::Scheduled,
+                trigger: Some(TriggerConfiguration::BlockHeight {
+                    target_height: env.block.height + 10,
+                }),
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
-
-        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
 
-        assert_eq!(vault.status, VaultStatus::Scheduled);
-        assert_eq!(updated_vault.status, VaultStatus::Active);
+        assert_eq!(
+            err.to_string(),
+            "Error: trigger execution height has not yet been reached"
+        );
     }
 
     #[test]
-    fn should_set_scheduled_vault_start_time() {
+    fn with_block_height_trigger_set_on_creation_executes_once_height_is_reached() {
         let mut deps = calc_mock_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &[]);
+        let mut env = mock_env();
+        let info = mock_info(USER, &[Coin::new(TEN.into(), DENOM_UKUJI)]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info);
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let vault = setup_vault(
+        let target_height = env.block.height + 10;
+
+        let response = create_bounty_handler(
             deps.as_mut(),
             env.clone(),
-            Vault {
-                status: VaultStatus::Scheduled,
-                ..Vault::default()
-            },
+            &info,
+            info.sender.clone(),
+            None,
+            None,
+            None,
+            vec![],
+            Denom::new(DENOM_UUSK).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(target_height),
+            TimeInterval::Daily,
+            None,
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            get_trigger(deps.as_ref().storage, Uint128::one())
+                .unwrap()
+                .unwrap()
+                .configuration,
+            TriggerConfiguration::BlockHeight { target_height }
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        env.block.height = target_height;
 
-        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            Uint128::one(),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
-        assert_eq!(vault.started_at, None);
-        assert_eq!(updated_vault.started_at, Some(env.block.time));
+        let vault = get_vault(deps.as_ref().storage, Uint128::one()).unwrap();
+
+        assert_eq!(vault.status, VaultStatus::Active);
+        assert_eq!(vault.started_at, Some(env.block.time));
+        assert_eq!(
+            get_trigger(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .unwrap()
+                .configuration,
+            TriggerConfiguration::Time {
+                target_time: get_next_target_time(
+                    env.block.time,
+                    env.block.time,
+                    TimeInterval::Daily
+                ),
+            }
+        );
     }
 
     #[test]
-    fn publishes_execution_triggered_event() {
+    fn when_bounty_has_expired_should_refund_balance_to_owner() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info);
 
-        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
-
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                expires_at: Some(env.block.time),
+                ..Vault::default()
+            },
+        );
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
         assert_eq!(
-            events.first().unwrap(),
-            &Event {
-                id: 1,
-                resource_id: vault.id,
-                timestamp: env.block.time,
-                block_height: env.block.height,
-                data: EventData::DcaVaultExecutionTriggered {
-                    base_denom: vault.target_denom.clone(),
-                    quote_denom: vault.get_swap_denom(),
-                    asset_price: Decimal::one()
-                }
-            }
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![vault.balance.clone()],
+            })]
         );
     }
 
     #[test]
-    fn with_price_trigger_should_withdraw_limit_order() {
+    fn when_bounty_has_expired_should_cancel_bounty() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info);
 
-        let order_idx = Uint128::new(46);
-
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                trigger: Some(TriggerConfiguration::Price {
-                    target_price: Decimal::percent(200),
-                    order_idx,
-                }),
+                expires_at: Some(env.block.time),
                 ..Vault::default()
             },
         );
 
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
-        let config = get_config(deps.as_ref().storage).unwrap();
+        assert_eq!(updated_vault.status, VaultStatus::Cancelled);
+        assert!(updated_vault.balance.amount.is_zero());
 
-        assert_eq!(
-            response.messages.first().unwrap(),
-            &SubMsg::new(WasmMsg::Execute {
-                contract_addr: config.exchange_contract_address.to_string(),
-                msg: to_json_binary(&ExchangeExecuteMsg::WithdrawOrder {
-                    order_idx,
-                    denoms: vault.denoms()
-                })
-                .unwrap(),
-                funds: vec![]
-            })
-        );
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(&Event {
+            id: 1,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::BountyExpired {},
+        }));
     }
 
     #[test]
-    fn for_non_standard_dca_should_simulate_execution() {
+    fn when_bounty_has_expired_should_refund_denom_balances_to_owner() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -632,47 +1068,45 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
-                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
+                expires_at: Some(env.block.time),
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
-
-        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
-
-        let fee_rate = get_swap_fee_rate(deps.as_mut().storage, &vault.swap_adjustment_strategy)
-            .unwrap()
-            + get_automation_fee_rate(deps.as_mut().storage, &vault).unwrap();
-
-        let received_amount_before_fee = vault.swap_amount * Decimal::percent(95);
-        let fee_amount = received_amount_before_fee * fee_rate;
-        let received_amount_after_fee = received_amount_before_fee - fee_amount;
+        save_denom_balance(
+            deps.as_mut().storage,
+            vault.id,
+            &DenomBalance {
+                balance: Coin::new(TEN.into(), "uatom"),
+                deposited_amount: Coin::new(TEN.into(), "uatom"),
+                swap_amount: ONE,
+                route: None,
+            },
+        )
+        .unwrap();
 
-        let performance_assessment_strategy =
-            updated_vault.performance_assessment_strategy.unwrap();
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
-        assert_eq!(
-            match performance_assessment_strategy.clone() {
-                PerformanceAssessmentStrategy::CompareToStandardDca { swapped_amount, .. } =>
-                    swapped_amount,
-            },
-            Coin::new(vault.swap_amount.into(), vault.get_swap_denom()),
-        );
-        assert_eq!(
-            match performance_assessment_strategy {
-                PerformanceAssessmentStrategy::CompareToStandardDca {
-                    received_amount, ..
-                } => received_amount,
-            },
-            Coin::new(received_amount_after_fee.into(), vault.target_denom)
-        );
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![Coin::new(TEN.into(), "uatom")],
+        })));
+        assert!(get_denom_balances(deps.as_ref().storage, vault.id)
+            .unwrap()
+            .is_empty());
     }
 
     #[test]
-    fn with_finished_compare_to_standard_dca_performance_assessment_should_not_simulate_execution()
-    {
+    fn when_bounty_has_expired_should_clear_terminal_bounty_state() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -683,71 +1117,90 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
-                escrowed_amount: Coin::new((TEN * Decimal::percent(5)).into(), DENOM_UUSK),
-                performance_assessment_strategy: Some(
-                    PerformanceAssessmentStrategy::CompareToStandardDca {
-                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
-                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
-                    },
-                ),
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                expires_at: Some(env.block.time),
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        add_destination_payouts(deps.as_mut().storage, vault.id, &vault.destinations, &[ONE])
+            .unwrap();
 
-        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
         assert_eq!(
-            updated_vault.swap_adjustment_strategy.unwrap(),
-            vault.swap_adjustment_strategy.unwrap()
+            get_destination_payouts(deps.as_ref().storage, vault.id, &vault.destinations).unwrap(),
+            vec![Uint128::zero()]
         );
+        assert!(get_trigger(deps.as_ref().storage, vault.id)
+            .unwrap()
+            .is_none());
     }
 
     #[test]
-    fn with_swap_adjusted_to_zero_should_publish_execution_skipped_event() {
+    fn when_bounty_with_price_trigger_has_expired_should_withdraw_limit_order() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info);
 
+        let order_idx = Uint128::new(28);
+
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
-                    base_receive_amount: ONE,
-                    multiplier: Decimal::percent(500),
-                    increase_only: false,
+                expires_at: Some(env.block.time),
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    order_idx,
                 }),
                 ..Vault::default()
             },
         );
 
-        deps.querier.update_fin_price(&THREE_DECIMAL);
-
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let config = get_config(deps.as_ref().storage).unwrap();
 
-        assert!(events.contains(&Event {
-            id: 2,
-            resource_id: vault.id,
-            timestamp: env.block.time,
-            block_height: env.block.height,
-            data: EventData::DcaVaultExecutionSkipped {
-                reason: ExecutionSkippedReason::SwapAmountAdjustedToZero
-            }
-        }));
+        assert!(response.messages.contains(&SubMsg::reply_on_error(
+            WasmMsg::Execute {
+                contract_addr: config.exchange_contract_address.to_string(),
+                msg: to_json_binary(&ExchangeExecuteMsg::WithdrawOrder {
+                    order_idx,
+                    denoms: vault.denoms(),
+                })
+                .unwrap(),
+                funds: vec![],
+            },
+            FAIL_SILENTLY_REPLY_ID,
+        )));
+        assert_eq!(
+            get_vault(deps.as_ref().storage, vault.id).unwrap().status,
+            VaultStatus::Cancelled
+        );
     }
 
     #[test]
-    fn with_swap_adjusted_to_zero_should_not_send_swap_message() {
+    fn when_bounty_is_paused_should_fail() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -758,164 +1211,210 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
-                    base_receive_amount: ONE,
-                    multiplier: Decimal::percent(500),
-                    increase_only: false,
+                status: VaultStatus::Paused,
+                trigger: Some(TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked(USER),
+                    denom: DENOM_UKUJI.to_string(),
+                    threshold: TEN,
+                    fired: false,
                 }),
                 ..Vault::default()
             },
         );
 
-        deps.querier.update_fin_price(&THREE_DECIMAL);
-
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
 
-        assert!(response.messages.is_empty());
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: bounty with id {} is paused, and is not available for execution",
+                vault.id
+            )
+        );
     }
 
     #[test]
-    fn with_rwa_swap_adjustment_strategy_should_adjust_swap_amount() {
+    fn when_balance_trigger_threshold_is_not_exceeded_should_fail() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
+        deps.querier
+            .update_balance(USER, vec![Coin::new(TEN.into(), DENOM_UKUJI)]);
+
         instantiate_contract(deps.as_mut(), env.clone(), info);
 
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
-                escrowed_amount: Coin::new(0, DENOM_UUSK),
-                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                trigger: Some(TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked(USER),
+                    denom: DENOM_UKUJI.to_string(),
+                    threshold: TEN,
+                    fired: false,
+                }),
                 ..Vault::default()
             },
         );
 
-        let swap_adjustment = Decimal::percent(150);
-
-        [PositionType::Enter, PositionType::Exit]
-            .into_iter()
-            .for_each(|position_type| {
-                update_swap_adjustment(
-                    deps.as_mut().storage,
-                    SwapAdjustmentStrategy::RiskWeightedAverage {
-                        model_id: 30,
-                        base_denom: BaseDenom::Bitcoin,
-                        position_type,
-                    },
-                    swap_adjustment,
-                    env.block.time,
-                )
-                .unwrap();
-            });
-
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
-
-        let config = get_config(deps.as_ref().storage).unwrap();
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
 
         assert_eq!(
-            response.messages.first().unwrap(),
-            &SubMsg::reply_always(
-                WasmMsg::Execute {
-                    contract_addr: config.exchange_contract_address.to_string(),
-                    msg: to_json_binary(&ExchangeExecuteMsg::Swap {
-                        minimum_receive_amount: Coin {
-                            amount: vault.minimum_receive_amount.unwrap_or(Uint128::zero()),
-                            denom: vault.target_denom.clone(),
-                        },
-                        route: vault.route.clone()
-                    })
-                    .unwrap(),
-                    funds: vec![Coin::new(
-                        (vault.swap_amount * swap_adjustment).into(),
-                        vault.get_swap_denom()
-                    )]
-                },
-                AFTER_SWAP_REPLY_ID,
+            err.to_string(),
+            format!(
+                "Error: watched balance of {}{} has not yet exceeded the threshold of {}{}",
+                TEN, DENOM_UKUJI, TEN, DENOM_UKUJI
             )
-        )
+        );
     }
 
     #[test]
-    fn with_rwa_swap_adjustment_strategy_and_exceeded_slippage_tolerance_should_simulate_skipped_execution(
-    ) {
+    fn with_balance_trigger_should_not_fire_again_until_balance_drops_back() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
+        deps.querier
+            .update_balance(USER, vec![Coin::new((TEN + ONE).into(), DENOM_UKUJI)]);
+
         instantiate_contract(deps.as_mut(), env.clone(), info);
 
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                slippage_tolerance: Decimal::percent(1),
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                trigger: Some(TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked(USER),
+                    denom: DENOM_UKUJI.to_string(),
+                    threshold: TEN,
+                    fired: false,
+                }),
                 ..Vault::default()
             },
         );
 
-        deps.querier.update_fin_price(&HALF_DECIMAL);
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        assert_eq!(
+            get_trigger(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .unwrap()
+                .configuration,
+            TriggerConfiguration::BalanceAbove {
+                account: Addr::unchecked(USER),
+                denom: DENOM_UKUJI.to_string(),
+                threshold: TEN,
+                fired: true,
+            }
+        );
 
-        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
 
         assert_eq!(
-            updated_vault.swap_adjustment_strategy.unwrap(),
-            SwapAdjustmentStrategy::default()
+            err.to_string(),
+            format!(
+                "Error: watched balance of {}{} has not dropped back to the threshold of {}{} since the trigger last fired",
+                TEN + ONE,
+                DENOM_UKUJI,
+                TEN,
+                DENOM_UKUJI
+            )
         );
     }
 
     #[test]
-    fn with_rwa_swap_adjustment_strategy_and_exceeded_price_threshold_should_publish_execution_skipped_event(
-    ) {
+    fn with_fired_balance_trigger_should_rearm_once_balance_drops_back() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
+        deps.querier
+            .update_balance(USER, vec![Coin::new(TEN.into(), DENOM_UKUJI)]);
+
         instantiate_contract(deps.as_mut(), env.clone(), info);
 
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                swap_amount: ONE,
-                minimum_receive_amount: Some(ONE + ONE),
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
-                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
+                trigger: Some(TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked(USER),
+                    denom: DENOM_UKUJI.to_string(),
+                    threshold: TEN,
+                    fired: true,
+                }),
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
-
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
+        assert!(response.attributes.contains(&Attribute::new(
+            "execution_skipped",
+            "balance_trigger_rearmed"
+        )));
         assert_eq!(
-            events.get(1).unwrap(),
-            &Event {
-                id: 2,
-                resource_id: vault.id,
-                timestamp: env.block.time,
-                block_height: env.block.height,
-                data: EventData::SimulatedDcaVaultExecutionSkipped {
-                    reason: ExecutionSkippedReason::PriceThresholdExceeded {
-                        price: Decimal::one()
-                    },
-                }
+            get_trigger(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .unwrap()
+                .configuration,
+            TriggerConfiguration::BalanceAbove {
+                account: Addr::unchecked(USER),
+                denom: DENOM_UKUJI.to_string(),
+                threshold: TEN,
+                fired: false,
             }
         );
     }
 
     #[test]
-    fn with_rwa_swap_adjustment_strategy_and_exceeded_slippage_tolerance_should_publish_execution_skipped_event(
-    ) {
+    fn should_make_scheduled_vault_active() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -926,34 +1425,30 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                slippage_tolerance: Decimal::percent(1),
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
-                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
+                status: VaultStatus::Scheduled,
                 ..Vault::default()
             },
         );
 
-        deps.querier.update_fin_price(&HALF_DECIMAL);
-
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
-        assert!(events.contains(&Event {
-            id: 2,
-            resource_id: vault.id,
-            timestamp: env.block.time,
-            block_height: env.block.height,
-            data: EventData::SimulatedDcaVaultExecutionSkipped {
-                reason: ExecutionSkippedReason::SlippageToleranceExceeded
-            }
-        }));
+        assert_eq!(vault.status, VaultStatus::Scheduled);
+        assert_eq!(updated_vault.status, VaultStatus::Active);
     }
 
     #[test]
-    fn for_inactive_vault_with_active_performance_assessment_should_simulate_execution() {
+    fn should_set_scheduled_vault_start_time() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -964,25 +1459,159 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                status: VaultStatus::Inactive,
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
-                balance: Coin::new(TEN.into(), DENOM_UKUJI),
-                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
-                escrow_level: Decimal::percent(5),
+                status: VaultStatus::Scheduled,
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
-        let fee_rate = get_swap_fee_rate(deps.as_ref().storage, &vault.swap_adjustment_strategy)
-            .unwrap()
-            + get_automation_fee_rate(deps.as_ref().storage, &vault).unwrap();
+        assert_eq!(vault.started_at, None);
+        assert_eq!(updated_vault.started_at, Some(env.block.time));
+    }
 
-        let received_amount_before_fee = vault.swap_amount * Decimal::percent(95);
-        let fee_amount = received_amount_before_fee * fee_rate;
+    #[test]
+    fn publishes_execution_triggered_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            events.first().unwrap(),
+            &Event {
+                id: 1,
+                resource_id: vault.id,
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                data: EventData::DcaVaultExecutionTriggered {
+                    base_denom: vault.target_denom.to_string(),
+                    quote_denom: vault.get_swap_denom(),
+                    asset_price: Decimal::one(),
+                    slippage_override: None,
+                    swap_adjustment: Some(Decimal::one()),
+                    exchange_adapter: None,
+                    route: vault.route.clone(),
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn with_price_trigger_should_withdraw_limit_order() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let order_idx = Uint128::new(46);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    order_idx,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::new(WasmMsg::Execute {
+                contract_addr: config.exchange_contract_address.to_string(),
+                msg: to_json_binary(&ExchangeExecuteMsg::WithdrawOrder {
+                    order_idx,
+                    denoms: vault.denoms()
+                })
+                .unwrap(),
+                funds: vec![]
+            })
+        );
+    }
+
+    #[test]
+    fn for_non_standard_dca_should_simulate_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        let fee_rate = get_swap_fee_rate(deps.as_mut().storage, &vault.swap_adjustment_strategy)
+            .unwrap()
+            + get_automation_fee_rate(deps.as_mut().storage, &vault).unwrap();
+
+        let received_amount_before_fee = vault.swap_amount * Decimal::percent(95);
+        let fee_amount = received_amount_before_fee * fee_rate;
         let received_amount_after_fee = received_amount_before_fee - fee_amount;
 
         let performance_assessment_strategy =
@@ -1006,7 +1635,8 @@ mod execute_trigger_tests {
     }
 
     #[test]
-    fn for_inactive_vault_with_finished_performance_assessment_should_disburse_escrow() {
+    fn with_finished_compare_to_standard_dca_performance_assessment_should_not_simulate_execution()
+    {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -1017,11 +1647,8 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                status: VaultStatus::Inactive,
-                balance: Coin::new(0, DENOM_UKUJI),
-                escrow_level: Decimal::percent(5),
                 deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
-                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                escrowed_amount: Coin::new((TEN * Decimal::percent(5)).into(), DENOM_UUSK),
                 performance_assessment_strategy: Some(
                     PerformanceAssessmentStrategy::CompareToStandardDca {
                         swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
@@ -1033,19 +1660,117 @@ mod execute_trigger_tests {
             },
         );
 
-        deps.querier.update_fin_price(&HALF_DECIMAL);
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
-        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
-        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
-            contract_addr: env.contract.address.to_string(),
-            msg: to_json_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id }).unwrap(),
-            funds: vec![],
-        })));
+        assert_eq!(
+            updated_vault.swap_adjustment_strategy.unwrap(),
+            vault.swap_adjustment_strategy.unwrap()
+        );
     }
 
     #[test]
-    fn for_inactive_vault_with_unfinished_performance_assessment_should_not_disburse_escrow() {
+    fn with_swap_adjusted_to_zero_should_publish_execution_skipped_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(500),
+                    increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&THREE_DECIMAL);
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::SwapAmountAdjustedToZero
+            }
+        }));
+    }
+
+    #[test]
+    fn with_swap_adjusted_to_zero_should_not_send_swap_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(500),
+                    increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&THREE_DECIMAL);
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+    }
+
+    #[test]
+    fn with_rwa_swap_adjustment_strategy_should_adjust_swap_amount() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -1056,123 +1781,1206 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                status: VaultStatus::Inactive,
-                balance: Coin::new(0, DENOM_UKUJI),
-                escrow_level: Decimal::percent(5),
                 deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
-                escrowed_amount: Coin::new(ONE_MICRON.into(), DENOM_UUSK),
-                performance_assessment_strategy: Some(
-                    PerformanceAssessmentStrategy::CompareToStandardDca {
-                        swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
-                        received_amount: Coin::new(ONE.into(), DENOM_UUSK),
-                    },
-                ),
+                escrowed_amount: Coin::new(0, DENOM_UUSK),
+                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
                 swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
                 ..Vault::default()
             },
         );
 
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        let swap_adjustment = Decimal::percent(150);
+
+        [PositionType::Enter, PositionType::Exit]
+            .into_iter()
+            .for_each(|position_type| {
+                update_swap_adjustment(
+                    deps.as_mut().storage,
+                    SwapAdjustmentStrategy::RiskWeightedAverage {
+                        model_id: 30,
+                        base_denom: BaseDenom::Bitcoin,
+                        position_type,
+                    },
+                    swap_adjustment,
+                    env.block.time,
+                )
+                .unwrap();
+            });
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: config.exchange_contract_address.to_string(),
+                    msg: to_json_binary(&ExchangeExecuteMsg::Swap {
+                        minimum_receive_amount: Coin {
+                            amount: vault.minimum_receive_amount.unwrap_or(Uint128::zero()),
+                            denom: vault.target_denom.to_string(),
+                        },
+                        route: vault.route.clone()
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(
+                        (vault.swap_amount * swap_adjustment).into(),
+                        vault.get_swap_denom()
+                    )]
+                },
+                AFTER_SWAP_REPLY_ID,
+            )
+        )
+    }
+
+    #[test]
+    fn with_rwa_swap_adjustment_strategy_and_exceeded_slippage_tolerance_should_simulate_skipped_execution(
+    ) {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                slippage_tolerance: Decimal::percent(1),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&HALF_DECIMAL);
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.swap_adjustment_strategy.unwrap(),
+            SwapAdjustmentStrategy::default()
+        );
+    }
+
+    #[test]
+    fn with_rwa_swap_adjustment_strategy_and_exceeded_price_threshold_should_publish_execution_skipped_event(
+    ) {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: ONE,
+                minimum_receive_amount: Some(ONE + ONE),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            events.get(1).unwrap(),
+            &Event {
+                id: 2,
+                resource_id: vault.id,
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                data: EventData::SimulatedDcaVaultExecutionSkipped {
+                    reason: ExecutionSkippedReason::PriceThresholdExceeded {
+                        price: Decimal::one()
+                    },
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn with_rwa_swap_adjustment_strategy_and_exceeded_slippage_tolerance_should_publish_execution_skipped_event(
+    ) {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                slippage_tolerance: Decimal::percent(1),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&HALF_DECIMAL);
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::SimulatedDcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::SlippageToleranceExceeded
+            }
+        }));
+    }
+
+    #[test]
+    fn with_expected_receive_amount_below_minimum_receive_amount_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                slippage_tolerance: Decimal::percent(100),
+                minimum_receive_amount: Some(Vault::default().swap_amount),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(response.attributes.iter().any(|attribute| attribute.key
+            == "execution_skipped"
+            && attribute.value == "slippage_tolerance_exceeded"));
+        assert!(response.messages.is_empty());
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::BountyExecutionSkipped {
+                reason: ExecutionSkippedReason::SlippageToleranceExceeded
+            }));
+        assert!(get_trigger(deps.as_ref().storage, vault.id)
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn with_exceeded_slippage_tolerance_should_reschedule_trigger_at_retry_interval() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                slippage_tolerance: Decimal::percent(100),
+                minimum_receive_amount: Some(Vault::default().swap_amount),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert!(response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "retry_count" && attribute.value == "1"));
+        assert_eq!(
+            get_trigger(deps.as_ref().storage, vault.id).unwrap(),
+            Some(Trigger {
+                vault_id: vault.id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: env
+                        .block
+                        .time
+                        .plus_seconds(config.slippage_retry_interval_seconds),
+                },
+                retry_count: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn with_exceeded_slippage_tolerance_and_max_retries_should_reschedule_trigger_at_time_interval(
+    ) {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                slippage_tolerance: Decimal::percent(100),
+                minimum_receive_amount: Some(Vault::default().swap_amount),
+                ..Vault::default()
+            },
+        );
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        save_trigger(
+            deps.as_mut().storage,
+            Trigger {
+                vault_id: vault.id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: env.block.time,
+                },
+                retry_count: config.max_slippage_retries,
+            },
+        )
+        .unwrap();
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "retry_count"));
+        assert_eq!(
+            get_trigger(deps.as_ref().storage, vault.id).unwrap(),
+            Some(Trigger {
+                vault_id: vault.id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: get_next_target_time(
+                        env.block.time,
+                        vault.started_at.unwrap_or(env.block.time),
+                        vault.time_interval,
+                    ),
+                },
+                retry_count: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn with_slippage_override_and_no_cap_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            Some(Decimal::percent(5)),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: bounty with id {} does not permit slippage overrides",
+                vault.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_slippage_override_above_cap_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                executor_slippage_override_cap: Some(Decimal::percent(5)),
+                ..Vault::default()
+            },
+        );
+
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            Some(Decimal::percent(6)),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: slippage override 0.06 exceeds the cap of 0.05 for bounty with id {}",
+                vault.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_slippage_override_within_cap_should_not_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                slippage_tolerance: Decimal::percent(1),
+                executor_slippage_override_cap: Some(Decimal::percent(100)),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&HALF_DECIMAL);
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            Some(Decimal::percent(100)),
+            None,
+        )
+        .unwrap();
+
+        assert!(!response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "execution_skipped"));
+        assert!(response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "slippage_override" && attribute.value == "1"));
+    }
+
+    #[test]
+    fn for_inactive_vault_with_active_performance_assessment_should_simulate_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                balance: Coin::new(TEN.into(), DENOM_UKUJI),
+                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
+                escrow_level: Decimal::percent(5),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        let fee_rate = get_swap_fee_rate(deps.as_ref().storage, &vault.swap_adjustment_strategy)
+            .unwrap()
+            + get_automation_fee_rate(deps.as_ref().storage, &vault).unwrap();
+
+        let received_amount_before_fee = vault.swap_amount * Decimal::percent(95);
+        let fee_amount = received_amount_before_fee * fee_rate;
+        let received_amount_after_fee = received_amount_before_fee - fee_amount;
+
+        let performance_assessment_strategy =
+            updated_vault.performance_assessment_strategy.unwrap();
+
+        assert_eq!(
+            match performance_assessment_strategy.clone() {
+                PerformanceAssessmentStrategy::CompareToStandardDca { swapped_amount, .. } =>
+                    swapped_amount,
+            },
+            Coin::new(vault.swap_amount.into(), vault.get_swap_denom()),
+        );
+        assert_eq!(
+            match performance_assessment_strategy {
+                PerformanceAssessmentStrategy::CompareToStandardDca {
+                    received_amount, ..
+                } => received_amount,
+            },
+            Coin::new(received_amount_after_fee.into(), vault.target_denom)
+        );
+    }
+
+    #[test]
+    fn for_inactive_vault_with_finished_performance_assessment_should_disburse_escrow() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                balance: Coin::new(0, DENOM_UKUJI),
+                escrow_level: Decimal::percent(5),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&HALF_DECIMAL);
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id }).unwrap(),
+            funds: vec![],
+        })));
+    }
+
+    #[test]
+    fn for_inactive_vault_with_unfinished_performance_assessment_should_not_disburse_escrow() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                balance: Coin::new(0, DENOM_UKUJI),
+                escrow_level: Decimal::percent(5),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(ONE_MICRON.into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+    }
+
+    #[test]
+    fn for_active_vault_should_create_a_new_trigger() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        let old_target_time = match vault.trigger.unwrap() {
+            TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("wrong trigger type"),
+        };
+
+        let new_target_time = match updated_vault.trigger.unwrap() {
+            TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("wrong trigger type"),
+        };
+
+        assert_eq!(old_target_time.seconds(), env.block.time.seconds());
+        assert_eq!(
+            new_target_time.seconds(),
+            env.block.time.plus_seconds(24 * 60 * 60).seconds()
+        );
+    }
+
+    #[test]
+    fn for_scheduled_vault_should_create_a_new_trigger() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        let old_target_time = match vault.trigger.unwrap() {
+            TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("wrong trigger type"),
+        };
+
+        let new_target_time = match updated_vault.trigger.unwrap() {
+            TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("wrong trigger type"),
+        };
+
+        assert_eq!(old_target_time.seconds(), env.block.time.seconds());
+        assert_eq!(
+            new_target_time.seconds(),
+            env.block.time.plus_seconds(24 * 60 * 60).seconds()
+        );
+    }
+
+    #[test]
+    fn for_inactive_vault_should_not_create_a_new_trigger() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert!(vault.trigger.is_some());
+        assert!(updated_vault.trigger.is_none(),);
+    }
+
+    #[test]
+    fn for_inactive_vault_with_active_performance_assessment_should_create_a_new_trigger() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                escrow_level: Decimal::percent(5),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(ONE_MICRON.into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        let old_target_time = match vault.trigger.unwrap() {
+            TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("wrong trigger type"),
+        };
+
+        let new_target_time = match updated_vault.trigger.unwrap() {
+            TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("wrong trigger type"),
+        };
+
+        assert_eq!(old_target_time.seconds(), env.block.time.seconds());
+        assert_eq!(
+            new_target_time.seconds(),
+            env.block.time.plus_seconds(24 * 60 * 60).seconds()
+        );
+    }
+
+    #[test]
+    fn for_inactive_vault_with_finished_performance_assessment_should_not_create_a_new_trigger() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                escrow_level: Decimal::percent(5),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new((TEN * Decimal::percent(5)).into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert!(vault.trigger.is_some());
+        assert!(updated_vault.trigger.is_none());
+    }
+
+    #[test]
+    fn should_create_swap_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: config.exchange_contract_address.to_string(),
+                    msg: to_json_binary(&ExchangeExecuteMsg::Swap {
+                        minimum_receive_amount: Coin {
+                            amount: vault.minimum_receive_amount.unwrap_or(Uint128::zero()),
+                            denom: vault.target_denom.to_string(),
+                        },
+                        route: vault.route.clone()
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(vault.swap_amount.into(), vault.get_swap_denom())]
+                },
+                AFTER_SWAP_REPLY_ID,
+            )
+        )
+    }
+
+    #[test]
+    fn should_cache_sender_as_executor() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info("keeper", &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            EXECUTOR_CACHE.load(deps.as_ref().storage).unwrap(),
+            Addr::unchecked("keeper")
+        );
+    }
+
+    #[test]
+    fn when_triggered_by_contract_should_not_cache_executor() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(env.contract.address.as_ref(), &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(EXECUTOR_CACHE
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn should_record_executed_trigger_for_executor() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info("keeper", &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let stats = get_executor_stats(deps.as_ref().storage, &Addr::unchecked("keeper")).unwrap();
+
+        assert_eq!(stats.triggers_executed, 1);
+        assert_eq!(stats.executions_skipped, 0);
+    }
+
+    #[test]
+    fn when_batched_should_record_executed_trigger_for_original_executor() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(env.contract.address.as_ref(), &[]),
+            vault.id,
+            None,
+            None,
+            Some(Addr::unchecked("keeper")),
+        )
+        .unwrap();
+
+        let stats = get_executor_stats(deps.as_ref().storage, &Addr::unchecked("keeper")).unwrap();
+
+        assert_eq!(stats.triggers_executed, 1);
+        assert_eq!(
+            EXECUTOR_CACHE.load(deps.as_ref().storage).unwrap(),
+            Addr::unchecked("keeper")
+        );
+    }
+
+    #[test]
+    fn when_external_sender_sets_on_behalf_of_should_fail() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info("keeper", &[]),
+            vault.id,
+            None,
+            None,
+            Some(Addr::unchecked("someone-else")),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn should_record_skipped_execution_for_executor() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                slippage_tolerance: Decimal::percent(100),
+                minimum_receive_amount: Some(Vault::default().swap_amount),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info("keeper", &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let stats = get_executor_stats(deps.as_ref().storage, &Addr::unchecked("keeper")).unwrap();
 
-        assert!(response.messages.is_empty());
+        assert_eq!(stats.triggers_executed, 0);
+        assert_eq!(stats.executions_skipped, 1);
     }
 
     #[test]
-    fn for_active_vault_should_create_a_new_trigger() {
+    fn in_simulation_mode_should_not_create_swap_message() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info);
 
-        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
-
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
-
-        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                simulation_mode: true,
+                ..Config::default()
+            },
+        )
+        .unwrap();
 
-        let old_target_time = match vault.trigger.unwrap() {
-            TriggerConfiguration::Time { target_time } => target_time,
-            _ => panic!("wrong trigger type"),
-        };
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
 
-        let new_target_time = match updated_vault.trigger.unwrap() {
-            TriggerConfiguration::Time { target_time } => target_time,
-            _ => panic!("wrong trigger type"),
-        };
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
-        assert_eq!(old_target_time.seconds(), env.block.time.seconds());
-        assert_eq!(
-            new_target_time.seconds(),
-            env.block.time.plus_seconds(24 * 60 * 60).seconds()
-        );
+        assert!(!response
+            .messages
+            .iter()
+            .any(|message| message.id == AFTER_SWAP_REPLY_ID));
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("simulated_swap", "true")));
     }
 
     #[test]
-    fn for_scheduled_vault_should_create_a_new_trigger() {
+    fn in_simulation_mode_should_account_for_swap_at_twap_price() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info);
 
-        let vault = setup_vault(
-            deps.as_mut(),
-            env.clone(),
-            Vault {
-                status: VaultStatus::Scheduled,
-                ..Vault::default()
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                simulation_mode: true,
+                ..Config::default()
             },
-        );
+        )
+        .unwrap();
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
 
-        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
-        let old_target_time = match vault.trigger.unwrap() {
-            TriggerConfiguration::Time { target_time } => target_time,
-            _ => panic!("wrong trigger type"),
-        };
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
-        let new_target_time = match updated_vault.trigger.unwrap() {
-            TriggerConfiguration::Time { target_time } => target_time,
-            _ => panic!("wrong trigger type"),
-        };
+        let config = get_config(deps.as_ref().storage).unwrap();
+        let swap_fee_rate =
+            get_swap_fee_rate(deps.as_ref().storage, &vault.target_denom, &None).unwrap();
+        let automation_fee_rate = get_automation_fee_rate(deps.as_ref().storage, &vault).unwrap();
+        let swap_fee = get_fee_amount(&config, vault.swap_amount, swap_fee_rate).unwrap();
+        let automation_fee =
+            get_fee_amount(&config, vault.swap_amount - swap_fee, automation_fee_rate).unwrap();
+        let received_amount_after_fees = vault.swap_amount - swap_fee - automation_fee;
 
-        assert_eq!(old_target_time.seconds(), env.block.time.seconds());
         assert_eq!(
-            new_target_time.seconds(),
-            env.block.time.plus_seconds(24 * 60 * 60).seconds()
+            updated_vault.balance.amount,
+            vault.balance.amount - vault.swap_amount
+        );
+        assert_eq!(
+            updated_vault.swapped_amount.amount,
+            vault.swapped_amount.amount + vault.swap_amount
+        );
+        assert_eq!(
+            updated_vault.received_amount.amount,
+            vault.received_amount.amount + received_amount_after_fees
         );
     }
 
     #[test]
-    fn for_inactive_vault_should_not_create_a_new_trigger() {
+    fn in_simulation_mode_should_escrow_and_disburse_simulated_receive_amount() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
         instantiate_contract(deps.as_mut(), env.clone(), info);
 
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                simulation_mode: true,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
-                status: VaultStatus::Inactive,
+                escrow_level: Decimal::percent(10),
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
-        assert!(vault.trigger.is_some());
-        assert!(updated_vault.trigger.is_none(),);
+        assert_eq!(
+            updated_vault.escrowed_amount.amount,
+            updated_vault.received_amount.amount * Decimal::percent(10)
+        );
+        assert_ne!(
+            get_destination_payouts(deps.as_ref().storage, vault.id, &vault.destinations).unwrap(),
+            vec![Uint128::zero()]
+        );
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("disburse_funds", "true")));
     }
 
     #[test]
-    fn for_inactive_vault_with_active_performance_assessment_should_create_a_new_trigger() {
+    fn with_target_denom_matching_swap_denom_should_pay_out_without_swapping() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -1183,44 +2991,39 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                status: VaultStatus::Inactive,
-                escrow_level: Decimal::percent(5),
-                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
-                escrowed_amount: Coin::new(ONE_MICRON.into(), DENOM_UUSK),
-                performance_assessment_strategy: Some(
-                    PerformanceAssessmentStrategy::CompareToStandardDca {
-                        swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
-                        received_amount: Coin::new(ONE.into(), DENOM_UUSK),
-                    },
-                ),
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                target_denom: Denom::new(DENOM_UKUJI).unwrap(),
+                received_amount: Coin::new(0, DENOM_UKUJI),
+                escrowed_amount: Coin::new(0, DENOM_UKUJI),
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
-        let old_target_time = match vault.trigger.unwrap() {
-            TriggerConfiguration::Time { target_time } => target_time,
-            _ => panic!("wrong trigger type"),
-        };
-
-        let new_target_time = match updated_vault.trigger.unwrap() {
-            TriggerConfiguration::Time { target_time } => target_time,
-            _ => panic!("wrong trigger type"),
-        };
-
-        assert_eq!(old_target_time.seconds(), env.block.time.seconds());
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("direct_payout", "true")));
+        assert!(!response.messages.iter().any(|message| message.id == AFTER_SWAP_REPLY_ID));
         assert_eq!(
-            new_target_time.seconds(),
-            env.block.time.plus_seconds(24 * 60 * 60).seconds()
+            updated_vault.balance.amount,
+            vault.balance.amount - vault.swap_amount
         );
+        assert_eq!(updated_vault.received_amount.amount, vault.swap_amount);
     }
 
     #[test]
-    fn for_inactive_vault_with_finished_performance_assessment_should_not_create_a_new_trigger() {
+    fn should_create_reduced_swap_message_when_balance_is_low() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -1231,40 +3034,22 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                status: VaultStatus::Inactive,
-                escrow_level: Decimal::percent(5),
-                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
-                escrowed_amount: Coin::new((TEN * Decimal::percent(5)).into(), DENOM_UUSK),
-                performance_assessment_strategy: Some(
-                    PerformanceAssessmentStrategy::CompareToStandardDca {
-                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
-                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
-                    },
-                ),
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                balance: Coin::new((ONE / TWO_MICRONS).into(), DENOM_UKUJI),
+                swap_amount: ONE,
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
-
-        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
-
-        assert!(vault.trigger.is_some());
-        assert!(updated_vault.trigger.is_none());
-    }
-
-    #[test]
-    fn should_create_swap_message() {
-        let mut deps = calc_mock_dependencies();
-        let env = mock_env();
-        let info = mock_info(ADMIN, &[]);
-
-        instantiate_contract(deps.as_mut(), env.clone(), info);
-
-        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
-
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
@@ -1276,20 +3061,20 @@ mod execute_trigger_tests {
                     msg: to_json_binary(&ExchangeExecuteMsg::Swap {
                         minimum_receive_amount: Coin {
                             amount: vault.minimum_receive_amount.unwrap_or(Uint128::zero()),
-                            denom: vault.target_denom.clone(),
+                            denom: vault.target_denom.to_string(),
                         },
-                        route: vault.route.clone()
+                        route: vault.route
                     })
                     .unwrap(),
-                    funds: vec![Coin::new(vault.swap_amount.into(), vault.get_swap_denom())]
+                    funds: vec![vault.balance]
                 },
                 AFTER_SWAP_REPLY_ID,
             )
-        )
+        );
     }
 
     #[test]
-    fn should_create_reduced_swap_message_when_balance_is_low() {
+    fn should_create_swap_message_with_target_receive_amount_when_slippage_tolerance_set() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -1300,13 +3085,21 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                balance: Coin::new((ONE / TWO_MICRONS).into(), DENOM_UKUJI),
-                swap_amount: ONE,
+                slippage_tolerance: Decimal::percent(6),
                 ..Vault::default()
             },
         );
 
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
@@ -1318,12 +3111,12 @@ mod execute_trigger_tests {
                     msg: to_json_binary(&ExchangeExecuteMsg::Swap {
                         minimum_receive_amount: Coin {
                             amount: vault.minimum_receive_amount.unwrap_or(Uint128::zero()),
-                            denom: vault.target_denom.clone(),
+                            denom: vault.target_denom.to_string(),
                         },
-                        route: vault.route
+                        route: vault.route.clone()
                     })
                     .unwrap(),
-                    funds: vec![vault.balance]
+                    funds: vec![get_swap_amount(&deps.as_ref(), &env, &vault).unwrap()]
                 },
                 AFTER_SWAP_REPLY_ID,
             )
@@ -1331,7 +3124,7 @@ mod execute_trigger_tests {
     }
 
     #[test]
-    fn should_create_swap_message_with_target_receive_amount_when_slippage_tolerance_set() {
+    fn should_skip_execution_if_price_threshold_exceeded() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -1342,37 +3135,45 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                slippage_tolerance: Decimal::percent(6),
+                swap_amount: ONE,
+                minimum_receive_amount: Some(ONE + ONE),
                 ..Vault::default()
             },
         );
 
-        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
-        let config = get_config(deps.as_ref().storage).unwrap();
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
 
         assert_eq!(
-            response.messages.first().unwrap(),
-            &SubMsg::reply_always(
-                WasmMsg::Execute {
-                    contract_addr: config.exchange_contract_address.to_string(),
-                    msg: to_json_binary(&ExchangeExecuteMsg::Swap {
-                        minimum_receive_amount: Coin {
-                            amount: vault.minimum_receive_amount.unwrap_or(Uint128::zero()),
-                            denom: vault.target_denom.clone(),
-                        },
-                        route: vault.route.clone()
-                    })
-                    .unwrap(),
-                    funds: vec![get_swap_amount(&deps.as_ref(), &env, &vault).unwrap()]
-                },
-                AFTER_SWAP_REPLY_ID,
-            )
+            events.get(1).unwrap(),
+            &Event {
+                id: 2,
+                resource_id: vault.id,
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                data: EventData::BountyExecutionSkipped {
+                    reason: ExecutionSkippedReason::PriceThresholdExceeded {
+                        price: Decimal::one()
+                    }
+                }
+            }
         );
     }
 
     #[test]
-    fn should_skip_execution_if_price_threshold_exceeded() {
+    fn should_skip_execution_if_maximum_price_exceeded() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -1384,12 +3185,23 @@ mod execute_trigger_tests {
             env.clone(),
             Vault {
                 swap_amount: ONE,
-                minimum_receive_amount: Some(ONE + ONE),
+                maximum_price: Some(Decimal::percent(50)),
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
 
         let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
             .unwrap()
@@ -1402,7 +3214,7 @@ mod execute_trigger_tests {
                 resource_id: vault.id,
                 timestamp: env.block.time,
                 block_height: env.block.height,
-                data: EventData::DcaVaultExecutionSkipped {
+                data: EventData::BountyExecutionSkipped {
                     reason: ExecutionSkippedReason::PriceThresholdExceeded {
                         price: Decimal::one()
                     }
@@ -1411,6 +3223,41 @@ mod execute_trigger_tests {
         );
     }
 
+    #[test]
+    fn should_skip_execution_if_minimum_price_not_reached() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: ONE,
+                minimum_price: Some(Decimal::percent(150)),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("execution_skipped", "price_threshold_exceeded")));
+    }
+
     #[test]
     fn should_create_new_trigger_if_price_threshold_exceeded() {
         let mut deps = calc_mock_dependencies();
@@ -1429,7 +3276,16 @@ mod execute_trigger_tests {
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        execute_trigger_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
@@ -1468,7 +3324,16 @@ mod execute_trigger_tests {
             },
         );
 
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
@@ -1480,7 +3345,7 @@ mod execute_trigger_tests {
                     msg: to_json_binary(&ExchangeExecuteMsg::Swap {
                         minimum_receive_amount: Coin {
                             amount: vault.minimum_receive_amount.unwrap_or(Uint128::zero()),
-                            denom: vault.target_denom.clone(),
+                            denom: vault.target_denom.to_string(),
                         },
                         route: vault.route.clone()
                     })
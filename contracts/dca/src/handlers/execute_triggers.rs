@@ -0,0 +1,149 @@
+use crate::constants::FAIL_SILENTLY_REPLY_ID;
+use crate::error::ContractError;
+use crate::helpers::validation::{
+    assert_contract_is_not_paused, assert_execute_is_not_paused, assert_no_more_than_20_trigger_ids,
+};
+use crate::msg::ExecuteMsg;
+use cosmwasm_std::{
+    to_json_binary, Binary, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128, WasmMsg,
+};
+
+pub fn execute_triggers_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    trigger_ids: Vec<Uint128>,
+    route: Option<Binary>,
+) -> Result<Response, ContractError> {
+    assert_contract_is_not_paused(deps.storage)?;
+    assert_execute_is_not_paused(deps.storage)?;
+    assert_no_more_than_20_trigger_ids(&trigger_ids)?;
+
+    let sub_messages = trigger_ids
+        .iter()
+        .map(|trigger_id| {
+            Ok(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::ExecuteTrigger {
+                        trigger_id: *trigger_id,
+                        route: route.clone(),
+                        slippage_override: None,
+                        on_behalf_of: Some(info.sender.clone()),
+                    })?,
+                    funds: vec![],
+                },
+                FAIL_SILENTLY_REPLY_ID,
+            ))
+        })
+        .collect::<Result<Vec<SubMsg>, ContractError>>()?;
+
+    Ok(Response::new()
+        .add_attribute("execute_triggers", "true")
+        .add_attribute("trigger_count", trigger_ids.len().to_string())
+        .add_submessages(sub_messages))
+}
+
+#[cfg(test)]
+mod execute_triggers_tests {
+    use super::*;
+    use crate::state::config::update_config;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use crate::types::config::Config;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    const KEEPER: &str = "keeper";
+
+    #[test]
+    fn when_contract_is_paused_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                paused: true,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let err = execute_triggers_handler(
+            deps.as_mut(),
+            env,
+            mock_info(KEEPER, &[]),
+            vec![Uint128::one()],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: contract is paused");
+    }
+
+    #[test]
+    fn with_more_than_20_trigger_ids_should_fail() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = execute_triggers_handler(
+            deps.as_mut(),
+            env,
+            mock_info(KEEPER, &[]),
+            (1..=21).map(Uint128::new).collect(),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: no more than 20 triggers can be executed at once"
+        );
+    }
+
+    #[test]
+    fn should_execute_each_trigger_in_an_isolated_sub_message() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let route = Some(Binary::from(b"route"));
+        let trigger_ids = vec![Uint128::new(1), Uint128::new(2), Uint128::new(3)];
+
+        let response = execute_triggers_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(KEEPER, &[]),
+            trigger_ids.clone(),
+            route.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            trigger_ids
+                .into_iter()
+                .map(|trigger_id| SubMsg::reply_on_error(
+                    WasmMsg::Execute {
+                        contract_addr: env.contract.address.to_string(),
+                        msg: to_json_binary(&ExecuteMsg::ExecuteTrigger {
+                            trigger_id,
+                            route: route.clone(),
+                            slippage_override: None,
+                            on_behalf_of: Some(Addr::unchecked(KEEPER)),
+                        })
+                        .unwrap(),
+                        funds: vec![],
+                    },
+                    FAIL_SILENTLY_REPLY_ID,
+                ))
+                .collect::<Vec<SubMsg>>()
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    error::ContractError,
+    state::{
+        bounties::{get_bounty, update_bounty},
+        events::create_event,
+        funding_rounds::{get_funding_round, get_round_contributions, update_funding_round},
+    },
+    types::{
+        bounty::Bounty,
+        event::{EventBuilder, EventData},
+        funding_round::FundingRound,
+    },
+};
+use cosmwasm_std::{Coin, Decimal256, DepsMut, Env, Response, StdError, SubMsg, Uint128, Uint256};
+use shared::{coin::add, cw20::into_transfer_msg};
+
+pub fn finalize_round_handler(
+    deps: DepsMut,
+    env: Env,
+    round_id: u64,
+) -> Result<Response, ContractError> {
+    let funding_round = get_funding_round(deps.storage, round_id)?;
+
+    if funding_round.is_finalized() {
+        return Err(ContractError::CustomError {
+            val: format!("funding round {} is already finalized", round_id),
+        });
+    }
+
+    if !funding_round.has_ended(env.block.time) {
+        return Err(ContractError::CustomError {
+            val: format!("funding round {} has not ended yet", round_id),
+        });
+    }
+
+    let mut weights = Vec::<(Bounty, Decimal256)>::new();
+
+    for bounty_id in funding_round.bounty_ids.iter() {
+        let bounty = get_bounty(deps.storage, *bounty_id)?;
+
+        let weight = if bounty.is_cancelled() {
+            Decimal256::zero()
+        } else {
+            funding_round.formula.matching_weight(
+                &get_round_contributions(deps.storage, round_id, bounty.id)?
+                    .into_iter()
+                    .map(|contribution| contribution.amount)
+                    .collect::<Vec<Uint128>>(),
+            )?
+        };
+
+        weights.push((bounty, weight));
+    }
+
+    let total_weight = weights
+        .iter()
+        .fold(Decimal256::zero(), |total, (_, weight)| total + weight);
+
+    let mut matched_amount = Uint128::zero();
+
+    if !total_weight.is_zero() {
+        for (bounty, weight) in weights {
+            let amount = Uint128::try_from(
+                Uint256::from(funding_round.matching_pool.amount)
+                    .multiply_ratio(weight.atomics(), total_weight.atomics()),
+            )
+            .map_err(StdError::from)?;
+
+            if amount.is_zero() {
+                continue;
+            }
+
+            let matching_funds =
+                Coin::new(amount.into(), funding_round.matching_pool.denom.clone());
+
+            update_bounty(
+                deps.storage,
+                Bounty {
+                    balance: add(bounty.balance.clone(), matching_funds.clone())?,
+                    deposited_amount: add(bounty.deposited_amount.clone(), matching_funds.clone())?,
+                    ..bounty.clone()
+                },
+            )?;
+
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    bounty.id,
+                    env.block.clone(),
+                    EventData::BountyMatchingFundsReceived {
+                        round_id,
+                        amount: matching_funds,
+                    },
+                ),
+            )?;
+
+            matched_amount += amount;
+        }
+    }
+
+    update_funding_round(
+        deps.storage,
+        &FundingRound {
+            finalized_at: Some(env.block.time),
+            ..funding_round.clone()
+        },
+    )?;
+
+    let refund_amount = funding_round.matching_pool.amount - matched_amount;
+
+    let mut response = Response::new()
+        .add_attribute("finalize_round", "true")
+        .add_attribute("round_id", round_id.to_string())
+        .add_attribute("matched_amount", matched_amount)
+        .add_attribute("refunded_amount", refund_amount);
+
+    if !refund_amount.is_zero() {
+        response = response.add_submessage(SubMsg::new(into_transfer_msg(
+            deps.api,
+            funding_round.sponsor.as_ref(),
+            Coin::new(refund_amount.into(), funding_round.matching_pool.denom),
+        )?));
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod finalize_round_tests {
+    use super::*;
+    use crate::handlers::{
+        contribute_to_round::contribute_to_round_handler,
+        create_funding_round::create_funding_round_handler,
+    };
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI};
+    use crate::types::funding_round::MatchingFormula;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::BankMsg;
+
+    const SPONSOR: &str = "sponsor";
+
+    #[test]
+    fn before_round_has_ended_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        create_funding_round_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(SPONSOR, &[Coin::new(100, DENOM_UKUJI)]),
+            vec![bounty.id],
+            MatchingFormula::Quadratic,
+            env.block.time.plus_seconds(100),
+        )
+        .unwrap();
+
+        let err = finalize_round_handler(deps.as_mut(), env, 1).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: funding round 1 has not ended yet");
+    }
+
+    #[test]
+    fn matches_bounties_by_quadratic_weight() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let popular_bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+        let whale_bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        create_funding_round_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(SPONSOR, &[Coin::new(1000, DENOM_UKUJI)]),
+            vec![popular_bounty.id, whale_bounty.id],
+            MatchingFormula::Quadratic,
+            env.block.time.plus_seconds(100),
+        )
+        .unwrap();
+
+        for (contributor, bounty_id, amount) in [
+            ("alice", popular_bounty.id, 4),
+            ("bob", popular_bounty.id, 9),
+            ("carol", whale_bounty.id, 13),
+        ] {
+            contribute_to_round_handler(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(contributor, &[Coin::new(amount, DENOM_UKUJI)]),
+                1,
+                bounty_id,
+            )
+            .unwrap();
+        }
+
+        env.block.time = env.block.time.plus_seconds(100);
+
+        let response = finalize_round_handler(deps.as_mut(), env.clone(), 1).unwrap();
+
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            get_bounty(deps.as_ref().storage, popular_bounty.id)
+                .unwrap()
+                .balance,
+            add(popular_bounty.balance, Coin::new(1013, DENOM_UKUJI)).unwrap()
+        );
+        assert_eq!(
+            get_bounty(deps.as_ref().storage, whale_bounty.id)
+                .unwrap()
+                .balance,
+            add(whale_bounty.balance, Coin::new(13, DENOM_UKUJI)).unwrap()
+        );
+        assert_eq!(
+            get_funding_round(deps.as_ref().storage, 1)
+                .unwrap()
+                .finalized_at,
+            Some(env.block.time)
+        );
+    }
+
+    #[test]
+    fn without_contributions_refunds_sponsor() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        create_funding_round_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(SPONSOR, &[Coin::new(100, DENOM_UKUJI)]),
+            vec![bounty.id],
+            MatchingFormula::Quadratic,
+            env.block.time.plus_seconds(100),
+        )
+        .unwrap();
+
+        env.block.time = env.block.time.plus_seconds(100);
+
+        let response = finalize_round_handler(deps.as_mut(), env, 1).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: SPONSOR.to_string(),
+                amount: vec![Coin::new(100, DENOM_UKUJI)],
+            })]
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{
+    helpers::validation::assert_page_limit_is_valid, msg::AdminActionsResponse,
+    state::admin_actions::get_admin_actions,
+};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_admin_actions_handler(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<AdminActionsResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    let admin_actions = get_admin_actions(deps.storage, start_after, limit, reverse)?;
+
+    Ok(AdminActionsResponse { admin_actions })
+}
@@ -0,0 +1,8 @@
+use crate::{msg::AllowedDenomsResponse, state::allowed_denoms::get_allowed_denoms};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_allowed_denoms_handler(deps: Deps) -> StdResult<AllowedDenomsResponse> {
+    let denoms = get_allowed_denoms(deps.storage)?;
+
+    Ok(AllowedDenomsResponse { denoms })
+}
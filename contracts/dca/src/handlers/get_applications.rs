@@ -0,0 +1,8 @@
+use crate::{msg::ApplicationsResponse, state::applications::get_applications};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_applications_handler(deps: Deps, bounty_id: Uint128) -> StdResult<ApplicationsResponse> {
+    let applications = get_applications(deps.storage, bounty_id)?;
+
+    Ok(ApplicationsResponse { applications })
+}
@@ -0,0 +1,8 @@
+use crate::{msg::AssigneesResponse, state::assignees::get_assignees};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_assignees_handler(deps: Deps, bounty_id: Uint128) -> StdResult<AssigneesResponse> {
+    let assignees = get_assignees(deps.storage, bounty_id)?;
+
+    Ok(AssigneesResponse { assignees })
+}
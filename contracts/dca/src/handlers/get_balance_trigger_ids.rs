@@ -0,0 +1,136 @@
+use crate::{
+    helpers::validation::assert_page_limit_is_valid,
+    msg::TriggerIdsResponse,
+    state::{bounties::get_bounty, triggers::get_balance_triggers},
+    types::trigger::TriggerConfiguration,
+};
+use cosmwasm_std::{Deps, StdResult};
+use shared::balance::query_balance;
+
+pub fn get_balance_trigger_ids_handler(
+    deps: Deps,
+    limit: Option<u16>,
+) -> StdResult<TriggerIdsResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    let mut trigger_ids = vec![];
+
+    for trigger in get_balance_triggers(deps.storage, limit)? {
+        if let TriggerConfiguration::BalanceAbove {
+            account,
+            denom,
+            threshold,
+            fired,
+        } = trigger.configuration
+        {
+            if !get_bounty(deps.storage, trigger.bounty_id)?.is_active() {
+                continue;
+            }
+
+            let balance = query_balance(deps.api, &deps.querier, &denom, &account)?;
+
+            // a fired trigger is due again once the balance drops back, so it can re-arm
+            if (balance.amount > threshold) != fired {
+                trigger_ids.push(trigger.bounty_id);
+            }
+        }
+    }
+
+    Ok(TriggerIdsResponse { trigger_ids })
+}
+
+#[cfg(test)]
+mod get_balance_trigger_ids_tests {
+    use super::*;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Addr, Coin, Uint128};
+
+    #[test]
+    fn returns_only_bounties_whose_watched_balance_exceeds_the_threshold() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        deps.querier
+            .update_balance(USER, vec![Coin::new(200, DENOM_UKUJI)]);
+
+        let ready_bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                trigger: Some(TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked(USER),
+                    denom: DENOM_UKUJI.to_string(),
+                    threshold: Uint128::new(100),
+                    fired: false,
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        setup_bounty(
+            deps.as_mut(),
+            env,
+            Bounty {
+                trigger: Some(TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked(USER),
+                    denom: DENOM_UKUJI.to_string(),
+                    threshold: Uint128::new(200),
+                    fired: false,
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        let response = get_balance_trigger_ids_handler(deps.as_ref(), None).unwrap();
+
+        assert_eq!(response.trigger_ids, vec![ready_bounty.id]);
+    }
+
+    #[test]
+    fn returns_fired_bounties_only_once_the_watched_balance_drops_back() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        deps.querier
+            .update_balance(USER, vec![Coin::new(200, DENOM_UKUJI)]);
+
+        setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                trigger: Some(TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked(USER),
+                    denom: DENOM_UKUJI.to_string(),
+                    threshold: Uint128::new(100),
+                    fired: true,
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        let rearmable_bounty = setup_bounty(
+            deps.as_mut(),
+            env,
+            Bounty {
+                trigger: Some(TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked(USER),
+                    denom: DENOM_UKUJI.to_string(),
+                    threshold: Uint128::new(200),
+                    fired: true,
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        let response = get_balance_trigger_ids_handler(deps.as_ref(), None).unwrap();
+
+        assert_eq!(response.trigger_ids, vec![rearmable_bounty.id]);
+    }
+}
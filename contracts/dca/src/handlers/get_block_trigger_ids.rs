@@ -0,0 +1,17 @@
+use crate::{
+    helpers::validation::assert_page_limit_is_valid, msg::TriggerIdsResponse,
+    state::triggers::get_block_triggers,
+};
+use cosmwasm_std::{Deps, Env, StdResult};
+
+pub fn get_block_trigger_ids_handler(
+    deps: Deps,
+    env: Env,
+    limit: Option<u16>,
+) -> StdResult<TriggerIdsResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    Ok(TriggerIdsResponse {
+        trigger_ids: get_block_triggers(deps.storage, env.block.height, limit)?,
+    })
+}
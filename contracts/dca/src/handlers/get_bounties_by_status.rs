@@ -0,0 +1,175 @@
+use crate::state::bounties::get_bounties_by_status;
+use crate::types::bounty::{Bounty, BountyStatus};
+use crate::{helpers::validation::assert_page_limit_is_valid, msg::BountiesResponse};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_bounties_by_status_handler(
+    deps: Deps,
+    status: BountyStatus,
+    start_after: Option<Uint128>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<BountiesResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    let bounties = get_bounties_by_status(deps.storage, status, start_after, limit, reverse)?
+        .into_iter()
+        .map(Bounty::without_private_metadata)
+        .collect();
+
+    Ok(BountiesResponse {
+        bounties,
+        projections: None,
+    })
+}
+
+#[cfg(test)]
+mod get_bounties_by_status_tests {
+    use super::*;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_limit_too_large_should_fail() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = get_bounties_by_status_handler(
+            deps.as_ref(),
+            BountyStatus::Active,
+            None,
+            Some(1001),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: limit cannot be greater than 1000."
+        );
+    }
+
+    #[test]
+    fn should_only_return_bounties_with_status() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                id: Uint128::new(1),
+                status: BountyStatus::Scheduled,
+                ..Bounty::default()
+            },
+        );
+
+        setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                id: Uint128::new(2),
+                status: BountyStatus::Active,
+                ..Bounty::default()
+            },
+        );
+
+        setup_bounty(
+            deps.as_mut(),
+            env,
+            Bounty {
+                id: Uint128::new(3),
+                status: BountyStatus::Scheduled,
+                ..Bounty::default()
+            },
+        );
+
+        let bounties = get_bounties_by_status_handler(
+            deps.as_ref(),
+            BountyStatus::Scheduled,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .bounties;
+
+        assert_eq!(
+            bounties.iter().map(|bounty| bounty.id).collect::<Vec<_>>(),
+            vec![Uint128::new(1), Uint128::new(3)]
+        );
+    }
+
+    #[test]
+    fn with_start_after_and_limit_should_return_limited_bounties_after_start_after() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for i in 1..40 {
+            setup_bounty(
+                deps.as_mut(),
+                env.clone(),
+                Bounty {
+                    id: Uint128::new(i),
+                    status: BountyStatus::Inactive,
+                    ..Bounty::default()
+                },
+            );
+        }
+
+        let bounties = get_bounties_by_status_handler(
+            deps.as_ref(),
+            BountyStatus::Inactive,
+            Some(Uint128::one()),
+            Some(30),
+            None,
+        )
+        .unwrap()
+        .bounties;
+
+        assert_eq!(bounties.len(), 30);
+        assert_eq!(bounties[0].id, Uint128::new(2));
+    }
+
+    #[test]
+    fn with_reverse_should_return_bounties_before_start_after_in_descending_order() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for i in 1..6 {
+            setup_bounty(
+                deps.as_mut(),
+                env.clone(),
+                Bounty {
+                    id: Uint128::new(i),
+                    status: BountyStatus::Inactive,
+                    ..Bounty::default()
+                },
+            );
+        }
+
+        let bounties = get_bounties_by_status_handler(
+            deps.as_ref(),
+            BountyStatus::Inactive,
+            Some(Uint128::new(4)),
+            Some(2),
+            Some(true),
+        )
+        .unwrap()
+        .bounties;
+
+        assert_eq!(
+            bounties.iter().map(|bounty| bounty.id).collect::<Vec<_>>(),
+            vec![Uint128::new(3), Uint128::new(2)]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::state::bounties::get_bounties_by_tag;
+use crate::types::bounty::Bounty;
+use crate::{helpers::validation::assert_page_limit_is_valid, msg::BountiesResponse};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_bounties_by_tag_handler(
+    deps: Deps,
+    tag: String,
+    start_after: Option<Uint128>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<BountiesResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    let bounties = get_bounties_by_tag(deps.storage, tag, start_after, limit, reverse)?
+        .into_iter()
+        .map(Bounty::without_private_metadata)
+        .collect();
+
+    Ok(BountiesResponse {
+        bounties,
+        projections: None,
+    })
+}
+
+#[cfg(test)]
+mod get_bounties_by_tag_tests {
+    use super::*;
+    use crate::state::bounties::{get_bounty, update_bounty};
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn should_only_return_bounties_with_tag() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                id: Uint128::new(1),
+                tags: vec!["design".to_string(), "frontend".to_string()],
+                ..Bounty::default()
+            },
+        );
+
+        setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                id: Uint128::new(2),
+                tags: vec!["backend".to_string()],
+                ..Bounty::default()
+            },
+        );
+
+        setup_bounty(
+            deps.as_mut(),
+            env,
+            Bounty {
+                id: Uint128::new(3),
+                tags: vec!["frontend".to_string()],
+                ..Bounty::default()
+            },
+        );
+
+        let bounties =
+            get_bounties_by_tag_handler(deps.as_ref(), "frontend".to_string(), None, None, None)
+                .unwrap()
+                .bounties;
+
+        assert_eq!(
+            bounties.iter().map(|bounty| bounty.id).collect::<Vec<_>>(),
+            vec![Uint128::new(1), Uint128::new(3)]
+        );
+    }
+
+    #[test]
+    fn should_not_return_bounties_after_tag_is_removed() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env,
+            Bounty {
+                tags: vec!["frontend".to_string()],
+                ..Bounty::default()
+            },
+        );
+
+        update_bounty(
+            deps.as_mut().storage,
+            Bounty {
+                tags: vec!["backend".to_string()],
+                ..get_bounty(deps.as_ref().storage, bounty.id).unwrap()
+            },
+        )
+        .unwrap();
+
+        let bounties =
+            get_bounties_by_tag_handler(deps.as_ref(), "frontend".to_string(), None, None, None)
+                .unwrap()
+                .bounties;
+
+        assert!(bounties.is_empty());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    msg::BountyBalancesResponse,
+    state::{bounties::get_bounty, denom_balances::get_denom_balances},
+    types::denom_balance::DenomBalance,
+};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_bounty_balances_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+) -> StdResult<BountyBalancesResponse> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    let mut balances = vec![DenomBalance {
+        balance: bounty.balance,
+        deposited_amount: bounty.deposited_amount,
+        swap_amount: bounty.swap_amount,
+        route: bounty.route,
+    }];
+
+    balances.extend(get_denom_balances(deps.storage, bounty_id)?);
+
+    Ok(BountyBalancesResponse { balances })
+}
+
+#[cfg(test)]
+mod get_bounty_balances_tests {
+    use super::*;
+    use crate::state::denom_balances::save_denom_balance;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Coin;
+
+    #[test]
+    fn returns_primary_balance_first() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let denom_balance = DenomBalance {
+            balance: Coin::new(100, "uatom"),
+            deposited_amount: Coin::new(100, "uatom"),
+            swap_amount: Uint128::new(10),
+            route: None,
+        };
+
+        save_denom_balance(deps.as_mut().storage, bounty.id, &denom_balance).unwrap();
+
+        let response = get_bounty_balances_handler(deps.as_ref(), bounty.id).unwrap();
+
+        assert_eq!(response.balances.len(), 2);
+        assert_eq!(response.balances[0].balance, bounty.balance);
+        assert_eq!(response.balances[1], denom_balance);
+    }
+}
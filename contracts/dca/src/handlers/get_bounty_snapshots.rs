@@ -0,0 +1,19 @@
+use crate::{
+    helpers::validation::assert_page_limit_is_valid, msg::BountySnapshotsResponse,
+    state::bounty_snapshots::get_bounty_snapshots,
+};
+use cosmwasm_std::{Deps, StdResult, Timestamp, Uint128};
+
+pub fn get_bounty_snapshots_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+    start_after: Option<Timestamp>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<BountySnapshotsResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    let snapshots = get_bounty_snapshots(deps.storage, bounty_id, start_after, limit, reverse)?;
+
+    Ok(BountySnapshotsResponse { snapshots })
+}
@@ -0,0 +1,11 @@
+use crate::{msg::BountyTemplateResponse, state::templates::get_bounty_template};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_bounty_template_handler(
+    deps: Deps,
+    template_id: u64,
+) -> StdResult<BountyTemplateResponse> {
+    let template = get_bounty_template(deps.storage, template_id)?;
+
+    Ok(BountyTemplateResponse { template })
+}
@@ -0,0 +1,108 @@
+use crate::{
+    msg::BountyResponse,
+    state::{bounties::get_bounty, viewing_keys::get_viewing_key},
+};
+use cosmwasm_std::{Deps, StdError, StdResult, Uint128};
+
+pub fn get_bounty_with_key_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+    key: String,
+) -> StdResult<BountyResponse> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    if get_viewing_key(deps.storage, bounty_id)? != Some(key) {
+        return Err(StdError::generic_err(format!(
+            "Invalid viewing key for bounty {}",
+            bounty_id
+        )));
+    }
+
+    Ok(BountyResponse {
+        locked_until: bounty.locked_until(),
+        bounty,
+    })
+}
+
+#[cfg(test)]
+mod get_bounty_with_key_tests {
+    use super::*;
+    use crate::handlers::get_bounty::get_bounty_handler;
+    use crate::handlers::set_viewing_key::set_viewing_key_handler;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_invalid_key_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env,
+            Bounty {
+                private_metadata: true,
+                ..Bounty::default()
+            },
+        );
+
+        set_viewing_key_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            "viewing-key".to_string(),
+        )
+        .unwrap();
+
+        let err = get_bounty_with_key_handler(deps.as_ref(), bounty.id, "wrong-key".to_string())
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Generic error: Invalid viewing key for bounty {}",
+                bounty.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_valid_key_returns_private_metadata() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env,
+            Bounty {
+                label: Some("private label".to_string()),
+                private_metadata: true,
+                ..Bounty::default()
+            },
+        );
+
+        set_viewing_key_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            "viewing-key".to_string(),
+        )
+        .unwrap();
+
+        let public_bounty = get_bounty_handler(deps.as_ref(), bounty.id).unwrap().bounty;
+
+        let private_bounty =
+            get_bounty_with_key_handler(deps.as_ref(), bounty.id, "viewing-key".to_string())
+                .unwrap()
+                .bounty;
+
+        assert_eq!(public_bounty.label, None);
+        assert_eq!(private_bounty.label, Some("private label".to_string()));
+    }
+}
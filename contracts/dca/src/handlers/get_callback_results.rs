@@ -0,0 +1,94 @@
+use crate::{
+    msg::CallbackResultsResponse,
+    state::{
+        bounties::get_bounty, callback_results::get_callback_results, viewing_keys::get_viewing_key,
+    },
+};
+use cosmwasm_std::{Deps, StdError, StdResult, Uint128};
+
+pub fn get_callback_results_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+    key: String,
+    execution_height: Option<u64>,
+) -> StdResult<CallbackResultsResponse> {
+    get_bounty(deps.storage, bounty_id)?;
+
+    if get_viewing_key(deps.storage, bounty_id)? != Some(key) {
+        return Err(StdError::generic_err(format!(
+            "Invalid viewing key for bounty {}",
+            bounty_id
+        )));
+    }
+
+    let results = get_callback_results(deps.storage, bounty_id, execution_height)?;
+
+    Ok(CallbackResultsResponse { results })
+}
+
+#[cfg(test)]
+mod get_callback_results_tests {
+    use super::*;
+    use crate::state::callback_results::save_callback_result;
+    use crate::state::viewing_keys::save_viewing_key;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::bounty::Bounty;
+    use crate::types::callback_result::CallbackResult;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Binary};
+
+    #[test]
+    fn with_invalid_key_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        save_viewing_key(deps.as_mut().storage, bounty.id, "key").unwrap();
+
+        let err = get_callback_results_handler(deps.as_ref(), bounty.id, "wrong".to_string(), None)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Generic error: Invalid viewing key for bounty {}",
+                bounty.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_valid_key_returns_results() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_viewing_key(deps.as_mut().storage, bounty.id, "key").unwrap();
+
+        let result = CallbackResult {
+            execution_height: env.block.height,
+            destination_index: 1,
+            destination: Addr::unchecked("contract"),
+            data: Binary::from(b"receipt".to_vec()),
+        };
+
+        save_callback_result(deps.as_mut().storage, bounty.id, &result).unwrap();
+
+        let response = get_callback_results_handler(
+            deps.as_ref(),
+            bounty.id,
+            "key".to_string(),
+            Some(env.block.height),
+        )
+        .unwrap();
+
+        assert_eq!(response.results, vec![result]);
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{msg::ChecklistResponse, state::checklists::get_checklist};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_checklist_handler(deps: Deps, bounty_id: Uint128) -> StdResult<ChecklistResponse> {
+    let items = get_checklist(deps.storage, bounty_id)?;
+
+    Ok(ChecklistResponse { items })
+}
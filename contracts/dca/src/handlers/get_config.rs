@@ -1,8 +1,48 @@
-use crate::{msg::ConfigResponse, state::config::get_config};
-use cosmwasm_std::{Deps, StdResult};
+use crate::{helpers::config::get_effective_config, msg::ConfigResponse};
+use cosmwasm_std::{Deps, Env, StdResult};
 
-pub fn get_config_handler(deps: Deps) -> StdResult<ConfigResponse> {
+pub fn get_config_handler(deps: Deps, env: Env) -> StdResult<ConfigResponse> {
     Ok(ConfigResponse {
-        config: get_config(deps.storage)?,
+        config: get_effective_config(deps.storage, env.block.time)?,
     })
 }
+
+#[cfg(test)]
+mod get_config_tests {
+    use super::*;
+    use crate::{
+        state::scheduled_config_updates::save_scheduled_config_update,
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+        types::scheduled_config_update::ConfigChanges,
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Decimal,
+    };
+
+    #[test]
+    fn returns_config_with_due_scheduled_updates_applied() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_scheduled_config_update(
+            deps.as_mut().storage,
+            env.block.time,
+            env.block.time.plus_seconds(10),
+            ConfigChanges {
+                automation_fee_percent: Some(Decimal::percent(2)),
+                ..ConfigChanges::default()
+            },
+        )
+        .unwrap();
+
+        let mut later_env = env.clone();
+        later_env.block.time = env.block.time.plus_seconds(10);
+
+        let config = get_config_handler(deps.as_ref(), later_env).unwrap().config;
+
+        assert_eq!(config.automation_fee_percent, Decimal::percent(2));
+    }
+}
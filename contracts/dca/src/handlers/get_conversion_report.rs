@@ -0,0 +1,95 @@
+use crate::{
+    msg::ConversionReportResponse,
+    state::{bounties::get_bounty, conversion_reports::get_conversion_report},
+};
+use cosmwasm_std::{Coin, Deps, StdResult, Uint128};
+
+pub fn get_conversion_report_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+) -> StdResult<ConversionReportResponse> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+    let report = get_conversion_report(deps.storage, bounty_id)?;
+
+    Ok(ConversionReportResponse {
+        executions: report.executions,
+        total_sent: Coin::new(report.total_sent.into(), bounty.get_swap_denom()),
+        total_received: Coin::new(report.total_received.into(), bounty.target_denom.clone()),
+        fees_paid: Coin::new(report.fees_paid.into(), bounty.target_denom.clone()),
+        average_price: report.average_price(),
+        twap_received: Coin::new(report.twap_received.into(), bounty.target_denom.clone()),
+        twap_average_price: report.twap_average_price(),
+    })
+}
+
+#[cfg(test)]
+mod get_conversion_report_tests {
+    use super::*;
+    use crate::state::conversion_reports::record_conversion;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Decimal;
+
+    #[test]
+    fn with_no_executions_returns_empty_report() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let response = get_conversion_report_handler(deps.as_ref(), bounty.id).unwrap();
+
+        assert_eq!(response.executions, 0);
+        assert_eq!(response.total_sent, Coin::new(0, bounty.get_swap_denom()));
+        assert_eq!(response.average_price, None);
+        assert_eq!(response.twap_average_price, None);
+    }
+
+    #[test]
+    fn with_executions_returns_blended_report() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        record_conversion(
+            deps.as_mut().storage,
+            bounty.id,
+            Uint128::new(100),
+            Uint128::new(50),
+            Uint128::new(1),
+            Uint128::new(40),
+        )
+        .unwrap();
+        record_conversion(
+            deps.as_mut().storage,
+            bounty.id,
+            Uint128::new(100),
+            Uint128::new(50),
+            Uint128::new(1),
+            Uint128::new(60),
+        )
+        .unwrap();
+
+        let response = get_conversion_report_handler(deps.as_ref(), bounty.id).unwrap();
+
+        assert_eq!(
+            response,
+            ConversionReportResponse {
+                executions: 2,
+                total_sent: Coin::new(200, bounty.get_swap_denom()),
+                total_received: Coin::new(100, bounty.target_denom.clone()),
+                fees_paid: Coin::new(2, bounty.target_denom.clone()),
+                average_price: Some(Decimal::percent(200)),
+                twap_received: Coin::new(100, bounty.target_denom.clone()),
+                twap_average_price: Some(Decimal::percent(200)),
+            }
+        );
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{msg::CustomFeesResponse, state::custom_fees::get_custom_swap_fees};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_custom_swap_fees_handler(deps: Deps) -> StdResult<CustomFeesResponse> {
+    let custom_fees = get_custom_swap_fees(deps.storage)?;
+
+    Ok(CustomFeesResponse { custom_fees })
+}
@@ -0,0 +1,8 @@
+use crate::{msg::Cw20WhitelistResponse, state::cw20_whitelist::get_whitelisted_cw20_tokens};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_cw20_whitelist_handler(deps: Deps) -> StdResult<Cw20WhitelistResponse> {
+    let tokens = get_whitelisted_cw20_tokens(deps.storage)?;
+
+    Ok(Cw20WhitelistResponse { tokens })
+}
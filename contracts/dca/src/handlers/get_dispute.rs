@@ -0,0 +1,8 @@
+use crate::{msg::DisputeResponse, state::disputes::get_dispute};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_dispute_handler(deps: Deps, bounty_id: Uint128) -> StdResult<DisputeResponse> {
+    let dispute = get_dispute(deps.storage, bounty_id)?;
+
+    Ok(DisputeResponse { dispute })
+}
@@ -0,0 +1,14 @@
+use crate::{
+    msg::ScheduledPaymentsResponse, state::scheduled_payments::get_due_scheduled_payments,
+};
+use cosmwasm_std::{Deps, Env, StdResult};
+
+pub fn get_due_scheduled_payments_handler(
+    deps: Deps,
+    env: Env,
+    limit: Option<u16>,
+) -> StdResult<ScheduledPaymentsResponse> {
+    let scheduled_payments = get_due_scheduled_payments(deps.storage, env.block.time, limit)?;
+
+    Ok(ScheduledPaymentsResponse { scheduled_payments })
+}
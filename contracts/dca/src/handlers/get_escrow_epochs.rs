@@ -0,0 +1,11 @@
+use crate::{msg::EscrowEpochsResponse, state::escrow_epochs::get_escrow_epochs};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_escrow_epochs_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+) -> StdResult<EscrowEpochsResponse> {
+    let epochs = get_escrow_epochs(deps.storage, bounty_id)?;
+
+    Ok(EscrowEpochsResponse { epochs })
+}
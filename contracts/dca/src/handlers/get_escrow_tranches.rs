@@ -0,0 +1,11 @@
+use crate::{msg::EscrowTranchesResponse, state::escrow_tranches::get_escrow_tranche_schedule};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_escrow_tranches_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+) -> StdResult<EscrowTranchesResponse> {
+    let schedule = get_escrow_tranche_schedule(deps.storage, bounty_id)?;
+
+    Ok(EscrowTranchesResponse { schedule })
+}
@@ -0,0 +1,11 @@
+use crate::{msg::EventSummaryResponse, state::event_summaries::get_event_summary};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_event_summary_handler(
+    deps: Deps,
+    resource_id: Uint128,
+) -> StdResult<EventSummaryResponse> {
+    Ok(EventSummaryResponse {
+        summary: get_event_summary(deps.storage, resource_id)?,
+    })
+}
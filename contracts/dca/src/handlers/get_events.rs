@@ -1,33 +1,67 @@
-use crate::state::config::get_config;
+use crate::helpers::pagination::Page;
 use crate::state::events::event_store;
-use crate::types::event::Event;
+use crate::types::event::{Event, EventOrder};
 use crate::{helpers::validation::assert_page_limit_is_valid, msg::EventsResponse};
-use cosmwasm_std::{from_json, Deps, Order, StdResult};
-use cw_storage_plus::Bound;
+use cosmwasm_std::{from_json, Binary, Deps, Order, StdResult, Timestamp};
 
 pub fn get_events_handler(
     deps: Deps,
     start_after: Option<u64>,
     limit: Option<u16>,
     reverse: Option<bool>,
+    order_by: Option<EventOrder>,
+    after_timestamp: Option<Timestamp>,
 ) -> StdResult<EventsResponse> {
     assert_page_limit_is_valid(limit)?;
 
-    let events = event_store()
-        .range(
-            deps.storage,
-            reverse.map_or(start_after.map(Bound::exclusive), |_| None),
-            reverse.and_then(|_| start_after.map(Bound::exclusive)),
-            reverse.map_or(Order::Ascending, |reverse| match reverse {
-                true => Order::Descending,
-                false => Order::Ascending,
-            }),
-        )
-        .take(
-            limit.unwrap_or_else(|| get_config(deps.storage).unwrap().default_page_limit) as usize,
-        )
-        .flat_map(|result| result.map(|(_, data)| from_json(data)))
+    let page = Page::new(deps.storage, start_after, limit, reverse)?;
+    let order = page.order;
+
+    let events: Box<dyn Iterator<Item = StdResult<Binary>>> =
+        match order_by.unwrap_or(EventOrder::Id) {
+            EventOrder::Id => {
+                let (min, max) = page.bounds();
+
+                Box::new(
+                    event_store()
+                        .range(deps.storage, min, max, order)
+                        .map(|result| result.map(|(_, data)| data)),
+                )
+            }
+            EventOrder::BlockHeight => {
+                let start_after = start_after
+                    .map(|id| -> StdResult<(u64, u64)> {
+                        let event: Event = from_json(event_store().load(deps.storage, id)?)?;
+                        Ok((event.block_height, event.id))
+                    })
+                    .transpose()?;
+
+                let (min, max) = Page {
+                    start_after,
+                    limit: page.limit,
+                    order,
+                }
+                .bounds();
+
+                Box::new(
+                    event_store()
+                        .idx
+                        .block_height
+                        .range(deps.storage, min, max, order)
+                        .map(|result| result.map(|(_, data)| data)),
+                )
+            }
+        };
+
+    let is_after_timestamp =
+        |event: &Event| after_timestamp.map_or(true, |timestamp| event.timestamp > timestamp);
+
+    let events = events
+        .flat_map(|result| result.map(|data| from_json::<Event>(data)))
         .flatten()
+        .skip_while(|event| order == Order::Ascending && !is_after_timestamp(event))
+        .take_while(|event| order == Order::Ascending || is_after_timestamp(event))
+        .take(page.limit)
         .collect::<Vec<Event>>();
 
     Ok(EventsResponse { events })
@@ -37,18 +71,19 @@ pub fn get_events_handler(
 mod get_events_tests {
     use super::*;
     use crate::{
-        state::events::create_events,
+        state::events::{create_event, create_events},
         tests::{helpers::instantiate_contract, mocks::ADMIN},
-        types::event::EventBuilder,
+        types::event::{EventBuilder, EventData},
     };
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{BlockInfo, Coin, DepsMut, Env, Uint128};
 
     #[test]
     fn events_are_empty() {
         let mut deps = mock_dependencies();
         instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
 
-        let events = get_events_handler(deps.as_ref(), None, None, None)
+        let events = get_events_handler(deps.as_ref(), None, None, None, None, None)
             .unwrap()
             .events;
 
@@ -70,7 +105,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, None, None)
+        let events = get_events_handler(deps.as_ref(), None, None, None, None, None)
             .unwrap()
             .events;
 
@@ -92,7 +127,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, None, None)
+        let events = get_events_handler(deps.as_ref(), None, None, None, None, None)
             .unwrap()
             .events;
 
@@ -115,7 +150,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, None, Some(false))
+        let events = get_events_handler(deps.as_ref(), None, None, Some(false), None, None)
             .unwrap()
             .events;
 
@@ -130,7 +165,7 @@ mod get_events_tests {
 
         create_events(deps.as_mut().storage, vec![EventBuilder::default(); 40]).unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, Some(30), None)
+        let events = get_events_handler(deps.as_ref(), None, Some(30), None, None, None)
             .unwrap()
             .events;
 
@@ -152,7 +187,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), Some(2), None, None)
+        let events = get_events_handler(deps.as_ref(), Some(2), None, None, None, None)
             .unwrap()
             .events;
 
@@ -174,7 +209,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, None, Some(true))
+        let events = get_events_handler(deps.as_ref(), None, None, Some(true), None, None)
             .unwrap()
             .events;
 
@@ -189,7 +224,7 @@ mod get_events_tests {
 
         create_events(deps.as_mut().storage, vec![EventBuilder::default(); 40]).unwrap();
 
-        let events = get_events_handler(deps.as_ref(), Some(1), Some(30), None)
+        let events = get_events_handler(deps.as_ref(), Some(1), Some(30), None, None, None)
             .unwrap()
             .events;
 
@@ -212,7 +247,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), Some(3), None, Some(true))
+        let events = get_events_handler(deps.as_ref(), Some(3), None, Some(true), None, None)
             .unwrap()
             .events;
 
@@ -227,11 +262,124 @@ mod get_events_tests {
 
         create_events(deps.as_mut().storage, vec![EventBuilder::default(); 40]).unwrap();
 
-        let events = get_events_handler(deps.as_ref(), Some(36), Some(30), Some(true))
-            .unwrap()
-            .events;
+        let events =
+            get_events_handler(deps.as_ref(), Some(36), Some(30), Some(true), None, None)
+                .unwrap()
+                .events;
 
         assert_eq!(events.len(), 30);
         assert_eq!(events.first().unwrap().id, 35);
     }
+
+    fn create_events_at_heights(deps: DepsMut, env: &Env, heights: Vec<u64>) {
+        for height in heights {
+            create_event(
+                deps.storage,
+                EventBuilder::new(
+                    Uint128::one(),
+                    BlockInfo {
+                        height,
+                        time: env.block.time.plus_seconds(height),
+                        ..env.block.clone()
+                    },
+                    EventData::BountyFundsDeposited {
+                        amount: Coin::new(100, "uusk"),
+                    },
+                ),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn events_are_ordered_by_block_height_descending() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        create_events_at_heights(deps.as_mut(), &env, vec![20, 10, 30]);
+
+        let events = get_events_handler(
+            deps.as_ref(),
+            None,
+            None,
+            Some(true),
+            Some(EventOrder::BlockHeight),
+            None,
+        )
+        .unwrap()
+        .events;
+
+        assert_eq!(
+            events.iter().map(|event| event.id).collect::<Vec<_>>(),
+            vec![3, 1, 2]
+        );
+    }
+
+    #[test]
+    fn events_ordered_by_block_height_are_skipped() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        create_events_at_heights(deps.as_mut(), &env, vec![20, 10, 30]);
+
+        let events = get_events_handler(
+            deps.as_ref(),
+            Some(1),
+            None,
+            Some(true),
+            Some(EventOrder::BlockHeight),
+            None,
+        )
+        .unwrap()
+        .events;
+
+        assert_eq!(
+            events.iter().map(|event| event.id).collect::<Vec<_>>(),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn events_are_filtered_by_after_timestamp() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        create_events_at_heights(deps.as_mut(), &env, vec![10, 20, 30]);
+
+        let after_timestamp = Some(env.block.time.plus_seconds(15));
+
+        let ascending_events =
+            get_events_handler(deps.as_ref(), None, None, None, None, after_timestamp)
+                .unwrap()
+                .events;
+
+        let descending_events = get_events_handler(
+            deps.as_ref(),
+            None,
+            None,
+            Some(true),
+            Some(EventOrder::BlockHeight),
+            after_timestamp,
+        )
+        .unwrap()
+        .events;
+
+        assert_eq!(
+            ascending_events
+                .iter()
+                .map(|event| event.id)
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(
+            descending_events
+                .iter()
+                .map(|event| event.id)
+                .collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+    }
 }
@@ -1,9 +1,8 @@
-use crate::state::config::get_config;
+use crate::helpers::pagination::Page;
 use crate::state::events::event_store;
 use crate::types::event::Event;
 use crate::{helpers::validation::assert_page_limit_is_valid, msg::EventsResponse};
-use cosmwasm_std::{from_json, Deps, Order, StdResult, Uint128};
-use cw_storage_plus::Bound;
+use cosmwasm_std::{from_json, Deps, StdResult, Uint128};
 
 pub fn get_events_by_resource_id_handler(
     deps: Deps,
@@ -14,22 +13,15 @@ pub fn get_events_by_resource_id_handler(
 ) -> StdResult<EventsResponse> {
     assert_page_limit_is_valid(limit)?;
 
+    let page = Page::new(deps.storage, start_after, limit, reverse)?;
+    let (min, max) = page.bounds();
+
     let events = event_store()
         .idx
         .resource_id
         .prefix(resource_id.into())
-        .range(
-            deps.storage,
-            reverse.map_or(start_after.map(Bound::exclusive), |_| None),
-            reverse.and_then(|_| start_after.map(Bound::exclusive)),
-            reverse.map_or(Order::Ascending, |reverse| match reverse {
-                true => Order::Descending,
-                false => Order::Ascending,
-            }),
-        )
-        .take(
-            limit.unwrap_or_else(|| get_config(deps.storage).unwrap().default_page_limit) as usize,
-        )
+        .range(deps.storage, min, max, page.order)
+        .take(page.limit)
         .flat_map(|result| result.map(|(_, data)| from_json(data)))
         .flatten()
         .collect::<Vec<Event>>();
@@ -0,0 +1,12 @@
+use crate::{
+    msg::ExchangesResponse,
+    state::exchanges::{get_exchanges, get_pair_exchanges},
+};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_exchanges_handler(deps: Deps) -> StdResult<ExchangesResponse> {
+    Ok(ExchangesResponse {
+        exchanges: get_exchanges(deps.storage)?,
+        pair_exchanges: get_pair_exchanges(deps.storage)?,
+    })
+}
@@ -0,0 +1,10 @@
+use crate::{msg::ExecutorStatsResponse, state::executor_stats::get_executor_stats};
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+pub fn get_executor_stats_handler(deps: Deps, address: Addr) -> StdResult<ExecutorStatsResponse> {
+    deps.api.addr_validate(address.as_ref())?;
+
+    Ok(ExecutorStatsResponse {
+        stats: get_executor_stats(deps.storage, &address)?,
+    })
+}
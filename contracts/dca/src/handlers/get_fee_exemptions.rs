@@ -0,0 +1,8 @@
+use crate::{msg::FeeExemptionsResponse, state::fee_exemptions::get_fee_exemptions};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_fee_exemptions_handler(deps: Deps) -> StdResult<FeeExemptionsResponse> {
+    let addresses = get_fee_exemptions(deps.storage)?;
+
+    Ok(FeeExemptionsResponse { addresses })
+}
@@ -0,0 +1,8 @@
+use crate::{msg::FundingRoundResponse, state::funding_rounds::get_funding_round};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_funding_round_handler(deps: Deps, round_id: u64) -> StdResult<FundingRoundResponse> {
+    let funding_round = get_funding_round(deps.storage, round_id)?;
+
+    Ok(FundingRoundResponse { funding_round })
+}
@@ -0,0 +1,86 @@
+use crate::{
+    msg::GasUsageReportResponse,
+    state::{bounties::get_bounty, gas_usage_reports::get_gas_usage_report},
+};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_gas_usage_report_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+) -> StdResult<GasUsageReportResponse> {
+    get_bounty(deps.storage, bounty_id)?;
+
+    let report = get_gas_usage_report(deps.storage, bounty_id)?;
+
+    Ok(GasUsageReportResponse {
+        average_estimated_gas: report.average_estimated_gas(),
+        last_weight: report.last_receipt.as_ref().map(|receipt| receipt.weight()),
+        report,
+    })
+}
+
+#[cfg(test)]
+mod get_gas_usage_report_tests {
+    use super::*;
+    use crate::state::gas_usage_reports::record_execution_receipt;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::bounty::Bounty;
+    use crate::types::execution_receipt::{ExecutionReceipt, ExecutionWeight, GasUsageReport};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_no_executions_returns_empty_report() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let response = get_gas_usage_report_handler(deps.as_ref(), bounty.id).unwrap();
+
+        assert_eq!(
+            response,
+            GasUsageReportResponse {
+                report: GasUsageReport::default(),
+                average_estimated_gas: 0,
+                last_weight: None,
+            }
+        );
+    }
+
+    #[test]
+    fn with_executions_returns_average_estimated_gas() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let light_receipt = ExecutionReceipt {
+            executed_at: env.block.time,
+            message_count: 1,
+            submessage_count: 0,
+            destination_callback_count: 0,
+        };
+        let heavy_receipt = ExecutionReceipt {
+            executed_at: env.block.time,
+            message_count: 8,
+            submessage_count: 4,
+            destination_callback_count: 4,
+        };
+
+        record_execution_receipt(deps.as_mut().storage, bounty.id, &light_receipt).unwrap();
+        record_execution_receipt(deps.as_mut().storage, bounty.id, &heavy_receipt).unwrap();
+
+        let response = get_gas_usage_report_handler(deps.as_ref(), bounty.id).unwrap();
+
+        assert_eq!(
+            response.average_estimated_gas,
+            (light_receipt.estimated_gas() + heavy_receipt.estimated_gas()) / 2
+        );
+        assert_eq!(response.last_weight, Some(ExecutionWeight::Heavy));
+    }
+}
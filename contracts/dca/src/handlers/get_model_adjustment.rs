@@ -0,0 +1,18 @@
+use crate::{
+    msg::ModelAdjustmentResponse,
+    state::model_adjustments::{get_model_adjustment, get_recent_model_submissions},
+};
+use cosmwasm_std::{Deps, Env, StdResult};
+
+pub fn get_model_adjustment_handler(
+    deps: Deps,
+    env: Env,
+    model_id: u8,
+) -> StdResult<ModelAdjustmentResponse> {
+    Ok(ModelAdjustmentResponse {
+        model_id,
+        value: get_model_adjustment(deps.storage, model_id, env.block.time)?,
+        submissions: get_recent_model_submissions(deps.storage, model_id, env.block.time)?.len()
+            as u32,
+    })
+}
@@ -0,0 +1,8 @@
+use crate::{msg::ModelProvidersResponse, state::model_adjustments::get_model_providers};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_model_providers_handler(deps: Deps) -> StdResult<ModelProvidersResponse> {
+    Ok(ModelProvidersResponse {
+        providers: get_model_providers(deps.storage)?,
+    })
+}
@@ -0,0 +1,176 @@
+use crate::{
+    msg::{BountyStatusCount, OwnerDigestResponse},
+    state::{
+        bounties::get_bounties_by_address, denom_balances::get_denom_balances,
+        triggers::get_trigger,
+    },
+    types::{bounty::Bounty, trigger::TriggerConfiguration},
+};
+use cosmwasm_std::{Addr, Deps, StdResult, Timestamp};
+use shared::coin::add_to_coins;
+
+pub fn get_owner_digest_handler(deps: Deps, address: Addr) -> StdResult<OwnerDigestResponse> {
+    deps.api.addr_validate(address.as_ref())?;
+
+    let mut bounties: Vec<Bounty> = vec![];
+
+    loop {
+        let page = get_bounties_by_address(
+            deps.storage,
+            address.clone(),
+            None,
+            bounties.last().map(|bounty| bounty.id),
+            Some(u16::MAX),
+            None,
+        )?;
+
+        if page.is_empty() {
+            break;
+        }
+
+        bounties.extend(page);
+    }
+
+    let mut digest = OwnerDigestResponse {
+        status_counts: vec![],
+        total_locked: vec![],
+        next_trigger_time: None,
+        escrow_pending: vec![],
+    };
+
+    for bounty in bounties {
+        match digest
+            .status_counts
+            .iter_mut()
+            .find(|status_count| status_count.status == bounty.status)
+        {
+            Some(status_count) => status_count.count += 1,
+            None => digest.status_counts.push(BountyStatusCount {
+                status: bounty.status.clone(),
+                count: 1,
+            }),
+        }
+
+        digest.total_locked = add_to_coins(digest.total_locked, &bounty.balance);
+
+        for denom_balance in get_denom_balances(deps.storage, bounty.id)? {
+            digest.total_locked = add_to_coins(digest.total_locked, &denom_balance.balance);
+        }
+
+        digest.escrow_pending = add_to_coins(digest.escrow_pending, &bounty.escrowed_amount);
+
+        if let Some(TriggerConfiguration::Time { target_time }) =
+            get_trigger(deps.storage, bounty.id)?.map(|trigger| trigger.configuration)
+        {
+            digest.next_trigger_time = Some(
+                digest
+                    .next_trigger_time
+                    .map_or(target_time, |next_trigger_time: Timestamp| {
+                        next_trigger_time.min(target_time)
+                    }),
+            );
+        }
+    }
+
+    Ok(digest)
+}
+
+#[cfg(test)]
+mod get_owner_digest_tests {
+    use super::*;
+    use crate::{
+        tests::{
+            helpers::{instantiate_contract, setup_bounty},
+            mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK, USER},
+        },
+        types::bounty::BountyStatus,
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Coin,
+    };
+
+    #[test]
+    fn with_no_bounties_returns_empty_digest() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env, mock_info(ADMIN, &[]));
+
+        let digest = get_owner_digest_handler(deps.as_ref(), Addr::unchecked(USER)).unwrap();
+
+        assert!(digest.status_counts.is_empty());
+        assert!(digest.total_locked.is_empty());
+        assert!(digest.escrow_pending.is_empty());
+        assert_eq!(digest.next_trigger_time, None);
+    }
+
+    #[test]
+    fn summarises_all_owner_bounties() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Active,
+                balance: Coin::new(1000, DENOM_UKUJI),
+                escrowed_amount: Coin::new(50, DENOM_UUSK),
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: env.block.time.plus_seconds(10),
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Active,
+                balance: Coin::new(500, DENOM_UKUJI),
+                escrowed_amount: Coin::new(25, DENOM_UUSK),
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: env.block.time.plus_seconds(20),
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Cancelled,
+                balance: Coin::new(0, DENOM_UKUJI),
+                trigger: None,
+                ..Bounty::default()
+            },
+        );
+
+        let digest = get_owner_digest_handler(deps.as_ref(), Addr::unchecked(USER)).unwrap();
+
+        assert_eq!(
+            digest.status_counts,
+            vec![
+                BountyStatusCount {
+                    status: BountyStatus::Active,
+                    count: 2,
+                },
+                BountyStatusCount {
+                    status: BountyStatus::Cancelled,
+                    count: 1,
+                },
+            ]
+        );
+        assert_eq!(digest.total_locked, vec![Coin::new(1500, DENOM_UKUJI)]);
+        assert_eq!(digest.escrow_pending, vec![Coin::new(75, DENOM_UUSK)]);
+        assert_eq!(
+            digest.next_trigger_time,
+            Some(env.block.time.plus_seconds(10))
+        );
+    }
+}
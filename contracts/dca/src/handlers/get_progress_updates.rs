@@ -0,0 +1,20 @@
+use crate::{
+    helpers::validation::assert_page_limit_is_valid, msg::ProgressUpdatesResponse,
+    state::progress_updates::get_progress_updates,
+};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_progress_updates_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+    start_after: Option<u64>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<ProgressUpdatesResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    let progress_updates =
+        get_progress_updates(deps.storage, bounty_id, start_after, limit, reverse)?;
+
+    Ok(ProgressUpdatesResponse { progress_updates })
+}
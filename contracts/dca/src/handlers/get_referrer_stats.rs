@@ -0,0 +1,14 @@
+use crate::{
+    msg::ReferrerStatsResponse,
+    state::referrals::{get_referred_bounties, get_referrer_earnings},
+};
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+pub fn get_referrer_stats_handler(deps: Deps, address: Addr) -> StdResult<ReferrerStatsResponse> {
+    deps.api.addr_validate(address.as_ref())?;
+
+    Ok(ReferrerStatsResponse {
+        referred_bounties: get_referred_bounties(deps.storage, &address)?,
+        earnings: get_referrer_earnings(deps.storage, &address)?,
+    })
+}
@@ -0,0 +1,10 @@
+use crate::{msg::RolesResponse, state::roles::get_roles};
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+pub fn get_roles_handler(deps: Deps, address: Addr) -> StdResult<RolesResponse> {
+    deps.api.addr_validate(address.as_ref())?;
+
+    Ok(RolesResponse {
+        roles: get_roles(deps.storage, &address)?,
+    })
+}
@@ -0,0 +1,12 @@
+use crate::{msg::RoundContributionsResponse, state::funding_rounds::get_round_contributions};
+use cosmwasm_std::{Deps, StdResult, Uint128};
+
+pub fn get_round_contributions_handler(
+    deps: Deps,
+    round_id: u64,
+    bounty_id: Uint128,
+) -> StdResult<RoundContributionsResponse> {
+    let contributions = get_round_contributions(deps.storage, round_id, bounty_id)?;
+
+    Ok(RoundContributionsResponse { contributions })
+}
@@ -0,0 +1,17 @@
+use crate::{
+    msg::ScheduledConfigUpdatesResponse,
+    state::scheduled_config_updates::get_scheduled_config_updates,
+};
+use cosmwasm_std::{Deps, Env, StdResult};
+
+pub fn get_scheduled_config_updates_handler(
+    deps: Deps,
+    env: Env,
+) -> StdResult<ScheduledConfigUpdatesResponse> {
+    Ok(ScheduledConfigUpdatesResponse {
+        scheduled_config_updates: get_scheduled_config_updates(deps.storage)?
+            .into_iter()
+            .filter(|update| update.activates_at > env.block.time)
+            .collect(),
+    })
+}
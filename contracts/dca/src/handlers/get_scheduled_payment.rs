@@ -0,0 +1,11 @@
+use crate::{msg::ScheduledPaymentResponse, state::scheduled_payments::get_scheduled_payment};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_scheduled_payment_handler(
+    deps: Deps,
+    payment_id: u64,
+) -> StdResult<ScheduledPaymentResponse> {
+    let scheduled_payment = get_scheduled_payment(deps.storage, payment_id)?;
+
+    Ok(ScheduledPaymentResponse { scheduled_payment })
+}
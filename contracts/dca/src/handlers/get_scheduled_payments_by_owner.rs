@@ -0,0 +1,23 @@
+use crate::{
+    helpers::{address::normalize_address, validation::assert_page_limit_is_valid},
+    msg::ScheduledPaymentsResponse,
+    state::scheduled_payments::get_scheduled_payments_by_owner,
+};
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+pub fn get_scheduled_payments_by_owner_handler(
+    deps: Deps,
+    owner: Addr,
+    start_after: Option<u64>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<ScheduledPaymentsResponse> {
+    let owner = normalize_address(&owner);
+
+    assert_page_limit_is_valid(limit)?;
+
+    let scheduled_payments =
+        get_scheduled_payments_by_owner(deps.storage, owner, start_after, limit, reverse)?;
+
+    Ok(ScheduledPaymentsResponse { scheduled_payments })
+}
@@ -0,0 +1,99 @@
+use crate::{
+    helpers::{pagination::get_page_limit, validation::assert_page_limit_is_valid},
+    msg::{AssigneeRanking, TopAssigneesResponse},
+    state::completions::get_completion_counts,
+};
+use cosmwasm_std::{Deps, StdError, StdResult, Timestamp};
+
+pub fn get_top_assignees_handler(
+    deps: Deps,
+    period_start: Timestamp,
+    period_end: Timestamp,
+    limit: Option<u16>,
+) -> StdResult<TopAssigneesResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    if period_end < period_start {
+        return Err(StdError::generic_err(
+            "period_end must not be before period_start",
+        ));
+    }
+
+    let mut assignees = get_completion_counts(deps.storage, period_start, period_end)?
+        .into_iter()
+        .map(|(address, completions)| AssigneeRanking {
+            address,
+            completions,
+        })
+        .collect::<Vec<AssigneeRanking>>();
+
+    assignees.sort_by(|a, b| b.completions.cmp(&a.completions));
+    assignees.truncate(get_page_limit(deps.storage, limit)?);
+
+    Ok(TopAssigneesResponse { assignees })
+}
+
+#[cfg(test)]
+mod get_top_assignees_tests {
+    use super::*;
+    use crate::state::completions::record_completion;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Uint128};
+
+    #[test]
+    fn ranks_assignees_by_completions_in_period() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for (bounty_id, assignee) in [(1u128, "alice"), (2, "bob"), (3, "bob"), (4, "carol")] {
+            record_completion(
+                deps.as_mut().storage,
+                Uint128::new(bounty_id),
+                &Addr::unchecked(assignee),
+                env.block.time,
+            )
+            .unwrap();
+        }
+
+        let response =
+            get_top_assignees_handler(deps.as_ref(), env.block.time, env.block.time, Some(2))
+                .unwrap();
+
+        assert_eq!(
+            response.assignees,
+            vec![
+                AssigneeRanking {
+                    address: Addr::unchecked("bob"),
+                    completions: 2,
+                },
+                AssigneeRanking {
+                    address: Addr::unchecked("alice"),
+                    completions: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn with_period_end_before_period_start_fails() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+
+        let err = get_top_assignees_handler(
+            deps.as_ref(),
+            env.block.time,
+            env.block.time.minus_seconds(1),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: period_end must not be before period_start"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{msg::TriggerResponse, state::triggers::get_trigger};
+use cosmwasm_std::{Deps, StdError, StdResult, Uint128};
+
+pub fn get_trigger_handler(deps: Deps, bounty_id: Uint128) -> StdResult<TriggerResponse> {
+    get_trigger(deps.storage, bounty_id)?
+        .map(|trigger| TriggerResponse { trigger })
+        .ok_or_else(|| StdError::generic_err("Trigger not found"))
+}
+
+#[cfg(test)]
+mod get_trigger_handler_tests {
+    use super::get_trigger_handler;
+    use crate::{
+        state::triggers::save_trigger,
+        types::trigger::{Trigger, TriggerConfiguration},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Uint128,
+    };
+
+    #[test]
+    fn returns_trigger_configuration() {
+        let mut deps = mock_dependencies();
+
+        let trigger = Trigger {
+            bounty_id: Uint128::one(),
+            configuration: TriggerConfiguration::Time {
+                target_time: mock_env().block.time,
+            },
+            retry_count: 0,
+        };
+
+        save_trigger(deps.as_mut().storage, trigger.clone()).unwrap();
+
+        let response = get_trigger_handler(deps.as_ref(), Uint128::one()).unwrap();
+
+        assert_eq!(response.trigger, trigger);
+    }
+
+    #[test]
+    fn returns_error_if_trigger_not_found() {
+        let deps = mock_dependencies();
+
+        let err = get_trigger_handler(deps.as_ref(), Uint128::one()).unwrap_err();
+
+        assert_eq!(err.to_string(), "Generic error: Trigger not found");
+    }
+}
@@ -43,6 +43,7 @@ mod get_trigger_id_by_fin_limit_order_idx_handler_tests {
                     target_price: Decimal::percent(200),
                     order_idx,
                 },
+                retry_count: 0,
             },
         )
         .unwrap();
@@ -67,6 +68,7 @@ mod get_trigger_id_by_fin_limit_order_idx_handler_tests {
                     target_price: Decimal::percent(200),
                     order_idx,
                 },
+                retry_count: 0,
             },
         )
         .unwrap();
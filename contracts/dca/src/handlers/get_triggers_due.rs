@@ -0,0 +1,83 @@
+use crate::{
+    helpers::validation::assert_page_limit_is_valid, msg::TriggersResponse,
+    state::triggers::get_time_triggers_between,
+};
+use cosmwasm_std::{Deps, StdError, StdResult, Timestamp};
+
+pub fn get_triggers_due_handler(
+    deps: Deps,
+    from: Timestamp,
+    to: Timestamp,
+    limit: Option<u16>,
+) -> StdResult<TriggersResponse> {
+    assert_page_limit_is_valid(limit)?;
+
+    if from > to {
+        return Err(StdError::generic_err("from must not be after to"));
+    }
+
+    Ok(TriggersResponse {
+        triggers: get_time_triggers_between(deps.storage, from, to, limit)?,
+    })
+}
+
+#[cfg(test)]
+mod get_triggers_due_handler_tests {
+    use super::get_triggers_due_handler;
+    use crate::{
+        state::triggers::save_trigger,
+        types::trigger::{Trigger, TriggerConfiguration},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Uint128,
+    };
+
+    #[test]
+    fn returns_time_triggers_due_in_window() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let triggers = [0, 60, 600]
+            .iter()
+            .enumerate()
+            .map(|(index, seconds)| Trigger {
+                bounty_id: Uint128::new(index as u128 + 1),
+                configuration: TriggerConfiguration::Time {
+                    target_time: env.block.time.plus_seconds(*seconds),
+                },
+                retry_count: 0,
+            })
+            .collect::<Vec<Trigger>>();
+
+        for trigger in triggers.clone() {
+            save_trigger(deps.as_mut().storage, trigger).unwrap();
+        }
+
+        let response = get_triggers_due_handler(
+            deps.as_ref(),
+            env.block.time.plus_seconds(30),
+            env.block.time.plus_seconds(600),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(response.triggers, triggers[1..].to_vec());
+    }
+
+    #[test]
+    fn with_inverted_window_fails() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+
+        let err = get_triggers_due_handler(
+            deps.as_ref(),
+            env.block.time.plus_seconds(10),
+            env.block.time,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Generic error: from must not be after to");
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{
+    helpers::address::normalize_address, msg::UpdatePermissionsResponse,
+    state::update_permissions::get_update_permissions,
+};
+use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+
+pub fn get_update_permissions_handler(
+    deps: Deps,
+    bounty_id: Uint128,
+    delegate: Addr,
+) -> StdResult<UpdatePermissionsResponse> {
+    let delegate = normalize_address(&delegate);
+
+    let fields = get_update_permissions(deps.storage, bounty_id, &delegate)?;
+
+    Ok(UpdatePermissionsResponse { fields })
+}
@@ -2,7 +2,10 @@ use crate::{msg::BountyResponse, state::bounties::get_bounty as fetch_bounty};
 use cosmwasm_std::{Deps, StdResult, Uint128};
 
 pub fn get_bounty_handler(deps: Deps, bounty_id: Uint128) -> StdResult<BountyResponse> {
-    let bounty = fetch_bounty(deps.storage, bounty_id)?;
+    let bounty = fetch_bounty(deps.storage, bounty_id)?.without_private_metadata();
 
-    Ok(BountyResponse { bounty })
+    Ok(BountyResponse {
+        locked_until: bounty.locked_until(),
+        bounty,
+    })
 }
@@ -1,26 +1,33 @@
 use crate::{
-    helpers::{fees::get_performance_fee, price::get_twap_to_now, vault::get_performance_factor},
+    helpers::{
+        fees::get_performance_fee, price::get_twap_to_now_with_fallback,
+        vault::get_performance_factor,
+    },
     msg::BountyPerformanceResponse,
     state::{config::get_config, bounties::get_bounty},
 };
-use cosmwasm_std::{Deps, StdError, StdResult, Uint128};
+use cosmwasm_std::{Decimal, Deps, StdError, StdResult, Uint128};
 
 pub fn get_bounty_performance_handler(
     deps: Deps,
     bounty_id: Uint128,
+    at_price: Option<Decimal>,
 ) -> StdResult<BountyPerformanceResponse> {
     let bounty = get_bounty(deps.storage, bounty_id)?;
 
-    let config = get_config(deps.storage)?;
-
-    let current_price = get_twap_to_now(
-        &deps.querier,
-        config.exchange_contract_address.clone(),
-        bounty.get_swap_denom(),
-        bounty.target_denom.clone(),
-        config.twap_period,
-        bounty.route.clone(),
-    )?;
+    let (current_price, price_oracle_address) = match at_price {
+        Some(price) if price.is_zero() => {
+            return Err(StdError::generic_err("at_price must be greater than zero"))
+        }
+        Some(price) => (price, None),
+        None => get_twap_to_now_with_fallback(
+            &deps.querier,
+            &get_config(deps.storage)?,
+            bounty.get_swap_denom(),
+            bounty.target_denom.to_string(),
+            bounty.route.clone(),
+        )?,
+    };
 
     bounty.performance_assessment_strategy.clone().map_or(
         Err(StdError::GenericErr {
@@ -33,6 +40,7 @@ pub fn get_bounty_performance_handler(
             Ok(BountyPerformanceResponse {
                 fee: get_performance_fee(&bounty, current_price)?,
                 factor: get_performance_factor(&bounty, current_price)?,
+                price_oracle: price_oracle_address,
             })
         },
     )
@@ -43,6 +51,7 @@ mod get_bounty_performance_tests {
     use super::get_bounty_performance_handler;
     use crate::{
         constants::{ONE, TEN},
+        helpers::{fees::get_performance_fee, vault::get_performance_factor},
         tests::{
             helpers::{instantiate_contract, setup_bounty},
             mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK},
@@ -66,7 +75,7 @@ mod get_bounty_performance_tests {
 
         let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
 
-        let err = get_bounty_performance_handler(deps.as_ref(), bounty.id).unwrap_err();
+        let err = get_bounty_performance_handler(deps.as_ref(), bounty.id, None).unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -102,7 +111,7 @@ mod get_bounty_performance_tests {
             },
         );
 
-        let response = get_bounty_performance_handler(deps.as_ref(), bounty.id).unwrap();
+        let response = get_bounty_performance_handler(deps.as_ref(), bounty.id, None).unwrap();
 
         assert_eq!(
             response.fee,
@@ -115,4 +124,63 @@ mod get_bounty_performance_tests {
             )
         );
     }
+
+    #[test]
+    fn with_hypothetical_price_uses_supplied_price() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let performance_assessment_strategy = PerformanceAssessmentStrategy::CompareToStandardDca {
+            swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+            received_amount: Coin::new((TEN - ONE).into(), DENOM_UUSK),
+        };
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env,
+            Bounty {
+                swapped_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                escrowed_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                performance_assessment_strategy: Some(performance_assessment_strategy),
+                escrow_level: Decimal::percent(5),
+                ..Bounty::default()
+            },
+        );
+
+        let at_price = Decimal::percent(300);
+
+        let response =
+            get_bounty_performance_handler(deps.as_ref(), bounty.id, Some(at_price)).unwrap();
+
+        assert_eq!(
+            response.factor,
+            get_performance_factor(&bounty, at_price).unwrap()
+        );
+        assert_eq!(
+            response.fee,
+            get_performance_fee(&bounty, at_price).unwrap()
+        );
+    }
+
+    #[test]
+    fn with_zero_hypothetical_price_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = get_bounty_performance_handler(deps.as_ref(), bounty.id, Some(Decimal::zero()))
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: at_price must be greater than zero"
+        );
+    }
 }
@@ -1,4 +1,6 @@
+use crate::helpers::vault::project_bounties;
 use crate::state::bounties::get_bounties;
+use crate::types::bounty::Bounty;
 use crate::{helpers::validation::assert_page_limit_is_valid, msg::BountiesResponse};
 use cosmwasm_std::{Deps, StdResult, Uint128};
 
@@ -7,12 +9,16 @@ pub fn get_bounties_handler(
     start_after: Option<Uint128>,
     limit: Option<u16>,
     reverse: Option<bool>,
+    fields: Option<Vec<String>>,
 ) -> StdResult<BountiesResponse> {
     assert_page_limit_is_valid(limit)?;
 
-    let bounties = get_bounties(deps.storage, start_after, limit, reverse)?;
+    let bounties = get_bounties(deps.storage, start_after, limit, reverse)?
+        .into_iter()
+        .map(Bounty::without_private_metadata)
+        .collect::<Vec<Bounty>>();
 
-    Ok(BountiesResponse { bounties })
+    project_bounties(bounties, fields)
 }
 
 #[cfg(test)]
@@ -21,6 +27,7 @@ mod get_bounties_tests {
     use crate::tests::helpers::{instantiate_contract, setup_bounty};
     use crate::tests::mocks::ADMIN;
     use crate::types::bounty::Bounty;
+    use crate::types::bounty_projection::BountyProjection;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
     use cosmwasm_std::Uint128;
 
@@ -30,7 +37,7 @@ mod get_bounties_tests {
 
         instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
 
-        let err = get_bounties_handler(deps.as_ref(), None, Some(1001), None).unwrap_err();
+        let err = get_bounties_handler(deps.as_ref(), None, Some(1001), None, None).unwrap_err();
 
         assert_eq!(
             err.to_string(),
@@ -44,7 +51,7 @@ mod get_bounties_tests {
 
         instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
 
-        let bounties = get_bounties_handler(deps.as_ref(), None, None, None)
+        let bounties = get_bounties_handler(deps.as_ref(), None, None, None, None)
             .unwrap()
             .bounties;
 
@@ -76,7 +83,7 @@ mod get_bounties_tests {
             },
         );
 
-        let bounties = get_bounties_handler(deps.as_ref(), None, None, None)
+        let bounties = get_bounties_handler(deps.as_ref(), None, None, None, None)
             .unwrap()
             .bounties;
 
@@ -92,7 +99,7 @@ mod get_bounties_tests {
 
         let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
 
-        let bounties = get_bounties_handler(deps.as_ref(), None, None, None)
+        let bounties = get_bounties_handler(deps.as_ref(), None, None, None, None)
             .unwrap()
             .bounties;
 
@@ -117,7 +124,7 @@ mod get_bounties_tests {
             );
         }
 
-        let bounties = get_bounties_handler(deps.as_ref(), None, Some(30), None)
+        let bounties = get_bounties_handler(deps.as_ref(), None, Some(30), None, None)
             .unwrap()
             .bounties;
 
@@ -150,7 +157,7 @@ mod get_bounties_tests {
             },
         );
 
-        let bounties = get_bounties_handler(deps.as_ref(), Some(Uint128::one()), None, None)
+        let bounties = get_bounties_handler(deps.as_ref(), Some(Uint128::one()), None, None, None)
             .unwrap()
             .bounties;
 
@@ -176,11 +183,63 @@ mod get_bounties_tests {
             );
         }
 
-        let bounties = get_bounties_handler(deps.as_ref(), Some(Uint128::one()), Some(30), None)
-            .unwrap()
-            .bounties;
+        let bounties =
+            get_bounties_handler(deps.as_ref(), Some(Uint128::one()), Some(30), None, None)
+                .unwrap()
+                .bounties;
 
         assert_eq!(bounties.len(), 30);
         assert_eq!(bounties[0].id, Uint128::new(2));
     }
+
+    #[test]
+    fn with_fields_should_return_projections_only() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let response = get_bounties_handler(
+            deps.as_ref(),
+            None,
+            None,
+            None,
+            Some(vec!["owner".to_string(), "status".to_string()]),
+        )
+        .unwrap();
+
+        assert!(response.bounties.is_empty());
+        assert_eq!(
+            response.projections.unwrap(),
+            vec![BountyProjection {
+                id: bounty.id,
+                owner: Some(bounty.owner),
+                status: Some(bounty.status),
+                ..BountyProjection::default()
+            }]
+        );
+    }
+
+    #[test]
+    fn with_unknown_field_should_fail() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = get_bounties_handler(
+            deps.as_ref(),
+            None,
+            None,
+            None,
+            Some(vec!["route".to_string()]),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: unknown bounty field: route"
+        );
+    }
 }
@@ -1,5 +1,7 @@
+use crate::helpers::address::normalize_address;
+use crate::helpers::vault::project_bounties;
 use crate::state::bounties::get_bounties_by_address as fetch_bounties_by_address;
-use crate::types::bounty::BountyStatus;
+use crate::types::bounty::{Bounty, BountyStatus};
 use crate::{helpers::validation::assert_page_limit_is_valid, msg::BountiesResponse};
 use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
 
@@ -9,13 +11,21 @@ pub fn get_bounties_by_address_handler(
     status: Option<BountyStatus>,
     start_after: Option<Uint128>,
     limit: Option<u16>,
+    reverse: Option<bool>,
+    fields: Option<Vec<String>>,
 ) -> StdResult<BountiesResponse> {
+    let address = normalize_address(&address);
+
     deps.api.addr_validate(address.as_ref())?;
     assert_page_limit_is_valid(limit)?;
 
-    let bounties = fetch_bounties_by_address(deps.storage, address, status, start_after, limit)?;
+    let bounties =
+        fetch_bounties_by_address(deps.storage, address, status, start_after, limit, reverse)?
+            .into_iter()
+            .map(Bounty::without_private_metadata)
+            .collect::<Vec<Bounty>>();
 
-    Ok(BountiesResponse { bounties })
+    project_bounties(bounties, fields)
 }
 
 #[cfg(test)]
@@ -45,6 +55,8 @@ mod get_bounties_by_address_tests {
                     status: None,
                     start_after: None,
                     limit: None,
+                    reverse: None,
+                    fields: None,
                 },
             )
             .unwrap(),
@@ -75,6 +87,8 @@ mod get_bounties_by_address_tests {
                     status: None,
                     start_after: None,
                     limit: None,
+                    reverse: None,
+                    fields: None,
                 },
             )
             .unwrap(),
@@ -106,6 +120,8 @@ mod get_bounties_by_address_tests {
                     status: None,
                     start_after: None,
                     limit: Some(30),
+                    reverse: None,
+                    fields: None,
                 },
             )
             .unwrap(),
@@ -137,6 +153,8 @@ mod get_bounties_by_address_tests {
                     status: None,
                     start_after: Some(Uint128::zero()),
                     limit: None,
+                    reverse: None,
+                    fields: None,
                 },
             )
             .unwrap(),
@@ -169,6 +187,8 @@ mod get_bounties_by_address_tests {
                     status: None,
                     start_after: Some(Uint128::one()),
                     limit: Some(30),
+                    reverse: None,
+                    fields: None,
                 },
             )
             .unwrap(),
@@ -196,6 +216,8 @@ mod get_bounties_by_address_tests {
                 status: None,
                 start_after: Some(Uint128::one()),
                 limit: Some(10000),
+                reverse: None,
+                fields: None,
             },
         )
         .unwrap_err();
@@ -250,6 +272,8 @@ mod get_bounties_by_address_tests {
                     status: Some(BountyStatus::Active),
                     start_after: None,
                     limit: None,
+                    reverse: None,
+                    fields: None,
                 },
             )
             .unwrap(),
@@ -0,0 +1,101 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        address::normalize_address,
+        validation::{assert_bounty_is_not_cancelled, asset_sender_is_bounty_owner},
+    },
+    state::{authz::save_operator_permissions, bounties::get_bounty},
+    types::authz::OperatorPermission,
+};
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response, Uint128};
+
+pub fn grant_bounty_operator_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    operator: Addr,
+    permissions: Vec<OperatorPermission>,
+) -> Result<Response, ContractError> {
+    let operator = normalize_address(&operator);
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+
+    deps.api.addr_validate(operator.as_ref())?;
+
+    if permissions.is_empty() {
+        return Err(ContractError::CustomError {
+            val: "must grant at least one permission".to_string(),
+        });
+    }
+
+    save_operator_permissions(deps.storage, bounty.id, &operator, &permissions)?;
+
+    Ok(Response::new()
+        .add_attribute("grant_bounty_operator", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("operator", operator)
+        .add_attribute("permissions", format!("{:?}", permissions)))
+}
+
+#[cfg(test)]
+mod grant_bounty_operator_tests {
+    use super::*;
+    use crate::state::authz::get_operator_permissions;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_sender_that_is_not_owner_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = grant_bounty_operator_handler(
+            deps.as_mut(),
+            mock_info("stranger", &[]),
+            bounty.id,
+            Addr::unchecked("operator"),
+            vec![OperatorPermission::Cancel],
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn saves_operator_permissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        grant_bounty_operator_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("operator"),
+            vec![OperatorPermission::UpdateLabel, OperatorPermission::TopUp],
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_operator_permissions(
+                deps.as_ref().storage,
+                bounty.id,
+                &Addr::unchecked("operator")
+            )
+            .unwrap(),
+            vec![OperatorPermission::UpdateLabel, OperatorPermission::TopUp]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{admin_actions::create_admin_action, roles::grant_role},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn grant_role_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+    role: Role,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    deps.api.addr_validate(address.as_ref())?;
+
+    grant_role(deps.storage, &address, &role)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::RoleGranted {
+                address: address.clone(),
+                role: role.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("grant_role", "true")
+        .add_attribute("address", address)
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+#[cfg(test)]
+mod grant_role_tests {
+    use super::*;
+    use crate::state::roles::get_roles;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = grant_role_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Addr::unchecked(USER),
+            Role::Admin,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn grants_role_to_address() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        grant_role_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("fee-manager"),
+            Role::FeeManager,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_roles(deps.as_ref().storage, &Addr::unchecked("fee-manager")).unwrap(),
+            vec![Role::FeeManager]
+        );
+    }
+}
@@ -2,13 +2,22 @@ use crate::{
     error::ContractError,
     state::{
         cache::{POST_EXECUTION_ACTION_CACHE, BOUNTY_ID_CACHE},
+        callback_results::save_callback_result,
         events::create_event,
+        ibc_transfers::{save_failed_ibc_transfer, FailedIbcTransfer},
         bounties::get_bounty,
     },
-    types::event::{EventBuilder, EventData},
+    types::{
+        callback_result::CallbackResult,
+        destination::IbcDestination,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{
+    Addr, CosmosMsg, DepsMut, Env, IbcMsg, Reply, Response, StdResult, SubMsg, SubMsgResult,
+    WasmMsg,
 };
-use cosmwasm_std::{DepsMut, Env, Reply, Response, SubMsg, SubMsgResult};
-use shared::cw20::into_bank_msg;
+use shared::cw20::into_transfer_msg;
 
 pub fn handle_failed_automation_handler(
     deps: DepsMut,
@@ -20,13 +29,40 @@ pub fn handle_failed_automation_handler(
 
     let mut cache = POST_EXECUTION_ACTION_CACHE.load(deps.storage, bounty_id.into())?;
     let entry = cache.pop_front().unwrap();
-    POST_EXECUTION_ACTION_CACHE.save(deps.storage, bounty_id.into(), &cache)?;
+
+    if cache.is_empty() {
+        POST_EXECUTION_ACTION_CACHE.remove(deps.storage, bounty_id.into());
+    } else {
+        POST_EXECUTION_ACTION_CACHE.save(deps.storage, bounty_id.into(), &cache)?;
+    }
 
     let destination_num = bounty.destinations.len() - cache.len();
 
     Ok(match reply.result {
-        SubMsgResult::Ok(_) => Response::new()
-            .add_attribute(format!("destination_msg_{}", destination_num), "succeeded"),
+        SubMsgResult::Ok(response) => {
+            let mut response_with_attributes = Response::new()
+                .add_attribute(format!("destination_msg_{}", destination_num), "succeeded");
+
+            if let (Some(data), CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. })) =
+                (response.data, entry.msg.msg)
+            {
+                save_callback_result(
+                    deps.storage,
+                    bounty_id,
+                    &CallbackResult {
+                        execution_height: env.block.height,
+                        destination_index: destination_num as u32,
+                        destination: Addr::unchecked(contract_addr),
+                        data,
+                    },
+                )?;
+
+                response_with_attributes = response_with_attributes
+                    .add_attribute(format!("destination_msg_{}_data", destination_num), "saved");
+            }
+
+            response_with_attributes
+        }
         SubMsgResult::Err(_) => {
             create_event(
                 deps.storage,
@@ -34,19 +70,49 @@ pub fn handle_failed_automation_handler(
                     bounty_id,
                     env.block,
                     EventData::DcaVaultPostExecutionActionFailed {
-                        msg: entry.msg,
+                        msg: entry.msg.clone(),
                         funds: entry.funds.clone(),
                     },
                 ),
             )?;
 
+            if let CosmosMsg::Ibc(IbcMsg::Transfer {
+                channel_id,
+                to_address,
+                amount,
+                ..
+            }) = entry.msg.msg
+            {
+                save_failed_ibc_transfer(
+                    deps.storage,
+                    bounty_id,
+                    FailedIbcTransfer {
+                        destination: IbcDestination {
+                            channel_id,
+                            remote_address: to_address,
+                            timeout_seconds: None,
+                        },
+                        amount,
+                    },
+                )?;
+
+                return Ok(Response::new()
+                    .add_attribute(format!("destination_msg_{}", destination_num), "failed")
+                    .add_attribute("ibc_transfer_awaiting_retry", "true"));
+            }
+
             Response::new()
                 .add_attribute(format!("destination_msg_{}", destination_num), "failed")
-                .add_submessage(SubMsg::new(into_bank_msg(
-                    deps.api,
-                    bounty.owner.as_ref(),
-                    entry.funds,
-                )?))
+                .add_submessages(
+                    entry
+                        .funds
+                        .into_iter()
+                        .map(|amount| {
+                            into_transfer_msg(deps.api, bounty.owner.as_ref(), amount)
+                                .map(SubMsg::new)
+                        })
+                        .collect::<StdResult<Vec<SubMsg>>>()?,
+                )
         }
     })
 }
@@ -58,21 +124,26 @@ mod handle_failed_automation_handler_tests {
         constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
         handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
         helpers::disbursement::get_disbursement_messages,
-        state::cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
+        state::{
+            cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
+            callback_results::get_callback_results,
+            ibc_transfers::{get_failed_ibc_transfers, FailedIbcTransfer},
+        },
         tests::{
             helpers::{instantiate_contract, setup_bounty},
             mocks::ADMIN,
         },
         types::{
-            destination::Destination,
+            callback_result::CallbackResult,
+            destination::{Destination, DisbursementOutcome, IbcDestination},
             event::{EventBuilder, EventData},
             bounty::Bounty,
         },
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        to_json_binary, Addr, BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse, SubMsgResult,
-        WasmMsg,
+        to_json_binary, Addr, BankMsg, Binary, Coin, Decimal, Reply, SubMsg, SubMsgResponse,
+        SubMsgResult, WasmMsg,
     };
     use std::collections::VecDeque;
 
@@ -86,6 +157,9 @@ mod handle_failed_automation_handler_tests {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("contract"),
@@ -98,6 +172,9 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -113,8 +190,12 @@ mod handle_failed_automation_handler_tests {
         get_disbursement_messages(
             &deps.api.clone(),
             deps.as_mut().storage,
+            env.block.time,
             &bounty,
             bounty.swap_amount,
+            &DisbursementOutcome::Succeeded {
+                received: bounty.swap_amount,
+            },
         )
         .unwrap();
 
@@ -167,6 +248,9 @@ mod handle_failed_automation_handler_tests {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("contract"),
@@ -179,6 +263,9 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -194,8 +281,12 @@ mod handle_failed_automation_handler_tests {
         get_disbursement_messages(
             &deps.api.clone(),
             deps.as_mut().storage,
+            env.block.time,
             &bounty,
             bounty.swap_amount,
+            &DisbursementOutcome::Succeeded {
+                received: bounty.swap_amount,
+            },
         )
         .unwrap();
 
@@ -215,6 +306,66 @@ mod handle_failed_automation_handler_tests {
         assert!(response.messages.is_empty());
     }
 
+    #[test]
+    fn saves_callback_result_data_on_success() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                destinations: vec![Destination {
+                    address: Addr::unchecked("contract"),
+                    allocation: Decimal::percent(100),
+                    msg: Some(to_json_binary("mint_receipt").unwrap()),
+                    condition: None,
+                    ibc: None,
+                    gas_limit: None,
+                }],
+                ..Bounty::default()
+            },
+        );
+
+        get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            env.block.time,
+            &bounty,
+            bounty.swap_amount,
+            &DisbursementOutcome::Succeeded {
+                received: bounty.swap_amount,
+            },
+        )
+        .unwrap();
+
+        let data = Binary::from(b"receipt-1".to_vec());
+
+        handle_failed_automation_handler(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: Some(data.clone()),
+                }),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_callback_results(deps.as_ref().storage, bounty.id, Some(env.block.height))
+                .unwrap(),
+            vec![CallbackResult {
+                execution_height: env.block.height,
+                destination_index: 1,
+                destination: Addr::unchecked("contract"),
+                data,
+            }]
+        );
+    }
+
     #[test]
     fn removes_appropriate_post_execution_action_cache_entry_on_failure() {
         let mut deps = mock_dependencies();
@@ -225,6 +376,9 @@ mod handle_failed_automation_handler_tests {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("contract"),
@@ -237,6 +391,9 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -252,8 +409,12 @@ mod handle_failed_automation_handler_tests {
         get_disbursement_messages(
             &deps.api.clone(),
             deps.as_mut().storage,
+            env.block.time,
             &bounty,
             bounty.swap_amount,
+            &DisbursementOutcome::Succeeded {
+                received: bounty.swap_amount,
+            },
         )
         .unwrap();
 
@@ -312,6 +473,9 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("contract2"),
@@ -324,6 +488,9 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -339,8 +506,12 @@ mod handle_failed_automation_handler_tests {
         get_disbursement_messages(
             &deps.api.clone(),
             deps.as_mut().storage,
+            env.block.time,
             &bounty,
             bounty.swap_amount,
+            &DisbursementOutcome::Succeeded {
+                received: bounty.swap_amount,
+            },
         )
         .unwrap();
 
@@ -404,6 +575,9 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("contract2"),
@@ -416,6 +590,9 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -431,8 +608,12 @@ mod handle_failed_automation_handler_tests {
         get_disbursement_messages(
             &deps.api.clone(),
             deps.as_mut().storage,
+            env.block.time,
             &bounty,
             bounty.swap_amount,
+            &DisbursementOutcome::Succeeded {
+                received: bounty.swap_amount,
+            },
         )
         .unwrap();
 
@@ -457,4 +638,61 @@ mod handle_failed_automation_handler_tests {
             })]
         );
     }
+
+    #[test]
+    fn saves_failed_ibc_transfer_for_retry_instead_of_refunding_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let ibc_destination = IbcDestination {
+            channel_id: "channel-0".to_string(),
+            remote_address: "osmo1remote".to_string(),
+            timeout_seconds: None,
+        };
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                destinations: vec![Destination {
+                    ibc: Some(ibc_destination.clone()),
+                    ..Destination::default()
+                }],
+                ..Bounty::default()
+            },
+        );
+
+        get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            env.block.time,
+            &bounty,
+            bounty.swap_amount,
+            &DisbursementOutcome::Succeeded {
+                received: bounty.swap_amount,
+            },
+        )
+        .unwrap();
+
+        let response = handle_failed_automation_handler(
+            deps.as_mut(),
+            env,
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Err("error".to_string()),
+            },
+        )
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            get_failed_ibc_transfers(deps.as_ref().storage, bounty.id).unwrap(),
+            vec![FailedIbcTransfer {
+                destination: ibc_destination,
+                amount: Coin::new(bounty.swap_amount.into(), bounty.target_denom),
+            }]
+        );
+    }
 }
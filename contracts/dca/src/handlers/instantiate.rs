@@ -2,15 +2,19 @@ use crate::{
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
     error::ContractError,
     helpers::validation::{
-        assert_addresses_are_valid, assert_fee_collector_addresses_are_valid,
-        assert_fee_collector_allocations_add_up_to_one, assert_fee_level_is_valid,
-        assert_no_more_than_10_fee_collectors, assert_page_limit_is_valid,
+        assert_address_is_valid, assert_addresses_are_valid,
+        assert_auto_route_safety_margin_is_no_greater_than_100_percent,
+        assert_executor_reward_percent_is_no_greater_than_100_percent,
+        assert_fee_collector_addresses_are_valid, assert_fee_collector_allocations_add_up_to_one,
+        assert_fee_level_is_valid, assert_no_more_than_10_fee_collectors,
+        assert_page_limit_is_valid, assert_referral_fee_percent_is_no_greater_than_100_percent,
         assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent,
+        assert_slippage_retry_interval_is_valid,
         assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_twap_period_is_valid,
     },
     msg::InstantiateMsg,
-    state::config::update_config,
-    types::config::Config,
+    state::{config::update_config, keys::initialise_storage_version, roles::grant_role},
+    types::{config::Config, role::Role},
 };
 use cosmwasm_std::{DepsMut, Response};
 use cw2::set_contract_version;
@@ -32,25 +36,69 @@ pub fn instantiate_handler(deps: DepsMut, msg: InstantiateMsg) -> Result<Respons
         msg.risk_weighted_average_escrow_level,
     )?;
 
+    assert_referral_fee_percent_is_no_greater_than_100_percent(msg.referral_fee_percent)?;
+    assert_executor_reward_percent_is_no_greater_than_100_percent(msg.executor_reward_percent)?;
+    assert_auto_route_safety_margin_is_no_greater_than_100_percent(
+        msg.auto_route_safety_margin,
+    )?;
+    assert_addresses_are_valid(deps.as_ref(), &msg.exchange_adapters, "exchange adapter")?;
+    assert_slippage_retry_interval_is_valid(msg.slippage_retry_interval_seconds)?;
+
+    if let Some(price_oracle_address) = &msg.price_oracle_address {
+        assert_address_is_valid(deps.as_ref(), price_oracle_address, "price oracle")?;
+    }
+
+    if let Some(fee_grant) = &msg.fee_grant {
+        assert_addresses_are_valid(deps.as_ref(), &fee_grant.partners, "fee grant partner")?;
+    }
+
     update_config(
         deps.storage,
         Config {
             admin: msg.admin.clone(),
-            executors: msg.executors.clone(),
+            pending_admin: None,
             fee_collectors: msg.fee_collectors.clone(),
             default_swap_fee_percent: msg.default_swap_fee_percent,
             weighted_scale_swap_fee_percent: msg.weighted_scale_swap_fee_percent,
             automation_fee_percent: msg.automation_fee_percent,
             default_page_limit: msg.default_page_limit,
             paused: msg.paused,
+            create_paused: false,
+            execute_paused: false,
+            deposit_paused: false,
+            disburse_paused: false,
             risk_weighted_average_escrow_level: msg.risk_weighted_average_escrow_level,
             twap_period: msg.twap_period,
             default_slippage_tolerance: msg.default_slippage_tolerance,
             exchange_contract_address: msg.exchange_contract_address,
+            arbiter: msg.arbiter.clone(),
+            fee_grant: msg.fee_grant.clone(),
+            simulation_mode: msg.simulation_mode,
+            referral_fee_percent: msg.referral_fee_percent,
+            executor_reward_percent: msg.executor_reward_percent,
+            minimum_deposit: msg.minimum_deposit.clone(),
+            minimum_swap_amount: msg.minimum_swap_amount,
+            event_retention_blocks: msg.event_retention_blocks,
+            max_bounty_balance: msg.max_bounty_balance.clone(),
+            max_total_balance_per_owner: msg.max_total_balance_per_owner.clone(),
+            exchange_adapters: msg.exchange_adapters.clone(),
+            auto_route_safety_margin: msg.auto_route_safety_margin,
+            price_oracle_address: msg.price_oracle_address.clone(),
+            rounding_mode: msg.rounding_mode.clone(),
+            address_prefix: msg.address_prefix.clone(),
+            slippage_retry_interval_seconds: msg.slippage_retry_interval_seconds,
+            max_slippage_retries: msg.max_slippage_retries,
         },
     )?;
 
+    grant_role(deps.storage, &msg.admin, &Role::Admin)?;
+
+    for executor in &msg.executors {
+        grant_role(deps.storage, executor, &Role::Executor)?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    initialise_storage_version(deps.storage)?;
 
     Ok(Response::new()
         .add_attribute("instantiate", "true")
@@ -63,8 +111,9 @@ mod instantiate_tests {
     use crate::contract::instantiate;
     use crate::msg::InstantiateMsg;
     use crate::types::fee_collector::FeeCollector;
+    use crate::types::rounding_mode::RoundingMode;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{attr, Addr, Decimal};
+    use cosmwasm_std::{attr, Addr, Decimal, Uint128};
     use std::str::FromStr;
 
     pub const INVALID_ADDRESS: &str = "";
@@ -79,7 +128,7 @@ mod instantiate_tests {
         let instantiate_message = InstantiateMsg {
             admin: Addr::unchecked(VALID_ADDRESS_ONE),
             executors: vec![Addr::unchecked("executor")],
-            fee_collectors: vec![FeeCollector {
+            fee_collectors: vec![FeeCollector::Address {
                 address: VALID_ADDRESS_ONE.to_string(),
                 allocation: Decimal::from_str("1").unwrap(),
             }],
@@ -92,6 +141,23 @@ mod instantiate_tests {
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
             exchange_contract_address: Addr::unchecked(EXCHANGE_CONTRACT_ADDRESS),
+            arbiter: None,
+            fee_grant: None,
+            simulation_mode: false,
+            referral_fee_percent: Decimal::percent(10),
+            executor_reward_percent: Decimal::percent(10),
+            minimum_deposit: vec![],
+            minimum_swap_amount: Uint128::zero(),
+            event_retention_blocks: None,
+            max_bounty_balance: vec![],
+            max_total_balance_per_owner: vec![],
+            exchange_adapters: vec![],
+            auto_route_safety_margin: Decimal::zero(),
+            price_oracle_address: None,
+            rounding_mode: RoundingMode::FloorToProtocol,
+            address_prefix: None,
+            slippage_retry_interval_seconds: 600,
+            max_slippage_retries: 3,
         };
 
         let result = instantiate(deps.as_mut(), env, info, instantiate_message).unwrap();
@@ -114,7 +180,7 @@ mod instantiate_tests {
         let instantiate_message = InstantiateMsg {
             admin: Addr::unchecked(INVALID_ADDRESS),
             executors: vec![Addr::unchecked("executor")],
-            fee_collectors: vec![FeeCollector {
+            fee_collectors: vec![FeeCollector::Address {
                 address: VALID_ADDRESS_ONE.to_string(),
                 allocation: Decimal::from_str("1").unwrap(),
             }],
@@ -127,6 +193,23 @@ mod instantiate_tests {
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
             exchange_contract_address: Addr::unchecked(EXCHANGE_CONTRACT_ADDRESS),
+            arbiter: None,
+            fee_grant: None,
+            simulation_mode: false,
+            referral_fee_percent: Decimal::percent(10),
+            executor_reward_percent: Decimal::percent(10),
+            minimum_deposit: vec![],
+            minimum_swap_amount: Uint128::zero(),
+            event_retention_blocks: None,
+            max_bounty_balance: vec![],
+            max_total_balance_per_owner: vec![],
+            exchange_adapters: vec![],
+            auto_route_safety_margin: Decimal::zero(),
+            price_oracle_address: None,
+            rounding_mode: RoundingMode::FloorToProtocol,
+            address_prefix: None,
+            slippage_retry_interval_seconds: 600,
+            max_slippage_retries: 3,
         };
 
         let result = instantiate(deps.as_mut(), env, info, instantiate_message).unwrap_err();
@@ -146,7 +229,7 @@ mod instantiate_tests {
         let instantiate_message = InstantiateMsg {
             admin: Addr::unchecked(VALID_ADDRESS_ONE),
             executors: vec![Addr::unchecked("executor")],
-            fee_collectors: vec![FeeCollector {
+            fee_collectors: vec![FeeCollector::Address {
                 address: INVALID_ADDRESS.to_string(),
                 allocation: Decimal::from_str("1").unwrap(),
             }],
@@ -159,6 +242,23 @@ mod instantiate_tests {
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
             exchange_contract_address: Addr::unchecked(EXCHANGE_CONTRACT_ADDRESS),
+            arbiter: None,
+            fee_grant: None,
+            simulation_mode: false,
+            referral_fee_percent: Decimal::percent(10),
+            executor_reward_percent: Decimal::percent(10),
+            minimum_deposit: vec![],
+            minimum_swap_amount: Uint128::zero(),
+            event_retention_blocks: None,
+            max_bounty_balance: vec![],
+            max_total_balance_per_owner: vec![],
+            exchange_adapters: vec![],
+            auto_route_safety_margin: Decimal::zero(),
+            price_oracle_address: None,
+            rounding_mode: RoundingMode::FloorToProtocol,
+            address_prefix: None,
+            slippage_retry_interval_seconds: 600,
+            max_slippage_retries: 3,
         };
 
         let result = instantiate(deps.as_mut(), env, info, instantiate_message).unwrap_err();
@@ -188,6 +288,23 @@ mod instantiate_tests {
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
             exchange_contract_address: Addr::unchecked(EXCHANGE_CONTRACT_ADDRESS),
+            arbiter: None,
+            fee_grant: None,
+            simulation_mode: false,
+            referral_fee_percent: Decimal::percent(10),
+            executor_reward_percent: Decimal::percent(10),
+            minimum_deposit: vec![],
+            minimum_swap_amount: Uint128::zero(),
+            event_retention_blocks: None,
+            max_bounty_balance: vec![],
+            max_total_balance_per_owner: vec![],
+            exchange_adapters: vec![],
+            auto_route_safety_margin: Decimal::zero(),
+            price_oracle_address: None,
+            rounding_mode: RoundingMode::FloorToProtocol,
+            address_prefix: None,
+            slippage_retry_interval_seconds: 600,
+            max_slippage_retries: 3,
         };
 
         let result = instantiate(deps.as_mut(), env, info, instantiate_message).unwrap_err();
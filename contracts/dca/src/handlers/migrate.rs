@@ -2,15 +2,22 @@ use crate::{
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
     error::ContractError,
     helpers::validation::{
-        assert_addresses_are_valid, assert_fee_collector_addresses_are_valid,
-        assert_fee_collector_allocations_add_up_to_one, assert_fee_level_is_valid,
-        assert_no_more_than_10_fee_collectors, assert_page_limit_is_valid,
+        assert_addresses_are_valid, assert_executor_reward_percent_is_no_greater_than_100_percent,
+        assert_fee_collector_addresses_are_valid, assert_fee_collector_allocations_add_up_to_one,
+        assert_fee_level_is_valid, assert_no_more_than_10_fee_collectors,
+        assert_page_limit_is_valid, assert_referral_fee_percent_is_no_greater_than_100_percent,
         assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent,
         assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_twap_period_is_valid,
     },
     msg::MigrateMsg,
-    state::config::update_config,
-    types::config::Config,
+    state::{
+        bounties::{migrate_legacy_vaults, rebuild_owner_balances},
+        config::update_config,
+        events::{index_events_by_block_height, migrate_legacy_bounty_updated_events},
+        keys::migrate_storage_keys,
+        roles::grant_role,
+    },
+    types::{config::Config, role::Role},
 };
 use cosmwasm_std::{DepsMut, Response, StdError};
 use cw2::{get_contract_version, set_contract_version};
@@ -32,21 +39,49 @@ pub fn migrate_handler(deps: DepsMut, msg: MigrateMsg) -> Result<Response, Contr
         msg.risk_weighted_average_escrow_level,
     )?;
 
+    assert_referral_fee_percent_is_no_greater_than_100_percent(msg.referral_fee_percent)?;
+    assert_executor_reward_percent_is_no_greater_than_100_percent(msg.executor_reward_percent)?;
+
+    if let Some(fee_grant) = &msg.fee_grant {
+        assert_addresses_are_valid(deps.as_ref(), &fee_grant.partners, "fee grant partner")?;
+    }
+
     update_config(
         deps.storage,
         Config {
             admin: msg.admin.clone(),
-            executors: msg.executors.clone(),
+            pending_admin: None,
             fee_collectors: msg.fee_collectors.clone(),
             default_swap_fee_percent: msg.default_swap_fee_percent,
             weighted_scale_swap_fee_percent: msg.weighted_scale_swap_fee_percent,
             automation_fee_percent: msg.automation_fee_percent,
             default_page_limit: msg.default_page_limit,
             paused: msg.paused,
+            create_paused: false,
+            execute_paused: false,
+            deposit_paused: false,
+            disburse_paused: false,
             risk_weighted_average_escrow_level: msg.risk_weighted_average_escrow_level,
             twap_period: msg.twap_period,
             default_slippage_tolerance: msg.default_slippage_tolerance,
             exchange_contract_address: msg.exchange_contract_address.clone(),
+            arbiter: msg.arbiter.clone(),
+            fee_grant: msg.fee_grant.clone(),
+            simulation_mode: msg.simulation_mode,
+            referral_fee_percent: msg.referral_fee_percent,
+            executor_reward_percent: msg.executor_reward_percent,
+            minimum_deposit: msg.minimum_deposit.clone(),
+            minimum_swap_amount: msg.minimum_swap_amount,
+            event_retention_blocks: msg.event_retention_blocks,
+            max_bounty_balance: msg.max_bounty_balance.clone(),
+            max_total_balance_per_owner: msg.max_total_balance_per_owner.clone(),
+            exchange_adapters: msg.exchange_adapters.clone(),
+            auto_route_safety_margin: msg.auto_route_safety_margin,
+            price_oracle_address: msg.price_oracle_address.clone(),
+            rounding_mode: msg.rounding_mode.clone(),
+            address_prefix: msg.address_prefix.clone(),
+            slippage_retry_interval_seconds: msg.slippage_retry_interval_seconds,
+            max_slippage_retries: msg.max_slippage_retries,
         },
     )?;
 
@@ -63,7 +98,27 @@ pub fn migrate_handler(deps: DepsMut, msg: MigrateMsg) -> Result<Response, Contr
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    grant_role(deps.storage, &msg.admin, &Role::Admin)?;
+
+    for executor in &msg.executors {
+        grant_role(deps.storage, executor, &Role::Executor)?;
+    }
+
+    let migrated_keys = migrate_storage_keys(deps.storage)?;
+    let migration_limit = msg
+        .legacy_vault_migration_limit
+        .unwrap_or(msg.default_page_limit);
+    let migrated_events = migrate_legacy_bounty_updated_events(deps.storage, migration_limit)?;
+    let indexed_events = index_events_by_block_height(deps.storage, migration_limit)?;
+    let migrated_bounties = migrate_legacy_vaults(deps.storage, migration_limit)?;
+    let indexed_owner_balances = rebuild_owner_balances(deps.storage, migration_limit)?;
+
     Ok(Response::new()
         .add_attribute("migrate", "true")
+        .add_attribute("migrated_keys", migrated_keys.to_string())
+        .add_attribute("migrated_events", migrated_events.to_string())
+        .add_attribute("indexed_events", indexed_events.to_string())
+        .add_attribute("migrated_bounties", migrated_bounties.to_string())
+        .add_attribute("indexed_owner_balances", indexed_owner_balances.to_string())
         .add_attribute("msg", format!("{:?}", msg)))
 }
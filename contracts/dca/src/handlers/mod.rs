@@ -1,24 +1,134 @@
+pub mod accept_admin;
+pub mod accept_application;
+pub mod add_allowed_denom;
+pub mod add_exchange;
+pub mod add_fee_exemption;
+pub mod add_funding_denom;
+pub mod add_model_provider;
+pub mod apply_to_bounty;
+pub mod assign_bounty;
+pub mod bulk_update_slippage;
+pub mod cancel_all_bounties;
 pub mod cancel_bounty;
+pub mod cancel_scheduled_config_update;
+pub mod cancel_scheduled_payment;
+pub mod claim_vested;
+pub mod complete_checklist_item;
+pub mod contribute_to_round;
 pub mod create_bounty;
+pub mod create_bounty_from_template;
+pub mod create_funding_round;
+pub mod delete_bounty_template;
 pub mod deposit;
 pub mod disburse_escrow;
 pub mod disburse_funds;
+pub mod execute_escrow_accept;
+pub mod execute_escrow_reject;
+pub mod execute_scheduled_payment;
 pub mod execute_trigger;
+pub mod execute_triggers;
+pub mod finalize_round;
+pub mod get_admin_actions;
+pub mod get_allowed_denoms;
+pub mod get_applications;
+pub mod get_assignees;
+pub mod get_balance_trigger_ids;
+pub mod get_block_trigger_ids;
+pub mod get_bounty_balances;
+pub mod get_bounty_snapshots;
+pub mod get_bounty_template;
+pub mod get_callback_results;
+pub mod get_checklist;
 pub mod get_config;
+pub mod get_conversion_report;
+pub mod get_custom_swap_fees;
+pub mod get_cw20_whitelist;
 pub mod get_disburse_escrow_tasks;
+pub mod get_dispute;
+pub mod get_due_scheduled_payments;
+pub mod get_escrow_epochs;
+pub mod get_escrow_tranches;
+pub mod get_event_summary;
 pub mod get_events;
 pub mod get_events_by_resource_id;
+pub mod get_exchanges;
+pub mod get_executor_stats;
+pub mod get_fee_exemptions;
+pub mod get_funding_round;
+pub mod get_gas_usage_report;
+pub mod get_model_adjustment;
+pub mod get_model_providers;
+pub mod get_owner_digest;
 pub mod get_pairs;
+pub mod get_progress_updates;
+pub mod get_referrer_stats;
+pub mod get_roles;
+pub mod get_round_contributions;
+pub mod get_scheduled_config_updates;
+pub mod get_scheduled_payment;
+pub mod get_scheduled_payments_by_owner;
 pub mod get_time_trigger_ids;
+pub mod get_top_assignees;
+pub mod get_trigger;
 pub mod get_trigger_id_by_fin_limit_order_idx;
+pub mod get_triggers_due;
+pub mod get_update_permissions;
 pub mod get_bounty;
+pub mod get_bounty_with_key;
 pub mod get_bounty_performance;
 pub mod get_bounties;
 pub mod get_bounties_by_address;
+pub mod get_bounties_by_status;
+pub mod get_bounties_by_tag;
+pub mod grant_bounty_operator;
+pub mod grant_role;
 pub mod handle_failed_automation;
 pub mod instantiate;
 pub mod migrate;
+pub mod pause_bounty;
+pub mod post_progress_update;
+pub mod probe_trigger;
+pub mod process_refund_queue;
+pub mod propose_new_admin;
+pub mod prune_events;
+pub mod raise_dispute;
+pub mod refund_failed_ibc_transfers;
+pub mod register_referrer;
+pub mod remove_allowed_denom;
+pub mod remove_custom_swap_fee;
+pub mod remove_cw20_token;
+pub mod remove_exchange;
+pub mod remove_fee_exemption;
+pub mod remove_model_provider;
+pub mod report_executor;
+pub mod reprice_trigger;
+pub mod request_escrow_release;
+pub mod resolve_dispute;
+pub mod resume_bounty;
+pub mod retry_failed_ibc_transfers;
+pub mod revoke_bounty_operator;
+pub mod revoke_role;
+pub mod save_bounty_template;
+pub mod schedule_config_update;
+pub mod schedule_payment;
+pub mod set_bounty_snapshots;
+pub mod set_checklist;
+pub mod set_circuit_breakers;
+pub mod set_custom_swap_fee;
+pub mod set_escrow_tranches;
+pub mod set_pair_exchange;
+pub mod set_paused;
+pub mod set_payout_address;
+pub mod set_update_permissions;
+pub mod set_viewing_key;
+pub mod simulate_execution;
+pub mod submit_model_adjustment;
+pub mod submit_work;
+pub mod unassign_bounty;
 pub mod update_config;
 pub mod update_swap_adjustment_handler;
 pub mod update_bounty;
+pub mod whitelist_cw20_token;
+pub mod withdraw;
+pub mod withdraw_application;
 pub mod z_delegate;
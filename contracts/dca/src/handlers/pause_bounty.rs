@@ -0,0 +1,149 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{assert_bounty_is_active, asset_sender_is_bounty_owner},
+    state::{
+        bounties::{get_bounty, update_bounty},
+        events::create_event,
+        triggers::delete_trigger,
+    },
+    types::{
+        bounty::{Bounty, BountyStatus},
+        event::{EventBuilder, EventData},
+        trigger::TriggerConfiguration,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn pause_bounty_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_active(&bounty)?;
+
+    update_bounty(
+        deps.storage,
+        Bounty {
+            status: BountyStatus::Paused,
+            ..bounty.clone()
+        },
+    )?;
+
+    // Balance and block height triggers carry their own configuration, so they
+    // are kept while paused rather than being rebuilt from the time interval on
+    // resume.
+    if !matches!(
+        bounty.trigger,
+        Some(TriggerConfiguration::BalanceAbove { .. } | TriggerConfiguration::BlockHeight { .. })
+    ) {
+        delete_trigger(deps.storage, bounty.id)?;
+    }
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(bounty.id, env.block, EventData::BountyPaused {}),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("pause_bounty", "true")
+        .add_attribute("bounty_id", bounty.id))
+}
+
+#[cfg(test)]
+mod pause_bounty_tests {
+    use super::*;
+    use crate::state::triggers::get_trigger;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = pause_bounty_handler(deps.as_mut(), env, mock_info("not-owner", &[]), bounty.id)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_inactive_bounty_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Inactive,
+                ..Bounty::default()
+            },
+        );
+
+        let err =
+            pause_bounty_handler(deps.as_mut(), env, mock_info(USER, &[]), bounty.id).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: bounty is not active");
+    }
+
+    #[test]
+    fn pauses_bounty_and_deletes_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        pause_bounty_handler(deps.as_mut(), env, mock_info(USER, &[]), bounty.id).unwrap();
+
+        let updated_bounty = get_bounty(&deps.storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.status, BountyStatus::Paused);
+        assert_eq!(get_trigger(&deps.storage, bounty.id).unwrap(), None);
+    }
+
+    #[test]
+    fn pauses_bounty_and_keeps_balance_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                trigger: Some(TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked(USER),
+                    denom: "ukuji".to_string(),
+                    threshold: Uint128::new(100),
+                    fired: false,
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        pause_bounty_handler(deps.as_mut(), env, mock_info(USER, &[]), bounty.id).unwrap();
+
+        assert_eq!(
+            get_trigger(&deps.storage, bounty.id)
+                .unwrap()
+                .map(|trigger| trigger.configuration),
+            bounty.trigger
+        );
+    }
+}
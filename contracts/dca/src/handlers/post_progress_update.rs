@@ -0,0 +1,200 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_is_not_cancelled, assert_metadata_uri_is_valid,
+        assert_progress_update_is_not_rate_limited,
+    },
+    state::{
+        assignees::get_assignee,
+        bounties::get_bounty,
+        progress_updates::{get_last_progress_update_time, save_progress_update},
+    },
+    types::progress_update::ProgressUpdate,
+};
+use cosmwasm_std::{DepsMut, Env, HexBinary, MessageInfo, Response, Uint128};
+
+pub fn post_progress_update_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    message_hash: [u8; 32],
+    uri: Option<String>,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    assert_bounty_is_not_cancelled(&bounty)?;
+
+    if let Some(uri) = &uri {
+        assert_metadata_uri_is_valid(uri)?;
+    }
+
+    get_assignee(deps.storage, bounty.id, &info.sender)?.ok_or(ContractError::Unauthorized {})?;
+
+    assert_progress_update_is_not_rate_limited(
+        get_last_progress_update_time(deps.storage, bounty.id, &info.sender)?,
+        env.block.time,
+    )?;
+
+    let progress_update = save_progress_update(
+        deps.storage,
+        bounty.id,
+        ProgressUpdate {
+            id: 0,
+            assignee: info.sender.clone(),
+            message_hash,
+            uri,
+            posted_at: env.block.time,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("post_progress_update", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("assignee", info.sender)
+        .add_attribute("progress_update_id", progress_update.id.to_string())
+        .add_attribute("message_hash", HexBinary::from(message_hash).to_hex()))
+}
+
+#[cfg(test)]
+mod post_progress_update_tests {
+    use super::*;
+    use crate::constants::PROGRESS_UPDATE_INTERVAL_SECONDS;
+    use crate::state::assignees::save_assignee;
+    use crate::state::progress_updates::get_progress_updates;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::assignee::Assignee;
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Decimal};
+
+    fn assign(deps: DepsMut, bounty_id: Uint128) {
+        save_assignee(
+            deps.storage,
+            bounty_id,
+            &Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn with_sender_that_is_not_an_assignee_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = post_progress_update_handler(
+            deps.as_mut(),
+            env,
+            mock_info("stranger", &[]),
+            bounty.id,
+            [1; 32],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn saves_progress_update_to_bounty_log() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        assign(deps.as_mut(), bounty.id);
+
+        post_progress_update_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("assignee", &[]),
+            bounty.id,
+            [1; 32],
+            Some("ipfs://progress".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_progress_updates(deps.as_ref().storage, bounty.id, None, Some(10), None).unwrap(),
+            vec![ProgressUpdate {
+                id: 1,
+                assignee: Addr::unchecked("assignee"),
+                message_hash: [1; 32],
+                uri: Some("ipfs://progress".to_string()),
+                posted_at: env.block.time,
+            }]
+        );
+    }
+
+    #[test]
+    fn within_rate_limit_interval_fails() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        assign(deps.as_mut(), bounty.id);
+
+        post_progress_update_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("assignee", &[]),
+            bounty.id,
+            [1; 32],
+            None,
+        )
+        .unwrap();
+
+        let next_allowed_at = env
+            .block
+            .time
+            .plus_seconds(PROGRESS_UPDATE_INTERVAL_SECONDS);
+
+        env.block.time = env.block.time.plus_seconds(10);
+
+        let err = post_progress_update_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("assignee", &[]),
+            bounty.id,
+            [2; 32],
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: progress updates are rate limited, next update allowed at {}",
+                next_allowed_at
+            )
+        );
+
+        env.block.time = next_allowed_at;
+
+        post_progress_update_handler(
+            deps.as_mut(),
+            env,
+            mock_info("assignee", &[]),
+            bounty.id,
+            [2; 32],
+            None,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        price::get_twap_to_now,
+        validation::{
+            assert_balance_is_above_threshold, assert_contract_is_not_paused,
+            assert_route_exists_for_denoms, assert_target_height_is_in_past,
+            assert_target_time_is_in_past,
+        },
+    },
+    state::{bounties::get_bounty, config::get_config, triggers::get_trigger},
+    types::trigger::TriggerConfiguration,
+};
+use cosmwasm_std::{DepsMut, Env, Response, Uint128};
+use shared::balance::query_balance;
+
+pub fn probe_trigger_handler(
+    deps: DepsMut,
+    env: Env,
+    trigger_id: Uint128,
+) -> Result<Response, ContractError> {
+    let response = Response::new()
+        .add_attribute("probe_trigger", "true")
+        .add_attribute("trigger_id", trigger_id);
+
+    Ok(match assert_trigger_is_executable(deps, &env, trigger_id) {
+        Ok(()) => response.add_attribute("executable", "true"),
+        Err(err) => response
+            .add_attribute("executable", "false")
+            .add_attribute("reason", err.to_string()),
+    })
+}
+
+fn assert_trigger_is_executable(
+    deps: DepsMut,
+    env: &Env,
+    trigger_id: Uint128,
+) -> Result<(), ContractError> {
+    assert_contract_is_not_paused(deps.storage)?;
+
+    let bounty = get_bounty(deps.storage, trigger_id)?;
+
+    if bounty.is_cancelled() {
+        return Err(ContractError::CustomError {
+            val: format!("bounty with id {} is cancelled", bounty.id),
+        });
+    }
+
+    if bounty.is_expired(env.block.time) {
+        return Err(ContractError::CustomError {
+            val: format!("bounty with id {} has expired", bounty.id),
+        });
+    }
+
+    let trigger = get_trigger(deps.storage, bounty.id)?.ok_or(ContractError::CustomError {
+        val: format!("bounty with id {} has no trigger attached", bounty.id),
+    })?;
+
+    match trigger.configuration {
+        TriggerConfiguration::Time { target_time } => {
+            assert_target_time_is_in_past(env.block.time, target_time)?;
+        }
+        TriggerConfiguration::BlockHeight { target_height } => {
+            assert_target_height_is_in_past(env.block.height, target_height)?;
+        }
+        TriggerConfiguration::BalanceAbove {
+            account,
+            denom,
+            threshold,
+            fired,
+        } => {
+            if fired {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "balance trigger for bounty with id {} has fired and re-arms once the watched balance drops back to the threshold",
+                        bounty.id
+                    ),
+                });
+            }
+
+            let balance = query_balance(deps.api, &deps.querier, &denom, &account)?;
+            assert_balance_is_above_threshold(balance, threshold)?;
+        }
+        _ => {}
+    }
+
+    if bounty.balance.amount.is_zero() {
+        return Err(ContractError::CustomError {
+            val: format!("bounty with id {} has no balance to swap", bounty.id),
+        });
+    }
+
+    assert_route_exists_for_denoms(
+        deps.as_ref(),
+        bounty.get_swap_denom(),
+        bounty.target_denom.to_string(),
+        bounty.route.clone(),
+    )?;
+
+    let config = get_config(deps.storage)?;
+
+    let twap_price = get_twap_to_now(
+        &deps.querier,
+        config.exchange_contract_address,
+        bounty.get_swap_denom(),
+        bounty.target_denom.to_string(),
+        config.twap_period,
+        bounty.route.clone(),
+    )?;
+
+    if bounty.price_threshold_exceeded(twap_price)? {
+        return Err(ContractError::CustomError {
+            val: format!("price threshold exceeded at twap price {}", twap_price),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod probe_trigger_tests {
+    use super::*;
+    use crate::state::config::update_config;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN};
+    use crate::types::bounty::{Bounty, BountyStatus};
+    use crate::types::config::Config;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Attribute;
+
+    #[test]
+    fn with_paused_contract_is_not_executable() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                paused: true,
+                ..get_config(deps.as_ref().storage).unwrap()
+            },
+        )
+        .unwrap();
+
+        let response = probe_trigger_handler(deps.as_mut(), env, bounty.id).unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("executable", "false")));
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("reason", "Error: contract is paused")));
+    }
+
+    #[test]
+    fn with_cancelled_bounty_is_not_executable() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Cancelled,
+                ..Bounty::default()
+            },
+        );
+
+        let response = probe_trigger_handler(deps.as_mut(), env, bounty.id).unwrap();
+
+        assert!(response.attributes.contains(&Attribute::new(
+            "reason",
+            format!("Error: bounty with id {} is cancelled", bounty.id)
+        )));
+    }
+
+    #[test]
+    fn with_due_trigger_is_executable() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let response = probe_trigger_handler(deps.as_mut(), env, bounty.id).unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("executable", "true")));
+    }
+}
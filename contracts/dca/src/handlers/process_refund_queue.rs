@@ -0,0 +1,215 @@
+use crate::constants::{AFTER_QUEUED_REFUND_REPLY_ID, DEFAULT_REFUND_QUEUE_LIMIT};
+use crate::error::ContractError;
+use crate::helpers::validation::assert_page_limit_is_valid;
+use crate::state::cache::REFUND_QUEUE_CACHE;
+use crate::state::refund_queue::{get_queued_refunds, park_queued_refund, remove_queued_refund};
+use cosmwasm_std::{DepsMut, Reply, Response, StdResult, SubMsg, SubMsgResult};
+use shared::cw20::into_transfer_msg;
+use std::collections::VecDeque;
+
+pub fn process_refund_queue_handler(
+    deps: DepsMut,
+    limit: Option<u16>,
+) -> Result<Response, ContractError> {
+    assert_page_limit_is_valid(limit)?;
+
+    if limit == Some(0) {
+        return Err(ContractError::CustomError {
+            val: "limit must be greater than zero".to_string(),
+        });
+    }
+
+    let refunds = get_queued_refunds(deps.storage, limit.unwrap_or(DEFAULT_REFUND_QUEUE_LIMIT))?;
+
+    let submessages = refunds
+        .iter()
+        .map(|refund| {
+            into_transfer_msg(deps.api, refund.recipient.as_ref(), refund.amount.clone())
+                .map(|msg| SubMsg::reply_always(msg, AFTER_QUEUED_REFUND_REPLY_ID))
+        })
+        .collect::<StdResult<Vec<SubMsg>>>()?;
+
+    if !refunds.is_empty() {
+        REFUND_QUEUE_CACHE.save(
+            deps.storage,
+            &refunds
+                .iter()
+                .map(|refund| refund.id)
+                .collect::<VecDeque<u64>>(),
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("process_refund_queue", "true")
+        .add_attribute("refunds_processed", refunds.len().to_string())
+        .add_submessages(submessages))
+}
+
+pub fn handle_queued_refund_result(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
+    let mut cache = REFUND_QUEUE_CACHE.load(deps.storage)?;
+    let refund_id = cache.pop_front().ok_or(ContractError::CustomError {
+        val: "no queued refund is awaiting a result".to_string(),
+    })?;
+
+    if cache.is_empty() {
+        REFUND_QUEUE_CACHE.remove(deps.storage);
+    } else {
+        REFUND_QUEUE_CACHE.save(deps.storage, &cache)?;
+    }
+
+    Ok(match reply.result {
+        SubMsgResult::Ok(_) => {
+            remove_queued_refund(deps.storage, refund_id);
+            Response::new().add_attribute(format!("queued_refund_{}", refund_id), "sent")
+        }
+        SubMsgResult::Err(_) => {
+            park_queued_refund(deps.storage, refund_id)?;
+            Response::new().add_attribute(format!("queued_refund_{}", refund_id), "parked")
+        }
+    })
+}
+
+#[cfg(test)]
+mod process_refund_queue_tests {
+    use super::*;
+    use crate::state::refund_queue::{enqueue_refund, get_parked_refunds, has_queued_refunds};
+    use crate::types::queued_refund::QueuedRefund;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{Addr, BankMsg, Coin, SubMsgResponse, Uint128};
+
+    fn queue_refunds(deps: DepsMut, count: u128) {
+        for i in 0..count {
+            enqueue_refund(
+                deps.storage,
+                QueuedRefund {
+                    id: 0,
+                    bounty_id: Uint128::one(),
+                    recipient: Addr::unchecked("owner"),
+                    amount: Coin::new(100 + i, "ukuji"),
+                    queued_at: mock_env().block.time,
+                },
+            )
+            .unwrap();
+        }
+    }
+
+    fn reply(result: SubMsgResult) -> Reply {
+        Reply {
+            id: AFTER_QUEUED_REFUND_REPLY_ID,
+            result,
+        }
+    }
+
+    fn succeeded() -> SubMsgResult {
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        })
+    }
+
+    #[test]
+    fn with_limit_larger_than_1000_should_fail() {
+        let mut deps = mock_dependencies();
+
+        let err = process_refund_queue_handler(deps.as_mut(), Some(1001)).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: limit cannot be greater than 1000.");
+    }
+
+    #[test]
+    fn with_zero_limit_should_fail() {
+        let mut deps = mock_dependencies();
+
+        let err = process_refund_queue_handler(deps.as_mut(), Some(0)).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: limit must be greater than zero");
+    }
+
+    #[test]
+    fn with_empty_queue_should_send_nothing() {
+        let mut deps = mock_dependencies();
+
+        let response = process_refund_queue_handler(deps.as_mut(), None).unwrap();
+
+        assert!(response.messages.is_empty());
+    }
+
+    #[test]
+    fn should_send_queued_refunds_up_to_limit() {
+        let mut deps = mock_dependencies();
+
+        queue_refunds(deps.as_mut(), 3);
+
+        let response = process_refund_queue_handler(deps.as_mut(), Some(2)).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![
+                SubMsg::reply_always(
+                    BankMsg::Send {
+                        to_address: "owner".to_string(),
+                        amount: vec![Coin::new(100, "ukuji")],
+                    },
+                    AFTER_QUEUED_REFUND_REPLY_ID
+                ),
+                SubMsg::reply_always(
+                    BankMsg::Send {
+                        to_address: "owner".to_string(),
+                        amount: vec![Coin::new(101, "ukuji")],
+                    },
+                    AFTER_QUEUED_REFUND_REPLY_ID
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_keep_refunds_queued_until_sent() {
+        let mut deps = mock_dependencies();
+
+        queue_refunds(deps.as_mut(), 1);
+
+        process_refund_queue_handler(deps.as_mut(), None).unwrap();
+
+        assert!(has_queued_refunds(deps.as_ref().storage));
+
+        handle_queued_refund_result(deps.as_mut(), reply(succeeded())).unwrap();
+
+        assert!(!has_queued_refunds(deps.as_ref().storage));
+        assert!(REFUND_QUEUE_CACHE
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn should_park_failed_refund_and_continue_with_the_rest() {
+        let mut deps = mock_dependencies();
+
+        queue_refunds(deps.as_mut(), 3);
+
+        process_refund_queue_handler(deps.as_mut(), Some(2)).unwrap();
+
+        handle_queued_refund_result(deps.as_mut(), reply(SubMsgResult::Err("rejected".into())))
+            .unwrap();
+        handle_queued_refund_result(deps.as_mut(), reply(succeeded())).unwrap();
+
+        let parked_refunds = get_parked_refunds(deps.as_ref().storage, 10).unwrap();
+
+        assert_eq!(parked_refunds.len(), 1);
+        assert_eq!(parked_refunds[0].amount, Coin::new(100, "ukuji"));
+
+        let response = process_refund_queue_handler(deps.as_mut(), Some(2)).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: "owner".to_string(),
+                    amount: vec![Coin::new(102, "ukuji")],
+                },
+                AFTER_QUEUED_REFUND_REPLY_ID
+            )]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{
+        admin_actions::create_admin_action,
+        config::{get_config, update_config},
+    },
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        config::Config,
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn propose_new_admin_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    deps.api.addr_validate(address.as_ref())?;
+
+    let config = get_config(deps.storage)?;
+
+    update_config(
+        deps.storage,
+        Config {
+            pending_admin: Some(address.clone()),
+            ..config
+        },
+    )?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::AdminTransferProposed {
+                pending_admin: address.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("propose_new_admin", "true")
+        .add_attribute("pending_admin", address))
+}
+
+#[cfg(test)]
+mod propose_new_admin_tests {
+    use super::*;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = propose_new_admin_handler(
+            deps.as_mut(),
+            env,
+            mock_info("stranger", &[]),
+            Addr::unchecked("new-admin"),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn saves_pending_admin_without_changing_admin() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        propose_new_admin_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("new-admin"),
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.admin, Addr::unchecked(ADMIN));
+        assert_eq!(config.pending_admin, Some(Addr::unchecked("new-admin")));
+    }
+}
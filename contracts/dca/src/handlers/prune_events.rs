@@ -0,0 +1,262 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        cleanup::clear_terminal_bounty_state, validation::assert_sender_is_admin_or_vault_owner,
+    },
+    state::{
+        bounties::get_bounty,
+        config::get_config,
+        event_summaries::{get_event_summary, save_event_summary},
+        events::prune_events,
+    },
+    types::event_summary::EventSummary,
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn prune_events_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    resource_id: Uint128,
+    before_block: u64,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, resource_id)?;
+
+    assert_sender_is_admin_or_vault_owner(deps.storage, bounty.owner.clone(), info.sender)?;
+
+    let config = get_config(deps.storage)?;
+
+    if let Some(retention_blocks) = config.event_retention_blocks {
+        if before_block > env.block.height.saturating_sub(retention_blocks) {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "events from the last {} blocks must be retained",
+                    retention_blocks
+                ),
+            });
+        }
+    }
+
+    let pruned_events = prune_events(
+        deps.storage,
+        resource_id,
+        before_block,
+        config.default_page_limit as usize,
+    )?;
+
+    let summary = pruned_events.iter().fold(
+        get_event_summary(deps.storage, resource_id)?.unwrap_or(EventSummary {
+            resource_id,
+            ..EventSummary::default()
+        }),
+        |summary, event| summary.add_event(event),
+    );
+
+    save_event_summary(deps.storage, &summary)?;
+
+    if bounty.is_terminal() {
+        clear_terminal_bounty_state(deps.storage, &bounty)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("prune_events", "true")
+        .add_attribute("resource_id", resource_id)
+        .add_attribute("pruned_events", pruned_events.len().to_string()))
+}
+
+#[cfg(test)]
+mod prune_events_tests {
+    use super::*;
+    use crate::{
+        constants::ONE,
+        handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
+        state::{
+            assignees::{get_assignees, save_assignee},
+            config::update_config,
+            events::create_event,
+        },
+        tests::{
+            helpers::{instantiate_contract, setup_bounty},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK, USER},
+        },
+        types::{
+            assignee::Assignee,
+            bounty::{Bounty, BountyStatus},
+            config::Config,
+            event::{EventBuilder, EventData},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Addr, Coin, Decimal,
+    };
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = prune_events_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("not-owner", &[]),
+            bounty.id,
+            env.block.height,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn within_retention_period_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                event_retention_blocks: Some(100),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = prune_events_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            bounty.id,
+            env.block.height - 10,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: events from the last 100 blocks must be retained"
+        );
+    }
+
+    #[test]
+    fn prunes_events_and_saves_summary() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let mut old_block = env.block.clone();
+        old_block.height -= 10;
+
+        for _ in 0..2 {
+            create_event(
+                deps.as_mut().storage,
+                EventBuilder::new(
+                    bounty.id,
+                    old_block.clone(),
+                    EventData::BountyExecutionCompleted {
+                        sent: Coin::new(ONE.into(), DENOM_UKUJI),
+                        received: Coin::new(ONE.into(), DENOM_UUSK),
+                        fee: Coin::new(100, DENOM_UUSK),
+                    },
+                ),
+            )
+            .unwrap();
+        }
+
+        create_event(
+            deps.as_mut().storage,
+            EventBuilder::new(bounty.id, env.block.clone(), EventData::BountyPaused {}),
+        )
+        .unwrap();
+
+        let events_before =
+            get_events_by_resource_id_handler(deps.as_ref(), bounty.id, None, None, None)
+                .unwrap()
+                .events;
+
+        prune_events_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            bounty.id,
+            env.block.height,
+        )
+        .unwrap();
+
+        let events_after =
+            get_events_by_resource_id_handler(deps.as_ref(), bounty.id, None, None, None)
+                .unwrap()
+                .events;
+
+        assert_eq!(events_after.len(), events_before.len() - 2);
+        assert!(events_after
+            .iter()
+            .all(|event| event.block_height >= env.block.height));
+
+        let summary = get_event_summary(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(summary.pruned_events, 2);
+        assert_eq!(
+            summary.total_swapped,
+            vec![Coin::new((ONE + ONE).into(), DENOM_UKUJI)]
+        );
+        assert_eq!(summary.total_fees, vec![Coin::new(200, DENOM_UUSK)]);
+    }
+
+    #[test]
+    fn on_terminal_bounty_clears_terminal_bounty_state() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Completed,
+                ..Bounty::default()
+            },
+        );
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        prune_events_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            bounty.id,
+            env.block.height,
+        )
+        .unwrap();
+
+        assert!(get_assignees(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .is_empty());
+    }
+}
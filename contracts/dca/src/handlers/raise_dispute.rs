@@ -0,0 +1,215 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_has_no_open_dispute, assert_bounty_is_not_cancelled,
+        assert_dispute_reason_is_no_longer_than_1000_characters,
+    },
+    state::{
+        assignees::get_assignee, bounties::get_bounty, disputes::save_dispute, events::create_event,
+    },
+    types::{
+        dispute::Dispute,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn raise_dispute_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    if info.sender != bounty.owner && get_assignee(deps.storage, bounty.id, &info.sender)?.is_none()
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_bounty_has_no_open_dispute(deps.storage, bounty.id)?;
+    assert_dispute_reason_is_no_longer_than_1000_characters(&reason)?;
+
+    if bounty.escrowed_amount.amount.is_zero() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "bounty with id {} has no escrowed funds to dispute",
+                bounty.id
+            ),
+        });
+    }
+
+    save_dispute(
+        deps.storage,
+        &Dispute {
+            bounty_id: bounty.id,
+            raised_by: info.sender.clone(),
+            reason: reason.clone(),
+            raised_at: env.block.time,
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyDisputeRaised {
+                raised_by: info.sender.clone(),
+                reason,
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("raise_dispute", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("raised_by", info.sender))
+}
+
+#[cfg(test)]
+mod raise_dispute_tests {
+    use super::*;
+    use crate::constants::ONE;
+    use crate::state::disputes::get_dispute;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UUSK, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Coin};
+
+    #[test]
+    fn with_sender_that_is_not_owner_or_assignee_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        let err = raise_dispute_handler(
+            deps.as_mut(),
+            env,
+            mock_info("stranger", &[]),
+            bounty.id,
+            "work was not delivered".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_no_escrowed_funds_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = raise_dispute_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            "work was not delivered".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: bounty with id {} has no escrowed funds to dispute",
+                bounty.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_open_dispute_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        raise_dispute_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            bounty.id,
+            "work was not delivered".to_string(),
+        )
+        .unwrap();
+
+        let err = raise_dispute_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            "work was still not delivered".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: bounty with id {} has an open dispute", bounty.id)
+        );
+    }
+
+    #[test]
+    fn saves_dispute() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        raise_dispute_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            bounty.id,
+            "work was not delivered".to_string(),
+        )
+        .unwrap();
+
+        let dispute = get_dispute(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(
+            dispute,
+            Some(Dispute {
+                bounty_id: bounty.id,
+                raised_by: Addr::unchecked(USER),
+                reason: "work was not delivered".to_string(),
+                raised_at: env.block.time,
+            })
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::asset_sender_is_bounty_owner,
+    state::{
+        bounties::get_bounty,
+        ibc_transfers::{clear_failed_ibc_transfers, get_failed_ibc_transfers},
+    },
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response, StdResult, SubMsg, Uint128};
+use shared::cw20::into_transfer_msg;
+
+pub fn refund_failed_ibc_transfers_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty_id: Uint128,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+
+    let failed_transfers = get_failed_ibc_transfers(deps.storage, bounty.id)?;
+
+    if failed_transfers.is_empty() {
+        return Err(ContractError::CustomError {
+            val: format!("bounty with id {} has no failed ibc transfers", bounty.id),
+        });
+    }
+
+    clear_failed_ibc_transfers(deps.storage, bounty.id);
+
+    Ok(Response::new()
+        .add_attribute("refund_failed_ibc_transfers", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("transfer_count", failed_transfers.len().to_string())
+        .add_submessages(
+            failed_transfers
+                .into_iter()
+                .map(|transfer| {
+                    into_transfer_msg(deps.api, bounty.owner.as_ref(), transfer.amount)
+                        .map(SubMsg::new)
+                })
+                .collect::<StdResult<Vec<SubMsg>>>()?,
+        ))
+}
+
+#[cfg(test)]
+mod refund_failed_ibc_transfers_tests {
+    use super::*;
+    use crate::state::ibc_transfers::{save_failed_ibc_transfer, FailedIbcTransfer};
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use crate::types::destination::IbcDestination;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{BankMsg, Coin};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = refund_failed_ibc_transfers_handler(
+            deps.as_mut(),
+            mock_info("not-owner", &[]),
+            bounty.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn sends_failed_transfers_to_owner_and_clears_them() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        save_failed_ibc_transfer(
+            deps.as_mut().storage,
+            bounty.id,
+            FailedIbcTransfer {
+                destination: IbcDestination {
+                    channel_id: "channel-0".to_string(),
+                    remote_address: "osmo1remote".to_string(),
+                    timeout_seconds: None,
+                },
+                amount: Coin::new(100, "uosmo"),
+            },
+        )
+        .unwrap();
+
+        let response =
+            refund_failed_ibc_transfers_handler(deps.as_mut(), mock_info(USER, &[]), bounty.id)
+                .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: bounty.owner.to_string(),
+                amount: vec![Coin::new(100, "uosmo")],
+            })]
+        );
+        assert!(get_failed_ibc_transfers(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    error::ContractError,
+    helpers::address::normalize_address,
+    helpers::validation::{
+        assert_address_is_valid, assert_referral_code_is_valid,
+        assert_sender_is_admin_or_vault_owner,
+    },
+    state::referrals::{get_referrer, save_referrer},
+};
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response};
+
+pub fn register_referrer_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    code: String,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    assert_address_is_valid(deps.as_ref(), &address, "referrer")?;
+    assert_sender_is_admin_or_vault_owner(deps.storage, address.clone(), info.sender)?;
+    assert_referral_code_is_valid(&code)?;
+
+    if get_referrer(deps.storage, &code)?.is_some() {
+        return Err(ContractError::CustomError {
+            val: format!("referral code {} is already registered", code),
+        });
+    }
+
+    save_referrer(deps.storage, &code, &address)?;
+
+    Ok(Response::new()
+        .add_attribute("register_referrer", "true")
+        .add_attribute("code", code)
+        .add_attribute("referrer", address))
+}
+
+#[cfg(test)]
+mod register_referrer_tests {
+    use super::*;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = register_referrer_handler(
+            deps.as_mut(),
+            mock_info("not-referrer", &[]),
+            "prismo".to_string(),
+            Addr::unchecked(USER),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_registered_code_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        register_referrer_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            "prismo".to_string(),
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        let err = register_referrer_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            "prismo".to_string(),
+            Addr::unchecked(ADMIN),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: referral code prismo is already registered"
+        );
+    }
+
+    #[test]
+    fn saves_referrer() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        register_referrer_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            "prismo".to_string(),
+            Addr::unchecked(USER),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_referrer(&deps.storage, "prismo").unwrap(),
+            Some(Addr::unchecked(USER))
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_has_role,
+    state::{admin_actions::create_admin_action, allowed_denoms::remove_allowed_denom},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        denom::Denom,
+        role::Role,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn remove_allowed_denom_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: Denom,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    remove_allowed_denom(deps.storage, &denom);
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::AllowedDenomRemoved {
+                denom: denom.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("remove_allowed_denom", "true")
+        .add_attribute("denom", denom.to_string()))
+}
+
+#[cfg(test)]
+mod remove_allowed_denom_tests {
+    use super::*;
+    use crate::state::allowed_denoms::{get_allowed_denoms, save_allowed_denom};
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn removes_allowed_denom() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_allowed_denom(deps.as_mut().storage, &Denom::new(DENOM_UKUJI).unwrap()).unwrap();
+        save_allowed_denom(deps.as_mut().storage, &Denom::new(DENOM_UUSK).unwrap()).unwrap();
+
+        remove_allowed_denom_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Denom::new(DENOM_UKUJI).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_allowed_denoms(deps.as_ref().storage).unwrap(),
+            vec![Denom::new(DENOM_UUSK).unwrap()]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_has_role,
+    state::{admin_actions::create_admin_action, custom_fees::remove_custom_swap_fee},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn remove_custom_swap_fee_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::FeeManager)?;
+
+    remove_custom_swap_fee(deps.storage, denom.clone());
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::CustomSwapFeeRemoved {
+                denom: denom.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("remove_custom_swap_fee", "true")
+        .add_attribute("denom", denom))
+}
+
+#[cfg(test)]
+mod remove_custom_swap_fee_tests {
+    use super::*;
+    use crate::state::custom_fees::{get_custom_swap_fee, save_custom_swap_fee};
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Decimal;
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = remove_custom_swap_fee_handler(
+            deps.as_mut(),
+            env,
+            mock_info("stranger", &[]),
+            DENOM_UKUJI.to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn removes_custom_swap_fee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_custom_swap_fee(
+            deps.as_mut().storage,
+            DENOM_UKUJI.to_string(),
+            Decimal::percent(1),
+        )
+        .unwrap();
+
+        remove_custom_swap_fee_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UKUJI.to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_custom_swap_fee(deps.as_ref().storage, DENOM_UKUJI.to_string()).unwrap(),
+            None
+        );
+    }
+}
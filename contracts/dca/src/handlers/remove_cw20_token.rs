@@ -0,0 +1,70 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{admin_actions::create_admin_action, cw20_whitelist::remove_whitelisted_cw20_token},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn remove_cw20_token_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token: Addr,
+) -> Result<Response, ContractError> {
+    let token = normalize_address(&token);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    remove_whitelisted_cw20_token(deps.storage, &token);
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::Cw20TokenRemoved {
+                token: token.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("remove_cw20_token", "true")
+        .add_attribute("token", token))
+}
+
+#[cfg(test)]
+mod remove_cw20_token_tests {
+    use super::*;
+    use crate::state::cw20_whitelist::{is_cw20_token_whitelisted, save_whitelisted_cw20_token};
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn removes_token_from_whitelist() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_whitelisted_cw20_token(deps.as_mut().storage, &Addr::unchecked("token")).unwrap();
+
+        remove_cw20_token_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("token"),
+        )
+        .unwrap();
+
+        assert!(!is_cw20_token_whitelisted(
+            deps.as_ref().storage,
+            &Addr::unchecked("token")
+        ));
+    }
+}
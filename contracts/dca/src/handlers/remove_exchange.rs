@@ -0,0 +1,108 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{
+        admin_actions::create_admin_action,
+        exchanges::{is_exchange, remove_exchange},
+    },
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn remove_exchange_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    if !is_exchange(deps.storage, &address) {
+        return Err(ContractError::CustomError {
+            val: format!("exchange {} is not registered", address),
+        });
+    }
+
+    remove_exchange(deps.storage, &address)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ExchangeRemoved {
+                address: address.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("remove_exchange", "true")
+        .add_attribute("exchange", address))
+}
+
+#[cfg(test)]
+mod remove_exchange_tests {
+    use super::*;
+    use crate::state::exchanges::save_exchange;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use crate::types::exchange::Exchange;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unregistered_exchange_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = remove_exchange_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("astroport"),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: exchange astroport is not registered"
+        );
+    }
+
+    #[test]
+    fn removes_registered_exchange() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_exchange(
+            deps.as_mut().storage,
+            &Exchange {
+                address: Addr::unchecked("astroport"),
+                weight: 1,
+            },
+        )
+        .unwrap();
+
+        remove_exchange_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("astroport"),
+        )
+        .unwrap();
+
+        assert!(!is_exchange(
+            deps.as_ref().storage,
+            &Addr::unchecked("astroport")
+        ));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{admin_actions::create_admin_action, fee_exemptions::remove_fee_exemption},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn remove_fee_exemption_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::FeeManager)?;
+
+    remove_fee_exemption(deps.storage, &address);
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::FeeExemptionRemoved {
+                address: address.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("remove_fee_exemption", "true")
+        .add_attribute("address", address))
+}
+
+#[cfg(test)]
+mod remove_fee_exemption_tests {
+    use super::*;
+    use crate::state::fee_exemptions::{is_fee_exempt, save_fee_exemption};
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn removes_fee_exemption() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_fee_exemption(deps.as_mut().storage, &Addr::unchecked("dao")).unwrap();
+
+        remove_fee_exemption_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("dao"),
+        )
+        .unwrap();
+
+        assert!(!is_fee_exempt(
+            deps.as_ref().storage,
+            &Addr::unchecked("dao")
+        ));
+    }
+}
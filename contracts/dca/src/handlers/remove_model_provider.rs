@@ -0,0 +1,38 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{admin_actions::create_admin_action, model_adjustments::remove_model_provider},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn remove_model_provider_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    provider: Addr,
+) -> Result<Response, ContractError> {
+    let provider = normalize_address(&provider);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    remove_model_provider(deps.storage, &provider);
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ModelProviderRemoved {
+                provider: provider.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("remove_model_provider", "true")
+        .add_attribute("provider", provider))
+}
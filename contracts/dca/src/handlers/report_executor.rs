@@ -0,0 +1,126 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{admin_actions::create_admin_action, executor_stats::record_executor_report},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn report_executor_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    deps.api.addr_validate(address.as_ref())?;
+
+    if reason.trim().is_empty() {
+        return Err(ContractError::CustomError {
+            val: "Executor report reason cannot be empty".to_string(),
+        });
+    }
+
+    let stats = record_executor_report(deps.storage, &address)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ExecutorReported {
+                address: address.clone(),
+                reason: reason.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("report_executor", "true")
+        .add_attribute("address", address)
+        .add_attribute("reason", reason)
+        .add_attribute("reports", stats.reports.to_string()))
+}
+
+#[cfg(test)]
+mod report_executor_tests {
+    use super::*;
+    use crate::state::executor_stats::get_executor_stats;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = report_executor_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Addr::unchecked("keeper"),
+            "missed triggers".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_empty_reason_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = report_executor_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("keeper"),
+            " ".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Executor report reason cannot be empty"
+        );
+    }
+
+    #[test]
+    fn increments_executor_reports() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for _ in 0..2 {
+            report_executor_handler(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(ADMIN, &[]),
+                Addr::unchecked("keeper"),
+                "missed triggers".to_string(),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            get_executor_stats(deps.as_ref().storage, &Addr::unchecked("keeper"))
+                .unwrap()
+                .reports,
+            2
+        );
+    }
+}
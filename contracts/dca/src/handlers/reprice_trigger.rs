@@ -0,0 +1,254 @@
+use crate::{
+    constants::{AFTER_LIMIT_ORDER_PLACED_REPLY_ID, TWO_MICRONS},
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_is_not_cancelled, assert_bounty_is_not_completed,
+        asset_sender_is_bounty_owner,
+    },
+    state::{
+        bounties::get_bounty,
+        cache::{BOUNTY_ID_CACHE, LIMIT_ORDER_TARGET_PRICE_CACHE},
+        config::get_config,
+        events::create_event,
+        triggers::delete_trigger,
+    },
+    types::{
+        event::{EventBuilder, EventData},
+        trigger::TriggerConfiguration,
+    },
+};
+use cosmwasm_std::{
+    to_json_binary, Coin, Decimal, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128, WasmMsg,
+};
+use exchange::msg::ExecuteMsg;
+
+pub fn reprice_trigger_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    new_target_price: Decimal,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_bounty_is_not_completed(&bounty)?;
+
+    if new_target_price.is_zero() {
+        return Err(ContractError::CustomError {
+            val: "new target price must be greater than zero".to_string(),
+        });
+    }
+
+    let (order_idx, old_target_price) = match bounty.trigger {
+        Some(TriggerConfiguration::Price {
+            order_idx,
+            target_price,
+        }) => (order_idx, target_price),
+        _ => {
+            return Err(ContractError::CustomError {
+                val: format!("bounty {} does not have a price trigger", bounty.id),
+            })
+        }
+    };
+
+    let config = get_config(deps.storage)?;
+
+    delete_trigger(deps.storage, bounty.id)?;
+
+    BOUNTY_ID_CACHE.save(deps.storage, &bounty.id)?;
+    LIMIT_ORDER_TARGET_PRICE_CACHE.save(deps.storage, &new_target_price)?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyTriggerRepriced {
+                old_target_price,
+                new_target_price,
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("reprice_trigger", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("old_order_idx", order_idx)
+        .add_attribute("old_target_price", old_target_price.to_string())
+        .add_attribute("new_target_price", new_target_price.to_string())
+        .add_submessage(SubMsg::new(WasmMsg::Execute {
+            contract_addr: config.exchange_contract_address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::RetractOrder {
+                order_idx,
+                denoms: bounty.denoms(),
+            })?,
+            funds: vec![],
+        }))
+        .add_submessage(SubMsg::new(WasmMsg::Execute {
+            contract_addr: config.exchange_contract_address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::WithdrawOrder {
+                order_idx,
+                denoms: bounty.denoms(),
+            })?,
+            funds: vec![],
+        }))
+        .add_submessage(SubMsg::reply_always(
+            WasmMsg::Execute {
+                contract_addr: config.exchange_contract_address.to_string(),
+                msg: to_json_binary(&ExecuteMsg::SubmitOrder {
+                    target_price: new_target_price.into(),
+                    target_denom: bounty.target_denom.to_string(),
+                })?,
+                funds: vec![Coin::new(TWO_MICRONS.into(), bounty.get_swap_denom())],
+            },
+            AFTER_LIMIT_ORDER_PLACED_REPLY_ID,
+        )))
+}
+
+#[cfg(test)]
+mod reprice_trigger_tests {
+    use super::*;
+    use crate::state::triggers::get_trigger;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    order_idx: Uint128::new(67),
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        let err = reprice_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            bounty.id,
+            Decimal::percent(150),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn without_price_trigger_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = reprice_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Decimal::percent(150),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: bounty {} does not have a price trigger", bounty.id)
+        );
+    }
+
+    #[test]
+    fn retracts_old_order_and_submits_new_order() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let order_idx = Uint128::new(67);
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    order_idx,
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        let response = reprice_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Decimal::percent(150),
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![
+                SubMsg::new(WasmMsg::Execute {
+                    contract_addr: config.exchange_contract_address.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::RetractOrder {
+                        order_idx,
+                        denoms: bounty.denoms(),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }),
+                SubMsg::new(WasmMsg::Execute {
+                    contract_addr: config.exchange_contract_address.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::WithdrawOrder {
+                        order_idx,
+                        denoms: bounty.denoms(),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }),
+                SubMsg::reply_always(
+                    WasmMsg::Execute {
+                        contract_addr: config.exchange_contract_address.to_string(),
+                        msg: to_json_binary(&ExecuteMsg::SubmitOrder {
+                            target_price: Decimal::percent(150).into(),
+                            target_denom: bounty.target_denom.to_string(),
+                        })
+                        .unwrap(),
+                        funds: vec![Coin::new(TWO_MICRONS.into(), bounty.get_swap_denom())],
+                    },
+                    AFTER_LIMIT_ORDER_PLACED_REPLY_ID,
+                ),
+            ]
+        );
+
+        assert_eq!(get_trigger(deps.as_ref().storage, bounty.id).unwrap(), None);
+        assert_eq!(
+            BOUNTY_ID_CACHE.load(deps.as_ref().storage).unwrap(),
+            bounty.id
+        );
+        assert_eq!(
+            LIMIT_ORDER_TARGET_PRICE_CACHE
+                .load(deps.as_ref().storage)
+                .unwrap(),
+            Decimal::percent(150)
+        );
+    }
+}
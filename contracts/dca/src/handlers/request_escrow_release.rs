@@ -0,0 +1,335 @@
+use crate::{
+    constants::ESCROW_RELEASE_COUNTDOWN_SECONDS,
+    error::ContractError,
+    helpers::validation::{assert_bounty_has_no_open_dispute, assert_bounty_is_not_cancelled},
+    state::{
+        assignees::get_assignee, bounties::get_bounty, events::create_event, triggers::save_trigger,
+    },
+    types::{
+        bounty::BountyStatus,
+        event::{EventBuilder, EventData},
+        trigger::{Trigger, TriggerConfiguration},
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+/// Schedules an `EscrowAccept` trigger on behalf of an assignee. Keepers can
+/// execute it once the countdown elapses, unless the owner raises a dispute
+/// before then.
+pub fn request_escrow_release_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    get_assignee(deps.storage, bounty.id, &info.sender)?.ok_or(ContractError::Unauthorized {})?;
+
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_bounty_has_no_open_dispute(deps.storage, bounty.id)?;
+
+    if bounty.is_completed() {
+        return Err(ContractError::CustomError {
+            val: format!("bounty with id {} is already completed", bounty.id),
+        });
+    }
+
+    if let Some(TriggerConfiguration::EscrowAccept { .. }) = bounty.trigger {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "bounty with id {} already has a pending escrow release",
+                bounty.id
+            ),
+        });
+    }
+
+    if bounty.escrowed_amount.amount.is_zero() && bounty.balance.amount.is_zero() {
+        return Err(ContractError::CustomError {
+            val: format!("bounty with id {} has no funds to release", bounty.id),
+        });
+    }
+
+    let release_at = env
+        .block
+        .time
+        .plus_seconds(ESCROW_RELEASE_COUNTDOWN_SECONDS);
+
+    save_trigger(
+        deps.storage,
+        Trigger {
+            bounty_id: bounty.id,
+            configuration: TriggerConfiguration::EscrowAccept {
+                target_time: release_at,
+                bounty_id: bounty.id,
+                label: bounty.label.clone(),
+                bounty_description: bounty.description.clone(),
+                status: Some(BountyStatus::Completed),
+                destinations: vec![],
+                target_denom: bounty.target_denom.to_string(),
+                route: bounty.route.clone(),
+                slippage_tolerance: Some(bounty.slippage_tolerance),
+            },
+            retry_count: 0,
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyEscrowReleaseRequested {
+                requested_by: info.sender.clone(),
+                release_at,
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("request_escrow_release", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("requested_by", info.sender)
+        .add_attribute("release_at", release_at.to_string()))
+}
+
+#[cfg(test)]
+mod request_escrow_release_tests {
+    use super::*;
+    use crate::constants::TEN;
+    use crate::handlers::execute_trigger::execute_trigger_handler;
+    use crate::state::assignees::save_assignee;
+    use crate::state::disputes::save_dispute;
+    use crate::state::triggers::{get_time_triggers, get_trigger};
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UUSK};
+    use crate::types::assignee::Assignee;
+    use crate::types::bounty::Bounty;
+    use crate::types::dispute::Dispute;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Coin, Decimal};
+
+    const ASSIGNEE: &str = "assignee";
+
+    fn setup_escrowed_bounty(deps: DepsMut, env: Env) -> Bounty {
+        setup_bounty(
+            deps,
+            env,
+            Bounty {
+                escrowed_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        )
+    }
+
+    fn save_bounty_assignee(deps: DepsMut, bounty_id: Uint128) {
+        save_assignee(
+            deps.storage,
+            bounty_id,
+            &Assignee {
+                address: Addr::unchecked(ASSIGNEE),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn with_sender_that_is_not_an_assignee_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_escrowed_bounty(deps.as_mut(), env.clone());
+
+        let err = request_escrow_release_handler(
+            deps.as_mut(),
+            env,
+            mock_info("stranger", &[]),
+            bounty.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_open_dispute_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_escrowed_bounty(deps.as_mut(), env.clone());
+        save_bounty_assignee(deps.as_mut(), bounty.id);
+
+        save_dispute(
+            deps.as_mut().storage,
+            &Dispute {
+                bounty_id: bounty.id,
+                raised_by: bounty.owner.clone(),
+                reason: "work was not delivered".to_string(),
+                raised_at: env.block.time,
+            },
+        )
+        .unwrap();
+
+        let err =
+            request_escrow_release_handler(deps.as_mut(), env, mock_info(ASSIGNEE, &[]), bounty.id)
+                .unwrap_err();
+
+        assert!(err.to_string().contains("open dispute"));
+    }
+
+    #[test]
+    fn with_pending_release_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_escrowed_bounty(deps.as_mut(), env.clone());
+        save_bounty_assignee(deps.as_mut(), bounty.id);
+
+        request_escrow_release_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ASSIGNEE, &[]),
+            bounty.id,
+        )
+        .unwrap();
+
+        let err =
+            request_escrow_release_handler(deps.as_mut(), env, mock_info(ASSIGNEE, &[]), bounty.id)
+                .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: bounty with id {} already has a pending escrow release",
+                bounty.id
+            )
+        );
+    }
+
+    #[test]
+    fn saves_escrow_accept_trigger_after_countdown() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_escrowed_bounty(deps.as_mut(), env.clone());
+        save_bounty_assignee(deps.as_mut(), bounty.id);
+
+        request_escrow_release_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ASSIGNEE, &[]),
+            bounty.id,
+        )
+        .unwrap();
+
+        let release_at = env
+            .block
+            .time
+            .plus_seconds(ESCROW_RELEASE_COUNTDOWN_SECONDS);
+
+        let trigger = get_trigger(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .unwrap();
+
+        assert!(matches!(
+            trigger.configuration,
+            TriggerConfiguration::EscrowAccept { target_time, .. } if target_time == release_at
+        ));
+        assert!(
+            get_time_triggers(deps.as_ref().storage, env.block.time, None)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            get_time_triggers(deps.as_ref().storage, release_at, None).unwrap(),
+            vec![bounty.id]
+        );
+    }
+
+    #[test]
+    fn executing_release_before_countdown_elapses_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_escrowed_bounty(deps.as_mut(), env.clone());
+        save_bounty_assignee(deps.as_mut(), bounty.id);
+
+        request_escrow_release_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ASSIGNEE, &[]),
+            bounty.id,
+        )
+        .unwrap();
+
+        assert!(execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn executing_release_after_countdown_elapses_completes_bounty() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_escrowed_bounty(deps.as_mut(), env.clone());
+        save_bounty_assignee(deps.as_mut(), bounty.id);
+
+        request_escrow_release_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ASSIGNEE, &[]),
+            bounty.id,
+        )
+        .unwrap();
+
+        let mut later_env = env;
+        later_env.block.time = later_env
+            .block
+            .time
+            .plus_seconds(ESCROW_RELEASE_COUNTDOWN_SECONDS);
+
+        let response = execute_trigger_handler(
+            deps.as_mut(),
+            later_env,
+            mock_info(ADMIN, &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "escrow_accepted"));
+        assert_eq!(
+            get_bounty(deps.as_ref().storage, bounty.id).unwrap().status,
+            BountyStatus::Completed
+        );
+    }
+}
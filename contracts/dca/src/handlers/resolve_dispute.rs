@@ -0,0 +1,401 @@
+use crate::{
+    error::ContractError,
+    helpers::cleanup::clear_terminal_bounty_state,
+    helpers::validation::{assert_assignee_share_is_no_greater_than_one, assert_sender_is_arbiter},
+    state::{
+        assignees::get_assignee,
+        bounties::{get_bounty, update_bounty},
+        disburse_escrow_tasks::delete_disburse_escrow_task,
+        disputes::{delete_dispute, get_dispute},
+        escrow_epochs::clear_escrow_epochs,
+        escrow_tranches::delete_escrow_tranche_schedule,
+        events::create_event,
+    },
+    types::{
+        bounty::Bounty,
+        dispute::Ruling,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{Coin, Decimal, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128};
+use shared::{coin::empty_of, cw20::into_transfer_msg};
+
+pub fn resolve_dispute_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    ruling: Ruling,
+) -> Result<Response, ContractError> {
+    assert_sender_is_arbiter(deps.storage, info.sender)?;
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    if get_dispute(deps.storage, bounty.id)?.is_none() {
+        return Err(ContractError::CustomError {
+            val: format!("bounty with id {} has no open dispute", bounty.id),
+        });
+    }
+
+    let (assignee, assignee_share) = match ruling.clone() {
+        Ruling::Owner {} => (None, Decimal::zero()),
+        Ruling::Assignee { assignee } => (Some(assignee), Decimal::one()),
+        Ruling::Split {
+            assignee,
+            assignee_share,
+        } => {
+            assert_assignee_share_is_no_greater_than_one(assignee_share)?;
+            (Some(assignee), assignee_share)
+        }
+    };
+
+    let assignee = match assignee {
+        Some(assignee) => Some(get_assignee(deps.storage, bounty.id, &assignee)?.ok_or(
+            ContractError::CustomError {
+                val: format!(
+                    "{} is not an assignee of bounty with id {}",
+                    assignee, bounty.id
+                ),
+            },
+        )?),
+        None => None,
+    };
+
+    let assignee_amount = bounty.escrowed_amount.amount * assignee_share;
+    let owner_amount = bounty.escrowed_amount.amount - assignee_amount;
+
+    let mut messages = Vec::<SubMsg>::new();
+
+    if let Some(assignee) = assignee {
+        if !assignee_amount.is_zero() {
+            messages.push(SubMsg::new(into_transfer_msg(
+                deps.api,
+                assignee.get_payout_address().as_ref(),
+                Coin::new(assignee_amount.into(), bounty.escrowed_amount.denom.clone()),
+            )?));
+        }
+    }
+
+    if !owner_amount.is_zero() {
+        messages.push(SubMsg::new(into_transfer_msg(
+            deps.api,
+            bounty.owner.as_ref(),
+            Coin::new(owner_amount.into(), bounty.escrowed_amount.denom.clone()),
+        )?));
+    }
+
+    let bounty = update_bounty(
+        deps.storage,
+        Bounty {
+            escrowed_amount: empty_of(bounty.escrowed_amount.clone()),
+            ..bounty
+        },
+    )?;
+
+    delete_dispute(deps.storage, bounty.id);
+    delete_disburse_escrow_task(deps.storage, bounty.id)?;
+    delete_escrow_tranche_schedule(deps.storage, bounty.id);
+    clear_escrow_epochs(deps.storage, bounty.id);
+
+    if bounty.is_terminal() {
+        clear_terminal_bounty_state(deps.storage, &bounty)?;
+    }
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyDisputeResolved { ruling },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("resolve_dispute", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("owner_amount", owner_amount)
+        .add_attribute("assignee_amount", assignee_amount)
+        .add_submessages(messages))
+}
+
+#[cfg(test)]
+mod resolve_dispute_tests {
+    use super::*;
+    use crate::constants::TEN;
+    use crate::state::assignees::{get_assignees, save_assignee};
+    use crate::state::disputes::{get_dispute, save_dispute};
+    use crate::state::escrow_epochs::{get_escrow_epochs, start_escrow_epoch};
+    use crate::state::escrow_tranches::{
+        get_escrow_tranche_schedule, save_escrow_tranche_schedule,
+    };
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, ARBITER, DENOM_UUSK, USER};
+    use crate::types::assignee::Assignee;
+    use crate::types::bounty::BountyStatus;
+    use crate::types::dispute::Dispute;
+    use crate::types::escrow_tranche::{EscrowTranche, EscrowTrancheSchedule};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, BankMsg};
+
+    fn setup_disputed_bounty(mut deps: DepsMut, env: Env) -> Bounty {
+        let bounty = setup_bounty(
+            deps.branch(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        save_dispute(
+            deps.storage,
+            &Dispute {
+                bounty_id: bounty.id,
+                raised_by: Addr::unchecked(USER),
+                reason: "work was only partially delivered".to_string(),
+                raised_at: env.block.time,
+            },
+        )
+        .unwrap();
+
+        bounty
+    }
+
+    #[test]
+    fn with_sender_that_is_not_arbiter_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_disputed_bounty(deps.as_mut(), env.clone());
+
+        let err = resolve_dispute_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            bounty.id,
+            Ruling::Owner {},
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_no_open_dispute_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        let err = resolve_dispute_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ARBITER, &[]),
+            bounty.id,
+            Ruling::Owner {},
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: bounty with id {} has no open dispute", bounty.id)
+        );
+    }
+
+    #[test]
+    fn with_split_ruling_sends_escrow_to_assignee_and_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_disputed_bounty(deps.as_mut(), env.clone());
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        let response = resolve_dispute_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ARBITER, &[]),
+            bounty.id,
+            Ruling::Split {
+                assignee: Addr::unchecked("assignee"),
+                assignee_share: Decimal::percent(30),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: "assignee".to_string(),
+                    amount: vec![Coin::new((TEN * Decimal::percent(30)).into(), DENOM_UUSK)],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: USER.to_string(),
+                    amount: vec![Coin::new((TEN * Decimal::percent(70)).into(), DENOM_UUSK)],
+                }),
+            ]
+        );
+
+        let bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert!(bounty.escrowed_amount.amount.is_zero());
+        assert_eq!(get_dispute(deps.as_ref().storage, bounty.id).unwrap(), None);
+    }
+
+    #[test]
+    fn on_terminal_bounty_clears_terminal_bounty_state() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_disputed_bounty(deps.as_mut(), env.clone());
+
+        update_bounty(
+            deps.as_mut().storage,
+            Bounty {
+                status: BountyStatus::Cancelled,
+                ..bounty.clone()
+            },
+        )
+        .unwrap();
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        resolve_dispute_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ARBITER, &[]),
+            bounty.id,
+            Ruling::Owner {},
+        )
+        .unwrap();
+
+        assert!(get_assignees(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn with_assignee_ruling_sends_escrow_to_payout_address() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_disputed_bounty(deps.as_mut(), env.clone());
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: Some(Addr::unchecked("payout")),
+            },
+        )
+        .unwrap();
+
+        let response = resolve_dispute_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ARBITER, &[]),
+            bounty.id,
+            Ruling::Assignee {
+                assignee: Addr::unchecked("assignee"),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: "payout".to_string(),
+                amount: vec![Coin::new(TEN.into(), DENOM_UUSK)],
+            })]
+        );
+    }
+
+    #[test]
+    fn clears_escrow_epochs_and_tranche_schedule() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_disputed_bounty(deps.as_mut(), env.clone());
+
+        save_escrow_tranche_schedule(
+            deps.as_mut().storage,
+            bounty.id,
+            &EscrowTrancheSchedule::new(vec![EscrowTranche {
+                allocation: Decimal::percent(100),
+                delay_seconds: 0,
+            }]),
+        )
+        .unwrap();
+
+        start_escrow_epoch(
+            deps.as_mut().storage,
+            &bounty,
+            Decimal::percent(5),
+            env.block.time,
+        )
+        .unwrap();
+
+        resolve_dispute_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ARBITER, &[]),
+            bounty.id,
+            Ruling::Owner {},
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_escrow_tranche_schedule(deps.as_ref().storage, bounty.id).unwrap(),
+            None
+        );
+        assert!(get_escrow_epochs(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        time::get_next_target_time,
+        validation::{assert_bounty_is_paused, asset_sender_is_bounty_owner},
+    },
+    state::{
+        bounties::{get_bounty, update_bounty},
+        events::create_event,
+        triggers::save_trigger,
+    },
+    types::{
+        bounty::{Bounty, BountyStatus},
+        event::{EventBuilder, EventData},
+        trigger::{Trigger, TriggerConfiguration},
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn resume_bounty_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_paused(&bounty)?;
+
+    update_bounty(
+        deps.storage,
+        Bounty {
+            status: BountyStatus::Active,
+            ..bounty.clone()
+        },
+    )?;
+
+    if bounty.trigger.is_none() {
+        save_trigger(
+            deps.storage,
+            Trigger {
+                bounty_id: bounty.id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: get_next_target_time(
+                        env.block.time,
+                        bounty.started_at.unwrap_or(env.block.time),
+                        bounty.time_interval.clone(),
+                    ),
+                },
+                retry_count: 0,
+            },
+        )?;
+    }
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(bounty.id, env.block, EventData::BountyResumed {}),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("resume_bounty", "true")
+        .add_attribute("bounty_id", bounty.id))
+}
+
+#[cfg(test)]
+mod resume_bounty_tests {
+    use super::*;
+    use crate::handlers::pause_bounty::pause_bounty_handler;
+    use crate::state::triggers::get_trigger;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_active_bounty_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err =
+            resume_bounty_handler(deps.as_mut(), env, mock_info(USER, &[]), bounty.id).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: bounty is not paused");
+    }
+
+    #[test]
+    fn resumes_bounty_and_recreates_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        pause_bounty_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), bounty.id).unwrap();
+        resume_bounty_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), bounty.id).unwrap();
+
+        let updated_bounty = get_bounty(&deps.storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.status, BountyStatus::Active);
+        assert_eq!(
+            get_trigger(&deps.storage, bounty.id).unwrap(),
+            Some(Trigger {
+                bounty_id: bounty.id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: get_next_target_time(
+                        env.block.time,
+                        bounty.started_at.unwrap_or(env.block.time),
+                        bounty.time_interval,
+                    ),
+                },
+                retry_count: 0,
+            })
+        );
+    }
+}
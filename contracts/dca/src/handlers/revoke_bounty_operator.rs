@@ -0,0 +1,71 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::asset_sender_is_bounty_owner},
+    state::{authz::delete_operator_permissions, bounties::get_bounty},
+};
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response, Uint128};
+
+pub fn revoke_bounty_operator_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    operator: Addr,
+) -> Result<Response, ContractError> {
+    let operator = normalize_address(&operator);
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+
+    delete_operator_permissions(deps.storage, bounty.id, &operator);
+
+    Ok(Response::new()
+        .add_attribute("revoke_bounty_operator", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("operator", operator))
+}
+
+#[cfg(test)]
+mod revoke_bounty_operator_tests {
+    use super::*;
+    use crate::state::authz::{get_operator_permissions, save_operator_permissions};
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::authz::OperatorPermission;
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn deletes_operator_permissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        save_operator_permissions(
+            deps.as_mut().storage,
+            bounty.id,
+            &Addr::unchecked("operator"),
+            &vec![OperatorPermission::Cancel],
+        )
+        .unwrap();
+
+        revoke_bounty_operator_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("operator"),
+        )
+        .unwrap();
+
+        assert!(get_operator_permissions(
+            deps.as_ref().storage,
+            bounty.id,
+            &Addr::unchecked("operator")
+        )
+        .unwrap()
+        .is_empty());
+    }
+}
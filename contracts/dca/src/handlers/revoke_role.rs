@@ -0,0 +1,121 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{admin_actions::create_admin_action, config::get_config, roles::revoke_role},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn revoke_role_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: Addr,
+    role: Role,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    if role == Role::Admin && address == get_config(deps.storage)?.admin {
+        return Err(ContractError::CustomError {
+            val: "cannot revoke the admin role from the contract admin".to_string(),
+        });
+    }
+
+    revoke_role(deps.storage, &address, &role)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::RoleRevoked {
+                address: address.clone(),
+                role: role.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("revoke_role", "true")
+        .add_attribute("address", address)
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+#[cfg(test)]
+mod revoke_role_tests {
+    use super::*;
+    use crate::state::roles::has_role;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = revoke_role_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Addr::unchecked("executor"),
+            Role::Executor,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_contract_admin_as_target_of_admin_role_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = revoke_role_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked(ADMIN),
+            Role::Admin,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot revoke the admin role from the contract admin"
+        );
+    }
+
+    #[test]
+    fn revokes_role_from_address() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        revoke_role_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("executor"),
+            Role::Executor,
+        )
+        .unwrap();
+
+        assert!(!has_role(
+            deps.as_ref().storage,
+            &Addr::unchecked("executor"),
+            &Role::Executor
+        )
+        .unwrap());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_description_is_no_longer_than_1000_characters,
+        assert_destination_allocations_add_up_to_one,
+        assert_destination_callback_addresses_are_valid, assert_destination_gas_limits_are_valid,
+        assert_destinations_limit_is_not_breached,
+        assert_disbursement_schedule_is_valid, assert_ibc_destinations_are_valid,
+        assert_label_is_no_longer_than_100_characters, assert_metadata_uri_is_valid,
+        assert_no_destination_allocations_are_zero, assert_no_more_than_5_fallback_routes,
+        assert_price_bounds_are_valid,
+        assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_tags_are_valid,
+    },
+    state::templates::save_bounty_template,
+    types::template::BountyTemplateParams,
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn save_bounty_template_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    label: String,
+    params: BountyTemplateParams,
+) -> Result<Response, ContractError> {
+    assert_label_is_no_longer_than_100_characters(&label)?;
+    assert_destinations_limit_is_not_breached(&params.destinations)?;
+    assert_no_more_than_5_fallback_routes(&params.fallback_routes)?;
+    assert_tags_are_valid(&params.tags)?;
+    assert_price_bounds_are_valid(params.minimum_price, params.maximum_price)?;
+
+    if let Some(bounty_description) = &params.bounty_description {
+        assert_description_is_no_longer_than_1000_characters(bounty_description)?;
+    }
+
+    if let Some(metadata_uri) = &params.metadata_uri {
+        assert_metadata_uri_is_valid(metadata_uri)?;
+    }
+
+    if !params.destinations.is_empty() {
+        assert_destination_callback_addresses_are_valid(deps.as_ref(), &params.destinations)?;
+        assert_destination_gas_limits_are_valid(&params.destinations)?;
+        assert_ibc_destinations_are_valid(
+            deps.as_ref(),
+            &params.destinations,
+            &params.target_denom,
+        )?;
+        assert_no_destination_allocations_are_zero(&params.destinations)?;
+        assert_destination_allocations_add_up_to_one(&params.destinations)?;
+    }
+
+    if let Some(slippage_tolerance) = params.slippage_tolerance {
+        assert_slippage_tolerance_is_less_than_or_equal_to_one(slippage_tolerance)?;
+    }
+
+    if let Some(executor_slippage_override_cap) = params.executor_slippage_override_cap {
+        assert_slippage_tolerance_is_less_than_or_equal_to_one(executor_slippage_override_cap)?;
+    }
+
+    if let Some(disbursement_schedule) = &params.disbursement_schedule {
+        assert_disbursement_schedule_is_valid(disbursement_schedule)?;
+    }
+
+    let template = save_bounty_template(deps.storage, info.sender, label, params)?;
+
+    Ok(Response::new()
+        .add_attribute("save_bounty_template", "true")
+        .add_attribute("template_id", template.id.to_string())
+        .add_attribute("owner", template.owner))
+}
+
+#[cfg(test)]
+mod save_bounty_template_tests {
+    use super::*;
+    use crate::state::templates::get_bounty_template;
+    use crate::tests::mocks::USER;
+    use crate::types::denom::Denom;
+    use crate::types::destination::Destination;
+    use cosmwasm_std::testing::{mock_dependencies, mock_info};
+    use cosmwasm_std::{Addr, Decimal};
+
+    fn template_params() -> BountyTemplateParams {
+        BountyTemplateParams {
+            bounty_description: Some("weekly report".to_string()),
+            destinations: vec![],
+            target_denom: Denom::new("uusk").unwrap(),
+            route: None,
+            slippage_tolerance: None,
+            exact_receive_amount: None,
+            disbursement_schedule: None,
+            private_metadata: false,
+            executor_slippage_override_cap: None,
+            fallback_routes: vec![],
+            tags: vec![],
+            metadata_uri: None,
+            minimum_price: None,
+            maximum_price: None,
+        }
+    }
+
+    #[test]
+    fn with_destination_allocations_not_adding_up_to_one_fails() {
+        let mut deps = mock_dependencies();
+
+        let err = save_bounty_template_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            "template".to_string(),
+            BountyTemplateParams {
+                destinations: vec![Destination {
+                    allocation: Decimal::percent(50),
+                    ..Destination::default()
+                }],
+                ..template_params()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: destination allocations must add up to 1"
+        );
+    }
+
+    #[test]
+    fn saves_template_owned_by_sender() {
+        let mut deps = mock_dependencies();
+
+        save_bounty_template_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            "template".to_string(),
+            template_params(),
+        )
+        .unwrap();
+
+        let template = get_bounty_template(&deps.storage, 1).unwrap();
+
+        assert_eq!(template.owner, Addr::unchecked(USER));
+        assert_eq!(template.label, "template");
+        assert_eq!(template.params, template_params());
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_executor_reward_percent_is_no_greater_than_100_percent,
+        assert_fee_collector_addresses_are_valid, assert_fee_collector_allocations_add_up_to_one,
+        assert_fee_level_is_valid, assert_no_more_than_10_fee_collectors,
+        assert_page_limit_is_valid, assert_referral_fee_percent_is_no_greater_than_100_percent,
+        assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent,
+        assert_scheduled_config_updates_limit_is_not_breached, assert_sender_has_role,
+        assert_slippage_tolerance_is_less_than_or_equal_to_one,
+        assert_target_start_time_is_in_future,
+    },
+    state::{
+        admin_actions::create_admin_action, config::get_config,
+        scheduled_config_updates::save_scheduled_config_update,
+    },
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+        scheduled_config_update::ConfigChanges,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Timestamp};
+
+pub fn schedule_config_update_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    at: Timestamp,
+    changes: ConfigChanges,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+    assert_target_start_time_is_in_future(env.block.time, at)?;
+    assert_scheduled_config_updates_limit_is_not_breached(deps.storage)?;
+
+    let config = changes.apply_to(get_config(deps.storage)?);
+
+    assert_fee_level_is_valid(&config.automation_fee_percent)?;
+    assert_page_limit_is_valid(Some(config.default_page_limit))?;
+    assert_slippage_tolerance_is_less_than_or_equal_to_one(config.default_slippage_tolerance)?;
+    assert_no_more_than_10_fee_collectors(&config.fee_collectors)?;
+    assert_fee_collector_addresses_are_valid(deps.as_ref(), &config.fee_collectors)?;
+    assert_fee_collector_allocations_add_up_to_one(&config.fee_collectors)?;
+    assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent(
+        config.risk_weighted_average_escrow_level,
+    )?;
+    assert_referral_fee_percent_is_no_greater_than_100_percent(config.referral_fee_percent)?;
+    assert_executor_reward_percent_is_no_greater_than_100_percent(config.executor_reward_percent)?;
+
+    let scheduled_config_update =
+        save_scheduled_config_update(deps.storage, env.block.time, at, changes)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ConfigUpdateScheduled {
+                scheduled_config_update_id: scheduled_config_update.id,
+                activates_at: at,
+                changes: scheduled_config_update.changes.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("schedule_config_update", "true")
+        .add_attribute("scheduled_config_update_id", scheduled_config_update.id.to_string())
+        .add_attribute("activates_at", at.to_string())
+        .add_attribute(
+            "changes",
+            format!("{:?}", scheduled_config_update.changes),
+        ))
+}
+
+#[cfg(test)]
+mod schedule_config_update_tests {
+    use super::*;
+    use crate::{
+        state::scheduled_config_updates::get_scheduled_config_updates,
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{ADMIN, USER},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Decimal,
+    };
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = schedule_config_update_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            env.block.time.plus_seconds(10),
+            ConfigChanges::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_activation_time_in_the_past_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = schedule_config_update_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            env.block.time.minus_seconds(10),
+            ConfigChanges::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: target_start_time_utc_seconds must be some time in the future"
+        );
+    }
+
+    #[test]
+    fn with_invalid_fee_level_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = schedule_config_update_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            env.block.time.plus_seconds(10),
+            ConfigChanges {
+                automation_fee_percent: Some(Decimal::percent(15)),
+                ..ConfigChanges::default()
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: fee level cannot be larger than 5%");
+    }
+
+    #[test]
+    fn with_too_many_scheduled_updates_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for _ in 0..10 {
+            schedule_config_update_handler(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(ADMIN, &[]),
+                env.block.time.plus_seconds(10),
+                ConfigChanges::default(),
+            )
+            .unwrap();
+        }
+
+        let err = schedule_config_update_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            env.block.time.plus_seconds(10),
+            ConfigChanges::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: no more than 10 config updates can be scheduled at once"
+        );
+    }
+
+    #[test]
+    fn saves_scheduled_config_update() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let changes = ConfigChanges {
+            automation_fee_percent: Some(Decimal::percent(2)),
+            ..ConfigChanges::default()
+        };
+
+        schedule_config_update_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            env.block.time.plus_seconds(10),
+            changes.clone(),
+        )
+        .unwrap();
+
+        let updates = get_scheduled_config_updates(deps.as_ref().storage).unwrap();
+
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].activates_at, env.block.time.plus_seconds(10));
+        assert_eq!(updates[0].changes, changes);
+    }
+}
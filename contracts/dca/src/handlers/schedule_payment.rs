@@ -0,0 +1,152 @@
+use crate::{
+    error::ContractError,
+    helpers::address::normalize_address,
+    helpers::validation::{
+        assert_address_is_valid, assert_contract_is_not_paused, assert_exactly_one_asset,
+        assert_target_start_time_is_in_future,
+    },
+    state::scheduled_payments::save_scheduled_payment,
+};
+use cosmwasm_std::{Addr, Coin, DepsMut, Env, MessageInfo, Response, Timestamp};
+
+pub fn schedule_payment_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    to: Addr,
+    amount: Coin,
+    at: Timestamp,
+) -> Result<Response, ContractError> {
+    let to = normalize_address(&to);
+
+    assert_contract_is_not_paused(deps.storage)?;
+    assert_exactly_one_asset(info.funds.clone())?;
+    assert_address_is_valid(deps.as_ref(), &to, "recipient")?;
+    assert_target_start_time_is_in_future(env.block.time, at)?;
+
+    if amount.amount.is_zero() {
+        return Err(ContractError::CustomError {
+            val: String::from("scheduled payment amount must be greater than zero"),
+        });
+    }
+
+    if info.funds[0] != amount {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "received {:?} but scheduled payment requires {:?}",
+                info.funds[0], amount
+            ),
+        });
+    }
+
+    let scheduled_payment =
+        save_scheduled_payment(deps.storage, info.sender, to, amount, at, env.block.time)?;
+
+    Ok(Response::new()
+        .add_attribute("schedule_payment", "true")
+        .add_attribute("payment_id", scheduled_payment.id.to_string())
+        .add_attribute("owner", scheduled_payment.owner)
+        .add_attribute("to", scheduled_payment.to)
+        .add_attribute("amount", scheduled_payment.amount.to_string())
+        .add_attribute("at", scheduled_payment.at.to_string()))
+}
+
+#[cfg(test)]
+mod schedule_payment_tests {
+    use super::*;
+    use crate::{
+        state::scheduled_payments::get_scheduled_payment,
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK, USER},
+        },
+        types::scheduled_payment::ScheduledPayment,
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_time_in_the_past_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let amount = Coin::new(1000, DENOM_UKUJI);
+
+        let err = schedule_payment_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[amount.clone()]),
+            Addr::unchecked("recipient"),
+            amount,
+            env.block.time.minus_seconds(10),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: target_start_time_utc_seconds must be some time in the future"
+        );
+    }
+
+    #[test]
+    fn with_mismatched_funds_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = schedule_payment_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[Coin::new(1000, DENOM_UUSK)]),
+            Addr::unchecked("recipient"),
+            Coin::new(1000, DENOM_UKUJI),
+            env.block.time.plus_seconds(10),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: received {:?} but scheduled payment requires {:?}",
+                Coin::new(1000, DENOM_UUSK),
+                Coin::new(1000, DENOM_UKUJI)
+            )
+        );
+    }
+
+    #[test]
+    fn saves_scheduled_payment() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let amount = Coin::new(1000, DENOM_UKUJI);
+
+        schedule_payment_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[amount.clone()]),
+            Addr::unchecked("recipient"),
+            amount.clone(),
+            env.block.time.plus_seconds(10),
+        )
+        .unwrap();
+
+        let scheduled_payment = get_scheduled_payment(deps.as_ref().storage, 1).unwrap();
+
+        assert_eq!(
+            scheduled_payment,
+            ScheduledPayment {
+                id: 1,
+                owner: Addr::unchecked(USER),
+                to: Addr::unchecked("recipient"),
+                amount,
+                at: env.block.time.plus_seconds(10),
+                created_at: env.block.time,
+            }
+        );
+    }
+}
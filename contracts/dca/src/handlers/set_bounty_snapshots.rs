@@ -0,0 +1,80 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::asset_sender_is_bounty_owner,
+    state::{
+        bounties::get_bounty,
+        bounty_snapshots::{disable_bounty_snapshots, enable_bounty_snapshots},
+    },
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+
+pub fn set_bounty_snapshots_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+
+    if enabled {
+        enable_bounty_snapshots(deps.storage, bounty.id)?;
+    } else {
+        disable_bounty_snapshots(deps.storage, bounty.id);
+    }
+
+    Ok(Response::new()
+        .add_attribute("set_bounty_snapshots", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("enabled", enabled.to_string()))
+}
+
+#[cfg(test)]
+mod set_bounty_snapshots_tests {
+    use super::*;
+    use crate::state::bounty_snapshots::bounty_snapshots_enabled;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = set_bounty_snapshots_handler(
+            deps.as_mut(),
+            mock_info("not-owner", &[]),
+            bounty.id,
+            true,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn enables_and_disables_snapshots() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        set_bounty_snapshots_handler(deps.as_mut(), mock_info(USER, &[]), bounty.id, true).unwrap();
+
+        assert!(bounty_snapshots_enabled(&deps.storage, bounty.id));
+
+        set_bounty_snapshots_handler(deps.as_mut(), mock_info(USER, &[]), bounty.id, false)
+            .unwrap();
+
+        assert!(!bounty_snapshots_enabled(&deps.storage, bounty.id));
+    }
+}
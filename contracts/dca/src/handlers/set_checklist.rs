@@ -0,0 +1,184 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_is_not_cancelled, assert_checklist_items_are_valid,
+        asset_sender_is_bounty_owner,
+    },
+    state::{
+        bounties::get_bounty,
+        checklists::{get_checklist, save_checklist},
+    },
+    types::checklist::{ChecklistItem, ChecklistItemParams},
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+
+pub fn set_checklist_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    items: Vec<ChecklistItemParams>,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_checklist_items_are_valid(&items)?;
+
+    if bounty.disbursement_schedule.is_some() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "bounty with id {} already has a disbursement schedule",
+                bounty.id
+            ),
+        });
+    }
+
+    if get_checklist(deps.storage, bounty.id)?
+        .iter()
+        .any(ChecklistItem::is_completed)
+    {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "bounty with id {} has completed checklist items and cannot be changed",
+                bounty.id
+            ),
+        });
+    }
+
+    let item_count = items.len();
+
+    save_checklist(
+        deps.storage,
+        bounty.id,
+        items.into_iter().map(ChecklistItem::from).collect(),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("set_checklist", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("item_count", item_count.to_string()))
+}
+
+#[cfg(test)]
+mod set_checklist_tests {
+    use super::*;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Decimal, StdError};
+
+    fn checklist_items() -> Vec<ChecklistItemParams> {
+        vec![
+            ChecklistItemParams {
+                description: "design".to_string(),
+                weight: Decimal::percent(40),
+            },
+            ChecklistItemParams {
+                description: "build".to_string(),
+                weight: Decimal::percent(60),
+            },
+        ]
+    }
+
+    #[test]
+    fn with_weights_not_adding_up_to_one_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = set_checklist_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            vec![ChecklistItemParams {
+                description: "design".to_string(),
+                weight: Decimal::percent(40),
+            }],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: checklist item weights must add up to 1"
+        );
+    }
+
+    #[test]
+    fn with_weights_overflowing_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = set_checklist_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            vec![
+                ChecklistItemParams {
+                    description: "design".to_string(),
+                    weight: Decimal::MAX,
+                },
+                ChecklistItemParams {
+                    description: "build".to_string(),
+                    weight: Decimal::percent(60),
+                },
+            ],
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::Std(StdError::Overflow { .. })));
+    }
+
+    #[test]
+    fn with_sender_that_is_not_the_owner_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = set_checklist_handler(
+            deps.as_mut(),
+            mock_info("stranger", &[]),
+            bounty.id,
+            checklist_items(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn saves_checklist() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        set_checklist_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            checklist_items(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_checklist(deps.as_ref().storage, bounty.id).unwrap(),
+            checklist_items()
+                .into_iter()
+                .map(ChecklistItem::from)
+                .collect::<Vec<_>>()
+        );
+    }
+}
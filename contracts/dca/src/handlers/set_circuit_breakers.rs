@@ -0,0 +1,147 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_has_role,
+    state::{
+        admin_actions::create_admin_action,
+        config::{get_config, update_config},
+    },
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        config::Config,
+        role::Role,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn set_circuit_breakers_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    create_paused: Option<bool>,
+    execute_paused: Option<bool>,
+    deposit_paused: Option<bool>,
+    disburse_paused: Option<bool>,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Pauser)?;
+
+    let existing_config = get_config(deps.storage)?;
+
+    let config = update_config(
+        deps.storage,
+        Config {
+            create_paused: create_paused.unwrap_or(existing_config.create_paused),
+            execute_paused: execute_paused.unwrap_or(existing_config.execute_paused),
+            deposit_paused: deposit_paused.unwrap_or(existing_config.deposit_paused),
+            disburse_paused: disburse_paused.unwrap_or(existing_config.disburse_paused),
+            ..existing_config
+        },
+    )?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ConfigUpdated {
+                config: config.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("set_circuit_breakers", "true")
+        .add_attribute("create_paused", config.create_paused.to_string())
+        .add_attribute("execute_paused", config.execute_paused.to_string())
+        .add_attribute("deposit_paused", config.deposit_paused.to_string())
+        .add_attribute("disburse_paused", config.disburse_paused.to_string()))
+}
+
+#[cfg(test)]
+mod set_circuit_breakers_tests {
+    use super::*;
+    use crate::handlers::execute_triggers::execute_triggers_handler;
+    use crate::state::roles::grant_role;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Uint128};
+
+    #[test]
+    fn with_sender_without_pauser_role_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = set_circuit_breakers_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            None,
+            Some(true),
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn only_updates_provided_switches() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        grant_role(
+            deps.as_mut().storage,
+            &Addr::unchecked("pauser"),
+            &Role::Pauser,
+        )
+        .unwrap();
+
+        set_circuit_breakers_handler(
+            deps.as_mut(),
+            env,
+            mock_info("pauser", &[]),
+            None,
+            Some(true),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert!(!config.create_paused);
+        assert!(config.execute_paused);
+        assert!(!config.deposit_paused);
+        assert!(!config.disburse_paused);
+        assert!(!config.paused);
+    }
+
+    #[test]
+    fn with_execute_paused_blocks_trigger_execution() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        set_circuit_breakers_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            None,
+            Some(true),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let err =
+            execute_triggers_handler(deps.as_mut(), env, vec![Uint128::one()], None).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: execution is paused");
+    }
+}
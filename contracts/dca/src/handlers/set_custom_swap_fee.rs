@@ -0,0 +1,109 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{assert_fee_level_is_valid, assert_sender_has_role},
+    state::{admin_actions::create_admin_action, custom_fees::save_custom_swap_fee},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response};
+
+pub fn set_custom_swap_fee_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    swap_fee_percent: Decimal,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::FeeManager)?;
+    assert_fee_level_is_valid(&swap_fee_percent)?;
+
+    save_custom_swap_fee(deps.storage, denom.clone(), swap_fee_percent)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::CustomSwapFeeSet {
+                denom: denom.clone(),
+                swap_fee_percent,
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("set_custom_swap_fee", "true")
+        .add_attribute("denom", denom)
+        .add_attribute("swap_fee_percent", swap_fee_percent.to_string()))
+}
+
+#[cfg(test)]
+mod set_custom_swap_fee_tests {
+    use super::*;
+    use crate::state::custom_fees::get_custom_swap_fee;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = set_custom_swap_fee_handler(
+            deps.as_mut(),
+            env,
+            mock_info("stranger", &[]),
+            DENOM_UKUJI.to_string(),
+            Decimal::percent(1),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_fee_larger_than_5_percent_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = set_custom_swap_fee_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UKUJI.to_string(),
+            Decimal::percent(6),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: fee level cannot be larger than 5%");
+    }
+
+    #[test]
+    fn saves_custom_swap_fee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        set_custom_swap_fee_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UKUJI.to_string(),
+            Decimal::percent(1),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_custom_swap_fee(deps.as_ref().storage, DENOM_UKUJI.to_string()).unwrap(),
+            Some(Decimal::percent(1))
+        );
+    }
+}
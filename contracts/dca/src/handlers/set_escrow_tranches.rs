@@ -0,0 +1,152 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_is_not_cancelled, assert_bounty_is_not_completed,
+        assert_escrow_tranches_are_valid, asset_sender_is_bounty_owner,
+    },
+    state::{
+        bounties::get_bounty,
+        escrow_tranches::{
+            delete_escrow_tranche_schedule, get_escrow_tranche_schedule,
+            save_escrow_tranche_schedule,
+        },
+    },
+    types::escrow_tranche::{EscrowTranche, EscrowTrancheSchedule},
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+
+pub fn set_escrow_tranches_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    tranches: Vec<EscrowTranche>,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_bounty_is_not_completed(&bounty)?;
+
+    if bounty.disbursement_schedule.is_some() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "bounty with id {} disburses escrow on a linear schedule and cannot use tranches",
+                bounty.id
+            ),
+        });
+    }
+
+    if get_escrow_tranche_schedule(deps.storage, bounty.id)?
+        .is_some_and(|schedule| schedule.started_at.is_some())
+    {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "escrow tranche release has already started for bounty with id {}",
+                bounty.id
+            ),
+        });
+    }
+
+    if tranches.is_empty() {
+        delete_escrow_tranche_schedule(deps.storage, bounty.id);
+    } else {
+        assert_escrow_tranches_are_valid(&tranches)?;
+        save_escrow_tranche_schedule(
+            deps.storage,
+            bounty.id,
+            &EscrowTrancheSchedule::new(tranches.clone()),
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("set_escrow_tranches", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("tranches", tranches.len().to_string()))
+}
+
+#[cfg(test)]
+mod set_escrow_tranches_tests {
+    use super::*;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Decimal;
+
+    fn tranches() -> Vec<EscrowTranche> {
+        vec![
+            EscrowTranche {
+                allocation: Decimal::percent(50),
+                delay_seconds: 0,
+            },
+            EscrowTranche {
+                allocation: Decimal::percent(50),
+                delay_seconds: 30 * 24 * 60 * 60,
+            },
+        ]
+    }
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = set_escrow_tranches_handler(
+            deps.as_mut(),
+            mock_info("not-owner", &[]),
+            bounty.id,
+            tranches(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_allocations_not_adding_up_to_one_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = set_escrow_tranches_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            vec![EscrowTranche {
+                allocation: Decimal::percent(50),
+                delay_seconds: 0,
+            }],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: escrow tranche allocations must add up to 1"
+        );
+    }
+
+    #[test]
+    fn saves_escrow_tranche_schedule() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        set_escrow_tranches_handler(deps.as_mut(), mock_info(USER, &[]), bounty.id, tranches())
+            .unwrap();
+
+        assert_eq!(
+            get_escrow_tranche_schedule(&deps.storage, bounty.id).unwrap(),
+            Some(EscrowTrancheSchedule::new(tranches()))
+        );
+    }
+}
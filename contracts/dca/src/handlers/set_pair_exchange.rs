@@ -0,0 +1,154 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        address::normalize_address,
+        validation::{assert_exchange_is_registered, assert_sender_has_role},
+    },
+    state::{
+        admin_actions::create_admin_action,
+        exchanges::{remove_pair_exchange, save_pair_exchange},
+    },
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn set_pair_exchange_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    swap_denom: String,
+    target_denom: String,
+    exchange: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let exchange = exchange.as_ref().map(normalize_address);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    match &exchange {
+        Some(exchange) => {
+            assert_exchange_is_registered(deps.storage, exchange)?;
+            save_pair_exchange(
+                deps.storage,
+                swap_denom.clone(),
+                target_denom.clone(),
+                exchange,
+            )?;
+        }
+        None => remove_pair_exchange(deps.storage, swap_denom.clone(), target_denom.clone()),
+    }
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::PairExchangeSet {
+                swap_denom: swap_denom.clone(),
+                target_denom: target_denom.clone(),
+                exchange: exchange.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("set_pair_exchange", "true")
+        .add_attribute("swap_denom", swap_denom)
+        .add_attribute("target_denom", target_denom)
+        .add_attribute(
+            "exchange",
+            exchange.map_or("none".to_string(), |exchange| exchange.to_string()),
+        ))
+}
+
+#[cfg(test)]
+mod set_pair_exchange_tests {
+    use super::*;
+    use crate::state::exchanges::{get_pair_exchange, save_exchange};
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK};
+    use crate::types::exchange::Exchange;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unregistered_exchange_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = set_pair_exchange_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UKUJI.to_string(),
+            DENOM_UUSK.to_string(),
+            Some(Addr::unchecked("astroport")),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: exchange astroport is not registered"
+        );
+    }
+
+    #[test]
+    fn sets_and_clears_pair_exchange() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_exchange(
+            deps.as_mut().storage,
+            &Exchange {
+                address: Addr::unchecked("astroport"),
+                weight: 1,
+            },
+        )
+        .unwrap();
+
+        set_pair_exchange_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            DENOM_UKUJI.to_string(),
+            DENOM_UUSK.to_string(),
+            Some(Addr::unchecked("astroport")),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_pair_exchange(
+                deps.as_ref().storage,
+                DENOM_UKUJI.to_string(),
+                DENOM_UUSK.to_string()
+            )
+            .unwrap(),
+            Some(Addr::unchecked("astroport"))
+        );
+
+        set_pair_exchange_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            DENOM_UKUJI.to_string(),
+            DENOM_UUSK.to_string(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_pair_exchange(
+                deps.as_ref().storage,
+                DENOM_UKUJI.to_string(),
+                DENOM_UUSK.to_string()
+            )
+            .unwrap(),
+            None
+        );
+    }
+}
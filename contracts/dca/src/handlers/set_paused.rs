@@ -0,0 +1,87 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_has_role,
+    state::{
+        admin_actions::create_admin_action,
+        config::{get_config, update_config},
+    },
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        config::Config,
+        role::Role,
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+pub fn set_paused_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Pauser)?;
+
+    let existing_config = get_config(deps.storage)?;
+
+    let config = update_config(
+        deps.storage,
+        Config {
+            paused,
+            ..existing_config
+        },
+    )?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ConfigUpdated { config },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("set_paused", "true")
+        .add_attribute("paused", paused.to_string()))
+}
+
+#[cfg(test)]
+mod set_paused_tests {
+    use super::*;
+    use crate::state::roles::grant_role;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn with_sender_without_pauser_role_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = set_paused_handler(deps.as_mut(), env, mock_info(USER, &[]), true).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_pauser_role_pauses_contract() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        grant_role(
+            deps.as_mut().storage,
+            &Addr::unchecked("pauser"),
+            &Role::Pauser,
+        )
+        .unwrap();
+
+        set_paused_handler(deps.as_mut(), env, mock_info("pauser", &[]), true).unwrap();
+
+        assert!(get_config(deps.as_ref().storage).unwrap().paused);
+    }
+}
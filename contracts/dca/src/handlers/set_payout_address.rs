@@ -0,0 +1,230 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        address::normalize_address,
+        validation::{assert_address_is_valid, assert_bounty_is_not_cancelled},
+    },
+    state::{
+        assignees::{get_assignee, save_assignee},
+        bounties::get_bounty,
+        events::create_event,
+    },
+    types::{
+        assignee::Assignee,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn set_payout_address_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    address: Addr,
+) -> Result<Response, ContractError> {
+    let address = normalize_address(&address);
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_address_is_valid(deps.as_ref(), &address, "payout address")?;
+
+    let assignee = get_assignee(deps.storage, bounty.id, &info.sender)?
+        .ok_or(ContractError::Unauthorized {})?;
+
+    save_assignee(
+        deps.storage,
+        bounty.id,
+        &Assignee {
+            payout_address: Some(address.clone()),
+            ..assignee
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyPayoutAddressSet {
+                assignee: info.sender.clone(),
+                payout_address: address.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("set_payout_address", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("assignee", info.sender)
+        .add_attribute("payout_address", address))
+}
+
+#[cfg(test)]
+mod set_payout_address_tests {
+    use super::*;
+    use crate::state::config::{get_config, update_config};
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::bounty::Bounty;
+    use crate::types::config::Config;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Decimal;
+
+    #[test]
+    fn with_sender_that_is_not_an_assignee_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = set_payout_address_handler(
+            deps.as_mut(),
+            env,
+            mock_info("stranger", &[]),
+            bounty.id,
+            Addr::unchecked("cold-wallet"),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn saves_payout_address_against_assignee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("hot-wallet"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        set_payout_address_handler(
+            deps.as_mut(),
+            env,
+            mock_info("hot-wallet", &[]),
+            bounty.id,
+            Addr::unchecked("cold-wallet"),
+        )
+        .unwrap();
+
+        let assignee = get_assignee(
+            deps.as_ref().storage,
+            bounty.id,
+            &Addr::unchecked("hot-wallet"),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            assignee.payout_address,
+            Some(Addr::unchecked("cold-wallet"))
+        );
+        assert_eq!(
+            assignee.get_payout_address(),
+            Addr::unchecked("cold-wallet")
+        );
+    }
+
+    fn save_hot_wallet_assignee(deps: DepsMut, bounty_id: Uint128) {
+        save_assignee(
+            deps.storage,
+            bounty_id,
+            &Assignee {
+                address: Addr::unchecked("hot-wallet"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn with_payout_address_from_another_chain_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                address_prefix: Some("kujira".to_string()),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_hot_wallet_assignee(deps.as_mut(), bounty.id);
+
+        let err = set_payout_address_handler(
+            deps.as_mut(),
+            env,
+            mock_info("hot-wallet", &[]),
+            bounty.id,
+            Addr::unchecked("osmo1coldwallet"),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: payout address osmo1coldwallet does not have the expected prefix kujira"
+        );
+    }
+
+    #[test]
+    fn with_upper_case_payout_address_saves_normalized_address() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_hot_wallet_assignee(deps.as_mut(), bounty.id);
+
+        set_payout_address_handler(
+            deps.as_mut(),
+            env,
+            mock_info("hot-wallet", &[]),
+            bounty.id,
+            Addr::unchecked("COLD-WALLET"),
+        )
+        .unwrap();
+
+        let assignee = get_assignee(
+            deps.as_ref().storage,
+            bounty.id,
+            &Addr::unchecked("hot-wallet"),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            assignee.payout_address,
+            Some(Addr::unchecked("cold-wallet"))
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        address::normalize_address,
+        validation::{assert_bounty_is_not_cancelled, asset_sender_is_bounty_owner},
+    },
+    state::{
+        bounties::get_bounty,
+        update_permissions::{delete_update_permissions, save_update_permissions},
+    },
+    types::updatable_field::UpdatableField,
+};
+use cosmwasm_std::{Addr, DepsMut, MessageInfo, Response, Uint128};
+
+pub fn set_update_permissions_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    delegate: Addr,
+    fields: Vec<UpdatableField>,
+) -> Result<Response, ContractError> {
+    let delegate = normalize_address(&delegate);
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+
+    deps.api.addr_validate(delegate.as_ref())?;
+
+    if fields.is_empty() {
+        delete_update_permissions(deps.storage, bounty.id, &delegate);
+    } else {
+        save_update_permissions(deps.storage, bounty.id, &delegate, &fields)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("set_update_permissions", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("delegate", delegate)
+        .add_attribute("fields", format!("{:?}", fields)))
+}
+
+#[cfg(test)]
+mod set_update_permissions_tests {
+    use super::*;
+    use crate::state::update_permissions::get_update_permissions;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_sender_that_is_not_owner_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = set_update_permissions_handler(
+            deps.as_mut(),
+            mock_info("stranger", &[]),
+            bounty.id,
+            Addr::unchecked("bot"),
+            vec![UpdatableField::SlippageTolerance],
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn saves_update_permissions_for_delegate() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        set_update_permissions_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("bot"),
+            vec![UpdatableField::SlippageTolerance],
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_update_permissions(deps.as_ref().storage, bounty.id, &Addr::unchecked("bot"))
+                .unwrap(),
+            vec![UpdatableField::SlippageTolerance]
+        );
+    }
+
+    #[test]
+    fn with_no_fields_revokes_update_permissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        set_update_permissions_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("bot"),
+            vec![UpdatableField::Label],
+        )
+        .unwrap();
+
+        set_update_permissions_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("bot"),
+            vec![],
+        )
+        .unwrap();
+
+        assert!(
+            get_update_permissions(deps.as_ref().storage, bounty.id, &Addr::unchecked("bot"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_viewing_key_is_between_8_and_64_characters, asset_sender_is_bounty_owner,
+    },
+    state::{bounties::get_bounty, viewing_keys::save_viewing_key},
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+
+pub fn set_viewing_key_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    key: String,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner, info.sender)?;
+    assert_viewing_key_is_between_8_and_64_characters(&key)?;
+
+    save_viewing_key(deps.storage, bounty.id, &key)?;
+
+    Ok(Response::new()
+        .add_attribute("set_viewing_key", "true")
+        .add_attribute("bounty_id", bounty.id))
+}
+
+#[cfg(test)]
+mod set_viewing_key_tests {
+    use super::*;
+    use crate::state::viewing_keys::get_viewing_key;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = set_viewing_key_handler(
+            deps.as_mut(),
+            mock_info("not-owner", &[]),
+            bounty.id,
+            "viewing-key".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_short_key_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        let err = set_viewing_key_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            "key".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Viewing key must be between 8 and 64 characters"
+        );
+    }
+
+    #[test]
+    fn saves_viewing_key() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        set_viewing_key_handler(
+            deps.as_mut(),
+            mock_info(USER, &[]),
+            bounty.id,
+            "viewing-key".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_viewing_key(&deps.storage, bounty.id).unwrap(),
+            Some("viewing-key".to_string())
+        );
+    }
+}
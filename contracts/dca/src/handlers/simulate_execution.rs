@@ -0,0 +1,173 @@
+use crate::{
+    helpers::{
+        disbursement::get_disbursement_destinations,
+        fees::{get_automation_fee_rate, get_swap_fee_rate},
+        price::get_twap_to_now,
+        vault::get_swap_amount,
+    },
+    msg::{DestinationAmount, ExecutionSimulationResponse},
+    state::{bounties::get_bounty, config::get_config},
+    types::destination::DisbursementOutcome,
+};
+use cosmwasm_std::{Coin, Decimal, Deps, Env, StdError, StdResult, Uint128};
+
+pub fn simulate_execution_handler(
+    deps: Deps,
+    env: Env,
+    bounty_id: Uint128,
+) -> StdResult<ExecutionSimulationResponse> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+    let config = get_config(deps.storage)?;
+
+    let sent = get_swap_amount(&deps, &env, &bounty)?;
+
+    let (price, received_before_fees, swap_fee_rate) = if bounty.is_direct_payout() {
+        (Decimal::one(), sent.amount, Decimal::zero())
+    } else {
+        let price = get_twap_to_now(
+            &deps.querier,
+            config.exchange_contract_address.clone(),
+            bounty.get_swap_denom(),
+            bounty.target_denom.clone(),
+            config.twap_period,
+            bounty.route.clone(),
+        )?;
+
+        if price.is_zero() {
+            return Err(StdError::generic_err(format!(
+                "twap price for bounty with id {} is zero",
+                bounty.id
+            )));
+        }
+
+        (
+            price,
+            sent.amount * (Decimal::one() / price),
+            get_swap_fee_rate(
+                deps.storage,
+                &bounty.target_denom.to_string(),
+                &bounty.swap_adjustment_strategy,
+            )?,
+        )
+    };
+
+    let swap_fee = received_before_fees * swap_fee_rate;
+    let automation_fee =
+        (received_before_fees - swap_fee) * get_automation_fee_rate(deps.storage, &bounty)?;
+    let received = received_before_fees - swap_fee - automation_fee;
+    let escrowed = received * bounty.escrow_level;
+    let disbursed = received - escrowed;
+
+    let target_denom = bounty.target_denom.to_string();
+
+    let destinations = get_disbursement_destinations(
+        deps.storage,
+        &bounty,
+        &DisbursementOutcome::Succeeded {
+            received: disbursed,
+        },
+    )?
+    .into_iter()
+    .map(|destination| DestinationAmount {
+        amount: Coin::new(
+            (disbursed * destination.allocation).into(),
+            target_denom.clone(),
+        ),
+        address: destination.address,
+    })
+    .collect::<Vec<DestinationAmount>>();
+
+    Ok(ExecutionSimulationResponse {
+        sent,
+        received: Coin::new(received.into(), target_denom.clone()),
+        swap_fee: Coin::new(swap_fee.into(), target_denom.clone()),
+        automation_fee: Coin::new(automation_fee.into(), target_denom.clone()),
+        escrowed: Coin::new(escrowed.into(), target_denom),
+        price,
+        destinations,
+    })
+}
+
+#[cfg(test)]
+mod simulate_execution_tests {
+    use super::*;
+    use crate::{
+        tests::{
+            helpers::{instantiate_contract, setup_bounty},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, USER},
+        },
+        types::{bounty::Bounty, denom::Denom},
+    };
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        Addr,
+    };
+
+    #[test]
+    fn for_swap_bounty_applies_price_and_fees() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let response = simulate_execution_handler(deps.as_ref(), env, bounty.id).unwrap();
+
+        let swap_fee_rate = get_swap_fee_rate(
+            deps.as_ref().storage,
+            &bounty.target_denom.to_string(),
+            &None,
+        )
+        .unwrap();
+        let swap_fee = bounty.swap_amount * swap_fee_rate;
+
+        assert_eq!(response.price, Decimal::one());
+        assert_eq!(
+            response.sent,
+            Coin::new(bounty.swap_amount.into(), bounty.get_swap_denom())
+        );
+        assert_eq!(response.swap_fee.amount, swap_fee);
+        assert_eq!(
+            response.received.amount,
+            bounty.swap_amount - swap_fee - response.automation_fee.amount
+        );
+        assert_eq!(
+            response.destinations,
+            vec![DestinationAmount {
+                address: Addr::unchecked(USER),
+                amount: response.received.clone(),
+            }]
+        );
+    }
+
+    #[test]
+    fn for_direct_payout_bounty_charges_no_swap_fee() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                target_denom: Denom::new(DENOM_UKUJI).unwrap(),
+                escrow_level: Decimal::percent(10),
+                ..Bounty::default()
+            },
+        );
+
+        let response = simulate_execution_handler(deps.as_ref(), env, bounty.id).unwrap();
+
+        assert_eq!(response.swap_fee, Coin::new(0, DENOM_UKUJI));
+        assert_eq!(
+            response.escrowed.amount,
+            response.received.amount * Decimal::percent(10)
+        );
+        assert_eq!(
+            response.destinations[0].amount.amount,
+            response.received.amount - response.escrowed.amount
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_model_adjustment_value_is_valid,
+    state::model_adjustments::{get_model_adjustment, is_model_provider, save_model_submission},
+};
+use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response};
+
+pub fn submit_model_adjustment_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    model_id: u8,
+    value: Decimal,
+) -> Result<Response, ContractError> {
+    if !is_model_provider(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    assert_model_adjustment_value_is_valid(value)?;
+
+    save_model_submission(deps.storage, model_id, &info.sender, value, env.block.time)?;
+
+    let adjustment = get_model_adjustment(deps.storage, model_id, env.block.time)?;
+
+    Ok(Response::new()
+        .add_attribute("submit_model_adjustment", "true")
+        .add_attribute("model_id", model_id.to_string())
+        .add_attribute("provider", info.sender)
+        .add_attribute("value", value.to_string())
+        .add_attribute(
+            "median_value",
+            adjustment.map_or("none".to_string(), |value| value.to_string()),
+        ))
+}
+
+#[cfg(test)]
+mod submit_model_adjustment_tests {
+    use super::*;
+    use crate::state::model_adjustments::save_model_provider;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn with_sender_that_is_not_a_provider_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = submit_model_adjustment_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            30,
+            Decimal::percent(90),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_value_out_of_range_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        save_model_provider(deps.as_mut().storage, &Addr::unchecked("provider")).unwrap();
+
+        let err = submit_model_adjustment_handler(
+            deps.as_mut(),
+            env,
+            mock_info("provider", &[]),
+            30,
+            Decimal::percent(400),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: model adjustment value must be between 0.2 and 3.5"
+        );
+    }
+
+    #[test]
+    fn updates_median_of_provider_submissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for (provider, value) in [
+            ("provider-1", Decimal::percent(80)),
+            ("provider-2", Decimal::percent(120)),
+        ] {
+            save_model_provider(deps.as_mut().storage, &Addr::unchecked(provider)).unwrap();
+            submit_model_adjustment_handler(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(provider, &[]),
+                30,
+                value,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            get_model_adjustment(deps.as_ref().storage, 30, env.block.time).unwrap(),
+            Some(Decimal::one())
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_is_not_cancelled, assert_submission_is_no_longer_than_1000_characters,
+    },
+    state::{
+        assignees::{get_assignee, save_assignee},
+        bounties::get_bounty,
+        events::create_event,
+    },
+    types::{
+        assignee::Assignee,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn submit_work_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    submission: String,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    assert_bounty_is_not_cancelled(&bounty)?;
+    assert_submission_is_no_longer_than_1000_characters(&submission)?;
+
+    let assignee = get_assignee(deps.storage, bounty.id, &info.sender)?
+        .ok_or(ContractError::Unauthorized {})?;
+
+    save_assignee(
+        deps.storage,
+        bounty.id,
+        &Assignee {
+            submission: Some(submission.clone()),
+            submitted_at: Some(env.block.time),
+            ..assignee
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyWorkSubmitted {
+                assignee: info.sender.clone(),
+                submission,
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("submit_work", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("assignee", info.sender))
+}
+
+#[cfg(test)]
+mod submit_work_tests {
+    use super::*;
+    use crate::state::assignees::save_assignee;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Decimal};
+
+    #[test]
+    fn with_sender_that_is_not_an_assignee_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = submit_work_handler(
+            deps.as_mut(),
+            env,
+            mock_info("stranger", &[]),
+            bounty.id,
+            "https://github.com/pr/1".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn saves_submission_against_assignee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        submit_work_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("assignee", &[]),
+            bounty.id,
+            "https://github.com/pr/1".to_string(),
+        )
+        .unwrap();
+
+        let assignee = get_assignee(deps.as_ref().storage, bounty.id, &Addr::unchecked("assignee"))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            assignee.submission,
+            Some("https://github.com/pr/1".to_string())
+        );
+        assert_eq!(assignee.submitted_at, Some(env.block.time));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        address::normalize_address,
+        validation::{assert_bounty_is_not_cancelled, asset_sender_is_bounty_owner},
+    },
+    state::{
+        assignees::{delete_assignee, get_assignee},
+        bounties::get_bounty,
+        events::create_event,
+    },
+    types::event::{EventBuilder, EventData},
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn unassign_bounty_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    assignee: Addr,
+) -> Result<Response, ContractError> {
+    let assignee = normalize_address(&assignee);
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_not_cancelled(&bounty)?;
+
+    if get_assignee(deps.storage, bounty.id, &assignee)?.is_none() {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "{} is not an assignee of bounty with id {}",
+                assignee, bounty.id
+            ),
+        });
+    }
+
+    delete_assignee(deps.storage, bounty.id, &assignee);
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyUnassigned {
+                assignee: assignee.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("unassign_bounty", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("assignee", assignee))
+}
+
+#[cfg(test)]
+mod unassign_bounty_tests {
+    use super::*;
+    use crate::state::assignees::{get_assignees, save_assignee};
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::assignee::Assignee;
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Decimal;
+
+    #[test]
+    fn with_unknown_assignee_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = unassign_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("assignee"),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: assignee is not an assignee of bounty with id {}",
+                bounty.id
+            )
+        );
+    }
+
+    #[test]
+    fn removes_assignee() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        unassign_bounty_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            bounty.id,
+            Addr::unchecked("assignee"),
+        )
+        .unwrap();
+
+        let assignees = get_assignees(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert!(assignees.is_empty());
+    }
+}
@@ -1,22 +1,37 @@
 use crate::{
     error::ContractError,
     helpers::validation::{
-        assert_addresses_are_valid, assert_fee_collector_addresses_are_valid,
-        assert_fee_collector_allocations_add_up_to_one, assert_fee_level_is_valid,
-        assert_no_more_than_10_fee_collectors, assert_page_limit_is_valid,
+        assert_address_is_valid, assert_addresses_are_valid,
+        assert_auto_route_safety_margin_is_no_greater_than_100_percent,
+        assert_executor_reward_percent_is_no_greater_than_100_percent,
+        assert_fee_collector_addresses_are_valid, assert_fee_collector_allocations_add_up_to_one,
+        assert_fee_level_is_valid, assert_no_more_than_10_fee_collectors,
+        assert_page_limit_is_valid, assert_referral_fee_percent_is_no_greater_than_100_percent,
         assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent,
-        assert_sender_is_admin, assert_slippage_tolerance_is_less_than_or_equal_to_one,
-        assert_twap_period_is_valid,
+        assert_sender_has_role, assert_slippage_retry_interval_is_valid,
+        assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_twap_period_is_valid,
     },
-    state::config::{get_config, update_config},
-    types::{config::Config, fee_collector::FeeCollector},
+    state::{
+        admin_actions::create_admin_action,
+        config::{get_config, update_config},
+    },
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        config::Config,
+        fee_collector::FeeCollector,
+        fee_grant_config::FeeGrantConfig,
+        role::Role,
+        rounding_mode::RoundingMode,
+    },
+};
+use cosmwasm_std::{
+    Addr, Coin, Decimal, DepsMut, Env, MessageInfo, Response, Uint128,
 };
-use cosmwasm_std::{Addr, Decimal, DepsMut, MessageInfo, Response};
 
 pub fn update_config_handler(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    executors: Option<Vec<Addr>>,
     fee_collectors: Option<Vec<FeeCollector>>,
     default_swap_fee_percent: Option<Decimal>,
     weighted_scale_swap_fee_percent: Option<Decimal>,
@@ -27,13 +42,29 @@ pub fn update_config_handler(
     twap_period: Option<u64>,
     default_slippage_tolerance: Option<Decimal>,
     exchange_contract_address: Option<Addr>,
+    arbiter: Option<Option<Addr>>,
+    fee_grant: Option<FeeGrantConfig>,
+    referral_fee_percent: Option<Decimal>,
+    executor_reward_percent: Option<Decimal>,
+    minimum_deposit: Option<Vec<Coin>>,
+    minimum_swap_amount: Option<Uint128>,
+    event_retention_blocks: Option<u64>,
+    max_bounty_balance: Option<Vec<Coin>>,
+    max_total_balance_per_owner: Option<Vec<Coin>>,
+    exchange_adapters: Option<Vec<Addr>>,
+    auto_route_safety_margin: Option<Decimal>,
+    price_oracle_address: Option<Addr>,
+    rounding_mode: Option<RoundingMode>,
+    address_prefix: Option<String>,
+    slippage_retry_interval_seconds: Option<u64>,
+    max_slippage_retries: Option<u8>,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
     let existing_config = get_config(deps.storage)?;
 
     let config = Config {
         admin: existing_config.admin,
-        executors: executors.unwrap_or(existing_config.executors),
+        pending_admin: existing_config.pending_admin,
         fee_collectors: fee_collectors.unwrap_or(existing_config.fee_collectors),
         default_swap_fee_percent: default_swap_fee_percent
             .unwrap_or(existing_config.default_swap_fee_percent),
@@ -43,6 +74,10 @@ pub fn update_config_handler(
             .unwrap_or(existing_config.automation_fee_percent),
         default_page_limit: default_page_limit.unwrap_or(existing_config.default_page_limit),
         paused: paused.unwrap_or(existing_config.paused),
+        create_paused: existing_config.create_paused,
+        execute_paused: existing_config.execute_paused,
+        deposit_paused: existing_config.deposit_paused,
+        disburse_paused: existing_config.disburse_paused,
         risk_weighted_average_escrow_level: risk_weighted_average_escrow_level
             .unwrap_or(existing_config.risk_weighted_average_escrow_level),
         twap_period: twap_period.unwrap_or(existing_config.twap_period),
@@ -50,6 +85,28 @@ pub fn update_config_handler(
             .unwrap_or(existing_config.default_slippage_tolerance),
         exchange_contract_address: exchange_contract_address
             .unwrap_or(existing_config.exchange_contract_address),
+        arbiter: arbiter.unwrap_or(existing_config.arbiter),
+        fee_grant: fee_grant.or(existing_config.fee_grant),
+        simulation_mode: existing_config.simulation_mode,
+        referral_fee_percent: referral_fee_percent
+            .unwrap_or(existing_config.referral_fee_percent),
+        executor_reward_percent: executor_reward_percent
+            .unwrap_or(existing_config.executor_reward_percent),
+        minimum_deposit: minimum_deposit.unwrap_or(existing_config.minimum_deposit),
+        minimum_swap_amount: minimum_swap_amount.unwrap_or(existing_config.minimum_swap_amount),
+        event_retention_blocks: event_retention_blocks.or(existing_config.event_retention_blocks),
+        max_bounty_balance: max_bounty_balance.unwrap_or(existing_config.max_bounty_balance),
+        max_total_balance_per_owner: max_total_balance_per_owner
+            .unwrap_or(existing_config.max_total_balance_per_owner),
+        exchange_adapters: exchange_adapters.unwrap_or(existing_config.exchange_adapters),
+        auto_route_safety_margin: auto_route_safety_margin
+            .unwrap_or(existing_config.auto_route_safety_margin),
+        price_oracle_address: price_oracle_address.or(existing_config.price_oracle_address),
+        rounding_mode: rounding_mode.unwrap_or(existing_config.rounding_mode),
+        address_prefix: address_prefix.or(existing_config.address_prefix),
+        slippage_retry_interval_seconds: slippage_retry_interval_seconds
+            .unwrap_or(existing_config.slippage_retry_interval_seconds),
+        max_slippage_retries: max_slippage_retries.unwrap_or(existing_config.max_slippage_retries),
     };
 
     assert_fee_level_is_valid(&config.default_swap_fee_percent)?;
@@ -58,16 +115,41 @@ pub fn update_config_handler(
     assert_page_limit_is_valid(Some(config.default_page_limit))?;
     assert_slippage_tolerance_is_less_than_or_equal_to_one(config.default_slippage_tolerance)?;
     assert_twap_period_is_valid(config.twap_period)?;
-    assert_addresses_are_valid(deps.as_ref(), &config.executors, "executor")?;
     assert_no_more_than_10_fee_collectors(&config.fee_collectors)?;
     assert_fee_collector_addresses_are_valid(deps.as_ref(), &config.fee_collectors)?;
     assert_fee_collector_allocations_add_up_to_one(&config.fee_collectors)?;
     assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent(
         config.risk_weighted_average_escrow_level,
     )?;
+    assert_referral_fee_percent_is_no_greater_than_100_percent(config.referral_fee_percent)?;
+    assert_executor_reward_percent_is_no_greater_than_100_percent(config.executor_reward_percent)?;
+    assert_auto_route_safety_margin_is_no_greater_than_100_percent(
+        config.auto_route_safety_margin,
+    )?;
+    assert_addresses_are_valid(deps.as_ref(), &config.exchange_adapters, "exchange adapter")?;
+    assert_slippage_retry_interval_is_valid(config.slippage_retry_interval_seconds)?;
+
+    if let Some(price_oracle_address) = &config.price_oracle_address {
+        assert_address_is_valid(deps.as_ref(), price_oracle_address, "price oracle")?;
+    }
+
+    if let Some(fee_grant) = &config.fee_grant {
+        assert_addresses_are_valid(deps.as_ref(), &fee_grant.partners, "fee grant partner")?;
+    }
 
     let config = update_config(deps.storage, config)?;
 
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::ConfigUpdated {
+                config: config.clone(),
+            },
+        ),
+    )?;
+
     Ok(Response::default()
         .add_attribute("update_config", "true")
         .add_attribute("config", format!("{:?}", config)))
@@ -77,65 +159,49 @@ pub fn update_config_handler(
 mod update_config_tests {
     use super::*;
     use crate::{
-        state::config::get_config,
-        tests::{helpers::instantiate_contract, mocks::ADMIN},
+        msg::ExecuteMsg,
+        state::{admin_actions::get_admin_actions, config::get_config},
+        tests::{
+            helpers::instantiate_contract,
+            mocks::{ADMIN, ARBITER},
+        },
+        types::admin_action::AdminAction,
     };
     use cosmwasm_std::{
+        from_json,
         testing::{mock_dependencies, mock_env, mock_info},
         Decimal,
     };
     use std::str::FromStr;
 
     #[test]
-    fn update_executors_with_no_value_should_not_change_value() {
+    fn records_admin_action() {
         let mut deps = mock_dependencies();
+        let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
-
-        let config_before_update = get_config(deps.as_ref().storage).unwrap();
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
 
         update_config_handler(
             deps.as_mut(),
+            env.clone(),
             info,
             None,
             None,
             None,
             None,
             None,
+            Some(true),
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
             None,
             None,
-        )
-        .unwrap();
-
-        let config_after_update = get_config(deps.as_ref().storage).unwrap();
-
-        assert_eq!(
-            config_after_update.executors,
-            config_before_update.executors
-        );
-    }
-
-    #[test]
-    fn update_executors_with_valid_value_should_succeed() {
-        let mut deps = mock_dependencies();
-        let info = mock_info(ADMIN, &[]);
-
-        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
-
-        let executors = Some(vec![
-            Addr::unchecked("executor-1"),
-            Addr::unchecked("executor-2"),
-        ]);
-
-        update_config_handler(
-            deps.as_mut(),
-            info,
-            executors.clone(),
             None,
             None,
             None,
@@ -151,7 +217,16 @@ mod update_config_tests {
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
-        assert_eq!(config.executors, executors.unwrap());
+        assert_eq!(
+            get_admin_actions(deps.as_ref().storage, None, None, None).unwrap(),
+            vec![AdminAction {
+                id: 1,
+                admin: Addr::unchecked(ADMIN),
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                data: AdminActionData::ConfigUpdated { config },
+            }]
+        );
     }
 
     #[test]
@@ -163,9 +238,9 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
-            None,
             Some(Decimal::percent(2)),
             None,
             None,
@@ -175,6 +250,22 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -192,9 +283,9 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
-            None,
             Some(Decimal::percent(15)),
             None,
             None,
@@ -204,6 +295,22 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -219,11 +326,11 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
             None,
-            None,
             Some(Decimal::percent(2)),
             None,
             None,
@@ -231,6 +338,22 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -248,11 +371,11 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
             None,
-            None,
             Some(Decimal::percent(15)),
             None,
             None,
@@ -260,6 +383,22 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -277,6 +416,7 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -289,6 +429,21 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -308,11 +463,11 @@ mod update_config_tests {
         instantiate_contract(deps.as_mut(), mock_env(), info.clone());
 
         let fee_collectors = Some(vec![
-            FeeCollector {
+            FeeCollector::Address {
                 address: ADMIN.to_string(),
                 allocation: Decimal::from_str("0.9").unwrap(),
             },
-            FeeCollector {
+            FeeCollector::Address {
                 address: ADMIN.to_string(),
                 allocation: Decimal::from_str("0.1").unwrap(),
             },
@@ -320,8 +475,8 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
-            None,
             fee_collectors.clone(),
             None,
             None,
@@ -332,6 +487,22 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -349,14 +520,14 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
-            None,
             Some(vec![
-                FeeCollector {
+                FeeCollector::Address {
                     address: ADMIN.to_string(),
                     allocation: Decimal::from_str("1").unwrap(),
                 },
-                FeeCollector {
+                FeeCollector::Address {
                     address: ADMIN.to_string(),
                     allocation: Decimal::from_str("1").unwrap(),
                 },
@@ -370,6 +541,22 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -388,6 +575,7 @@ mod update_config_tests {
 
         update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -395,11 +583,26 @@ mod update_config_tests {
             None,
             None,
             None,
-            None,
             Some(Decimal::percent(19)),
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -420,6 +623,7 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -427,11 +631,26 @@ mod update_config_tests {
             None,
             None,
             None,
-            None,
             Some(Decimal::percent(150)),
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -450,6 +669,7 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
             None,
             None,
@@ -459,9 +679,24 @@ mod update_config_tests {
             None,
             None,
             None,
-            None,
             Some(Decimal::percent(150)),
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -480,10 +715,10 @@ mod update_config_tests {
 
         let err = update_config_handler(
             deps.as_mut(),
+            mock_env(),
             info,
-            None,
             Some(vec![
-                FeeCollector {
+                FeeCollector::Address {
                     address: "fee-collector".to_string(),
                     allocation: Decimal::percent(5),
                 };
@@ -498,6 +733,22 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -506,4 +757,113 @@ mod update_config_tests {
             "Error: no more than 10 fee collectors are allowed"
         )
     }
+
+    #[test]
+    fn update_arbiter_with_no_value_should_not_change_value() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.arbiter, Some(Addr::unchecked(ARBITER)));
+    }
+
+    #[test]
+    fn update_arbiter_with_null_value_should_clear_arbiter() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(None),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.arbiter, None);
+    }
+
+    #[test]
+    fn update_config_msg_distinguishes_missing_and_null_arbiter() {
+        let ExecuteMsg::UpdateConfig { arbiter, .. } =
+            from_json::<ExecuteMsg>(br#"{"update_config":{}}"#).unwrap()
+        else {
+            panic!("expected an update config msg");
+        };
+
+        assert_eq!(arbiter, None);
+
+        let ExecuteMsg::UpdateConfig { arbiter, .. } =
+            from_json::<ExecuteMsg>(br#"{"update_config":{"arbiter":null}}"#).unwrap()
+        else {
+            panic!("expected an update config msg");
+        };
+
+        assert_eq!(arbiter, Some(None));
+    }
 }
@@ -1,8 +1,8 @@
 use crate::{
     error::ContractError,
-    helpers::validation::{assert_sender_is_executor, assert_swap_adjustment_value_is_valid},
+    helpers::validation::{assert_sender_has_role, assert_swap_adjustment_value_is_valid},
     state::swap_adjustments::update_swap_adjustment,
-    types::swap_adjustment_strategy::SwapAdjustmentStrategy,
+    types::{role::Role, swap_adjustment_strategy::SwapAdjustmentStrategy},
 };
 use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response};
 
@@ -13,7 +13,7 @@ pub fn update_swap_adjustment_handler(
     strategy: SwapAdjustmentStrategy,
     value: Decimal,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_executor(deps.storage, &env, &info.sender)?;
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Executor)?;
     assert_swap_adjustment_value_is_valid(&strategy, value)?;
     update_swap_adjustment(deps.storage, strategy.clone(), value, env.block.time)?;
 
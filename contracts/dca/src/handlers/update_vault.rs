@@ -1,33 +1,51 @@
 use crate::{
+    constants::FAIL_SILENTLY_REPLY_ID,
     error::ContractError,
     helpers::{
         time::get_next_target_time,
         validation::{
+            assert_bounty_is_not_completed, assert_compound_destinations_are_valid,
+            assert_description_is_no_longer_than_1000_characters,
             assert_destination_allocations_add_up_to_one,
             assert_destination_callback_addresses_are_valid,
+            assert_destination_gas_limits_are_valid,
+            assert_ibc_destinations_are_valid,
             assert_destinations_limit_is_not_breached,
-            assert_label_is_no_longer_than_100_characters,
-            assert_no_destination_allocations_are_zero,
-            assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_time_interval_is_valid,
+            assert_escrow_level_is_no_greater_than_100_percent,
+            assert_label_is_no_longer_than_100_characters, assert_metadata_hash_has_metadata_uri,
+            assert_metadata_uri_is_valid,
+            assert_no_destination_allocations_are_zero, assert_route_exists_for_denoms,
+            assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_tags_are_valid,
+            assert_time_interval_is_valid,
             assert_vault_is_not_cancelled, assert_weighted_scale_multiplier_is_no_more_than_10,
-            asset_sender_is_vault_owner,
+            assert_weighted_scale_swap_amount_bounds_are_valid,
+            assert_sender_can_update_bounty_field,
+            assert_sender_is_bounty_owner_or_update_delegate,
         },
     },
     state::{
+        config::get_config,
+        escrow_epochs::start_escrow_epoch,
         events::create_event,
         triggers::{delete_trigger, save_trigger},
         bounties::{get_bounty, update_bounty},
     },
     types::{
+        denom::Denom,
         destination::Destination,
         event::{EventBuilder, EventData},
         swap_adjustment_strategy::{SwapAdjustmentStrategy, SwapAdjustmentStrategyParams},
         time_interval::TimeInterval,
         trigger::{Trigger, TriggerConfiguration},
-        update::Update,
+        updatable_field::UpdatableField,
+        update::FieldUpdate,
     },
 };
-use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{
+    to_json_binary, Binary, Coin, Decimal, DepsMut, Env, HexBinary, MessageInfo, Response, SubMsg,
+    Uint128, WasmMsg,
+};
+use exchange::msg::ExecuteMsg;
 
 pub fn update_bounty_handler(
     deps: DepsMut,
@@ -36,16 +54,22 @@ pub fn update_bounty_handler(
     vault_id: Uint128,
     label: Option<String>,
     bounty_description: Option<String>,
+    tags: Option<Vec<String>>,
+    metadata_uri: Option<String>,
     destinations: Option<Vec<Destination>>,
     slippage_tolerance: Option<Decimal>,
     minimum_receive_amount: Option<Uint128>,
     time_interval: Option<TimeInterval>,
     swap_adjustment_strategy: Option<SwapAdjustmentStrategyParams>,
     swap_amount: Option<Uint128>,
+    target_denom: Option<Denom>,
+    route: Option<Binary>,
+    metadata_hash: Option<[u8; 32]>,
+    escrow_level: Option<Decimal>,
 ) -> Result<Response, ContractError> {
     let mut bounty = get_bounty(deps.storage, bounty_id)?;
 
-    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_sender_is_bounty_owner_or_update_delegate(deps.storage, &bounty, &info.sender)?;
     assert_bounty_is_not_cancelled(&bounty)?;
 
     let mut response = Response::default()
@@ -53,9 +77,129 @@ pub fn update_bounty_handler(
         .add_attribute("bounty_id", bounty.id)
         .add_attribute("owner", bounty.owner.clone());
 
-    let mut updates = Vec::<Update>::new();
+    let mut updates = Vec::<FieldUpdate>::new();
+
+    if target_denom.is_some() || route.is_some() {
+        if target_denom.is_some() {
+            assert_sender_can_update_bounty_field(
+                deps.storage,
+                &bounty,
+                &info.sender,
+                UpdatableField::TargetDenom,
+            )?;
+        }
+
+        if route.is_some() {
+            assert_sender_can_update_bounty_field(
+                deps.storage,
+                &bounty,
+                &info.sender,
+                UpdatableField::Route,
+            )?;
+        }
+
+        if !bounty.is_scheduled() || bounty.balance.amount < bounty.deposited_amount.amount {
+            return Err(ContractError::CustomError {
+                val: "cannot update target denom or route after the bounty has started".to_string(),
+            });
+        }
+
+        let new_target_denom = target_denom.unwrap_or(bounty.target_denom.clone());
+        let new_route = route.or(bounty.route.clone());
+
+        assert_route_exists_for_denoms(
+            deps.as_ref(),
+            bounty.get_swap_denom(),
+            new_target_denom.to_string(),
+            new_route.clone(),
+        )?;
+
+        if new_target_denom != bounty.target_denom {
+            assert_ibc_destinations_are_valid(
+                deps.as_ref(),
+                &bounty.destinations,
+                &new_target_denom,
+            )?;
+
+            if let Some(old_trigger @ TriggerConfiguration::Price { order_idx, .. }) =
+                bounty.trigger.clone()
+            {
+                let config = get_config(deps.storage)?;
+
+                for msg in [
+                    ExecuteMsg::RetractOrder {
+                        order_idx,
+                        denoms: bounty.denoms(),
+                    },
+                    ExecuteMsg::WithdrawOrder {
+                        order_idx,
+                        denoms: bounty.denoms(),
+                    },
+                ] {
+                    response = response.add_submessage(SubMsg::reply_on_error(
+                        WasmMsg::Execute {
+                            contract_addr: config.exchange_contract_address.to_string(),
+                            msg: to_json_binary(&msg)?,
+                            funds: vec![],
+                        },
+                        FAIL_SILENTLY_REPLY_ID,
+                    ));
+                }
+
+                delete_trigger(deps.storage, bounty.id)?;
+
+                let new_trigger = TriggerConfiguration::Time {
+                    target_time: env.block.time,
+                };
+
+                save_trigger(
+                    deps.storage,
+                    Trigger {
+                        bounty_id: bounty.id,
+                        configuration: new_trigger.clone(),
+                        retry_count: 0,
+                    },
+                )?;
+
+                updates.push(FieldUpdate::Trigger {
+                    old: old_trigger,
+                    new: new_trigger.clone(),
+                });
+
+                bounty.trigger = Some(new_trigger.clone());
+                response = response.add_attribute("trigger", format!("{:?}", new_trigger));
+            }
+
+            updates.push(FieldUpdate::TargetDenom {
+                old: bounty.target_denom.clone(),
+                new: new_target_denom.clone(),
+            });
+
+            bounty.received_amount = Coin::new(0, new_target_denom.clone());
+            bounty.escrowed_amount = Coin::new(0, new_target_denom.clone());
+            bounty.target_denom = new_target_denom;
+            response = response.add_attribute("target_denom", bounty.target_denom.to_string());
+        }
+
+        if new_route != bounty.route {
+            updates.push(FieldUpdate::Route {
+                old: bounty.route.clone(),
+                new: new_route.clone(),
+            });
+
+            bounty.route = new_route;
+            response = response.add_attribute("route", format!("{:?}", bounty.route));
+        }
+    }
 
     if let Some(swap_amount) = swap_amount {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::SwapAmount,
+        )?;
+
         if minimum_receive_amount.is_some() {
             return Err(ContractError::CustomError {
                 val: "cannot update swap amount and minimum receive amount at the same time."
@@ -74,10 +218,9 @@ pub fn update_bounty_handler(
             let updated_minimum_receive_amount =
                 Some(minimum_receive_amount * Decimal::from_ratio(swap_amount, bounty.swap_amount));
 
-            updates.push(Update {
-                field: "minimum_receive_amount".to_string(),
-                old_value: format!("{:?}", bounty.minimum_receive_amount),
-                new_value: format!("{:?}", updated_minimum_receive_amount),
+            updates.push(FieldUpdate::MinimumReceiveAmount {
+                old: bounty.minimum_receive_amount,
+                new: updated_minimum_receive_amount,
             });
 
             bounty.minimum_receive_amount = updated_minimum_receive_amount;
@@ -91,6 +234,8 @@ pub fn update_bounty_handler(
             base_receive_amount,
             multiplier,
             increase_only,
+            max_swap_amount,
+            min_swap_amount,
         }) = bounty.swap_adjustment_strategy
         {
             let updated_swap_adjustment_strategy = Some(SwapAdjustmentStrategy::WeightedScale {
@@ -98,12 +243,13 @@ pub fn update_bounty_handler(
                     * Decimal::from_ratio(swap_amount, bounty.swap_amount),
                 multiplier,
                 increase_only,
+                max_swap_amount,
+                min_swap_amount,
             });
 
-            updates.push(Update {
-                field: "swap_adjustment_strategy".to_string(),
-                old_value: format!("{:?}", bounty.swap_adjustment_strategy),
-                new_value: format!("{:?}", updated_swap_adjustment_strategy),
+            updates.push(FieldUpdate::SwapAdjustmentStrategy {
+                old: bounty.swap_adjustment_strategy.clone(),
+                new: updated_swap_adjustment_strategy.clone(),
             });
 
             bounty.swap_adjustment_strategy = updated_swap_adjustment_strategy;
@@ -113,10 +259,9 @@ pub fn update_bounty_handler(
             );
         }
 
-        updates.push(Update {
-            field: "swap_amount".to_string(),
-            old_value: format!("{}", bounty.swap_amount),
-            new_value: format!("{}", swap_amount),
+        updates.push(FieldUpdate::SwapAmount {
+            old: bounty.swap_amount,
+            new: swap_amount,
         });
 
         bounty.swap_amount = swap_amount;
@@ -124,36 +269,132 @@ pub fn update_bounty_handler(
     }
 
     if let Some(label) = label {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::Label,
+        )?;
+
         assert_label_is_no_longer_than_100_characters(&label)?;
 
-        updates.push(Update {
-            field: "label".to_string(),
-            old_value: bounty.label.unwrap_or_default(),
-            new_value: label.clone(),
+        updates.push(FieldUpdate::Label {
+            old: bounty.label.clone(),
+            new: Some(label.clone()),
         });
 
         bounty.label = Some(label.clone());
         response = response.add_attribute("label", label);
     }
 
+    if let Some(bounty_description) = bounty_description {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::Description,
+        )?;
+
+        assert_description_is_no_longer_than_1000_characters(&bounty_description)?;
+
+        updates.push(FieldUpdate::Description {
+            old: bounty.description.clone(),
+            new: Some(bounty_description.clone()),
+        });
+
+        bounty.description = Some(bounty_description.clone());
+        response = response.add_attribute("description", bounty_description);
+    }
+
+    if let Some(tags) = tags {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::Tags,
+        )?;
+
+        assert_tags_are_valid(&tags)?;
+
+        updates.push(FieldUpdate::Tags {
+            old: bounty.tags.clone(),
+            new: tags.clone(),
+        });
+
+        bounty.tags = tags.clone();
+        response = response.add_attribute("tags", tags.join(","));
+    }
+
+    assert_metadata_hash_has_metadata_uri(&metadata_uri, &metadata_hash)?;
+
+    if let Some(metadata_uri) = metadata_uri {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::MetadataUri,
+        )?;
+
+        assert_metadata_uri_is_valid(&metadata_uri)?;
+
+        updates.push(FieldUpdate::MetadataUri {
+            old: bounty.metadata_uri.clone(),
+            new: Some(metadata_uri.clone()),
+        });
+
+        if bounty.metadata_hash != metadata_hash {
+            updates.push(FieldUpdate::MetadataHash {
+                old: bounty.metadata_hash,
+                new: metadata_hash,
+            });
+        }
+
+        bounty.metadata_uri = Some(metadata_uri.clone());
+        bounty.metadata_hash = metadata_hash;
+        response = response.add_attribute("metadata_uri", metadata_uri);
+
+        if let Some(metadata_hash) = metadata_hash {
+            response =
+                response.add_attribute("metadata_hash", HexBinary::from(metadata_hash).to_hex());
+        }
+    }
+
     if let Some(mut destinations) = destinations {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::Destinations,
+        )?;
+
         if destinations.is_empty() {
             destinations.push(Destination {
                 allocation: Decimal::percent(100),
                 address: bounty.owner.clone(),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             });
         }
 
         assert_destinations_limit_is_not_breached(&destinations)?;
         assert_destination_callback_addresses_are_valid(deps.as_ref(), &destinations)?;
+        assert_destination_gas_limits_are_valid(&destinations)?;
+        assert_compound_destinations_are_valid(
+            deps.storage,
+            &destinations,
+            &env.contract.address,
+            &bounty.owner,
+            &bounty.target_denom,
+        )?;
+        assert_ibc_destinations_are_valid(deps.as_ref(), &destinations, &bounty.target_denom)?;
         assert_no_destination_allocations_are_zero(&destinations)?;
         assert_destination_allocations_add_up_to_one(&destinations)?;
 
-        updates.push(Update {
-            field: "destinations".to_string(),
-            old_value: format!("{:?}", bounty.destinations),
-            new_value: format!("{:?}", destinations),
+        updates.push(FieldUpdate::Destinations {
+            old: bounty.destinations.clone(),
+            new: destinations.clone(),
         });
 
         bounty.destinations = destinations.clone();
@@ -161,12 +402,18 @@ pub fn update_bounty_handler(
     }
 
     if let Some(slippage_tolerance) = slippage_tolerance {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::SlippageTolerance,
+        )?;
+
         assert_slippage_tolerance_is_less_than_or_equal_to_one(slippage_tolerance)?;
 
-        updates.push(Update {
-            field: "slippage_tolerance".to_string(),
-            old_value: format!("{}", bounty.slippage_tolerance),
-            new_value: format!("{}", slippage_tolerance),
+        updates.push(FieldUpdate::SlippageTolerance {
+            old: bounty.slippage_tolerance,
+            new: slippage_tolerance,
         });
 
         bounty.slippage_tolerance = slippage_tolerance;
@@ -174,10 +421,16 @@ pub fn update_bounty_handler(
     }
 
     if let Some(minimum_receive_amount) = minimum_receive_amount {
-        updates.push(Update {
-            field: "minimum_receive_amount".to_string(),
-            old_value: format!("{}", bounty.minimum_receive_amount.unwrap_or_default()),
-            new_value: format!("{}", minimum_receive_amount),
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::MinimumReceiveAmount,
+        )?;
+
+        updates.push(FieldUpdate::MinimumReceiveAmount {
+            old: bounty.minimum_receive_amount,
+            new: Some(minimum_receive_amount),
         });
 
         bounty.minimum_receive_amount = Some(minimum_receive_amount);
@@ -185,12 +438,18 @@ pub fn update_bounty_handler(
     }
 
     if let Some(time_interval) = time_interval {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::TimeInterval,
+        )?;
+
         assert_time_interval_is_valid(&time_interval)?;
 
-        updates.push(Update {
-            field: "time_interval".to_string(),
-            old_value: format!("{}", bounty.time_interval),
-            new_value: format!("{}", time_interval),
+        updates.push(FieldUpdate::TimeInterval {
+            old: bounty.time_interval.clone(),
+            new: time_interval.clone(),
         });
 
         bounty.time_interval = time_interval.clone();
@@ -212,39 +471,58 @@ pub fn update_bounty_handler(
                 Trigger {
                     bounty_id: bounty.id,
                     configuration: new_trigger.clone(),
+                    retry_count: 0,
                 },
             )?;
 
-            updates.push(Update {
-                field: "trigger".to_string(),
-                old_value: format!("{:?}", old_trigger),
-                new_value: format!("{:?}", new_trigger),
+            updates.push(FieldUpdate::Trigger {
+                old: old_trigger,
+                new: new_trigger.clone(),
             });
 
             response = response.add_attribute("trigger", format!("{:?}", new_trigger));
         }
     }
 
+    if swap_adjustment_strategy.is_some() {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::SwapAdjustmentStrategy,
+        )?;
+    }
+
     match swap_adjustment_strategy {
         Some(SwapAdjustmentStrategyParams::WeightedScale {
             base_receive_amount,
             multiplier,
             increase_only,
+            max_swap_amount,
+            min_swap_amount,
         }) => match bounty.swap_adjustment_strategy {
             Some(SwapAdjustmentStrategy::WeightedScale { .. }) => {
                 assert_weighted_scale_multiplier_is_no_more_than_10(multiplier)?;
-
-                updates.push(Update {
-                    field: "swap_adjustment_strategy".to_string(),
-                    old_value: format!("{:?}", bounty.swap_adjustment_strategy),
-                    new_value: format!("{:?}", swap_adjustment_strategy),
+                assert_weighted_scale_swap_amount_bounds_are_valid(
+                    max_swap_amount,
+                    min_swap_amount,
+                )?;
+
+                let updated_swap_adjustment_strategy =
+                    Some(SwapAdjustmentStrategy::WeightedScale {
+                        base_receive_amount,
+                        multiplier,
+                        increase_only,
+                        max_swap_amount,
+                        min_swap_amount,
+                    });
+
+                updates.push(FieldUpdate::SwapAdjustmentStrategy {
+                    old: bounty.swap_adjustment_strategy.clone(),
+                    new: updated_swap_adjustment_strategy.clone(),
                 });
 
-                bounty.swap_adjustment_strategy = Some(SwapAdjustmentStrategy::WeightedScale {
-                    base_receive_amount,
-                    multiplier,
-                    increase_only,
-                });
+                bounty.swap_adjustment_strategy = updated_swap_adjustment_strategy;
 
                 response = response.add_attribute(
                     "swap_adjustment_strategy",
@@ -271,11 +549,35 @@ pub fn update_bounty_handler(
         _ => {}
     }
 
+    if let Some(escrow_level) = escrow_level {
+        assert_sender_can_update_bounty_field(
+            deps.storage,
+            &bounty,
+            &info.sender,
+            UpdatableField::EscrowLevel,
+        )?;
+
+        assert_bounty_is_not_completed(&bounty)?;
+        assert_escrow_level_is_no_greater_than_100_percent(escrow_level)?;
+
+        if escrow_level != bounty.escrow_level {
+            start_escrow_epoch(deps.storage, &bounty, escrow_level, env.block.time)?;
+
+            updates.push(FieldUpdate::EscrowLevel {
+                old: bounty.escrow_level,
+                new: escrow_level,
+            });
+
+            bounty.escrow_level = escrow_level;
+            response = response.add_attribute("escrow_level", escrow_level.to_string());
+        }
+    }
+
     update_bounty(deps.storage, vault.clone())?;
 
     create_event(
         deps.storage,
-        EventBuilder::new(bounty.id, env.block, EventData::DcaVaultUpdated { updates }),
+        EventBuilder::new(bounty.id, env.block, EventData::BountyUpdated { updates }),
     )?;
 
     Ok(response)
@@ -288,13 +590,18 @@ mod update_bounty_tests {
         constants::{ONE, TEN},
         handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
         helpers::time::get_next_target_time,
-        state::{config::update_config, vaults::get_bounty},
+        state::{
+            config::update_config, escrow_epochs::get_escrow_epochs,
+            update_permissions::save_update_permissions,
+            vaults::get_bounty,
+        },
         tests::{
             helpers::{instantiate_contract, setup_bounty},
-            mocks::{ADMIN, USER},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_UUSK, USER},
         },
         types::{
             config::Config,
+            denom::Denom,
             destination::Destination,
             event::{Event, EventData},
             position_type::PositionType,
@@ -303,13 +610,14 @@ mod update_bounty_tests {
             },
             time_interval::TimeInterval,
             trigger::TriggerConfiguration,
-            update::Update,
+            updatable_field::UpdatableField,
+            update::FieldUpdate,
             bounty::{Bounty, BountyStatus},
         },
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, Decimal, Uint128,
+        Addr, Binary, Coin, Decimal, Uint128,
     };
 
     #[test]
@@ -327,11 +635,18 @@ mod update_bounty_tests {
             bounty.id,
             None,
             None,
+            None,
+            None,
+            None,
             Some(Decimal::percent(101)),
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -358,9 +673,16 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             Some(TimeInterval::Custom { seconds: 12 }),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -430,12 +752,110 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_delegate_updating_field_it_has_no_permission_for_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        save_update_permissions(
+            deps.as_mut().storage,
+            bounty.id,
+            &Addr::unchecked("bot"),
+            &vec![UpdatableField::SlippageTolerance],
+        )
+        .unwrap();
+
+        let err = update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bot", &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![Destination {
+                address: Addr::unchecked("bot"),
+                allocation: Decimal::percent(100),
+                msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
+            }]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
         assert_eq!(err.to_string(), "Unauthorized");
     }
 
+    #[test]
+    fn with_delegate_updating_permitted_field_succeeds() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        save_update_permissions(
+            deps.as_mut().storage,
+            bounty.id,
+            &Addr::unchecked("bot"),
+            &vec![UpdatableField::SlippageTolerance],
+        )
+        .unwrap();
+
+        update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bot", &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(3)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.slippage_tolerance, Decimal::percent(3));
+    }
+
     #[test]
     fn for_cancelled_bounty_fails() {
         let mut deps = mock_dependencies();
@@ -463,6 +883,13 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -480,6 +907,9 @@ mod update_bounty_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             };
             11
         ];
@@ -490,12 +920,19 @@ mod update_bounty_tests {
             mock_info(USER, &[]),
             bounty.id,
             None,
+            None,
+            None,
+            None,
             Some(destinations),
             None,
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -516,11 +953,17 @@ mod update_bounty_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -530,12 +973,19 @@ mod update_bounty_tests {
             mock_info(USER, &[]),
             bounty.id,
             None,
+            None,
+            None,
+            None,
             Some(destinations),
             None,
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -556,11 +1006,17 @@ mod update_bounty_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(51),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -570,12 +1026,19 @@ mod update_bounty_tests {
             mock_info(USER, &[]),
             bounty.id,
             None,
+            None,
+            None,
+            None,
             Some(destinations),
             None,
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -596,11 +1059,17 @@ mod update_bounty_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(100),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::zero(),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -610,12 +1079,19 @@ mod update_bounty_tests {
             mock_info(USER, &[]),
             bounty.id,
             None,
+            None,
+            None,
+            None,
             Some(destinations),
             None,
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -659,8 +1135,15 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             Some(new_swap_adjustment_strategy.clone()),
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -696,6 +1179,8 @@ mod update_bounty_tests {
             base_receive_amount: Uint128::new(18277),
             multiplier: Decimal::percent(213),
             increase_only: false,
+            max_swap_amount: None,
+            min_swap_amount: None,
         });
 
         let err = update_bounty_handler(
@@ -708,8 +1193,15 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             new_swap_adjustment_strategy.clone(),
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -732,6 +1224,8 @@ mod update_bounty_tests {
             base_receive_amount: Uint128::new(2732),
             multiplier: Decimal::percent(150),
             increase_only: false,
+            max_swap_amount: None,
+            min_swap_amount: None,
         });
 
         let err = update_bounty_handler(
@@ -744,8 +1238,15 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             strategy.clone(),
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -772,10 +1273,17 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             Some(Uint128::new(621837621)),
             None,
             None,
             Some(Uint128::new(3498473290)),
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -803,12 +1311,21 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             Some(SwapAdjustmentStrategyParams::WeightedScale {
                 base_receive_amount: Uint128::new(2732),
                 multiplier: Decimal::percent(150),
                 increase_only: false,
+                max_swap_amount: None,
+                min_swap_amount: None,
             }),
             Some(Uint128::new(436753262)),
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -845,7 +1362,14 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             Some(bounty.swap_amount * Uint128::new(2)),
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -869,6 +1393,8 @@ mod update_bounty_tests {
                     base_receive_amount: Uint128::new(2732),
                     multiplier: Decimal::percent(150),
                     increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
                 }),
                 ..Bounty::default()
             },
@@ -885,7 +1411,14 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             Some(bounty.swap_amount * Uint128::new(2)),
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -897,6 +1430,8 @@ mod update_bounty_tests {
                 base_receive_amount: Uint128::new(2732) * Uint128::new(2),
                 multiplier: Decimal::percent(150),
                 increase_only: false,
+                max_swap_amount: None,
+                min_swap_amount: None,
             })
         );
     }
@@ -913,6 +1448,8 @@ mod update_bounty_tests {
                     base_receive_amount: Uint128::new(2732),
                     multiplier: Decimal::percent(150),
                     increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
                 }),
                 minimum_receive_amount: Some(ONE),
                 ..Bounty::default()
@@ -932,8 +1469,15 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             Some(swap_amount),
-        )
+            None,
+            None,
+            None,
+            None,
+        )
         .unwrap();
 
         let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
@@ -942,6 +1486,59 @@ mod update_bounty_tests {
         assert_eq!(updated_bounty.swap_amount, swap_amount);
     }
 
+    #[test]
+    fn updating_weighted_scale_with_min_swap_amount_above_max_fails() {
+        let mut deps = mock_dependencies();
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            mock_env(),
+            Bounty {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: Uint128::new(2732),
+                    multiplier: Decimal::percent(150),
+                    increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        let err = update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(2732),
+                multiplier: Decimal::percent(150),
+                increase_only: false,
+                max_swap_amount: Some(Uint128::new(100)),
+                min_swap_amount: Some(Uint128::new(200)),
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: weighted scale min_swap_amount cannot be greater than max_swap_amount"
+        );
+    }
+
     #[test]
     fn updates_weighted_scale_swap_adjustment_strategy() {
         let mut deps = mock_dependencies();
@@ -954,6 +1551,8 @@ mod update_bounty_tests {
                     base_receive_amount: Uint128::new(2732),
                     multiplier: Decimal::percent(150),
                     increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
                 }),
                 ..Bounty::default()
             },
@@ -962,11 +1561,15 @@ mod update_bounty_tests {
         let base_receive_amount = Uint128::new(212831);
         let multiplier = Decimal::percent(300);
         let increase_only = true;
+        let max_swap_amount = Some(Uint128::new(5000));
+        let min_swap_amount = Some(Uint128::new(500));
 
         let strategy = Some(SwapAdjustmentStrategyParams::WeightedScale {
             base_receive_amount,
             multiplier,
             increase_only,
+            max_swap_amount,
+            min_swap_amount,
         });
 
         update_bounty_handler(
@@ -979,8 +1582,15 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             strategy,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -992,6 +1602,8 @@ mod update_bounty_tests {
                 base_receive_amount,
                 multiplier,
                 increase_only,
+                max_swap_amount,
+                min_swap_amount,
             })
         );
     }
@@ -1016,6 +1628,13 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1024,6 +1643,149 @@ mod update_bounty_tests {
         assert_eq!(updated_bounty.label, label);
     }
 
+    #[test]
+    fn updates_the_bounty_description_tags_and_metadata_uri() {
+        let mut deps = mock_dependencies();
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            bounty.id,
+            None,
+            Some("translate the docs".to_string()),
+            Some(vec!["docs".to_string()]),
+            Some("ipfs://bounty".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(
+            updated_bounty.description,
+            Some("translate the docs".to_string())
+        );
+        assert_eq!(updated_bounty.tags, vec!["docs".to_string()]);
+        assert_eq!(
+            updated_bounty.metadata_uri,
+            Some("ipfs://bounty".to_string())
+        );
+    }
+
+    #[test]
+    fn updates_the_bounty_metadata_uri_and_hash_together() {
+        let mut deps = mock_dependencies();
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+            Some("ipfs://bounty".to_string()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some([1; 32]),
+            None,
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(
+            updated_bounty.metadata_uri,
+            Some("ipfs://bounty".to_string())
+        );
+        assert_eq!(updated_bounty.metadata_hash, Some([1; 32]));
+    }
+
+    #[test]
+    fn with_metadata_hash_but_no_metadata_uri_fails() {
+        let mut deps = mock_dependencies();
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        let err = update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some([1; 32]),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Metadata hash can only be set together with a metadata uri"
+        );
+    }
+
+    #[test]
+    fn with_duplicate_tags_fails() {
+        let mut deps = mock_dependencies();
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        let err = update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            bounty.id,
+            None,
+            None,
+            Some(vec!["docs".to_string(), "docs".to_string()]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: tag docs is duplicated");
+    }
+
     #[test]
     fn updates_the_bounty_destinations() {
         let mut deps = mock_dependencies();
@@ -1035,11 +1797,17 @@ mod update_bounty_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -1049,12 +1817,19 @@ mod update_bounty_tests {
             mock_info(USER, &[]),
             bounty.id,
             None,
+            None,
+            None,
+            None,
             Some(destinations.clone()),
             None,
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1076,12 +1851,19 @@ mod update_bounty_tests {
             mock_info(USER, &[]),
             bounty.id,
             None,
+            None,
+            None,
+            None,
             Some(vec![]),
             None,
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1094,6 +1876,9 @@ mod update_bounty_tests {
                 address: bounty.owner,
                 allocation: Decimal::percent(100),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             }]
         );
     }
@@ -1113,11 +1898,18 @@ mod update_bounty_tests {
             bounty.id,
             None,
             None,
+            None,
+            None,
+            None,
             Some(slippage_tolerance),
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1126,6 +1918,58 @@ mod update_bounty_tests {
         assert_eq!(updated_bounty.slippage_tolerance, slippage_tolerance);
     }
 
+    #[test]
+    fn updates_escrow_level_and_starts_new_escrow_epoch() {
+        let mut deps = mock_dependencies();
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            mock_env(),
+            Bounty {
+                escrow_level: Decimal::percent(5),
+                escrowed_amount: Coin::new(100, DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(10)),
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+        let escrow_epochs = get_escrow_epochs(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.escrow_level, Decimal::percent(10));
+        assert_eq!(
+            escrow_epochs
+                .iter()
+                .map(|epoch| (epoch.escrow_level, epoch.escrowed_amount))
+                .collect::<Vec<_>>(),
+            vec![
+                (Decimal::percent(5), Uint128::new(100)),
+                (Decimal::percent(10), Uint128::zero()),
+            ]
+        );
+    }
+
     #[test]
     fn updates_minimum_receive_amount() {
         let mut deps = mock_dependencies();
@@ -1142,10 +1986,17 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             minimum_receive_amount,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1171,9 +2022,16 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             Some(time_interval.clone()),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1200,9 +2058,16 @@ mod update_bounty_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
             Some(time_interval.clone()),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1240,11 +2105,17 @@ mod update_bounty_tests {
                 address: Addr::unchecked("random-1"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("random-2"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
         let new_slippage_tolerance = Decimal::percent(12);
@@ -1256,12 +2127,19 @@ mod update_bounty_tests {
             mock_info(USER, &[]),
             bounty.id,
             Some(new_label.to_string()),
+            None,
+            None,
+            None,
             Some(new_destinations.clone()),
             Some(new_slippage_tolerance),
             Some(new_minimum_receive_amount),
             Some(new_time_interval.clone()),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1276,50 +2154,177 @@ mod update_bounty_tests {
                 resource_id: vault.id,
                 timestamp: env.block.time,
                 block_height: env.block.height,
-                data: EventData::DcaVaultUpdated {
+                data: EventData::BountyUpdated {
                     updates: vec![
-                        Update {
-                            field: "label".to_string(),
-                            old_value: bounty.label.unwrap(),
-                            new_value: new_label.to_string(),
+                        FieldUpdate::Label {
+                            old: bounty.label,
+                            new: Some(new_label.to_string()),
                         },
-                        Update {
-                            field: "destinations".to_string(),
-                            old_value: format!("{:?}", bounty.destinations),
-                            new_value: format!("{:?}", new_destinations),
+                        FieldUpdate::Destinations {
+                            old: bounty.destinations,
+                            new: new_destinations,
                         },
-                        Update {
-                            field: "slippage_tolerance".to_string(),
-                            old_value: bounty.slippage_tolerance.to_string(),
-                            new_value: new_slippage_tolerance.to_string(),
+                        FieldUpdate::SlippageTolerance {
+                            old: bounty.slippage_tolerance,
+                            new: new_slippage_tolerance,
                         },
-                        Update {
-                            field: "minimum_receive_amount".to_string(),
-                            old_value: bounty.minimum_receive_amount.unwrap_or_default().to_string(),
-                            new_value: new_minimum_receive_amount.to_string(),
+                        FieldUpdate::MinimumReceiveAmount {
+                            old: bounty.minimum_receive_amount,
+                            new: Some(new_minimum_receive_amount),
                         },
-                        Update {
-                            field: "time_interval".to_string(),
-                            old_value: bounty.time_interval.to_string(),
-                            new_value: new_time_interval.to_string(),
+                        FieldUpdate::TimeInterval {
+                            old: bounty.time_interval,
+                            new: new_time_interval.clone(),
                         },
-                        Update {
-                            field: "trigger".to_string(),
-                            old_value: format!("{:?}", bounty.trigger.unwrap()),
-                            new_value: format!(
-                                "{:?}",
-                                TriggerConfiguration::Time {
-                                    target_time: get_next_target_time(
-                                        env.block.time,
-                                        bounty.started_at.unwrap_or(env.block.time),
-                                        new_time_interval,
-                                    )
-                                }
-                            )
+                        FieldUpdate::Trigger {
+                            old: bounty.trigger.unwrap(),
+                            new: TriggerConfiguration::Time {
+                                target_time: get_next_target_time(
+                                    env.block.time,
+                                    bounty.started_at.unwrap_or(env.block.time),
+                                    new_time_interval,
+                                )
+                            }
                         }
                     ]
                 }
             }
         )
     }
+
+    #[test]
+    fn updating_target_denom_of_started_bounty_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), mock_env(), Bounty::default());
+
+        let err = update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Denom::new("uatom").unwrap()),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot update target denom or route after the bounty has started"
+        );
+    }
+
+    #[test]
+    fn updates_target_denom_of_scheduled_bounty() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            mock_env(),
+            Bounty {
+                status: BountyStatus::Scheduled,
+                ..Bounty::default()
+            },
+        );
+
+        let target_denom = Denom::new("uatom").unwrap();
+
+        update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(target_denom.clone()),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.target_denom, target_denom);
+        assert_eq!(
+            updated_bounty.received_amount.denom,
+            target_denom.to_string()
+        );
+        assert_eq!(
+            updated_bounty.escrowed_amount.denom,
+            target_denom.to_string()
+        );
+    }
+
+    #[test]
+    fn updates_route_of_scheduled_bounty() {
+        let mut deps = calc_mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            mock_env(),
+            Bounty {
+                status: BountyStatus::Scheduled,
+                ..Bounty::default()
+            },
+        );
+
+        let route = Binary::from(b"route".to_vec());
+
+        update_bounty_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            bounty.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(route.clone()),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(updated_bounty.route, Some(route));
+        assert_eq!(updated_bounty.target_denom, bounty.target_denom);
+    }
 }
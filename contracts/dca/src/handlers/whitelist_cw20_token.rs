@@ -0,0 +1,88 @@
+use crate::{
+    error::ContractError,
+    helpers::{address::normalize_address, validation::assert_sender_has_role},
+    state::{admin_actions::create_admin_action, cw20_whitelist::save_whitelisted_cw20_token},
+    types::{
+        admin_action::{AdminActionBuilder, AdminActionData},
+        role::Role,
+    },
+};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+
+pub fn whitelist_cw20_token_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token: Addr,
+) -> Result<Response, ContractError> {
+    let token = normalize_address(&token);
+
+    assert_sender_has_role(deps.storage, &env, &info.sender, Role::Admin)?;
+
+    deps.api.addr_validate(token.as_ref())?;
+
+    save_whitelisted_cw20_token(deps.storage, &token)?;
+
+    create_admin_action(
+        deps.storage,
+        AdminActionBuilder::new(
+            info.sender,
+            env.block,
+            AdminActionData::Cw20TokenWhitelisted {
+                token: token.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("whitelist_cw20_token", "true")
+        .add_attribute("token", token))
+}
+
+#[cfg(test)]
+mod whitelist_cw20_token_tests {
+    use super::*;
+    use crate::state::cw20_whitelist::is_cw20_token_whitelisted;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::{ADMIN, USER};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = whitelist_cw20_token_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            Addr::unchecked("token"),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn whitelists_token() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        whitelist_cw20_token_handler(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            Addr::unchecked("token"),
+        )
+        .unwrap();
+
+        assert!(is_cw20_token_whitelisted(
+            deps.as_ref().storage,
+            &Addr::unchecked("token")
+        ));
+    }
+}
@@ -0,0 +1,275 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{
+        assert_bounty_has_no_open_dispute, assert_bounty_is_active, assert_contract_is_not_paused,
+        assert_deposit_is_not_paused, asset_sender_is_bounty_owner,
+    },
+    state::{
+        bounties::{get_bounty, update_bounty},
+        events::create_event,
+    },
+    types::{
+        bounty::Bounty,
+        event::{EventBuilder, EventData},
+    },
+};
+use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128};
+use shared::cw20::into_transfer_msg;
+
+pub fn withdraw_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    assert_contract_is_not_paused(deps.storage)?;
+    assert_deposit_is_not_paused(deps.storage)?;
+
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    asset_sender_is_bounty_owner(bounty.owner.clone(), info.sender)?;
+    assert_bounty_is_active(&bounty)?;
+    assert_bounty_has_no_open_dispute(deps.storage, bounty.id)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::CustomError {
+            val: "withdrawal amount must be greater than zero".to_string(),
+        });
+    }
+
+    let remaining_balance =
+        bounty
+            .balance
+            .amount
+            .checked_sub(amount)
+            .map_err(|_| ContractError::CustomError {
+                val: format!(
+                    "withdrawal amount {} exceeds bounty balance {}",
+                    amount, bounty.balance.amount
+                ),
+            })?;
+
+    if remaining_balance < bounty.swap_amount {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "remaining balance {} must cover at least one swap amount of {}",
+                remaining_balance, bounty.swap_amount
+            ),
+        });
+    }
+
+    let withdrawn_amount = Coin::new(amount.into(), bounty.get_swap_denom());
+
+    update_bounty(
+        deps.storage,
+        Bounty {
+            balance: Coin::new(remaining_balance.into(), bounty.get_swap_denom()),
+            deposited_amount: Coin::new(
+                bounty.deposited_amount.amount.saturating_sub(amount).into(),
+                bounty.deposited_amount.denom.clone(),
+            ),
+            ..bounty.clone()
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyFundsWithdrawn {
+                amount: withdrawn_amount.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::new(into_transfer_msg(
+            deps.api,
+            bounty.owner.as_ref(),
+            withdrawn_amount.clone(),
+        )?))
+        .add_attribute("withdraw", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("withdrawn_amount", withdrawn_amount.to_string()))
+}
+
+#[cfg(test)]
+mod withdraw_tests {
+    use super::*;
+    use crate::constants::{ONE, TEN};
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::state::config::{get_config, update_config};
+    use crate::state::disputes::save_dispute;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI, USER};
+    use crate::types::config::Config;
+    use crate::types::dispute::Dispute;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::BankMsg;
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = withdraw_handler(
+            deps.as_mut(),
+            env,
+            mock_info("not-owner", &[]),
+            bounty.id,
+            ONE,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn when_contract_is_paused_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                paused: true,
+                ..config
+            },
+        )
+        .unwrap();
+
+        let err =
+            withdraw_handler(deps.as_mut(), env, mock_info(USER, &[]), bounty.id, ONE).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: contract is paused");
+    }
+
+    #[test]
+    fn when_deposits_are_paused_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                deposit_paused: true,
+                ..config
+            },
+        )
+        .unwrap();
+
+        let err =
+            withdraw_handler(deps.as_mut(), env, mock_info(USER, &[]), bounty.id, ONE).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: deposits are paused");
+    }
+
+    #[test]
+    fn with_open_dispute_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_dispute(
+            deps.as_mut().storage,
+            &Dispute {
+                bounty_id: bounty.id,
+                raised_by: bounty.owner.clone(),
+                reason: "work was not delivered".to_string(),
+                raised_at: env.block.time,
+            },
+        )
+        .unwrap();
+
+        let err =
+            withdraw_handler(deps.as_mut(), env, mock_info(USER, &[]), bounty.id, ONE).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!("Error: bounty with id {} has an open dispute", bounty.id)
+        );
+    }
+
+    #[test]
+    fn with_remaining_balance_less_than_swap_amount_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err =
+            withdraw_handler(deps.as_mut(), env, mock_info(USER, &[]), bounty.id, TEN).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: remaining balance 0 must cover at least one swap amount of {}",
+                bounty.swap_amount
+            )
+        );
+    }
+
+    #[test]
+    fn withdraws_funds_and_publishes_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let response = withdraw_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            bounty.id,
+            ONE,
+        )
+        .unwrap();
+
+        let updated_bounty = get_bounty(deps.as_ref().storage, bounty.id).unwrap();
+
+        assert_eq!(
+            updated_bounty.balance,
+            Coin::new((TEN - ONE).into(), DENOM_UKUJI)
+        );
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: USER.to_string(),
+            amount: vec![Coin::new(ONE.into(), DENOM_UKUJI)],
+        })));
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), bounty.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            events.last().unwrap().data,
+            EventData::BountyFundsWithdrawn {
+                amount: Coin::new(ONE.into(), DENOM_UKUJI),
+            }
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    error::ContractError,
+    state::{
+        applications::{delete_application, get_application},
+        bounties::get_bounty,
+        events::create_event,
+    },
+    types::event::{EventBuilder, EventData},
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn withdraw_application_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: Uint128,
+) -> Result<Response, ContractError> {
+    let bounty = get_bounty(deps.storage, bounty_id)?;
+
+    if get_application(deps.storage, bounty.id, &info.sender)?.is_none() {
+        return Err(ContractError::CustomError {
+            val: format!("{} has not applied to this bounty", info.sender),
+        });
+    }
+
+    delete_application(deps.storage, bounty.id, &info.sender);
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            bounty.id,
+            env.block,
+            EventData::BountyApplicationWithdrawn {
+                applicant: info.sender.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("withdraw_application", "true")
+        .add_attribute("bounty_id", bounty.id)
+        .add_attribute("applicant", info.sender))
+}
+
+#[cfg(test)]
+mod withdraw_application_tests {
+    use super::*;
+    use crate::handlers::apply_to_bounty::apply_to_bounty_handler;
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::ADMIN;
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn without_application_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        let err = withdraw_application_handler(
+            deps.as_mut(),
+            env,
+            mock_info("applicant", &[]),
+            bounty.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: applicant has not applied to this bounty"
+        );
+    }
+
+    #[test]
+    fn deletes_application() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        apply_to_bounty_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("applicant", &[]),
+            bounty.id,
+            "I can do this".to_string(),
+        )
+        .unwrap();
+
+        withdraw_application_handler(deps.as_mut(), env, mock_info("applicant", &[]), bounty.id)
+            .unwrap();
+
+        assert_eq!(
+            get_application(
+                deps.as_ref().storage,
+                bounty.id,
+                &Addr::unchecked("applicant")
+            )
+            .unwrap(),
+            None
+        );
+    }
+}
@@ -5,7 +5,7 @@ use crate::{error::ContractError, helpers::validation::assert_exactly_one_asset}
 use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
 use cosmos_sdk_proto::cosmos::staking::v1beta1::MsgDelegate;
 use cosmwasm_std::{Addr, Deps, Env, MessageInfo, Reply, Response, SubMsg, SubMsgResult};
-use shared::cw20::into_bank_msg;
+use shared::cw20::into_transfer_msg;
 
 pub fn z_delegate_handler(
     deps: Deps,
@@ -28,10 +28,10 @@ pub fn z_delegate_handler(
             ("validator", validator_address.to_string()),
         ])
         .add_submessages(vec![
-            SubMsg::new(into_bank_msg(
+            SubMsg::new(into_transfer_msg(
                 deps.api,
                 delegator_address.as_ref(),
-                vec![amount_to_delegate.clone()],
+                amount_to_delegate.clone(),
             )?),
             SubMsg::reply_always(
                 create_authz_exec_message(
@@ -0,0 +1,55 @@
+use cosmwasm_std::Addr;
+
+pub fn normalize_address(address: &Addr) -> Addr {
+    Addr::unchecked(address.as_str().to_lowercase())
+}
+
+pub fn get_address_prefix(address: &Addr) -> Option<&str> {
+    address
+        .as_str()
+        .rsplit_once('1')
+        .map(|(prefix, _)| prefix)
+        .filter(|prefix| !prefix.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_address_lowercases_address() {
+        assert_eq!(
+            normalize_address(&Addr::unchecked("KUJIRA1QWERTY")),
+            Addr::unchecked("kujira1qwerty")
+        );
+    }
+
+    #[test]
+    fn normalize_address_leaves_lowercase_address_unchanged() {
+        assert_eq!(
+            normalize_address(&Addr::unchecked("kujira1qwerty")),
+            Addr::unchecked("kujira1qwerty")
+        );
+    }
+
+    #[test]
+    fn get_address_prefix_returns_human_readable_part() {
+        assert_eq!(
+            get_address_prefix(&Addr::unchecked("kujira1qwerty")),
+            Some("kujira")
+        );
+    }
+
+    #[test]
+    fn get_address_prefix_uses_last_separator() {
+        assert_eq!(
+            get_address_prefix(&Addr::unchecked("osmo1abc1def")),
+            Some("osmo1abc")
+        );
+    }
+
+    #[test]
+    fn get_address_prefix_without_separator_returns_none() {
+        assert_eq!(get_address_prefix(&Addr::unchecked("admin")), None);
+    }
+}
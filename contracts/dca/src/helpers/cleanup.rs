@@ -0,0 +1,345 @@
+use crate::{
+    state::{
+        applications::{delete_application, get_applications},
+        assignees::{delete_assignee, get_assignees},
+        cache::POST_EXECUTION_ACTION_CACHE,
+        checklists::delete_checklist,
+        claims::clear_claimed_amounts,
+        delivered_amounts::clear_delivered_amount,
+        denom_balances::{delete_denom_balance, get_denom_balances},
+        destination_payouts::clear_destination_payouts,
+        disburse_escrow_tasks::delete_disburse_escrow_task,
+        disputes::delete_dispute,
+        escrow_epochs::clear_escrow_epochs,
+        escrow_tranches::delete_escrow_tranche_schedule,
+        triggers::delete_trigger,
+    },
+    types::bounty::Bounty,
+};
+use cosmwasm_std::{StdError, StdResult, Storage};
+
+pub fn clear_terminal_bounty_state(store: &mut dyn Storage, bounty: &Bounty) -> StdResult<()> {
+    delete_trigger(store, bounty.id)?;
+
+    for denom_balance in get_denom_balances(store, bounty.id)? {
+        if !denom_balance.balance.amount.is_zero() {
+            return Err(StdError::generic_err(format!(
+                "bounty with id {} still holds {:?} and must be paid out or refunded before cleanup",
+                bounty.id, denom_balance.balance
+            )));
+        }
+
+        delete_denom_balance(store, bounty.id, &denom_balance.balance.denom)?;
+    }
+
+    clear_destination_payouts(store, bounty.id);
+
+    if POST_EXECUTION_ACTION_CACHE
+        .may_load(store, bounty.id.into())?
+        .map_or(false, |cache| cache.is_empty())
+    {
+        POST_EXECUTION_ACTION_CACHE.remove(store, bounty.id.into());
+    }
+
+    if bounty.escrowed_amount.amount.is_zero() {
+        delete_disburse_escrow_task(store, bounty.id)?;
+        delete_escrow_tranche_schedule(store, bounty.id);
+        clear_escrow_epochs(store, bounty.id);
+        clear_delivered_amount(store, bounty.id);
+
+        // assignees and disputes are still needed to settle any remaining escrow
+        for assignee in get_assignees(store, bounty.id)? {
+            delete_assignee(store, bounty.id, &assignee.address);
+        }
+
+        for application in get_applications(store, bounty.id)? {
+            delete_application(store, bounty.id, &application.applicant);
+        }
+
+        delete_checklist(store, bounty.id);
+        delete_dispute(store, bounty.id);
+        clear_claimed_amounts(store, bounty.id)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod clear_terminal_bounty_state_tests {
+    use super::*;
+    use crate::{
+        constants::{ONE, TEN},
+        handlers::{
+            cancel_vault::cancel_bounty, execute_escrow_accept::execute_escrow_accept_handler,
+        },
+        state::{
+            applications::save_application, assignees::save_assignee, checklists::save_checklist,
+            claims::add_claimed_amount, delivered_amounts::add_delivered_amount,
+            denom_balances::save_denom_balance, destination_payouts::add_destination_payouts,
+            disburse_escrow_tasks::save_disburse_escrow_task, disputes::save_dispute,
+            escrow_epochs::start_escrow_epoch, escrow_tranches::save_escrow_tranche_schedule,
+            triggers::save_trigger,
+        },
+        tests::{
+            helpers::{instantiate_contract, setup_bounty},
+            mocks::{ADMIN, DENOM_UUSK, USER},
+        },
+        types::{
+            application::Application,
+            assignee::Assignee,
+            checklist::ChecklistItem,
+            denom_balance::DenomBalance,
+            dispute::Dispute,
+            escrow_tranche::{EscrowTranche, EscrowTrancheSchedule},
+            trigger::{Trigger, TriggerConfiguration},
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Coin, Decimal, Order, Response, Uint128,
+    };
+    use std::collections::VecDeque;
+
+    #[test]
+    fn leaves_no_residual_keys_for_the_bounty() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                trigger: None,
+                ..Bounty::default()
+            },
+        );
+
+        let keys_before = deps.storage.range(None, None, Order::Ascending).count();
+
+        save_trigger(
+            deps.as_mut().storage,
+            Trigger {
+                bounty_id: bounty.id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: env.block.time,
+                },
+                retry_count: 0,
+            },
+        )
+        .unwrap();
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(0, DENOM_UUSK),
+                deposited_amount: Coin::new(100, DENOM_UUSK),
+                swap_amount: Uint128::new(10),
+                route: None,
+            },
+        )
+        .unwrap();
+
+        add_destination_payouts(
+            deps.as_mut().storage,
+            bounty.id,
+            &bounty.destinations,
+            &[Uint128::new(10)],
+        )
+        .unwrap();
+
+        POST_EXECUTION_ACTION_CACHE
+            .save(deps.as_mut().storage, bounty.id.into(), &VecDeque::new())
+            .unwrap();
+
+        save_disburse_escrow_task(deps.as_mut().storage, bounty.id, env.block.time).unwrap();
+
+        save_escrow_tranche_schedule(
+            deps.as_mut().storage,
+            bounty.id,
+            &EscrowTrancheSchedule::new(vec![EscrowTranche {
+                allocation: Decimal::percent(100),
+                delay_seconds: 0,
+            }]),
+        )
+        .unwrap();
+
+        start_escrow_epoch(
+            deps.as_mut().storage,
+            &bounty,
+            Decimal::percent(5),
+            env.block.time,
+        )
+        .unwrap();
+
+        add_delivered_amount(deps.as_mut().storage, bounty.id, Uint128::new(10)).unwrap();
+
+        save_assignee(
+            deps.as_mut().storage,
+            bounty.id,
+            &Assignee {
+                address: Addr::unchecked("assignee"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: Some(Addr::unchecked("payout")),
+            },
+        )
+        .unwrap();
+
+        save_application(
+            deps.as_mut().storage,
+            bounty.id,
+            &Application {
+                applicant: Addr::unchecked("applicant"),
+                message: "I can do this".to_string(),
+                applied_at: env.block.time,
+            },
+        )
+        .unwrap();
+
+        save_checklist(
+            deps.as_mut().storage,
+            bounty.id,
+            vec![ChecklistItem {
+                description: "write tests".to_string(),
+                weight: Decimal::percent(100),
+                completed_at: None,
+            }],
+        )
+        .unwrap();
+
+        save_dispute(
+            deps.as_mut().storage,
+            &Dispute {
+                bounty_id: bounty.id,
+                raised_by: Addr::unchecked(USER),
+                reason: "work was not delivered".to_string(),
+                raised_at: env.block.time,
+            },
+        )
+        .unwrap();
+
+        add_claimed_amount(
+            deps.as_mut().storage,
+            bounty.id,
+            &Addr::unchecked("assignee"),
+            Uint128::new(10),
+        )
+        .unwrap();
+
+        clear_terminal_bounty_state(deps.as_mut().storage, &bounty).unwrap();
+
+        assert_eq!(
+            deps.storage.range(None, None, Order::Ascending).count(),
+            keys_before
+        );
+    }
+
+    #[test]
+    fn with_non_zero_denom_balance_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env, Bounty::default());
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(TEN.into(), "uatom"),
+                deposited_amount: Coin::new(TEN.into(), "uatom"),
+                swap_amount: ONE,
+                route: None,
+            },
+        )
+        .unwrap();
+
+        let err = clear_terminal_bounty_state(deps.as_mut().storage, &bounty).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("must be paid out or refunded before cleanup"));
+        assert_eq!(
+            get_denom_balances(deps.as_ref().storage, bounty.id)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn cancel_with_non_zero_denom_balance_transfers_it_before_cleanup() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(deps.as_mut(), env.clone(), Bounty::default());
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(TEN.into(), "uatom"),
+                deposited_amount: Coin::new(TEN.into(), "uatom"),
+                swap_amount: ONE,
+                route: None,
+            },
+        )
+        .unwrap();
+
+        cancel_bounty(deps.as_mut(), &env, bounty.clone(), None, None).unwrap();
+
+        assert!(get_denom_balances(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn escrow_accept_with_non_zero_denom_balance_transfers_it_before_cleanup() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrowed_amount: Coin::new(0, DENOM_UUSK),
+                ..Bounty::default()
+            },
+        );
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(TEN.into(), "uatom"),
+                deposited_amount: Coin::new(TEN.into(), "uatom"),
+                swap_amount: ONE,
+                route: None,
+            },
+        )
+        .unwrap();
+
+        execute_escrow_accept_handler(
+            deps.as_mut(),
+            env,
+            Response::new(),
+            bounty.clone(),
+            vec![],
+            None,
+        )
+        .unwrap();
+
+        assert!(get_denom_balances(deps.as_ref().storage, bounty.id)
+            .unwrap()
+            .is_empty());
+    }
+}
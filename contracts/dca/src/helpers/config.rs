@@ -0,0 +1,164 @@
+use crate::{
+    state::{
+        config::{get_config, update_config},
+        scheduled_config_updates::{
+            delete_scheduled_config_update, get_due_scheduled_config_updates,
+        },
+    },
+    types::config::Config,
+};
+use cosmwasm_std::{StdResult, Storage, Timestamp};
+
+pub fn get_effective_config(store: &dyn Storage, current_time: Timestamp) -> StdResult<Config> {
+    Ok(get_due_scheduled_config_updates(store, current_time)?
+        .into_iter()
+        .fold(get_config(store)?, |config, update| {
+            update.changes.apply_to(config)
+        }))
+}
+
+pub fn apply_due_scheduled_config_updates(
+    store: &mut dyn Storage,
+    current_time: Timestamp,
+) -> StdResult<Option<Config>> {
+    let due_updates = get_due_scheduled_config_updates(store, current_time)?;
+
+    if due_updates.is_empty() {
+        return Ok(None);
+    }
+
+    let mut config = get_config(store)?;
+
+    for update in due_updates {
+        config = update.changes.apply_to(config);
+        delete_scheduled_config_update(store, update.id)?;
+    }
+
+    update_config(store, config).map(Some)
+}
+
+#[cfg(test)]
+mod apply_due_scheduled_config_updates_tests {
+    use super::*;
+    use crate::{
+        state::{
+            config::update_config,
+            scheduled_config_updates::{
+                get_scheduled_config_updates, save_scheduled_config_update,
+            },
+        },
+        types::scheduled_config_update::ConfigChanges,
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Decimal,
+    };
+
+    #[test]
+    fn applies_updates_that_are_due() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        save_scheduled_config_update(
+            deps.as_mut().storage,
+            env.block.time,
+            env.block.time,
+            ConfigChanges {
+                automation_fee_percent: Some(Decimal::percent(2)),
+                ..ConfigChanges::default()
+            },
+        )
+        .unwrap();
+
+        apply_due_scheduled_config_updates(deps.as_mut().storage, env.block.time).unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.automation_fee_percent, Decimal::percent(2));
+        assert!(get_scheduled_config_updates(deps.as_ref().storage)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn does_not_apply_updates_that_are_not_due() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        save_scheduled_config_update(
+            deps.as_mut().storage,
+            env.block.time,
+            env.block.time.plus_seconds(10),
+            ConfigChanges {
+                automation_fee_percent: Some(Decimal::percent(2)),
+                ..ConfigChanges::default()
+            },
+        )
+        .unwrap();
+
+        apply_due_scheduled_config_updates(deps.as_mut().storage, env.block.time).unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            config.automation_fee_percent,
+            Config::default().automation_fee_percent
+        );
+        assert_eq!(
+            get_scheduled_config_updates(deps.as_ref().storage)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn resolves_effective_config_without_applying_updates() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        save_scheduled_config_update(
+            deps.as_mut().storage,
+            env.block.time,
+            env.block.time,
+            ConfigChanges {
+                automation_fee_percent: Some(Decimal::percent(2)),
+                ..ConfigChanges::default()
+            },
+        )
+        .unwrap();
+
+        save_scheduled_config_update(
+            deps.as_mut().storage,
+            env.block.time,
+            env.block.time.plus_seconds(10),
+            ConfigChanges {
+                automation_fee_percent: Some(Decimal::percent(3)),
+                ..ConfigChanges::default()
+            },
+        )
+        .unwrap();
+
+        let config = get_effective_config(deps.as_ref().storage, env.block.time).unwrap();
+
+        assert_eq!(config.automation_fee_percent, Decimal::percent(2));
+        assert_eq!(
+            get_config(deps.as_ref().storage)
+                .unwrap()
+                .automation_fee_percent,
+            Config::default().automation_fee_percent
+        );
+        assert_eq!(
+            get_scheduled_config_updates(deps.as_ref().storage)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
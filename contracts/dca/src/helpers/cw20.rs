@@ -0,0 +1,177 @@
+use crate::{
+    error::ContractError, msg::ExecuteMsg, state::cw20_whitelist::is_cw20_token_whitelisted,
+};
+use cosmwasm_std::{from_json, Deps, MessageInfo};
+use cw20::Cw20ReceiveMsg;
+use shared::cw20::from_cw20_receive_msg;
+
+pub fn parse_cw20_receive_msg(
+    deps: Deps,
+    info: MessageInfo,
+    receive_msg: Cw20ReceiveMsg,
+) -> Result<(MessageInfo, ExecuteMsg), ContractError> {
+    if !is_cw20_token_whitelisted(deps.storage, &info.sender) {
+        return Err(ContractError::Cw20TokenNotWhitelisted {
+            token: info.sender.to_string(),
+        });
+    }
+
+    if receive_msg.amount.is_zero() {
+        return Err(ContractError::ZeroAmountCw20Send {
+            token: info.sender.to_string(),
+        });
+    }
+
+    let msg = from_json::<ExecuteMsg>(&receive_msg.msg).map_err(|error| {
+        ContractError::UnknownCw20HookMessage {
+            reason: error.to_string(),
+        }
+    })?;
+
+    if let ExecuteMsg::Receive(_) = msg {
+        return Err(ContractError::NestedCw20Receive {});
+    }
+
+    Ok((from_cw20_receive_msg(&deps, info, receive_msg)?, msg))
+}
+
+#[cfg(test)]
+mod parse_cw20_receive_msg_tests {
+    use super::*;
+    use crate::state::cw20_whitelist::save_whitelisted_cw20_token;
+    use crate::tests::mocks::USER;
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_info},
+        to_json_binary, Addr, Binary, Coin, Uint128,
+    };
+
+    const TOKEN: &str = "token";
+
+    fn deposit_msg() -> Binary {
+        to_json_binary(&ExecuteMsg::Deposit {
+            address: Addr::unchecked(USER),
+            bounty_id: Uint128::one(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn with_token_not_on_whitelist_fails() {
+        let deps = mock_dependencies();
+
+        let err = parse_cw20_receive_msg(
+            deps.as_ref(),
+            mock_info(TOKEN, &[]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: Uint128::new(100),
+                msg: deposit_msg(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cw20 token token is not whitelisted"
+        );
+    }
+
+    #[test]
+    fn with_zero_amount_fails() {
+        let mut deps = mock_dependencies();
+
+        save_whitelisted_cw20_token(deps.as_mut().storage, &Addr::unchecked(TOKEN)).unwrap();
+
+        let err = parse_cw20_receive_msg(
+            deps.as_ref(),
+            mock_info(TOKEN, &[]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: Uint128::zero(),
+                msg: deposit_msg(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cw20 send of token token must have a non-zero amount"
+        );
+    }
+
+    #[test]
+    fn with_unknown_hook_message_fails() {
+        let mut deps = mock_dependencies();
+
+        save_whitelisted_cw20_token(deps.as_mut().storage, &Addr::unchecked(TOKEN)).unwrap();
+
+        let err = parse_cw20_receive_msg(
+            deps.as_ref(),
+            mock_info(TOKEN, &[]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: Uint128::new(100),
+                msg: to_json_binary(&"not_a_hook").unwrap(),
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::UnknownCw20HookMessage { .. }));
+    }
+
+    #[test]
+    fn with_nested_receive_fails() {
+        let mut deps = mock_dependencies();
+
+        save_whitelisted_cw20_token(deps.as_mut().storage, &Addr::unchecked(TOKEN)).unwrap();
+
+        let err = parse_cw20_receive_msg(
+            deps.as_ref(),
+            mock_info(TOKEN, &[]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: Uint128::new(100),
+                msg: to_json_binary(&ExecuteMsg::Receive(Cw20ReceiveMsg {
+                    sender: USER.to_string(),
+                    amount: Uint128::new(100),
+                    msg: deposit_msg(),
+                }))
+                .unwrap(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: nested cw20 receive messages are not allowed"
+        );
+    }
+
+    #[test]
+    fn with_whitelisted_token_returns_sender_and_funds() {
+        let mut deps = mock_dependencies();
+
+        save_whitelisted_cw20_token(deps.as_mut().storage, &Addr::unchecked(TOKEN)).unwrap();
+
+        let (info, msg) = parse_cw20_receive_msg(
+            deps.as_ref(),
+            mock_info(TOKEN, &[]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: Uint128::new(100),
+                msg: deposit_msg(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(info.sender, Addr::unchecked(USER));
+        assert_eq!(info.funds, vec![Coin::new(100, TOKEN)]);
+        assert_eq!(
+            msg,
+            ExecuteMsg::Deposit {
+                address: Addr::unchecked(USER),
+                bounty_id: Uint128::one(),
+            }
+        );
+    }
+}
@@ -1,56 +1,96 @@
-use super::math::checked_mul;
 use crate::{
-    constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
-    state::cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
-    types::vault::Vault,
+    constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, DEFAULT_IBC_TRANSFER_TIMEOUT_SECONDS},
+    state::{
+        assignees::get_assignees,
+        cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
+        delivered_amounts::{add_delivered_amount, get_delivered_amount},
+        destination_payouts::{add_destination_payouts, get_destination_payouts},
+        events::create_event,
+    },
+    types::{
+        destination::{Destination, DisbursementOutcome, IbcDestination},
+        event::{EventBuilder, EventData},
+        vault::Vault,
+    },
 };
-use cosmwasm_std::{Api, Coin, StdResult, Storage, SubMsg, Uint128};
-use shared::cw20::{into_bank_msg, into_execute_msg};
+use cosmwasm_std::{
+    Api, BlockInfo, Coin, CosmosMsg, Decimal, IbcMsg, IbcTimeout, StdResult, Storage, SubMsg,
+    Timestamp, Uint128, Uint256,
+};
+use shared::cw20::{into_execute_msg, into_transfer_msg};
 use std::collections::VecDeque;
 
 pub fn get_disbursement_messages(
     api: &dyn Api,
     store: &mut dyn Storage,
+    block_time: Timestamp,
     vault: &Vault,
     amount_to_disburse: Uint128,
+    outcome: &DisbursementOutcome,
 ) -> StdResult<VecDeque<SubMsg>> {
     let mut post_execution_action_caches = VecDeque::<PostExecutionActionCacheEntry>::new();
 
-    let messages = vault
-        .destinations
+    let surplus_amount = match vault.exact_receive_amount {
+        Some(exact_receive_amount) => amount_to_disburse.saturating_sub(
+            exact_receive_amount.saturating_sub(get_delivered_amount(store, vault.id)?),
+        ),
+        None => Uint128::zero(),
+    };
+
+    let amount_to_disburse = amount_to_disburse - surplus_amount;
+
+    if vault.exact_receive_amount.is_some() && !amount_to_disburse.is_zero() {
+        add_delivered_amount(store, vault.id, amount_to_disburse)?;
+    }
+
+    let destinations = get_disbursement_destinations(store, vault, outcome)?;
+
+    let amounts =
+        get_drift_corrected_amounts(store, vault.id, &destinations, amount_to_disburse)?;
+
+    add_destination_payouts(store, vault.id, &destinations, &amounts)?;
+
+    let mut messages = destinations
         .iter()
-        .flat_map(|destination| {
-            let allocation_amount = Coin::new(
-                checked_mul(amount_to_disburse, destination.allocation)
-                    .expect("amount to be distributed should be valid")
-                    .into(),
-                vault.target_denom.clone(),
-            );
+        .zip(amounts)
+        .flat_map(|(destination, amount)| {
+            let allocation_amount = Coin::new(amount.into(), vault.target_denom.clone());
 
             if allocation_amount.amount.gt(&Uint128::zero()) {
-                let msg = destination.msg.clone().map_or(
+                let mut msg = if let Some(ibc) = &destination.ibc {
                     SubMsg::reply_always(
-                        into_bank_msg(
-                            api,
-                            destination.address.as_ref(),
-                            vec![allocation_amount.clone()],
-                        )
-                        .expect("valid bank msg"),
+                        into_ibc_transfer_msg(ibc, allocation_amount.clone(), block_time),
                         AFTER_FAILED_AUTOMATION_REPLY_ID,
-                    ),
-                    |msg| {
+                    )
+                } else {
+                    destination.msg.clone().map_or(
                         SubMsg::reply_always(
-                            into_execute_msg(
+                            into_transfer_msg(
                                 api,
-                                destination.address.clone(),
-                                msg,
+                                destination.address.as_ref(),
                                 allocation_amount.clone(),
                             )
-                            .expect("valid wasm execute msg"),
+                            .expect("valid transfer msg"),
                             AFTER_FAILED_AUTOMATION_REPLY_ID,
-                        )
-                    },
-                );
+                        ),
+                        |msg| {
+                            SubMsg::reply_always(
+                                into_execute_msg(
+                                    api,
+                                    destination.address.clone(),
+                                    msg,
+                                    allocation_amount.clone(),
+                                )
+                                .expect("valid wasm execute msg"),
+                                AFTER_FAILED_AUTOMATION_REPLY_ID,
+                            )
+                        },
+                    )
+                };
+
+                if let Some(gas_limit) = destination.gas_limit {
+                    msg = msg.with_gas_limit(gas_limit);
+                }
 
                 post_execution_action_caches.push_back(PostExecutionActionCacheEntry {
                     msg: msg.clone(),
@@ -66,19 +106,258 @@ pub fn get_disbursement_messages(
 
     POST_EXECUTION_ACTION_CACHE.save(store, vault.id.into(), &post_execution_action_caches)?;
 
+    if surplus_amount > Uint128::zero() {
+        messages.push_back(SubMsg::new(into_transfer_msg(
+            api,
+            vault.owner.as_ref(),
+            Coin::new(surplus_amount.into(), vault.target_denom.clone()),
+        )?));
+    }
+
     Ok(messages)
 }
 
+pub fn reconcile_exact_receive_amount(
+    store: &mut dyn Storage,
+    block: &BlockInfo,
+    vault: &Vault,
+) -> StdResult<()> {
+    if let Some(exact_receive_amount) = vault.exact_receive_amount {
+        let delivered_amount = get_delivered_amount(store, vault.id)?;
+
+        create_event(
+            store,
+            EventBuilder::new(
+                vault.id,
+                block.clone(),
+                EventData::BountyExactReceiveReconciled {
+                    exact_receive_amount: Coin::new(
+                        exact_receive_amount.into(),
+                        vault.target_denom.clone(),
+                    ),
+                    delivered_amount: Coin::new(
+                        delivered_amount.into(),
+                        vault.target_denom.clone(),
+                    ),
+                },
+            ),
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn get_drift_corrected_amounts(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    destinations: &[Destination],
+    amount: Uint128,
+) -> StdResult<Vec<Uint128>> {
+    let paid = get_destination_payouts(store, bounty_id, destinations)?;
+
+    let total = paid.iter().fold(amount, |acc, paid| acc + paid);
+
+    let (targets, by_largest_remainder) = get_allocated_amounts(destinations, total)?;
+
+    let mut amounts = targets
+        .iter()
+        .zip(paid.iter())
+        .map(|(target, paid)| target.saturating_sub(*paid))
+        .collect::<Vec<Uint128>>();
+
+    let mut excess = amounts.iter().sum::<Uint128>().saturating_sub(amount);
+
+    while !excess.is_zero() {
+        for index in by_largest_remainder.iter().rev() {
+            if excess.is_zero() {
+                break;
+            }
+
+            if !amounts[*index].is_zero() {
+                amounts[*index] -= Uint128::one();
+                excess -= Uint128::one();
+            }
+        }
+    }
+
+    Ok(amounts)
+}
+
+/// Splits `total` by destination allocation, handing any remainder left by
+/// flooring each share to the destinations with the largest fractional
+/// shares, and returns the amounts along with that ordering.
+fn get_allocated_amounts(
+    destinations: &[Destination],
+    total: Uint128,
+) -> StdResult<(Vec<Uint128>, Vec<usize>)> {
+    let fractional = Uint256::from(Decimal::one().atomics());
+
+    let quotas = destinations
+        .iter()
+        .map(|destination| Uint256::from(total) * Uint256::from(destination.allocation.atomics()))
+        .collect::<Vec<Uint256>>();
+
+    let mut amounts = quotas
+        .iter()
+        .map(|quota| Uint128::try_from(*quota / fractional))
+        .collect::<Result<Vec<Uint128>, _>>()?;
+
+    let remainders = quotas
+        .iter()
+        .map(|quota| *quota % fractional)
+        .collect::<Vec<Uint256>>();
+
+    let mut by_largest_remainder = (0..destinations.len()).collect::<Vec<usize>>();
+    by_largest_remainder.sort_by(|a, b| remainders[*b].cmp(&remainders[*a]));
+
+    let mut leftover = total.saturating_sub(amounts.iter().sum());
+
+    for index in by_largest_remainder.iter().cycle() {
+        if leftover.is_zero() {
+            break;
+        }
+
+        amounts[*index] += Uint128::one();
+        leftover -= Uint128::one();
+    }
+
+    Ok((amounts, by_largest_remainder))
+}
+
+pub fn get_split_transfer_messages(
+    api: &dyn Api,
+    destinations: &[Destination],
+    amount: &Coin,
+) -> StdResult<Vec<SubMsg>> {
+    let mut messages = Vec::<SubMsg>::new();
+
+    let (shares, _) = get_allocated_amounts(destinations, amount.amount)?;
+
+    for (destination, share) in destinations.iter().zip(shares) {
+        if share.is_zero() {
+            continue;
+        }
+
+        messages.push(SubMsg::new(into_transfer_msg(
+            api,
+            destination.address.as_ref(),
+            Coin::new(share.into(), amount.denom.clone()),
+        )?));
+    }
+
+    Ok(messages)
+}
+
+pub fn into_ibc_transfer_msg(
+    ibc: &IbcDestination,
+    amount: Coin,
+    block_time: Timestamp,
+) -> CosmosMsg {
+    let timeout_seconds = ibc
+        .timeout_seconds
+        .unwrap_or(DEFAULT_IBC_TRANSFER_TIMEOUT_SECONDS);
+
+    CosmosMsg::Ibc(IbcMsg::Transfer {
+        channel_id: ibc.channel_id.clone(),
+        to_address: ibc.remote_address.clone(),
+        amount,
+        timeout: IbcTimeout::with_timestamp(block_time.plus_seconds(timeout_seconds)),
+    })
+}
+
+/// Returns who is paid for a disbursement. Once a bounty has weighted
+/// assignees they replace the owner's destinations entirely, including any
+/// outcome conditions, and are paid by weight on every outcome. The owner's
+/// destinations only apply while the bounty has no assignees.
+pub fn get_disbursement_destinations(
+    store: &dyn Storage,
+    vault: &Vault,
+    outcome: &DisbursementOutcome,
+) -> StdResult<Vec<Destination>> {
+    let assignees = get_assignees(store, vault.id)?;
+
+    let total_weight = assignees
+        .iter()
+        .fold(Decimal::zero(), |acc, assignee| acc + assignee.weight);
+
+    if total_weight.is_zero() {
+        return Ok(get_eligible_destinations(vault, outcome));
+    }
+
+    Ok(assignees
+        .into_iter()
+        .map(|assignee| Destination {
+            allocation: Decimal::from_ratio(assignee.weight.atomics(), total_weight.atomics()),
+            address: assignee.get_payout_address(),
+            msg: None,
+            condition: None,
+            ibc: None,
+            gas_limit: None,
+        })
+        .collect())
+}
+
+fn get_eligible_destinations(vault: &Vault, outcome: &DisbursementOutcome) -> Vec<Destination> {
+    let eligible_destinations = vault
+        .destinations
+        .iter()
+        .filter(|destination| destination.is_eligible_for(outcome))
+        .cloned()
+        .collect::<Vec<Destination>>();
+
+    let total_allocation = eligible_destinations
+        .iter()
+        .fold(Decimal::zero(), |acc, destination| acc + destination.allocation);
+
+    if total_allocation.is_zero() {
+        return vec![Destination {
+            allocation: Decimal::percent(100),
+            address: vault.owner.clone(),
+            msg: None,
+            condition: None,
+            ibc: None,
+            gas_limit: None,
+        }];
+    }
+
+    eligible_destinations
+        .into_iter()
+        .map(|destination| Destination {
+            allocation: Decimal::from_ratio(
+                destination.allocation.atomics(),
+                total_allocation.atomics(),
+            ),
+            ..destination
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod get_disbursement_messages_tests {
-    use super::get_disbursement_messages;
+    use super::{
+        get_disbursement_messages, get_drift_corrected_amounts, get_split_transfer_messages,
+        reconcile_exact_receive_amount,
+    };
     use crate::{
-        constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, ONE},
-        state::cache::POST_EXECUTION_ACTION_CACHE,
-        types::{destination::Destination, vault::Vault},
+        constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, DEFAULT_IBC_TRANSFER_TIMEOUT_SECONDS, ONE},
+        handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
+        state::{
+            assignees::save_assignee, cache::POST_EXECUTION_ACTION_CACHE,
+            destination_payouts::get_destination_payouts,
+        },
+        types::{
+            assignee::Assignee,
+            destination::{
+                Destination, DestinationCondition, DisbursementOutcome, IbcDestination,
+            },
+            event::EventData,
+            vault::Vault,
+        },
     };
     use cosmwasm_std::{
-        testing::mock_dependencies, to_json_binary, Addr, BankMsg, Coin, Decimal, SubMsg, WasmMsg,
+        testing::{mock_dependencies, mock_env},
+        to_json_binary, Addr, BankMsg, Coin, Decimal, IbcMsg, IbcTimeout, SubMsg, Uint128,
+        WasmMsg,
     };
 
     #[test]
@@ -89,6 +368,9 @@ mod get_disbursement_messages_tests {
             address: Addr::unchecked("test"),
             allocation: Decimal::percent(100),
             msg: None,
+            condition: None,
+            ibc: None,
+            gas_limit: None,
         };
 
         let vault = Vault {
@@ -96,9 +378,15 @@ mod get_disbursement_messages_tests {
             ..Vault::default()
         };
 
-        let messages =
-            get_disbursement_messages(&deps.api.clone(), deps.as_mut().storage, &vault, ONE)
-                .unwrap();
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
 
         assert!(messages.contains(&SubMsg::reply_always(
             BankMsg::Send {
@@ -109,6 +397,87 @@ mod get_disbursement_messages_tests {
         )))
     }
 
+    #[test]
+    fn applies_destination_gas_limit_to_callback_messages() {
+        let mut deps = mock_dependencies();
+
+        let destination = Destination {
+            address: Addr::unchecked("contract"),
+            allocation: Decimal::percent(100),
+            msg: Some(to_json_binary("callback").unwrap()),
+            condition: None,
+            ibc: None,
+            gas_limit: Some(250_000),
+        };
+
+        let vault = Vault {
+            destinations: vec![destination.clone()],
+            ..Vault::default()
+        };
+
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].gas_limit, Some(250_000));
+        assert_eq!(messages[0].id, AFTER_FAILED_AUTOMATION_REPLY_ID);
+    }
+
+    #[test]
+    fn generates_ibc_transfers_for_destinations_with_ibc_channel() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let destination = Destination {
+            address: Addr::unchecked("test"),
+            allocation: Decimal::percent(100),
+            msg: None,
+            condition: None,
+            ibc: Some(IbcDestination {
+                channel_id: "channel-0".to_string(),
+                remote_address: "osmo1remote".to_string(),
+                timeout_seconds: None,
+            }),
+            gas_limit: None,
+        };
+
+        let vault = Vault {
+            destinations: vec![destination],
+            ..Vault::default()
+        };
+
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            env.block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
+
+        assert!(messages.contains(&SubMsg::reply_always(
+            IbcMsg::Transfer {
+                channel_id: "channel-0".to_string(),
+                to_address: "osmo1remote".to_string(),
+                amount: Coin::new(ONE.into(), vault.target_denom),
+                timeout: IbcTimeout::with_timestamp(
+                    env.block
+                        .time
+                        .plus_seconds(DEFAULT_IBC_TRANSFER_TIMEOUT_SECONDS)
+                ),
+            },
+            AFTER_FAILED_AUTOMATION_REPLY_ID
+        )))
+    }
+
     #[test]
     fn saves_disbursement_messages_to_cache_queue() {
         let mut deps = mock_dependencies();
@@ -118,6 +487,9 @@ mod get_disbursement_messages_tests {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
                 msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
             Destination {
                 address: Addr::unchecked("contract"),
@@ -130,6 +502,9 @@ mod get_disbursement_messages_tests {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             },
         ];
 
@@ -138,7 +513,15 @@ mod get_disbursement_messages_tests {
             ..Vault::default()
         };
 
-        get_disbursement_messages(&deps.api.clone(), deps.as_mut().storage, &vault, ONE).unwrap();
+        get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
 
         let mut cache = POST_EXECUTION_ACTION_CACHE
             .load(deps.as_ref().storage, vault.id.into())
@@ -173,4 +556,477 @@ mod get_disbursement_messages_tests {
             )
         );
     }
+
+    #[test]
+    fn splits_disbursement_between_assignees_by_weight() {
+        let mut deps = mock_dependencies();
+
+        let vault = Vault::default();
+
+        save_assignee(
+            deps.as_mut().storage,
+            vault.id,
+            &Assignee {
+                address: Addr::unchecked("assignee-1"),
+                weight: Decimal::percent(30),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        save_assignee(
+            deps.as_mut().storage,
+            vault.id,
+            &Assignee {
+                address: Addr::unchecked("assignee-2"),
+                weight: Decimal::percent(90),
+                submission: None,
+                submitted_at: None,
+                payout_address: None,
+            },
+        )
+        .unwrap();
+
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert!(messages.contains(&SubMsg::reply_always(
+            BankMsg::Send {
+                to_address: "assignee-1".to_string(),
+                amount: vec![Coin::new(
+                    (ONE * Decimal::percent(25)).into(),
+                    vault.target_denom.clone()
+                )],
+            },
+            AFTER_FAILED_AUTOMATION_REPLY_ID
+        )));
+        assert!(messages.contains(&SubMsg::reply_always(
+            BankMsg::Send {
+                to_address: "assignee-2".to_string(),
+                amount: vec![Coin::new(
+                    (ONE * Decimal::percent(75)).into(),
+                    vault.target_denom
+                )],
+            },
+            AFTER_FAILED_AUTOMATION_REPLY_ID
+        )));
+    }
+
+    #[test]
+    fn returns_surplus_over_exact_receive_amount_to_owner() {
+        let mut deps = mock_dependencies();
+
+        let destination = Destination {
+            address: Addr::unchecked("test"),
+            allocation: Decimal::percent(100),
+            msg: None,
+            condition: None,
+            ibc: None,
+            gas_limit: None,
+        };
+
+        let vault = Vault {
+            destinations: vec![destination.clone()],
+            exact_receive_amount: Some(ONE - Uint128::new(100)),
+            ..Vault::default()
+        };
+
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
+
+        assert!(messages.contains(&SubMsg::reply_always(
+            BankMsg::Send {
+                to_address: destination.address.to_string(),
+                amount: vec![Coin::new(
+                    (ONE - Uint128::new(100)).into(),
+                    vault.target_denom.clone()
+                )],
+            },
+            AFTER_FAILED_AUTOMATION_REPLY_ID
+        )));
+        assert!(messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![Coin::new(100, vault.target_denom)],
+        })));
+    }
+
+    #[test]
+    fn caps_exact_receive_amount_against_cumulative_delivered_amount() {
+        let mut deps = mock_dependencies();
+
+        let destination = Destination {
+            address: Addr::unchecked("test"),
+            allocation: Decimal::percent(100),
+            msg: None,
+            condition: None,
+            ibc: None,
+            gas_limit: None,
+        };
+
+        let vault = Vault {
+            destinations: vec![destination.clone()],
+            exact_receive_amount: Some(ONE + ONE / Uint128::new(2)),
+            ..Vault::default()
+        };
+
+        get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
+
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages,
+            vec![
+                SubMsg::reply_always(
+                    BankMsg::Send {
+                        to_address: destination.address.to_string(),
+                        amount: vec![Coin::new(
+                            (ONE / Uint128::new(2)).into(),
+                            vault.target_denom.clone()
+                        )],
+                    },
+                    AFTER_FAILED_AUTOMATION_REPLY_ID
+                ),
+                SubMsg::new(BankMsg::Send {
+                    to_address: vault.owner.to_string(),
+                    amount: vec![Coin::new(
+                        (ONE / Uint128::new(2)).into(),
+                        vault.target_denom.clone()
+                    )],
+                }),
+            ]
+        );
+
+        reconcile_exact_receive_amount(deps.as_mut().storage, &mock_env().block, &vault).unwrap();
+
+        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            events.last().unwrap().data,
+            EventData::BountyExactReceiveReconciled {
+                exact_receive_amount: Coin::new(
+                    (ONE + ONE / Uint128::new(2)).into(),
+                    vault.target_denom.clone()
+                ),
+                delivered_amount: Coin::new(
+                    (ONE + ONE / Uint128::new(2)).into(),
+                    vault.target_denom
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn sends_assignee_share_to_payout_address_when_set() {
+        let mut deps = mock_dependencies();
+
+        let vault = Vault::default();
+
+        save_assignee(
+            deps.as_mut().storage,
+            vault.id,
+            &Assignee {
+                address: Addr::unchecked("hot-wallet"),
+                weight: Decimal::percent(100),
+                submission: None,
+                submitted_at: None,
+                payout_address: Some(Addr::unchecked("cold-wallet")),
+            },
+        )
+        .unwrap();
+
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages,
+            vec![SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: "cold-wallet".to_string(),
+                    amount: vec![Coin::new(ONE.into(), vault.target_denom)],
+                },
+                AFTER_FAILED_AUTOMATION_REPLY_ID
+            )]
+        );
+    }
+
+    #[test]
+    fn pays_assignees_instead_of_owner_destinations() {
+        let mut deps = mock_dependencies();
+
+        let vault = Vault {
+            destinations: vec![Destination {
+                address: Addr::unchecked("owner-destination"),
+                allocation: Decimal::percent(100),
+                msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
+            }],
+            ..Vault::default()
+        };
+
+        for (address, weight) in [("first-assignee", 3), ("second-assignee", 1)] {
+            save_assignee(
+                deps.as_mut().storage,
+                vault.id,
+                &Assignee {
+                    address: Addr::unchecked(address),
+                    weight: Decimal::percent(weight),
+                    submission: None,
+                    submitted_at: None,
+                    payout_address: None,
+                },
+            )
+            .unwrap();
+        }
+
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            Uint128::new(100),
+            &DisbursementOutcome::Skipped,
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages,
+            [("first-assignee", 75), ("second-assignee", 25)]
+                .into_iter()
+                .map(|(address, amount)| SubMsg::reply_always(
+                    BankMsg::Send {
+                        to_address: address.to_string(),
+                        amount: vec![Coin::new(amount, vault.target_denom.clone())],
+                    },
+                    AFTER_FAILED_AUTOMATION_REPLY_ID
+                ))
+                .collect::<Vec<SubMsg>>()
+        );
+    }
+
+    #[test]
+    fn excludes_ineligible_destinations_and_renormalises_allocations() {
+        let mut deps = mock_dependencies();
+
+        let on_success = Destination {
+            address: Addr::unchecked("on-success"),
+            allocation: Decimal::percent(25),
+            msg: None,
+            condition: Some(DestinationCondition::OnSuccess),
+            ibc: None,
+            gas_limit: None,
+        };
+
+        let on_skipped = Destination {
+            address: Addr::unchecked("on-skipped"),
+            allocation: Decimal::percent(75),
+            msg: None,
+            condition: Some(DestinationCondition::OnSkipped),
+            ibc: None,
+            gas_limit: None,
+        };
+
+        let vault = Vault {
+            destinations: vec![on_success.clone(), on_skipped],
+            ..Vault::default()
+        };
+
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages,
+            vec![SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: on_success.address.to_string(),
+                    amount: vec![Coin::new(ONE.into(), vault.target_denom.to_string())],
+                },
+                AFTER_FAILED_AUTOMATION_REPLY_ID
+            )]
+        );
+    }
+
+    #[test]
+    fn sends_to_owner_when_no_destinations_are_eligible() {
+        let mut deps = mock_dependencies();
+
+        let vault = Vault {
+            destinations: vec![Destination {
+                address: Addr::unchecked("min-received"),
+                allocation: Decimal::percent(100),
+                msg: None,
+                condition: Some(DestinationCondition::MinReceived {
+                    amount: ONE + ONE,
+                }),
+                ibc: None,
+                gas_limit: None,
+            }],
+            ..Vault::default()
+        };
+
+        let messages = get_disbursement_messages(
+            &deps.api.clone(),
+            deps.as_mut().storage,
+            mock_env().block.time,
+            &vault,
+            ONE,
+            &DisbursementOutcome::Succeeded { received: ONE },
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages,
+            vec![SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: vault.owner.to_string(),
+                    amount: vec![Coin::new(ONE.into(), vault.target_denom.to_string())],
+                },
+                AFTER_FAILED_AUTOMATION_REPLY_ID
+            )]
+        );
+    }
+
+    #[test]
+    fn corrects_allocation_drift_across_executions() {
+        let mut deps = mock_dependencies();
+
+        let destinations = ["a", "b", "c"]
+            .iter()
+            .map(|address| Destination {
+                address: Addr::unchecked(*address),
+                allocation: Decimal::from_ratio(1u128, 3u128),
+                msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
+            })
+            .collect::<Vec<Destination>>();
+
+        let vault = Vault {
+            destinations: destinations.clone(),
+            ..Vault::default()
+        };
+
+        let mut split = vec![];
+
+        for _ in 0..3 {
+            let amounts = get_drift_corrected_amounts(
+                deps.as_ref().storage,
+                vault.id,
+                &destinations,
+                Uint128::new(10),
+            )
+            .unwrap();
+
+            get_disbursement_messages(
+                &deps.api.clone(),
+                deps.as_mut().storage,
+                mock_env().block.time,
+                &vault,
+                Uint128::new(10),
+                &DisbursementOutcome::Succeeded {
+                    received: Uint128::new(10),
+                },
+            )
+            .unwrap();
+
+            split.push(amounts);
+        }
+
+        assert_eq!(
+            split,
+            vec![
+                vec![Uint128::new(4), Uint128::new(3), Uint128::new(3)],
+                vec![Uint128::new(3), Uint128::new(4), Uint128::new(3)],
+                vec![Uint128::new(3), Uint128::new(3), Uint128::new(4)],
+            ]
+        );
+
+        assert_eq!(
+            get_destination_payouts(deps.as_ref().storage, vault.id, &destinations).unwrap(),
+            vec![Uint128::new(10); 3]
+        );
+    }
+
+    #[test]
+    fn split_transfers_pay_out_the_whole_amount() {
+        let deps = mock_dependencies();
+
+        let destinations = ["first", "second", "third"]
+            .into_iter()
+            .map(|address| Destination {
+                address: Addr::unchecked(address),
+                allocation: Decimal::from_ratio(1u128, 3u128),
+                msg: None,
+                condition: None,
+                ibc: None,
+                gas_limit: None,
+            })
+            .collect::<Vec<Destination>>();
+
+        let messages =
+            get_split_transfer_messages(&deps.api, &destinations, &Coin::new(100, "ukuji"))
+                .unwrap();
+
+        assert_eq!(
+            messages,
+            [("first", 34), ("second", 33), ("third", 33)]
+                .into_iter()
+                .map(|(address, amount)| SubMsg::new(BankMsg::Send {
+                    to_address: address.to_string(),
+                    amount: vec![Coin::new(amount, "ukuji")],
+                }))
+                .collect::<Vec<SubMsg>>()
+        );
+    }
 }
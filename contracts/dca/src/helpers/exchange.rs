@@ -0,0 +1,137 @@
+use crate::state::exchanges::{
+    get_and_increment_exchange_cursor, get_exchanges, get_pair_exchange,
+};
+use cosmwasm_std::{Addr, StdResult, Storage};
+
+/// Picks the exchange to swap `swap_denom` into `target_denom` with. A pair
+/// preference wins outright, otherwise registered exchanges are cycled
+/// through in proportion to their weights, falling back to `default_exchange`
+/// when none are registered.
+pub fn get_exchange_for_pair(
+    store: &mut dyn Storage,
+    default_exchange: Addr,
+    swap_denom: String,
+    target_denom: String,
+) -> StdResult<Addr> {
+    if let Some(exchange) = get_pair_exchange(store, swap_denom, target_denom)? {
+        return Ok(exchange);
+    }
+
+    let exchanges = get_exchanges(store)?;
+    let total_weight = exchanges.iter().fold(0u64, |total, exchange| {
+        total.saturating_add(exchange.weight)
+    });
+
+    if total_weight == 0 {
+        return Ok(default_exchange);
+    }
+
+    let mut slot = get_and_increment_exchange_cursor(store)? % total_weight;
+
+    for exchange in exchanges {
+        if slot < exchange.weight {
+            return Ok(exchange.address);
+        }
+        slot -= exchange.weight;
+    }
+
+    Ok(default_exchange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        state::exchanges::{save_exchange, save_pair_exchange},
+        types::exchange::Exchange,
+    };
+    use cosmwasm_std::testing::mock_dependencies;
+
+    fn next_exchange(store: &mut dyn Storage) -> Addr {
+        get_exchange_for_pair(
+            store,
+            Addr::unchecked("default"),
+            "ukuji".to_string(),
+            "uusk".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn with_no_registered_exchanges_uses_default_exchange() {
+        let mut deps = mock_dependencies();
+
+        assert_eq!(
+            next_exchange(deps.as_mut().storage),
+            Addr::unchecked("default")
+        );
+    }
+
+    #[test]
+    fn with_pair_preference_uses_preferred_exchange() {
+        let mut deps = mock_dependencies();
+
+        save_exchange(
+            deps.as_mut().storage,
+            &Exchange {
+                address: Addr::unchecked("astroport"),
+                weight: 1,
+            },
+        )
+        .unwrap();
+
+        save_pair_exchange(
+            deps.as_mut().storage,
+            "ukuji".to_string(),
+            "uusk".to_string(),
+            &Addr::unchecked("osmosis"),
+        )
+        .unwrap();
+
+        for _ in 0..3 {
+            assert_eq!(
+                next_exchange(deps.as_mut().storage),
+                Addr::unchecked("osmosis")
+            );
+        }
+    }
+
+    #[test]
+    fn with_weighted_exchanges_cycles_in_proportion_to_weights() {
+        let mut deps = mock_dependencies();
+
+        save_exchange(
+            deps.as_mut().storage,
+            &Exchange {
+                address: Addr::unchecked("astroport"),
+                weight: 1,
+            },
+        )
+        .unwrap();
+
+        save_exchange(
+            deps.as_mut().storage,
+            &Exchange {
+                address: Addr::unchecked("osmosis"),
+                weight: 2,
+            },
+        )
+        .unwrap();
+
+        let picks = (0..6)
+            .map(|_| next_exchange(deps.as_mut().storage).to_string())
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            picks,
+            vec![
+                "astroport",
+                "osmosis",
+                "osmosis",
+                "astroport",
+                "osmosis",
+                "osmosis"
+            ]
+        );
+    }
+}
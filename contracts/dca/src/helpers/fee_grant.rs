@@ -0,0 +1,84 @@
+use cosmos_sdk_proto::{
+    cosmos::{
+        base::v1beta1::Coin as ProtoCoin,
+        feegrant::v1beta1::{AllowedMsgAllowance, BasicAllowance, MsgGrantAllowance},
+    },
+    Any,
+};
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg};
+use prost::Message;
+
+pub fn create_fee_grant_message(granter: Addr, grantee: Addr, spend_limit: Coin) -> CosmosMsg {
+    CosmosMsg::Stargate {
+        type_url: "/cosmos.feegrant.v1beta1.MsgGrantAllowance".to_string(),
+        value: Binary::from(
+            MsgGrantAllowance {
+                granter: granter.to_string(),
+                grantee: grantee.to_string(),
+                allowance: Some(Any {
+                    type_url: "/cosmos.feegrant.v1beta1.AllowedMsgAllowance".to_string(),
+                    value: AllowedMsgAllowance {
+                        allowance: Some(Any {
+                            type_url: "/cosmos.feegrant.v1beta1.BasicAllowance".to_string(),
+                            value: BasicAllowance {
+                                spend_limit: vec![ProtoCoin {
+                                    denom: spend_limit.denom,
+                                    amount: spend_limit.amount.to_string(),
+                                }],
+                                expiration: None,
+                            }
+                            .encode_to_vec(),
+                        }),
+                        allowed_messages: vec!["/cosmwasm.wasm.v1.MsgExecuteContract".to_string()],
+                    }
+                    .encode_to_vec(),
+                }),
+            }
+            .encode_to_vec(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod create_fee_grant_message_tests {
+    use super::*;
+
+    #[test]
+    fn grants_allowance_restricted_to_contract_executions() {
+        let msg = create_fee_grant_message(
+            Addr::unchecked("contract"),
+            Addr::unchecked("assignee"),
+            Coin::new(100000, "ukuji"),
+        );
+
+        let CosmosMsg::Stargate { type_url, value } = msg else {
+            panic!("expected stargate message");
+        };
+
+        assert_eq!(type_url, "/cosmos.feegrant.v1beta1.MsgGrantAllowance");
+
+        let grant = MsgGrantAllowance::decode(value.as_slice()).unwrap();
+
+        assert_eq!(grant.granter, "contract");
+        assert_eq!(grant.grantee, "assignee");
+
+        let allowance =
+            AllowedMsgAllowance::decode(grant.allowance.unwrap().value.as_slice()).unwrap();
+
+        assert_eq!(
+            allowance.allowed_messages,
+            vec!["/cosmwasm.wasm.v1.MsgExecuteContract".to_string()]
+        );
+
+        let basic_allowance =
+            BasicAllowance::decode(allowance.allowance.unwrap().value.as_slice()).unwrap();
+
+        assert_eq!(
+            basic_allowance.spend_limit,
+            vec![ProtoCoin {
+                denom: "ukuji".to_string(),
+                amount: "100000".to_string(),
+            }]
+        );
+    }
+}
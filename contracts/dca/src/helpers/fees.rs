@@ -1,8 +1,11 @@
-use super::math::checked_mul;
+use super::math::{checked_mul, checked_mul_with_rounding};
 use crate::{
-    state::config::get_config,
+    state::{
+        config::get_config, custom_fees::get_custom_swap_fee, fee_exemptions::is_fee_exempt,
+        referrals::add_referrer_earnings,
+    },
     types::{
-        fee_collector::FeeCollector,
+        config::Config, fee_collector::FeeCollector,
         performance_assessment_strategy::PerformanceAssessmentStrategy,
         swap_adjustment_strategy::SwapAdjustmentStrategy, vault::Vault,
     },
@@ -12,9 +15,10 @@ use cosmos_sdk_proto::{
     traits::Message,
 };
 use cosmwasm_std::{
-    Binary, Coin, CosmosMsg, Decimal, Deps, Env, StdResult, Storage, SubMsg, Uint128,
+    Addr, Binary, Coin, CosmosMsg, Decimal, Deps, Env, StdError, StdResult, Storage, SubMsg,
+    Uint128,
 };
-use shared::cw20::into_bank_msg;
+use shared::cw20::{into_burn_msg, into_transfer_msg};
 use std::cmp::min;
 
 pub fn get_fee_messages(
@@ -23,46 +27,77 @@ pub fn get_fee_messages(
     fee_amounts: Vec<Uint128>,
     denom: String,
     skip_community_pool: bool,
+    referrer: Option<Addr>,
+    executor: Option<Addr>,
 ) -> StdResult<Vec<SubMsg>> {
     let config = get_config(deps.storage)?;
 
+    let referral_fees = fee_amounts
+        .iter()
+        .map(|fee| {
+            referrer
+                .as_ref()
+                .map_or(Uint128::zero(), |_| get_referral_fee(&config, *fee))
+        })
+        .collect::<Vec<Uint128>>();
+
+    let fee_amounts = fee_amounts
+        .iter()
+        .zip(referral_fees.iter())
+        .map(|(fee, referral_fee)| fee - referral_fee)
+        .collect::<Vec<Uint128>>();
+
+    let executor_rewards = fee_amounts
+        .iter()
+        .map(|fee| {
+            executor
+                .as_ref()
+                .map_or(Uint128::zero(), |_| get_executor_reward(&config, *fee))
+        })
+        .collect::<Vec<Uint128>>();
+
+    let fee_amounts = fee_amounts
+        .iter()
+        .zip(executor_rewards.iter())
+        .map(|(fee, executor_reward)| fee - executor_reward)
+        .collect::<Vec<Uint128>>();
+
     let fee_collectors = config
         .fee_collectors
         .iter()
         .flat_map(|fee_collector| {
-            if skip_community_pool && fee_collector.address == "community_pool" {
+            if skip_community_pool && fee_collector.is_community_pool() {
                 return None;
             }
-            Some(FeeCollector {
-                address: fee_collector.address.clone(),
-                allocation: if skip_community_pool {
-                    let community_pool_allocation = config
-                        .fee_collectors
-                        .iter()
-                        .find(|fee_collector| fee_collector.address == "community_pool")
-                        .map_or(Decimal::zero(), |community_pool| community_pool.allocation);
-                    fee_collector.allocation / (Decimal::one() - community_pool_allocation)
-                } else {
-                    fee_collector.allocation
-                },
-            })
+            Some(fee_collector.with_allocation(if skip_community_pool {
+                let community_pool_allocation = config
+                    .fee_collectors
+                    .iter()
+                    .find(|fee_collector| fee_collector.is_community_pool())
+                    .map_or(Decimal::zero(), |community_pool| {
+                        community_pool.allocation()
+                    });
+                fee_collector.allocation() / (Decimal::one() - community_pool_allocation)
+            } else {
+                fee_collector.allocation()
+            }))
         })
         .collect::<Vec<FeeCollector>>();
 
-    Ok(fee_collectors
+    let mut messages = fee_collectors
         .iter()
         .flat_map(|fee_collector| {
             fee_amounts.iter().flat_map(|fee| {
                 let fee_allocation = Coin::new(
-                    checked_mul(*fee, fee_collector.allocation)
+                    checked_mul(*fee, fee_collector.allocation())
                         .expect("amount to be distributed should be valid")
                         .into(),
                     denom.clone(),
                 );
 
                 if fee_allocation.amount.gt(&Uint128::zero()) {
-                    match fee_collector.address.as_str() {
-                        "community_pool" => {
+                    match fee_collector {
+                        FeeCollector::Address { address, .. } if address == "community_pool" => {
                             if skip_community_pool {
                                 None
                             } else {
@@ -82,9 +117,12 @@ pub fn get_fee_messages(
                                 }))
                             }
                         }
-                        _ => Some(SubMsg::new(
-                            into_bank_msg(deps.api, &fee_collector.address, vec![fee_allocation])
-                                .expect("fee collection bank msg"),
+                        FeeCollector::Address { address, .. } => Some(SubMsg::new(
+                            into_transfer_msg(deps.api, address, fee_allocation)
+                                .expect("fee collection transfer msg"),
+                        )),
+                        FeeCollector::Burn { .. } => Some(SubMsg::new(
+                            into_burn_msg(deps.api, fee_allocation).expect("fee burn msg"),
                         )),
                     }
                 } else {
@@ -92,10 +130,53 @@ pub fn get_fee_messages(
                 }
             })
         })
-        .collect::<Vec<SubMsg>>())
+        .collect::<Vec<SubMsg>>();
+
+    let total_referral_fee = referral_fees.iter().sum::<Uint128>();
+
+    if let Some(referrer) = referrer {
+        if total_referral_fee > Uint128::zero() {
+            messages.push(SubMsg::new(into_transfer_msg(
+                deps.api,
+                referrer.as_ref(),
+                Coin::new(total_referral_fee.into(), denom.clone()),
+            )?));
+        }
+    }
+
+    let total_executor_reward = executor_rewards.iter().sum::<Uint128>();
+
+    if let Some(executor) = executor {
+        if total_executor_reward > Uint128::zero() {
+            messages.push(SubMsg::new(into_transfer_msg(
+                deps.api,
+                executor.as_ref(),
+                Coin::new(total_executor_reward.into(), denom),
+            )?));
+        }
+    }
+
+    Ok(messages)
+}
+
+pub fn get_fee_amount(config: &Config, amount: Uint128, fee_rate: Decimal) -> StdResult<Uint128> {
+    checked_mul_with_rounding(amount, fee_rate, &config.rounding_mode)
+        .map_err(|err| StdError::generic_err(format!("fee amount should be valid: {}", err)))
+}
+
+pub fn get_referral_fee(config: &Config, fee: Uint128) -> Uint128 {
+    checked_mul(fee, config.referral_fee_percent).expect("referral fee should be valid")
+}
+
+pub fn get_executor_reward(config: &Config, fee: Uint128) -> Uint128 {
+    checked_mul(fee, config.executor_reward_percent).expect("executor reward should be valid")
 }
 
 pub fn get_automation_fee_rate(storage: &dyn Storage, vault: &Vault) -> StdResult<Decimal> {
+    if is_fee_exempt(storage, &vault.owner) {
+        return Ok(Decimal::zero());
+    }
+
     let default_automation_fee_level = get_config(storage)?.automation_fee_percent;
 
     Ok(default_automation_fee_level.checked_mul(
@@ -110,8 +191,13 @@ pub fn get_automation_fee_rate(storage: &dyn Storage, vault: &Vault) -> StdResul
 
 pub fn get_swap_fee_rate(
     storage: &dyn Storage,
+    denom: &str,
     swap_adjustment_strategy: &Option<SwapAdjustmentStrategy>,
 ) -> StdResult<Decimal> {
+    if let Some(custom_swap_fee) = get_custom_swap_fee(storage, denom.to_string())? {
+        return Ok(custom_swap_fee);
+    }
+
     let config = get_config(storage)?;
 
     Ok(match swap_adjustment_strategy {
@@ -144,7 +230,7 @@ pub fn get_performance_fee(vault: &Vault, current_price: Decimal) -> StdResult<C
                     * (Decimal::one() / current_price);
 
                 Coin {
-                    denom: vault.target_denom.clone(),
+                    denom: vault.target_denom.to_string(),
                     amount: min(
                         vault.escrowed_amount.amount,
                         added_value_in_terms_of_receive_denom * strategy.performance_fee_rate(),
@@ -155,22 +241,64 @@ pub fn get_performance_fee(vault: &Vault, current_price: Decimal) -> StdResult<C
     ))
 }
 
+pub fn take_performance_fee(
+    storage: &mut dyn Storage,
+    config: &Config,
+    vault: &Vault,
+    escrow_to_release: &Coin,
+    current_price: Decimal,
+) -> StdResult<Coin> {
+    if escrow_to_release.amount.is_zero() {
+        return Ok(Coin::new(0, vault.target_denom.clone()));
+    }
+
+    let performance_fee = get_performance_fee(vault, current_price)?;
+    let performance_fee = Coin::new(
+        performance_fee
+            .amount
+            .multiply_ratio(escrow_to_release.amount, vault.escrowed_amount.amount)
+            .into(),
+        performance_fee.denom,
+    );
+
+    if let Some(referrer) = &vault.referrer {
+        add_referrer_earnings(
+            storage,
+            referrer,
+            &Coin::new(
+                get_referral_fee(config, performance_fee.amount).into(),
+                performance_fee.denom.clone(),
+            ),
+        )?;
+    }
+
+    Ok(performance_fee)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::get_swap_fee_rate;
+    use super::{get_automation_fee_rate, get_fee_amount, get_fee_messages, get_swap_fee_rate};
     use crate::{
         constants::{ONE, TEN},
         helpers::fees::get_performance_fee,
-        state::config::get_config,
-        tests::{helpers::instantiate_contract, mocks::ADMIN},
+        state::{
+            config::get_config, custom_fees::save_custom_swap_fee,
+            fee_exemptions::save_fee_exemption,
+        },
+        tests::{
+            helpers::{instantiate_contract, instantiate_contract_with_multiple_fee_collectors},
+            mocks::ADMIN,
+        },
         types::{
+            config::Config, destination::Destination, fee_collector::FeeCollector,
             performance_assessment_strategy::PerformanceAssessmentStrategy,
-            swap_adjustment_strategy::SwapAdjustmentStrategy, vault::Vault,
+            rounding_mode::RoundingMode, swap_adjustment_strategy::SwapAdjustmentStrategy,
+            vault::Vault,
         },
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Coin, Decimal, Uint128,
+        Addr, BankMsg, Binary, Coin, Decimal, SubMsg, Uint128,
     };
     use std::str::FromStr;
 
@@ -407,6 +535,8 @@ mod tests {
             base_receive_amount: ONE,
             multiplier: Decimal::one(),
             increase_only: false,
+            max_swap_amount: None,
+            min_swap_amount: None,
         };
 
         let vault = Vault {
@@ -414,11 +544,283 @@ mod tests {
             ..Default::default()
         };
 
-        let fee_rate =
-            get_swap_fee_rate(deps.as_ref().storage, &vault.swap_adjustment_strategy).unwrap();
+        let fee_rate = get_swap_fee_rate(
+            deps.as_ref().storage,
+            &vault.target_denom,
+            &vault.swap_adjustment_strategy,
+        )
+        .unwrap();
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
         assert_eq!(config.weighted_scale_swap_fee_percent, fee_rate);
     }
+
+    #[test]
+    fn denom_specific_fee_level_is_used() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let vault = Vault::default();
+
+        save_custom_swap_fee(
+            deps.as_mut().storage,
+            vault.target_denom.to_string(),
+            Decimal::percent(3),
+        )
+        .unwrap();
+
+        let fee_rate = get_swap_fee_rate(
+            deps.as_ref().storage,
+            &vault.target_denom,
+            &vault.swap_adjustment_strategy,
+        )
+        .unwrap();
+
+        assert_eq!(fee_rate, Decimal::percent(3));
+    }
+
+    #[test]
+    fn default_fee_level_is_used_for_denoms_without_custom_fee() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let vault = Vault::default();
+
+        save_custom_swap_fee(
+            deps.as_mut().storage,
+            "other_denom".to_string(),
+            Decimal::percent(3),
+        )
+        .unwrap();
+
+        let fee_rate = get_swap_fee_rate(
+            deps.as_ref().storage,
+            &vault.target_denom,
+            &vault.swap_adjustment_strategy,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(fee_rate, config.default_swap_fee_percent);
+    }
+
+    #[test]
+    fn fees_are_split_between_collectors_and_burned() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract_with_multiple_fee_collectors(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(ADMIN, &[]),
+            vec![
+                FeeCollector::Address {
+                    address: "fee_collector".to_string(),
+                    allocation: Decimal::percent(75),
+                },
+                FeeCollector::Burn {
+                    allocation: Decimal::percent(25),
+                },
+            ],
+        );
+
+        let messages = get_fee_messages(
+            deps.as_ref(),
+            env,
+            vec![Uint128::new(1000)],
+            "uusk".to_string(),
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: "fee_collector".to_string(),
+                    amount: vec![Coin::new(750, "uusk")],
+                }),
+                SubMsg::new(BankMsg::Burn {
+                    amount: vec![Coin::new(250, "uusk")],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn referral_share_of_fees_is_sent_to_referrer() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+        let FeeCollector::Address {
+            address: fee_collector,
+            ..
+        } = config.fee_collectors[0].clone()
+        else {
+            panic!("expected an address fee collector");
+        };
+
+        let messages = get_fee_messages(
+            deps.as_ref(),
+            env,
+            vec![Uint128::new(1000)],
+            "uusk".to_string(),
+            false,
+            Some(Addr::unchecked("referrer")),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: fee_collector,
+                    amount: vec![Coin::new(900, "uusk")],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: "referrer".to_string(),
+                    amount: vec![Coin::new(100, "uusk")],
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn executor_share_of_fees_is_sent_to_executor() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+        let FeeCollector::Address {
+            address: fee_collector,
+            ..
+        } = config.fee_collectors[0].clone()
+        else {
+            panic!("expected an address fee collector");
+        };
+
+        let messages = get_fee_messages(
+            deps.as_ref(),
+            env,
+            vec![Uint128::new(1000)],
+            "uusk".to_string(),
+            false,
+            Some(Addr::unchecked("referrer")),
+            Some(Addr::unchecked("keeper")),
+        )
+        .unwrap();
+
+        assert_eq!(
+            messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: fee_collector,
+                    amount: vec![Coin::new(810, "uusk")],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: "referrer".to_string(),
+                    amount: vec![Coin::new(100, "uusk")],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: "keeper".to_string(),
+                    amount: vec![Coin::new(90, "uusk")],
+                }),
+            ]
+        );
+    }
+
+    fn vault_with_msg_destination() -> Vault {
+        Vault {
+            destinations: vec![Destination {
+                allocation: Decimal::percent(100),
+                address: Addr::unchecked("contract"),
+                msg: Some(Binary::default()),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
+            }],
+            ..Vault::default()
+        }
+    }
+
+    #[test]
+    fn automation_fee_rate_is_charged_for_msg_destinations() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract_with_multiple_fee_collectors(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vec![FeeCollector::Address {
+                address: "fee_collector".to_string(),
+                allocation: Decimal::percent(100),
+            }],
+        );
+
+        assert_eq!(
+            get_automation_fee_rate(deps.as_ref().storage, &vault_with_msg_destination())
+                .unwrap(),
+            Decimal::from_str("0.0075").unwrap()
+        );
+    }
+
+    #[test]
+    fn automation_fee_rate_is_zero_for_exempt_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract_with_multiple_fee_collectors(
+            deps.as_mut(),
+            env,
+            mock_info(ADMIN, &[]),
+            vec![FeeCollector::Address {
+                address: "fee_collector".to_string(),
+                allocation: Decimal::percent(100),
+            }],
+        );
+
+        let vault = vault_with_msg_destination();
+
+        save_fee_exemption(deps.as_mut().storage, &vault.owner).unwrap();
+
+        assert_eq!(
+            get_automation_fee_rate(deps.as_ref().storage, &vault).unwrap(),
+            Decimal::zero()
+        );
+    }
+
+    #[test]
+    fn fee_amount_uses_configured_rounding_mode() {
+        let amount = Uint128::new(110);
+        let fee_rate = Decimal::percent(5);
+
+        for (rounding_mode, expected) in [
+            (RoundingMode::FloorToProtocol, 5),
+            (RoundingMode::FloorToUser, 6),
+            (RoundingMode::Bankers, 6),
+        ] {
+            let config = Config {
+                rounding_mode,
+                ..Config::default()
+            };
+
+            assert_eq!(
+                get_fee_amount(&config, amount, fee_rate).unwrap(),
+                Uint128::new(expected)
+            );
+        }
+    }
 }
@@ -1,4 +1,5 @@
-use cosmwasm_std::{CheckedMultiplyRatioError, Decimal, Uint128};
+use crate::types::rounding_mode::RoundingMode;
+use cosmwasm_std::{CheckedMultiplyRatioError, Decimal, Uint128, Uint256};
 
 pub fn checked_mul(a: Uint128, b: Decimal) -> Result<Uint128, CheckedMultiplyRatioError> {
     a.checked_multiply_ratio(
@@ -7,6 +8,53 @@ pub fn checked_mul(a: Uint128, b: Decimal) -> Result<Uint128, CheckedMultiplyRat
     )
 }
 
+/// Multiplies `a` by `b` where the result is the protocol's share of `a`,
+/// resolving any fractional remainder according to the given rounding mode.
+pub fn checked_mul_with_rounding(
+    a: Uint128,
+    b: Decimal,
+    rounding_mode: &RoundingMode,
+) -> Result<Uint128, CheckedMultiplyRatioError> {
+    let floor = checked_mul(a, b)?;
+
+    let denominator = Uint256::from(Uint128::new(10).checked_pow(b.decimal_places()).unwrap());
+    let remainder = a.full_mul(b.atomics()) % denominator;
+
+    let round_up = match rounding_mode {
+        RoundingMode::FloorToProtocol => false,
+        RoundingMode::FloorToUser => !remainder.is_zero(),
+        RoundingMode::Bankers => {
+            let twice_remainder = remainder + remainder;
+            twice_remainder > denominator
+                || (twice_remainder == denominator && floor.u128() % 2 == 1)
+        }
+    };
+
+    if round_up {
+        floor
+            .checked_add(Uint128::one())
+            .map_err(|_| CheckedMultiplyRatioError::Overflow)
+    } else {
+        Ok(floor)
+    }
+}
+
+pub fn median(mut values: Vec<Decimal>) -> Option<Decimal> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort();
+
+    let middle = values.len() / 2;
+
+    Some(if values.len() % 2 == 0 {
+        (values[middle - 1] + values[middle]) / Uint128::new(2)
+    } else {
+        values[middle]
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,4 +152,142 @@ mod tests {
             Uint128::new(1500000000000000000000000000000000000)
         );
     }
+
+    #[test]
+    fn rounding_an_exact_product_is_the_same_in_every_mode() {
+        for rounding_mode in [
+            RoundingMode::FloorToProtocol,
+            RoundingMode::FloorToUser,
+            RoundingMode::Bankers,
+        ] {
+            assert_eq!(
+                checked_mul_with_rounding(Uint128::new(100), Decimal::percent(5), &rounding_mode)
+                    .unwrap(),
+                Uint128::new(5)
+            );
+        }
+    }
+
+    #[test]
+    fn rounding_zero_is_zero_in_every_mode() {
+        for rounding_mode in [
+            RoundingMode::FloorToProtocol,
+            RoundingMode::FloorToUser,
+            RoundingMode::Bankers,
+        ] {
+            assert_eq!(
+                checked_mul_with_rounding(Uint128::zero(), Decimal::percent(5), &rounding_mode)
+                    .unwrap(),
+                Uint128::zero()
+            );
+            assert_eq!(
+                checked_mul_with_rounding(Uint128::new(100), Decimal::zero(), &rounding_mode)
+                    .unwrap(),
+                Uint128::zero()
+            );
+        }
+    }
+
+    #[test]
+    fn floor_to_protocol_rounds_the_protocol_share_down() {
+        let rounding_mode = RoundingMode::FloorToProtocol;
+
+        for (amount, expected) in [(101, 5), (110, 5), (119, 5), (130, 6), (150, 7)] {
+            assert_eq!(
+                checked_mul_with_rounding(
+                    Uint128::new(amount),
+                    Decimal::percent(5),
+                    &rounding_mode
+                )
+                .unwrap(),
+                Uint128::new(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn floor_to_user_rounds_the_protocol_share_up() {
+        let rounding_mode = RoundingMode::FloorToUser;
+
+        for (amount, expected) in [(101, 6), (110, 6), (119, 6), (130, 7), (150, 8)] {
+            assert_eq!(
+                checked_mul_with_rounding(
+                    Uint128::new(amount),
+                    Decimal::percent(5),
+                    &rounding_mode
+                )
+                .unwrap(),
+                Uint128::new(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn bankers_rounds_half_to_even() {
+        let rounding_mode = RoundingMode::Bankers;
+
+        for (amount, expected) in [(101, 5), (110, 6), (119, 6), (130, 6), (150, 8)] {
+            assert_eq!(
+                checked_mul_with_rounding(
+                    Uint128::new(amount),
+                    Decimal::percent(5),
+                    &rounding_mode
+                )
+                .unwrap(),
+                Uint128::new(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn floor_to_protocol_matches_checked_mul() {
+        let a = Uint128::new(11);
+        let b = Decimal::from_str("1.5").unwrap();
+
+        assert_eq!(
+            checked_mul_with_rounding(a, b, &RoundingMode::FloorToProtocol).unwrap(),
+            checked_mul(a, b).unwrap()
+        );
+    }
+
+    #[test]
+    fn rounding_up_at_max_int_should_fail() {
+        let result = checked_mul_with_rounding(
+            Uint128::MAX,
+            Decimal::from_str("1.5").unwrap(),
+            &RoundingMode::FloorToUser,
+        );
+
+        assert_eq!(result.unwrap_err(), CheckedMultiplyRatioError::Overflow);
+    }
+
+    #[test]
+    fn median_of_odd_number_of_values_is_middle_value() {
+        assert_eq!(
+            median(vec![
+                Decimal::percent(120),
+                Decimal::percent(80),
+                Decimal::percent(100)
+            ]),
+            Some(Decimal::percent(100))
+        );
+    }
+
+    #[test]
+    fn median_of_even_number_of_values_is_average_of_middle_values() {
+        assert_eq!(
+            median(vec![
+                Decimal::percent(90),
+                Decimal::percent(300),
+                Decimal::percent(80),
+                Decimal::percent(110)
+            ]),
+            Some(Decimal::percent(100))
+        );
+    }
+
+    #[test]
+    fn median_of_no_values_is_none() {
+        assert_eq!(median(vec![]), None);
+    }
 }
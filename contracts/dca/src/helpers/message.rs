@@ -20,3 +20,12 @@ pub fn get_attribute_in_event(
 
     Ok(attribute.value.clone())
 }
+
+pub fn find_attribute_in_wasm_events(events: &[Event], attribute_keys: &[&str]) -> Option<String> {
+    events
+        .iter()
+        .filter(|event| event.ty == "wasm" || event.ty.starts_with("wasm-"))
+        .flat_map(|event| event.attributes.iter())
+        .find(|attribute| attribute_keys.contains(&attribute.key.as_str()))
+        .map(|attribute| attribute.value.clone())
+}
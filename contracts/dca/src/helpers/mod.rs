@@ -1,8 +1,15 @@
+pub mod address;
 pub mod authz;
+pub mod cleanup;
+pub mod config;
+pub mod cw20;
 pub mod disbursement;
+pub mod exchange;
+pub mod fee_grant;
 pub mod fees;
 pub mod math;
 pub mod message;
+pub mod pagination;
 pub mod price;
 pub mod state;
 pub mod time;
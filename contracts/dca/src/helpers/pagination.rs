@@ -0,0 +1,141 @@
+use crate::constants::MAX_PAGE_LIMIT;
+use crate::state::config::get_config;
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::{Bound, PrimaryKey};
+
+pub struct Page<K> {
+    pub start_after: Option<K>,
+    pub limit: usize,
+    pub order: Order,
+}
+
+impl<K: Clone> Page<K> {
+    pub fn new(
+        store: &dyn Storage,
+        start_after: Option<K>,
+        limit: Option<u16>,
+        reverse: Option<bool>,
+    ) -> StdResult<Self> {
+        Ok(Self {
+            start_after,
+            limit: get_page_limit(store, limit)?,
+            order: get_page_order(reverse),
+        })
+    }
+
+    /// Returns the (min, max) range bounds, placing the exclusive `start_after`
+    /// cursor on whichever side the page is iterating away from.
+    pub fn bounds<'a, B>(&self) -> (Option<Bound<'a, B>>, Option<Bound<'a, B>>)
+    where
+        B: PrimaryKey<'a>,
+        K: Into<B>,
+    {
+        let start_after = self.start_after.clone().map(Bound::exclusive);
+
+        match self.order {
+            Order::Ascending => (start_after, None),
+            Order::Descending => (None, start_after),
+        }
+    }
+}
+
+pub fn get_page_limit(store: &dyn Storage, limit: Option<u16>) -> StdResult<usize> {
+    let limit = match limit {
+        Some(limit) => limit,
+        None => get_config(store)?.default_page_limit,
+    };
+
+    Ok(limit.min(MAX_PAGE_LIMIT) as usize)
+}
+
+pub fn get_page_order(reverse: Option<bool>) -> Order {
+    match reverse {
+        Some(true) => Order::Descending,
+        _ => Order::Ascending,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cw_storage_plus::Map;
+
+    const ITEMS: Map<u64, u64> = Map::new("items");
+
+    fn get_items(
+        store: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<u16>,
+        reverse: Option<bool>,
+    ) -> Vec<u64> {
+        let page = Page::new(store, start_after, limit, reverse).unwrap();
+        let (min, max) = page.bounds();
+
+        ITEMS
+            .keys(store, min, max, page.order)
+            .take(page.limit)
+            .collect::<StdResult<Vec<u64>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn without_limit_uses_default_page_limit() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let default_page_limit = get_config(deps.as_ref().storage)
+            .unwrap()
+            .default_page_limit;
+
+        assert_eq!(
+            get_page_limit(deps.as_ref().storage, None).unwrap(),
+            default_page_limit as usize
+        );
+    }
+
+    #[test]
+    fn with_limit_above_max_caps_limit() {
+        let deps = mock_dependencies();
+
+        assert_eq!(
+            get_page_limit(deps.as_ref().storage, Some(MAX_PAGE_LIMIT + 1)).unwrap(),
+            MAX_PAGE_LIMIT as usize
+        );
+    }
+
+    #[test]
+    fn pages_forwards_after_start_after() {
+        let mut deps = mock_dependencies();
+
+        for id in 1..6 {
+            ITEMS.save(deps.as_mut().storage, id, &id).unwrap();
+        }
+
+        assert_eq!(
+            get_items(deps.as_ref().storage, Some(2), Some(2), None),
+            vec![3, 4]
+        );
+    }
+
+    #[test]
+    fn pages_backwards_before_start_after() {
+        let mut deps = mock_dependencies();
+
+        for id in 1..6 {
+            ITEMS.save(deps.as_mut().storage, id, &id).unwrap();
+        }
+
+        assert_eq!(
+            get_items(deps.as_ref().storage, Some(4), Some(2), Some(true)),
+            vec![3, 2]
+        );
+        assert_eq!(
+            get_items(deps.as_ref().storage, None, Some(2), Some(true)),
+            vec![5, 4]
+        );
+    }
+}
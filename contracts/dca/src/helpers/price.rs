@@ -1,7 +1,9 @@
-use cosmwasm_std::{Addr, Binary, Coin, Decimal, QuerierWrapper, StdResult, Storage, Uint128};
+use cosmwasm_std::{
+    Addr, Binary, Coin, Decimal, QuerierWrapper, StdError, StdResult, Storage, Uint128,
+};
 use exchange::msg::QueryMsg;
 
-use crate::state::config::get_config;
+use crate::{state::config::get_config, types::config::Config};
 
 pub fn get_twap_to_now(
     querier: &QuerierWrapper,
@@ -22,6 +24,37 @@ pub fn get_twap_to_now(
     )
 }
 
+pub fn get_twap_to_now_with_fallback(
+    querier: &QuerierWrapper,
+    config: &Config,
+    swap_denom: String,
+    target_denom: String,
+    route: Option<Binary>,
+) -> StdResult<(Decimal, Option<Addr>)> {
+    match get_twap_to_now(
+        querier,
+        config.exchange_contract_address.clone(),
+        swap_denom.clone(),
+        target_denom.clone(),
+        config.twap_period,
+        route,
+    ) {
+        Ok(price) => Ok((price, None)),
+        Err(err) => match config.price_oracle_address.clone() {
+            Some(price_oracle_address) => get_twap_to_now(
+                querier,
+                price_oracle_address.clone(),
+                swap_denom,
+                target_denom,
+                config.twap_period,
+                None,
+            )
+            .map(|price| (price, Some(price_oracle_address))),
+            None => Err(err),
+        },
+    }
+}
+
 pub fn get_expected_receive_amount(
     querier: &QuerierWrapper,
     exchange_contract_address: Addr,
@@ -39,6 +72,66 @@ pub fn get_expected_receive_amount(
     )
 }
 
+pub fn get_best_quote(
+    querier: &QuerierWrapper,
+    exchange_contract_addresses: Vec<Addr>,
+    swap_amount: Coin,
+    target_denom: String,
+) -> StdResult<(Addr, Coin)> {
+    exchange_contract_addresses
+        .into_iter()
+        .filter_map(|exchange_contract_address| {
+            get_expected_receive_amount(
+                querier,
+                exchange_contract_address.clone(),
+                swap_amount.clone(),
+                target_denom.clone(),
+                None,
+            )
+            .ok()
+            .filter(|expected_receive_amount| expected_receive_amount.denom == target_denom)
+            .map(|expected_receive_amount| (exchange_contract_address, expected_receive_amount))
+        })
+        .max_by_key(|(_, expected_receive_amount)| expected_receive_amount.amount)
+        .ok_or_else(|| {
+            StdError::generic_err(format!(
+                "no exchange adapter returned a quote for swapping {} to {}",
+                swap_amount.denom, target_denom
+            ))
+        })
+}
+
+pub fn get_required_swap_amount(
+    querier: &QuerierWrapper,
+    exchange_contract_address: Addr,
+    swap_amount: Coin,
+    target_receive_amount: Coin,
+    slippage_tolerance: Decimal,
+    route: Option<Binary>,
+) -> StdResult<Uint128> {
+    let expected_receive_amount = get_expected_receive_amount(
+        querier,
+        exchange_contract_address,
+        swap_amount.clone(),
+        target_receive_amount.denom.clone(),
+        route,
+    )?;
+
+    let minimum_receive_amount =
+        expected_receive_amount.amount * (Decimal::one() - slippage_tolerance);
+
+    if minimum_receive_amount.is_zero() {
+        return Err(StdError::generic_err(format!(
+            "unable to receive any {} for {}",
+            target_receive_amount.denom, swap_amount
+        )));
+    }
+
+    Ok(target_receive_amount
+        .amount
+        .multiply_ratio(swap_amount.amount, minimum_receive_amount))
+}
+
 pub fn get_slippage(
     querier: &QuerierWrapper,
     exchange_contract_address: Addr,
@@ -63,14 +156,25 @@ pub fn get_slippage(
         return Ok(Decimal::percent(0));
     }
 
-    let expected_receive_amount = expected_receive_amount?;
+    Ok(get_slippage_from_expected_receive_amount(
+        &swap_amount,
+        &expected_receive_amount?,
+        belief_price,
+    ))
+}
+
+pub fn get_slippage_from_expected_receive_amount(
+    swap_amount: &Coin,
+    expected_receive_amount: &Coin,
+    belief_price: Decimal,
+) -> Decimal {
     let expected_price = Decimal::from_ratio(swap_amount.amount, expected_receive_amount.amount);
 
     if belief_price >= expected_price {
-        return Ok(Decimal::percent(0));
+        return Decimal::percent(0);
     }
 
-    Ok(expected_price.abs_diff(belief_price) / belief_price)
+    expected_price.abs_diff(belief_price) / belief_price
 }
 
 pub fn get_price(
@@ -113,3 +217,170 @@ pub fn get_price(
         expected_receive_amount,
     ))
 }
+
+#[cfg(test)]
+mod get_best_quote_tests {
+    use super::*;
+    use crate::tests::mocks::{calc_mock_dependencies, CalcMockQuerier, DENOM_UKUJI, DENOM_UUSK};
+    use cosmwasm_std::{
+        from_json, to_json_binary, ContractResult, SystemError, SystemResult, WasmQuery,
+    };
+
+    fn mock_quotes(querier: &mut CalcMockQuerier) {
+        querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, msg } => match from_json::<QueryMsg>(msg).unwrap() {
+                QueryMsg::GetExpectedReceiveAmount {
+                    swap_amount,
+                    target_denom,
+                    ..
+                } => match contract_addr.as_str() {
+                    "failing-exchange" => SystemResult::Err(SystemError::InvalidRequest {
+                        error: "no pool".to_string(),
+                        request: msg.clone(),
+                    }),
+                    "best-exchange" => SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&Coin::new(
+                            (swap_amount.amount * Decimal::percent(98)).into(),
+                            target_denom,
+                        ))
+                        .unwrap(),
+                    )),
+                    _ => SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&Coin::new(
+                            (swap_amount.amount * Decimal::percent(95)).into(),
+                            target_denom,
+                        ))
+                        .unwrap(),
+                    )),
+                },
+                _ => panic!("Unsupported query"),
+            },
+            _ => panic!("Unsupported query"),
+        });
+    }
+
+    #[test]
+    fn picks_the_highest_quote_and_skips_failing_adapters() {
+        let mut deps = calc_mock_dependencies();
+
+        mock_quotes(&mut deps.querier);
+
+        let (exchange_contract_address, expected_receive_amount) = get_best_quote(
+            &QuerierWrapper::new(&deps.querier),
+            vec![
+                Addr::unchecked("exchange"),
+                Addr::unchecked("failing-exchange"),
+                Addr::unchecked("best-exchange"),
+            ],
+            Coin::new(100, DENOM_UKUJI),
+            DENOM_UUSK.to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(exchange_contract_address, Addr::unchecked("best-exchange"));
+        assert_eq!(expected_receive_amount, Coin::new(98, DENOM_UUSK));
+    }
+
+    #[test]
+    fn with_no_quotes_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        mock_quotes(&mut deps.querier);
+
+        let err = get_best_quote(
+            &QuerierWrapper::new(&deps.querier),
+            vec![Addr::unchecked("failing-exchange")],
+            Coin::new(100, DENOM_UKUJI),
+            DENOM_UUSK.to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Generic error: no exchange adapter returned a quote for swapping {} to {}",
+                DENOM_UKUJI, DENOM_UUSK
+            )
+        );
+    }
+}
+
+#[cfg(test)]
+mod get_twap_to_now_with_fallback_tests {
+    use super::*;
+    use crate::tests::mocks::{calc_mock_dependencies, CalcMockQuerier, DENOM_UKUJI, DENOM_UUSK};
+    use cosmwasm_std::{to_json_binary, ContractResult, SystemError, SystemResult, WasmQuery};
+
+    fn mock_stale_exchange(querier: &mut CalcMockQuerier) {
+        querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, msg } => match contract_addr.as_str() {
+                "oracle" => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&Decimal::percent(150)).unwrap(),
+                )),
+                _ => SystemResult::Err(SystemError::InvalidRequest {
+                    error: "stale pool".to_string(),
+                    request: msg.clone(),
+                }),
+            },
+            _ => panic!("Unsupported query"),
+        });
+    }
+
+    #[test]
+    fn when_exchange_fails_uses_price_oracle() {
+        let mut deps = calc_mock_dependencies();
+
+        mock_stale_exchange(&mut deps.querier);
+
+        let (price, price_oracle_address) = get_twap_to_now_with_fallback(
+            &QuerierWrapper::new(&deps.querier),
+            &Config {
+                price_oracle_address: Some(Addr::unchecked("oracle")),
+                ..Config::default()
+            },
+            DENOM_UKUJI.to_string(),
+            DENOM_UUSK.to_string(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(price, Decimal::percent(150));
+        assert_eq!(price_oracle_address, Some(Addr::unchecked("oracle")));
+    }
+
+    #[test]
+    fn when_exchange_fails_without_price_oracle_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        mock_stale_exchange(&mut deps.querier);
+
+        assert!(get_twap_to_now_with_fallback(
+            &QuerierWrapper::new(&deps.querier),
+            &Config::default(),
+            DENOM_UKUJI.to_string(),
+            DENOM_UUSK.to_string(),
+            None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn when_exchange_succeeds_does_not_use_price_oracle() {
+        let deps = calc_mock_dependencies();
+
+        let (price, price_oracle_address) = get_twap_to_now_with_fallback(
+            &QuerierWrapper::new(&deps.querier),
+            &Config {
+                price_oracle_address: Some(Addr::unchecked("oracle")),
+                ..Config::default()
+            },
+            DENOM_UKUJI.to_string(),
+            DENOM_UUSK.to_string(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(price, Decimal::one());
+        assert_eq!(price_oracle_address, None);
+    }
+}
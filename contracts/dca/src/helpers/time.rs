@@ -45,6 +45,18 @@ pub fn get_next_target_time(
     Timestamp::from_seconds(next_execution_time.timestamp().try_into().unwrap())
 }
 
+pub fn get_month_start(timestamp: Timestamp) -> Timestamp {
+    let time = Utc
+        .timestamp_opt(timestamp.seconds().try_into().unwrap(), 0)
+        .unwrap();
+
+    let month_start = Utc
+        .with_ymd_and_hms(time.year(), time.month(), 1, 0, 0, 0)
+        .unwrap();
+
+    Timestamp::from_seconds(month_start.timestamp().try_into().unwrap())
+}
+
 pub fn get_total_execution_duration(
     block_time: Timestamp,
     iterations: u128,
@@ -714,4 +726,15 @@ mod get_total_execution_duration_tests {
             shift_months(block_time_utc, 3) - block_time_utc,
         );
     }
+
+    #[test]
+    fn month_start_is_midnight_on_the_first_of_the_month() {
+        let time = Utc.with_ymd_and_hms(2023, 3, 17, 14, 32, 5).unwrap();
+        let expected = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            get_month_start(Timestamp::from_seconds(time.timestamp() as u64)),
+            Timestamp::from_seconds(expected.timestamp() as u64)
+        );
+    }
 }
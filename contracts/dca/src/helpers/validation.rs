@@ -1,23 +1,45 @@
+use crate::constants::{MAX_PAGE_LIMIT, PROGRESS_UPDATE_INTERVAL_SECONDS};
 use crate::error::ContractError;
+use crate::helpers::address::get_address_prefix;
 use crate::msg::ExecuteMsg;
-use crate::state::config::get_config;
+use crate::state::allowed_denoms::is_denom_allowed;
+use crate::state::authz::operator_has_permission;
+use crate::state::bounties::{get_bounty, owner_balances_are_rebuilding};
+use crate::state::config::{get_config, get_expected_address_prefix};
+use crate::state::disputes::get_dispute;
+use crate::state::exchanges::is_exchange;
+use crate::state::owner_balances::get_owner_balance;
+use crate::state::roles::has_role;
+use crate::state::scheduled_config_updates::get_scheduled_config_updates;
+use crate::state::update_permissions::get_update_permissions;
+use crate::types::assignee::Assignee;
+use crate::types::authz::OperatorPermission;
+use crate::types::bounty::Bounty;
+use crate::types::checklist::ChecklistItemParams;
+use crate::types::denom::Denom;
 use crate::types::destination::Destination;
+use crate::types::disbursement_schedule::DisbursementSchedule;
+use crate::types::escrow_tranche::EscrowTranche;
 use crate::types::fee_collector::FeeCollector;
 use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategyParams;
+use crate::types::role::Role;
 use crate::types::swap_adjustment_strategy::{
     SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
 use crate::types::time_interval::TimeInterval;
+use crate::types::trigger::BalanceThreshold;
+use crate::types::updatable_field::UpdatableField;
 use crate::types::vault::{Vault, VaultStatus};
 use cosmwasm_std::{
-    from_json, Addr, Binary, Coin, Decimal, Deps, Env, Storage, Timestamp, Uint128,
+    from_json, Addr, Binary, Coin, Decimal, Deps, Env, Storage, Timestamp, Uint128, Uint64,
 };
 use exchange::msg::QueryMsg;
+use shared::cw20::cw20_token_address;
 
 pub fn assert_exactly_one_asset(funds: Vec<Coin>) -> Result<(), ContractError> {
     if funds.is_empty() || funds.len() > 1 {
-        return Err(ContractError::CustomError {
-            val: format!("received {} denoms but required exactly 1", funds.len()),
+        return Err(ContractError::WrongNumberOfDenoms {
+            received: funds.len(),
         });
     }
     Ok(())
@@ -26,34 +48,60 @@ pub fn assert_exactly_one_asset(funds: Vec<Coin>) -> Result<(), ContractError> {
 pub fn assert_contract_is_not_paused(storage: &mut dyn Storage) -> Result<(), ContractError> {
     let config = get_config(storage)?;
     if config.paused {
-        return Err(ContractError::CustomError {
-            val: "contract is paused".to_string(),
-        });
+        return Err(ContractError::ContractPaused {});
     }
     Ok(())
 }
 
-pub fn assert_sender_is_admin(
-    storage: &mut dyn Storage,
-    sender: Addr,
+pub fn assert_create_is_not_paused(storage: &dyn Storage) -> Result<(), ContractError> {
+    if get_config(storage)?.create_paused {
+        return Err(ContractError::CreatePaused {});
+    }
+    Ok(())
+}
+
+pub fn assert_execute_is_not_paused(storage: &dyn Storage) -> Result<(), ContractError> {
+    if get_config(storage)?.execute_paused {
+        return Err(ContractError::ExecutePaused {});
+    }
+    Ok(())
+}
+
+pub fn assert_deposit_is_not_paused(storage: &dyn Storage) -> Result<(), ContractError> {
+    if get_config(storage)?.deposit_paused {
+        return Err(ContractError::DepositPaused {});
+    }
+    Ok(())
+}
+
+pub fn assert_disburse_is_not_paused(storage: &dyn Storage) -> Result<(), ContractError> {
+    if get_config(storage)?.disburse_paused {
+        return Err(ContractError::DisbursePaused {});
+    }
+    Ok(())
+}
+
+pub fn assert_sender_has_role(
+    storage: &dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    role: Role,
 ) -> Result<(), ContractError> {
-    let config = get_config(storage)?;
-    if sender != config.admin {
+    if role == Role::Executor && sender == env.contract.address {
+        return Ok(());
+    }
+    if !has_role(storage, sender, &role)? {
         return Err(ContractError::Unauthorized {});
     }
     Ok(())
 }
 
-pub fn assert_sender_is_executor(
+pub fn assert_sender_is_arbiter(
     storage: &mut dyn Storage,
-    env: &Env,
-    sender: &Addr,
+    sender: Addr,
 ) -> Result<(), ContractError> {
     let config = get_config(storage)?;
-    if !config.executors.contains(sender)
-        && sender != config.admin
-        && sender != env.contract.address
-    {
+    if config.arbiter != Some(sender) {
         return Err(ContractError::Unauthorized {});
     }
     Ok(())
@@ -66,13 +114,34 @@ pub fn asset_sender_is_vault_owner(vault_owner: Addr, sender: Addr) -> Result<()
     Ok(())
 }
 
+pub fn asset_sender_is_bounty_owner(bounty_owner: Addr, sender: Addr) -> Result<(), ContractError> {
+    if sender != bounty_owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
 pub fn assert_sender_is_admin_or_vault_owner(
     storage: &mut dyn Storage,
     vault_owner: Addr,
     sender: Addr,
 ) -> Result<(), ContractError> {
-    let config = get_config(storage)?;
-    if sender != config.admin && sender != vault_owner {
+    if sender != vault_owner && !has_role(storage, &sender, &Role::Admin)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn assert_sender_is_admin_or_bounty_owner(
+    storage: &dyn Storage,
+    bounty: &Bounty,
+    sender: &Addr,
+    permission: OperatorPermission,
+) -> Result<(), ContractError> {
+    if sender != bounty.owner
+        && !has_role(storage, sender, &Role::Admin)?
+        && !operator_has_permission(storage, bounty.id, sender, &permission)?
+    {
         return Err(ContractError::Unauthorized {});
     }
     Ok(())
@@ -83,8 +152,7 @@ pub fn assert_sender_is_contract_or_admin(
     sender: &Addr,
     env: &Env,
 ) -> Result<(), ContractError> {
-    let config = get_config(storage)?;
-    if sender != config.admin && sender != env.contract.address {
+    if sender != env.contract.address && !has_role(storage, sender, &Role::Admin)? {
         return Err(ContractError::Unauthorized {});
     }
     Ok(())
@@ -99,6 +167,154 @@ pub fn assert_vault_is_not_cancelled(vault: &Vault) -> Result<(), ContractError>
     Ok(())
 }
 
+pub fn assert_bounty_is_not_cancelled(bounty: &Bounty) -> Result<(), ContractError> {
+    if bounty.is_cancelled() {
+        return Err(ContractError::BountyCancelled {});
+    }
+    Ok(())
+}
+
+pub fn assert_bounty_is_not_locked(
+    bounty: &Bounty,
+    current_time: Timestamp,
+) -> Result<(), ContractError> {
+    if bounty.is_locked(current_time) {
+        return Err(ContractError::BountyLocked {
+            locked_until: bounty.locked_until().unwrap(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_bounty_is_not_completed(bounty: &Bounty) -> Result<(), ContractError> {
+    if bounty.is_completed() {
+        return Err(ContractError::BountyCompleted {});
+    }
+    Ok(())
+}
+
+pub fn assert_bounty_is_active(bounty: &Bounty) -> Result<(), ContractError> {
+    if !bounty.is_active() {
+        return Err(ContractError::BountyNotActive {});
+    }
+    Ok(())
+}
+
+pub fn assert_bounty_is_paused(bounty: &Bounty) -> Result<(), ContractError> {
+    if !bounty.is_paused() {
+        return Err(ContractError::BountyNotPaused {});
+    }
+    Ok(())
+}
+
+pub fn assert_deposit_covers_exact_receive_amount(
+    deposit: &Coin,
+    required_deposit_amount: Uint128,
+    exact_receive_amount: &Coin,
+) -> Result<(), ContractError> {
+    if deposit.amount < required_deposit_amount {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "deposit of {} cannot guarantee a receive amount of {}, at least {}{} is required",
+                deposit, exact_receive_amount, required_deposit_amount, deposit.denom
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_deposit_meets_minimum_deposit(
+    storage: &dyn Storage,
+    deposit: &Coin,
+) -> Result<(), ContractError> {
+    let config = get_config(storage)?;
+    if let Some(minimum_deposit) = config
+        .minimum_deposit
+        .iter()
+        .find(|minimum_deposit| minimum_deposit.denom == deposit.denom)
+    {
+        if deposit.amount < minimum_deposit.amount {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "deposit of {} is less than the minimum deposit of {}",
+                    deposit, minimum_deposit
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_bounty_balance_is_within_cap(
+    storage: &dyn Storage,
+    balance: &Coin,
+) -> Result<(), ContractError> {
+    let config = get_config(storage)?;
+    if let Some(max_bounty_balance) = config
+        .max_bounty_balance
+        .iter()
+        .find(|max_bounty_balance| max_bounty_balance.denom == balance.denom)
+    {
+        if balance.amount > max_bounty_balance.amount {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "bounty balance of {} would exceed the maximum bounty balance of {}",
+                    balance, max_bounty_balance
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_owner_balance_is_within_cap(
+    storage: &dyn Storage,
+    owner: &Addr,
+    deposit: &Coin,
+) -> Result<(), ContractError> {
+    let config = get_config(storage)?;
+    if let Some(max_total_balance) = config
+        .max_total_balance_per_owner
+        .iter()
+        .find(|max_total_balance| max_total_balance.denom == deposit.denom)
+    {
+        if owner_balances_are_rebuilding(storage)? {
+            return Err(ContractError::CustomError {
+                val: String::from(
+                    "owner balances are being rebuilt, try again once migration completes",
+                ),
+            });
+        }
+
+        let total_balance = get_owner_balance(storage, owner, &deposit.denom)? + deposit.amount;
+        if total_balance > max_total_balance.amount {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "total balance of {}{} for owner {} would exceed the maximum of {}",
+                    total_balance, deposit.denom, owner, max_total_balance
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_swap_amount_meets_minimum_swap_amount(
+    storage: &dyn Storage,
+    swap_amount: &Coin,
+) -> Result<(), ContractError> {
+    let config = get_config(storage)?;
+    if swap_amount.amount < config.minimum_swap_amount {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "swap amount of {} is less than the minimum swap amount of {}{}",
+                swap_amount, config.minimum_swap_amount, swap_amount.denom
+            ),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_swap_amount_is_greater_than_50000(swap_amount: Uint128) -> Result<(), ContractError> {
     if swap_amount <= Uint128::from(50000u128) {
         return Err(ContractError::CustomError {
@@ -108,16 +324,23 @@ pub fn assert_swap_amount_is_greater_than_50000(swap_amount: Uint128) -> Result<
     Ok(())
 }
 
+pub fn assert_denom_is_allowed(storage: &dyn Storage, denom: &str) -> Result<(), ContractError> {
+    if !is_denom_allowed(storage, denom) {
+        return Err(ContractError::DenomNotAllowed {
+            denom: denom.to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_deposited_denom_matches_send_denom(
-    deposit_denom: String,
-    send_denom: String,
+    deposit_denom: &Denom,
+    send_denom: &Denom,
 ) -> Result<(), ContractError> {
     if deposit_denom != send_denom {
-        return Err(ContractError::CustomError {
-            val: format!(
-                "received asset with denom {}, but needed {}",
-                deposit_denom, send_denom
-            ),
+        return Err(ContractError::WrongDenom {
+            expected: send_denom.to_string(),
+            received: deposit_denom.to_string(),
         });
     }
     Ok(())
@@ -135,6 +358,33 @@ pub fn assert_target_start_time_is_in_future(
     Ok(())
 }
 
+pub fn assert_expiry_is_in_future(
+    current_time: Timestamp,
+    expires_at: Timestamp,
+) -> Result<(), ContractError> {
+    if expires_at <= current_time {
+        return Err(ContractError::CustomError {
+            val: String::from("expires_at must be some time in the future"),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_disbursement_schedule_is_valid(
+    disbursement_schedule: &DisbursementSchedule,
+) -> Result<(), ContractError> {
+    match disbursement_schedule {
+        DisbursementSchedule::Linear { start, end } => {
+            if end <= start {
+                return Err(ContractError::CustomError {
+                    val: String::from("disbursement schedule must end after it starts"),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
 pub fn assert_target_time_is_in_past(
     current_time: Timestamp,
     target_time: Timestamp,
@@ -147,18 +397,96 @@ pub fn assert_target_time_is_in_past(
     Ok(())
 }
 
+pub fn assert_target_height_is_in_past(
+    current_height: u64,
+    target_height: u64,
+) -> Result<(), ContractError> {
+    if current_height < target_height {
+        return Err(ContractError::CustomError {
+            val: String::from("trigger execution height has not yet been reached"),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_target_height_is_in_future(
+    current_height: u64,
+    target_height: u64,
+) -> Result<(), ContractError> {
+    if target_height <= current_height {
+        return Err(ContractError::CustomError {
+            val: String::from("target_block_height must be some block in the future"),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_no_more_than_one_start_trigger(
+    target_start_time_utc_seconds: Option<Uint64>,
+    target_receive_amount: Option<Uint128>,
+    target_block_height: Option<u64>,
+    balance_threshold: &Option<BalanceThreshold>,
+) -> Result<(), ContractError> {
+    if [
+        target_start_time_utc_seconds.is_some(),
+        target_receive_amount.is_some(),
+        target_block_height.is_some(),
+        balance_threshold.is_some(),
+    ]
+    .iter()
+    .filter(|provided| **provided)
+    .count()
+        > 1
+    {
+        return Err(ContractError::CustomError {
+            val: String::from(
+                "only one of target_start_time_utc_seconds, target_receive_amount, target_block_height and balance_threshold can be provided",
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_balance_is_above_threshold(
+    balance: Coin,
+    threshold: Uint128,
+) -> Result<(), ContractError> {
+    if balance.amount <= threshold {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "watched balance of {} has not yet exceeded the threshold of {}{}",
+                balance, threshold, balance.denom
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_balance_has_dropped_to_threshold(
+    balance: Coin,
+    threshold: Uint128,
+) -> Result<(), ContractError> {
+    if balance.amount > threshold {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "watched balance of {} has not dropped back to the threshold of {}{} since the trigger last fired",
+                balance, threshold, balance.denom
+            ),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_fee_collector_addresses_are_valid(
     deps: Deps,
     fee_collectors: &[FeeCollector],
 ) -> Result<(), ContractError> {
     for fee_collector in fee_collectors {
-        match fee_collector.address.as_str() {
-            "community_pool" => (),
-            _ => assert_address_is_valid(
-                deps,
-                &Addr::unchecked(fee_collector.address.clone()),
-                "fee collector",
-            )?,
+        match fee_collector {
+            FeeCollector::Address { address, .. } if address != "community_pool" => {
+                assert_address_is_valid(deps, &Addr::unchecked(address.clone()), "fee collector")?
+            }
+            _ => (),
         }
     }
     Ok(())
@@ -173,6 +501,50 @@ pub fn assert_fee_level_is_valid(swap_fee_percent: &Decimal) -> Result<(), Contr
     Ok(())
 }
 
+pub fn assert_slippage_retry_interval_is_valid(
+    slippage_retry_interval_seconds: u64,
+) -> Result<(), ContractError> {
+    if slippage_retry_interval_seconds < 60 {
+        return Err(ContractError::CustomError {
+            val: "slippage_retry_interval_seconds must be at least 60 seconds".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_executor_reward_percent_is_no_greater_than_100_percent(
+    executor_reward_percent: Decimal,
+) -> Result<(), ContractError> {
+    if executor_reward_percent > Decimal::percent(100) {
+        return Err(ContractError::CustomError {
+            val: "executor_reward_percent cannot be greater than 100%".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_referral_fee_percent_is_no_greater_than_100_percent(
+    referral_fee_percent: Decimal,
+) -> Result<(), ContractError> {
+    if referral_fee_percent > Decimal::percent(100) {
+        return Err(ContractError::CustomError {
+            val: "referral_fee_percent cannot be greater than 100%".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_auto_route_safety_margin_is_no_greater_than_100_percent(
+    auto_route_safety_margin: Decimal,
+) -> Result<(), ContractError> {
+    if auto_route_safety_margin > Decimal::percent(100) {
+        return Err(ContractError::CustomError {
+            val: "auto_route_safety_margin cannot be greater than 100%".to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent(
     risk_weighted_average_escrow_level: Decimal,
 ) -> Result<(), ContractError> {
@@ -197,20 +569,56 @@ pub fn assert_slippage_tolerance_is_less_than_or_equal_to_one(
     slippage_tolerance: Decimal,
 ) -> Result<(), ContractError> {
     if slippage_tolerance > Decimal::percent(100) {
-        return Err(ContractError::CustomError {
-            val: "slippage tolerance must be less than or equal to 1".to_string(),
-        });
+        return Err(ContractError::SlippageToleranceTooHigh {});
     }
     Ok(())
 }
 
+pub fn assert_slippage_override_is_within_cap(
+    bounty: &Bounty,
+    slippage_override: Decimal,
+) -> Result<(), ContractError> {
+    match bounty.executor_slippage_override_cap {
+        None => Err(ContractError::SlippageOverrideNotPermitted {
+            bounty_id: bounty.id,
+        }),
+        Some(cap) if slippage_override > cap => Err(ContractError::SlippageOverrideTooHigh {
+            bounty_id: bounty.id,
+            slippage: slippage_override,
+            cap,
+        }),
+        _ => Ok(()),
+    }
+}
+
 pub fn assert_no_more_than_10_fee_collectors(
     fee_collectors: &[FeeCollector],
 ) -> Result<(), ContractError> {
     if fee_collectors.len() > 10 {
-        return Err(ContractError::CustomError {
-            val: String::from("no more than 10 fee collectors are allowed"),
-        });
+        return Err(ContractError::TooManyFeeCollectors { max: 10 });
+    }
+    Ok(())
+}
+
+pub fn assert_no_more_than_20_trigger_ids(trigger_ids: &[Uint128]) -> Result<(), ContractError> {
+    if trigger_ids.len() > 20 {
+        return Err(ContractError::TooManyTriggers { max: 20 });
+    }
+    Ok(())
+}
+
+pub fn assert_no_more_than_5_fallback_routes(
+    fallback_routes: &[Binary],
+) -> Result<(), ContractError> {
+    if fallback_routes.len() > 5 {
+        return Err(ContractError::TooManyFallbackRoutes { max: 5 });
+    }
+    Ok(())
+}
+
+pub fn assert_no_more_than_50_bounty_ids(bounty_ids: &[Uint128]) -> Result<(), ContractError> {
+    if bounty_ids.len() > 50 {
+        return Err(ContractError::TooManyBounties { max: 50 });
     }
     Ok(())
 }
@@ -222,10 +630,22 @@ pub fn assert_address_is_valid(
 ) -> Result<(), ContractError> {
     deps.api
         .addr_validate(address.as_ref())
-        .map(|_| ())
-        .map_err(|_| ContractError::CustomError {
-            val: format!("{} address {} is invalid", label, address),
-        })
+        .map_err(|_| ContractError::InvalidAddress {
+            label: label.to_string(),
+            address: address.to_string(),
+        })?;
+
+    if let Some(expected) = get_expected_address_prefix(deps.storage)? {
+        if get_address_prefix(address) != Some(expected.as_str()) {
+            return Err(ContractError::UnexpectedAddressPrefix {
+                label: label.to_string(),
+                address: address.to_string(),
+                expected,
+            });
+        }
+    }
+
+    Ok(())
 }
 
 pub fn assert_addresses_are_valid(
@@ -244,7 +664,7 @@ pub fn assert_fee_collector_allocations_add_up_to_one(
     if fee_collectors
         .iter()
         .fold(Decimal::zero(), |acc, fee_collector| {
-            acc.checked_add(fee_collector.allocation).unwrap()
+            acc.checked_add(fee_collector.allocation()).unwrap()
         })
         != Decimal::percent(100)
     {
@@ -255,6 +675,17 @@ pub fn assert_fee_collector_allocations_add_up_to_one(
     Ok(())
 }
 
+pub fn assert_escrow_level_is_no_greater_than_100_percent(
+    escrow_level: Decimal,
+) -> Result<(), ContractError> {
+    if escrow_level > Decimal::percent(100) {
+        return Err(ContractError::CustomError {
+            val: "escrow_level cannot be greater than 100%".to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_dca_plus_escrow_level_is_less_than_100_percent(
     dca_plus_escrow_level: Decimal,
 ) -> Result<(), ContractError> {
@@ -268,9 +699,9 @@ pub fn assert_dca_plus_escrow_level_is_less_than_100_percent(
 
 pub fn assert_page_limit_is_valid(limit: Option<u16>) -> Result<(), ContractError> {
     if let Some(limit) = limit {
-        if limit > 1000 {
-            return Err(ContractError::CustomError {
-                val: "limit cannot be greater than 1000.".to_string(),
+        if limit > MAX_PAGE_LIMIT {
+            return Err(ContractError::PageLimitTooLarge {
+                max: MAX_PAGE_LIMIT,
             });
         }
     }
@@ -311,16 +742,103 @@ pub fn assert_contract_destination_callbacks_are_valid(
         })
 }
 
-pub fn assert_destination_callback_addresses_are_valid(
-    deps: Deps,
+pub fn assert_compound_destinations_are_valid(
+    storage: &dyn Storage,
     destinations: &[Destination],
+    contract_address: &Addr,
+    owner: &Addr,
+    target_denom: &Denom,
 ) -> Result<(), ContractError> {
-    destinations.iter().for_each(|destination| {
+    destinations
+        .iter()
+        .filter(|d| d.address == *contract_address)
+        .try_for_each(|d| {
+            d.msg.clone().map_or(Ok(()), |msg| match from_json(msg) {
+                Ok(ExecuteMsg::Deposit { address, bounty_id }) => {
+                    if address != *owner {
+                        return Err(ContractError::CustomError {
+                            val: "compound destinations can only deposit into bounties owned by the same owner"
+                                .to_string(),
+                        });
+                    }
+                    let target_bounty = get_bounty(storage, bounty_id)?;
+                    if target_bounty.owner != *owner {
+                        return Err(ContractError::CustomError {
+                            val: format!("bounty {} is not owned by {}", bounty_id, owner),
+                        });
+                    }
+                    if target_bounty.get_swap_denom() != target_denom.to_string() {
+                        return Err(ContractError::CustomError {
+                            val: format!(
+                                "bounty {} does not accept deposits of {}",
+                                bounty_id, target_denom
+                            ),
+                        });
+                    }
+                    Ok(())
+                }
+                _ => Ok(()),
+            })
+        })
+}
+
+pub fn assert_destination_gas_limits_are_valid(
+    destinations: &[Destination],
+) -> Result<(), ContractError> {
+    if destinations
+        .iter()
+        .any(|destination| destination.gas_limit == Some(0))
+    {
+        return Err(ContractError::CustomError {
+            val: "destination gas limits must be greater than zero".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_destination_callback_addresses_are_valid(
+    deps: Deps,
+    destinations: &[Destination],
+) -> Result<(), ContractError> {
+    destinations.iter().for_each(|destination| {
         assert_address_is_valid(deps, &destination.address, "destination").unwrap();
     });
     Ok(())
 }
 
+pub fn assert_ibc_destinations_are_valid(
+    deps: Deps,
+    destinations: &[Destination],
+    target_denom: &str,
+) -> Result<(), ContractError> {
+    destinations
+        .iter()
+        .filter_map(|destination| destination.ibc.as_ref().map(|ibc| (destination, ibc)))
+        .try_for_each(|(destination, ibc)| {
+            if !ibc.channel_id.starts_with("channel-") {
+                return Err(ContractError::CustomError {
+                    val: format!("ibc channel id {} is invalid", ibc.channel_id),
+                });
+            }
+            if ibc.remote_address.is_empty() {
+                return Err(ContractError::CustomError {
+                    val: "ibc remote address cannot be empty".to_string(),
+                });
+            }
+            if destination.msg.is_some() {
+                return Err(ContractError::CustomError {
+                    val: "ibc destinations cannot specify a callback msg".to_string(),
+                });
+            }
+            if cw20_token_address(deps.api, target_denom).is_ok() {
+                return Err(ContractError::CustomError {
+                    val: "cw20 target denoms cannot be sent to ibc destinations".to_string(),
+                });
+            }
+            Ok(())
+        })
+}
+
 pub fn assert_label_is_no_longer_than_100_characters(label: &str) -> Result<(), ContractError> {
     if label.len() > 100 {
         return Err(ContractError::CustomError {
@@ -330,12 +848,183 @@ pub fn assert_label_is_no_longer_than_100_characters(label: &str) -> Result<(),
     Ok(())
 }
 
+pub fn assert_cancel_note_is_no_longer_than_280_characters(
+    note: &str,
+) -> Result<(), ContractError> {
+    if note.len() > 280 {
+        return Err(ContractError::CustomError {
+            val: "Cancellation note cannot be longer than 280 characters".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_description_is_no_longer_than_1000_characters(
+    description: &str,
+) -> Result<(), ContractError> {
+    if description.len() > 1000 {
+        return Err(ContractError::CustomError {
+            val: "Bounty description cannot be longer than 1000 characters".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_tags_are_valid(tags: &[String]) -> Result<(), ContractError> {
+    if tags.len() > 10 {
+        return Err(ContractError::CustomError {
+            val: "cannot provide more than 10 tags".to_string(),
+        });
+    }
+    for (index, tag) in tags.iter().enumerate() {
+        if tag.is_empty() || tag.len() > 32 {
+            return Err(ContractError::CustomError {
+                val: format!("tag {} must be between 1 and 32 characters", tag),
+            });
+        }
+        if tags[..index].contains(tag) {
+            return Err(ContractError::CustomError {
+                val: format!("tag {} is duplicated", tag),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_progress_update_is_not_rate_limited(
+    last_posted_at: Option<Timestamp>,
+    current_time: Timestamp,
+) -> Result<(), ContractError> {
+    if let Some(last_posted_at) = last_posted_at {
+        let next_allowed_at = last_posted_at.plus_seconds(PROGRESS_UPDATE_INTERVAL_SECONDS);
+
+        if current_time < next_allowed_at {
+            return Err(ContractError::ProgressUpdateRateLimited { next_allowed_at });
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_metadata_uri_is_valid(metadata_uri: &str) -> Result<(), ContractError> {
+    if metadata_uri.is_empty() || metadata_uri.len() > 256 {
+        return Err(ContractError::CustomError {
+            val: "Metadata uri must be between 1 and 256 characters".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_metadata_hash_has_metadata_uri(
+    metadata_uri: &Option<String>,
+    metadata_hash: &Option<[u8; 32]>,
+) -> Result<(), ContractError> {
+    if metadata_hash.is_some() && metadata_uri.is_none() {
+        return Err(ContractError::CustomError {
+            val: "Metadata hash can only be set together with a metadata uri".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_escrow_tranches_are_valid(tranches: &[EscrowTranche]) -> Result<(), ContractError> {
+    if tranches.len() > 10 {
+        return Err(ContractError::CustomError {
+            val: "cannot provide more than 10 escrow tranches".to_string(),
+        });
+    }
+    if tranches.iter().any(|tranche| tranche.allocation.is_zero()) {
+        return Err(ContractError::CustomError {
+            val: "all escrow tranche allocations must be greater than 0".to_string(),
+        });
+    }
+    if tranches
+        .iter()
+        .fold(Decimal::zero(), |acc, tranche| acc + tranche.allocation)
+        != Decimal::one()
+    {
+        return Err(ContractError::CustomError {
+            val: "escrow tranche allocations must add up to 1".to_string(),
+        });
+    }
+    if tranches
+        .windows(2)
+        .any(|pair| pair[0].delay_seconds > pair[1].delay_seconds)
+    {
+        return Err(ContractError::CustomError {
+            val: "escrow tranche delays must be in ascending order".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_swap_adjustment_bounds_are_valid(
+    min_adjustment: Option<Decimal>,
+    max_adjustment: Option<Decimal>,
+) -> Result<(), ContractError> {
+    if let (Some(min_adjustment), Some(max_adjustment)) = (min_adjustment, max_adjustment) {
+        if min_adjustment > max_adjustment {
+            return Err(ContractError::CustomError {
+                val: "Minimum adjustment must be less than or equal to maximum adjustment"
+                    .to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_price_bounds_are_valid(
+    minimum_price: Option<Decimal>,
+    maximum_price: Option<Decimal>,
+) -> Result<(), ContractError> {
+    if minimum_price.is_some_and(|price| price.is_zero())
+        || maximum_price.is_some_and(|price| price.is_zero())
+    {
+        return Err(ContractError::CustomError {
+            val: "Price bounds must be greater than zero".to_string(),
+        });
+    }
+    if let (Some(minimum_price), Some(maximum_price)) = (minimum_price, maximum_price) {
+        if minimum_price > maximum_price {
+            return Err(ContractError::CustomError {
+                val: "Minimum price must be less than or equal to maximum price".to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn assert_viewing_key_is_between_8_and_64_characters(key: &str) -> Result<(), ContractError> {
+    if key.len() < 8 || key.len() > 64 {
+        return Err(ContractError::CustomError {
+            val: "Viewing key must be between 8 and 64 characters".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_referral_code_is_valid(code: &str) -> Result<(), ContractError> {
+    if code.len() < 3
+        || code.len() > 32
+        || !code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(ContractError::CustomError {
+            val: "Referral code must be 3 to 32 alphanumeric, '-' or '_' characters".to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_route_exists_for_denoms(
     deps: Deps,
     swap_denom: String,
     target_denom: String,
     route: Option<Binary>,
 ) -> Result<(), ContractError> {
+    if swap_denom == target_denom {
+        return Ok(());
+    }
     let config = get_config(deps.storage)?;
     let twap_request = deps.querier.query_wasm_smart::<Decimal>(
         config.exchange_contract_address.clone(),
@@ -347,8 +1036,9 @@ pub fn assert_route_exists_for_denoms(
         },
     );
     if twap_request.is_err() {
-        return Err(ContractError::CustomError {
-            val: format!("swapping {} to {} not supported", swap_denom, target_denom,),
+        return Err(ContractError::PairNotSupported {
+            swap_denom,
+            target_denom,
         });
     }
     Ok(())
@@ -392,12 +1082,41 @@ pub fn assert_swap_adjustment_and_performance_assessment_strategies_are_compatib
 pub fn assert_swap_adjustment_strategy_params_are_valid(
     strategy: &SwapAdjustmentStrategyParams,
 ) -> Result<(), ContractError> {
-    if let SwapAdjustmentStrategyParams::WeightedScale { multiplier, .. } = strategy {
+    if let SwapAdjustmentStrategyParams::WeightedScale {
+        multiplier,
+        max_swap_amount,
+        min_swap_amount,
+        ..
+    } = strategy
+    {
         if multiplier > &Decimal::percent(1000) {
             return Err(ContractError::CustomError {
                 val: "Cannot set weighted scale multiplier to more than 10".to_string(),
             });
         }
+
+        assert_weighted_scale_swap_amount_bounds_are_valid(*max_swap_amount, *min_swap_amount)?;
+    }
+    Ok(())
+}
+
+pub fn assert_weighted_scale_swap_amount_bounds_are_valid(
+    max_swap_amount: Option<Uint128>,
+    min_swap_amount: Option<Uint128>,
+) -> Result<(), ContractError> {
+    if max_swap_amount == Some(Uint128::zero()) {
+        return Err(ContractError::CustomError {
+            val: "weighted scale max_swap_amount must be greater than zero".to_string(),
+        });
+    }
+
+    if let (Some(max_swap_amount), Some(min_swap_amount)) = (max_swap_amount, min_swap_amount) {
+        if min_swap_amount > max_swap_amount {
+            return Err(ContractError::CustomError {
+                val: "weighted scale min_swap_amount cannot be greater than max_swap_amount"
+                    .to_string(),
+            });
+        }
     }
     Ok(())
 }
@@ -429,9 +1148,7 @@ pub fn assert_destinations_limit_is_not_breached(
     destinations: &[Destination],
 ) -> Result<(), ContractError> {
     if destinations.len() > 10 {
-        return Err(ContractError::CustomError {
-            val: String::from("no more than 10 destinations can be provided"),
-        });
+        return Err(ContractError::TooManyDestinations { max: 10 });
     };
     Ok(())
 }
@@ -464,6 +1181,19 @@ pub fn assert_destination_allocations_add_up_to_one(
     Ok(())
 }
 
+pub fn assert_model_adjustment_value_is_valid(value: Decimal) -> Result<(), ContractError> {
+    if value < Decimal::percent(20) || value > Decimal::percent(350) {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "model adjustment value must be between {} and {}",
+                Decimal::percent(20),
+                Decimal::percent(350)
+            ),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_swap_adjustment_value_is_valid(
     strategy: &SwapAdjustmentStrategy,
     value: Decimal,
@@ -491,3 +1221,193 @@ pub fn assert_weighted_scale_multiplier_is_no_more_than_10(
     }
     Ok(())
 }
+
+pub fn assert_assignees_limit_is_not_breached(
+    assignees: &[Assignee],
+) -> Result<(), ContractError> {
+    if assignees.len() > 10 {
+        return Err(ContractError::CustomError {
+            val: String::from("no more than 10 assignees can be added to a bounty"),
+        });
+    };
+    Ok(())
+}
+
+pub fn assert_scheduled_config_updates_limit_is_not_breached(
+    store: &dyn Storage,
+) -> Result<(), ContractError> {
+    if get_scheduled_config_updates(store)?.len() >= 10 {
+        return Err(ContractError::CustomError {
+            val: String::from("no more than 10 config updates can be scheduled at once"),
+        });
+    };
+    Ok(())
+}
+
+pub fn assert_assignee_weight_is_greater_than_zero(weight: Decimal) -> Result<(), ContractError> {
+    if weight.is_zero() {
+        return Err(ContractError::CustomError {
+            val: String::from("assignee weight must be greater than 0"),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_exchange_weight_is_greater_than_zero(weight: u64) -> Result<(), ContractError> {
+    if weight == 0 {
+        return Err(ContractError::CustomError {
+            val: String::from("exchange weight must be greater than 0"),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_exchange_is_registered(
+    storage: &dyn Storage,
+    exchange: &Addr,
+) -> Result<(), ContractError> {
+    if !is_exchange(storage, exchange) && exchange != get_config(storage)?.exchange_contract_address
+    {
+        return Err(ContractError::CustomError {
+            val: format!("exchange {} is not registered", exchange),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_application_message_is_no_longer_than_1000_characters(
+    message: &str,
+) -> Result<(), ContractError> {
+    if message.len() > 1000 {
+        return Err(ContractError::CustomError {
+            val: "Application message cannot be longer than 1000 characters".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_submission_is_no_longer_than_1000_characters(
+    submission: &str,
+) -> Result<(), ContractError> {
+    if submission.len() > 1000 {
+        return Err(ContractError::CustomError {
+            val: "Work submission cannot be longer than 1000 characters".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_dispute_reason_is_no_longer_than_1000_characters(
+    reason: &str,
+) -> Result<(), ContractError> {
+    if reason.len() > 1000 {
+        return Err(ContractError::CustomError {
+            val: "Dispute reason cannot be longer than 1000 characters".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_checklist_items_are_valid(
+    items: &[ChecklistItemParams],
+) -> Result<(), ContractError> {
+    if items.is_empty() || items.len() > 20 {
+        return Err(ContractError::CustomError {
+            val: "checklist must contain between 1 and 20 items".to_string(),
+        });
+    }
+    if items.iter().any(|item| item.weight.is_zero()) {
+        return Err(ContractError::CustomError {
+            val: "checklist item weights cannot be zero".to_string(),
+        });
+    }
+    if items.iter().any(|item| item.description.len() > 280) {
+        return Err(ContractError::CustomError {
+            val: "checklist item description cannot be longer than 280 characters".to_string(),
+        });
+    }
+    if items
+        .iter()
+        .try_fold(Decimal::zero(), |acc, item| acc.checked_add(item.weight))?
+        != Decimal::percent(100)
+    {
+        return Err(ContractError::CustomError {
+            val: "checklist item weights must add up to 1".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_bounty_has_no_open_dispute(
+    storage: &dyn Storage,
+    bounty_id: Uint128,
+) -> Result<(), ContractError> {
+    if get_dispute(storage, bounty_id)?.is_some() {
+        return Err(ContractError::BountyHasOpenDispute { bounty_id });
+    }
+    Ok(())
+}
+
+pub fn assert_assignee_share_is_no_greater_than_one(
+    assignee_share: Decimal,
+) -> Result<(), ContractError> {
+    if assignee_share > Decimal::one() {
+        return Err(ContractError::CustomError {
+            val: "assignee share must be less than or equal to 1".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_sender_is_bounty_owner_or_update_delegate(
+    storage: &dyn Storage,
+    bounty: &Bounty,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    if sender != bounty.owner
+        && get_update_permissions(storage, bounty.id, sender)?.is_empty()
+        && !operator_has_permission(storage, bounty.id, sender, &OperatorPermission::UpdateLabel)?
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn assert_sender_can_update_bounty_field(
+    storage: &dyn Storage,
+    bounty: &Bounty,
+    sender: &Addr,
+    field: UpdatableField,
+) -> Result<(), ContractError> {
+    if sender != bounty.owner
+        && !get_update_permissions(storage, bounty.id, sender)?.contains(&field)
+        && !(field == UpdatableField::Label
+            && operator_has_permission(
+                storage,
+                bounty.id,
+                sender,
+                &OperatorPermission::UpdateLabel,
+            )?)
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn assert_funding_round_bounty_ids_are_valid(
+    bounty_ids: &[Uint128],
+) -> Result<(), ContractError> {
+    if bounty_ids.is_empty() || bounty_ids.len() > 20 {
+        return Err(ContractError::CustomError {
+            val: "funding round must include between 1 and 20 bounties".to_string(),
+        });
+    }
+    for (index, bounty_id) in bounty_ids.iter().enumerate() {
+        if bounty_ids[..index].contains(bounty_id) {
+            return Err(ContractError::CustomError {
+                val: format!("bounty with id {} is included more than once", bounty_id),
+            });
+        }
+    }
+    Ok(())
+}
@@ -1,14 +1,21 @@
 use super::{
-    fees::{get_automation_fee_rate, get_swap_fee_rate},
+    fees::{get_automation_fee_rate, get_fee_amount, get_swap_fee_rate},
     price::{get_price, get_slippage, get_twap_to_now},
     time::get_total_execution_duration,
 };
 use crate::{
+    msg::BountiesResponse,
     state::{
-        config::get_config, events::create_event, swap_adjustments::get_swap_adjustment,
+        config::get_config,
+        denom_balances::{delete_denom_balance, get_denom_balances},
+        events::create_event,
+        swap_adjustments::get_swap_adjustment,
         vaults::update_vault,
     },
     types::{
+        bounty::Bounty,
+        bounty_projection::{BountyField, BountyProjection},
+        denom_balance::DenomBalance,
         event::{EventBuilder, EventData, ExecutionSkippedReason},
         performance_assessment_strategy::PerformanceAssessmentStrategy,
         swap_adjustment_strategy::SwapAdjustmentStrategy,
@@ -24,11 +31,32 @@ use shared::coin::add_to;
 use std::cmp::min;
 
 pub fn get_swap_amount(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Coin> {
+    let adjusted_amount = vault.swap_amount * get_clamped_swap_adjustment(deps, env, vault)?;
+
+    let adjusted_amount = match vault.swap_adjustment_strategy {
+        Some(SwapAdjustmentStrategy::WeightedScale {
+            max_swap_amount,
+            min_swap_amount,
+            ..
+        }) => adjusted_amount
+            .max(min_swap_amount.unwrap_or_default())
+            .min(max_swap_amount.unwrap_or(Uint128::MAX)),
+        _ => adjusted_amount,
+    };
+
+    Ok(Coin::new(
+        min(adjusted_amount, vault.balance.amount).into(),
+        vault.get_swap_denom(),
+    ))
+}
+
+pub fn get_clamped_swap_adjustment(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Decimal> {
     let swap_adjustment = match vault.swap_adjustment_strategy.clone() {
         Some(SwapAdjustmentStrategy::WeightedScale {
             base_receive_amount,
             multiplier,
             increase_only,
+            ..
         }) => {
             let config = get_config(deps.storage)?;
 
@@ -36,7 +64,7 @@ pub fn get_swap_amount(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Coin>
                 &deps.querier,
                 config.exchange_contract_address.clone(),
                 vault.get_swap_denom(),
-                vault.target_denom.clone(),
+                vault.target_denom.to_string(),
                 config.twap_period,
                 vault.route.clone(),
             )?;
@@ -62,12 +90,7 @@ pub fn get_swap_amount(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Coin>
         None => Decimal::one(),
     };
 
-    let adjusted_amount = vault.swap_amount * swap_adjustment;
-
-    Ok(Coin::new(
-        min(adjusted_amount, vault.balance.amount).into(),
-        vault.get_swap_denom(),
-    ))
+    Ok(vault.clamp_swap_adjustment(swap_adjustment))
 }
 
 pub fn get_risk_weighted_average_model_id(
@@ -157,7 +180,7 @@ pub fn simulate_standard_dca_execution(
                 storage,
                 config.exchange_contract_address.clone(),
                 Coin::new(swap_amount.into(), vault.get_swap_denom()),
-                vault.target_denom.clone(),
+                vault.target_denom.to_string(),
                 vault.route.clone(),
             )?;
 
@@ -185,7 +208,7 @@ pub fn simulate_standard_dca_execution(
                 querier,
                 config.exchange_contract_address,
                 Coin::new(swap_amount.into(), vault.get_swap_denom()),
-                vault.target_denom.clone(),
+                vault.target_denom.to_string(),
                 belief_price,
                 vault.route.clone(),
             )?;
@@ -208,11 +231,12 @@ pub fn simulate_standard_dca_execution(
                 return Ok((vault, response));
             }
 
-            let fee_rate = get_swap_fee_rate(storage, &vault.swap_adjustment_strategy)?
-                + get_automation_fee_rate(storage, &vault)?;
+            let fee_rate =
+                get_swap_fee_rate(storage, &vault.target_denom, &vault.swap_adjustment_strategy)?
+                    + get_automation_fee_rate(storage, &vault)?;
 
             let received_amount_before_fee = swap_amount * (Decimal::one() / actual_price);
-            let fee_amount = received_amount_before_fee * fee_rate;
+            let fee_amount = get_fee_amount(&config, received_amount_before_fee, fee_rate)?;
             let received_amount_after_fee = received_amount_before_fee - fee_amount;
 
             let vault = update_vault(
@@ -258,6 +282,59 @@ pub fn simulate_standard_dca_execution(
     }
 }
 
+pub fn promote_next_denom_balance(storage: &mut dyn Storage, vault: Vault) -> StdResult<Vault> {
+    if !vault.balance.amount.is_zero() {
+        return Ok(vault);
+    }
+
+    let next_denom_balance = get_denom_balances(storage, vault.id)?
+        .into_iter()
+        .find(|denom_balance| !denom_balance.balance.amount.is_zero());
+
+    match next_denom_balance {
+        Some(denom_balance) => {
+            delete_denom_balance(storage, vault.id, &denom_balance.balance.denom)?;
+            Ok(apply_denom_balance(vault, denom_balance))
+        }
+        None => Ok(vault),
+    }
+}
+
+pub fn apply_denom_balance(vault: Vault, denom_balance: DenomBalance) -> Vault {
+    Vault {
+        balance: denom_balance.balance,
+        deposited_amount: denom_balance.deposited_amount,
+        swap_amount: denom_balance.swap_amount,
+        route: denom_balance.route,
+        ..vault
+    }
+}
+
+pub fn project_bounties(
+    bounties: Vec<Bounty>,
+    fields: Option<Vec<String>>,
+) -> StdResult<BountiesResponse> {
+    match fields {
+        Some(fields) => {
+            let fields = BountyField::parse_all(&fields)?;
+
+            Ok(BountiesResponse {
+                bounties: vec![],
+                projections: Some(
+                    bounties
+                        .into_iter()
+                        .map(|bounty| BountyProjection::from_bounty(bounty, &fields))
+                        .collect(),
+                ),
+            })
+        }
+        None => Ok(BountiesResponse {
+            bounties,
+            projections: None,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod get_swap_amount_tests {
     use super::*;
@@ -459,6 +536,8 @@ mod get_swap_amount_tests {
                     base_receive_amount,
                     multiplier,
                     increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
                 }),
                 ..Vault::default()
             },
@@ -500,6 +579,8 @@ mod get_swap_amount_tests {
                     base_receive_amount,
                     multiplier,
                     increase_only: true,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
                 }),
                 ..Vault::default()
             },
@@ -528,6 +609,8 @@ mod get_swap_amount_tests {
                     base_receive_amount,
                     multiplier,
                     increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
                 }),
                 ..Vault::default()
             },
@@ -565,6 +648,8 @@ mod get_swap_amount_tests {
                     base_receive_amount,
                     multiplier,
                     increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: None,
                 }),
                 ..Vault::default()
             },
@@ -576,6 +661,66 @@ mod get_swap_amount_tests {
 
         assert_eq!(swap_amount.amount, Uint128::zero());
     }
+
+    #[test]
+    fn ws_should_cap_increased_swap_amount_at_max_swap_amount() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(150),
+                    increase_only: false,
+                    max_swap_amount: Some(Vault::default().swap_amount),
+                    min_swap_amount: None,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&HALF_DECIMAL);
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.swap_amount);
+    }
+
+    #[test]
+    fn ws_should_floor_decreased_swap_amount_at_min_swap_amount() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let min_swap_amount = Vault::default().swap_amount / Uint128::new(2);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(300),
+                    increase_only: false,
+                    max_swap_amount: None,
+                    min_swap_amount: Some(min_swap_amount),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&TWO_DECIMAL);
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, min_swap_amount);
+    }
 }
 
 #[cfg(test)]
@@ -1042,6 +1187,7 @@ mod simulate_standard_dca_execution_tests {
 
         let fee_rate = get_swap_fee_rate(
             storage_deps.as_ref().storage,
+            &vault.target_denom,
             &vault.swap_adjustment_strategy,
         )
         .unwrap()
@@ -1100,6 +1246,7 @@ mod simulate_standard_dca_execution_tests {
 
         let fee_rate = get_swap_fee_rate(
             storage_deps.as_ref().storage,
+            &vault.target_denom,
             &vault.swap_adjustment_strategy,
         )
         .unwrap()
@@ -1129,3 +1276,67 @@ mod simulate_standard_dca_execution_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod promote_next_denom_balance_tests {
+    use super::promote_next_denom_balance;
+    use crate::{
+        state::denom_balances::{get_denom_balance, save_denom_balance},
+        types::{denom_balance::DenomBalance, vault::Vault},
+    };
+    use cosmwasm_std::{testing::mock_dependencies, Coin, Uint128};
+
+    #[test]
+    fn keeps_primary_balance_when_not_exhausted() {
+        let mut deps = mock_dependencies();
+
+        let vault = Vault::default();
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            vault.id,
+            &DenomBalance {
+                balance: Coin::new(100, "uatom"),
+                deposited_amount: Coin::new(100, "uatom"),
+                swap_amount: Uint128::new(10),
+                route: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            promote_next_denom_balance(deps.as_mut().storage, vault.clone()).unwrap(),
+            vault
+        );
+    }
+
+    #[test]
+    fn promotes_next_funded_denom_when_primary_balance_exhausted() {
+        let mut deps = mock_dependencies();
+
+        let vault = Vault {
+            balance: Coin::new(0, Vault::default().balance.denom),
+            ..Vault::default()
+        };
+
+        let denom_balance = DenomBalance {
+            balance: Coin::new(100, "uatom"),
+            deposited_amount: Coin::new(100, "uatom"),
+            swap_amount: Uint128::new(10),
+            route: None,
+        };
+
+        save_denom_balance(deps.as_mut().storage, vault.id, &denom_balance).unwrap();
+
+        let promoted_vault =
+            promote_next_denom_balance(deps.as_mut().storage, vault.clone()).unwrap();
+
+        assert_eq!(promoted_vault.balance, denom_balance.balance);
+        assert_eq!(promoted_vault.deposited_amount, denom_balance.deposited_amount);
+        assert_eq!(promoted_vault.swap_amount, denom_balance.swap_amount);
+        assert_eq!(
+            get_denom_balance(deps.as_ref().storage, vault.id, "uatom").unwrap(),
+            None
+        );
+    }
+}
@@ -1,15 +1,44 @@
+use crate::types::admin_action::AdminAction;
+use crate::types::application::Application;
+use crate::types::assignee::Assignee;
+use crate::types::authz::OperatorPermission;
+use crate::types::bounty_projection::BountyProjection;
+use crate::types::bounty_snapshot::BountySnapshot;
+use crate::types::callback_result::CallbackResult;
+use crate::types::cancel_reason::CancelReason;
+use crate::types::checklist::{ChecklistItem, ChecklistItemParams};
 use crate::types::config::Config;
+use crate::types::denom::Denom;
+use crate::types::denom_balance::DenomBalance;
 use crate::types::destination::Destination;
-use crate::types::event::Event;
+use crate::types::disbursement_schedule::DisbursementSchedule;
+use crate::types::dispute::{Dispute, Ruling};
+use crate::types::escrow_epoch::EscrowEpoch;
+use crate::types::escrow_tranche::{EscrowTranche, EscrowTrancheSchedule};
+use crate::types::event::{Event, EventOrder};
+use crate::types::event_summary::EventSummary;
+use crate::types::exchange::{Exchange, PairExchange};
+use crate::types::execution_receipt::{ExecutionWeight, GasUsageReport};
+use crate::types::executor_stats::ExecutorStats;
 use crate::types::fee_collector::FeeCollector;
+use crate::types::fee_grant_config::FeeGrantConfig;
+use crate::types::funding_round::{FundingRound, MatchingFormula, RoundContribution};
 use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategyParams;
+use crate::types::progress_update::ProgressUpdate;
+use crate::types::role::Role;
+use crate::types::rounding_mode::RoundingMode;
+use crate::types::scheduled_config_update::{ConfigChanges, ScheduledConfigUpdate};
+use crate::types::scheduled_payment::ScheduledPayment;
 use crate::types::swap_adjustment_strategy::{
     SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
+use crate::types::template::{BountyTemplate, BountyTemplateOverrides, BountyTemplateParams};
 use crate::types::time_interval::TimeInterval;
+use crate::types::trigger::{BalanceThreshold, Trigger};
+use crate::types::updatable_field::UpdatableField;
 use crate::types::bounty::{Bounty, BountyStatus};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Coin, Decimal, Uint128, Uint64};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Timestamp, Uint128, Uint64};
 use cw20::Cw20ReceiveMsg;
 use exchange::msg::Pair;
 
@@ -21,6 +50,23 @@ pub struct InstantiateMsg {
     pub automation_fee_percent: Decimal,
     pub paused: bool,
     pub exchange_contract_address: Addr,
+    pub arbiter: Option<Addr>,
+    pub fee_grant: Option<FeeGrantConfig>,
+    pub simulation_mode: bool,
+    pub referral_fee_percent: Decimal,
+    pub executor_reward_percent: Decimal,
+    pub minimum_deposit: Vec<Coin>,
+    pub minimum_swap_amount: Uint128,
+    pub event_retention_blocks: Option<u64>,
+    pub max_bounty_balance: Vec<Coin>,
+    pub max_total_balance_per_owner: Vec<Coin>,
+    pub exchange_adapters: Vec<Addr>,
+    pub auto_route_safety_margin: Decimal,
+    pub price_oracle_address: Option<Addr>,
+    pub rounding_mode: RoundingMode,
+    pub address_prefix: Option<String>,
+    pub slippage_retry_interval_seconds: u64,
+    pub max_slippage_retries: u8,
 }
 
 #[cw_serde]
@@ -31,6 +77,24 @@ pub struct MigrateMsg {
     pub automation_fee_percent: Decimal,
     pub paused: bool,
     pub exchange_contract_address: Addr,
+    pub arbiter: Option<Addr>,
+    pub fee_grant: Option<FeeGrantConfig>,
+    pub simulation_mode: bool,
+    pub referral_fee_percent: Decimal,
+    pub executor_reward_percent: Decimal,
+    pub minimum_deposit: Vec<Coin>,
+    pub minimum_swap_amount: Uint128,
+    pub event_retention_blocks: Option<u64>,
+    pub max_bounty_balance: Vec<Coin>,
+    pub max_total_balance_per_owner: Vec<Coin>,
+    pub exchange_adapters: Vec<Addr>,
+    pub auto_route_safety_margin: Decimal,
+    pub price_oracle_address: Option<Addr>,
+    pub rounding_mode: RoundingMode,
+    pub address_prefix: Option<String>,
+    pub slippage_retry_interval_seconds: u64,
+    pub max_slippage_retries: u8,
+    pub legacy_vault_migration_limit: Option<u16>,
 }
 
 #[cw_serde]
@@ -40,9 +104,27 @@ pub enum ExecuteMsg {
         label: Option<String>,
         bounty_description: Option<String>,
         destinations: Option<Vec<Destination>>, // Destination is in types and consists of allocation, address, and msg. 
-        target_denom: String,
+        target_denom: Denom,
         route: Option<Binary>,
         slippage_tolerance: Option<Decimal>,
+        exact_receive_amount: Option<Uint128>,
+        expires_at: Option<Timestamp>,
+        disbursement_schedule: Option<DisbursementSchedule>,
+        private_metadata: Option<bool>,
+        referral_code: Option<String>,
+        executor_slippage_override_cap: Option<Decimal>,
+        fallback_routes: Option<Vec<Binary>>,
+        tags: Option<Vec<String>>,
+        metadata_uri: Option<String>,
+        metadata_hash: Option<[u8; 32]>,
+        minimum_price: Option<Decimal>,
+        maximum_price: Option<Decimal>,
+        escrow_available_after: Option<Timestamp>,
+        min_adjustment: Option<Decimal>,
+        max_adjustment: Option<Decimal>,
+        lock_period: Option<u64>,
+        balance_threshold: Option<BalanceThreshold>,
+        target_block_height: Option<u64>,
         // minimum_receive_amount: Option<Uint128>,
         pay_amount: Uint128,
         // time_interval: TimeInterval,
@@ -53,26 +135,74 @@ pub enum ExecuteMsg {
         address: Addr,
         bounty_id: Uint128,
     },
+    Withdraw {
+        bounty_id: Uint128,
+        amount: Uint128,
+    },
     UpdateBounty {
         bounty_id: Uint128,
         label: Option<String>,
         bounty_description: Option<String>,
+        tags: Option<Vec<String>>,
+        metadata_uri: Option<String>,
+        metadata_hash: Option<[u8; 32]>,
         destinations: Option<Vec<Destination>>,
         slippage_tolerance: Option<Decimal>,
         // minimum_receive_amount: Option<Uint128>,
         // time_interval: Option<TimeInterval>,
         // swap_adjustment_strategy: Option<SwapAdjustmentStrategyParams>,
         // swap_amount: Option<Uint128>,
+        target_denom: Option<Denom>,
+        route: Option<Binary>,
+        escrow_level: Option<Decimal>,
+    },
+    BulkUpdateSlippage {
+        bounty_ids: Vec<Uint128>,
+        slippage_tolerance: Decimal,
     },
     CancelBounty {
         bounty_id: Uint128,
+        reason: Option<CancelReason>,
+        note: Option<String>,
+    },
+    CancelAllBounties {
+        status: Option<BountyStatus>,
+        start_after: Option<Uint128>,
+        limit: Option<u16>,
+    },
+    ProcessRefundQueue {
+        limit: Option<u16>,
+    },
+    PauseBounty {
+        bounty_id: Uint128,
+    },
+    ResumeBounty {
+        bounty_id: Uint128,
+    },
+    RepriceTrigger {
+        bounty_id: Uint128,
+        new_target_price: Decimal,
+    },
+    AddFundingDenom {
+        bounty_id: Uint128,
+        denom: Denom,
+        swap_amount: Uint128,
+        route: Option<Binary>,
     },
     ExecuteTrigger {
         trigger_id: Uint128,
         route: Option<Binary>,
+        slippage_override: Option<Decimal>,
+        on_behalf_of: Option<Addr>,
+    },
+    ExecuteTriggers {
+        trigger_ids: Vec<Uint128>,
+        route: Option<Binary>,
+    },
+    ProbeTrigger {
+        trigger_id: Uint128,
     },
     UpdateConfig {
-        executors: Option<Vec<Addr>>,
         fee_collectors: Option<Vec<FeeCollector>>,
        // default_swap_fee_percent: Option<Decimal>,
        //  weighted_scale_swap_fee_percent: Option<Decimal>,
@@ -83,18 +213,248 @@ pub enum ExecuteMsg {
         // twap_period: Option<u64>,
         default_slippage_tolerance: Option<Decimal>,
         exchange_contract_address: Option<Addr>,
+        /// Omit to keep the current arbiter, or pass `null` to remove it.
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::util::deserialize_clearable"
+        )]
+        arbiter: Option<Option<Addr>>,
+        fee_grant: Option<FeeGrantConfig>,
+        referral_fee_percent: Option<Decimal>,
+        executor_reward_percent: Option<Decimal>,
+        minimum_deposit: Option<Vec<Coin>>,
+        minimum_swap_amount: Option<Uint128>,
+        event_retention_blocks: Option<u64>,
+        max_bounty_balance: Option<Vec<Coin>>,
+        max_total_balance_per_owner: Option<Vec<Coin>>,
+        exchange_adapters: Option<Vec<Addr>>,
+        auto_route_safety_margin: Option<Decimal>,
+        price_oracle_address: Option<Addr>,
+        rounding_mode: Option<RoundingMode>,
+        address_prefix: Option<String>,
+        slippage_retry_interval_seconds: Option<u64>,
+        max_slippage_retries: Option<u8>,
     },
    // UpdateSwapAdjustment {
      //   strategy: SwapAdjustmentStrategy,
        // value: Decimal,
    // },
+    ScheduleConfigUpdate {
+        at: Timestamp,
+        changes: ConfigChanges,
+    },
+    CancelScheduledConfigUpdate {
+        scheduled_config_update_id: u64,
+    },
     DisburseEscrow {
         bounty_id: Uint128,
     },
+    RetryFailedIbcTransfers {
+        bounty_id: Uint128,
+    },
+    RefundFailedIbcTransfers {
+        bounty_id: Uint128,
+    },
     ZDelegate {
         delegator_address: Addr,
         validator_address: Addr,
     },
+    AssignBounty {
+        bounty_id: Uint128,
+        assignee: Addr,
+        weight: Decimal,
+    },
+    UnassignBounty {
+        bounty_id: Uint128,
+        assignee: Addr,
+    },
+    SubmitWork {
+        bounty_id: Uint128,
+        submission: String,
+    },
+    RequestEscrowRelease {
+        bounty_id: Uint128,
+    },
+    PostProgressUpdate {
+        bounty_id: Uint128,
+        message_hash: [u8; 32],
+        uri: Option<String>,
+    },
+    SetPayoutAddress {
+        bounty_id: Uint128,
+        address: Addr,
+    },
+    ApplyToBounty {
+        bounty_id: Uint128,
+        message: String,
+    },
+    WithdrawApplication {
+        bounty_id: Uint128,
+    },
+    RegisterReferrer {
+        code: String,
+        address: Addr,
+    },
+    SaveBountyTemplate {
+        label: String,
+        params: BountyTemplateParams,
+    },
+    DeleteBountyTemplate {
+        template_id: u64,
+    },
+    CreateBountyFromTemplate {
+        template_id: u64,
+        overrides: Option<BountyTemplateOverrides>,
+    },
+    SetViewingKey {
+        bounty_id: Uint128,
+        key: String,
+    },
+    AcceptApplication {
+        bounty_id: Uint128,
+        applicant: Addr,
+    },
+    ClaimVested {
+        bounty_id: Uint128,
+    },
+    SetChecklist {
+        bounty_id: Uint128,
+        items: Vec<ChecklistItemParams>,
+    },
+    SetBountySnapshots {
+        bounty_id: Uint128,
+        enabled: bool,
+    },
+    CompleteChecklistItem {
+        bounty_id: Uint128,
+        item_index: u32,
+    },
+    SetEscrowTranches {
+        bounty_id: Uint128,
+        tranches: Vec<EscrowTranche>,
+    },
+    CreateFundingRound {
+        bounty_ids: Vec<Uint128>,
+        formula: MatchingFormula,
+        ends_at: Timestamp,
+    },
+    ContributeToRound {
+        round_id: u64,
+        bounty_id: Uint128,
+    },
+    FinalizeRound {
+        round_id: u64,
+    },
+    RaiseDispute {
+        bounty_id: Uint128,
+        reason: String,
+    },
+    ResolveDispute {
+        bounty_id: Uint128,
+        ruling: Ruling,
+    },
+    SetCustomSwapFee {
+        denom: String,
+        swap_fee_percent: Decimal,
+    },
+    RemoveCustomSwapFee {
+        denom: String,
+    },
+    AddFeeExemption {
+        address: Addr,
+    },
+    RemoveFeeExemption {
+        address: Addr,
+    },
+    WhitelistCw20Token {
+        token: Addr,
+    },
+    RemoveCw20Token {
+        token: Addr,
+    },
+    AddAllowedDenom {
+        denom: Denom,
+    },
+    RemoveAllowedDenom {
+        denom: Denom,
+    },
+    PruneEvents {
+        resource_id: Uint128,
+        before_block: u64,
+    },
+    ReportExecutor {
+        address: Addr,
+        reason: String,
+    },
+    GrantRole {
+        address: Addr,
+        role: Role,
+    },
+    RevokeRole {
+        address: Addr,
+        role: Role,
+    },
+    SetPaused {
+        paused: bool,
+    },
+    AddModelProvider {
+        provider: Addr,
+    },
+    RemoveModelProvider {
+        provider: Addr,
+    },
+    AddExchange {
+        address: Addr,
+        weight: u64,
+    },
+    RemoveExchange {
+        address: Addr,
+    },
+    SetPairExchange {
+        swap_denom: String,
+        target_denom: String,
+        exchange: Option<Addr>,
+    },
+    SubmitModelAdjustment {
+        model_id: u8,
+        value: Decimal,
+    },
+    SetCircuitBreakers {
+        create_paused: Option<bool>,
+        execute_paused: Option<bool>,
+        deposit_paused: Option<bool>,
+        disburse_paused: Option<bool>,
+    },
+    ProposeNewAdmin {
+        address: Addr,
+    },
+    AcceptAdmin {},
+    SetUpdatePermissions {
+        bounty_id: Uint128,
+        delegate: Addr,
+        fields: Vec<UpdatableField>,
+    },
+    GrantBountyOperator {
+        bounty_id: Uint128,
+        operator: Addr,
+        permissions: Vec<OperatorPermission>,
+    },
+    RevokeBountyOperator {
+        bounty_id: Uint128,
+        operator: Addr,
+    },
+    SchedulePayment {
+        to: Addr,
+        amount: Coin,
+        at: Timestamp,
+    },
+    ExecuteScheduledPayment {
+        payment_id: u64,
+    },
+    CancelScheduledPayment {
+        payment_id: u64,
+    },
     Receive(Cw20ReceiveMsg),
 }
 
@@ -103,6 +463,22 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(ConfigResponse)]
     GetConfig {},
+    #[returns(ScheduledConfigUpdatesResponse)]
+    GetScheduledConfigUpdates {},
+    #[returns(CustomFeesResponse)]
+    GetCustomSwapFees {},
+    #[returns(FeeExemptionsResponse)]
+    GetFeeExemptions {},
+    #[returns(Cw20WhitelistResponse)]
+    GetCw20Whitelist {},
+    #[returns(AllowedDenomsResponse)]
+    GetAllowedDenoms {},
+    #[returns(AdminActionsResponse)]
+    GetAdminActions {
+        start_after: Option<u64>,
+        limit: Option<u16>,
+        reverse: Option<bool>,
+    },
     #[returns(PairsResponse)]
     GetPairs {
         start_after: Option<Pair>,
@@ -110,22 +486,61 @@ pub enum QueryMsg {
     },
     #[returns(TriggerIdsResponse)]
     GetTimeTriggerIds { limit: Option<u16> },
+    #[returns(TriggerIdsResponse)]
+    GetBalanceTriggerIds { limit: Option<u16> },
+    #[returns(TriggerIdsResponse)]
+    GetBlockTriggerIds { limit: Option<u16> },
+    #[returns(TriggerResponse)]
+    GetTrigger { bounty_id: Uint128 },
+    #[returns(TriggersResponse)]
+    GetTriggersDue {
+        from: Timestamp,
+        to: Timestamp,
+        limit: Option<u16>,
+    },
     #[returns(TriggerIdResponse)]
     GetTriggerIdByFinLimitOrderIdx { order_idx: Uint128 },
     #[returns(BountyResponse)]
     GetBounty { vault_id: Uint128 },
+    #[returns(BountyResponse)]
+    GetBountyWithKey { bounty_id: Uint128, key: String },
+    #[returns(CallbackResultsResponse)]
+    GetCallbackResults {
+        bounty_id: Uint128,
+        key: String,
+        execution_height: Option<u64>,
+    },
     #[returns(BountiesResponse)]
     GetBountiesByAddress {
         address: Addr,
         status: Option<BountyStatus>,
         start_after: Option<Uint128>,
         limit: Option<u16>,
+        reverse: Option<bool>,
+        fields: Option<Vec<String>>,
+    },
+    #[returns(OwnerDigestResponse)]
+    GetOwnerDigest { address: Addr },
+    #[returns(BountiesResponse)]
+    GetBountiesByStatus {
+        status: BountyStatus,
+        start_after: Option<Uint128>,
+        limit: Option<u16>,
+        reverse: Option<bool>,
+    },
+    #[returns(BountiesResponse)]
+    GetBountiesByTag {
+        tag: String,
+        start_after: Option<Uint128>,
+        limit: Option<u16>,
+        reverse: Option<bool>,
     },
     #[returns(BountiesResponse)]
     GetBounties {
         start_after: Option<Uint128>,
         limit: Option<u16>,
         reverse: Option<bool>,
+        fields: Option<Vec<String>>,
     },
     #[returns(EventsResponse)]
     GetEventsByResourceId {
@@ -134,16 +549,94 @@ pub enum QueryMsg {
         limit: Option<u16>,
         reverse: Option<bool>,
     },
+    #[returns(EventSummaryResponse)]
+    GetEventSummary { resource_id: Uint128 },
     #[returns(EventsResponse)]
     GetEvents {
         start_after: Option<u64>,
         limit: Option<u16>,
         reverse: Option<bool>,
+        order_by: Option<EventOrder>,
+        after_timestamp: Option<Timestamp>,
     },
     #[returns(BountyPerformanceResponse)]
-    GetBountyPerformance { bounty_id: Uint128 },
+    GetBountyPerformance {
+        bounty_id: Uint128,
+        at_price: Option<Decimal>,
+    },
+    #[returns(ConversionReportResponse)]
+    GetConversionReport { bounty_id: Uint128 },
+    #[returns(GasUsageReportResponse)]
+    GetGasUsageReport { bounty_id: Uint128 },
+    #[returns(BountyBalancesResponse)]
+    GetBountyBalances { bounty_id: Uint128 },
+    #[returns(BountySnapshotsResponse)]
+    GetBountySnapshots {
+        bounty_id: Uint128,
+        start_after: Option<Timestamp>,
+        limit: Option<u16>,
+        reverse: Option<bool>,
+    },
+    #[returns(ExecutionSimulationResponse)]
+    SimulateExecution { bounty_id: Uint128 },
     #[returns(DisburseEscrowTasksResponse)]
     GetDisburseEscrowTasks { limit: Option<u16> },
+    #[returns(AssigneesResponse)]
+    GetAssignees { bounty_id: Uint128 },
+    #[returns(EscrowEpochsResponse)]
+    GetEscrowEpochs { bounty_id: Uint128 },
+    #[returns(EscrowTranchesResponse)]
+    GetEscrowTranches { bounty_id: Uint128 },
+    #[returns(ChecklistResponse)]
+    GetChecklist { bounty_id: Uint128 },
+    #[returns(TopAssigneesResponse)]
+    GetTopAssignees {
+        period_start: Timestamp,
+        period_end: Timestamp,
+        limit: Option<u16>,
+    },
+    #[returns(FundingRoundResponse)]
+    GetFundingRound { round_id: u64 },
+    #[returns(RoundContributionsResponse)]
+    GetRoundContributions { round_id: u64, bounty_id: Uint128 },
+    #[returns(ApplicationsResponse)]
+    GetApplications { bounty_id: Uint128 },
+    #[returns(ProgressUpdatesResponse)]
+    GetProgressUpdates {
+        bounty_id: Uint128,
+        start_after: Option<u64>,
+        limit: Option<u16>,
+        reverse: Option<bool>,
+    },
+    #[returns(ReferrerStatsResponse)]
+    GetReferrerStats { address: Addr },
+    #[returns(ExecutorStatsResponse)]
+    GetExecutorStats { address: Addr },
+    #[returns(RolesResponse)]
+    GetRoles { address: Addr },
+    #[returns(ModelAdjustmentResponse)]
+    GetModelAdjustment { model_id: u8 },
+    #[returns(ModelProvidersResponse)]
+    GetModelProviders {},
+    #[returns(ExchangesResponse)]
+    GetExchanges {},
+    #[returns(DisputeResponse)]
+    GetDispute { bounty_id: Uint128 },
+    #[returns(BountyTemplateResponse)]
+    GetBountyTemplate { template_id: u64 },
+    #[returns(UpdatePermissionsResponse)]
+    GetUpdatePermissions { bounty_id: Uint128, delegate: Addr },
+    #[returns(ScheduledPaymentResponse)]
+    GetScheduledPayment { payment_id: u64 },
+    #[returns(ScheduledPaymentsResponse)]
+    GetScheduledPaymentsByOwner {
+        owner: Addr,
+        start_after: Option<u64>,
+        limit: Option<u16>,
+        reverse: Option<bool>,
+    },
+    #[returns(ScheduledPaymentsResponse)]
+    GetDueScheduledPayments { limit: Option<u16> },
 }
 
 #[cw_serde]
@@ -151,6 +644,16 @@ pub struct ConfigResponse {
     pub config: Config,
 }
 
+#[cw_serde]
+pub struct ScheduledConfigUpdatesResponse {
+    pub scheduled_config_updates: Vec<ScheduledConfigUpdate>,
+}
+
+#[cw_serde]
+pub struct AdminActionsResponse {
+    pub admin_actions: Vec<AdminAction>,
+}
+
 #[cw_serde]
 pub struct PairsResponse {
     pub pairs: Vec<Pair>,
@@ -166,20 +669,92 @@ pub struct TriggerIdsResponse {
     pub trigger_ids: Vec<Uint128>,
 }
 
+#[cw_serde]
+pub struct TriggerResponse {
+    pub trigger: Trigger,
+}
+
+#[cw_serde]
+pub struct TriggersResponse {
+    pub triggers: Vec<Trigger>,
+}
+
 #[cw_serde]
 pub struct BountyResponse {
     pub bounty: Bounty,
+    pub locked_until: Option<Timestamp>,
 }
 
 #[cw_serde]
 pub struct BountyPerformanceResponse {
     pub fee: Coin,
     pub factor: Decimal,
+    pub price_oracle: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct ConversionReportResponse {
+    pub executions: u64,
+    pub total_sent: Coin,
+    pub total_received: Coin,
+    pub fees_paid: Coin,
+    pub average_price: Option<Decimal>,
+    pub twap_received: Coin,
+    pub twap_average_price: Option<Decimal>,
+}
+
+#[cw_serde]
+pub struct DestinationAmount {
+    pub address: Addr,
+    pub amount: Coin,
+}
+
+#[cw_serde]
+pub struct ExecutionSimulationResponse {
+    pub sent: Coin,
+    pub received: Coin,
+    pub swap_fee: Coin,
+    pub automation_fee: Coin,
+    pub escrowed: Coin,
+    pub price: Decimal,
+    pub destinations: Vec<DestinationAmount>,
+}
+
+#[cw_serde]
+pub struct GasUsageReportResponse {
+    pub report: GasUsageReport,
+    pub average_estimated_gas: u64,
+    pub last_weight: Option<ExecutionWeight>,
+}
+
+#[cw_serde]
+pub struct BountyStatusCount {
+    pub status: BountyStatus,
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct OwnerDigestResponse {
+    pub status_counts: Vec<BountyStatusCount>,
+    pub total_locked: Vec<Coin>,
+    pub next_trigger_time: Option<Timestamp>,
+    pub escrow_pending: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct BountyBalancesResponse {
+    pub balances: Vec<DenomBalance>,
+}
+
+#[cw_serde]
+pub struct BountySnapshotsResponse {
+    pub snapshots: Vec<BountySnapshot>,
 }
 
 #[cw_serde]
 pub struct BountiesResponse {
     pub bounties: Vec<Bounty>,
+    pub projections: Option<Vec<BountyProjection>>,
 }
 
 #[cw_serde]
@@ -192,7 +767,142 @@ pub struct CustomFeesResponse {
     pub custom_fees: Vec<(String, Decimal)>,
 }
 
+#[cw_serde]
+pub struct FeeExemptionsResponse {
+    pub addresses: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct Cw20WhitelistResponse {
+    pub tokens: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct CallbackResultsResponse {
+    pub results: Vec<CallbackResult>,
+}
+
+#[cw_serde]
+pub struct EventSummaryResponse {
+    pub summary: Option<EventSummary>,
+}
+
+#[cw_serde]
+pub struct AllowedDenomsResponse {
+    pub denoms: Vec<Denom>,
+}
+
 #[cw_serde]
 pub struct DisburseEscrowTasksResponse {
     pub bounty_ids: Vec<Uint128>,
 }
+
+#[cw_serde]
+pub struct ScheduledPaymentResponse {
+    pub scheduled_payment: ScheduledPayment,
+}
+
+#[cw_serde]
+pub struct ScheduledPaymentsResponse {
+    pub scheduled_payments: Vec<ScheduledPayment>,
+}
+
+#[cw_serde]
+pub struct AssigneesResponse {
+    pub assignees: Vec<Assignee>,
+}
+
+#[cw_serde]
+pub struct EscrowEpochsResponse {
+    pub epochs: Vec<EscrowEpoch>,
+}
+
+#[cw_serde]
+pub struct EscrowTranchesResponse {
+    pub schedule: Option<EscrowTrancheSchedule>,
+}
+
+#[cw_serde]
+pub struct ChecklistResponse {
+    pub items: Vec<ChecklistItem>,
+}
+
+#[cw_serde]
+pub struct AssigneeRanking {
+    pub address: Addr,
+    pub completions: u32,
+}
+
+#[cw_serde]
+pub struct TopAssigneesResponse {
+    pub assignees: Vec<AssigneeRanking>,
+}
+
+#[cw_serde]
+pub struct FundingRoundResponse {
+    pub funding_round: FundingRound,
+}
+
+#[cw_serde]
+pub struct RoundContributionsResponse {
+    pub contributions: Vec<RoundContribution>,
+}
+
+#[cw_serde]
+pub struct ApplicationsResponse {
+    pub applications: Vec<Application>,
+}
+
+#[cw_serde]
+pub struct ProgressUpdatesResponse {
+    pub progress_updates: Vec<ProgressUpdate>,
+}
+
+#[cw_serde]
+pub struct ReferrerStatsResponse {
+    pub referred_bounties: u64,
+    pub earnings: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct ExecutorStatsResponse {
+    pub stats: ExecutorStats,
+}
+
+#[cw_serde]
+pub struct RolesResponse {
+    pub roles: Vec<Role>,
+}
+
+#[cw_serde]
+pub struct ModelAdjustmentResponse {
+    pub model_id: u8,
+    pub value: Option<Decimal>,
+    pub submissions: u32,
+}
+
+#[cw_serde]
+pub struct ModelProvidersResponse {
+    pub providers: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct ExchangesResponse {
+    pub exchanges: Vec<Exchange>,
+    pub pair_exchanges: Vec<PairExchange>,
+}
+
+#[cw_serde]
+pub struct DisputeResponse {
+    pub dispute: Option<Dispute>,
+}
+
+#[cw_serde]
+pub struct BountyTemplateResponse {
+    pub template: BountyTemplate,
+}
+
+#[cw_serde]
+pub struct UpdatePermissionsResponse {
+    pub fields: Vec<UpdatableField>,
+}
@@ -0,0 +1,110 @@
+use super::{keys, state_helpers::fetch_and_increment_counter};
+use crate::helpers::pagination::Page;
+use crate::types::admin_action::{AdminAction, AdminActionBuilder};
+use cosmwasm_std::{StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+const ADMIN_ACTION_COUNTER: Item<u64> = Item::new(keys::ADMIN_ACTION_COUNTER);
+
+const ADMIN_ACTIONS: Map<u64, AdminAction> = Map::new(keys::ADMIN_ACTIONS);
+
+pub fn create_admin_action(
+    store: &mut dyn Storage,
+    admin_action_builder: AdminActionBuilder,
+) -> StdResult<u64> {
+    let admin_action =
+        admin_action_builder.build(fetch_and_increment_counter(store, ADMIN_ACTION_COUNTER)?);
+    ADMIN_ACTIONS.save(store, admin_action.id, &admin_action)?;
+    Ok(admin_action.id)
+}
+
+pub fn get_admin_actions(
+    store: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<Vec<AdminAction>> {
+    let page = Page::new(store, start_after, limit, reverse)?;
+    let (min, max) = page.bounds();
+
+    ADMIN_ACTIONS
+        .range(store, min, max, page.order)
+        .take(page.limit)
+        .map(|result| result.map(|(_, admin_action)| admin_action))
+        .collect::<StdResult<Vec<AdminAction>>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use crate::types::admin_action::AdminActionData;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, Uint128};
+
+    #[test]
+    fn appends_admin_actions_in_order() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for bounty_id in 1..4 {
+            create_admin_action(
+                deps.as_mut().storage,
+                AdminActionBuilder::new(
+                    Addr::unchecked(ADMIN),
+                    env.block.clone(),
+                    AdminActionData::BountyCancelled {
+                        bounty_id: Uint128::new(bounty_id),
+                    },
+                ),
+            )
+            .unwrap();
+        }
+
+        let admin_actions = get_admin_actions(deps.as_ref().storage, None, None, None).unwrap();
+
+        assert_eq!(
+            admin_actions
+                .iter()
+                .map(|admin_action| admin_action.id)
+                .collect::<Vec<u64>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn fetches_admin_actions_after_start_after_with_limit() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for bounty_id in 1..6 {
+            create_admin_action(
+                deps.as_mut().storage,
+                AdminActionBuilder::new(
+                    Addr::unchecked(ADMIN),
+                    env.block.clone(),
+                    AdminActionData::BountyCancelled {
+                        bounty_id: Uint128::new(bounty_id),
+                    },
+                ),
+            )
+            .unwrap();
+        }
+
+        let admin_actions =
+            get_admin_actions(deps.as_ref().storage, Some(2), Some(2), None).unwrap();
+
+        assert_eq!(
+            admin_actions
+                .iter()
+                .map(|admin_action| admin_action.id)
+                .collect::<Vec<u64>>(),
+            vec![3, 4]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use super::keys;
+use crate::types::denom::Denom;
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const ALLOWED_DENOMS: Map<String, ()> = Map::new(keys::ALLOWED_DENOMS);
+
+pub fn save_allowed_denom(store: &mut dyn Storage, denom: &Denom) -> StdResult<()> {
+    ALLOWED_DENOMS.save(store, denom.to_string(), &())
+}
+
+pub fn is_denom_allowed(store: &dyn Storage, denom: &str) -> bool {
+    ALLOWED_DENOMS.is_empty(store) || ALLOWED_DENOMS.has(store, denom.to_string())
+}
+
+pub fn get_allowed_denoms(store: &dyn Storage) -> StdResult<Vec<Denom>> {
+    ALLOWED_DENOMS
+        .keys(store, None, None, Order::Ascending)
+        .map(|denom| denom.and_then(Denom::new))
+        .collect::<StdResult<Vec<Denom>>>()
+}
+
+pub fn remove_allowed_denom(store: &mut dyn Storage, denom: &Denom) {
+    ALLOWED_DENOMS.remove(store, denom.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn allows_any_denom_when_registry_is_empty() {
+        let deps = mock_dependencies();
+
+        assert!(is_denom_allowed(&deps.storage, "uatom"));
+    }
+
+    #[test]
+    fn only_allows_registered_denoms_when_registry_is_not_empty() {
+        let mut deps = mock_dependencies();
+
+        save_allowed_denom(&mut deps.storage, &Denom::new("ukuji").unwrap()).unwrap();
+
+        assert!(is_denom_allowed(&deps.storage, "ukuji"));
+        assert!(!is_denom_allowed(&deps.storage, "uatom"));
+    }
+}
@@ -0,0 +1,85 @@
+use super::keys;
+use crate::types::application::Application;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const BOUNTY_APPLICATIONS: Map<(u128, Addr), Application> = Map::new(keys::BOUNTY_APPLICATIONS);
+
+pub fn save_application(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    application: &Application,
+) -> StdResult<()> {
+    BOUNTY_APPLICATIONS.save(
+        store,
+        (bounty_id.into(), application.applicant.clone()),
+        application,
+    )
+}
+
+pub fn get_application(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    applicant: &Addr,
+) -> StdResult<Option<Application>> {
+    BOUNTY_APPLICATIONS.may_load(store, (bounty_id.into(), applicant.clone()))
+}
+
+pub fn get_applications(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Vec<Application>> {
+    BOUNTY_APPLICATIONS
+        .prefix(bounty_id.into())
+        .range(store, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, application)| application))
+        .collect::<StdResult<Vec<Application>>>()
+}
+
+pub fn delete_application(store: &mut dyn Storage, bounty_id: Uint128, applicant: &Addr) {
+    BOUNTY_APPLICATIONS.remove(store, (bounty_id.into(), applicant.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    #[test]
+    fn does_not_fetch_applications_for_other_bounties() {
+        let mut deps = mock_dependencies();
+
+        let application = Application {
+            applicant: Addr::unchecked("applicant"),
+            message: "I can do this".to_string(),
+            applied_at: mock_env().block.time,
+        };
+
+        save_application(&mut deps.storage, Uint128::one(), &application).unwrap();
+
+        assert_eq!(
+            get_applications(&deps.storage, Uint128::one()).unwrap(),
+            vec![application]
+        );
+        assert!(get_applications(&deps.storage, Uint128::new(2))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn deletes_application_by_applicant() {
+        let mut deps = mock_dependencies();
+
+        let application = Application {
+            applicant: Addr::unchecked("applicant"),
+            message: "I can do this".to_string(),
+            applied_at: mock_env().block.time,
+        };
+
+        save_application(&mut deps.storage, Uint128::one(), &application).unwrap();
+
+        delete_application(&mut deps.storage, Uint128::one(), &application.applicant);
+
+        assert_eq!(
+            get_application(&deps.storage, Uint128::one(), &application.applicant).unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use super::keys;
+use crate::types::assignee::Assignee;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const ASSIGNEES: Map<(u128, Addr), Assignee> = Map::new(keys::ASSIGNEES);
+
+pub fn save_assignee(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    assignee: &Assignee,
+) -> StdResult<()> {
+    ASSIGNEES.save(store, (bounty_id.into(), assignee.address.clone()), assignee)
+}
+
+pub fn get_assignee(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    address: &Addr,
+) -> StdResult<Option<Assignee>> {
+    ASSIGNEES.may_load(store, (bounty_id.into(), address.clone()))
+}
+
+pub fn get_assignees(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Vec<Assignee>> {
+    ASSIGNEES
+        .prefix(bounty_id.into())
+        .range(store, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, assignee)| assignee))
+        .collect::<StdResult<Vec<Assignee>>>()
+}
+
+pub fn delete_assignee(store: &mut dyn Storage, bounty_id: Uint128, address: &Addr) {
+    ASSIGNEES.remove(store, (bounty_id.into(), address.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::Decimal;
+
+    #[test]
+    fn fetches_assignees_for_bounty() {
+        let mut deps = mock_dependencies();
+
+        let assignee = Assignee {
+            address: Addr::unchecked("assignee"),
+            weight: Decimal::percent(100),
+            submission: None,
+            submitted_at: None,
+            payout_address: None,
+        };
+
+        save_assignee(&mut deps.storage, Uint128::one(), &assignee).unwrap();
+
+        let assignees = get_assignees(&deps.storage, Uint128::one()).unwrap();
+
+        assert_eq!(assignees, vec![assignee]);
+    }
+
+    #[test]
+    fn does_not_fetch_assignees_for_other_bounties() {
+        let mut deps = mock_dependencies();
+
+        let assignee = Assignee {
+            address: Addr::unchecked("assignee"),
+            weight: Decimal::percent(100),
+            submission: None,
+            submitted_at: None,
+            payout_address: None,
+        };
+
+        save_assignee(&mut deps.storage, Uint128::one(), &assignee).unwrap();
+
+        let assignees = get_assignees(&deps.storage, Uint128::new(2)).unwrap();
+
+        assert!(assignees.is_empty());
+    }
+
+    #[test]
+    fn deletes_assignee_by_address() {
+        let mut deps = mock_dependencies();
+
+        let assignee_1 = Assignee {
+            address: Addr::unchecked("assignee-1"),
+            weight: Decimal::percent(50),
+            submission: None,
+            submitted_at: None,
+            payout_address: None,
+        };
+        let assignee_2 = Assignee {
+            address: Addr::unchecked("assignee-2"),
+            weight: Decimal::percent(50),
+            submission: None,
+            submitted_at: None,
+            payout_address: None,
+        };
+
+        save_assignee(&mut deps.storage, Uint128::one(), &assignee_1).unwrap();
+        save_assignee(&mut deps.storage, Uint128::one(), &assignee_2).unwrap();
+
+        delete_assignee(&mut deps.storage, Uint128::one(), &assignee_1.address);
+
+        let assignees = get_assignees(&deps.storage, Uint128::one()).unwrap();
+
+        assert_eq!(assignees, vec![assignee_2]);
+    }
+}
@@ -0,0 +1,80 @@
+use super::keys;
+use crate::types::authz::OperatorPermission;
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const BOUNTY_OPERATORS: Map<(u128, Addr), Vec<OperatorPermission>> =
+    Map::new(keys::BOUNTY_OPERATORS);
+
+pub fn save_operator_permissions(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    operator: &Addr,
+    permissions: &Vec<OperatorPermission>,
+) -> StdResult<()> {
+    BOUNTY_OPERATORS.save(store, (bounty_id.into(), operator.clone()), permissions)
+}
+
+pub fn get_operator_permissions(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    operator: &Addr,
+) -> StdResult<Vec<OperatorPermission>> {
+    Ok(BOUNTY_OPERATORS
+        .may_load(store, (bounty_id.into(), operator.clone()))?
+        .unwrap_or_default())
+}
+
+pub fn operator_has_permission(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    operator: &Addr,
+    permission: &OperatorPermission,
+) -> StdResult<bool> {
+    Ok(get_operator_permissions(store, bounty_id, operator)?.contains(permission))
+}
+
+pub fn delete_operator_permissions(store: &mut dyn Storage, bounty_id: Uint128, operator: &Addr) {
+    BOUNTY_OPERATORS.remove(store, (bounty_id.into(), operator.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn checks_operator_permissions_per_bounty() {
+        let mut deps = mock_dependencies();
+
+        save_operator_permissions(
+            &mut deps.storage,
+            Uint128::one(),
+            &Addr::unchecked("operator"),
+            &vec![OperatorPermission::Cancel],
+        )
+        .unwrap();
+
+        assert!(operator_has_permission(
+            &deps.storage,
+            Uint128::one(),
+            &Addr::unchecked("operator"),
+            &OperatorPermission::Cancel
+        )
+        .unwrap());
+        assert!(!operator_has_permission(
+            &deps.storage,
+            Uint128::one(),
+            &Addr::unchecked("operator"),
+            &OperatorPermission::TopUp
+        )
+        .unwrap());
+        assert!(!operator_has_permission(
+            &deps.storage,
+            Uint128::new(2),
+            &Addr::unchecked("operator"),
+            &OperatorPermission::Cancel
+        )
+        .unwrap());
+    }
+}
@@ -0,0 +1,123 @@
+use super::keys;
+use crate::helpers::pagination::Page;
+use crate::helpers::time::get_month_start;
+use crate::types::{bounty::Bounty, bounty_snapshot::BountySnapshot};
+use cosmwasm_std::{StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::Map;
+
+const SNAPSHOTS_ENABLED: Map<u128, ()> = Map::new(keys::BOUNTY_SNAPSHOTS_ENABLED);
+
+const BOUNTY_SNAPSHOTS: Map<(u128, u64), BountySnapshot> = Map::new(keys::BOUNTY_SNAPSHOTS);
+
+pub fn enable_bounty_snapshots(store: &mut dyn Storage, bounty_id: Uint128) -> StdResult<()> {
+    SNAPSHOTS_ENABLED.save(store, bounty_id.into(), &())
+}
+
+pub fn disable_bounty_snapshots(store: &mut dyn Storage, bounty_id: Uint128) {
+    SNAPSHOTS_ENABLED.remove(store, bounty_id.into())
+}
+
+pub fn bounty_snapshots_enabled(store: &dyn Storage, bounty_id: Uint128) -> bool {
+    SNAPSHOTS_ENABLED.has(store, bounty_id.into())
+}
+
+pub fn record_bounty_snapshot_if_due(
+    store: &mut dyn Storage,
+    bounty: &Bounty,
+    block_time: Timestamp,
+) -> StdResult<Option<BountySnapshot>> {
+    if !bounty_snapshots_enabled(store, bounty.id) {
+        return Ok(None);
+    }
+
+    let period_start = get_month_start(block_time);
+    let key = (bounty.id.into(), period_start.seconds());
+
+    if BOUNTY_SNAPSHOTS.has(store, key) {
+        return Ok(None);
+    }
+
+    let snapshot = BountySnapshot {
+        period_start,
+        recorded_at: block_time,
+        balance: bounty.balance.clone(),
+        received_amount: bounty.received_amount.clone(),
+        escrowed_amount: bounty.escrowed_amount.clone(),
+    };
+
+    BOUNTY_SNAPSHOTS.save(store, key, &snapshot)?;
+
+    Ok(Some(snapshot))
+}
+
+pub fn get_bounty_snapshots(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    start_after: Option<Timestamp>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<Vec<BountySnapshot>> {
+    let page = Page::new(
+        store,
+        start_after.map(|timestamp| timestamp.seconds()),
+        limit,
+        reverse,
+    )?;
+    let (min, max) = page.bounds();
+
+    BOUNTY_SNAPSHOTS
+        .prefix(bounty_id.into())
+        .range(store, min, max, page.order)
+        .take(page.limit)
+        .map(|result| result.map(|(_, snapshot)| snapshot))
+        .collect::<StdResult<Vec<BountySnapshot>>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn does_not_record_snapshot_when_disabled() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let snapshot =
+            record_bounty_snapshot_if_due(&mut deps.storage, &Bounty::default(), env.block.time)
+                .unwrap();
+
+        assert_eq!(snapshot, None);
+    }
+
+    #[test]
+    fn records_one_snapshot_per_month() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = Bounty::default();
+
+        enable_bounty_snapshots(&mut deps.storage, bounty.id).unwrap();
+
+        record_bounty_snapshot_if_due(&mut deps.storage, &bounty, env.block.time).unwrap();
+        record_bounty_snapshot_if_due(&mut deps.storage, &bounty, env.block.time.plus_seconds(60))
+            .unwrap();
+        record_bounty_snapshot_if_due(
+            &mut deps.storage,
+            &bounty,
+            env.block.time.plus_seconds(32 * 24 * 60 * 60),
+        )
+        .unwrap();
+
+        let snapshots = get_bounty_snapshots(&deps.storage, bounty.id, None, None, None).unwrap();
+
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0].period_start, get_month_start(env.block.time));
+        assert_eq!(snapshots[0].recorded_at, env.block.time);
+        assert_eq!(snapshots[0].balance, bounty.balance);
+    }
+}
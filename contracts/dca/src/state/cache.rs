@@ -1,9 +1,17 @@
+use super::keys;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Coin, SubMsg, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, SubMsg, Uint128};
 use cw_storage_plus::{Item, Map};
 use std::collections::VecDeque;
 
-pub const BOUNTY_ID_CACHE: Item<Uint128> = Item::new("bounty_id_cache_v1");
+pub const BOUNTY_ID_CACHE: Item<Uint128> = Item::new(keys::BOUNTY_ID_CACHE);
+
+pub const EXECUTOR_CACHE: Item<Addr> = Item::new(keys::EXECUTOR_CACHE);
+
+pub const TWAP_PRICE_CACHE: Item<Decimal> = Item::new(keys::TWAP_PRICE_CACHE);
+
+pub const LIMIT_ORDER_TARGET_PRICE_CACHE: Item<Decimal> =
+    Item::new(keys::LIMIT_ORDER_TARGET_PRICE_CACHE);
 
 // #[cw_serde]
 // pub struct SwapCache {
@@ -13,6 +21,15 @@ pub const BOUNTY_ID_CACHE: Item<Uint128> = Item::new("bounty_id_cache_v1");
 
 // pub const SWAP_CACHE: Item<SwapCache> = Item::new("swap_cache_v1");
 
+#[cw_serde]
+pub struct SwapRetryCache {
+    pub swap_amount: Coin,
+    pub minimum_receive_amount: Coin,
+    pub fallback_routes_tried: usize,
+}
+
+pub const SWAP_RETRY_CACHE: Item<SwapRetryCache> = Item::new(keys::SWAP_RETRY_CACHE);
+
 #[cw_serde]
 pub struct PostExecutionActionCacheEntry {
     pub msg: SubMsg,
@@ -20,4 +37,6 @@ pub struct PostExecutionActionCacheEntry {
 }
 
 pub const POST_EXECUTION_ACTION_CACHE: Map<u128, VecDeque<PostExecutionActionCacheEntry>> =
-    Map::new("post_execution_action_cache_v1");
+    Map::new(keys::POST_EXECUTION_ACTION_CACHE);
+
+pub const REFUND_QUEUE_CACHE: Item<VecDeque<u64>> = Item::new(keys::REFUND_QUEUE_CACHE);
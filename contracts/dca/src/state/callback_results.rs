@@ -0,0 +1,78 @@
+use super::keys;
+use crate::types::callback_result::CallbackResult;
+use cosmwasm_std::{Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const CALLBACK_RESULTS: Map<(u128, u64, u32), CallbackResult> = Map::new(keys::CALLBACK_RESULTS);
+
+pub fn save_callback_result(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    result: &CallbackResult,
+) -> StdResult<()> {
+    CALLBACK_RESULTS.save(
+        store,
+        (
+            bounty_id.into(),
+            result.execution_height,
+            result.destination_index,
+        ),
+        result,
+    )
+}
+
+pub fn get_callback_results(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    execution_height: Option<u64>,
+) -> StdResult<Vec<CallbackResult>> {
+    match execution_height {
+        Some(execution_height) => CALLBACK_RESULTS
+            .prefix((bounty_id.into(), execution_height))
+            .range(store, None, None, Order::Ascending)
+            .map(|result| result.map(|(_, callback_result)| callback_result))
+            .collect::<StdResult<Vec<CallbackResult>>>(),
+        None => CALLBACK_RESULTS
+            .sub_prefix(bounty_id.into())
+            .range(store, None, None, Order::Ascending)
+            .map(|result| result.map(|(_, callback_result)| callback_result))
+            .collect::<StdResult<Vec<CallbackResult>>>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::{Addr, Binary};
+
+    fn callback_result(execution_height: u64, destination_index: u32) -> CallbackResult {
+        CallbackResult {
+            execution_height,
+            destination_index,
+            destination: Addr::unchecked("contract"),
+            data: Binary::from(b"receipt".to_vec()),
+        }
+    }
+
+    #[test]
+    fn fetches_callback_results_for_execution() {
+        let mut deps = mock_dependencies();
+
+        save_callback_result(&mut deps.storage, Uint128::one(), &callback_result(10, 1)).unwrap();
+        save_callback_result(&mut deps.storage, Uint128::one(), &callback_result(10, 2)).unwrap();
+        save_callback_result(&mut deps.storage, Uint128::one(), &callback_result(20, 1)).unwrap();
+        save_callback_result(&mut deps.storage, Uint128::new(2), &callback_result(10, 1)).unwrap();
+
+        assert_eq!(
+            get_callback_results(&deps.storage, Uint128::one(), Some(10)).unwrap(),
+            vec![callback_result(10, 1), callback_result(10, 2)]
+        );
+        assert_eq!(
+            get_callback_results(&deps.storage, Uint128::one(), None)
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use super::keys;
+use crate::types::checklist::ChecklistItem;
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const CHECKLISTS: Map<u128, Vec<ChecklistItem>> = Map::new(keys::CHECKLISTS);
+
+pub fn save_checklist(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    items: Vec<ChecklistItem>,
+) -> StdResult<()> {
+    CHECKLISTS.save(store, bounty_id.into(), &items)
+}
+
+pub fn get_checklist(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Vec<ChecklistItem>> {
+    Ok(CHECKLISTS
+        .may_load(store, bounty_id.into())?
+        .unwrap_or_default())
+}
+
+pub fn delete_checklist(store: &mut dyn Storage, bounty_id: Uint128) {
+    CHECKLISTS.remove(store, bounty_id.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::Decimal;
+
+    #[test]
+    fn returns_empty_checklist_when_none_is_saved() {
+        let deps = mock_dependencies();
+
+        assert!(get_checklist(&deps.storage, Uint128::one())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn fetches_saved_checklist() {
+        let mut deps = mock_dependencies();
+
+        let items = vec![ChecklistItem {
+            description: "write tests".to_string(),
+            weight: Decimal::percent(100),
+            completed_at: None,
+        }];
+
+        save_checklist(&mut deps.storage, Uint128::one(), items.clone()).unwrap();
+
+        assert_eq!(get_checklist(&deps.storage, Uint128::one()).unwrap(), items);
+    }
+}
@@ -0,0 +1,142 @@
+use super::keys;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const CLAIMS: Map<(u128, Addr), Uint128> = Map::new(keys::CLAIMS);
+
+pub fn add_claimed_amount(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    assignee: &Addr,
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    CLAIMS.update(store, (bounty_id.into(), assignee.clone()), |claimed| {
+        Ok(claimed.unwrap_or_default() + amount)
+    })
+}
+
+pub fn get_claimed_amount(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    assignee: &Addr,
+) -> StdResult<Uint128> {
+    Ok(CLAIMS
+        .may_load(store, (bounty_id.into(), assignee.clone()))?
+        .unwrap_or_default())
+}
+
+pub fn get_total_claimed_amount(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Uint128> {
+    CLAIMS
+        .prefix(bounty_id.into())
+        .range(store, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, claimed)| claimed))
+        .sum::<StdResult<Uint128>>()
+}
+
+pub fn clear_claimed_amounts(store: &mut dyn Storage, bounty_id: Uint128) -> StdResult<()> {
+    let assignees = CLAIMS
+        .prefix(bounty_id.into())
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    for assignee in assignees {
+        CLAIMS.remove(store, (bounty_id.into(), assignee));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn accumulates_claimed_amounts() {
+        let mut deps = mock_dependencies();
+
+        let assignee = Addr::unchecked("assignee");
+
+        add_claimed_amount(
+            &mut deps.storage,
+            Uint128::one(),
+            &assignee,
+            Uint128::new(10),
+        )
+        .unwrap();
+        add_claimed_amount(
+            &mut deps.storage,
+            Uint128::one(),
+            &assignee,
+            Uint128::new(5),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_claimed_amount(&deps.storage, Uint128::one(), &assignee).unwrap(),
+            Uint128::new(15)
+        );
+    }
+
+    #[test]
+    fn totals_claimed_amounts_for_bounty() {
+        let mut deps = mock_dependencies();
+
+        add_claimed_amount(
+            &mut deps.storage,
+            Uint128::one(),
+            &Addr::unchecked("assignee-1"),
+            Uint128::new(10),
+        )
+        .unwrap();
+        add_claimed_amount(
+            &mut deps.storage,
+            Uint128::one(),
+            &Addr::unchecked("assignee-2"),
+            Uint128::new(5),
+        )
+        .unwrap();
+        add_claimed_amount(
+            &mut deps.storage,
+            Uint128::new(2),
+            &Addr::unchecked("assignee-1"),
+            Uint128::new(7),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_total_claimed_amount(&deps.storage, Uint128::one()).unwrap(),
+            Uint128::new(15)
+        );
+    }
+
+    #[test]
+    fn clears_claimed_amounts_for_bounty_only() {
+        let mut deps = mock_dependencies();
+
+        add_claimed_amount(
+            &mut deps.storage,
+            Uint128::one(),
+            &Addr::unchecked("assignee-1"),
+            Uint128::new(10),
+        )
+        .unwrap();
+        add_claimed_amount(
+            &mut deps.storage,
+            Uint128::new(2),
+            &Addr::unchecked("assignee-1"),
+            Uint128::new(7),
+        )
+        .unwrap();
+
+        clear_claimed_amounts(&mut deps.storage, Uint128::one()).unwrap();
+
+        assert!(get_total_claimed_amount(&deps.storage, Uint128::one())
+            .unwrap()
+            .is_zero());
+        assert_eq!(
+            get_total_claimed_amount(&deps.storage, Uint128::new(2)).unwrap(),
+            Uint128::new(7)
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use super::keys;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Map, PrefixBound};
+use std::collections::BTreeMap;
+
+const COMPLETION_PERIOD_SECONDS: u64 = 24 * 60 * 60;
+
+const COMPLETIONS: Map<(Addr, u128), Timestamp> = Map::new(keys::COMPLETIONS);
+
+const COMPLETION_COUNTS: Map<(u64, Addr), u32> = Map::new(keys::COMPLETION_COUNTS);
+
+fn get_period(time: Timestamp) -> u64 {
+    time.seconds() / COMPLETION_PERIOD_SECONDS
+}
+
+pub fn record_completion(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    assignee: &Addr,
+    completed_at: Timestamp,
+) -> StdResult<()> {
+    if COMPLETIONS.has(store, (assignee.clone(), bounty_id.into())) {
+        return Ok(());
+    }
+
+    COMPLETIONS.save(store, (assignee.clone(), bounty_id.into()), &completed_at)?;
+    COMPLETION_COUNTS.update(
+        store,
+        (get_period(completed_at), assignee.clone()),
+        |count| -> StdResult<u32> { Ok(count.unwrap_or_default() + 1) },
+    )?;
+
+    Ok(())
+}
+
+pub fn get_completion_counts(
+    store: &dyn Storage,
+    period_start: Timestamp,
+    period_end: Timestamp,
+) -> StdResult<Vec<(Addr, u32)>> {
+    let mut counts = BTreeMap::<Addr, u32>::new();
+
+    for result in COMPLETION_COUNTS.prefix_range(
+        store,
+        Some(PrefixBound::inclusive(get_period(period_start))),
+        Some(PrefixBound::inclusive(get_period(period_end))),
+        Order::Ascending,
+    ) {
+        let ((_, assignee), count) = result?;
+        *counts.entry(assignee).or_default() += count;
+    }
+
+    Ok(counts.into_iter().collect())
+}
+
+#[cfg(test)]
+mod completions_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn sums_completion_counts_across_periods_in_range() {
+        let mut deps = mock_dependencies();
+
+        let day = Timestamp::from_seconds(COMPLETION_PERIOD_SECONDS);
+
+        for (bounty_id, assignee, completed_at) in [
+            (1u128, "alice", day),
+            (2, "alice", day.plus_seconds(COMPLETION_PERIOD_SECONDS)),
+            (3, "bob", day),
+            (4, "bob", day.plus_seconds(10 * COMPLETION_PERIOD_SECONDS)),
+        ] {
+            record_completion(
+                deps.as_mut().storage,
+                bounty_id.into(),
+                &Addr::unchecked(assignee),
+                completed_at,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            get_completion_counts(
+                deps.as_ref().storage,
+                day,
+                day.plus_seconds(COMPLETION_PERIOD_SECONDS)
+            )
+            .unwrap(),
+            vec![(Addr::unchecked("alice"), 2), (Addr::unchecked("bob"), 1)]
+        );
+    }
+
+    #[test]
+    fn does_not_count_the_same_completion_twice() {
+        let mut deps = mock_dependencies();
+
+        let day = Timestamp::from_seconds(COMPLETION_PERIOD_SECONDS);
+
+        for _ in 0..2 {
+            record_completion(
+                deps.as_mut().storage,
+                Uint128::one(),
+                &Addr::unchecked("alice"),
+                day,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            get_completion_counts(deps.as_ref().storage, day, day).unwrap(),
+            vec![(Addr::unchecked("alice"), 1)]
+        );
+    }
+}
@@ -1,8 +1,9 @@
+use super::keys;
 use crate::types::config::Config;
 use cosmwasm_std::{StdResult, Storage};
 use cw_storage_plus::Item;
 
-const CONFIG: Item<Config> = Item::new("config_v9");
+const CONFIG: Item<Config> = Item::new(keys::CONFIG);
 
 pub fn get_config(store: &dyn Storage) -> StdResult<Config> {
     CONFIG.load(store)
@@ -12,3 +13,9 @@ pub fn update_config(store: &mut dyn Storage, config: Config) -> StdResult<Confi
     CONFIG.save(store, &config)?;
     Ok(config)
 }
+
+pub fn get_expected_address_prefix(store: &dyn Storage) -> StdResult<Option<String>> {
+    Ok(CONFIG
+        .may_load(store)?
+        .and_then(|config| config.address_prefix))
+}
@@ -0,0 +1,87 @@
+use super::keys;
+use crate::types::conversion_report::ConversionReport;
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const CONVERSION_REPORTS: Map<u128, ConversionReport> = Map::new(keys::CONVERSION_REPORTS);
+
+pub fn record_conversion(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    sent: Uint128,
+    received: Uint128,
+    fee: Uint128,
+    twap_received: Uint128,
+) -> StdResult<ConversionReport> {
+    CONVERSION_REPORTS.update(store, bounty_id.into(), |report| {
+        let report = report.unwrap_or_default();
+
+        Ok(ConversionReport {
+            executions: report.executions + 1,
+            total_sent: report.total_sent + sent,
+            total_received: report.total_received + received,
+            fees_paid: report.fees_paid + fee,
+            twap_received: report.twap_received + twap_received,
+        })
+    })
+}
+
+pub fn get_conversion_report(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+) -> StdResult<ConversionReport> {
+    Ok(CONVERSION_REPORTS
+        .may_load(store, bounty_id.into())?
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn accumulates_conversions_for_bounty() {
+        let mut deps = mock_dependencies();
+
+        record_conversion(
+            &mut deps.storage,
+            Uint128::one(),
+            Uint128::new(100),
+            Uint128::new(50),
+            Uint128::new(1),
+            Uint128::new(50),
+        )
+        .unwrap();
+        record_conversion(
+            &mut deps.storage,
+            Uint128::one(),
+            Uint128::new(100),
+            Uint128::new(100),
+            Uint128::new(2),
+            Uint128::new(200),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_conversion_report(&deps.storage, Uint128::one()).unwrap(),
+            ConversionReport {
+                executions: 2,
+                total_sent: Uint128::new(200),
+                total_received: Uint128::new(150),
+                fees_paid: Uint128::new(3),
+                twap_received: Uint128::new(250),
+            }
+        );
+    }
+
+    #[test]
+    fn returns_empty_report_for_unexecuted_bounty() {
+        let deps = mock_dependencies();
+
+        assert_eq!(
+            get_conversion_report(&deps.storage, Uint128::one()).unwrap(),
+            ConversionReport::default()
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use super::keys;
+use cosmwasm_std::{Decimal, Order, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const CUSTOM_FEES: Map<String, Decimal> = Map::new(keys::CUSTOM_FEES);
+
+pub fn save_custom_swap_fee(
+    store: &mut dyn Storage,
+    denom: String,
+    swap_fee_percent: Decimal,
+) -> StdResult<()> {
+    CUSTOM_FEES.save(store, denom, &swap_fee_percent)
+}
+
+pub fn get_custom_swap_fee(store: &dyn Storage, denom: String) -> StdResult<Option<Decimal>> {
+    CUSTOM_FEES.may_load(store, denom)
+}
+
+pub fn get_custom_swap_fees(store: &dyn Storage) -> StdResult<Vec<(String, Decimal)>> {
+    CUSTOM_FEES
+        .range(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Decimal)>>>()
+}
+
+pub fn remove_custom_swap_fee(store: &mut dyn Storage, denom: String) {
+    CUSTOM_FEES.remove(store, denom)
+}
@@ -0,0 +1,23 @@
+use super::keys;
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const CW20_WHITELIST: Map<Addr, ()> = Map::new(keys::CW20_WHITELIST);
+
+pub fn save_whitelisted_cw20_token(store: &mut dyn Storage, token: &Addr) -> StdResult<()> {
+    CW20_WHITELIST.save(store, token.clone(), &())
+}
+
+pub fn is_cw20_token_whitelisted(store: &dyn Storage, token: &Addr) -> bool {
+    CW20_WHITELIST.has(store, token.clone())
+}
+
+pub fn get_whitelisted_cw20_tokens(store: &dyn Storage) -> StdResult<Vec<Addr>> {
+    CW20_WHITELIST
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()
+}
+
+pub fn remove_whitelisted_cw20_token(store: &mut dyn Storage, token: &Addr) {
+    CW20_WHITELIST.remove(store, token.clone())
+}
@@ -0,0 +1,55 @@
+use super::keys;
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const DELIVERED_AMOUNTS: Map<u128, Uint128> = Map::new(keys::DELIVERED_AMOUNTS);
+
+pub fn get_delivered_amount(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Uint128> {
+    Ok(DELIVERED_AMOUNTS
+        .may_load(store, bounty_id.into())?
+        .unwrap_or_default())
+}
+
+pub fn add_delivered_amount(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    DELIVERED_AMOUNTS.update(store, bounty_id.into(), |delivered| {
+        Ok(delivered.unwrap_or_default() + amount)
+    })
+}
+
+pub fn clear_delivered_amount(store: &mut dyn Storage, bounty_id: Uint128) {
+    DELIVERED_AMOUNTS.remove(store, bounty_id.into())
+}
+
+#[cfg(test)]
+mod delivered_amounts_tests {
+    use super::{add_delivered_amount, clear_delivered_amount, get_delivered_amount};
+    use cosmwasm_std::{testing::mock_dependencies, Uint128};
+
+    #[test]
+    fn accumulates_delivered_amounts_per_bounty() {
+        let mut deps = mock_dependencies();
+
+        add_delivered_amount(deps.as_mut().storage, Uint128::one(), Uint128::new(3)).unwrap();
+        add_delivered_amount(deps.as_mut().storage, Uint128::one(), Uint128::new(4)).unwrap();
+
+        assert_eq!(
+            get_delivered_amount(deps.as_ref().storage, Uint128::one()).unwrap(),
+            Uint128::new(7)
+        );
+        assert_eq!(
+            get_delivered_amount(deps.as_ref().storage, Uint128::new(2)).unwrap(),
+            Uint128::zero()
+        );
+
+        clear_delivered_amount(deps.as_mut().storage, Uint128::one());
+
+        assert_eq!(
+            get_delivered_amount(deps.as_ref().storage, Uint128::one()).unwrap(),
+            Uint128::zero()
+        );
+    }
+}
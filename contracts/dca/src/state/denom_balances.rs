@@ -0,0 +1,121 @@
+use super::{bounties::update_owner_denom_balance_index, keys};
+use crate::types::denom_balance::DenomBalance;
+use cosmwasm_std::{Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const DENOM_BALANCES: Map<(u128, String), DenomBalance> = Map::new(keys::DENOM_BALANCES);
+
+pub fn save_denom_balance(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    denom_balance: &DenomBalance,
+) -> StdResult<()> {
+    let previous = get_denom_balance(store, bounty_id, &denom_balance.balance.denom)?;
+
+    update_owner_denom_balance_index(
+        store,
+        bounty_id,
+        previous.as_ref().map(|previous| &previous.balance),
+        Some(&denom_balance.balance),
+    )?;
+
+    DENOM_BALANCES.save(
+        store,
+        (bounty_id.into(), denom_balance.balance.denom.clone()),
+        denom_balance,
+    )
+}
+
+pub fn get_denom_balance(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    denom: &str,
+) -> StdResult<Option<DenomBalance>> {
+    DENOM_BALANCES.may_load(store, (bounty_id.into(), denom.to_string()))
+}
+
+pub fn get_denom_balances(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Vec<DenomBalance>> {
+    DENOM_BALANCES
+        .prefix(bounty_id.into())
+        .range(store, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, denom_balance)| denom_balance))
+        .collect::<StdResult<Vec<DenomBalance>>>()
+}
+
+pub fn delete_denom_balance(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    denom: &str,
+) -> StdResult<()> {
+    if let Some(previous) = get_denom_balance(store, bounty_id, denom)? {
+        update_owner_denom_balance_index(store, bounty_id, Some(&previous.balance), None)?;
+    }
+
+    DENOM_BALANCES.remove(store, (bounty_id.into(), denom.to_string()));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::Coin;
+
+    fn denom_balance(denom: &str, amount: u128) -> DenomBalance {
+        DenomBalance {
+            balance: Coin::new(amount, denom),
+            deposited_amount: Coin::new(amount, denom),
+            swap_amount: Uint128::new(10),
+            route: None,
+        }
+    }
+
+    #[test]
+    fn fetches_denom_balances_for_bounty() {
+        let mut deps = mock_dependencies();
+
+        save_denom_balance(
+            &mut deps.storage,
+            Uint128::one(),
+            &denom_balance("uatom", 100),
+        )
+        .unwrap();
+        save_denom_balance(
+            &mut deps.storage,
+            Uint128::one(),
+            &denom_balance("uosmo", 200),
+        )
+        .unwrap();
+        save_denom_balance(
+            &mut deps.storage,
+            Uint128::new(2),
+            &denom_balance("uatom", 300),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_denom_balances(&deps.storage, Uint128::one()).unwrap(),
+            vec![denom_balance("uatom", 100), denom_balance("uosmo", 200)]
+        );
+    }
+
+    #[test]
+    fn deletes_denom_balance() {
+        let mut deps = mock_dependencies();
+
+        save_denom_balance(
+            &mut deps.storage,
+            Uint128::one(),
+            &denom_balance("uatom", 100),
+        )
+        .unwrap();
+
+        delete_denom_balance(&mut deps.storage, Uint128::one(), "uatom").unwrap();
+
+        assert_eq!(
+            get_denom_balance(&deps.storage, Uint128::one(), "uatom").unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use super::keys;
+use crate::types::destination::Destination;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+/// Cumulative payouts for each position in a bounty's destination set. The
+/// history only applies to the exact set it was recorded against, so a change
+/// in addresses, allocations or ordering (e.g. a different outcome or new
+/// assignees) starts it again from zero.
+#[cw_serde]
+struct DestinationPayouts {
+    destinations: Vec<(Addr, Decimal)>,
+    paid: Vec<Uint128>,
+}
+
+const DESTINATION_PAYOUTS: Map<u128, DestinationPayouts> = Map::new(keys::DESTINATION_PAYOUTS);
+
+fn get_destination_set(destinations: &[Destination]) -> Vec<(Addr, Decimal)> {
+    destinations
+        .iter()
+        .map(|destination| (destination.address.clone(), destination.allocation))
+        .collect()
+}
+
+pub fn get_destination_payouts(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    destinations: &[Destination],
+) -> StdResult<Vec<Uint128>> {
+    Ok(DESTINATION_PAYOUTS
+        .may_load(store, bounty_id.into())?
+        .filter(|payouts| payouts.destinations == get_destination_set(destinations))
+        .map_or_else(
+            || vec![Uint128::zero(); destinations.len()],
+            |payouts| payouts.paid,
+        ))
+}
+
+pub fn add_destination_payouts(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    destinations: &[Destination],
+    amounts: &[Uint128],
+) -> StdResult<()> {
+    let paid = get_destination_payouts(store, bounty_id, destinations)?
+        .into_iter()
+        .zip(amounts.iter())
+        .map(|(paid, amount)| paid + amount)
+        .collect::<Vec<Uint128>>();
+
+    DESTINATION_PAYOUTS.save(
+        store,
+        bounty_id.into(),
+        &DestinationPayouts {
+            destinations: get_destination_set(destinations),
+            paid,
+        },
+    )
+}
+
+pub fn clear_destination_payouts(store: &mut dyn Storage, bounty_id: Uint128) {
+    DESTINATION_PAYOUTS.remove(store, bounty_id.into())
+}
+
+#[cfg(test)]
+mod destination_payouts_tests {
+    use super::{add_destination_payouts, get_destination_payouts};
+    use crate::types::destination::Destination;
+    use cosmwasm_std::{testing::mock_dependencies, Addr, Decimal, Uint128};
+
+    fn destination(address: &str, allocation: Decimal) -> Destination {
+        Destination {
+            address: Addr::unchecked(address),
+            allocation,
+            msg: None,
+            condition: None,
+            ibc: None,
+            gas_limit: None,
+        }
+    }
+
+    #[test]
+    fn accumulates_payouts_per_destination() {
+        let mut deps = mock_dependencies();
+
+        let destinations = vec![
+            destination("first", Decimal::percent(50)),
+            destination("second", Decimal::percent(50)),
+        ];
+
+        add_destination_payouts(
+            deps.as_mut().storage,
+            Uint128::one(),
+            &destinations,
+            &[Uint128::new(3), Uint128::new(1)],
+        )
+        .unwrap();
+        add_destination_payouts(
+            deps.as_mut().storage,
+            Uint128::one(),
+            &destinations,
+            &[Uint128::new(4), Uint128::new(2)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_destination_payouts(deps.as_ref().storage, Uint128::one(), &destinations).unwrap(),
+            vec![Uint128::new(7), Uint128::new(3)]
+        );
+        assert_eq!(
+            get_destination_payouts(deps.as_ref().storage, Uint128::new(2), &destinations).unwrap(),
+            vec![Uint128::zero(), Uint128::zero()]
+        );
+    }
+
+    #[test]
+    fn tracks_duplicate_addresses_separately() {
+        let mut deps = mock_dependencies();
+
+        let destinations = vec![
+            destination("destination", Decimal::percent(50)),
+            destination("destination", Decimal::percent(50)),
+        ];
+
+        add_destination_payouts(
+            deps.as_mut().storage,
+            Uint128::one(),
+            &destinations,
+            &[Uint128::new(4), Uint128::new(3)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_destination_payouts(deps.as_ref().storage, Uint128::one(), &destinations).unwrap(),
+            vec![Uint128::new(4), Uint128::new(3)]
+        );
+    }
+
+    #[test]
+    fn resets_payouts_when_destination_set_changes() {
+        let mut deps = mock_dependencies();
+
+        let destinations = vec![
+            destination("first", Decimal::percent(50)),
+            destination("second", Decimal::percent(50)),
+        ];
+
+        add_destination_payouts(
+            deps.as_mut().storage,
+            Uint128::one(),
+            &destinations,
+            &[Uint128::new(4), Uint128::new(3)],
+        )
+        .unwrap();
+
+        let changed_destinations = vec![
+            destination("first", Decimal::percent(25)),
+            destination("second", Decimal::percent(75)),
+        ];
+
+        assert_eq!(
+            get_destination_payouts(deps.as_ref().storage, Uint128::one(), &changed_destinations)
+                .unwrap(),
+            vec![Uint128::zero(), Uint128::zero()]
+        );
+
+        add_destination_payouts(
+            deps.as_mut().storage,
+            Uint128::one(),
+            &changed_destinations,
+            &[Uint128::new(1), Uint128::new(3)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_destination_payouts(deps.as_ref().storage, Uint128::one(), &changed_destinations)
+                .unwrap(),
+            vec![Uint128::new(1), Uint128::new(3)]
+        );
+    }
+}
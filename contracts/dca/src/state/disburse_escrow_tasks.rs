@@ -2,7 +2,8 @@ use cosmwasm_std::{Order, StdResult, Storage, Timestamp, Uint128};
 use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
 use std::marker::PhantomData;
 
-use super::config::get_config;
+use super::{bounties::get_bounty, keys};
+use crate::helpers::pagination::get_page_limit;
 
 struct DisburseEscrowTaskIndexes<'a> {
     pub due_date: MultiIndex<'a, u64, (u64, u128), u128>,
@@ -20,11 +21,17 @@ fn disburse_escrow_task_store<'a>(
     let indexes = DisburseEscrowTaskIndexes {
         due_date: MultiIndex::new(
             |_, (due_date, _)| *due_date,
-            "disburse_escrow_task_v8",
-            "disburse_escrow_task_v8__due_date",
+            keys::DISBURSE_ESCROW_TASKS,
+            keys::DISBURSE_ESCROW_TASKS_DUE_DATE_INDEX,
         ),
     };
-    IndexedMap::new("disburse_escrow_task_v8", indexes)
+    IndexedMap::new(keys::DISBURSE_ESCROW_TASKS, indexes)
+}
+
+fn get_escrow_available_after(store: &dyn Storage, bounty_id: Uint128) -> Option<Timestamp> {
+    get_bounty(store, bounty_id)
+        .ok()
+        .and_then(|bounty| bounty.escrow_available_after)
 }
 
 pub fn save_disburse_escrow_task(
@@ -32,6 +39,9 @@ pub fn save_disburse_escrow_task(
     bounty_id: Uint128,
     due_date: Timestamp,
 ) -> StdResult<()> {
+    let due_date = get_escrow_available_after(store, bounty_id)
+        .map_or(due_date, |cliff| cliff.max(due_date));
+
     disburse_escrow_task_store().save(
         store,
         bounty_id.into(),
@@ -43,9 +53,15 @@ pub fn get_disburse_escrow_task_due_date(
     store: &dyn Storage,
     bounty_id: Uint128,
 ) -> StdResult<Option<Timestamp>> {
+    let cliff = get_escrow_available_after(store, bounty_id);
+
     disburse_escrow_task_store()
         .may_load(store, bounty_id.into())
-        .map(|result| result.map(|(seconds, _)| Timestamp::from_seconds(seconds)))
+        .map(|result| {
+            result.map(|(seconds, _)| {
+                Timestamp::from_seconds(seconds).max(cliff.unwrap_or_default())
+            })
+        })
 }
 
 pub fn get_disburse_escrow_tasks(
@@ -65,7 +81,7 @@ pub fn get_disburse_escrow_tasks(
             ))),
             Order::Ascending,
         )
-        .take(limit.unwrap_or_else(|| get_config(store).unwrap().default_page_limit) as usize)
+        .take(get_page_limit(store, limit)?)
         .flat_map(|result| result.map(|(_, (_, bounty_id))| bounty_id.into()))
         .collect::<Vec<Uint128>>())
 }
@@ -77,7 +93,10 @@ pub fn delete_disburse_escrow_task(store: &mut dyn Storage, bounty_id: Uint128)
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use crate::tests::helpers::{instantiate_contract, setup_bounty};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN};
+    use crate::types::bounty::Bounty;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
     use cosmwasm_std::Uint128;
 
     #[test]
@@ -179,4 +198,33 @@ mod tests {
         assert_eq!(bounty_ids_before_delete, vec![bounty_id_1, bounty_id_2]);
         assert_eq!(bounty_ids_after_delete, vec![bounty_id_2]);
     }
+
+    #[test]
+    fn returns_escrow_cliff_when_later_than_due_date() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                escrow_available_after: Some(env.block.time.plus_seconds(100)),
+                ..Bounty::default()
+            },
+        );
+
+        save_disburse_escrow_task(&mut deps.storage, bounty.id, env.block.time).unwrap();
+
+        assert_eq!(
+            get_disburse_escrow_task_due_date(&deps.storage, bounty.id).unwrap(),
+            Some(env.block.time.plus_seconds(100))
+        );
+        assert!(
+            get_disburse_escrow_tasks(&deps.storage, env.block.time.plus_seconds(10), Some(100))
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
@@ -0,0 +1,65 @@
+use super::keys;
+use crate::types::dispute::Dispute;
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const DISPUTES: Map<u128, Dispute> = Map::new(keys::DISPUTES);
+
+pub fn save_dispute(store: &mut dyn Storage, dispute: &Dispute) -> StdResult<()> {
+    DISPUTES.save(store, dispute.bounty_id.into(), dispute)
+}
+
+pub fn get_dispute(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Option<Dispute>> {
+    DISPUTES.may_load(store, bounty_id.into())
+}
+
+pub fn delete_dispute(store: &mut dyn Storage, bounty_id: Uint128) {
+    DISPUTES.remove(store, bounty_id.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn fetches_dispute_for_bounty() {
+        let mut deps = mock_dependencies();
+
+        let dispute = Dispute {
+            bounty_id: Uint128::one(),
+            raised_by: Addr::unchecked("owner"),
+            reason: "work was not delivered".to_string(),
+            raised_at: mock_env().block.time,
+        };
+
+        save_dispute(&mut deps.storage, &dispute).unwrap();
+
+        assert_eq!(
+            get_dispute(&deps.storage, Uint128::one()).unwrap(),
+            Some(dispute)
+        );
+        assert_eq!(get_dispute(&deps.storage, Uint128::new(2)).unwrap(), None);
+    }
+
+    #[test]
+    fn deletes_dispute_for_bounty() {
+        let mut deps = mock_dependencies();
+
+        save_dispute(
+            &mut deps.storage,
+            &Dispute {
+                bounty_id: Uint128::one(),
+                raised_by: Addr::unchecked("owner"),
+                reason: "work was not delivered".to_string(),
+                raised_at: mock_env().block.time,
+            },
+        )
+        .unwrap();
+
+        delete_dispute(&mut deps.storage, Uint128::one());
+
+        assert_eq!(get_dispute(&deps.storage, Uint128::one()).unwrap(), None);
+    }
+}
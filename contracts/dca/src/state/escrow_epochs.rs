@@ -0,0 +1,173 @@
+use super::keys;
+use crate::types::{bounty::Bounty, escrow_epoch::EscrowEpoch};
+use cosmwasm_std::{Decimal, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::Map;
+
+const ESCROW_EPOCHS: Map<u128, Vec<EscrowEpoch>> = Map::new(keys::ESCROW_EPOCHS);
+
+pub fn get_escrow_epochs(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Vec<EscrowEpoch>> {
+    Ok(ESCROW_EPOCHS
+        .may_load(store, bounty_id.into())?
+        .unwrap_or_default())
+}
+
+pub fn start_escrow_epoch(
+    store: &mut dyn Storage,
+    bounty: &Bounty,
+    escrow_level: Decimal,
+    started_at: Timestamp,
+) -> StdResult<Vec<EscrowEpoch>> {
+    let mut epochs = get_escrow_epochs(store, bounty.id)?;
+
+    if epochs.is_empty() && !bounty.escrowed_amount.amount.is_zero() {
+        epochs.push(EscrowEpoch {
+            escrow_level: bounty.escrow_level,
+            escrowed_amount: bounty.escrowed_amount.amount,
+            started_at: bounty.started_at.unwrap_or(bounty.created_at),
+        });
+    }
+
+    epochs.push(EscrowEpoch {
+        escrow_level,
+        escrowed_amount: Uint128::zero(),
+        started_at,
+    });
+
+    ESCROW_EPOCHS.save(store, bounty.id.into(), &epochs)?;
+
+    Ok(epochs)
+}
+
+pub fn add_to_current_escrow_epoch(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    amount: Uint128,
+) -> StdResult<()> {
+    let mut epochs = get_escrow_epochs(store, bounty_id)?;
+
+    if let Some(current_epoch) = epochs.last_mut() {
+        current_epoch.escrowed_amount += amount;
+        ESCROW_EPOCHS.save(store, bounty_id.into(), &epochs)?;
+    }
+
+    Ok(())
+}
+
+pub fn release_from_escrow_epochs(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    amount: Uint128,
+) -> StdResult<Vec<EscrowEpoch>> {
+    let mut remaining = amount;
+    let mut released = Vec::<EscrowEpoch>::new();
+    let mut epochs = get_escrow_epochs(store, bounty_id)?;
+
+    for epoch in epochs.iter_mut() {
+        if remaining.is_zero() {
+            break;
+        }
+
+        let release_amount = epoch.escrowed_amount.min(remaining);
+
+        if !release_amount.is_zero() {
+            epoch.escrowed_amount -= release_amount;
+            remaining -= release_amount;
+
+            released.push(EscrowEpoch {
+                escrowed_amount: release_amount,
+                ..epoch.clone()
+            });
+        }
+    }
+
+    let current_epoch = epochs.pop();
+
+    let epochs = epochs
+        .into_iter()
+        .filter(|epoch| !epoch.escrowed_amount.is_zero())
+        .chain(current_epoch)
+        .collect::<Vec<EscrowEpoch>>();
+
+    ESCROW_EPOCHS.save(store, bounty_id.into(), &epochs)?;
+
+    Ok(released)
+}
+
+pub fn clear_escrow_epochs(store: &mut dyn Storage, bounty_id: Uint128) {
+    ESCROW_EPOCHS.remove(store, bounty_id.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Coin;
+
+    #[test]
+    fn seeds_previous_epoch_with_existing_escrow() {
+        let mut deps = mock_dependencies();
+        let now = mock_env().block.time;
+
+        let bounty = Bounty {
+            escrow_level: Decimal::percent(5),
+            escrowed_amount: Coin::new(100, "uusk"),
+            ..Bounty::default()
+        };
+
+        let epochs =
+            start_escrow_epoch(&mut deps.storage, &bounty, Decimal::percent(10), now).unwrap();
+
+        assert_eq!(
+            epochs,
+            vec![
+                EscrowEpoch {
+                    escrow_level: Decimal::percent(5),
+                    escrowed_amount: Uint128::new(100),
+                    started_at: bounty.started_at.unwrap_or(bounty.created_at),
+                },
+                EscrowEpoch {
+                    escrow_level: Decimal::percent(10),
+                    escrowed_amount: Uint128::zero(),
+                    started_at: now,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn releases_oldest_epochs_first() {
+        let mut deps = mock_dependencies();
+        let now = mock_env().block.time;
+
+        let bounty = Bounty {
+            escrow_level: Decimal::percent(5),
+            escrowed_amount: Coin::new(100, "uusk"),
+            ..Bounty::default()
+        };
+
+        start_escrow_epoch(&mut deps.storage, &bounty, Decimal::percent(10), now).unwrap();
+        add_to_current_escrow_epoch(&mut deps.storage, bounty.id, Uint128::new(50)).unwrap();
+
+        let released =
+            release_from_escrow_epochs(&mut deps.storage, bounty.id, Uint128::new(120)).unwrap();
+
+        assert_eq!(
+            released
+                .iter()
+                .map(|epoch| (epoch.escrow_level, epoch.escrowed_amount))
+                .collect::<Vec<_>>(),
+            vec![
+                (Decimal::percent(5), Uint128::new(100)),
+                (Decimal::percent(10), Uint128::new(20)),
+            ]
+        );
+        assert_eq!(
+            get_escrow_epochs(&deps.storage, bounty.id).unwrap(),
+            vec![EscrowEpoch {
+                escrow_level: Decimal::percent(10),
+                escrowed_amount: Uint128::new(30),
+                started_at: now,
+            }]
+        );
+    }
+}
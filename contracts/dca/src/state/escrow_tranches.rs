@@ -0,0 +1,26 @@
+use super::keys;
+use crate::types::escrow_tranche::EscrowTrancheSchedule;
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const ESCROW_TRANCHE_SCHEDULES: Map<u128, EscrowTrancheSchedule> =
+    Map::new(keys::ESCROW_TRANCHE_SCHEDULES);
+
+pub fn save_escrow_tranche_schedule(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    schedule: &EscrowTrancheSchedule,
+) -> StdResult<()> {
+    ESCROW_TRANCHE_SCHEDULES.save(store, bounty_id.into(), schedule)
+}
+
+pub fn get_escrow_tranche_schedule(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+) -> StdResult<Option<EscrowTrancheSchedule>> {
+    ESCROW_TRANCHE_SCHEDULES.may_load(store, bounty_id.into())
+}
+
+pub fn delete_escrow_tranche_schedule(store: &mut dyn Storage, bounty_id: Uint128) {
+    ESCROW_TRANCHE_SCHEDULES.remove(store, bounty_id.into())
+}
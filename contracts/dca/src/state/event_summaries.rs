@@ -0,0 +1,17 @@
+use super::keys;
+use crate::types::event_summary::EventSummary;
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const EVENT_SUMMARIES: Map<u128, EventSummary> = Map::new(keys::EVENT_SUMMARIES);
+
+pub fn save_event_summary(store: &mut dyn Storage, summary: &EventSummary) -> StdResult<()> {
+    EVENT_SUMMARIES.save(store, summary.resource_id.into(), summary)
+}
+
+pub fn get_event_summary(
+    store: &dyn Storage,
+    resource_id: Uint128,
+) -> StdResult<Option<EventSummary>> {
+    EVENT_SUMMARIES.may_load(store, resource_id.into())
+}
@@ -1,17 +1,26 @@
-use super::state_helpers::fetch_and_increment_counter;
-use crate::types::event::{Event, EventBuilder};
-use cosmwasm_std::{from_json, to_json_binary, Binary, StdResult, Storage};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, UniqueIndex};
+use super::{keys, state_helpers::fetch_and_increment_counter};
+use crate::types::event::{Event, EventBuilder, EventData};
+use crate::types::update::Update;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, Order, StdResult, Storage, Timestamp, Uint128,
+};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, UniqueIndex};
 
-const EVENT_COUNTER: Item<u64> = Item::new("event_counter_v8");
+const EVENT_COUNTER: Item<u64> = Item::new(keys::EVENT_COUNTER);
+
+const LEGACY_EVENT_MIGRATION_CURSOR: Item<u64> = Item::new(keys::LEGACY_EVENT_MIGRATION_CURSOR);
+
+const EVENT_BLOCK_HEIGHT_INDEX_CURSOR: Item<u64> = Item::new(keys::EVENT_BLOCK_HEIGHT_INDEX_CURSOR);
 
 pub struct EventIndexes<'a> {
     pub resource_id: UniqueIndex<'a, (u128, u64), Binary, u64>,
+    pub block_height: UniqueIndex<'a, (u64, u64), Binary, u64>,
 }
 
 impl<'a> IndexList<Binary> for EventIndexes<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Binary>> + '_> {
-        let v: Vec<&dyn Index<Binary>> = vec![&self.resource_id];
+        let v: Vec<&dyn Index<Binary>> = vec![&self.resource_id, &self.block_height];
         Box::new(v.into_iter())
     }
 }
@@ -24,10 +33,18 @@ pub fn event_store<'a>() -> IndexedMap<'a, u64, Binary, EventIndexes<'a>> {
                     .map(|event: Event| (event.resource_id.into(), event.id))
                     .expect("deserialised event")
             },
-            "serialised_events_v8__resource_id",
+            keys::SERIALISED_EVENTS_RESOURCE_ID_INDEX,
+        ),
+        block_height: UniqueIndex::new(
+            |event| {
+                from_json(event)
+                    .map(|event: Event| (event.block_height, event.id))
+                    .expect("deserialised event")
+            },
+            keys::SERIALISED_EVENTS_BLOCK_HEIGHT_INDEX,
         ),
     };
-    IndexedMap::new("serialised_events_v8", indexes)
+    IndexedMap::new(keys::SERIALISED_EVENTS, indexes)
 }
 
 pub fn create_event(store: &mut dyn Storage, event_builder: EventBuilder) -> StdResult<u64> {
@@ -46,3 +63,326 @@ pub fn create_events(store: &mut dyn Storage, event_builders: Vec<EventBuilder>)
     }
     Ok(())
 }
+
+pub fn prune_events(
+    store: &mut dyn Storage,
+    resource_id: Uint128,
+    before_block: u64,
+    limit: usize,
+) -> StdResult<Vec<Event>> {
+    let events = event_store()
+        .idx
+        .resource_id
+        .prefix(resource_id.into())
+        .range(store, None, None, Order::Ascending)
+        .map(|result| result.and_then(|(_, data)| from_json::<Event>(data)))
+        .filter(|result| {
+            result
+                .as_ref()
+                .map_or(true, |event| event.block_height < before_block)
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<Event>>>()?;
+
+    for event in events.iter() {
+        event_store().remove(store, event.id)?;
+    }
+
+    Ok(events)
+}
+
+#[cw_serde]
+enum LegacyEventData {
+    BountyUpdated { updates: Vec<Update> },
+}
+
+#[cw_serde]
+struct LegacyEvent {
+    id: u64,
+    resource_id: Uint128,
+    timestamp: Timestamp,
+    block_height: u64,
+    data: LegacyEventData,
+}
+
+pub fn migrate_legacy_bounty_updated_events(store: &mut dyn Storage, limit: u16) -> StdResult<u64> {
+    let cursor = match LEGACY_EVENT_MIGRATION_CURSOR.may_load(store)? {
+        Some(cursor) => cursor,
+        None => return Ok(0),
+    };
+
+    let events = event_store()
+        .range(
+            store,
+            Some(Bound::exclusive(cursor)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<(u64, Binary)>>>()?;
+
+    let mut migrated_events = 0;
+
+    for (id, data) in events.iter() {
+        if let Ok(legacy_event) = from_json::<LegacyEvent>(data) {
+            let LegacyEventData::BountyUpdated { updates } = legacy_event.data;
+
+            event_store().save(
+                store,
+                legacy_event.id,
+                &to_json_binary(&Event {
+                    id: legacy_event.id,
+                    resource_id: legacy_event.resource_id,
+                    timestamp: legacy_event.timestamp,
+                    block_height: legacy_event.block_height,
+                    data: EventData::BountyUpdated {
+                        updates: updates.into_iter().map(Into::into).collect(),
+                    },
+                })
+                .expect("serialised event"),
+            )?;
+
+            migrated_events += 1;
+        }
+
+        LEGACY_EVENT_MIGRATION_CURSOR.save(store, id)?;
+    }
+
+    if events.len() < limit as usize {
+        LEGACY_EVENT_MIGRATION_CURSOR.remove(store);
+    }
+
+    Ok(migrated_events)
+}
+
+pub fn index_events_by_block_height(store: &mut dyn Storage, limit: u16) -> StdResult<u64> {
+    let cursor = match EVENT_BLOCK_HEIGHT_INDEX_CURSOR.may_load(store)? {
+        Some(cursor) => cursor,
+        None => return Ok(0),
+    };
+
+    let events = event_store()
+        .range(
+            store,
+            Some(Bound::exclusive(cursor)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<(u64, Binary)>>>()?;
+
+    for (id, data) in events.iter() {
+        event_store().save(store, *id, data)?;
+        EVENT_BLOCK_HEIGHT_INDEX_CURSOR.save(store, id)?;
+    }
+
+    if events.len() < limit as usize {
+        EVENT_BLOCK_HEIGHT_INDEX_CURSOR.remove(store);
+    }
+
+    Ok(events.len() as u64)
+}
+
+#[cfg(test)]
+mod events_tests {
+    use super::*;
+    use crate::types::update::FieldUpdate;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Decimal;
+
+    #[test]
+    fn migrates_legacy_bounty_updated_events() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let legacy_event = LegacyEvent {
+            id: 1,
+            resource_id: Uint128::one(),
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: LegacyEventData::BountyUpdated {
+                updates: vec![Update {
+                    field: "label".to_string(),
+                    old_value: "old label".to_string(),
+                    new_value: "new label".to_string(),
+                }],
+            },
+        };
+
+        event_store()
+            .save(
+                deps.as_mut().storage,
+                legacy_event.id,
+                &to_json_binary(&legacy_event).unwrap(),
+            )
+            .unwrap();
+
+        LEGACY_EVENT_MIGRATION_CURSOR
+            .save(deps.as_mut().storage, &0)
+            .unwrap();
+
+        let migrated = migrate_legacy_bounty_updated_events(deps.as_mut().storage, 10).unwrap();
+
+        assert_eq!(migrated, 1);
+        assert!(LEGACY_EVENT_MIGRATION_CURSOR
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            from_json::<Event>(event_store().load(deps.as_ref().storage, 1).unwrap()).unwrap(),
+            Event {
+                id: 1,
+                resource_id: Uint128::one(),
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                data: EventData::BountyUpdated {
+                    updates: vec![FieldUpdate::Legacy {
+                        field: "label".to_string(),
+                        old_value: "old label".to_string(),
+                        new_value: "new label".to_string(),
+                    }],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn leaves_typed_events_untouched() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let event_id = create_event(
+            deps.as_mut().storage,
+            EventBuilder::new(
+                Uint128::one(),
+                env.block,
+                EventData::BountyUpdated {
+                    updates: vec![FieldUpdate::SlippageTolerance {
+                        old: Decimal::percent(1),
+                        new: Decimal::percent(2),
+                    }],
+                },
+            ),
+        )
+        .unwrap();
+
+        let before = event_store().load(deps.as_ref().storage, event_id).unwrap();
+
+        LEGACY_EVENT_MIGRATION_CURSOR
+            .save(deps.as_mut().storage, &0)
+            .unwrap();
+
+        let migrated = migrate_legacy_bounty_updated_events(deps.as_mut().storage, 10).unwrap();
+
+        assert_eq!(migrated, 0);
+        assert_eq!(
+            event_store().load(deps.as_ref().storage, event_id).unwrap(),
+            before
+        );
+    }
+
+    #[test]
+    fn migrates_legacy_bounty_updated_events_in_chunks_once() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        for id in 1..=3 {
+            event_store()
+                .save(
+                    deps.as_mut().storage,
+                    id,
+                    &to_json_binary(&LegacyEvent {
+                        id,
+                        resource_id: Uint128::one(),
+                        timestamp: env.block.time,
+                        block_height: env.block.height,
+                        data: LegacyEventData::BountyUpdated {
+                            updates: vec![Update {
+                                field: "label".to_string(),
+                                old_value: "old label".to_string(),
+                                new_value: "new label".to_string(),
+                            }],
+                        },
+                    })
+                    .unwrap(),
+                )
+                .unwrap();
+        }
+
+        LEGACY_EVENT_MIGRATION_CURSOR
+            .save(deps.as_mut().storage, &0)
+            .unwrap();
+
+        assert_eq!(
+            migrate_legacy_bounty_updated_events(deps.as_mut().storage, 2).unwrap(),
+            2
+        );
+        assert_eq!(
+            LEGACY_EVENT_MIGRATION_CURSOR
+                .may_load(deps.as_ref().storage)
+                .unwrap(),
+            Some(2)
+        );
+
+        assert_eq!(
+            migrate_legacy_bounty_updated_events(deps.as_mut().storage, 2).unwrap(),
+            1
+        );
+        assert!(LEGACY_EVENT_MIGRATION_CURSOR
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+
+        assert_eq!(
+            migrate_legacy_bounty_updated_events(deps.as_mut().storage, 2).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn indexes_events_by_block_height_in_chunks_once() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        for _ in 0..3 {
+            create_event(
+                deps.as_mut().storage,
+                EventBuilder::new(
+                    Uint128::one(),
+                    env.block.clone(),
+                    EventData::BountyUpdated { updates: vec![] },
+                ),
+            )
+            .unwrap();
+        }
+
+        EVENT_BLOCK_HEIGHT_INDEX_CURSOR
+            .save(deps.as_mut().storage, &0)
+            .unwrap();
+
+        assert_eq!(
+            index_events_by_block_height(deps.as_mut().storage, 2).unwrap(),
+            2
+        );
+        assert_eq!(
+            EVENT_BLOCK_HEIGHT_INDEX_CURSOR
+                .may_load(deps.as_ref().storage)
+                .unwrap(),
+            Some(2)
+        );
+
+        assert_eq!(
+            index_events_by_block_height(deps.as_mut().storage, 2).unwrap(),
+            1
+        );
+        assert!(EVENT_BLOCK_HEIGHT_INDEX_CURSOR
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+
+        assert_eq!(
+            index_events_by_block_height(deps.as_mut().storage, 2).unwrap(),
+            0
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use super::keys;
+use crate::types::exchange::{Exchange, PairExchange};
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+const EXCHANGES: Map<Addr, u64> = Map::new(keys::EXCHANGES);
+
+const PAIR_EXCHANGES: Map<(String, String), Addr> = Map::new(keys::PAIR_EXCHANGES);
+
+const EXCHANGE_CURSOR: Item<u64> = Item::new(keys::EXCHANGE_CURSOR);
+
+pub fn save_exchange(store: &mut dyn Storage, exchange: &Exchange) -> StdResult<()> {
+    EXCHANGES.save(store, exchange.address.clone(), &exchange.weight)
+}
+
+pub fn remove_exchange(store: &mut dyn Storage, address: &Addr) -> StdResult<()> {
+    EXCHANGES.remove(store, address.clone());
+
+    let pairs = PAIR_EXCHANGES
+        .range(store, None, None, Order::Ascending)
+        .filter(|entry| {
+            entry
+                .as_ref()
+                .map_or(true, |(_, exchange)| exchange == address)
+        })
+        .map(|entry| entry.map(|(pair, _)| pair))
+        .collect::<StdResult<Vec<(String, String)>>>()?;
+
+    for pair in pairs {
+        PAIR_EXCHANGES.remove(store, pair);
+    }
+
+    Ok(())
+}
+
+pub fn is_exchange(store: &dyn Storage, address: &Addr) -> bool {
+    EXCHANGES.has(store, address.clone())
+}
+
+pub fn get_exchanges(store: &dyn Storage) -> StdResult<Vec<Exchange>> {
+    EXCHANGES
+        .range(store, None, None, Order::Ascending)
+        .map(|entry| entry.map(|(address, weight)| Exchange { address, weight }))
+        .collect()
+}
+
+pub fn save_pair_exchange(
+    store: &mut dyn Storage,
+    swap_denom: String,
+    target_denom: String,
+    exchange: &Addr,
+) -> StdResult<()> {
+    PAIR_EXCHANGES.save(store, (swap_denom, target_denom), exchange)
+}
+
+pub fn remove_pair_exchange(store: &mut dyn Storage, swap_denom: String, target_denom: String) {
+    PAIR_EXCHANGES.remove(store, (swap_denom, target_denom))
+}
+
+pub fn get_pair_exchange(
+    store: &dyn Storage,
+    swap_denom: String,
+    target_denom: String,
+) -> StdResult<Option<Addr>> {
+    PAIR_EXCHANGES.may_load(store, (swap_denom, target_denom))
+}
+
+pub fn get_pair_exchanges(store: &dyn Storage) -> StdResult<Vec<PairExchange>> {
+    PAIR_EXCHANGES
+        .range(store, None, None, Order::Ascending)
+        .map(|entry| {
+            entry.map(|((swap_denom, target_denom), exchange)| PairExchange {
+                swap_denom,
+                target_denom,
+                exchange,
+            })
+        })
+        .collect()
+}
+
+pub fn get_and_increment_exchange_cursor(store: &mut dyn Storage) -> StdResult<u64> {
+    let cursor = EXCHANGE_CURSOR.may_load(store)?.unwrap_or_default();
+    EXCHANGE_CURSOR.save(store, &cursor.wrapping_add(1))?;
+    Ok(cursor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn removing_an_exchange_clears_its_pair_preferences() {
+        let mut deps = mock_dependencies();
+
+        let exchange = Exchange {
+            address: Addr::unchecked("astroport"),
+            weight: 1,
+        };
+
+        save_exchange(deps.as_mut().storage, &exchange).unwrap();
+        save_pair_exchange(
+            deps.as_mut().storage,
+            "ukuji".to_string(),
+            "uusk".to_string(),
+            &exchange.address,
+        )
+        .unwrap();
+        save_pair_exchange(
+            deps.as_mut().storage,
+            "uatom".to_string(),
+            "uusk".to_string(),
+            &Addr::unchecked("osmosis"),
+        )
+        .unwrap();
+
+        remove_exchange(deps.as_mut().storage, &exchange.address).unwrap();
+
+        assert!(!is_exchange(deps.as_ref().storage, &exchange.address));
+        assert_eq!(
+            get_pair_exchanges(deps.as_ref().storage).unwrap(),
+            vec![PairExchange {
+                swap_denom: "uatom".to_string(),
+                target_denom: "uusk".to_string(),
+                exchange: Addr::unchecked("osmosis"),
+            }]
+        );
+    }
+
+    #[test]
+    fn exchange_cursor_increments_on_each_read() {
+        let mut deps = mock_dependencies();
+
+        assert_eq!(
+            get_and_increment_exchange_cursor(deps.as_mut().storage).unwrap(),
+            0
+        );
+        assert_eq!(
+            get_and_increment_exchange_cursor(deps.as_mut().storage).unwrap(),
+            1
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use super::keys;
+use crate::types::executor_stats::ExecutorStats;
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const EXECUTOR_STATS: Map<Addr, ExecutorStats> = Map::new(keys::EXECUTOR_STATS);
+
+fn update_executor_stats(
+    store: &mut dyn Storage,
+    executor: &Addr,
+    update: impl FnOnce(ExecutorStats) -> ExecutorStats,
+) -> StdResult<ExecutorStats> {
+    EXECUTOR_STATS.update(store, executor.clone(), |stats| {
+        Ok(update(stats.unwrap_or_default()))
+    })
+}
+
+pub fn get_executor_stats(store: &dyn Storage, executor: &Addr) -> StdResult<ExecutorStats> {
+    Ok(EXECUTOR_STATS
+        .may_load(store, executor.clone())?
+        .unwrap_or_default())
+}
+
+pub fn record_trigger_executed(
+    store: &mut dyn Storage,
+    executor: &Addr,
+) -> StdResult<ExecutorStats> {
+    update_executor_stats(store, executor, |stats| ExecutorStats {
+        triggers_executed: stats.triggers_executed + 1,
+        ..stats
+    })
+}
+
+pub fn record_execution_skipped(
+    store: &mut dyn Storage,
+    executor: &Addr,
+) -> StdResult<ExecutorStats> {
+    update_executor_stats(store, executor, |stats| ExecutorStats {
+        executions_skipped: stats.executions_skipped + 1,
+        ..stats
+    })
+}
+
+pub fn record_failed_sub_msg(store: &mut dyn Storage, executor: &Addr) -> StdResult<ExecutorStats> {
+    update_executor_stats(store, executor, |stats| ExecutorStats {
+        failed_sub_msgs: stats.failed_sub_msgs + 1,
+        ..stats
+    })
+}
+
+pub fn record_executor_report(
+    store: &mut dyn Storage,
+    executor: &Addr,
+) -> StdResult<ExecutorStats> {
+    update_executor_stats(store, executor, |stats| ExecutorStats {
+        reports: stats.reports + 1,
+        ..stats
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn tracks_counters_per_executor() {
+        let mut deps = mock_dependencies();
+
+        let keeper = Addr::unchecked("keeper");
+        let other_keeper = Addr::unchecked("other-keeper");
+
+        record_trigger_executed(&mut deps.storage, &keeper).unwrap();
+        record_trigger_executed(&mut deps.storage, &keeper).unwrap();
+        record_execution_skipped(&mut deps.storage, &keeper).unwrap();
+        record_failed_sub_msg(&mut deps.storage, &keeper).unwrap();
+        record_executor_report(&mut deps.storage, &other_keeper).unwrap();
+
+        assert_eq!(
+            get_executor_stats(&deps.storage, &keeper).unwrap(),
+            ExecutorStats {
+                triggers_executed: 2,
+                executions_skipped: 1,
+                failed_sub_msgs: 1,
+                reports: 0,
+            }
+        );
+        assert_eq!(
+            get_executor_stats(&deps.storage, &other_keeper).unwrap(),
+            ExecutorStats {
+                reports: 1,
+                ..ExecutorStats::default()
+            }
+        );
+    }
+}
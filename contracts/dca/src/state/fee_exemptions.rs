@@ -0,0 +1,23 @@
+use super::keys;
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const FEE_EXEMPTIONS: Map<Addr, ()> = Map::new(keys::FEE_EXEMPTIONS);
+
+pub fn save_fee_exemption(store: &mut dyn Storage, address: &Addr) -> StdResult<()> {
+    FEE_EXEMPTIONS.save(store, address.clone(), &())
+}
+
+pub fn is_fee_exempt(store: &dyn Storage, address: &Addr) -> bool {
+    FEE_EXEMPTIONS.has(store, address.clone())
+}
+
+pub fn get_fee_exemptions(store: &dyn Storage) -> StdResult<Vec<Addr>> {
+    FEE_EXEMPTIONS
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()
+}
+
+pub fn remove_fee_exemption(store: &mut dyn Storage, address: &Addr) {
+    FEE_EXEMPTIONS.remove(store, address.clone())
+}
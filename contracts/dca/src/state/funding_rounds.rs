@@ -0,0 +1,119 @@
+use super::{keys, state_helpers::fetch_and_increment_counter};
+use crate::types::funding_round::{FundingRound, RoundContribution};
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+const FUNDING_ROUND_COUNTER: Item<u64> = Item::new(keys::FUNDING_ROUND_COUNTER);
+
+const FUNDING_ROUNDS: Map<u64, FundingRound> = Map::new(keys::FUNDING_ROUNDS);
+
+const ROUND_CONTRIBUTIONS: Map<(u64, u128, Addr), Uint128> = Map::new(keys::ROUND_CONTRIBUTIONS);
+
+pub fn save_funding_round(
+    store: &mut dyn Storage,
+    funding_round: FundingRound,
+) -> StdResult<FundingRound> {
+    let funding_round = FundingRound {
+        id: fetch_and_increment_counter(store, FUNDING_ROUND_COUNTER)?,
+        ..funding_round
+    };
+    FUNDING_ROUNDS.save(store, funding_round.id, &funding_round)?;
+    Ok(funding_round)
+}
+
+pub fn get_funding_round(store: &dyn Storage, round_id: u64) -> StdResult<FundingRound> {
+    FUNDING_ROUNDS.load(store, round_id)
+}
+
+pub fn update_funding_round(
+    store: &mut dyn Storage,
+    funding_round: &FundingRound,
+) -> StdResult<()> {
+    FUNDING_ROUNDS.save(store, funding_round.id, funding_round)
+}
+
+pub fn add_round_contribution(
+    store: &mut dyn Storage,
+    round_id: u64,
+    bounty_id: Uint128,
+    contributor: Addr,
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    ROUND_CONTRIBUTIONS.update(
+        store,
+        (round_id, bounty_id.into(), contributor),
+        |existing| -> StdResult<Uint128> { Ok(existing.unwrap_or_default() + amount) },
+    )
+}
+
+pub fn get_round_contributions(
+    store: &dyn Storage,
+    round_id: u64,
+    bounty_id: Uint128,
+) -> StdResult<Vec<RoundContribution>> {
+    ROUND_CONTRIBUTIONS
+        .prefix((round_id, bounty_id.into()))
+        .range(store, None, None, Order::Ascending)
+        .map(|result| {
+            result.map(|(contributor, amount)| RoundContribution {
+                contributor,
+                amount,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod funding_rounds_tests {
+    use super::*;
+    use crate::types::funding_round::MatchingFormula;
+    use cosmwasm_std::{testing::mock_dependencies, Coin, Timestamp};
+
+    #[test]
+    fn accumulates_contributions_per_contributor() {
+        let mut deps = mock_dependencies();
+
+        let funding_round = save_funding_round(
+            deps.as_mut().storage,
+            FundingRound {
+                id: 0,
+                sponsor: Addr::unchecked("sponsor"),
+                matching_pool: Coin::new(100, "uusk"),
+                formula: MatchingFormula::Quadratic,
+                bounty_ids: vec![Uint128::one()],
+                created_at: Timestamp::from_seconds(0),
+                ends_at: Timestamp::from_seconds(100),
+                finalized_at: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(funding_round.id, 1);
+
+        for (contributor, amount) in [("alice", 10u128), ("bob", 5), ("alice", 15)] {
+            add_round_contribution(
+                deps.as_mut().storage,
+                funding_round.id,
+                Uint128::one(),
+                Addr::unchecked(contributor),
+                Uint128::new(amount),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            get_round_contributions(deps.as_ref().storage, funding_round.id, Uint128::one())
+                .unwrap(),
+            vec![
+                RoundContribution {
+                    contributor: Addr::unchecked("alice"),
+                    amount: Uint128::new(25),
+                },
+                RoundContribution {
+                    contributor: Addr::unchecked("bob"),
+                    amount: Uint128::new(5),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use super::keys;
+use crate::types::execution_receipt::{ExecutionReceipt, GasUsageReport};
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const GAS_USAGE_REPORTS: Map<u128, GasUsageReport> = Map::new(keys::GAS_USAGE_REPORTS);
+
+pub fn record_execution_receipt(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    receipt: &ExecutionReceipt,
+) -> StdResult<GasUsageReport> {
+    GAS_USAGE_REPORTS.update(store, bounty_id.into(), |report| {
+        let report = report.unwrap_or_default();
+
+        Ok(GasUsageReport {
+            executions: report.executions + 1,
+            total_messages: report.total_messages + receipt.message_count as u64,
+            total_submessages: report.total_submessages + receipt.submessage_count as u64,
+            total_destination_callbacks: report.total_destination_callbacks
+                + receipt.destination_callback_count as u64,
+            total_estimated_gas: report.total_estimated_gas + receipt.estimated_gas(),
+            last_receipt: Some(receipt.clone()),
+        })
+    })
+}
+
+pub fn get_gas_usage_report(store: &dyn Storage, bounty_id: Uint128) -> StdResult<GasUsageReport> {
+    Ok(GAS_USAGE_REPORTS
+        .may_load(store, bounty_id.into())?
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    #[test]
+    fn accumulates_execution_receipts_for_bounty() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let first_receipt = ExecutionReceipt {
+            executed_at: env.block.time,
+            message_count: 2,
+            submessage_count: 1,
+            destination_callback_count: 0,
+        };
+        let second_receipt = ExecutionReceipt {
+            executed_at: env.block.time.plus_seconds(60),
+            message_count: 3,
+            submessage_count: 0,
+            destination_callback_count: 1,
+        };
+
+        record_execution_receipt(&mut deps.storage, Uint128::one(), &first_receipt).unwrap();
+        record_execution_receipt(&mut deps.storage, Uint128::one(), &second_receipt).unwrap();
+
+        assert_eq!(
+            get_gas_usage_report(&deps.storage, Uint128::one()).unwrap(),
+            GasUsageReport {
+                executions: 2,
+                total_messages: 5,
+                total_submessages: 1,
+                total_destination_callbacks: 1,
+                total_estimated_gas: first_receipt.estimated_gas() + second_receipt.estimated_gas(),
+                last_receipt: Some(second_receipt),
+            }
+        );
+    }
+
+    #[test]
+    fn returns_empty_report_for_unexecuted_bounty() {
+        let deps = mock_dependencies();
+
+        assert_eq!(
+            get_gas_usage_report(&deps.storage, Uint128::one()).unwrap(),
+            GasUsageReport::default()
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use super::keys;
+use crate::types::destination::IbcDestination;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+pub struct FailedIbcTransfer {
+    pub destination: IbcDestination,
+    pub amount: Coin,
+}
+
+const FAILED_IBC_TRANSFERS: Map<u128, Vec<FailedIbcTransfer>> =
+    Map::new(keys::FAILED_IBC_TRANSFERS);
+
+pub fn save_failed_ibc_transfer(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    transfer: FailedIbcTransfer,
+) -> StdResult<Vec<FailedIbcTransfer>> {
+    FAILED_IBC_TRANSFERS.update(store, bounty_id.into(), |transfers| {
+        let mut transfers = transfers.unwrap_or_default();
+        transfers.push(transfer);
+        Ok(transfers)
+    })
+}
+
+pub fn get_failed_ibc_transfers(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+) -> StdResult<Vec<FailedIbcTransfer>> {
+    Ok(FAILED_IBC_TRANSFERS
+        .may_load(store, bounty_id.into())?
+        .unwrap_or_default())
+}
+
+pub fn clear_failed_ibc_transfers(store: &mut dyn Storage, bounty_id: Uint128) {
+    FAILED_IBC_TRANSFERS.remove(store, bounty_id.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    fn failed_transfer(amount: u128) -> FailedIbcTransfer {
+        FailedIbcTransfer {
+            destination: IbcDestination {
+                channel_id: "channel-0".to_string(),
+                remote_address: "osmo1remote".to_string(),
+                timeout_seconds: None,
+            },
+            amount: Coin::new(amount, "uosmo"),
+        }
+    }
+
+    #[test]
+    fn accumulates_failed_transfers_per_bounty() {
+        let mut deps = mock_dependencies();
+
+        save_failed_ibc_transfer(&mut deps.storage, Uint128::one(), failed_transfer(10)).unwrap();
+        save_failed_ibc_transfer(&mut deps.storage, Uint128::one(), failed_transfer(5)).unwrap();
+        save_failed_ibc_transfer(&mut deps.storage, Uint128::new(2), failed_transfer(7)).unwrap();
+
+        assert_eq!(
+            get_failed_ibc_transfers(&deps.storage, Uint128::one()).unwrap(),
+            vec![failed_transfer(10), failed_transfer(5)]
+        );
+    }
+
+    #[test]
+    fn clearing_failed_transfers_leaves_other_bounties_untouched() {
+        let mut deps = mock_dependencies();
+
+        save_failed_ibc_transfer(&mut deps.storage, Uint128::one(), failed_transfer(10)).unwrap();
+        save_failed_ibc_transfer(&mut deps.storage, Uint128::new(2), failed_transfer(7)).unwrap();
+
+        clear_failed_ibc_transfers(&mut deps.storage, Uint128::one());
+
+        assert!(get_failed_ibc_transfers(&deps.storage, Uint128::one())
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            get_failed_ibc_transfers(&deps.storage, Uint128::new(2)).unwrap(),
+            vec![failed_transfer(7)]
+        );
+    }
+}
@@ -0,0 +1,496 @@
+//! Storage namespaces for every item, map and index the contract persists.
+//!
+//! Namespaces carry an explicit version suffix. When the shape of a stored
+//! value changes incompatibly, introduce a new namespace with a bumped suffix,
+//! move the old one to `LEGACY_NAMESPACES` and add a migration step below.
+//! Namespaces that are no longer written but may still hold data on chain are
+//! kept in `RETIRED_NAMESPACES` so they are never reused by accident.
+
+use cosmwasm_std::{StdError, StdResult, Storage};
+use cw_storage_plus::Item;
+use serde::{de::DeserializeOwned, Serialize};
+
+// Bounties
+
+pub const BOUNTY_COUNTER: &str = "bounty_counter_v1";
+pub const BOUNTIES: &str = "bounties_v1";
+pub const BOUNTIES_OWNER_INDEX: &str = "bounties_v1__owner";
+pub const BOUNTIES_OWNER_STATUS_INDEX: &str = "bounties_v1__owner_status";
+pub const BOUNTIES_STATUS_INDEX: &str = "bounties_v1__status";
+pub const BOUNTIES_BY_TAG: &str = "bounties_by_tag_v1";
+pub const LEGACY_VAULT_MIGRATION_CURSOR: &str = "legacy_vault_migration_cursor_v1";
+pub const ASSIGNEES: &str = "assignees_v1";
+pub const BOUNTY_APPLICATIONS: &str = "bounty_applications_v1";
+pub const BOUNTY_OPERATORS: &str = "bounty_operators_v1";
+pub const BOUNTY_SNAPSHOTS_ENABLED: &str = "bounty_snapshots_enabled_v1";
+pub const BOUNTY_SNAPSHOTS: &str = "bounty_snapshots_v1";
+pub const BOUNTY_TEMPLATE_COUNTER: &str = "bounty_template_counter_v1";
+pub const BOUNTY_TEMPLATES: &str = "bounty_templates_v1";
+pub const CALLBACK_RESULTS: &str = "callback_results_v1";
+pub const CHECKLISTS: &str = "checklists_v1";
+pub const CLAIMS: &str = "claims_v1";
+pub const COMPLETION_COUNTS: &str = "completion_counts_v1";
+pub const COMPLETIONS: &str = "completions_v1";
+pub const CONVERSION_REPORTS: &str = "conversion_reports_v1";
+pub const DELIVERED_AMOUNTS: &str = "delivered_amounts_v1";
+pub const DENOM_BALANCES: &str = "denom_balances_v1";
+pub const DESTINATION_PAYOUTS: &str = "destination_payouts_v2";
+pub const DISPUTES: &str = "disputes_v1";
+pub const ESCROW_EPOCHS: &str = "escrow_epochs_v1";
+pub const ESCROW_TRANCHE_SCHEDULES: &str = "escrow_tranche_schedules_v1";
+pub const FAILED_IBC_TRANSFERS: &str = "failed_ibc_transfers_v1";
+pub const GAS_USAGE_REPORTS: &str = "gas_usage_reports_v1";
+pub const OWNER_BALANCES: &str = "owner_balances_v2";
+pub const OWNER_BALANCE_REBUILD_CURSOR: &str = "owner_balance_rebuild_cursor_v1";
+pub const PROGRESS_UPDATE_COUNTERS: &str = "progress_update_counters_v1";
+pub const PROGRESS_UPDATES: &str = "progress_updates_v1";
+pub const LAST_PROGRESS_UPDATES: &str = "last_progress_updates_v1";
+pub const UPDATE_PERMISSIONS: &str = "update_permissions_v1";
+pub const VIEWING_KEYS: &str = "viewing_keys_v1";
+
+// Triggers and scheduling
+
+pub const TRIGGERS: &str = "triggers_v8";
+pub const TRIGGERS_DUE_DATE_INDEX: &str = "triggers_v8__due_date";
+pub const TRIGGERS_DUE_HEIGHT_INDEX: &str = "triggers_v8__due_height";
+pub const TRIGGERS_ORDER_IDX_INDEX: &str = "triggers_v8__order_idx";
+pub const BALANCE_TRIGGER_IDS: &str = "balance_trigger_ids_v1";
+pub const DISBURSE_ESCROW_TASKS: &str = "disburse_escrow_task_v8";
+pub const DISBURSE_ESCROW_TASKS_DUE_DATE_INDEX: &str = "disburse_escrow_task_v8__due_date";
+pub const SCHEDULED_CONFIG_UPDATE_COUNTER: &str = "scheduled_config_update_counter_v1";
+pub const SCHEDULED_CONFIG_UPDATES: &str = "scheduled_config_updates_v1";
+pub const SCHEDULED_CONFIG_UPDATES_ACTIVATES_AT_INDEX: &str =
+    "scheduled_config_updates_v1__activates_at";
+pub const SCHEDULED_PAYMENT_COUNTER: &str = "scheduled_payment_counter_v1";
+pub const SCHEDULED_PAYMENTS: &str = "scheduled_payments_v1";
+pub const SCHEDULED_PAYMENTS_DUE_DATE_INDEX: &str = "scheduled_payments_v1__due_date";
+pub const SCHEDULED_PAYMENTS_OWNER_INDEX: &str = "scheduled_payments_v1__owner";
+pub const REFUND_QUEUE_COUNTER: &str = "refund_queue_counter_v1";
+pub const REFUND_QUEUE: &str = "refund_queue_v1";
+pub const PARKED_REFUNDS: &str = "parked_refunds_v1";
+
+// Events
+
+pub const EVENT_COUNTER: &str = "event_counter_v8";
+pub const SERIALISED_EVENTS: &str = "serialised_events_v8";
+pub const SERIALISED_EVENTS_RESOURCE_ID_INDEX: &str = "serialised_events_v8__resource_id";
+pub const SERIALISED_EVENTS_BLOCK_HEIGHT_INDEX: &str = "serialised_events_v8__block_height";
+pub const EVENT_SUMMARIES: &str = "event_summaries_v1";
+pub const LEGACY_EVENT_MIGRATION_CURSOR: &str = "legacy_event_migration_cursor_v1";
+pub const EVENT_BLOCK_HEIGHT_INDEX_CURSOR: &str = "event_block_height_index_cursor_v1";
+
+// Configuration and access control
+
+pub const CONFIG: &str = "config_v9";
+pub const STORAGE_VERSION: &str = "storage_version_v1";
+pub const ADMIN_ACTION_COUNTER: &str = "admin_action_counter_v1";
+pub const ADMIN_ACTIONS: &str = "admin_actions_v1";
+pub const ALLOWED_DENOMS: &str = "allowed_denoms_v1";
+pub const CUSTOM_FEES: &str = "custom_fees_v1";
+pub const CW20_WHITELIST: &str = "cw20_whitelist_v1";
+pub const EXCHANGES: &str = "exchanges_v1";
+pub const EXCHANGE_CURSOR: &str = "exchange_cursor_v1";
+pub const EXECUTOR_STATS: &str = "executor_stats_v1";
+pub const FEE_EXEMPTIONS: &str = "fee_exemptions_v1";
+pub const MODEL_PROVIDERS: &str = "model_providers_v1";
+pub const MODEL_SUBMISSIONS: &str = "model_submissions_v1";
+pub const PAIR_EXCHANGES: &str = "pair_exchanges_v1";
+pub const ROLES: &str = "roles_v1";
+
+// Referrals and funding rounds
+
+pub const REFERRERS: &str = "referrers_v1";
+pub const REFERRED_BOUNTIES: &str = "referred_bounties_v1";
+pub const REFERRER_EARNINGS: &str = "referrer_earnings_v1";
+pub const FUNDING_ROUND_COUNTER: &str = "funding_round_counter_v1";
+pub const FUNDING_ROUNDS: &str = "funding_rounds_v1";
+pub const ROUND_CONTRIBUTIONS: &str = "round_contributions_v1";
+
+// Reply caches
+
+pub const BOUNTY_ID_CACHE: &str = "bounty_id_cache_v1";
+pub const EXECUTOR_CACHE: &str = "executor_cache_v1";
+pub const TWAP_PRICE_CACHE: &str = "twap_price_cache_v1";
+pub const LIMIT_ORDER_TARGET_PRICE_CACHE: &str = "limit_order_target_price_cache_v1";
+pub const SWAP_RETRY_CACHE: &str = "swap_retry_cache_v1";
+pub const POST_EXECUTION_ACTION_CACHE: &str = "post_execution_action_cache_v1";
+pub const REFUND_QUEUE_CACHE: &str = "refund_queue_cache_v1";
+
+// Legacy namespaces, read through aliases until migrated
+
+pub const LEGACY_VAULT_COUNTER: &str = "vault_counter_v8";
+pub const LEGACY_VAULTS: &str = "bounties_v8";
+
+// Retired namespaces, never written again
+
+pub const RETIRED_SWAP_ADJUSTMENTS: &str = "swap_adjustments_v8";
+pub const RETIRED_SWAP_CACHE: &str = "swap_cache_v1";
+pub const RETIRED_OWNER_BALANCES: &str = "owner_balances_v1";
+pub const RETIRED_DESTINATION_PAYOUTS: &str = "destination_payouts_v1";
+
+pub const NAMESPACES: &[&str] = &[
+    BOUNTY_COUNTER,
+    BOUNTIES,
+    BOUNTIES_OWNER_INDEX,
+    BOUNTIES_OWNER_STATUS_INDEX,
+    BOUNTIES_STATUS_INDEX,
+    BOUNTIES_BY_TAG,
+    LEGACY_VAULT_MIGRATION_CURSOR,
+    ASSIGNEES,
+    BOUNTY_APPLICATIONS,
+    BOUNTY_OPERATORS,
+    BOUNTY_SNAPSHOTS_ENABLED,
+    BOUNTY_SNAPSHOTS,
+    BOUNTY_TEMPLATE_COUNTER,
+    BOUNTY_TEMPLATES,
+    CALLBACK_RESULTS,
+    CHECKLISTS,
+    CLAIMS,
+    COMPLETION_COUNTS,
+    COMPLETIONS,
+    CONVERSION_REPORTS,
+    DELIVERED_AMOUNTS,
+    DENOM_BALANCES,
+    DESTINATION_PAYOUTS,
+    DISPUTES,
+    ESCROW_EPOCHS,
+    ESCROW_TRANCHE_SCHEDULES,
+    FAILED_IBC_TRANSFERS,
+    GAS_USAGE_REPORTS,
+    OWNER_BALANCES,
+    OWNER_BALANCE_REBUILD_CURSOR,
+    PROGRESS_UPDATE_COUNTERS,
+    PROGRESS_UPDATES,
+    LAST_PROGRESS_UPDATES,
+    UPDATE_PERMISSIONS,
+    VIEWING_KEYS,
+    TRIGGERS,
+    TRIGGERS_DUE_DATE_INDEX,
+    TRIGGERS_DUE_HEIGHT_INDEX,
+    TRIGGERS_ORDER_IDX_INDEX,
+    BALANCE_TRIGGER_IDS,
+    DISBURSE_ESCROW_TASKS,
+    DISBURSE_ESCROW_TASKS_DUE_DATE_INDEX,
+    SCHEDULED_CONFIG_UPDATE_COUNTER,
+    SCHEDULED_CONFIG_UPDATES,
+    SCHEDULED_CONFIG_UPDATES_ACTIVATES_AT_INDEX,
+    SCHEDULED_PAYMENT_COUNTER,
+    SCHEDULED_PAYMENTS,
+    SCHEDULED_PAYMENTS_DUE_DATE_INDEX,
+    SCHEDULED_PAYMENTS_OWNER_INDEX,
+    REFUND_QUEUE_COUNTER,
+    REFUND_QUEUE,
+    PARKED_REFUNDS,
+    EVENT_COUNTER,
+    SERIALISED_EVENTS,
+    SERIALISED_EVENTS_RESOURCE_ID_INDEX,
+    SERIALISED_EVENTS_BLOCK_HEIGHT_INDEX,
+    EVENT_SUMMARIES,
+    LEGACY_EVENT_MIGRATION_CURSOR,
+    EVENT_BLOCK_HEIGHT_INDEX_CURSOR,
+    CONFIG,
+    STORAGE_VERSION,
+    ADMIN_ACTION_COUNTER,
+    ADMIN_ACTIONS,
+    ALLOWED_DENOMS,
+    CUSTOM_FEES,
+    CW20_WHITELIST,
+    EXCHANGES,
+    EXCHANGE_CURSOR,
+    EXECUTOR_STATS,
+    FEE_EXEMPTIONS,
+    MODEL_PROVIDERS,
+    MODEL_SUBMISSIONS,
+    PAIR_EXCHANGES,
+    ROLES,
+    REFERRERS,
+    REFERRED_BOUNTIES,
+    REFERRER_EARNINGS,
+    FUNDING_ROUND_COUNTER,
+    FUNDING_ROUNDS,
+    ROUND_CONTRIBUTIONS,
+    BOUNTY_ID_CACHE,
+    EXECUTOR_CACHE,
+    TWAP_PRICE_CACHE,
+    LIMIT_ORDER_TARGET_PRICE_CACHE,
+    SWAP_RETRY_CACHE,
+    POST_EXECUTION_ACTION_CACHE,
+    REFUND_QUEUE_CACHE,
+];
+
+pub const LEGACY_NAMESPACES: &[&str] = &[LEGACY_VAULT_COUNTER, LEGACY_VAULTS];
+
+pub const RETIRED_NAMESPACES: &[&str] = &[
+    RETIRED_SWAP_ADJUSTMENTS,
+    RETIRED_SWAP_CACHE,
+    RETIRED_OWNER_BALANCES,
+    RETIRED_DESTINATION_PAYOUTS,
+];
+
+/// Bumped whenever a migration step is added to `migrate_storage_keys`.
+pub const CURRENT_STORAGE_VERSION: u16 = 2;
+
+const STORAGE_VERSION_ITEM: Item<u16> = Item::new(STORAGE_VERSION);
+
+const LEGACY_VAULT_COUNTER_ITEM: Item<u64> = Item::new(LEGACY_VAULT_COUNTER);
+
+const OWNER_BALANCE_REBUILD_CURSOR_ITEM: Item<u128> = Item::new(OWNER_BALANCE_REBUILD_CURSOR);
+
+const LEGACY_EVENT_MIGRATION_CURSOR_ITEM: Item<u64> = Item::new(LEGACY_EVENT_MIGRATION_CURSOR);
+
+const EVENT_BLOCK_HEIGHT_INDEX_CURSOR_ITEM: Item<u64> = Item::new(EVENT_BLOCK_HEIGHT_INDEX_CURSOR);
+
+/// Loads `item`, falling back to `legacy` when the current namespace has not
+/// been written yet.
+pub fn may_load_aliased<T>(
+    store: &dyn Storage,
+    item: &Item<T>,
+    legacy: &Item<T>,
+) -> StdResult<Option<T>>
+where
+    T: Serialize + DeserializeOwned,
+{
+    match item.may_load(store)? {
+        Some(value) => Ok(Some(value)),
+        None => legacy.may_load(store),
+    }
+}
+
+/// Copies `legacy` into `item` unless `item` already holds a value, then
+/// removes `legacy`. Returns whether a value was moved.
+pub fn migrate_aliased_item<T>(
+    store: &mut dyn Storage,
+    item: &Item<T>,
+    legacy: &Item<T>,
+) -> StdResult<bool>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let value = match legacy.may_load(store)? {
+        Some(value) => value,
+        None => return Ok(false),
+    };
+
+    if item.may_load(store)?.is_none() {
+        item.save(store, &value)?;
+    }
+
+    legacy.remove(store);
+
+    Ok(true)
+}
+
+pub fn get_storage_version(store: &dyn Storage) -> StdResult<u16> {
+    Ok(STORAGE_VERSION_ITEM.may_load(store)?.unwrap_or_default())
+}
+
+/// Records a freshly instantiated contract as being on the current storage
+/// version, so no migration steps run against it.
+pub fn initialise_storage_version(store: &mut dyn Storage) -> StdResult<()> {
+    STORAGE_VERSION_ITEM.save(store, &CURRENT_STORAGE_VERSION)
+}
+
+/// Moves legacy keys to their current namespaces and records the storage
+/// version. Safe to run on every migration.
+pub fn migrate_storage_keys(store: &mut dyn Storage) -> StdResult<u64> {
+    let version = get_storage_version(store)?;
+
+    if version > CURRENT_STORAGE_VERSION {
+        return Err(StdError::generic_err(format!(
+            "Cannot migrate storage from version {} to older version {}",
+            version, CURRENT_STORAGE_VERSION
+        )));
+    }
+
+    let mut migrated_keys = 0;
+
+    if migrate_aliased_item(
+        store,
+        &Item::<u64>::new(BOUNTY_COUNTER),
+        &LEGACY_VAULT_COUNTER_ITEM,
+    )? {
+        migrated_keys += 1;
+    }
+
+    // Version 2 moved owner balances to a new namespace that also counts
+    // queued denom balances, changed the shape of bounty updated events and
+    // indexed events by block height. All are migrated in chunks by later
+    // migrations.
+    if version < 2 {
+        OWNER_BALANCE_REBUILD_CURSOR_ITEM.save(store, &0)?;
+        LEGACY_EVENT_MIGRATION_CURSOR_ITEM.save(store, &0)?;
+        EVENT_BLOCK_HEIGHT_INDEX_CURSOR_ITEM.save(store, &0)?;
+    }
+
+    STORAGE_VERSION_ITEM.save(store, &CURRENT_STORAGE_VERSION)?;
+
+    Ok(migrated_keys)
+}
+
+#[cfg(test)]
+mod namespace_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+    use std::collections::HashSet;
+
+    #[test]
+    fn namespaces_are_unique() {
+        let mut seen = HashSet::new();
+
+        for namespace in NAMESPACES
+            .iter()
+            .chain(LEGACY_NAMESPACES)
+            .chain(RETIRED_NAMESPACES)
+        {
+            assert!(seen.insert(namespace), "duplicate namespace {}", namespace);
+        }
+    }
+
+    #[test]
+    fn namespaces_are_versioned() {
+        for namespace in NAMESPACES
+            .iter()
+            .chain(LEGACY_NAMESPACES)
+            .chain(RETIRED_NAMESPACES)
+        {
+            let base = namespace.split("__").next().unwrap();
+            let (_, version) = base.rsplit_once("_v").expect("versioned namespace");
+
+            assert!(
+                version.parse::<u16>().is_ok(),
+                "unversioned namespace {}",
+                namespace
+            );
+        }
+    }
+
+    #[test]
+    fn reads_legacy_value_through_alias() {
+        let mut deps = mock_dependencies();
+
+        LEGACY_VAULT_COUNTER_ITEM
+            .save(deps.as_mut().storage, &7)
+            .unwrap();
+
+        assert_eq!(
+            may_load_aliased(
+                deps.as_ref().storage,
+                &Item::<u64>::new(BOUNTY_COUNTER),
+                &LEGACY_VAULT_COUNTER_ITEM
+            )
+            .unwrap(),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn migrates_legacy_keys_once() {
+        let mut deps = mock_dependencies();
+
+        LEGACY_VAULT_COUNTER_ITEM
+            .save(deps.as_mut().storage, &7)
+            .unwrap();
+
+        assert_eq!(migrate_storage_keys(deps.as_mut().storage).unwrap(), 1);
+        assert_eq!(migrate_storage_keys(deps.as_mut().storage).unwrap(), 0);
+
+        assert_eq!(
+            Item::<u64>::new(BOUNTY_COUNTER)
+                .load(deps.as_ref().storage)
+                .unwrap(),
+            7
+        );
+        assert!(LEGACY_VAULT_COUNTER_ITEM
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            get_storage_version(deps.as_ref().storage).unwrap(),
+            CURRENT_STORAGE_VERSION
+        );
+    }
+
+    #[test]
+    fn starts_owner_balance_rebuild_when_upgrading_from_version_1() {
+        let mut deps = mock_dependencies();
+
+        STORAGE_VERSION_ITEM
+            .save(deps.as_mut().storage, &1)
+            .unwrap();
+
+        migrate_storage_keys(deps.as_mut().storage).unwrap();
+
+        assert_eq!(
+            OWNER_BALANCE_REBUILD_CURSOR_ITEM
+                .may_load(deps.as_ref().storage)
+                .unwrap(),
+            Some(0)
+        );
+        assert_eq!(
+            LEGACY_EVENT_MIGRATION_CURSOR_ITEM
+                .may_load(deps.as_ref().storage)
+                .unwrap(),
+            Some(0)
+        );
+        assert_eq!(
+            EVENT_BLOCK_HEIGHT_INDEX_CURSOR_ITEM
+                .may_load(deps.as_ref().storage)
+                .unwrap(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn does_not_rebuild_owner_balances_for_initialised_storage() {
+        let mut deps = mock_dependencies();
+
+        initialise_storage_version(deps.as_mut().storage).unwrap();
+
+        migrate_storage_keys(deps.as_mut().storage).unwrap();
+
+        assert!(OWNER_BALANCE_REBUILD_CURSOR_ITEM
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+        assert!(LEGACY_EVENT_MIGRATION_CURSOR_ITEM
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+        assert!(EVENT_BLOCK_HEIGHT_INDEX_CURSOR_ITEM
+            .may_load(deps.as_ref().storage)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn keeps_current_value_over_legacy_value() {
+        let mut deps = mock_dependencies();
+
+        Item::<u64>::new(BOUNTY_COUNTER)
+            .save(deps.as_mut().storage, &9)
+            .unwrap();
+        LEGACY_VAULT_COUNTER_ITEM
+            .save(deps.as_mut().storage, &7)
+            .unwrap();
+
+        migrate_storage_keys(deps.as_mut().storage).unwrap();
+
+        assert_eq!(
+            Item::<u64>::new(BOUNTY_COUNTER)
+                .load(deps.as_ref().storage)
+                .unwrap(),
+            9
+        );
+    }
+
+    #[test]
+    fn refuses_to_migrate_from_newer_storage_version() {
+        let mut deps = mock_dependencies();
+
+        STORAGE_VERSION_ITEM
+            .save(deps.as_mut().storage, &(CURRENT_STORAGE_VERSION + 1))
+            .unwrap();
+
+        assert!(migrate_storage_keys(deps.as_mut().storage).is_err());
+    }
+}
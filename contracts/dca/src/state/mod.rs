@@ -1,8 +1,46 @@
+pub mod admin_actions;
+pub mod allowed_denoms;
+pub mod applications;
+pub mod assignees;
+pub mod authz;
+pub mod bounty_snapshots;
 pub mod cache;
+pub mod callback_results;
+pub mod checklists;
+pub mod claims;
+pub mod completions;
 pub mod config;
+pub mod conversion_reports;
+pub mod custom_fees;
+pub mod cw20_whitelist;
+pub mod delivered_amounts;
+pub mod denom_balances;
+pub mod destination_payouts;
 pub mod disburse_escrow_tasks;
+pub mod disputes;
+pub mod escrow_epochs;
+pub mod escrow_tranches;
+pub mod event_summaries;
 pub mod events;
+pub mod exchanges;
+pub mod executor_stats;
+pub mod fee_exemptions;
+pub mod funding_rounds;
+pub mod gas_usage_reports;
+pub mod ibc_transfers;
+pub mod keys;
+pub mod model_adjustments;
+pub mod owner_balances;
+pub mod progress_updates;
+pub mod referrals;
+pub mod refund_queue;
+pub mod roles;
+pub mod scheduled_config_updates;
+pub mod scheduled_payments;
 pub mod state_helpers;
 pub mod swap_adjustments;
+pub mod templates;
 pub mod triggers;
+pub mod update_permissions;
+pub mod viewing_keys;
 pub mod bounties;
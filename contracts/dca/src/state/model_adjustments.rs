@@ -0,0 +1,168 @@
+use super::keys;
+use crate::{constants::MODEL_ADJUSTMENT_TTL_SECONDS, helpers::math::median};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+struct ModelSubmission {
+    value: Decimal,
+    timestamp: u64,
+}
+
+const MODEL_PROVIDERS: Map<Addr, ()> = Map::new(keys::MODEL_PROVIDERS);
+
+const MODEL_SUBMISSIONS: Map<(u8, Addr), ModelSubmission> = Map::new(keys::MODEL_SUBMISSIONS);
+
+pub fn save_model_provider(store: &mut dyn Storage, provider: &Addr) -> StdResult<()> {
+    MODEL_PROVIDERS.save(store, provider.clone(), &())
+}
+
+pub fn remove_model_provider(store: &mut dyn Storage, provider: &Addr) {
+    MODEL_PROVIDERS.remove(store, provider.clone())
+}
+
+pub fn is_model_provider(store: &dyn Storage, provider: &Addr) -> bool {
+    MODEL_PROVIDERS.has(store, provider.clone())
+}
+
+pub fn get_model_providers(store: &dyn Storage) -> StdResult<Vec<Addr>> {
+    MODEL_PROVIDERS
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()
+}
+
+pub fn save_model_submission(
+    store: &mut dyn Storage,
+    model_id: u8,
+    provider: &Addr,
+    value: Decimal,
+    block_time: Timestamp,
+) -> StdResult<()> {
+    MODEL_SUBMISSIONS.save(
+        store,
+        (model_id, provider.clone()),
+        &ModelSubmission {
+            value,
+            timestamp: block_time.seconds(),
+        },
+    )
+}
+
+pub fn get_recent_model_submissions(
+    store: &dyn Storage,
+    model_id: u8,
+    block_time: Timestamp,
+) -> StdResult<Vec<Decimal>> {
+    Ok(MODEL_SUBMISSIONS
+        .prefix(model_id)
+        .range(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, ModelSubmission)>>>()?
+        .into_iter()
+        .filter(|(provider, submission)| {
+            submission.timestamp + MODEL_ADJUSTMENT_TTL_SECONDS > block_time.seconds()
+                && is_model_provider(store, provider)
+        })
+        .map(|(_, submission)| submission.value)
+        .collect())
+}
+
+pub fn get_model_adjustment(
+    store: &dyn Storage,
+    model_id: u8,
+    block_time: Timestamp,
+) -> StdResult<Option<Decimal>> {
+    Ok(median(get_recent_model_submissions(
+        store, model_id, block_time,
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    #[test]
+    fn takes_median_of_recent_submissions_from_providers() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        for (provider, value) in [
+            ("provider-1", Decimal::percent(90)),
+            ("provider-2", Decimal::percent(100)),
+            ("provider-3", Decimal::percent(300)),
+        ] {
+            save_model_provider(&mut deps.storage, &Addr::unchecked(provider)).unwrap();
+            save_model_submission(
+                &mut deps.storage,
+                30,
+                &Addr::unchecked(provider),
+                value,
+                env.block.time,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            get_model_adjustment(&deps.storage, 30, env.block.time).unwrap(),
+            Some(Decimal::percent(100))
+        );
+        assert_eq!(
+            get_model_adjustment(&deps.storage, 60, env.block.time).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn ignores_stale_submissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let provider = Addr::unchecked("provider");
+
+        save_model_provider(&mut deps.storage, &provider).unwrap();
+        save_model_submission(
+            &mut deps.storage,
+            30,
+            &provider,
+            Decimal::percent(90),
+            env.block.time,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_model_adjustment(
+                &deps.storage,
+                30,
+                env.block.time.plus_seconds(MODEL_ADJUSTMENT_TTL_SECONDS)
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn ignores_submissions_from_removed_providers() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let provider = Addr::unchecked("provider");
+
+        save_model_provider(&mut deps.storage, &provider).unwrap();
+        save_model_submission(
+            &mut deps.storage,
+            30,
+            &provider,
+            Decimal::percent(90),
+            env.block.time,
+        )
+        .unwrap();
+        remove_model_provider(&mut deps.storage, &provider);
+
+        assert!(
+            get_recent_model_submissions(&deps.storage, 30, env.block.time)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use super::keys;
+use cosmwasm_std::{Addr, Coin, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const OWNER_BALANCES: Map<(Addr, String), Uint128> = Map::new(keys::OWNER_BALANCES);
+
+pub fn get_owner_balance(store: &dyn Storage, owner: &Addr, denom: &str) -> StdResult<Uint128> {
+    Ok(OWNER_BALANCES
+        .may_load(store, (owner.clone(), denom.to_string()))?
+        .unwrap_or_default())
+}
+
+pub fn add_to_owner_balance(store: &mut dyn Storage, owner: &Addr, amount: &Coin) -> StdResult<()> {
+    if amount.amount.is_zero() {
+        return Ok(());
+    }
+
+    OWNER_BALANCES.update(
+        store,
+        (owner.clone(), amount.denom.clone()),
+        |balance| -> StdResult<Uint128> { Ok(balance.unwrap_or_default() + amount.amount) },
+    )?;
+
+    Ok(())
+}
+
+pub fn subtract_from_owner_balance(
+    store: &mut dyn Storage,
+    owner: &Addr,
+    amount: &Coin,
+) -> StdResult<()> {
+    let key = (owner.clone(), amount.denom.clone());
+
+    let balance = OWNER_BALANCES
+        .may_load(store, key.clone())?
+        .unwrap_or_default()
+        .saturating_sub(amount.amount);
+
+    if balance.is_zero() {
+        OWNER_BALANCES.remove(store, key);
+        Ok(())
+    } else {
+        OWNER_BALANCES.save(store, key, &balance)
+    }
+}
+
+#[cfg(test)]
+mod owner_balances_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn accumulates_and_reduces_owner_balances() {
+        let mut deps = mock_dependencies();
+
+        let owner = Addr::unchecked("owner");
+
+        add_to_owner_balance(deps.as_mut().storage, &owner, &Coin::new(10, "uusk")).unwrap();
+        add_to_owner_balance(deps.as_mut().storage, &owner, &Coin::new(5, "uusk")).unwrap();
+        subtract_from_owner_balance(deps.as_mut().storage, &owner, &Coin::new(3, "uusk")).unwrap();
+
+        assert_eq!(
+            get_owner_balance(deps.as_ref().storage, &owner, "uusk").unwrap(),
+            Uint128::new(12)
+        );
+        assert_eq!(
+            get_owner_balance(deps.as_ref().storage, &owner, "ukuji").unwrap(),
+            Uint128::zero()
+        );
+    }
+
+    #[test]
+    fn saturates_at_zero() {
+        let mut deps = mock_dependencies();
+
+        let owner = Addr::unchecked("owner");
+
+        add_to_owner_balance(deps.as_mut().storage, &owner, &Coin::new(2, "uusk")).unwrap();
+        subtract_from_owner_balance(deps.as_mut().storage, &owner, &Coin::new(5, "uusk")).unwrap();
+
+        assert_eq!(
+            get_owner_balance(deps.as_ref().storage, &owner, "uusk").unwrap(),
+            Uint128::zero()
+        );
+    }
+}
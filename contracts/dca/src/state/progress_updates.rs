@@ -0,0 +1,137 @@
+use super::keys;
+use crate::helpers::pagination::Page;
+use crate::types::progress_update::ProgressUpdate;
+use cosmwasm_std::{Addr, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::Map;
+
+const PROGRESS_UPDATE_COUNTERS: Map<u128, u64> = Map::new(keys::PROGRESS_UPDATE_COUNTERS);
+const PROGRESS_UPDATES: Map<(u128, u64), ProgressUpdate> = Map::new(keys::PROGRESS_UPDATES);
+const LAST_PROGRESS_UPDATES: Map<(u128, Addr), Timestamp> = Map::new(keys::LAST_PROGRESS_UPDATES);
+
+pub fn save_progress_update(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    progress_update: ProgressUpdate,
+) -> StdResult<ProgressUpdate> {
+    let id = PROGRESS_UPDATE_COUNTERS
+        .may_load(store, bounty_id.into())?
+        .unwrap_or_default()
+        + 1;
+
+    let progress_update = ProgressUpdate {
+        id,
+        ..progress_update
+    };
+
+    PROGRESS_UPDATE_COUNTERS.save(store, bounty_id.into(), &id)?;
+    PROGRESS_UPDATES.save(store, (bounty_id.into(), id), &progress_update)?;
+    LAST_PROGRESS_UPDATES.save(
+        store,
+        (bounty_id.into(), progress_update.assignee.clone()),
+        &progress_update.posted_at,
+    )?;
+
+    Ok(progress_update)
+}
+
+pub fn get_last_progress_update_time(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    assignee: &Addr,
+) -> StdResult<Option<Timestamp>> {
+    LAST_PROGRESS_UPDATES.may_load(store, (bounty_id.into(), assignee.clone()))
+}
+
+pub fn get_progress_updates(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    start_after: Option<u64>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<Vec<ProgressUpdate>> {
+    let page = Page::new(store, start_after, limit, reverse)?;
+    let (min, max) = page.bounds();
+
+    PROGRESS_UPDATES
+        .prefix(bounty_id.into())
+        .range(store, min, max, page.order)
+        .take(page.limit)
+        .map(|result| result.map(|(_, progress_update)| progress_update))
+        .collect::<StdResult<Vec<ProgressUpdate>>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    fn progress_update(assignee: &str) -> ProgressUpdate {
+        ProgressUpdate {
+            id: 0,
+            assignee: Addr::unchecked(assignee),
+            message_hash: [1; 32],
+            uri: None,
+            posted_at: mock_env().block.time,
+        }
+    }
+
+    #[test]
+    fn assigns_sequential_ids_per_bounty() {
+        let mut deps = mock_dependencies();
+
+        save_progress_update(&mut deps.storage, Uint128::one(), progress_update("a")).unwrap();
+        save_progress_update(&mut deps.storage, Uint128::one(), progress_update("b")).unwrap();
+        save_progress_update(&mut deps.storage, Uint128::new(2), progress_update("a")).unwrap();
+
+        assert_eq!(
+            get_progress_updates(&deps.storage, Uint128::one(), None, Some(10), None)
+                .unwrap()
+                .iter()
+                .map(|progress_update| progress_update.id)
+                .collect::<Vec<u64>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            get_progress_updates(&deps.storage, Uint128::new(2), None, Some(10), None)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn paginates_with_start_after() {
+        let mut deps = mock_dependencies();
+
+        for _ in 0..5 {
+            save_progress_update(&mut deps.storage, Uint128::one(), progress_update("a")).unwrap();
+        }
+
+        assert_eq!(
+            get_progress_updates(&deps.storage, Uint128::one(), Some(2), Some(2), None)
+                .unwrap()
+                .iter()
+                .map(|progress_update| progress_update.id)
+                .collect::<Vec<u64>>(),
+            vec![3, 4]
+        );
+    }
+
+    #[test]
+    fn records_last_update_time_per_assignee() {
+        let mut deps = mock_dependencies();
+
+        save_progress_update(&mut deps.storage, Uint128::one(), progress_update("a")).unwrap();
+
+        assert_eq!(
+            get_last_progress_update_time(&deps.storage, Uint128::one(), &Addr::unchecked("a"))
+                .unwrap(),
+            Some(mock_env().block.time)
+        );
+        assert_eq!(
+            get_last_progress_update_time(&deps.storage, Uint128::one(), &Addr::unchecked("b"))
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use super::keys;
+use cosmwasm_std::{Addr, Coin, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const REFERRERS: Map<&str, Addr> = Map::new(keys::REFERRERS);
+
+const REFERRED_BOUNTIES: Map<Addr, u64> = Map::new(keys::REFERRED_BOUNTIES);
+
+const REFERRER_EARNINGS: Map<(Addr, String), Uint128> = Map::new(keys::REFERRER_EARNINGS);
+
+pub fn save_referrer(store: &mut dyn Storage, code: &str, referrer: &Addr) -> StdResult<()> {
+    REFERRERS.save(store, code, referrer)
+}
+
+pub fn get_referrer(store: &dyn Storage, code: &str) -> StdResult<Option<Addr>> {
+    REFERRERS.may_load(store, code)
+}
+
+pub fn add_referred_bounty(store: &mut dyn Storage, referrer: &Addr) -> StdResult<u64> {
+    REFERRED_BOUNTIES.update(store, referrer.clone(), |count| {
+        Ok(count.unwrap_or_default() + 1)
+    })
+}
+
+pub fn get_referred_bounties(store: &dyn Storage, referrer: &Addr) -> StdResult<u64> {
+    Ok(REFERRED_BOUNTIES
+        .may_load(store, referrer.clone())?
+        .unwrap_or_default())
+}
+
+pub fn add_referrer_earnings(
+    store: &mut dyn Storage,
+    referrer: &Addr,
+    earnings: &Coin,
+) -> StdResult<Uint128> {
+    REFERRER_EARNINGS.update(
+        store,
+        (referrer.clone(), earnings.denom.clone()),
+        |earned| Ok(earned.unwrap_or_default() + earnings.amount),
+    )
+}
+
+pub fn get_referrer_earnings(store: &dyn Storage, referrer: &Addr) -> StdResult<Vec<Coin>> {
+    REFERRER_EARNINGS
+        .prefix(referrer.clone())
+        .range(store, None, None, Order::Ascending)
+        .map(|result| result.map(|(denom, amount)| Coin::new(amount.into(), denom)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn accumulates_referrer_earnings_per_denom() {
+        let mut deps = mock_dependencies();
+
+        let referrer = Addr::unchecked("referrer");
+
+        add_referrer_earnings(&mut deps.storage, &referrer, &Coin::new(10, "uosmo")).unwrap();
+        add_referrer_earnings(&mut deps.storage, &referrer, &Coin::new(5, "uosmo")).unwrap();
+        add_referrer_earnings(&mut deps.storage, &referrer, &Coin::new(7, "uusk")).unwrap();
+
+        assert_eq!(
+            get_referrer_earnings(&deps.storage, &referrer).unwrap(),
+            vec![Coin::new(15, "uosmo"), Coin::new(7, "uusk")]
+        );
+    }
+
+    #[test]
+    fn counts_referred_bounties() {
+        let mut deps = mock_dependencies();
+
+        let referrer = Addr::unchecked("referrer");
+
+        add_referred_bounty(&mut deps.storage, &referrer).unwrap();
+        add_referred_bounty(&mut deps.storage, &referrer).unwrap();
+
+        assert_eq!(get_referred_bounties(&deps.storage, &referrer).unwrap(), 2);
+    }
+}
@@ -0,0 +1,128 @@
+use super::keys;
+use crate::types::queued_refund::QueuedRefund;
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+const REFUND_QUEUE_COUNTER: Item<u64> = Item::new(keys::REFUND_QUEUE_COUNTER);
+
+const REFUND_QUEUE: Map<u64, QueuedRefund> = Map::new(keys::REFUND_QUEUE);
+
+const PARKED_REFUNDS: Map<u64, QueuedRefund> = Map::new(keys::PARKED_REFUNDS);
+
+pub fn enqueue_refund(store: &mut dyn Storage, refund: QueuedRefund) -> StdResult<QueuedRefund> {
+    let id = REFUND_QUEUE_COUNTER.may_load(store)?.unwrap_or_default() + 1;
+    let refund = QueuedRefund { id, ..refund };
+
+    REFUND_QUEUE_COUNTER.save(store, &id)?;
+    REFUND_QUEUE.save(store, id, &refund)?;
+
+    Ok(refund)
+}
+
+pub fn get_queued_refunds(store: &dyn Storage, limit: u16) -> StdResult<Vec<QueuedRefund>> {
+    REFUND_QUEUE
+        .range(store, None, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|entry| entry.map(|(_, refund)| refund))
+        .collect()
+}
+
+pub fn remove_queued_refund(store: &mut dyn Storage, id: u64) {
+    REFUND_QUEUE.remove(store, id)
+}
+
+/// Moves a refund whose transfer failed out of the queue so it no longer
+/// blocks the refunds behind it.
+pub fn park_queued_refund(store: &mut dyn Storage, id: u64) -> StdResult<()> {
+    if let Some(refund) = REFUND_QUEUE.may_load(store, id)? {
+        PARKED_REFUNDS.save(store, id, &refund)?;
+        REFUND_QUEUE.remove(store, id);
+    }
+    Ok(())
+}
+
+pub fn get_parked_refunds(store: &dyn Storage, limit: u16) -> StdResult<Vec<QueuedRefund>> {
+    PARKED_REFUNDS
+        .range(store, None, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|entry| entry.map(|(_, refund)| refund))
+        .collect()
+}
+
+pub fn has_queued_refunds(store: &dyn Storage) -> bool {
+    REFUND_QUEUE
+        .keys(store, None, None, Order::Ascending)
+        .next()
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{testing::mock_dependencies, Addr, Coin, Timestamp, Uint128};
+
+    fn refund(denom: &str) -> QueuedRefund {
+        QueuedRefund {
+            id: 0,
+            bounty_id: Uint128::one(),
+            recipient: Addr::unchecked("owner"),
+            amount: Coin::new(100, denom),
+            queued_at: Timestamp::from_seconds(0),
+        }
+    }
+
+    #[test]
+    fn enqueued_refunds_are_returned_in_order() {
+        let mut deps = mock_dependencies();
+
+        enqueue_refund(deps.as_mut().storage, refund("ukuji")).unwrap();
+        enqueue_refund(deps.as_mut().storage, refund("uusk")).unwrap();
+        enqueue_refund(deps.as_mut().storage, refund("uatom")).unwrap();
+
+        let refunds = get_queued_refunds(deps.as_ref().storage, 2).unwrap();
+
+        assert_eq!(
+            refunds
+                .iter()
+                .map(|refund| (refund.id, refund.amount.denom.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "ukuji"), (2, "uusk")]
+        );
+    }
+
+    #[test]
+    fn removed_refunds_are_no_longer_queued() {
+        let mut deps = mock_dependencies();
+
+        let refund = enqueue_refund(deps.as_mut().storage, refund("ukuji")).unwrap();
+
+        assert!(has_queued_refunds(deps.as_ref().storage));
+
+        remove_queued_refund(deps.as_mut().storage, refund.id);
+
+        assert!(!has_queued_refunds(deps.as_ref().storage));
+    }
+
+    #[test]
+    fn parked_refunds_are_no_longer_queued() {
+        let mut deps = mock_dependencies();
+
+        let parked = enqueue_refund(deps.as_mut().storage, refund("ukuji")).unwrap();
+        enqueue_refund(deps.as_mut().storage, refund("uusk")).unwrap();
+
+        park_queued_refund(deps.as_mut().storage, parked.id).unwrap();
+
+        assert_eq!(
+            get_queued_refunds(deps.as_ref().storage, 10)
+                .unwrap()
+                .iter()
+                .map(|refund| refund.amount.denom.as_str())
+                .collect::<Vec<_>>(),
+            vec!["uusk"]
+        );
+        assert_eq!(
+            get_parked_refunds(deps.as_ref().storage, 10).unwrap(),
+            vec![parked]
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use super::keys;
+use crate::types::role::Role;
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const ROLES: Map<Addr, Vec<Role>> = Map::new(keys::ROLES);
+
+pub fn get_roles(store: &dyn Storage, address: &Addr) -> StdResult<Vec<Role>> {
+    Ok(ROLES.may_load(store, address.clone())?.unwrap_or_default())
+}
+
+pub fn grant_role(store: &mut dyn Storage, address: &Addr, role: &Role) -> StdResult<Vec<Role>> {
+    ROLES.update(store, address.clone(), |roles| {
+        let mut roles = roles.unwrap_or_default();
+        if !roles.contains(role) {
+            roles.push(role.clone());
+        }
+        Ok(roles)
+    })
+}
+
+pub fn revoke_role(store: &mut dyn Storage, address: &Addr, role: &Role) -> StdResult<Vec<Role>> {
+    let roles = get_roles(store, address)?
+        .into_iter()
+        .filter(|granted| granted != role)
+        .collect::<Vec<Role>>();
+
+    if roles.is_empty() {
+        ROLES.remove(store, address.clone());
+    } else {
+        ROLES.save(store, address.clone(), &roles)?;
+    }
+
+    Ok(roles)
+}
+
+pub fn has_role(store: &dyn Storage, address: &Addr, role: &Role) -> StdResult<bool> {
+    let roles = get_roles(store, address)?;
+    Ok(roles.contains(role) || roles.contains(&Role::Admin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn grants_and_revokes_roles() {
+        let mut deps = mock_dependencies();
+
+        let address = Addr::unchecked("ops");
+
+        grant_role(&mut deps.storage, &address, &Role::Pauser).unwrap();
+        grant_role(&mut deps.storage, &address, &Role::Pauser).unwrap();
+        grant_role(&mut deps.storage, &address, &Role::FeeManager).unwrap();
+
+        assert_eq!(
+            get_roles(&deps.storage, &address).unwrap(),
+            vec![Role::Pauser, Role::FeeManager]
+        );
+
+        revoke_role(&mut deps.storage, &address, &Role::Pauser).unwrap();
+
+        assert!(!has_role(&deps.storage, &address, &Role::Pauser).unwrap());
+        assert!(has_role(&deps.storage, &address, &Role::FeeManager).unwrap());
+    }
+
+    #[test]
+    fn admin_role_satisfies_every_role() {
+        let mut deps = mock_dependencies();
+
+        let address = Addr::unchecked("admin");
+
+        grant_role(&mut deps.storage, &address, &Role::Admin).unwrap();
+
+        assert!(has_role(&deps.storage, &address, &Role::FeeManager).unwrap());
+        assert!(has_role(&deps.storage, &address, &Role::Pauser).unwrap());
+        assert!(has_role(&deps.storage, &address, &Role::Executor).unwrap());
+    }
+}
@@ -0,0 +1,171 @@
+use super::{keys, state_helpers::fetch_and_increment_counter};
+use crate::types::scheduled_config_update::{ConfigChanges, ScheduledConfigUpdate};
+use cosmwasm_std::{Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, MultiIndex};
+use std::marker::PhantomData;
+
+const SCHEDULED_CONFIG_UPDATE_COUNTER: Item<u64> =
+    Item::new(keys::SCHEDULED_CONFIG_UPDATE_COUNTER);
+
+struct ScheduledConfigUpdateIndexes<'a> {
+    activates_at: MultiIndex<'a, u64, ScheduledConfigUpdate, u64>,
+}
+
+impl<'a> IndexList<ScheduledConfigUpdate> for ScheduledConfigUpdateIndexes<'a> {
+    fn get_indexes(
+        &'_ self,
+    ) -> Box<dyn Iterator<Item = &'_ dyn Index<ScheduledConfigUpdate>> + '_> {
+        let v: Vec<&dyn Index<ScheduledConfigUpdate>> = vec![&self.activates_at];
+        Box::new(v.into_iter())
+    }
+}
+
+fn scheduled_config_update_store<'a>(
+) -> IndexedMap<'a, u64, ScheduledConfigUpdate, ScheduledConfigUpdateIndexes<'a>> {
+    let indexes = ScheduledConfigUpdateIndexes {
+        activates_at: MultiIndex::new(
+            |_, update| update.activates_at.nanos(),
+            keys::SCHEDULED_CONFIG_UPDATES,
+            keys::SCHEDULED_CONFIG_UPDATES_ACTIVATES_AT_INDEX,
+        ),
+    };
+    IndexedMap::new(keys::SCHEDULED_CONFIG_UPDATES, indexes)
+}
+
+pub fn save_scheduled_config_update(
+    store: &mut dyn Storage,
+    scheduled_at: Timestamp,
+    activates_at: Timestamp,
+    changes: ConfigChanges,
+) -> StdResult<ScheduledConfigUpdate> {
+    let scheduled_config_update = ScheduledConfigUpdate {
+        id: fetch_and_increment_counter(store, SCHEDULED_CONFIG_UPDATE_COUNTER)?,
+        scheduled_at,
+        activates_at,
+        changes,
+    };
+    scheduled_config_update_store().save(
+        store,
+        scheduled_config_update.id,
+        &scheduled_config_update,
+    )?;
+    Ok(scheduled_config_update)
+}
+
+pub fn get_scheduled_config_update(
+    store: &dyn Storage,
+    id: u64,
+) -> StdResult<ScheduledConfigUpdate> {
+    scheduled_config_update_store().load(store, id)
+}
+
+pub fn get_scheduled_config_updates(store: &dyn Storage) -> StdResult<Vec<ScheduledConfigUpdate>> {
+    scheduled_config_update_store()
+        .idx
+        .activates_at
+        .range(store, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, update)| update))
+        .collect::<StdResult<Vec<ScheduledConfigUpdate>>>()
+}
+
+pub fn get_due_scheduled_config_updates(
+    store: &dyn Storage,
+    current_time: Timestamp,
+) -> StdResult<Vec<ScheduledConfigUpdate>> {
+    scheduled_config_update_store()
+        .idx
+        .activates_at
+        .range(
+            store,
+            None,
+            Some(Bound::Inclusive((
+                (current_time.nanos(), u64::MAX),
+                PhantomData,
+            ))),
+            Order::Ascending,
+        )
+        .map(|result| result.map(|(_, update)| update))
+        .collect::<StdResult<Vec<ScheduledConfigUpdate>>>()
+}
+
+pub fn delete_scheduled_config_update(store: &mut dyn Storage, id: u64) -> StdResult<()> {
+    scheduled_config_update_store().remove(store, id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    #[test]
+    fn fetches_scheduled_config_updates_in_activation_order() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let later_update = save_scheduled_config_update(
+            &mut deps.storage,
+            env.block.time,
+            env.block.time.plus_seconds(100),
+            ConfigChanges::default(),
+        )
+        .unwrap();
+
+        let earlier_update = save_scheduled_config_update(
+            &mut deps.storage,
+            env.block.time,
+            env.block.time.plus_seconds(10),
+            ConfigChanges::default(),
+        )
+        .unwrap();
+
+        let updates = get_scheduled_config_updates(&deps.storage).unwrap();
+
+        assert_eq!(updates, vec![earlier_update, later_update]);
+    }
+
+    #[test]
+    fn deletes_scheduled_config_update() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let update = save_scheduled_config_update(
+            &mut deps.storage,
+            env.block.time,
+            env.block.time.plus_seconds(100),
+            ConfigChanges::default(),
+        )
+        .unwrap();
+
+        delete_scheduled_config_update(&mut deps.storage, update.id).unwrap();
+
+        assert!(get_scheduled_config_updates(&deps.storage)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn fetches_only_due_scheduled_config_updates() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let due_update = save_scheduled_config_update(
+            &mut deps.storage,
+            env.block.time,
+            env.block.time,
+            ConfigChanges::default(),
+        )
+        .unwrap();
+
+        save_scheduled_config_update(
+            &mut deps.storage,
+            env.block.time,
+            env.block.time.plus_seconds(10),
+            ConfigChanges::default(),
+        )
+        .unwrap();
+
+        let updates = get_due_scheduled_config_updates(&deps.storage, env.block.time).unwrap();
+
+        assert_eq!(updates, vec![due_update]);
+    }
+}
@@ -0,0 +1,184 @@
+use super::{keys, state_helpers::fetch_and_increment_counter};
+use crate::helpers::pagination::{get_page_limit, Page};
+use crate::types::scheduled_payment::ScheduledPayment;
+use cosmwasm_std::{Addr, Coin, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, UniqueIndex};
+
+const SCHEDULED_PAYMENT_COUNTER: Item<u64> = Item::new(keys::SCHEDULED_PAYMENT_COUNTER);
+
+struct ScheduledPaymentIndexes<'a> {
+    pub due_date: UniqueIndex<'a, (u64, u64), ScheduledPayment, u64>,
+    pub owner: UniqueIndex<'a, (Addr, u64), ScheduledPayment, u64>,
+}
+
+impl<'a> IndexList<ScheduledPayment> for ScheduledPaymentIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<ScheduledPayment>> + '_> {
+        let v: Vec<&dyn Index<ScheduledPayment>> = vec![&self.due_date, &self.owner];
+        Box::new(v.into_iter())
+    }
+}
+
+fn scheduled_payment_store<'a>(
+) -> IndexedMap<'a, u64, ScheduledPayment, ScheduledPaymentIndexes<'a>> {
+    let indexes = ScheduledPaymentIndexes {
+        due_date: UniqueIndex::new(
+            |payment| (payment.at.seconds(), payment.id),
+            keys::SCHEDULED_PAYMENTS_DUE_DATE_INDEX,
+        ),
+        owner: UniqueIndex::new(
+            |payment| (payment.owner.clone(), payment.id),
+            keys::SCHEDULED_PAYMENTS_OWNER_INDEX,
+        ),
+    };
+    IndexedMap::new(keys::SCHEDULED_PAYMENTS, indexes)
+}
+
+pub fn save_scheduled_payment(
+    store: &mut dyn Storage,
+    owner: Addr,
+    to: Addr,
+    amount: Coin,
+    at: Timestamp,
+    created_at: Timestamp,
+) -> StdResult<ScheduledPayment> {
+    let scheduled_payment = ScheduledPayment {
+        id: fetch_and_increment_counter(store, SCHEDULED_PAYMENT_COUNTER)?,
+        owner,
+        to,
+        amount,
+        at,
+        created_at,
+    };
+    scheduled_payment_store().save(store, scheduled_payment.id, &scheduled_payment)?;
+    Ok(scheduled_payment)
+}
+
+pub fn get_scheduled_payment(store: &dyn Storage, id: u64) -> StdResult<ScheduledPayment> {
+    scheduled_payment_store().load(store, id)
+}
+
+pub fn get_due_scheduled_payments(
+    store: &dyn Storage,
+    due_before: Timestamp,
+    limit: Option<u16>,
+) -> StdResult<Vec<ScheduledPayment>> {
+    scheduled_payment_store()
+        .idx
+        .due_date
+        .range(
+            store,
+            None,
+            Some(Bound::inclusive((due_before.seconds(), u64::MAX))),
+            Order::Ascending,
+        )
+        .take(get_page_limit(store, limit)?)
+        .map(|result| result.map(|(_, payment)| payment))
+        .collect::<StdResult<Vec<ScheduledPayment>>>()
+}
+
+pub fn get_scheduled_payments_by_owner(
+    store: &dyn Storage,
+    owner: Addr,
+    start_after: Option<u64>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<Vec<ScheduledPayment>> {
+    let page = Page::new(store, start_after, limit, reverse)?;
+    let (min, max) = page.bounds();
+
+    scheduled_payment_store()
+        .idx
+        .owner
+        .prefix(owner)
+        .range(store, min, max, page.order)
+        .take(page.limit)
+        .map(|result| result.map(|(_, payment)| payment))
+        .collect::<StdResult<Vec<ScheduledPayment>>>()
+}
+
+pub fn delete_scheduled_payment(store: &mut dyn Storage, id: u64) -> StdResult<()> {
+    scheduled_payment_store().remove(store, id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    fn save_payment_at(store: &mut dyn Storage, owner: &str, at: Timestamp) -> ScheduledPayment {
+        save_scheduled_payment(
+            store,
+            Addr::unchecked(owner),
+            Addr::unchecked("recipient"),
+            Coin::new(100, "ukuji"),
+            at,
+            at,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn fetches_payments_that_are_due_in_due_date_order() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let later_payment = save_payment_at(&mut deps.storage, "owner", env.block.time);
+        let earlier_payment =
+            save_payment_at(&mut deps.storage, "owner", env.block.time.minus_seconds(10));
+
+        let payments =
+            get_due_scheduled_payments(&deps.storage, env.block.time, Some(100)).unwrap();
+
+        assert_eq!(payments, vec![earlier_payment, later_payment]);
+    }
+
+    #[test]
+    fn does_not_fetch_payments_that_are_not_due() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        save_payment_at(&mut deps.storage, "owner", env.block.time.plus_seconds(10));
+
+        let payments =
+            get_due_scheduled_payments(&deps.storage, env.block.time, Some(100)).unwrap();
+
+        assert!(payments.is_empty());
+    }
+
+    #[test]
+    fn fetches_payments_by_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let payment = save_payment_at(&mut deps.storage, "owner", env.block.time);
+        save_payment_at(&mut deps.storage, "other", env.block.time);
+
+        let payments = get_scheduled_payments_by_owner(
+            &deps.storage,
+            Addr::unchecked("owner"),
+            None,
+            Some(100),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(payments, vec![payment]);
+    }
+
+    #[test]
+    fn deletes_scheduled_payment() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let payment = save_payment_at(&mut deps.storage, "owner", env.block.time);
+
+        delete_scheduled_payment(&mut deps.storage, payment.id).unwrap();
+
+        assert!(get_scheduled_payment(&deps.storage, payment.id).is_err());
+        assert!(
+            get_due_scheduled_payments(&deps.storage, env.block.time, Some(100))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use super::{keys, state_helpers::fetch_and_increment_counter};
+use crate::types::template::{BountyTemplate, BountyTemplateParams};
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+const BOUNTY_TEMPLATE_COUNTER: Item<u64> = Item::new(keys::BOUNTY_TEMPLATE_COUNTER);
+
+const BOUNTY_TEMPLATES: Map<u64, BountyTemplate> = Map::new(keys::BOUNTY_TEMPLATES);
+
+pub fn save_bounty_template(
+    store: &mut dyn Storage,
+    owner: Addr,
+    label: String,
+    params: BountyTemplateParams,
+) -> StdResult<BountyTemplate> {
+    let template = BountyTemplate {
+        id: fetch_and_increment_counter(store, BOUNTY_TEMPLATE_COUNTER)?,
+        owner,
+        label,
+        params,
+    };
+    BOUNTY_TEMPLATES.save(store, template.id, &template)?;
+    Ok(template)
+}
+
+pub fn get_bounty_template(store: &dyn Storage, id: u64) -> StdResult<BountyTemplate> {
+    BOUNTY_TEMPLATES.load(store, id)
+}
+
+pub fn delete_bounty_template(store: &mut dyn Storage, id: u64) {
+    BOUNTY_TEMPLATES.remove(store, id)
+}
@@ -1,16 +1,20 @@
+use super::{bounties::get_bounty, keys};
 use crate::types::trigger::{Trigger, TriggerConfiguration};
 use cosmwasm_std::{Order, StdResult, Storage, Timestamp, Uint128};
-use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex, UniqueIndex};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Map, MultiIndex, UniqueIndex};
 use std::marker::PhantomData;
 
 pub(crate) struct TriggerIndexes<'a> {
     pub due_date: MultiIndex<'a, u64, Trigger, u128>,
+    pub due_height: MultiIndex<'a, u64, Trigger, u128>,
     pub order_idx: UniqueIndex<'a, u128, Trigger, u128>,
 }
 
+const BALANCE_TRIGGER_IDS: Map<u128, ()> = Map::new(keys::BALANCE_TRIGGER_IDS);
+
 impl<'a> IndexList<Trigger> for TriggerIndexes<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Trigger>> + '_> {
-        let v: Vec<&dyn Index<Trigger>> = vec![&self.due_date, &self.order_idx];
+        let v: Vec<&dyn Index<Trigger>> = vec![&self.due_date, &self.due_height, &self.order_idx];
         Box::new(v.into_iter())
     }
 }
@@ -19,24 +23,40 @@ pub(crate) fn trigger_store<'a>() -> IndexedMap<'a, u128, Trigger, TriggerIndexe
     let indexes = TriggerIndexes {
         due_date: MultiIndex::new(
             |_, trigger| match trigger.configuration {
-                TriggerConfiguration::Time { target_time } => target_time.seconds(),
+                TriggerConfiguration::Time { target_time }
+                | TriggerConfiguration::EscrowAccept { target_time, .. }
+                | TriggerConfiguration::EscrowReject { target_time, .. } => target_time.seconds(),
+                _ => u64::MAX,
+            },
+            keys::TRIGGERS,
+            keys::TRIGGERS_DUE_DATE_INDEX,
+        ),
+        due_height: MultiIndex::new(
+            |_, trigger| match trigger.configuration {
+                TriggerConfiguration::BlockHeight { target_height } => target_height,
                 _ => u64::MAX,
             },
-            "triggers_v8",
-            "triggers_v8__due_date",
+            keys::TRIGGERS,
+            keys::TRIGGERS_DUE_HEIGHT_INDEX,
         ),
         order_idx: UniqueIndex::new(
             |trigger| match trigger.configuration {
                 TriggerConfiguration::Price { order_idx, .. } => order_idx.into(),
                 _ => u128::MAX - trigger.bounty_id.u128(), // allows a unique entry that will never be found via an order_idx
             },
-            "triggers_v8__order_idx",
+            keys::TRIGGERS_ORDER_IDX_INDEX,
         ),
     };
-    IndexedMap::new("triggers_v8", indexes)
+    IndexedMap::new(keys::TRIGGERS, indexes)
 }
 
 pub fn save_trigger(store: &mut dyn Storage, trigger: Trigger) -> StdResult<()> {
+    match trigger.configuration {
+        TriggerConfiguration::BalanceAbove { .. } => {
+            BALANCE_TRIGGER_IDS.save(store, trigger.bounty_id.into(), &())?
+        }
+        _ => BALANCE_TRIGGER_IDS.remove(store, trigger.bounty_id.into()),
+    }
     trigger_store().save(store, trigger.bounty_id.into(), &trigger)
 }
 
@@ -45,6 +65,7 @@ pub fn get_trigger(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Option<
 }
 
 pub fn delete_trigger(store: &mut dyn Storage, bounty_id: Uint128) -> StdResult<()> {
+    BALANCE_TRIGGER_IDS.remove(store, bounty_id.into());
     trigger_store().remove(store, bounty_id.into())
 }
 
@@ -65,11 +86,69 @@ pub fn get_time_triggers(
             ))),
             Order::Ascending,
         )
+        .flat_map(|result| result.map(|(_, trigger)| trigger.bounty_id))
+        .filter(|bounty_id| !is_bounty_completed(store, *bounty_id))
+        .take(limit.unwrap_or(30) as usize)
+        .collect::<Vec<Uint128>>())
+}
+
+pub fn get_time_triggers_between(
+    store: &dyn Storage,
+    from: Timestamp,
+    to: Timestamp,
+    limit: Option<u16>,
+) -> StdResult<Vec<Trigger>> {
+    Ok(trigger_store()
+        .idx
+        .due_date
+        .range(
+            store,
+            Some(Bound::Inclusive(((from.seconds(), u128::MIN), PhantomData))),
+            Some(Bound::Inclusive(((to.seconds(), u128::MAX), PhantomData))),
+            Order::Ascending,
+        )
+        .flat_map(|result| result.map(|(_, trigger)| trigger))
+        .filter(|trigger| !is_bounty_completed(store, trigger.bounty_id))
+        .take(limit.unwrap_or(30) as usize)
+        .collect::<Vec<Trigger>>())
+}
+
+pub fn get_balance_triggers(store: &dyn Storage, limit: Option<u16>) -> StdResult<Vec<Trigger>> {
+    Ok(BALANCE_TRIGGER_IDS
+        .keys(store, None, None, Order::Ascending)
+        .flat_map(|result| result.and_then(|bounty_id| trigger_store().load(store, bounty_id)))
+        .filter(|trigger| !is_bounty_completed(store, trigger.bounty_id))
         .take(limit.unwrap_or(30) as usize)
+        .collect::<Vec<Trigger>>())
+}
+
+pub fn get_block_triggers(
+    store: &dyn Storage,
+    due_before_height: u64,
+    limit: Option<u16>,
+) -> StdResult<Vec<Uint128>> {
+    Ok(trigger_store()
+        .idx
+        .due_height
+        .range(
+            store,
+            None,
+            Some(Bound::Inclusive((
+                (due_before_height, u128::MAX),
+                PhantomData,
+            ))),
+            Order::Ascending,
+        )
         .flat_map(|result| result.map(|(_, trigger)| trigger.bounty_id))
+        .filter(|bounty_id| !is_bounty_completed(store, *bounty_id))
+        .take(limit.unwrap_or(30) as usize)
         .collect::<Vec<Uint128>>())
 }
 
+fn is_bounty_completed(store: &dyn Storage, bounty_id: Uint128) -> bool {
+    get_bounty(store, bounty_id).map_or(false, |bounty| bounty.is_completed())
+}
+
 pub fn get_trigger_by_order_idx(
     store: &dyn Storage,
     order_idx: Uint128,
@@ -84,9 +163,11 @@ pub fn get_trigger_by_order_idx(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::helpers::setup_bounty;
+    use crate::types::bounty::{Bounty, BountyStatus};
     use crate::types::trigger::TriggerConfiguration;
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
-    use cosmwasm_std::{Decimal, Uint128};
+    use cosmwasm_std::{Addr, Decimal, Uint128};
 
     #[test]
     fn fetches_trigger_ids_for_time_triggers_that_are_due() {
@@ -98,6 +179,7 @@ mod tests {
             configuration: TriggerConfiguration::Time {
                 target_time: env.block.time,
             },
+            retry_count: 0,
         };
 
         save_trigger(&mut deps.storage, trigger.clone()).unwrap();
@@ -108,6 +190,142 @@ mod tests {
         assert_eq!(trigger_ids, vec![trigger.bounty_id]);
     }
 
+    #[test]
+    fn does_not_fetch_trigger_ids_for_completed_bounties() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let bounty = setup_bounty(
+            deps.as_mut(),
+            env.clone(),
+            Bounty {
+                status: BountyStatus::Completed,
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: env.block.time,
+                }),
+                ..Bounty::default()
+            },
+        );
+
+        let trigger_ids =
+            get_time_triggers(&deps.storage, env.block.time.plus_seconds(10), Some(100)).unwrap();
+
+        assert!(!trigger_ids.contains(&bounty.id));
+    }
+
+    #[test]
+    fn fetches_balance_triggers_only() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let balance_trigger = Trigger {
+            bounty_id: Uint128::from(1u128),
+            configuration: TriggerConfiguration::BalanceAbove {
+                account: Addr::unchecked("revenue"),
+                denom: "ukuji".to_string(),
+                threshold: Uint128::new(100),
+                fired: false,
+            },
+            retry_count: 0,
+        };
+
+        save_trigger(&mut deps.storage, balance_trigger.clone()).unwrap();
+        save_trigger(
+            &mut deps.storage,
+            Trigger {
+                bounty_id: Uint128::from(2u128),
+                configuration: TriggerConfiguration::Time {
+                    target_time: env.block.time,
+                },
+                retry_count: 0,
+            },
+        )
+        .unwrap();
+
+        let triggers = get_balance_triggers(&deps.storage, Some(100)).unwrap();
+
+        assert_eq!(triggers, vec![balance_trigger]);
+    }
+
+    #[test]
+    fn only_indexes_balance_triggers() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let balance_trigger = Trigger {
+            bounty_id: Uint128::from(1u128),
+            configuration: TriggerConfiguration::BalanceAbove {
+                account: Addr::unchecked("revenue"),
+                denom: "ukuji".to_string(),
+                threshold: Uint128::new(100),
+                fired: false,
+            },
+            retry_count: 0,
+        };
+
+        save_trigger(&mut deps.storage, balance_trigger.clone()).unwrap();
+        save_trigger(
+            &mut deps.storage,
+            Trigger {
+                bounty_id: Uint128::from(2u128),
+                configuration: TriggerConfiguration::Time {
+                    target_time: env.block.time,
+                },
+                retry_count: 0,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            BALANCE_TRIGGER_IDS
+                .keys(&deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<u128>>>()
+                .unwrap(),
+            vec![1]
+        );
+
+        save_trigger(
+            &mut deps.storage,
+            Trigger {
+                configuration: TriggerConfiguration::Time {
+                    target_time: env.block.time,
+                },
+                ..balance_trigger
+            },
+        )
+        .unwrap();
+
+        assert!(get_balance_triggers(&deps.storage, Some(100))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn deleting_a_balance_trigger_removes_it_from_the_index() {
+        let mut deps = mock_dependencies();
+
+        save_trigger(
+            &mut deps.storage,
+            Trigger {
+                bounty_id: Uint128::from(1u128),
+                configuration: TriggerConfiguration::BalanceAbove {
+                    account: Addr::unchecked("revenue"),
+                    denom: "ukuji".to_string(),
+                    threshold: Uint128::new(100),
+                    fired: false,
+                },
+                retry_count: 0,
+            },
+        )
+        .unwrap();
+
+        delete_trigger(&mut deps.storage, Uint128::from(1u128)).unwrap();
+
+        assert!(get_balance_triggers(&deps.storage, Some(100))
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn does_not_fetch_trigger_ids_for_time_triggers_that_are_not_due() {
         let mut deps = mock_dependencies();
@@ -118,6 +336,7 @@ mod tests {
             configuration: TriggerConfiguration::Time {
                 target_time: env.block.time.plus_seconds(10),
             },
+            retry_count: 0,
         };
 
         save_trigger(&mut deps.storage, trigger).unwrap();
@@ -137,12 +356,14 @@ mod tests {
             configuration: TriggerConfiguration::Time {
                 target_time: env.block.time,
             },
+            retry_count: 0,
         };
         let trigger_2 = Trigger {
             bounty_id: Uint128::from(2u128),
             configuration: TriggerConfiguration::Time {
                 target_time: env.block.time,
             },
+            retry_count: 0,
         };
 
         save_trigger(&mut deps.storage, trigger_1.clone()).unwrap();
@@ -163,6 +384,7 @@ mod tests {
             configuration: TriggerConfiguration::Time {
                 target_time: env.block.time,
             },
+            retry_count: 0,
         };
 
         save_trigger(&mut deps.storage, trigger.clone()).unwrap();
@@ -189,6 +411,7 @@ mod tests {
             configuration: TriggerConfiguration::Time {
                 target_time: env.block.time,
             },
+            retry_count: 0,
         };
 
         let trigger_2 = Trigger {
@@ -196,6 +419,7 @@ mod tests {
             configuration: TriggerConfiguration::Time {
                 target_time: env.block.time.plus_seconds(10),
             },
+            retry_count: 0,
         };
 
         save_trigger(&mut deps.storage, trigger_1.clone()).unwrap();
@@ -220,12 +444,14 @@ mod tests {
             configuration: TriggerConfiguration::Time {
                 target_time: env.block.time,
             },
+            retry_count: 0,
         };
         let trigger_2 = Trigger {
             bounty_id: Uint128::from(2u128),
             configuration: TriggerConfiguration::Time {
                 target_time: env.block.time,
             },
+            retry_count: 0,
         };
 
         save_trigger(&mut deps.storage, trigger_1.clone()).unwrap();
@@ -246,6 +472,67 @@ mod tests {
         assert_eq!(trigger_ids_after_delete, vec![trigger_2.bounty_id]);
     }
 
+    #[test]
+    fn fetches_trigger_ids_for_block_triggers_that_are_due() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let trigger = Trigger {
+            bounty_id: Uint128::from(1u128),
+            configuration: TriggerConfiguration::BlockHeight {
+                target_height: env.block.height,
+            },
+            retry_count: 0,
+        };
+
+        save_trigger(&mut deps.storage, trigger.clone()).unwrap();
+
+        let trigger_ids =
+            get_block_triggers(&deps.storage, env.block.height + 10, Some(100)).unwrap();
+
+        assert_eq!(trigger_ids, vec![trigger.bounty_id]);
+    }
+
+    #[test]
+    fn does_not_fetch_trigger_ids_for_block_triggers_that_are_not_due() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let trigger = Trigger {
+            bounty_id: Uint128::from(1u128),
+            configuration: TriggerConfiguration::BlockHeight {
+                target_height: env.block.height + 10,
+            },
+            retry_count: 0,
+        };
+
+        save_trigger(&mut deps.storage, trigger).unwrap();
+
+        let trigger_ids = get_block_triggers(&deps.storage, env.block.height, Some(100)).unwrap();
+
+        assert!(trigger_ids.is_empty());
+    }
+
+    #[test]
+    fn does_not_fetch_time_triggers_as_block_triggers() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let trigger = Trigger {
+            bounty_id: Uint128::from(1u128),
+            configuration: TriggerConfiguration::Time {
+                target_time: env.block.time,
+            },
+            retry_count: 0,
+        };
+
+        save_trigger(&mut deps.storage, trigger).unwrap();
+
+        let trigger_ids = get_block_triggers(&deps.storage, env.block.height, Some(100)).unwrap();
+
+        assert!(trigger_ids.is_empty());
+    }
+
     #[test]
     fn fetches_price_trigger_by_order_id() {
         let mut deps = mock_dependencies();
@@ -258,6 +545,7 @@ mod tests {
                 target_price: Decimal::percent(120),
                 order_idx,
             },
+            retry_count: 0,
         };
 
         save_trigger(&mut deps.storage, trigger.clone()).unwrap();
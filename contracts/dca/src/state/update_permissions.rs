@@ -0,0 +1,75 @@
+use super::keys;
+use crate::types::updatable_field::UpdatableField;
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const UPDATE_PERMISSIONS: Map<(u128, Addr), Vec<UpdatableField>> =
+    Map::new(keys::UPDATE_PERMISSIONS);
+
+pub fn save_update_permissions(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    delegate: &Addr,
+    fields: &Vec<UpdatableField>,
+) -> StdResult<()> {
+    UPDATE_PERMISSIONS.save(store, (bounty_id.into(), delegate.clone()), fields)
+}
+
+pub fn get_update_permissions(
+    store: &dyn Storage,
+    bounty_id: Uint128,
+    delegate: &Addr,
+) -> StdResult<Vec<UpdatableField>> {
+    Ok(UPDATE_PERMISSIONS
+        .may_load(store, (bounty_id.into(), delegate.clone()))?
+        .unwrap_or_default())
+}
+
+pub fn delete_update_permissions(store: &mut dyn Storage, bounty_id: Uint128, delegate: &Addr) {
+    UPDATE_PERMISSIONS.remove(store, (bounty_id.into(), delegate.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn fetches_update_permissions_for_delegate() {
+        let mut deps = mock_dependencies();
+
+        let fields = vec![UpdatableField::SlippageTolerance];
+
+        save_update_permissions(
+            &mut deps.storage,
+            Uint128::one(),
+            &Addr::unchecked("bot"),
+            &fields,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_update_permissions(&deps.storage, Uint128::one(), &Addr::unchecked("bot")).unwrap(),
+            fields
+        );
+    }
+
+    #[test]
+    fn fetches_no_update_permissions_for_other_bounties() {
+        let mut deps = mock_dependencies();
+
+        save_update_permissions(
+            &mut deps.storage,
+            Uint128::one(),
+            &Addr::unchecked("bot"),
+            &vec![UpdatableField::Label],
+        )
+        .unwrap();
+
+        assert!(
+            get_update_permissions(&deps.storage, Uint128::new(2), &Addr::unchecked("bot"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -1,55 +1,113 @@
-use super::{config::get_config, triggers::get_trigger};
-use crate::{
-    helpers::state::fetch_and_increment_counter,
-    types::{
-        destination::Destination,
-        performance_assessment_strategy::PerformanceAssessmentStrategy,
-        swap_adjustment_strategy::SwapAdjustmentStrategy,
-        time_interval::TimeInterval,
-        vault::{Bounty, BountyBuilder, BountyStatus},
-    },
+use super::{
+    denom_balances::get_denom_balances,
+    keys,
+    owner_balances::{add_to_owner_balance, subtract_from_owner_balance},
+    triggers::get_trigger,
+};
+use crate::helpers::pagination::Page;
+use crate::types::{
+    cancel_reason::CancelReason,
+    denom::Denom,
+    destination::Destination,
+    disbursement_schedule::DisbursementSchedule,
+    performance_assessment_strategy::PerformanceAssessmentStrategy,
+    swap_adjustment_strategy::SwapAdjustmentStrategy,
+    time_interval::TimeInterval,
+    vault::{Bounty, BountyBuilder, BountyStatus},
 };
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Coin, Decimal, Order, StdResult, Storage, Timestamp, Uint128};
-use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, UniqueIndex};
+use cosmwasm_std::{
+    Addr, Binary, Coin, Decimal, Order, StdError, StdResult, Storage, Timestamp, Uint128,
+};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, UniqueIndex};
+
+const BOUNTY_COUNTER: Item<u64> = Item::new(keys::BOUNTY_COUNTER);
+
+const LEGACY_VAULT_COUNTER: Item<u64> = Item::new(keys::LEGACY_VAULT_COUNTER);
+
+const LEGACY_VAULTS: Map<u128, VaultData> = Map::new(keys::LEGACY_VAULTS);
+
+const LEGACY_VAULT_MIGRATION_CURSOR: Item<u128> = Item::new(keys::LEGACY_VAULT_MIGRATION_CURSOR);
 
-const BOUNTY_COUNTER: Item<u64> = Item::new("vault_counter_v8");
+const OWNER_BALANCE_REBUILD_CURSOR: Item<u128> = Item::new(keys::OWNER_BALANCE_REBUILD_CURSOR);
+
+const BOUNTIES_BY_TAG: Map<(String, u128), ()> = Map::new(keys::BOUNTIES_BY_TAG);
 
 struct BountyIndexes<'a> {
     pub owner: UniqueIndex<'a, (Addr, u128), BountyData, u128>,
     pub owner_status: UniqueIndex<'a, (Addr, u8, u128), BountyData, u128>,
+    pub status: UniqueIndex<'a, (u8, u128), BountyData, u128>,
 }
 
 impl<'a> IndexList<BountyData> for BountyIndexes<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<BountyData>> + '_> {
-        let v: Vec<&dyn Index<BountyData>> = vec![&self.owner, &self.owner_status];
+        let v: Vec<&dyn Index<BountyData>> = vec![&self.owner, &self.owner_status, &self.status];
         Box::new(v.into_iter())
     }
 }
 
 fn bounty_store<'a>() -> IndexedMap<'a, u128, BountyData, BountyIndexes<'a>> {
     let indexes = BountyIndexes {
-        owner: UniqueIndex::new(|v| (v.owner.clone(), v.id.into()), "bounties_v8__owner"),
+        owner: UniqueIndex::new(|v| (v.owner.clone(), v.id.into()), keys::BOUNTIES_OWNER_INDEX),
         owner_status: UniqueIndex::new(
             |v| (v.owner.clone(), v.status.clone() as u8, v.id.into()),
-            "bounties_v8__owner_status",
+            keys::BOUNTIES_OWNER_STATUS_INDEX,
+        ),
+        status: UniqueIndex::new(
+            |v| (v.status.clone() as u8, v.id.into()),
+            keys::BOUNTIES_STATUS_INDEX,
         ),
     };
-    IndexedMap::new("bounties_v8", indexes)
+    IndexedMap::new(keys::BOUNTIES, indexes)
 }
 
 pub fn migrate_bounty(store: &mut dyn Storage, bounty: Bounty) -> StdResult<()> {
+    update_tag_index(store, &bounty)?;
+    update_owner_balance_index(store, &bounty)?;
     bounty_store().save(store, bounty.id.into(), &bounty.into())
 }
 
+pub fn migrate_legacy_vaults(store: &mut dyn Storage, limit: u16) -> StdResult<u64> {
+    keys::migrate_aliased_item(store, &BOUNTY_COUNTER, &LEGACY_VAULT_COUNTER)?;
+
+    let cursor = LEGACY_VAULT_MIGRATION_CURSOR.may_load(store)?;
+
+    let legacy_vaults = LEGACY_VAULTS
+        .range(store, cursor.map(Bound::exclusive), None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<(u128, VaultData)>>>()?;
+
+    for (id, vault_data) in legacy_vaults.iter() {
+        let bounty_data = BountyData::try_from(vault_data.clone())?;
+        let bounty = bounty_from(store, &bounty_data)?;
+        migrate_bounty(store, bounty)?;
+        LEGACY_VAULT_MIGRATION_CURSOR.save(store, id)?;
+    }
+
+    Ok(legacy_vaults.len() as u64)
+}
+
+fn fetch_and_increment_bounty_counter(store: &mut dyn Storage) -> StdResult<u64> {
+    let id = keys::may_load_aliased(store, &BOUNTY_COUNTER, &LEGACY_VAULT_COUNTER)?
+        .unwrap_or_default()
+        + 1;
+    BOUNTY_COUNTER.save(store, &id)?;
+    Ok(id)
+}
+
 pub fn save_bounty(store: &mut dyn Storage, bounty_builder: BountyBuilder) -> StdResult<Bounty> {
-    let bounty = bounty_builder.build(fetch_and_increment_counter(store, BOUNTY_COUNTER)?.into());
+    let bounty = bounty_builder.build(fetch_and_increment_bounty_counter(store)?.into());
+    update_tag_index(store, &bounty)?;
+    update_owner_balance_index(store, &bounty)?;
     bounty_store().save(store, bounty.id.into(), &bounty.clone().into())?;
     Ok(bounty)
 }
 
 pub fn get_bounty(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Bounty> {
-    let bounty_data = bounty_store().load(store, bounty_id.into())?;
+    let bounty_data = match bounty_store().may_load(store, bounty_id.into())? {
+        Some(bounty_data) => bounty_data,
+        None => BountyData::try_from(LEGACY_VAULTS.load(store, bounty_id.into())?)?,
+    };
     bounty_from(store, &bounty_data)
 }
 
@@ -59,6 +117,7 @@ pub fn get_bounties_by_address(
     status: Option<BountyStatus>,
     start_after: Option<Uint128>,
     limit: Option<u16>,
+    reverse: Option<bool>,
 ) -> StdResult<Vec<Bounty>> {
     let partition = match status {
         Some(status) => bounty_store()
@@ -68,46 +127,192 @@ pub fn get_bounties_by_address(
         None => bounty_store().idx.owner.prefix(address),
     };
 
+    let page = Page::new(store, start_after, limit, reverse)?;
+    let (min, max) = page.bounds();
+
     Ok(partition
-        .range(
-            store,
-            start_after.map(Bound::exclusive),
-            None,
-            cosmwasm_std::Order::Ascending,
-        )
-        .take(limit.unwrap_or_else(|| get_config(store).unwrap().default_page_limit) as usize)
+        .range(store, min, max, page.order)
+        .take(page.limit)
+        .flat_map(|result| result.map(|(_, bounty_data)| bounty_from(store, &bounty_data)))
+        .flatten()
+        .collect::<Vec<Bounty>>())
+}
+
+pub fn get_bounties_by_status(
+    store: &dyn Storage,
+    status: BountyStatus,
+    start_after: Option<Uint128>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<Vec<Bounty>> {
+    let page = Page::new(store, start_after, limit, reverse)?;
+    let (min, max) = page.bounds();
+
+    Ok(bounty_store()
+        .idx
+        .status
+        .prefix(status as u8)
+        .range(store, min, max, page.order)
+        .take(page.limit)
         .flat_map(|result| result.map(|(_, bounty_data)| bounty_from(store, &bounty_data)))
         .flatten()
         .collect::<Vec<Bounty>>())
 }
 
+pub fn get_bounties_by_tag(
+    store: &dyn Storage,
+    tag: String,
+    start_after: Option<Uint128>,
+    limit: Option<u16>,
+    reverse: Option<bool>,
+) -> StdResult<Vec<Bounty>> {
+    let page = Page::new(store, start_after, limit, reverse)?;
+    let (min, max) = page.bounds();
+
+    Ok(BOUNTIES_BY_TAG
+        .prefix(tag)
+        .keys(store, min, max, page.order)
+        .take(page.limit)
+        .flat_map(|result| result.map(|bounty_id| get_bounty(store, bounty_id.into())))
+        .flatten()
+        .collect::<Vec<Bounty>>())
+}
+
 pub fn get_bounties(
     store: &dyn Storage,
     start_after: Option<Uint128>,
     limit: Option<u16>,
     reverse: Option<bool>,
 ) -> StdResult<Vec<Bounty>> {
+    let page = Page::new(store, start_after, limit, reverse)?;
+    let (min, max) = page.bounds();
+
     Ok(bounty_store()
-        .range(
-            store,
-            start_after.map(Bound::exclusive),
-            None,
-            reverse.map_or(Order::Ascending, |reverse| match reverse {
-                true => Order::Descending,
-                false => Order::Ascending,
-            }),
-        )
-        .take(limit.unwrap_or_else(|| get_config(store).unwrap().default_page_limit) as usize)
+        .range(store, min, max, page.order)
+        .take(page.limit)
         .flat_map(|result| result.map(|(_, bounty_data)| bounty_from(store, &bounty_data)))
         .flatten()
         .collect::<Vec<Bounty>>())
 }
 
 pub fn update_bounty(store: &mut dyn Storage, bounty: Bounty) -> StdResult<Bounty> {
+    update_tag_index(store, &bounty)?;
+    update_owner_balance_index(store, &bounty)?;
     bounty_store().save(store, bounty.id.into(), &bounty.clone().into())?;
+    LEGACY_VAULTS.remove(store, bounty.id.into());
     Ok(bounty)
 }
 
+fn update_tag_index(store: &mut dyn Storage, bounty: &Bounty) -> StdResult<()> {
+    if let Some(existing) = bounty_store().may_load(store, bounty.id.into())? {
+        for tag in existing.tags {
+            BOUNTIES_BY_TAG.remove(store, (tag, bounty.id.into()));
+        }
+    }
+    for tag in &bounty.tags {
+        BOUNTIES_BY_TAG.save(store, (tag.clone(), bounty.id.into()), &())?;
+    }
+    Ok(())
+}
+
+/// Bounties past the rebuild cursor are counted when the rebuild reaches
+/// them, so changes to them are not indexed until then.
+fn is_owner_balance_indexed(store: &dyn Storage, bounty_id: Uint128) -> StdResult<bool> {
+    Ok(OWNER_BALANCE_REBUILD_CURSOR
+        .may_load(store)?
+        .map_or(true, |cursor| bounty_id.u128() <= cursor))
+}
+
+pub fn owner_balances_are_rebuilding(store: &dyn Storage) -> StdResult<bool> {
+    Ok(OWNER_BALANCE_REBUILD_CURSOR.may_load(store)?.is_some())
+}
+
+fn update_owner_balance_index(store: &mut dyn Storage, bounty: &Bounty) -> StdResult<()> {
+    if !is_owner_balance_indexed(store, bounty.id)? {
+        return Ok(());
+    }
+
+    let denom_balances = get_denom_balances(store, bounty.id)?;
+
+    match bounty_store().may_load(store, bounty.id.into())? {
+        Some(existing) => {
+            subtract_from_owner_balance(store, &existing.owner, &existing.balance)?;
+
+            if existing.owner != bounty.owner {
+                for denom_balance in denom_balances.iter() {
+                    subtract_from_owner_balance(store, &existing.owner, &denom_balance.balance)?;
+                    add_to_owner_balance(store, &bounty.owner, &denom_balance.balance)?;
+                }
+            }
+        }
+        None => {
+            for denom_balance in denom_balances.iter() {
+                add_to_owner_balance(store, &bounty.owner, &denom_balance.balance)?;
+            }
+        }
+    }
+
+    add_to_owner_balance(store, &bounty.owner, &bounty.balance)
+}
+
+/// Applies a change to one of a bounty's queued denom balances to its owner's
+/// balance. Bounties that are not stored yet are counted in full when they are.
+pub fn update_owner_denom_balance_index(
+    store: &mut dyn Storage,
+    bounty_id: Uint128,
+    previous_balance: Option<&Coin>,
+    balance: Option<&Coin>,
+) -> StdResult<()> {
+    if !is_owner_balance_indexed(store, bounty_id)? {
+        return Ok(());
+    }
+
+    if let Some(bounty_data) = bounty_store().may_load(store, bounty_id.into())? {
+        if let Some(previous_balance) = previous_balance {
+            subtract_from_owner_balance(store, &bounty_data.owner, previous_balance)?;
+        }
+
+        if let Some(balance) = balance {
+            add_to_owner_balance(store, &bounty_data.owner, balance)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn rebuild_owner_balances(store: &mut dyn Storage, limit: u16) -> StdResult<u64> {
+    let cursor = match OWNER_BALANCE_REBUILD_CURSOR.may_load(store)? {
+        Some(cursor) => cursor,
+        None => return Ok(0),
+    };
+
+    let bounties = bounty_store()
+        .range(
+            store,
+            Some(Bound::exclusive(cursor)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<(u128, BountyData)>>>()?;
+
+    for (id, bounty_data) in bounties.iter() {
+        add_to_owner_balance(store, &bounty_data.owner, &bounty_data.balance)?;
+
+        for denom_balance in get_denom_balances(store, (*id).into())? {
+            add_to_owner_balance(store, &bounty_data.owner, &denom_balance.balance)?;
+        }
+
+        OWNER_BALANCE_REBUILD_CURSOR.save(store, id)?;
+    }
+
+    if bounties.len() < limit as usize {
+        OWNER_BALANCE_REBUILD_CURSOR.remove(store);
+    }
+
+    Ok(bounties.len() as u64)
+}
+
 #[cw_serde]
 struct BountyData {
     id: Uint128,
@@ -117,7 +322,7 @@ struct BountyData {
     destinations: Vec<Destination>,
     status: BountyStatus,
     balance: Coin,
-    target_denom: String,
+    target_denom: Denom,
     swap_amount: Uint128,
     route: Option<Binary>,
     slippage_tolerance: Decimal,
@@ -129,6 +334,25 @@ struct BountyData {
     swapped_amount: Coin,
     received_amount: Coin,
     escrowed_amount: Coin,
+    exact_receive_amount: Option<Uint128>,
+    expires_at: Option<Timestamp>,
+    disbursement_schedule: Option<DisbursementSchedule>,
+    cancel_reason: Option<CancelReason>,
+    cancel_note: Option<String>,
+    private_metadata: bool,
+    referrer: Option<Addr>,
+    executor_slippage_override_cap: Option<Decimal>,
+    fallback_routes: Vec<Binary>,
+    description: Option<String>,
+    tags: Vec<String>,
+    metadata_uri: Option<String>,
+    minimum_price: Option<Decimal>,
+    maximum_price: Option<Decimal>,
+    escrow_available_after: Option<Timestamp>,
+    metadata_hash: Option<[u8; 32]>,
+    min_adjustment: Option<Decimal>,
+    max_adjustment: Option<Decimal>,
+    lock_period: Option<u64>,
    // performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
    // swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
 }
@@ -140,10 +364,9 @@ impl From<Bounty> for BountyData {
             created_at: bounty.created_at,
             owner: bounty.owner,
             label: bounty.label,
-            bount_description: bounty.bounty_description,
             status: bounty.status,
             balance: bounty.balance,
-            target_denom: bounty.target_denom,
+            target_denom: bounty.target_denom.into(),
             route: bounty.route,
             destinations: bounty.destinations,
             swap_amount: bounty.swap_amount,
@@ -156,12 +379,129 @@ impl From<Bounty> for BountyData {
            // swapped_amount: bounty.swapped_amount,
             received_amount: bounty.received_amount,
             escrowed_amount: bounty.escrowed_amount,
+            exact_receive_amount: bounty.exact_receive_amount,
+            expires_at: bounty.expires_at,
+            disbursement_schedule: bounty.disbursement_schedule,
+            cancel_reason: bounty.cancel_reason,
+            cancel_note: bounty.cancel_note,
+            private_metadata: bounty.private_metadata,
+            referrer: bounty.referrer,
+            executor_slippage_override_cap: bounty.executor_slippage_override_cap,
+            fallback_routes: bounty.fallback_routes,
+            description: bounty.description,
+            tags: bounty.tags,
+            metadata_uri: bounty.metadata_uri,
+            minimum_price: bounty.minimum_price,
+            maximum_price: bounty.maximum_price,
+            escrow_available_after: bounty.escrow_available_after,
+            metadata_hash: bounty.metadata_hash,
+            min_adjustment: bounty.min_adjustment,
+            max_adjustment: bounty.max_adjustment,
+            lock_period: bounty.lock_period,
            // performance_assessment_strategy: vault.performance_assessment_strategy,
            // swap_adjustment_strategy: vault.swap_adjustment_strategy,
         }
     }
 }
 
+#[cw_serde]
+struct VaultData {
+    id: Uint128,
+    created_at: Timestamp,
+    owner: Addr,
+    label: Option<String>,
+    destinations: Vec<Destination>,
+    status: BountyStatus,
+    balance: Coin,
+    target_denom: String,
+    swap_amount: Uint128,
+    route: Option<Binary>,
+    slippage_tolerance: Decimal,
+    minimum_receive_amount: Option<Uint128>,
+    time_interval: TimeInterval,
+    started_at: Option<Timestamp>,
+    escrow_level: Decimal,
+    deposited_amount: Coin,
+    swapped_amount: Coin,
+    received_amount: Coin,
+    escrowed_amount: Coin,
+    #[serde(default)]
+    exact_receive_amount: Option<Uint128>,
+    #[serde(default)]
+    expires_at: Option<Timestamp>,
+    #[serde(default)]
+    disbursement_schedule: Option<DisbursementSchedule>,
+    #[serde(default)]
+    cancel_reason: Option<CancelReason>,
+    #[serde(default)]
+    cancel_note: Option<String>,
+    #[serde(default)]
+    private_metadata: bool,
+    #[serde(default)]
+    referrer: Option<Addr>,
+    #[serde(default)]
+    executor_slippage_override_cap: Option<Decimal>,
+    #[serde(default)]
+    fallback_routes: Vec<Binary>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    metadata_uri: Option<String>,
+    #[serde(default)]
+    minimum_price: Option<Decimal>,
+    #[serde(default)]
+    maximum_price: Option<Decimal>,
+}
+
+impl TryFrom<VaultData> for BountyData {
+    type Error = StdError;
+
+    fn try_from(vault: VaultData) -> StdResult<Self> {
+        Ok(Self {
+            id: vault.id,
+            created_at: vault.created_at,
+            owner: vault.owner,
+            label: vault.label,
+            destinations: vault.destinations,
+            status: vault.status,
+            balance: vault.balance,
+            target_denom: Denom::new(vault.target_denom)?,
+            swap_amount: vault.swap_amount,
+            route: vault.route,
+            slippage_tolerance: vault.slippage_tolerance,
+            minimum_receive_amount: vault.minimum_receive_amount,
+            time_interval: vault.time_interval,
+            started_at: vault.started_at,
+            escrow_level: vault.escrow_level,
+            deposited_amount: vault.deposited_amount,
+            swapped_amount: vault.swapped_amount,
+            received_amount: vault.received_amount,
+            escrowed_amount: vault.escrowed_amount,
+            exact_receive_amount: vault.exact_receive_amount,
+            expires_at: vault.expires_at,
+            disbursement_schedule: vault.disbursement_schedule,
+            cancel_reason: vault.cancel_reason,
+            cancel_note: vault.cancel_note,
+            private_metadata: vault.private_metadata,
+            referrer: vault.referrer,
+            executor_slippage_override_cap: vault.executor_slippage_override_cap,
+            fallback_routes: vault.fallback_routes,
+            description: vault.description,
+            tags: vault.tags,
+            metadata_uri: vault.metadata_uri,
+            minimum_price: vault.minimum_price,
+            maximum_price: vault.maximum_price,
+            escrow_available_after: None,
+            metadata_hash: None,
+            min_adjustment: None,
+            max_adjustment: None,
+            lock_period: None,
+        })
+    }
+}
+
 fn bounty_from(store: &dyn Storage, data: &BountyData) -> StdResult<Bounty> {
     let trigger = get_trigger(store, data.id)?.map(|t| t.configuration);
 
@@ -185,8 +525,230 @@ fn bounty_from(store: &dyn Storage, data: &BountyData) -> StdResult<Bounty> {
        // swapped_amount: data.swapped_amount.clone(),
         received_amount: data.received_amount.clone(),
         escrowed_amount: data.escrowed_amount.clone(),
+        exact_receive_amount: data.exact_receive_amount,
+        expires_at: data.expires_at,
+        disbursement_schedule: data.disbursement_schedule.clone(),
+        cancel_reason: data.cancel_reason.clone(),
+        cancel_note: data.cancel_note.clone(),
+        private_metadata: data.private_metadata,
+        referrer: data.referrer.clone(),
+        executor_slippage_override_cap: data.executor_slippage_override_cap,
+        fallback_routes: data.fallback_routes.clone(),
+        description: data.description.clone(),
+        tags: data.tags.clone(),
+        metadata_uri: data.metadata_uri.clone(),
+        minimum_price: data.minimum_price,
+        maximum_price: data.maximum_price,
+        escrow_available_after: data.escrow_available_after,
+        metadata_hash: data.metadata_hash,
+        min_adjustment: data.min_adjustment,
+        max_adjustment: data.max_adjustment,
+        lock_period: data.lock_period,
         //performance_assessment_strategy: data.performance_assessment_strategy.clone(),
        // swap_adjustment_strategy: data.swap_adjustment_strategy.clone(),
         trigger,
     })
 }
+
+#[cfg(test)]
+mod migrate_legacy_vaults_tests {
+    use super::*;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    fn save_legacy_vaults(store: &mut dyn Storage, count: u128) {
+        let legacy_vaults: Map<u128, BountyData> = Map::new(keys::LEGACY_VAULTS);
+
+        for id in 1..=count {
+            legacy_vaults
+                .save(
+                    store,
+                    id,
+                    &Bounty {
+                        id: id.into(),
+                        tags: vec![],
+                        ..Bounty::default()
+                    }
+                    .into(),
+                )
+                .unwrap();
+        }
+
+        LEGACY_VAULT_COUNTER.save(store, &(count as u64)).unwrap();
+    }
+
+    #[test]
+    fn migrates_legacy_vaults_in_chunks() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env, mock_info(ADMIN, &[]));
+
+        save_legacy_vaults(deps.as_mut().storage, 3);
+
+        assert_eq!(migrate_legacy_vaults(deps.as_mut().storage, 2).unwrap(), 2);
+        assert_eq!(
+            get_bounties(deps.as_ref().storage, None, None, None)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        assert_eq!(migrate_legacy_vaults(deps.as_mut().storage, 2).unwrap(), 1);
+        assert_eq!(migrate_legacy_vaults(deps.as_mut().storage, 2).unwrap(), 0);
+        assert_eq!(
+            get_bounties(deps.as_ref().storage, None, None, None)
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn loads_unmigrated_legacy_vault() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env, mock_info(ADMIN, &[]));
+
+        save_legacy_vaults(deps.as_mut().storage, 3);
+
+        migrate_legacy_vaults(deps.as_mut().storage, 1).unwrap();
+
+        let bounty = get_bounty(deps.as_ref().storage, Uint128::new(3)).unwrap();
+
+        assert_eq!(bounty.id, Uint128::new(3));
+    }
+
+    #[test]
+    fn updating_unmigrated_legacy_vault_is_not_overwritten_by_migration() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env, mock_info(ADMIN, &[]));
+
+        save_legacy_vaults(deps.as_mut().storage, 2);
+
+        let bounty = get_bounty(deps.as_ref().storage, Uint128::new(2)).unwrap();
+
+        update_bounty(
+            deps.as_mut().storage,
+            Bounty {
+                status: BountyStatus::Cancelled,
+                ..bounty
+            },
+        )
+        .unwrap();
+
+        assert_eq!(migrate_legacy_vaults(deps.as_mut().storage, 10).unwrap(), 1);
+
+        assert_eq!(
+            get_bounty(deps.as_ref().storage, Uint128::new(2))
+                .unwrap()
+                .status,
+            BountyStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn continues_bounty_ids_from_legacy_counter() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env, mock_info(ADMIN, &[]));
+
+        save_legacy_vaults(deps.as_mut().storage, 3);
+
+        migrate_legacy_vaults(deps.as_mut().storage, 10).unwrap();
+
+        assert_eq!(BOUNTY_COUNTER.load(deps.as_ref().storage).unwrap(), 3);
+    }
+}
+
+#[cfg(test)]
+mod owner_balance_index_tests {
+    use super::*;
+    use crate::constants::TEN;
+    use crate::state::{
+        denom_balances::{delete_denom_balance, save_denom_balance},
+        owner_balances::get_owner_balance,
+    };
+    use crate::types::denom_balance::DenomBalance;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn counts_queued_denom_balances() {
+        let mut deps = mock_dependencies();
+
+        let bounty = update_bounty(deps.as_mut().storage, Bounty::default()).unwrap();
+
+        save_denom_balance(
+            deps.as_mut().storage,
+            bounty.id,
+            &DenomBalance {
+                balance: Coin::new(100, "uatom"),
+                deposited_amount: Coin::new(100, "uatom"),
+                swap_amount: Uint128::new(10),
+                route: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_owner_balance(deps.as_ref().storage, &bounty.owner, "uatom").unwrap(),
+            Uint128::new(100)
+        );
+
+        delete_denom_balance(deps.as_mut().storage, bounty.id, "uatom").unwrap();
+
+        assert_eq!(
+            get_owner_balance(deps.as_ref().storage, &bounty.owner, "uatom").unwrap(),
+            Uint128::zero()
+        );
+    }
+
+    #[test]
+    fn rebuilds_owner_balances_in_chunks() {
+        let mut deps = mock_dependencies();
+
+        OWNER_BALANCE_REBUILD_CURSOR
+            .save(deps.as_mut().storage, &0)
+            .unwrap();
+
+        for id in 1..=2u128 {
+            update_bounty(
+                deps.as_mut().storage,
+                Bounty {
+                    id: id.into(),
+                    ..Bounty::default()
+                },
+            )
+            .unwrap();
+        }
+
+        let owner = Bounty::default().owner;
+        let denom = Bounty::default().balance.denom;
+
+        assert_eq!(
+            get_owner_balance(deps.as_ref().storage, &owner, &denom).unwrap(),
+            Uint128::zero()
+        );
+
+        assert_eq!(rebuild_owner_balances(deps.as_mut().storage, 1).unwrap(), 1);
+        assert!(owner_balances_are_rebuilding(deps.as_ref().storage).unwrap());
+        assert_eq!(
+            get_owner_balance(deps.as_ref().storage, &owner, &denom).unwrap(),
+            TEN
+        );
+
+        assert_eq!(rebuild_owner_balances(deps.as_mut().storage, 1).unwrap(), 1);
+        assert_eq!(rebuild_owner_balances(deps.as_mut().storage, 1).unwrap(), 0);
+        assert!(!owner_balances_are_rebuilding(deps.as_ref().storage).unwrap());
+        assert_eq!(
+            get_owner_balance(deps.as_ref().storage, &owner, &denom).unwrap(),
+            TEN + TEN
+        );
+        assert_eq!(rebuild_owner_balances(deps.as_mut().storage, 1).unwrap(), 0);
+    }
+}
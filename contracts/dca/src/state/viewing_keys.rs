@@ -0,0 +1,13 @@
+use super::keys;
+use cosmwasm_std::{StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+const VIEWING_KEYS: Map<u128, String> = Map::new(keys::VIEWING_KEYS);
+
+pub fn save_viewing_key(store: &mut dyn Storage, bounty_id: Uint128, key: &str) -> StdResult<()> {
+    VIEWING_KEYS.save(store, bounty_id.into(), &key.to_string())
+}
+
+pub fn get_viewing_key(store: &dyn Storage, bounty_id: Uint128) -> StdResult<Option<String>> {
+    VIEWING_KEYS.may_load(store, bounty_id.into())
+}
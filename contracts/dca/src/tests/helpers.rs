@@ -1,4 +1,4 @@
-use super::mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK, USER, VALIDATOR};
+use super::mocks::{ADMIN, ARBITER, DENOM_UKUJI, DENOM_UUSK, USER, VALIDATOR};
 use crate::{
     constants::{EXCHANGE_CONTRACT_ADDRESS, ONE, TEN},
     contract::instantiate,
@@ -10,11 +10,13 @@ use crate::{
     },
     types::{
         config::Config,
+        denom::Denom,
         destination::Destination,
         event::{EventBuilder, EventData},
         fee_collector::FeeCollector,
         performance_assessment_strategy::PerformanceAssessmentStrategy,
         position_type::PositionType,
+        rounding_mode::RoundingMode,
         swap_adjustment_strategy::{
             BaseDenom, SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
         },
@@ -32,7 +34,7 @@ pub fn instantiate_contract(deps: DepsMut, env: Env, info: MessageInfo) {
     let instantiate_message = InstantiateMsg {
         admin: Addr::unchecked(ADMIN),
         executors: vec![Addr::unchecked("executor")],
-        fee_collectors: vec![FeeCollector {
+        fee_collectors: vec![FeeCollector::Address {
             address: ADMIN.to_string(),
             allocation: Decimal::from_str("1").unwrap(),
         }],
@@ -45,6 +47,23 @@ pub fn instantiate_contract(deps: DepsMut, env: Env, info: MessageInfo) {
         twap_period: 30,
         default_slippage_tolerance: Decimal::percent(2),
         exchange_contract_address: Addr::unchecked(EXCHANGE_CONTRACT_ADDRESS),
+        arbiter: Some(Addr::unchecked(ARBITER)),
+        fee_grant: None,
+        simulation_mode: false,
+        referral_fee_percent: Decimal::percent(10),
+        executor_reward_percent: Decimal::percent(10),
+        minimum_deposit: vec![],
+        minimum_swap_amount: Uint128::zero(),
+        event_retention_blocks: None,
+        max_bounty_balance: vec![],
+        max_total_balance_per_owner: vec![],
+        exchange_adapters: vec![],
+        auto_route_safety_margin: Decimal::zero(),
+        price_oracle_address: None,
+        rounding_mode: RoundingMode::FloorToProtocol,
+        address_prefix: None,
+        slippage_retry_interval_seconds: 600,
+        max_slippage_retries: 3,
     };
 
     instantiate(deps, env, info, instantiate_message).unwrap();
@@ -69,6 +88,23 @@ pub fn instantiate_contract_with_multiple_fee_collectors(
         twap_period: 30,
         default_slippage_tolerance: Decimal::percent(2),
         exchange_contract_address: Addr::unchecked(EXCHANGE_CONTRACT_ADDRESS),
+        arbiter: None,
+        fee_grant: None,
+        simulation_mode: false,
+        referral_fee_percent: Decimal::percent(10),
+        executor_reward_percent: Decimal::percent(10),
+        minimum_deposit: vec![],
+        minimum_swap_amount: Uint128::zero(),
+        event_retention_blocks: None,
+        max_bounty_balance: vec![],
+        max_total_balance_per_owner: vec![],
+        exchange_adapters: vec![],
+        auto_route_safety_margin: Decimal::zero(),
+        price_oracle_address: None,
+        rounding_mode: RoundingMode::FloorToProtocol,
+        address_prefix: None,
+        slippage_retry_interval_seconds: 600,
+        max_slippage_retries: 3,
     };
 
     instantiate(deps, env, info, instantiate_message).unwrap();
@@ -78,8 +114,8 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             admin: Addr::unchecked(ADMIN),
-            executors: vec![Addr::unchecked("executor")],
-            fee_collectors: vec![FeeCollector {
+            pending_admin: None,
+            fee_collectors: vec![FeeCollector::Address {
                 address: ADMIN.to_string(),
                 allocation: Decimal::from_str("1").unwrap(),
             }],
@@ -88,10 +124,31 @@ impl Default for Config {
             automation_fee_percent: Decimal::zero(),
             default_page_limit: 30,
             paused: false,
+            create_paused: false,
+            execute_paused: false,
+            deposit_paused: false,
+            disburse_paused: false,
             risk_weighted_average_escrow_level: Decimal::from_str("0.0075").unwrap(),
             twap_period: 30,
             default_slippage_tolerance: Decimal::percent(2),
             exchange_contract_address: Addr::unchecked(EXCHANGE_CONTRACT_ADDRESS),
+            arbiter: Some(Addr::unchecked(ARBITER)),
+            fee_grant: None,
+            simulation_mode: false,
+            referral_fee_percent: Decimal::percent(10),
+            executor_reward_percent: Decimal::percent(10),
+            minimum_deposit: vec![],
+            minimum_swap_amount: Uint128::zero(),
+            event_retention_blocks: None,
+            max_bounty_balance: vec![],
+            max_total_balance_per_owner: vec![],
+            exchange_adapters: vec![],
+            auto_route_safety_margin: Decimal::zero(),
+            price_oracle_address: None,
+            rounding_mode: RoundingMode::FloorToProtocol,
+            address_prefix: None,
+            slippage_retry_interval_seconds: 600,
+            max_slippage_retries: 3,
         }
     }
 }
@@ -102,6 +159,9 @@ impl Default for Destination {
             allocation: Decimal::percent(100),
             address: Addr::unchecked(USER),
             msg: None,
+            condition: None,
+            ibc: None,
+            gas_limit: None,
         }
     }
 }
@@ -123,10 +183,13 @@ impl Default for Vault {
                     })
                     .unwrap(),
                 ),
+                condition: None,
+                ibc: None,
+                gas_limit: None,
             }],
             status: VaultStatus::Active,
             balance: Coin::new(TEN.into(), DENOM_UKUJI),
-            target_denom: DENOM_UUSK.to_string(),
+            target_denom: Denom::new(DENOM_UUSK).unwrap(),
             swap_amount: ONE,
             route: Some(to_json_binary::<Vec<u64>>(&vec![1, 2]).unwrap()),
             slippage_tolerance: Decimal::percent(10),
@@ -138,6 +201,25 @@ impl Default for Vault {
             swapped_amount: Coin::new(0, DENOM_UKUJI),
             received_amount: Coin::new(0, DENOM_UUSK),
             escrowed_amount: Coin::new(0, DENOM_UUSK),
+            exact_receive_amount: None,
+            expires_at: None,
+            disbursement_schedule: None,
+            cancel_reason: None,
+            cancel_note: None,
+            private_metadata: false,
+            referrer: None,
+            executor_slippage_override_cap: None,
+            fallback_routes: vec![],
+            description: None,
+            tags: vec![],
+            metadata_uri: None,
+            minimum_price: None,
+            maximum_price: None,
+            escrow_available_after: None,
+            metadata_hash: None,
+            min_adjustment: None,
+            max_adjustment: None,
+            lock_period: None,
             trigger: Some(TriggerConfiguration::Time {
                 target_time: Timestamp::from_seconds(0),
             }),
@@ -195,6 +277,10 @@ impl Default for EventData {
             base_denom: DENOM_UUSK.to_string(),
             quote_denom: DENOM_UKUJI.to_string(),
             asset_price: Decimal::new(Uint128::one()),
+            slippage_override: None,
+            swap_adjustment: None,
+            exchange_adapter: None,
+            route: None,
         }
     }
 }
@@ -218,6 +304,20 @@ pub fn setup_vault(deps: DepsMut, env: Env, mut vault: Vault) -> Vault {
                     TriggerConfiguration::Time { target_time } => TriggerConfiguration::Time {
                         target_time: max(target_time, env.block.time),
                     },
+                    TriggerConfiguration::BlockHeight { target_height } => {
+                        TriggerConfiguration::BlockHeight { target_height }
+                    }
+                    TriggerConfiguration::BalanceAbove {
+                        account,
+                        denom,
+                        threshold,
+                        fired,
+                    } => TriggerConfiguration::BalanceAbove {
+                        account,
+                        denom,
+                        threshold,
+                        fired,
+                    },
                     TriggerConfiguration::Price {
                         target_price,
                         order_idx,
@@ -226,6 +326,7 @@ pub fn setup_vault(deps: DepsMut, env: Env, mut vault: Vault) -> Vault {
                         order_idx,
                     },
                 },
+                retry_count: 0,
             },
         )
         .unwrap();
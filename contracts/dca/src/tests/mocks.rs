@@ -14,6 +14,7 @@ pub const USER: &str = "user";
 pub const ADMIN: &str = "admin";
 pub const FEE_COLLECTOR: &str = "fee_collector";
 pub const VALIDATOR: &str = "validator";
+pub const ARBITER: &str = "arbiter";
 
 pub const DENOM_UKUJI: &str = "ukuji";
 pub const DENOM_UUSK: &str = "uusk";
@@ -114,6 +115,10 @@ impl<C: CustomQuery + DeserializeOwned> CalcMockQuerier<C> {
         self.mock_querier.update_wasm(wasm_handler);
     }
 
+    pub fn update_balance(&mut self, address: &str, balance: Vec<Coin>) {
+        self.mock_querier.update_balance(address, balance);
+    }
+
     pub fn update_fin_price(&mut self, price: &'static Decimal) {
         self.mock_querier.update_wasm(move |query| {
             SystemResult::Ok(ContractResult::Ok(match query {
@@ -0,0 +1,120 @@
+use super::{config::Config, denom::Denom, role::Role, scheduled_config_update::ConfigChanges};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, Decimal, Timestamp, Uint128};
+
+#[cw_serde]
+pub enum AdminActionData {
+    ConfigUpdated {
+        config: Config,
+    },
+    ConfigUpdateScheduled {
+        scheduled_config_update_id: u64,
+        activates_at: Timestamp,
+        changes: ConfigChanges,
+    },
+    ConfigUpdateCancelled {
+        scheduled_config_update_id: u64,
+    },
+    BountyCancelled {
+        bounty_id: Uint128,
+    },
+    AdminTransferProposed {
+        pending_admin: Addr,
+    },
+    AdminTransferAccepted {
+        previous_admin: Addr,
+    },
+    CustomSwapFeeSet {
+        denom: String,
+        swap_fee_percent: Decimal,
+    },
+    CustomSwapFeeRemoved {
+        denom: String,
+    },
+    FeeExemptionAdded {
+        address: Addr,
+    },
+    FeeExemptionRemoved {
+        address: Addr,
+    },
+    Cw20TokenWhitelisted {
+        token: Addr,
+    },
+    Cw20TokenRemoved {
+        token: Addr,
+    },
+    AllowedDenomAdded {
+        denom: Denom,
+    },
+    AllowedDenomRemoved {
+        denom: Denom,
+    },
+    ExecutorReported {
+        address: Addr,
+        reason: String,
+    },
+    RoleGranted {
+        address: Addr,
+        role: Role,
+    },
+    RoleRevoked {
+        address: Addr,
+        role: Role,
+    },
+    ModelProviderAdded {
+        provider: Addr,
+    },
+    ModelProviderRemoved {
+        provider: Addr,
+    },
+    ExchangeAdded {
+        address: Addr,
+        weight: u64,
+    },
+    ExchangeRemoved {
+        address: Addr,
+    },
+    PairExchangeSet {
+        swap_denom: String,
+        target_denom: String,
+        exchange: Option<Addr>,
+    },
+}
+
+#[cw_serde]
+pub struct AdminAction {
+    pub id: u64,
+    pub admin: Addr,
+    pub timestamp: Timestamp,
+    pub block_height: u64,
+    pub data: AdminActionData,
+}
+
+#[derive(Clone)]
+pub struct AdminActionBuilder {
+    admin: Addr,
+    timestamp: Timestamp,
+    block_height: u64,
+    data: AdminActionData,
+}
+
+impl AdminActionBuilder {
+    pub fn new(admin: Addr, block: BlockInfo, data: AdminActionData) -> AdminActionBuilder {
+        AdminActionBuilder {
+            admin,
+            timestamp: block.time,
+            block_height: block.height,
+            data,
+        }
+    }
+
+    pub fn build(self, id: u64) -> AdminAction {
+        AdminAction {
+            id,
+            admin: self.admin,
+            timestamp: self.timestamp,
+            block_height: self.block_height,
+            data: self.data,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp};
+
+#[cw_serde]
+pub struct Application {
+    pub applicant: Addr,
+    pub message: String,
+    pub applied_at: Timestamp,
+}
@@ -0,0 +1,19 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp};
+
+#[cw_serde]
+pub struct Assignee {
+    pub address: Addr,
+    pub weight: Decimal,
+    pub submission: Option<String>,
+    pub submitted_at: Option<Timestamp>,
+    pub payout_address: Option<Addr>,
+}
+
+impl Assignee {
+    pub fn get_payout_address(&self) -> Addr {
+        self.payout_address
+            .clone()
+            .unwrap_or_else(|| self.address.clone())
+    }
+}
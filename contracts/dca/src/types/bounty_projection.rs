@@ -0,0 +1,155 @@
+use crate::types::bounty::{Bounty, BountyStatus};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, StdError, StdResult, Timestamp, Uint128};
+
+#[cw_serde]
+pub enum BountyField {
+    Owner,
+    Status,
+    Balance,
+    Label,
+    TargetDenom,
+    CreatedAt,
+    StartedAt,
+    DepositedAmount,
+    ReceivedAmount,
+    EscrowedAmount,
+    ExpiresAt,
+    LockedUntil,
+    Tags,
+}
+
+impl BountyField {
+    pub fn parse(field: &str) -> StdResult<Self> {
+        match field {
+            "owner" => Ok(BountyField::Owner),
+            "status" => Ok(BountyField::Status),
+            "balance" => Ok(BountyField::Balance),
+            "label" => Ok(BountyField::Label),
+            "target_denom" => Ok(BountyField::TargetDenom),
+            "created_at" => Ok(BountyField::CreatedAt),
+            "started_at" => Ok(BountyField::StartedAt),
+            "deposited_amount" => Ok(BountyField::DepositedAmount),
+            "received_amount" => Ok(BountyField::ReceivedAmount),
+            "escrowed_amount" => Ok(BountyField::EscrowedAmount),
+            "expires_at" => Ok(BountyField::ExpiresAt),
+            "locked_until" => Ok(BountyField::LockedUntil),
+            "tags" => Ok(BountyField::Tags),
+            _ => Err(StdError::generic_err(format!(
+                "unknown bounty field: {}",
+                field
+            ))),
+        }
+    }
+
+    pub fn parse_all(fields: &[String]) -> StdResult<Vec<Self>> {
+        fields
+            .iter()
+            .map(|field| BountyField::parse(field))
+            .collect()
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct BountyProjection {
+    pub id: Uint128,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Addr>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<BountyStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<Coin>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_denom: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deposited_amount: Option<Coin>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub received_amount: Option<Coin>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escrowed_amount: Option<Coin>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locked_until: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+impl BountyProjection {
+    pub fn from_bounty(bounty: Bounty, fields: &[BountyField]) -> Self {
+        let mut projection = BountyProjection {
+            id: bounty.id,
+            ..BountyProjection::default()
+        };
+
+        for field in fields {
+            match field {
+                BountyField::Owner => projection.owner = Some(bounty.owner.clone()),
+                BountyField::Status => projection.status = Some(bounty.status.clone()),
+                BountyField::Balance => projection.balance = Some(bounty.balance.clone()),
+                BountyField::Label => projection.label = bounty.label.clone(),
+                BountyField::TargetDenom => {
+                    projection.target_denom = Some(bounty.target_denom.to_string())
+                }
+                BountyField::CreatedAt => projection.created_at = Some(bounty.created_at),
+                BountyField::StartedAt => projection.started_at = bounty.started_at,
+                BountyField::DepositedAmount => {
+                    projection.deposited_amount = Some(bounty.deposited_amount.clone())
+                }
+                BountyField::ReceivedAmount => {
+                    projection.received_amount = Some(bounty.received_amount.clone())
+                }
+                BountyField::EscrowedAmount => {
+                    projection.escrowed_amount = Some(bounty.escrowed_amount.clone())
+                }
+                BountyField::ExpiresAt => projection.expires_at = bounty.expires_at,
+                BountyField::LockedUntil => projection.locked_until = bounty.locked_until(),
+                BountyField::Tags => projection.tags = Some(bounty.tags.clone()),
+            }
+        }
+
+        projection
+    }
+}
+
+#[cfg(test)]
+mod bounty_projection_tests {
+    use super::*;
+
+    #[test]
+    fn projects_only_requested_fields() {
+        let bounty = Bounty::default();
+
+        let projection = BountyProjection::from_bounty(
+            bounty.clone(),
+            &BountyField::parse_all(&["owner".to_string(), "balance".to_string()]).unwrap(),
+        );
+
+        assert_eq!(
+            projection,
+            BountyProjection {
+                id: bounty.id,
+                owner: Some(bounty.owner),
+                balance: Some(bounty.balance),
+                ..BountyProjection::default()
+            }
+        );
+    }
+
+    #[test]
+    fn with_unknown_field_fails() {
+        assert_eq!(
+            BountyField::parse_all(&["swap_amount".to_string()])
+                .unwrap_err()
+                .to_string(),
+            "Generic error: unknown bounty field: swap_amount"
+        );
+    }
+}
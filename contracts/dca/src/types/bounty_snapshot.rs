@@ -0,0 +1,11 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Timestamp};
+
+#[cw_serde]
+pub struct BountySnapshot {
+    pub period_start: Timestamp,
+    pub recorded_at: Timestamp,
+    pub balance: Coin,
+    pub received_amount: Coin,
+    pub escrowed_amount: Coin,
+}
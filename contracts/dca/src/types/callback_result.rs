@@ -0,0 +1,10 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary};
+
+#[cw_serde]
+pub struct CallbackResult {
+    pub execution_height: u64,
+    pub destination_index: u32,
+    pub destination: Addr,
+    pub data: Binary,
+}
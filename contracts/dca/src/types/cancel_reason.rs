@@ -0,0 +1,9 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum CancelReason {
+    CompletedEarly,
+    Abandoned,
+    Disputed,
+    Other,
+}
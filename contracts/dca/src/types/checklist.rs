@@ -0,0 +1,31 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Timestamp};
+
+#[cw_serde]
+pub struct ChecklistItemParams {
+    pub description: String,
+    pub weight: Decimal,
+}
+
+#[cw_serde]
+pub struct ChecklistItem {
+    pub description: String,
+    pub weight: Decimal,
+    pub completed_at: Option<Timestamp>,
+}
+
+impl ChecklistItem {
+    pub fn is_completed(&self) -> bool {
+        self.completed_at.is_some()
+    }
+}
+
+impl From<ChecklistItemParams> for ChecklistItem {
+    fn from(params: ChecklistItemParams) -> Self {
+        ChecklistItem {
+            description: params.description,
+            weight: params.weight,
+            completed_at: None,
+        }
+    }
+}
@@ -1,19 +1,42 @@
-use super::fee_collector::FeeCollector;
+use super::{
+    fee_collector::FeeCollector, fee_grant_config::FeeGrantConfig, rounding_mode::RoundingMode,
+};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
 
 #[cw_serde]
 pub struct Config {
     pub admin: Addr,
-    pub executors: Vec<Addr>,
+    pub pending_admin: Option<Addr>,
     pub fee_collectors: Vec<FeeCollector>,
    // pub default_swap_fee_percent: Decimal,
    // pub weighted_scale_swap_fee_percent: Decimal,
     pub automation_fee_percent: Decimal,
     pub default_page_limit: u16,
     pub paused: bool,
+    pub create_paused: bool,
+    pub execute_paused: bool,
+    pub deposit_paused: bool,
+    pub disburse_paused: bool,
     pub risk_weighted_average_escrow_level: Decimal,
     // pub twap_period: u64,
     pub default_slippage_tolerance: Decimal,
     pub exchange_contract_address: Addr,
+    pub arbiter: Option<Addr>,
+    pub fee_grant: Option<FeeGrantConfig>,
+    pub simulation_mode: bool,
+    pub referral_fee_percent: Decimal,
+    pub executor_reward_percent: Decimal,
+    pub minimum_deposit: Vec<Coin>,
+    pub minimum_swap_amount: Uint128,
+    pub event_retention_blocks: Option<u64>,
+    pub max_bounty_balance: Vec<Coin>,
+    pub max_total_balance_per_owner: Vec<Coin>,
+    pub exchange_adapters: Vec<Addr>,
+    pub auto_route_safety_margin: Decimal,
+    pub price_oracle_address: Option<Addr>,
+    pub rounding_mode: RoundingMode,
+    pub address_prefix: Option<String>,
+    pub slippage_retry_interval_seconds: u64,
+    pub max_slippage_retries: u8,
 }
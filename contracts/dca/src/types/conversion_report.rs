@@ -0,0 +1,56 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Uint128};
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ConversionReport {
+    pub executions: u64,
+    pub total_sent: Uint128,
+    pub total_received: Uint128,
+    pub fees_paid: Uint128,
+    pub twap_received: Uint128,
+}
+
+impl ConversionReport {
+    pub fn average_price(&self) -> Option<Decimal> {
+        Decimal::checked_from_ratio(self.total_sent, self.total_received).ok()
+    }
+
+    pub fn twap_average_price(&self) -> Option<Decimal> {
+        Decimal::checked_from_ratio(self.total_sent, self.twap_received).ok()
+    }
+}
+
+#[cfg(test)]
+mod conversion_report_tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn average_prices_are_blended_across_executions() {
+        let report = ConversionReport {
+            executions: 2,
+            total_sent: Uint128::new(300),
+            total_received: Uint128::new(200),
+            fees_paid: Uint128::new(2),
+            twap_received: Uint128::new(250),
+        };
+
+        assert_eq!(
+            report.average_price(),
+            Some(Decimal::from_str("1.5").unwrap())
+        );
+        assert_eq!(
+            report.twap_average_price(),
+            Some(Decimal::from_str("1.2").unwrap())
+        );
+    }
+
+    #[test]
+    fn average_prices_are_empty_without_executions() {
+        let report = ConversionReport::default();
+
+        assert_eq!(report.average_price(), None);
+        assert_eq!(report.twap_average_price(), None);
+    }
+}
@@ -0,0 +1,202 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{StdError, StdResult};
+use std::{fmt::Display, ops::Deref};
+
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+#[cw_serde]
+#[serde(try_from = "String", into = "String")]
+pub struct Denom(String);
+
+impl Denom {
+    pub fn new(denom: impl Into<String>) -> StdResult<Self> {
+        let denom = denom.into();
+
+        if let Some(hash) = strip_prefix_ignore_case(&denom, "ibc/") {
+            if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(invalid_denom(&denom, "ibc hash must be 64 hex characters"));
+            }
+            return Ok(Denom(format!("ibc/{}", hash.to_lowercase())));
+        }
+
+        if let Some(address) = denom.strip_prefix("cw20:") {
+            if !is_bech32_address(address) {
+                return Err(invalid_denom(&denom, "cw20 address is not valid bech32"));
+            }
+            return Ok(Denom(denom));
+        }
+
+        if let Some(rest) = denom.strip_prefix("factory/") {
+            let (creator, subdenom) = rest
+                .split_once('/')
+                .ok_or_else(|| invalid_denom(&denom, "factory denom must have a subdenom"))?;
+
+            if !is_bech32_address(creator) {
+                return Err(invalid_denom(&denom, "factory creator is not valid bech32"));
+            }
+
+            if subdenom.is_empty() {
+                return Err(invalid_denom(&denom, "factory denom must have a subdenom"));
+            }
+        }
+
+        if !is_native_denom(&denom) {
+            return Err(invalid_denom(&denom, "native denom is malformed"));
+        }
+
+        Ok(Denom(denom))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    value
+        .get(..prefix.len())
+        .filter(|start| start.eq_ignore_ascii_case(prefix))
+        .map(|_| &value[prefix.len()..])
+}
+
+fn is_native_denom(denom: &str) -> bool {
+    let mut chars = denom.chars();
+
+    (3..=128).contains(&denom.len())
+        && chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c))
+}
+
+fn is_bech32_address(address: &str) -> bool {
+    address.rsplit_once('1').is_some_and(|(hrp, data)| {
+        !hrp.is_empty()
+            && data.len() >= 6
+            && hrp
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && data.chars().all(|c| BECH32_CHARSET.contains(c))
+    })
+}
+
+fn invalid_denom(denom: &str, reason: &str) -> StdError {
+    StdError::generic_err(format!("invalid denom {}: {}", denom, reason))
+}
+
+impl TryFrom<String> for Denom {
+    type Error = StdError;
+
+    fn try_from(denom: String) -> StdResult<Self> {
+        Denom::new(denom)
+    }
+}
+
+impl TryFrom<&str> for Denom {
+    type Error = StdError;
+
+    fn try_from(denom: &str) -> StdResult<Self> {
+        Denom::new(denom)
+    }
+}
+
+impl From<Denom> for String {
+    fn from(denom: Denom) -> Self {
+        denom.0
+    }
+}
+
+impl Deref for Denom {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Denom {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Denom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PartialEq<str> for Denom {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Denom {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Denom {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<Denom> for String {
+    fn eq(&self, other: &Denom) -> bool {
+        self == &other.0
+    }
+}
+
+#[cfg(test)]
+mod denom_tests {
+    use super::*;
+    use cosmwasm_std::from_json;
+
+    #[test]
+    fn accepts_native_denoms() {
+        assert_eq!(Denom::new("uosmo").unwrap(), "uosmo");
+    }
+
+    #[test]
+    fn rejects_malformed_native_denoms() {
+        assert!(Denom::new("").is_err());
+        assert!(Denom::new("1uosmo").is_err());
+        assert!(Denom::new("u osmo").is_err());
+    }
+
+    #[test]
+    fn lowercases_ibc_hashes() {
+        let hash = "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+        assert_eq!(
+            Denom::new(format!("IBC/{}", hash)).unwrap(),
+            format!("ibc/{}", hash.to_lowercase())
+        );
+    }
+
+    #[test]
+    fn rejects_ibc_denoms_without_a_valid_hash() {
+        assert!(Denom::new("ibc/1234").is_err());
+        assert!(Denom::new(format!("ibc/{}", "z".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn validates_factory_denoms() {
+        assert!(Denom::new("factory/osmo1qyqszqgpqyqszqgpqyqszqgpqyqszqgpjnp7du/utoken").is_ok());
+        assert!(Denom::new("factory/not-an-address/utoken").is_err());
+        assert!(Denom::new("factory/osmo1qyqszqgpqyqszqgpqyqszqgpqyqszqgpjnp7du").is_err());
+    }
+
+    #[test]
+    fn validates_cw20_denoms() {
+        assert!(Denom::new("cw20:kujira1qyqszqgpqyqszqgpqyqszqgpqyqszqgpjnp7du").is_ok());
+        assert!(Denom::new("cw20:").is_err());
+    }
+
+    #[test]
+    fn validates_when_deserialising() {
+        assert!(from_json::<Denom>(b"\"uosmo\"").is_ok());
+        assert!(from_json::<Denom>(b"\"ibc/1234\"").is_err());
+    }
+}
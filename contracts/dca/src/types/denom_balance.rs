@@ -0,0 +1,10 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, Coin, Uint128};
+
+#[cw_serde]
+pub struct DenomBalance {
+    pub balance: Coin,
+    pub deposited_amount: Coin,
+    pub swap_amount: Uint128,
+    pub route: Option<Binary>,
+}
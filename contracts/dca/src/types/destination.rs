@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Decimal};
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
 
 
 // Where funds should be sent once escrow verification. 
@@ -8,4 +8,94 @@ pub struct Destination {
     pub allocation: Decimal,
     pub address: Addr,
     pub msg: Option<Binary>,
+    pub condition: Option<DestinationCondition>,
+    pub ibc: Option<IbcDestination>,
+    #[serde(default)]
+    pub gas_limit: Option<u64>,
+}
+
+#[cw_serde]
+pub struct IbcDestination {
+    pub channel_id: String,
+    pub remote_address: String,
+    pub timeout_seconds: Option<u64>,
+}
+
+#[cw_serde]
+pub enum DestinationCondition {
+    OnSuccess,
+    OnSkipped,
+    MinReceived { amount: Uint128 },
+}
+
+#[cw_serde]
+pub enum DisbursementOutcome {
+    Succeeded { received: Uint128 },
+    Skipped,
+}
+
+impl Destination {
+    pub fn is_eligible_for(&self, outcome: &DisbursementOutcome) -> bool {
+        match (&self.condition, outcome) {
+            (None, _) => true,
+            (Some(DestinationCondition::OnSuccess), DisbursementOutcome::Succeeded { .. }) => true,
+            (Some(DestinationCondition::OnSkipped), DisbursementOutcome::Skipped) => true,
+            (
+                Some(DestinationCondition::MinReceived { amount }),
+                DisbursementOutcome::Succeeded { received },
+            ) => received >= amount,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod is_eligible_for_tests {
+    use super::*;
+
+    fn destination_with(condition: Option<DestinationCondition>) -> Destination {
+        Destination {
+            allocation: Decimal::percent(100),
+            address: Addr::unchecked("destination"),
+            msg: None,
+            condition,
+            ibc: None,
+            gas_limit: None,
+        }
+    }
+
+    #[test]
+    fn unconditional_destination_is_always_eligible() {
+        let destination = destination_with(None);
+
+        assert!(destination.is_eligible_for(&DisbursementOutcome::Skipped));
+        assert!(destination.is_eligible_for(&DisbursementOutcome::Succeeded {
+            received: Uint128::one()
+        }));
+    }
+
+    #[test]
+    fn on_skipped_destination_is_only_eligible_when_skipped() {
+        let destination = destination_with(Some(DestinationCondition::OnSkipped));
+
+        assert!(destination.is_eligible_for(&DisbursementOutcome::Skipped));
+        assert!(!destination.is_eligible_for(&DisbursementOutcome::Succeeded {
+            received: Uint128::one()
+        }));
+    }
+
+    #[test]
+    fn min_received_destination_is_eligible_at_or_above_threshold() {
+        let destination = destination_with(Some(DestinationCondition::MinReceived {
+            amount: Uint128::new(100),
+        }));
+
+        assert!(!destination.is_eligible_for(&DisbursementOutcome::Succeeded {
+            received: Uint128::new(99)
+        }));
+        assert!(destination.is_eligible_for(&DisbursementOutcome::Succeeded {
+            received: Uint128::new(100)
+        }));
+        assert!(!destination.is_eligible_for(&DisbursementOutcome::Skipped));
+    }
 }
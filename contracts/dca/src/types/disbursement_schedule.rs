@@ -0,0 +1,70 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Timestamp};
+
+#[cw_serde]
+pub enum DisbursementSchedule {
+    Linear { start: Timestamp, end: Timestamp },
+}
+
+impl DisbursementSchedule {
+    pub fn vested_ratio(&self, current_time: Timestamp) -> Decimal {
+        match self {
+            DisbursementSchedule::Linear { start, end } => {
+                if current_time <= *start {
+                    return Decimal::zero();
+                }
+
+                if current_time >= *end {
+                    return Decimal::one();
+                }
+
+                Decimal::from_ratio(
+                    current_time.seconds() - start.seconds(),
+                    end.seconds() - start.seconds(),
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod vested_ratio_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_env;
+
+    #[test]
+    fn before_start_is_zero() {
+        let now = mock_env().block.time;
+
+        let schedule = DisbursementSchedule::Linear {
+            start: now.plus_seconds(10),
+            end: now.plus_seconds(20),
+        };
+
+        assert_eq!(schedule.vested_ratio(now), Decimal::zero());
+    }
+
+    #[test]
+    fn halfway_through_is_half() {
+        let now = mock_env().block.time;
+
+        let schedule = DisbursementSchedule::Linear {
+            start: now.minus_seconds(10),
+            end: now.plus_seconds(10),
+        };
+
+        assert_eq!(schedule.vested_ratio(now), Decimal::percent(50));
+    }
+
+    #[test]
+    fn after_end_is_one() {
+        let now = mock_env().block.time;
+
+        let schedule = DisbursementSchedule::Linear {
+            start: now.minus_seconds(20),
+            end: now.minus_seconds(10),
+        };
+
+        assert_eq!(schedule.vested_ratio(now), Decimal::one());
+    }
+}
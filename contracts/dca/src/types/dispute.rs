@@ -0,0 +1,22 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+
+#[cw_serde]
+pub enum Ruling {
+    Owner {},
+    Assignee {
+        assignee: Addr,
+    },
+    Split {
+        assignee: Addr,
+        assignee_share: Decimal,
+    },
+}
+
+#[cw_serde]
+pub struct Dispute {
+    pub bounty_id: Uint128,
+    pub raised_by: Addr,
+    pub reason: String,
+    pub raised_at: Timestamp,
+}
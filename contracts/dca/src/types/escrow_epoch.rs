@@ -0,0 +1,9 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+
+#[cw_serde]
+pub struct EscrowEpoch {
+    pub escrow_level: Decimal,
+    pub escrowed_amount: Uint128,
+    pub started_at: Timestamp,
+}
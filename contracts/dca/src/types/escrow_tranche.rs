@@ -0,0 +1,143 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+
+#[cw_serde]
+pub struct EscrowTranche {
+    pub allocation: Decimal,
+    pub delay_seconds: u64,
+}
+
+#[cw_serde]
+pub struct EscrowTrancheSchedule {
+    pub tranches: Vec<EscrowTranche>,
+    pub started_at: Option<Timestamp>,
+    pub total_escrow: Uint128,
+    pub released_tranches: u32,
+}
+
+impl EscrowTrancheSchedule {
+    pub fn new(tranches: Vec<EscrowTranche>) -> Self {
+        Self {
+            tranches,
+            started_at: None,
+            total_escrow: Uint128::zero(),
+            released_tranches: 0,
+        }
+    }
+
+    pub fn start(self, started_at: Timestamp, total_escrow: Uint128) -> Self {
+        match self.started_at {
+            Some(_) => self,
+            None => Self {
+                started_at: Some(started_at),
+                total_escrow,
+                ..self
+            },
+        }
+    }
+
+    pub fn due_tranches(&self, current_time: Timestamp) -> u32 {
+        self.started_at.map_or(0, |started_at| {
+            self.tranches
+                .iter()
+                .filter(|tranche| started_at.plus_seconds(tranche.delay_seconds) <= current_time)
+                .count() as u32
+        })
+    }
+
+    pub fn is_final_tranche(&self, tranche_count: u32) -> bool {
+        tranche_count as usize >= self.tranches.len()
+    }
+
+    pub fn release_amount(&self, due_tranches: u32, escrowed_amount: Uint128) -> Uint128 {
+        if self.is_final_tranche(due_tranches) {
+            return escrowed_amount;
+        }
+
+        let allocation = self
+            .tranches
+            .iter()
+            .take(due_tranches as usize)
+            .skip(self.released_tranches as usize)
+            .fold(Decimal::zero(), |acc, tranche| acc + tranche.allocation);
+
+        (self.total_escrow * allocation).min(escrowed_amount)
+    }
+
+    pub fn next_due_date(&self) -> Option<Timestamp> {
+        self.started_at.and_then(|started_at| {
+            self.tranches
+                .get(self.released_tranches as usize)
+                .map(|tranche| started_at.plus_seconds(tranche.delay_seconds))
+        })
+    }
+}
+
+#[cfg(test)]
+mod escrow_tranche_schedule_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_env;
+
+    fn schedule() -> EscrowTrancheSchedule {
+        EscrowTrancheSchedule::new(vec![
+            EscrowTranche {
+                allocation: Decimal::percent(50),
+                delay_seconds: 0,
+            },
+            EscrowTranche {
+                allocation: Decimal::percent(25),
+                delay_seconds: 30 * 24 * 60 * 60,
+            },
+            EscrowTranche {
+                allocation: Decimal::percent(25),
+                delay_seconds: 90 * 24 * 60 * 60,
+            },
+        ])
+    }
+
+    #[test]
+    fn counts_no_tranches_due_before_start() {
+        assert_eq!(schedule().due_tranches(mock_env().block.time), 0);
+    }
+
+    #[test]
+    fn releases_first_tranche_at_start() {
+        let now = mock_env().block.time;
+        let schedule = schedule().start(now, Uint128::new(1000));
+
+        assert_eq!(schedule.due_tranches(now), 1);
+        assert_eq!(
+            schedule.release_amount(1, Uint128::new(1000)),
+            Uint128::new(500)
+        );
+    }
+
+    #[test]
+    fn releases_remaining_escrow_with_final_tranche() {
+        let now = mock_env().block.time;
+        let schedule = EscrowTrancheSchedule {
+            released_tranches: 1,
+            ..schedule().start(now.minus_seconds(91 * 24 * 60 * 60), Uint128::new(1000))
+        };
+
+        assert_eq!(schedule.due_tranches(now), 3);
+        assert_eq!(
+            schedule.release_amount(3, Uint128::new(499)),
+            Uint128::new(499)
+        );
+    }
+
+    #[test]
+    fn next_due_date_is_the_next_unreleased_tranche() {
+        let now = mock_env().block.time;
+        let schedule = EscrowTrancheSchedule {
+            released_tranches: 1,
+            ..schedule().start(now, Uint128::new(1000))
+        };
+
+        assert_eq!(
+            schedule.next_due_date(),
+            Some(now.plus_seconds(30 * 24 * 60 * 60))
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{BlockInfo, Coin, Decimal, SubMsg, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Binary, BlockInfo, Coin, Decimal, SubMsg, Timestamp, Uint128};
 
-use super::update::Update;
+use super::{cancel_reason::CancelReason, dispute::Ruling, update::FieldUpdate};
 
 #[cw_serde]
 pub enum ExecutionSkippedReason {
@@ -17,10 +17,17 @@ pub enum EventData {
     BountyFundsDeposited {
         amount: Coin,
     },
+    BountyFundsWithdrawn {
+        amount: Coin,
+    },
     BountyExecutionTriggered {
         base_denom: String,
         quote_denom: String,
         asset_price: Decimal,
+        slippage_override: Option<Decimal>,
+        swap_adjustment: Option<Decimal>,
+        exchange_adapter: Option<Addr>,
+        route: Option<Binary>,
     },
     BountyExecutionCompleted {
         sent: Coin,
@@ -38,17 +45,93 @@ pub enum EventData {
     SimulatedBountyExecutionSkipped {
         reason: ExecutionSkippedReason,
     },
-    BountyCancelled {},
+    BountyCancelled {
+        reason: Option<CancelReason>,
+        note: Option<String>,
+    },
+    BountyExpired {},
+    BountyPaused {},
+    BountyResumed {},
+    BountyPriceOracleUsed {
+        oracle: Addr,
+        price: Decimal,
+    },
     BountyEscrowDisbursed {
         amount_disbursed: Coin,
         performance_fee: Coin,
     },
+    BountyEscrowClaimed {
+        assignee: Addr,
+        amount_claimed: Coin,
+    },
+    BountyChecklistItemCompleted {
+        item_index: u32,
+        amount_disbursed: Coin,
+    },
+    BountyMatchingFundsReceived {
+        round_id: u64,
+        amount: Coin,
+    },
     BountyPostExecutionActionFailed {
         msg: SubMsg,
         funds: Vec<Coin>,
     },
     BountyUpdated {
-        updates: Vec<Update>,
+        updates: Vec<FieldUpdate>,
+    },
+    BountyAssigned {
+        assignee: Addr,
+        weight: Decimal,
+    },
+    BountyUnassigned {
+        assignee: Addr,
+    },
+    BountyPayoutAddressSet {
+        assignee: Addr,
+        payout_address: Addr,
+    },
+    BountyWorkSubmitted {
+        assignee: Addr,
+        submission: String,
+    },
+    BountyApplicationSubmitted {
+        applicant: Addr,
+        message: String,
+    },
+    BountyApplicationWithdrawn {
+        applicant: Addr,
+    },
+    BountyApplicationAccepted {
+        applicant: Addr,
+    },
+    BountyDisputeRaised {
+        raised_by: Addr,
+        reason: String,
+    },
+    BountyDisputeResolved {
+        ruling: Ruling,
+    },
+    BountyEscrowReleaseRequested {
+        requested_by: Addr,
+        release_at: Timestamp,
+    },
+    BountyEscrowAccepted {
+        amount_disbursed: Vec<Coin>,
+    },
+    BountyEscrowRejected {
+        amount_refunded: Vec<Coin>,
+    },
+    BountyLimitOrderFailed {
+        amount_refunded: Coin,
+        reason: String,
+    },
+    BountyTriggerRepriced {
+        old_target_price: Decimal,
+        new_target_price: Decimal,
+    },
+    BountyExactReceiveReconciled {
+        exact_receive_amount: Coin,
+        delivered_amount: Coin,
     },
 }
 
@@ -61,6 +144,12 @@ pub struct Event {
     pub data: EventData,
 }
 
+#[cw_serde]
+pub enum EventOrder {
+    Id,
+    BlockHeight,
+}
+
 #[derive(Clone)]
 pub struct EventBuilder {
     resource_id: Uint128,
@@ -0,0 +1,37 @@
+use super::event::{Event, EventData};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Uint128};
+use shared::coin::add_to_coins;
+
+#[cw_serde]
+#[derive(Default)]
+pub struct EventSummary {
+    pub resource_id: Uint128,
+    pub pruned_events: u64,
+    pub total_swapped: Vec<Coin>,
+    pub total_fees: Vec<Coin>,
+}
+
+impl EventSummary {
+    pub fn add_event(self, event: &Event) -> Self {
+        let summary = Self {
+            pruned_events: self.pruned_events + 1,
+            ..self
+        };
+
+        match &event.data {
+            EventData::BountyExecutionCompleted { sent, fee, .. } => Self {
+                total_swapped: add_to_coins(summary.total_swapped, sent),
+                total_fees: add_to_coins(summary.total_fees, fee),
+                ..summary
+            },
+            EventData::BountyEscrowDisbursed {
+                performance_fee, ..
+            } => Self {
+                total_fees: add_to_coins(summary.total_fees, performance_fee),
+                ..summary
+            },
+            _ => summary,
+        }
+    }
+}
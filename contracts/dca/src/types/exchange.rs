@@ -0,0 +1,15 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+
+#[cw_serde]
+pub struct Exchange {
+    pub address: Addr,
+    pub weight: u64,
+}
+
+#[cw_serde]
+pub struct PairExchange {
+    pub swap_denom: String,
+    pub target_denom: String,
+    pub exchange: Addr,
+}
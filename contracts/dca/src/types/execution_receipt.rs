@@ -0,0 +1,138 @@
+use super::destination::Destination;
+use crate::constants::{
+    BASE_EXECUTION_GAS_ESTIMATE, DESTINATION_CALLBACK_GAS_ESTIMATE, MESSAGE_GAS_ESTIMATE,
+    SUBMESSAGE_GAS_ESTIMATE,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ReplyOn, SubMsg, Timestamp};
+
+#[cw_serde]
+pub enum ExecutionWeight {
+    Light,
+    Medium,
+    Heavy,
+}
+
+#[cw_serde]
+pub struct ExecutionReceipt {
+    pub executed_at: Timestamp,
+    pub message_count: u32,
+    pub submessage_count: u32,
+    pub destination_callback_count: u32,
+}
+
+impl ExecutionReceipt {
+    pub fn new(executed_at: Timestamp, sub_msgs: &[SubMsg], destinations: &[Destination]) -> Self {
+        Self {
+            executed_at,
+            message_count: sub_msgs.len() as u32,
+            submessage_count: sub_msgs
+                .iter()
+                .filter(|sub_msg| sub_msg.reply_on != ReplyOn::Never)
+                .count() as u32,
+            destination_callback_count: destinations
+                .iter()
+                .filter(|destination| destination.msg.is_some())
+                .count() as u32,
+        }
+    }
+
+    pub fn estimated_gas(&self) -> u64 {
+        BASE_EXECUTION_GAS_ESTIMATE
+            + self.message_count as u64 * MESSAGE_GAS_ESTIMATE
+            + self.submessage_count as u64 * SUBMESSAGE_GAS_ESTIMATE
+            + self.destination_callback_count as u64 * DESTINATION_CALLBACK_GAS_ESTIMATE
+    }
+
+    pub fn weight(&self) -> ExecutionWeight {
+        match self.estimated_gas() {
+            gas if gas < 400_000 => ExecutionWeight::Light,
+            gas if gas < 1_000_000 => ExecutionWeight::Medium,
+            _ => ExecutionWeight::Heavy,
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct GasUsageReport {
+    pub executions: u64,
+    pub total_messages: u64,
+    pub total_submessages: u64,
+    pub total_destination_callbacks: u64,
+    pub total_estimated_gas: u64,
+    pub last_receipt: Option<ExecutionReceipt>,
+}
+
+impl GasUsageReport {
+    pub fn average_estimated_gas(&self) -> u64 {
+        self.total_estimated_gas
+            .checked_div(self.executions)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod execution_receipt_tests {
+    use super::*;
+    use cosmwasm_std::{testing::mock_env, Addr, BankMsg, Binary, Decimal};
+
+    fn destination_with_callback() -> Destination {
+        Destination {
+            allocation: Decimal::one(),
+            address: Addr::unchecked("contract"),
+            msg: Some(Binary::from(b"{}")),
+            condition: None,
+            ibc: None,
+            gas_limit: None,
+        }
+    }
+
+    #[test]
+    fn counts_messages_submessages_and_callbacks() {
+        let env = mock_env();
+
+        let receipt = ExecutionReceipt::new(
+            env.block.time,
+            &[
+                SubMsg::new(BankMsg::Send {
+                    to_address: "user".to_string(),
+                    amount: vec![],
+                }),
+                SubMsg::reply_always(
+                    BankMsg::Send {
+                        to_address: "user".to_string(),
+                        amount: vec![],
+                    },
+                    1,
+                ),
+            ],
+            &[destination_with_callback()],
+        );
+
+        assert_eq!(receipt.message_count, 2);
+        assert_eq!(receipt.submessage_count, 1);
+        assert_eq!(receipt.destination_callback_count, 1);
+        assert_eq!(
+            receipt.estimated_gas(),
+            BASE_EXECUTION_GAS_ESTIMATE
+                + 2 * MESSAGE_GAS_ESTIMATE
+                + SUBMESSAGE_GAS_ESTIMATE
+                + DESTINATION_CALLBACK_GAS_ESTIMATE
+        );
+        assert_eq!(receipt.weight(), ExecutionWeight::Medium);
+    }
+
+    #[test]
+    fn empty_execution_is_light() {
+        let receipt = ExecutionReceipt::new(mock_env().block.time, &[], &[]);
+
+        assert_eq!(receipt.estimated_gas(), BASE_EXECUTION_GAS_ESTIMATE);
+        assert_eq!(receipt.weight(), ExecutionWeight::Light);
+    }
+
+    #[test]
+    fn average_estimated_gas_is_zero_without_executions() {
+        assert_eq!(GasUsageReport::default().average_estimated_gas(), 0);
+    }
+}
@@ -0,0 +1,10 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ExecutorStats {
+    pub triggers_executed: u64,
+    pub executions_skipped: u64,
+    pub failed_sub_msgs: u64,
+    pub reports: u64,
+}
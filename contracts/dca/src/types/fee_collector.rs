@@ -2,7 +2,36 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Decimal;
 
 #[cw_serde]
-pub struct FeeCollector {
-    pub address: String,
-    pub allocation: Decimal,
+pub enum FeeCollector {
+    Address {
+        address: String,
+        allocation: Decimal,
+    },
+    Burn {
+        allocation: Decimal,
+    },
+}
+
+impl FeeCollector {
+    pub fn allocation(&self) -> Decimal {
+        match self {
+            FeeCollector::Address { allocation, .. } | FeeCollector::Burn { allocation } => {
+                *allocation
+            }
+        }
+    }
+
+    pub fn with_allocation(&self, allocation: Decimal) -> FeeCollector {
+        match self {
+            FeeCollector::Address { address, .. } => FeeCollector::Address {
+                address: address.clone(),
+                allocation,
+            },
+            FeeCollector::Burn { .. } => FeeCollector::Burn { allocation },
+        }
+    }
+
+    pub fn is_community_pool(&self) -> bool {
+        matches!(self, FeeCollector::Address { address, .. } if address == "community_pool")
+    }
 }
@@ -0,0 +1,8 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin};
+
+#[cw_serde]
+pub struct FeeGrantConfig {
+    pub partners: Vec<Addr>,
+    pub spend_limit: Coin,
+}
@@ -0,0 +1,95 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Decimal256, StdResult, Timestamp, Uint128, Uint256};
+
+#[cw_serde]
+pub enum MatchingFormula {
+    Linear,
+    Quadratic,
+}
+
+impl MatchingFormula {
+    pub fn matching_weight(&self, contributions: &[Uint128]) -> StdResult<Decimal256> {
+        match self {
+            MatchingFormula::Linear => Ok(Decimal256::from_ratio(
+                contributions.iter().fold(Uint256::zero(), |total, amount| {
+                    total + Uint256::from(*amount)
+                }),
+                Uint256::one(),
+            )),
+            MatchingFormula::Quadratic => {
+                let sum_of_roots = contributions
+                    .iter()
+                    .map(|amount| Decimal256::from_ratio(*amount, Uint256::one()).sqrt())
+                    .fold(Decimal256::zero(), |total, root| total + root);
+
+                let sum_of_contributions = contributions
+                    .iter()
+                    .map(|amount| Decimal256::from_ratio(*amount, Uint256::one()))
+                    .fold(Decimal256::zero(), |total, amount| total + amount);
+
+                Ok(sum_of_roots
+                    .checked_pow(2)?
+                    .saturating_sub(sum_of_contributions))
+            }
+        }
+    }
+}
+
+#[cw_serde]
+pub struct FundingRound {
+    pub id: u64,
+    pub sponsor: Addr,
+    pub matching_pool: Coin,
+    pub formula: MatchingFormula,
+    pub bounty_ids: Vec<Uint128>,
+    pub created_at: Timestamp,
+    pub ends_at: Timestamp,
+    pub finalized_at: Option<Timestamp>,
+}
+
+impl FundingRound {
+    pub fn is_finalized(&self) -> bool {
+        self.finalized_at.is_some()
+    }
+
+    pub fn has_ended(&self, current_time: Timestamp) -> bool {
+        current_time >= self.ends_at
+    }
+}
+
+#[cw_serde]
+pub struct RoundContribution {
+    pub contributor: Addr,
+    pub amount: Uint128,
+}
+
+#[cfg(test)]
+mod matching_weight_tests {
+    use super::*;
+
+    #[test]
+    fn linear_weight_is_sum_of_contributions() {
+        assert_eq!(
+            MatchingFormula::Linear
+                .matching_weight(&[Uint128::new(4), Uint128::new(9)])
+                .unwrap(),
+            Decimal256::from_ratio(13u128, 1u128)
+        );
+    }
+
+    #[test]
+    fn quadratic_weight_favours_many_small_contributions() {
+        assert_eq!(
+            MatchingFormula::Quadratic
+                .matching_weight(&[Uint128::new(4), Uint128::new(9)])
+                .unwrap(),
+            Decimal256::from_ratio(12u128, 1u128)
+        );
+        assert_eq!(
+            MatchingFormula::Quadratic
+                .matching_weight(&[Uint128::new(13)])
+                .unwrap(),
+            Decimal256::zero()
+        );
+    }
+}
@@ -1,12 +1,42 @@
+pub mod admin_action;
+pub mod application;
+pub mod assignee;
+pub mod authz;
+pub mod bounty_projection;
+pub mod bounty_snapshot;
+pub mod callback_result;
+pub mod cancel_reason;
+pub mod checklist;
 pub mod config;
+pub mod conversion_report;
 pub mod dca_plus_config;
+pub mod denom;
+pub mod denom_balance;
 pub mod destination;
+pub mod disbursement_schedule;
+pub mod dispute;
+pub mod escrow_epoch;
+pub mod escrow_tranche;
 pub mod event;
+pub mod event_summary;
+pub mod exchange;
+pub mod execution_receipt;
+pub mod executor_stats;
 pub mod fee_collector;
+pub mod fee_grant_config;
+pub mod funding_round;
 pub mod performance_assessment_strategy;
 pub mod position_type;
+pub mod progress_update;
+pub mod queued_refund;
+pub mod role;
+pub mod rounding_mode;
+pub mod scheduled_config_update;
+pub mod scheduled_payment;
 pub mod swap_adjustment_strategy;
+pub mod template;
 pub mod time_interval;
 pub mod trigger;
+pub mod updatable_field;
 pub mod update;
 pub mod vault;
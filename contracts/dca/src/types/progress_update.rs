@@ -0,0 +1,11 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp};
+
+#[cw_serde]
+pub struct ProgressUpdate {
+    pub id: u64,
+    pub assignee: Addr,
+    pub message_hash: [u8; 32],
+    pub uri: Option<String>,
+    pub posted_at: Timestamp,
+}
@@ -0,0 +1,11 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
+
+#[cw_serde]
+pub struct QueuedRefund {
+    pub id: u64,
+    pub bounty_id: Uint128,
+    pub recipient: Addr,
+    pub amount: Coin,
+    pub queued_at: Timestamp,
+}
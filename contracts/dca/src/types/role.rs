@@ -0,0 +1,9 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum Role {
+    Admin,
+    FeeManager,
+    Pauser,
+    Executor,
+}
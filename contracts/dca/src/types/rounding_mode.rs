@@ -0,0 +1,14 @@
+use cosmwasm_schema::cw_serde;
+
+/// How the remainder of a fractional fee amount is resolved. Allocation
+/// splits between destinations always pay out the whole amount, handing any
+/// remainder to the destinations with the largest fractional shares.
+#[cw_serde]
+pub enum RoundingMode {
+    /// Round the protocol's share down, leaving the remainder with the user.
+    FloorToProtocol,
+    /// Round the user's share down, leaving the remainder with the protocol.
+    FloorToUser,
+    /// Round the protocol's share half to even.
+    Bankers,
+}
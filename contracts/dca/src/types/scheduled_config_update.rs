@@ -0,0 +1,111 @@
+use super::{
+    config::Config, fee_collector::FeeCollector, fee_grant_config::FeeGrantConfig,
+    rounding_mode::RoundingMode,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128};
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ConfigChanges {
+    pub fee_collectors: Option<Vec<FeeCollector>>,
+    pub automation_fee_percent: Option<Decimal>,
+    pub default_page_limit: Option<u16>,
+    pub paused: Option<bool>,
+    pub risk_weighted_average_escrow_level: Option<Decimal>,
+    pub default_slippage_tolerance: Option<Decimal>,
+    pub exchange_contract_address: Option<Addr>,
+    /// Omit to keep the current arbiter, or pass `null` to remove it.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::util::deserialize_clearable"
+    )]
+    pub arbiter: Option<Option<Addr>>,
+    pub fee_grant: Option<FeeGrantConfig>,
+    pub referral_fee_percent: Option<Decimal>,
+    pub executor_reward_percent: Option<Decimal>,
+    pub minimum_deposit: Option<Vec<Coin>>,
+    pub minimum_swap_amount: Option<Uint128>,
+    pub event_retention_blocks: Option<u64>,
+    pub max_bounty_balance: Option<Vec<Coin>>,
+    pub max_total_balance_per_owner: Option<Vec<Coin>>,
+    pub exchange_adapters: Option<Vec<Addr>>,
+    pub auto_route_safety_margin: Option<Decimal>,
+    pub price_oracle_address: Option<Addr>,
+    pub rounding_mode: Option<RoundingMode>,
+    pub address_prefix: Option<String>,
+    pub slippage_retry_interval_seconds: Option<u64>,
+    pub max_slippage_retries: Option<u8>,
+}
+
+impl ConfigChanges {
+    pub fn apply_to(&self, config: Config) -> Config {
+        let changes = self.clone();
+
+        Config {
+            fee_collectors: changes.fee_collectors.unwrap_or(config.fee_collectors),
+            automation_fee_percent: changes
+                .automation_fee_percent
+                .unwrap_or(config.automation_fee_percent),
+            default_page_limit: changes
+                .default_page_limit
+                .unwrap_or(config.default_page_limit),
+            paused: changes.paused.unwrap_or(config.paused),
+            risk_weighted_average_escrow_level: changes
+                .risk_weighted_average_escrow_level
+                .unwrap_or(config.risk_weighted_average_escrow_level),
+            default_slippage_tolerance: changes
+                .default_slippage_tolerance
+                .unwrap_or(config.default_slippage_tolerance),
+            exchange_contract_address: changes
+                .exchange_contract_address
+                .unwrap_or(config.exchange_contract_address),
+            arbiter: changes.arbiter.unwrap_or(config.arbiter),
+            fee_grant: changes.fee_grant.or(config.fee_grant),
+            referral_fee_percent: changes
+                .referral_fee_percent
+                .unwrap_or(config.referral_fee_percent),
+            executor_reward_percent: changes
+                .executor_reward_percent
+                .unwrap_or(config.executor_reward_percent),
+            minimum_deposit: changes.minimum_deposit.unwrap_or(config.minimum_deposit),
+            minimum_swap_amount: changes
+                .minimum_swap_amount
+                .unwrap_or(config.minimum_swap_amount),
+            event_retention_blocks: changes
+                .event_retention_blocks
+                .or(config.event_retention_blocks),
+            max_bounty_balance: changes
+                .max_bounty_balance
+                .unwrap_or(config.max_bounty_balance),
+            max_total_balance_per_owner: changes
+                .max_total_balance_per_owner
+                .unwrap_or(config.max_total_balance_per_owner),
+            exchange_adapters: changes
+                .exchange_adapters
+                .unwrap_or(config.exchange_adapters),
+            auto_route_safety_margin: changes
+                .auto_route_safety_margin
+                .unwrap_or(config.auto_route_safety_margin),
+            price_oracle_address: changes.price_oracle_address.or(config.price_oracle_address),
+            rounding_mode: changes.rounding_mode.unwrap_or(config.rounding_mode),
+            address_prefix: changes.address_prefix.or(config.address_prefix),
+            slippage_retry_interval_seconds: changes
+                .slippage_retry_interval_seconds
+                .unwrap_or(config.slippage_retry_interval_seconds),
+            max_slippage_retries: changes
+                .max_slippage_retries
+                .unwrap_or(config.max_slippage_retries),
+            ..config
+        }
+    }
+}
+
+#[cw_serde]
+pub struct ScheduledConfigUpdate {
+    pub id: u64,
+    pub scheduled_at: Timestamp,
+    pub activates_at: Timestamp,
+    pub changes: ConfigChanges,
+}
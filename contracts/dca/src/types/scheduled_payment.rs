@@ -0,0 +1,32 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BankMsg, Coin, Timestamp};
+
+#[cw_serde]
+pub struct ScheduledPayment {
+    pub id: u64,
+    pub owner: Addr,
+    pub to: Addr,
+    pub amount: Coin,
+    pub at: Timestamp,
+    pub created_at: Timestamp,
+}
+
+impl ScheduledPayment {
+    pub fn is_due(&self, current_time: Timestamp) -> bool {
+        current_time >= self.at
+    }
+
+    pub fn payment_msg(&self) -> BankMsg {
+        BankMsg::Send {
+            to_address: self.to.to_string(),
+            amount: vec![self.amount.clone()],
+        }
+    }
+
+    pub fn refund_msg(&self) -> BankMsg {
+        BankMsg::Send {
+            to_address: self.owner.to_string(),
+            amount: vec![self.amount.clone()],
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use super::{denom::Denom, destination::Destination, disbursement_schedule::DisbursementSchedule};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128};
+
+#[cw_serde]
+pub struct BountyTemplateParams {
+    pub bounty_description: Option<String>,
+    pub destinations: Vec<Destination>,
+    pub target_denom: Denom,
+    pub route: Option<Binary>,
+    pub slippage_tolerance: Option<Decimal>,
+    pub exact_receive_amount: Option<Uint128>,
+    pub disbursement_schedule: Option<DisbursementSchedule>,
+    pub private_metadata: bool,
+    pub executor_slippage_override_cap: Option<Decimal>,
+    pub fallback_routes: Vec<Binary>,
+    pub tags: Vec<String>,
+    pub metadata_uri: Option<String>,
+    pub minimum_price: Option<Decimal>,
+    pub maximum_price: Option<Decimal>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct BountyTemplateOverrides {
+    pub label: Option<String>,
+    pub bounty_description: Option<String>,
+    pub destinations: Option<Vec<Destination>>,
+    pub slippage_tolerance: Option<Decimal>,
+    pub exact_receive_amount: Option<Uint128>,
+    pub expires_at: Option<Timestamp>,
+    pub referral_code: Option<String>,
+}
+
+#[cw_serde]
+pub struct BountyTemplate {
+    pub id: u64,
+    pub owner: Addr,
+    pub label: String,
+    pub params: BountyTemplateParams,
+}
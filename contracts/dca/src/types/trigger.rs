@@ -1,29 +1,48 @@
+use super::{destination::Destination, vault::BountyStatus};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Decimal, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128};
+
+#[cw_serde]
+pub struct BalanceThreshold {
+    pub account: Addr,
+    pub denom: String,
+    pub threshold: Uint128,
+}
 
 #[cw_serde]
 pub enum TriggerConfiguration {
     Time {
         target_time: Timestamp,
     },
+    BlockHeight {
+        target_height: u64,
+    },
+    BalanceAbove {
+        account: Addr,
+        denom: String,
+        threshold: Uint128,
+        // Set once the trigger fires, cleared when the watched balance drops back to the threshold
+        #[serde(default)]
+        fired: bool,
+    },
     EscrowReject {
         target_time: Timestamp,
         bounty_id: Uint128,
         label: Option<String>,
         bounty_description: Option<String>,
         status: Option<BountyStatus>, // Status should be updated to Rejected/Canceled
-        mut destinations: Vec<Destination>, // Can we make this a clone of owner so that it just sends the target denom and funds to the bounty issuer (e.g. owner)
+        destinations: Vec<Destination>, // Can we make this a clone of owner so that it just sends the target denom and funds to the bounty issuer (e.g. owner)
         target_denom: String,
         route: Option<Binary>,
-        slippage_tolerance: Option<Decimal>,          
+        slippage_tolerance: Option<Decimal>,
     },
     EscrowAccept {
         target_time: Timestamp,
-        bounty_id: Uint128, 
+        bounty_id: Uint128,
         label: Option<String>,
         bounty_description: Option<String>,
         status: Option<BountyStatus>, // Status should be updated to Completed/Finished
-        mut destinations: Vec<Destination>, // Can we make this a Bounty Assignee addr so that it just sends the target denom and funds to the bounty assignee? 
+        destinations: Vec<Destination>, // Can we make this a Bounty Assignee addr so that it just sends the target denom and funds to the bounty assignee? 
         target_denom: String,
         route: Option<Binary>,
         slippage_tolerance: Option<Decimal>,
@@ -34,4 +53,6 @@ pub enum TriggerConfiguration {
 pub struct Trigger {
     pub bounty_id: Uint128,
     pub configuration: TriggerConfiguration,
+    #[serde(default)]
+    pub retry_count: u8,
 }
@@ -0,0 +1,18 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub enum UpdatableField {
+    Label,
+    Description,
+    Tags,
+    MetadataUri,
+    Destinations,
+    SlippageTolerance,
+    MinimumReceiveAmount,
+    SwapAmount,
+    TimeInterval,
+    SwapAdjustmentStrategy,
+    TargetDenom,
+    Route,
+    EscrowLevel,
+}
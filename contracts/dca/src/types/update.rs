@@ -1,4 +1,9 @@
+use super::{
+    denom::Denom, destination::Destination, swap_adjustment_strategy::SwapAdjustmentStrategy,
+    time_interval::TimeInterval, trigger::TriggerConfiguration,
+};
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, Decimal, Uint128};
 
 #[cw_serde]
 pub struct Update {
@@ -6,3 +11,82 @@ pub struct Update {
     pub old_value: String,
     pub new_value: String,
 }
+
+#[cw_serde]
+pub enum FieldUpdate {
+    Label {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    Description {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    Tags {
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+    MetadataUri {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    MetadataHash {
+        old: Option<[u8; 32]>,
+        new: Option<[u8; 32]>,
+    },
+    Destinations {
+        old: Vec<Destination>,
+        new: Vec<Destination>,
+    },
+    SlippageTolerance {
+        old: Decimal,
+        new: Decimal,
+    },
+    MinimumReceiveAmount {
+        old: Option<Uint128>,
+        new: Option<Uint128>,
+    },
+    SwapAmount {
+        old: Uint128,
+        new: Uint128,
+    },
+    TimeInterval {
+        old: TimeInterval,
+        new: TimeInterval,
+    },
+    Trigger {
+        old: TriggerConfiguration,
+        new: TriggerConfiguration,
+    },
+    SwapAdjustmentStrategy {
+        old: Option<SwapAdjustmentStrategy>,
+        new: Option<SwapAdjustmentStrategy>,
+    },
+    TargetDenom {
+        old: Denom,
+        new: Denom,
+    },
+    Route {
+        old: Option<Binary>,
+        new: Option<Binary>,
+    },
+    EscrowLevel {
+        old: Decimal,
+        new: Decimal,
+    },
+    Legacy {
+        field: String,
+        old_value: String,
+        new_value: String,
+    },
+}
+
+impl From<Update> for FieldUpdate {
+    fn from(update: Update) -> Self {
+        FieldUpdate::Legacy {
+            field: update.field,
+            old_value: update.old_value,
+            new_value: update.new_value,
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use super::{
+    cancel_reason::CancelReason,
+    denom::Denom,
     destination::Destination,
+    disbursement_schedule::DisbursementSchedule,
      time_interval::TimeInterval,
     trigger::TriggerConfiguration,
 };
@@ -15,7 +18,10 @@ pub enum BountyStatus {
     Scheduled,
     Active,
     Inactive,
+    Paused,
     Cancelled,
+    Completed,
+    Rejected,
 }
 
 #[cw_serde]
@@ -28,7 +34,7 @@ pub struct Bounty {
     pub destinations: Vec<Destination>,
     pub status: VaultStatus,
     pub balance: Coin,
-    pub target_denom: String,
+    pub target_denom: Denom,
     pub route: Option<Binary>,
     pub slippage_tolerance: Decimal,
     pub minimum_receive_amount: Option<Uint128>,
@@ -37,18 +43,41 @@ pub struct Bounty {
     pub deposited_amount: Coin,
     pub received_amount: Coin,
     pub escrowed_amount: Coin,
+    pub exact_receive_amount: Option<Uint128>,
+    pub expires_at: Option<Timestamp>,
+    pub disbursement_schedule: Option<DisbursementSchedule>,
+    pub cancel_reason: Option<CancelReason>,
+    pub cancel_note: Option<String>,
+    pub private_metadata: bool,
+    pub referrer: Option<Addr>,
+    pub executor_slippage_override_cap: Option<Decimal>,
+    pub fallback_routes: Vec<Binary>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub metadata_uri: Option<String>,
+    pub minimum_price: Option<Decimal>,
+    pub maximum_price: Option<Decimal>,
+    pub escrow_available_after: Option<Timestamp>,
+    pub metadata_hash: Option<[u8; 32]>,
+    pub min_adjustment: Option<Decimal>,
+    pub max_adjustment: Option<Decimal>,
+    pub lock_period: Option<u64>,
     pub trigger: Option<TriggerConfiguration>
 }
 
 impl Bounty {
     pub fn denoms(&self) -> [String; 2] {
-        [self.get_swap_denom(), self.target_denom.clone()]
+        [self.get_swap_denom(), self.target_denom.to_string()]
     }
 
     pub fn get_swap_denom(&self) -> String {
         self.balance.denom.clone()
     }
 
+    pub fn is_direct_payout(&self) -> bool {
+        self.get_swap_denom() == self.target_denom.to_string()
+    }
+
     pub fn get_expected_execution_completed_date(&self, current_time: Timestamp) -> Timestamp {
         let remaining_balance = match self.performance_assessment_strategy.clone() {
             Some(PerformanceAssessmentStrategy::CompareToStandardDca {
@@ -78,6 +107,16 @@ impl Bounty {
     }
 
     pub fn price_threshold_exceeded(&self, belief_price: Decimal) -> StdResult<bool> {
+        if self
+            .maximum_price
+            .is_some_and(|maximum_price| belief_price > maximum_price)
+            || self
+                .minimum_price
+                .is_some_and(|minimum_price| belief_price < minimum_price)
+        {
+            return Ok(true);
+        }
+
         self.minimum_receive_amount
             .map_or(Ok(false), |minimum_receive_amount| {
                 let swap_amount_as_decimal =
@@ -94,6 +133,15 @@ impl Bounty {
             })
     }
 
+    pub fn clamp_swap_adjustment(&self, swap_adjustment: Decimal) -> Decimal {
+        let swap_adjustment = self
+            .min_adjustment
+            .map_or(swap_adjustment, |min_adjustment| swap_adjustment.max(min_adjustment));
+
+        self.max_adjustment
+            .map_or(swap_adjustment, |max_adjustment| swap_adjustment.min(max_adjustment))
+    }
+
     pub fn is_active(&self) -> bool {
         self.status == BountyStatus::Active
     }
@@ -106,8 +154,16 @@ impl Bounty {
         self.status == BountyStatus::Inactive
     }
 
+    pub fn is_paused(&self) -> bool {
+        self.status == BountyStatus::Paused
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.status == BountyStatus::Completed
+    }
+
     pub fn should_not_continue(&self) -> bool {
-        self.is_inactive()
+        (self.is_inactive() || self.is_completed())
             && self.performance_assessment_strategy.clone().map_or(
                 true,
                 |performance_assessment_strategy| {
@@ -119,6 +175,42 @@ impl Bounty {
     pub fn is_cancelled(&self) -> bool {
         self.status == VaultStatus::Cancelled
     }
+
+    pub fn is_rejected(&self) -> bool {
+        self.status == BountyStatus::Rejected
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        self.is_completed() || self.is_cancelled() || self.is_rejected()
+    }
+
+    pub fn is_expired(&self, current_time: Timestamp) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| current_time >= expires_at)
+    }
+
+    pub fn locked_until(&self) -> Option<Timestamp> {
+        self.lock_period
+            .map(|lock_period| self.created_at.plus_seconds(lock_period))
+    }
+
+    pub fn is_locked(&self, current_time: Timestamp) -> bool {
+        self.locked_until()
+            .is_some_and(|locked_until| current_time < locked_until)
+    }
+
+    pub fn without_private_metadata(self) -> Self {
+        if self.private_metadata {
+            Bounty {
+                label: None,
+                description: None,
+                metadata_uri: None,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
 }
 
 pub struct BountyBuilder {
@@ -130,7 +222,7 @@ pub struct BountyBuilder {
     pub destinations: Vec<Destination>,
     pub status: VaultStatus,
     pub balance: Coin,
-    pub target_denom: String,
+    pub target_denom: Denom,
     pub route: Option<Binary>,
     pub slippage_tolerance: Decimal,
     pub minimum_receive_amount: Option<Uint128>,
@@ -139,6 +231,25 @@ pub struct BountyBuilder {
     pub deposited_amount: Coin,
     pub received_amount: Coin,
     pub escrowed_amount: Coin,
+    pub exact_receive_amount: Option<Uint128>,
+    pub expires_at: Option<Timestamp>,
+    pub disbursement_schedule: Option<DisbursementSchedule>,
+    pub cancel_reason: Option<CancelReason>,
+    pub cancel_note: Option<String>,
+    pub private_metadata: bool,
+    pub referrer: Option<Addr>,
+    pub executor_slippage_override_cap: Option<Decimal>,
+    pub fallback_routes: Vec<Binary>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub metadata_uri: Option<String>,
+    pub minimum_price: Option<Decimal>,
+    pub maximum_price: Option<Decimal>,
+    pub escrow_available_after: Option<Timestamp>,
+    pub metadata_hash: Option<[u8; 32]>,
+    pub min_adjustment: Option<Decimal>,
+    pub max_adjustment: Option<Decimal>,
+    pub lock_period: Option<u64>,
     pub trigger: Option<TriggerConfiguration>
 }
 
@@ -152,7 +263,7 @@ impl BountyBuilder {
     destinations: Vec<Destination>,
     status: BountyStatus,
     balance: Coin,
-    target_denom: String,
+    target_denom: Denom,
     route: Option<Binary>,
     slippage_tolerance: Decimal,
     minimum_receive_amount: Option<Uint128>,
@@ -161,6 +272,25 @@ impl BountyBuilder {
     deposited_amount: Coin,
     received_amount: Coin,
     escrowed_amount: Coin,
+    exact_receive_amount: Option<Uint128>,
+    expires_at: Option<Timestamp>,
+    disbursement_schedule: Option<DisbursementSchedule>,
+    cancel_reason: Option<CancelReason>,
+    cancel_note: Option<String>,
+    private_metadata: bool,
+    referrer: Option<Addr>,
+    executor_slippage_override_cap: Option<Decimal>,
+    fallback_routes: Vec<Binary>,
+    description: Option<String>,
+    tags: Vec<String>,
+    metadata_uri: Option<String>,
+    minimum_price: Option<Decimal>,
+    maximum_price: Option<Decimal>,
+    escrow_available_after: Option<Timestamp>,
+    metadata_hash: Option<[u8; 32]>,
+    min_adjustment: Option<Decimal>,
+    max_adjustment: Option<Decimal>,
+    lock_period: Option<u64>,
     trigger: Option<TriggerConfiguration>
     ) -> BountyBuilder {
         BountyBuilder {
@@ -181,6 +311,25 @@ impl BountyBuilder {
             deposited_amount,
             received_amount,
             escrowed_amount,
+            exact_receive_amount,
+            expires_at,
+            disbursement_schedule,
+            cancel_reason,
+            cancel_note,
+            private_metadata,
+            referrer,
+            executor_slippage_override_cap,
+            fallback_routes,
+            description,
+            tags,
+            metadata_uri,
+            minimum_price,
+            maximum_price,
+            escrow_available_after,
+            metadata_hash,
+            min_adjustment,
+            max_adjustment,
+            lock_period,
         }
     }
 
@@ -203,6 +352,25 @@ impl BountyBuilder {
             deposited_amount: self.deposited_amount,
             received_amount: self.received_amount,
             escrowed_amount: self.escrowed_amount,
+            exact_receive_amount: self.exact_receive_amount,
+            expires_at: self.expires_at,
+            disbursement_schedule: self.disbursement_schedule,
+            cancel_reason: self.cancel_reason,
+            cancel_note: self.cancel_note,
+            private_metadata: self.private_metadata,
+            referrer: self.referrer,
+            executor_slippage_override_cap: self.executor_slippage_override_cap,
+            fallback_routes: self.fallback_routes,
+            description: self.description,
+            tags: self.tags,
+            metadata_uri: self.metadata_uri,
+            minimum_price: self.minimum_price,
+            maximum_price: self.maximum_price,
+            escrow_available_after: self.escrow_available_after,
+            metadata_hash: self.metadata_hash,
+            min_adjustment: self.min_adjustment,
+            max_adjustment: self.max_adjustment,
+            lock_period: self.lock_period,
             trigger: None,
         }
     }
@@ -237,6 +405,16 @@ mod should_not_continue_tests {
         assert!(vault.should_not_continue());
     }
 
+    #[test]
+    fn when_regular_vault_is_completed_is_true() {
+        let vault = Vault {
+            status: VaultStatus::Completed,
+            ..Default::default()
+        };
+
+        assert!(vault.should_not_continue());
+    }
+
     #[test]
     fn when_dca_vault_is_active_is_false() {
         let vault = Vault {
@@ -409,4 +587,114 @@ mod price_threshold_exceeded_tests {
             Ok(true)
         );
     }
+
+    #[test]
+    fn should_be_exceeded_when_price_is_above_maximum_price() {
+        let vault = Vault {
+            maximum_price: Some(Decimal::from_str("2.0").unwrap()),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault.price_threshold_exceeded(Decimal::from_str("2.1").unwrap()),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn should_be_exceeded_when_price_is_below_minimum_price() {
+        let vault = Vault {
+            minimum_price: Some(Decimal::from_str("2.0").unwrap()),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault.price_threshold_exceeded(Decimal::from_str("1.9").unwrap()),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn should_not_be_exceeded_when_price_is_within_bounds() {
+        let vault = Vault {
+            minimum_price: Some(Decimal::from_str("1.0").unwrap()),
+            maximum_price: Some(Decimal::from_str("2.0").unwrap()),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault.price_threshold_exceeded(Decimal::from_str("1.5").unwrap()),
+            Ok(false)
+        );
+    }
+}
+
+#[cfg(test)]
+mod is_direct_payout_tests {
+    use super::*;
+    use crate::tests::mocks::{DENOM_UKUJI, DENOM_UUSK};
+
+    #[test]
+    fn is_direct_payout_when_target_denom_matches_swap_denom() {
+        let vault = Vault {
+            balance: Coin::new(100, DENOM_UKUJI),
+            target_denom: Denom::new(DENOM_UKUJI).unwrap(),
+            ..Vault::default()
+        };
+
+        assert!(vault.is_direct_payout());
+    }
+
+    #[test]
+    fn is_not_direct_payout_when_target_denom_differs_from_swap_denom() {
+        let vault = Vault {
+            balance: Coin::new(100, DENOM_UKUJI),
+            target_denom: Denom::new(DENOM_UUSK).unwrap(),
+            ..Vault::default()
+        };
+
+        assert!(!vault.is_direct_payout());
+    }
+}
+
+#[cfg(test)]
+mod clamp_swap_adjustment_tests {
+    use super::*;
+
+    #[test]
+    fn leaves_adjustment_unchanged_without_bounds() {
+        let vault = Vault::default();
+
+        assert_eq!(
+            vault.clamp_swap_adjustment(Decimal::percent(1000)),
+            Decimal::percent(1000)
+        );
+    }
+
+    #[test]
+    fn raises_adjustment_to_min_adjustment() {
+        let vault = Vault {
+            min_adjustment: Some(Decimal::percent(50)),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault.clamp_swap_adjustment(Decimal::zero()),
+            Decimal::percent(50)
+        );
+    }
+
+    #[test]
+    fn lowers_adjustment_to_max_adjustment() {
+        let vault = Vault {
+            min_adjustment: Some(Decimal::percent(50)),
+            max_adjustment: Some(Decimal::percent(200)),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault.clamp_swap_adjustment(Decimal::percent(1000)),
+            Decimal::percent(200)
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Deserializer};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
@@ -9,3 +10,13 @@ where
     t.hash(&mut s);
     s.finish()
 }
+
+/// Deserializes a clearable field so that a missing value is `None` (leave
+/// unchanged) and an explicit `null` is `Some(None)` (unset).
+pub fn deserialize_clearable<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
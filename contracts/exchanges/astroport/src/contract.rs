@@ -1,11 +1,19 @@
 use crate::error::ContractError;
+use crate::handlers::check_orders::check_orders_handler;
+use crate::handlers::fill_order::{fill_order_handler, record_filled_order};
 use crate::handlers::get_expected_receive_amount::get_expected_receive_amount_handler;
+use crate::handlers::get_order::get_order_handler;
+use crate::handlers::get_orders::get_orders_handler;
 use crate::handlers::get_twap_to_now::get_twap_to_now_handler;
+use crate::handlers::retract_order::retract_order_handler;
+use crate::handlers::submit_order::submit_order_handler;
 use crate::handlers::swap::{return_swapped_funds, swap_handler};
+use crate::handlers::withdraw_order::withdraw_order_handler;
 use crate::msg::{ExecuteMsg, QueryMsg};
 use crate::msg::{InstantiateMsg, MigrateMsg};
 use crate::state::config::{get_config, update_config};
 use crate::types::config::Config;
+use crate::types::native_wrapper::NativeWrapper;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
@@ -22,12 +30,14 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     deps.api.addr_validate(msg.admin.as_str())?;
     deps.api.addr_validate(msg.router_address.as_str())?;
+    validate_native_wrappers(deps.as_ref(), &msg.native_wrappers)?;
 
     update_config(
         deps.storage,
         Config {
             admin: msg.admin,
             router_address: msg.router_address,
+            native_wrappers: msg.native_wrappers,
         },
     )?;
 
@@ -39,11 +49,13 @@ pub fn migrate(deps: DepsMut, _: Env, msg: MigrateMsg) -> Result<Response, Contr
     let config = get_config(deps.storage)?;
 
     deps.api.addr_validate(msg.router_address.as_str())?;
+    validate_native_wrappers(deps.as_ref(), &msg.native_wrappers)?;
 
     update_config(
         deps.storage,
         Config {
             router_address: msg.router_address,
+            native_wrappers: msg.native_wrappers,
             ..config
         },
     )?;
@@ -59,9 +71,20 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::SubmitOrder { .. } => not_implemented_handler(),
-        ExecuteMsg::RetractOrder { .. } => not_implemented_handler(),
-        ExecuteMsg::WithdrawOrder { .. } => not_implemented_handler(),
+        ExecuteMsg::SubmitOrder {
+            target_price,
+            target_denom,
+        } => submit_order_handler(deps, info, target_price, target_denom),
+        ExecuteMsg::RetractOrder { order_idx, denoms } => {
+            retract_order_handler(deps, info, order_idx, denoms)
+        }
+        ExecuteMsg::WithdrawOrder { order_idx, denoms } => {
+            withdraw_order_handler(deps, info, order_idx, denoms)
+        }
+        ExecuteMsg::CheckOrders { orders } => check_orders_handler(deps.as_ref(), env, orders),
+        ExecuteMsg::FillOrder { order_idx, route } => {
+            fill_order_handler(deps, env, info, order_idx, route)
+        }
         ExecuteMsg::Swap {
             minimum_receive_amount,
             route,
@@ -89,7 +112,12 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::GetOrder { .. } => to_json_binary(&not_implemented_query()?),
+        QueryMsg::GetOrder { order_idx, denoms } => {
+            to_json_binary(&get_order_handler(deps, order_idx, denoms)?)
+        }
+        QueryMsg::GetOrders { start_after, limit } => {
+            to_json_binary(&get_orders_handler(deps, start_after, limit))
+        }
         QueryMsg::GetPairs { .. } => to_json_binary(&not_implemented_query()?),
         QueryMsg::GetTwapToNow {
             swap_denom,
@@ -126,21 +154,35 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 pub const AFTER_SWAP: u64 = 1;
+pub const AFTER_FILL_ORDER: u64 = 2;
+pub const AFTER_FILL_ORDER_FAILED: u64 = 3;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, ContractError> {
     match reply.id {
         AFTER_SWAP => return_swapped_funds(deps.as_ref(), env),
+        AFTER_FILL_ORDER => record_filled_order(deps, env),
+        AFTER_FILL_ORDER_FAILED => Ok(Response::new()
+            .add_attribute("fill_order_failed", "true")
+            .add_attribute("error", reply.result.unwrap_err())),
         _ => Err(ContractError::MissingReplyId {}),
     }
 }
 
+fn validate_native_wrappers(
+    deps: Deps,
+    native_wrappers: &[NativeWrapper],
+) -> Result<(), ContractError> {
+    for native_wrapper in native_wrappers {
+        deps.api
+            .addr_validate(native_wrapper.wrapped_token.as_str())?;
+    }
+
+    Ok(())
+}
+
 pub fn not_implemented_query() -> StdResult<()> {
     Err(cosmwasm_std::StdError::GenericErr {
         msg: "not implemented".to_string(),
     })
 }
-
-pub fn not_implemented_handler() -> Result<Response, ContractError> {
-    Err(ContractError::Std(not_implemented_query().unwrap_err()))
-}
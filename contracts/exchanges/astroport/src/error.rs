@@ -18,6 +18,12 @@ pub enum ContractError {
     #[error("Must provide a non zero swap amount")]
     SwapAmount {},
 
+    #[error("Invalid funds: {msg:?}")]
+    InvalidFunds { msg: String },
+
+    #[error("Must provide a non zero target price")]
+    TargetPrice {},
+
     #[error("Receive amount was less than the minimum specified")]
     ReceiveAmount {},
 
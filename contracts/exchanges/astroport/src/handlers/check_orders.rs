@@ -0,0 +1,50 @@
+use cosmwasm_std::{to_json_binary, Deps, Env, Response, SubMsg, WasmMsg};
+
+use crate::{
+    contract::AFTER_FILL_ORDER_FAILED,
+    handlers::get_expected_receive_amount::get_expected_receive_amount_handler, msg::ExecuteMsg,
+    state::orders::get_order, types::limit_order::OrderRoute, ContractError,
+};
+
+pub fn check_orders_handler(
+    deps: Deps,
+    env: Env,
+    orders: Vec<OrderRoute>,
+) -> Result<Response, ContractError> {
+    let mut response = Response::new().add_attribute("check_orders", "true");
+
+    for OrderRoute { order_idx, route } in orders {
+        let order = match get_order(deps.storage, order_idx) {
+            Ok(order) if order.is_open() => order,
+            _ => continue,
+        };
+
+        let minimum_receive_amount = order.minimum_receive_amount()?;
+
+        let expected_receive_amount = get_expected_receive_amount_handler(
+            deps,
+            order.remaining_offer_amount.clone(),
+            order.target_denom.clone(),
+            route.clone(),
+        );
+
+        match expected_receive_amount {
+            Ok(expected_receive_amount)
+                if expected_receive_amount.amount >= minimum_receive_amount.amount => {}
+            _ => continue,
+        }
+
+        response = response
+            .add_attribute("fill_order", order_idx)
+            .add_submessage(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::FillOrder { order_idx, route })?,
+                    funds: vec![],
+                },
+                AFTER_FILL_ORDER_FAILED,
+            ));
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,157 @@
+use astroport::router::{ExecuteMsg, SwapOperation};
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, Coin, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128,
+};
+use shared::{
+    balance::query_balance,
+    coin::{add, empty_of, subtract},
+    cw20::into_execute_msg,
+};
+
+use crate::{
+    contract::AFTER_FILL_ORDER,
+    handlers::swap::{ask_asset_info, find_native_wrapper, offer_asset_info},
+    state::{
+        cache::{FillOrderCache, FILL_ORDER_CACHE},
+        config::get_config,
+        orders::{get_order, update_order},
+    },
+    types::limit_order::LimitOrder,
+    ContractError,
+};
+
+pub fn fill_order_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    order_idx: Uint128,
+    route: Binary,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let order = get_order(deps.storage, order_idx)?;
+
+    if !order.is_open() {
+        return Err(ContractError::SwapAmount {});
+    }
+
+    let config = get_config(deps.storage)?;
+
+    let swap_operations = from_json::<Vec<SwapOperation>>(route)?;
+
+    let offer_wrapper = find_native_wrapper(
+        &config.native_wrappers,
+        &order.remaining_offer_amount.denom,
+        swap_operations.first().and_then(offer_asset_info),
+    );
+
+    let ask_wrapper = find_native_wrapper(
+        &config.native_wrappers,
+        &order.target_denom,
+        swap_operations.last().and_then(ask_asset_info),
+    );
+
+    let received_denom = ask_wrapper
+        .as_ref()
+        .map_or(order.target_denom.clone(), |native_wrapper| {
+            native_wrapper.wrapped_denom()
+        });
+
+    let minimum_receive_amount = order.minimum_receive_amount()?;
+
+    FILL_ORDER_CACHE.save(
+        deps.storage,
+        &FillOrderCache {
+            order_idx,
+            minimum_receive_amount: minimum_receive_amount.clone(),
+            target_denom_balance: query_balance(
+                deps.api,
+                &deps.querier,
+                &received_denom,
+                &env.contract.address,
+            )?,
+            native_wrapper: ask_wrapper,
+        },
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("fill_order", "true")
+        .add_attribute("order_idx", order_idx)
+        .add_attribute("minimum_receive_amount", minimum_receive_amount.to_string());
+
+    let swap_amount = match offer_wrapper {
+        Some(native_wrapper) => {
+            response = response
+                .add_attribute("wrapped_denom", native_wrapper.wrapped_denom())
+                .add_message(native_wrapper.wrap_msg(order.remaining_offer_amount.amount)?);
+
+            Coin::new(
+                order.remaining_offer_amount.amount.into(),
+                native_wrapper.wrapped_denom(),
+            )
+        }
+        None => order.remaining_offer_amount,
+    };
+
+    Ok(response.add_submessage(SubMsg::reply_on_success(
+        into_execute_msg(
+            deps.api,
+            config.router_address,
+            to_json_binary(&ExecuteMsg::ExecuteSwapOperations {
+                operations: swap_operations,
+                minimum_receive: Some(minimum_receive_amount.amount),
+                to: None,
+                max_spread: None,
+            })?,
+            swap_amount,
+        )?,
+        AFTER_FILL_ORDER,
+    )))
+}
+
+pub fn record_filled_order(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let cache = FILL_ORDER_CACHE.load(deps.storage)?;
+
+    let updated_target_denom_balance = query_balance(
+        deps.api,
+        &deps.querier,
+        &cache.target_denom_balance.denom,
+        &env.contract.address,
+    )?;
+
+    let received_amount = subtract(&updated_target_denom_balance, &cache.target_denom_balance)?;
+
+    if received_amount.amount < cache.minimum_receive_amount.amount {
+        return Err(ContractError::ReceiveAmount {});
+    }
+
+    let mut response = Response::new();
+
+    let received_amount = match cache.native_wrapper {
+        Some(native_wrapper) => {
+            response = response
+                .add_attribute("unwrapped_denom", native_wrapper.wrapped_denom())
+                .add_message(native_wrapper.unwrap_msg(received_amount.amount)?);
+
+            Coin::new(received_amount.amount.into(), native_wrapper.native_denom)
+        }
+        None => received_amount,
+    };
+
+    let order = get_order(deps.storage, cache.order_idx)?;
+
+    update_order(
+        deps.storage,
+        &LimitOrder {
+            remaining_offer_amount: empty_of(order.remaining_offer_amount.clone()),
+            filled_amount: add(order.filled_amount.clone(), received_amount.clone())?,
+            ..order
+        },
+    )?;
+
+    Ok(response
+        .add_attribute("order_idx", cache.order_idx)
+        .add_attribute("filled_amount", received_amount.to_string()))
+}
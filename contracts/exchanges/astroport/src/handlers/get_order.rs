@@ -0,0 +1,20 @@
+use cosmwasm_std::{Deps, StdError, StdResult, Uint128};
+use exchange::msg::Order;
+
+use crate::state::orders::get_order;
+
+pub fn get_order_handler(deps: Deps, order_idx: Uint128, denoms: [String; 2]) -> StdResult<Order> {
+    let order = get_order(deps.storage, order_idx)?;
+
+    if !order.trades(denoms.clone()) {
+        return Err(StdError::generic_err(format!(
+            "order {} does not trade denoms {:?}",
+            order_idx, denoms
+        )));
+    }
+
+    Ok(Order {
+        order_idx,
+        remaining_offer_amount: order.remaining_offer_amount,
+    })
+}
@@ -0,0 +1,11 @@
+use cosmwasm_std::{Deps, Uint128};
+
+use crate::{state::orders::get_orders, types::limit_order::LimitOrder};
+
+pub fn get_orders_handler(
+    deps: Deps,
+    start_after: Option<Uint128>,
+    limit: Option<u16>,
+) -> Vec<LimitOrder> {
+    get_orders(deps.storage, start_after, limit)
+}
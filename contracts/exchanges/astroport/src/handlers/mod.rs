@@ -1,3 +1,10 @@
+pub mod check_orders;
+pub mod fill_order;
 pub mod get_expected_receive_amount;
+pub mod get_order;
+pub mod get_orders;
 pub mod get_twap_to_now;
+pub mod retract_order;
+pub mod submit_order;
 pub mod swap;
+pub mod withdraw_order;
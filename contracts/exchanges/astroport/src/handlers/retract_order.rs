@@ -0,0 +1,130 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
+use shared::{coin::empty_of, cw20::into_transfer_msg};
+
+use crate::{
+    state::orders::{delete_order, get_order, update_order},
+    types::limit_order::LimitOrder,
+    ContractError,
+};
+
+pub fn retract_order_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    order_idx: Uint128,
+    denoms: [String; 2],
+) -> Result<Response, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::InvalidFunds {
+            msg: "must not provide funds to retract order".to_string(),
+        });
+    }
+
+    let order = get_order(deps.storage, order_idx)?;
+
+    if info.sender != order.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !order.trades(denoms) {
+        return Err(ContractError::InvalidFunds {
+            msg: "denoms do not match order".to_string(),
+        });
+    }
+
+    let mut response = Response::new()
+        .add_attribute("retract_order", "true")
+        .add_attribute("order_idx", order_idx);
+
+    if order.is_open() {
+        response = response.add_message(into_transfer_msg(
+            deps.api,
+            order.owner.as_ref(),
+            order.remaining_offer_amount.clone(),
+        )?);
+    }
+
+    if order.filled_amount.amount.is_zero() {
+        delete_order(deps.storage, order_idx);
+    } else {
+        update_order(
+            deps.storage,
+            &LimitOrder {
+                remaining_offer_amount: empty_of(order.remaining_offer_amount.clone()),
+                ..order
+            },
+        )?;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod retract_order_tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_info},
+        Addr, BankMsg, Coin, CosmosMsg, Decimal256, SubMsg,
+    };
+
+    use crate::{
+        handlers::retract_order::retract_order_handler,
+        state::orders::{create_order, get_order},
+        tests::constants::{DCA_CONTRACT_ADDRESS, DENOM_UATOM, DENOM_UOSMO, USER},
+        ContractError,
+    };
+
+    #[test]
+    fn from_non_owner_fails() {
+        let mut deps = mock_dependencies();
+
+        let order = create_order(
+            deps.as_mut().storage,
+            Addr::unchecked(DCA_CONTRACT_ADDRESS),
+            Coin::new(2, DENOM_UATOM),
+            DENOM_UOSMO.to_string(),
+            Decimal256::one(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            retract_order_handler(
+                deps.as_mut(),
+                mock_info(USER, &[]),
+                order.order_idx,
+                order.denoms(),
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+    }
+
+    #[test]
+    fn returns_remaining_offer_amount_and_deletes_unfilled_order() {
+        let mut deps = mock_dependencies();
+
+        let order = create_order(
+            deps.as_mut().storage,
+            Addr::unchecked(DCA_CONTRACT_ADDRESS),
+            Coin::new(2, DENOM_UATOM),
+            DENOM_UOSMO.to_string(),
+            Decimal256::one(),
+        )
+        .unwrap();
+
+        let response = retract_order_handler(
+            deps.as_mut(),
+            mock_info(DCA_CONTRACT_ADDRESS, &[]),
+            order.order_idx,
+            order.denoms(),
+        )
+        .unwrap();
+
+        assert!(response
+            .messages
+            .contains(&SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: DCA_CONTRACT_ADDRESS.to_string(),
+                amount: vec![Coin::new(2, DENOM_UATOM)],
+            }))));
+
+        assert!(get_order(deps.as_ref().storage, order.order_idx).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use cosmwasm_std::{Decimal256, DepsMut, MessageInfo, Response};
+
+use crate::{state::orders::create_order, ContractError};
+
+pub fn submit_order_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    target_price: Decimal256,
+    target_denom: String,
+) -> Result<Response, ContractError> {
+    if info.funds.len() != 1 {
+        return Err(ContractError::AssetCardinality {});
+    }
+
+    let offer_amount = info.funds[0].clone();
+
+    if offer_amount.amount.is_zero() {
+        return Err(ContractError::SwapAmount {});
+    }
+
+    if offer_amount.denom == target_denom {
+        return Err(ContractError::InvalidFunds {
+            msg: String::from("swap denom and target denom must be different"),
+        });
+    }
+
+    if target_price.is_zero() {
+        return Err(ContractError::TargetPrice {});
+    }
+
+    let order = create_order(
+        deps.storage,
+        info.sender,
+        offer_amount,
+        target_denom,
+        target_price,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("submit_order", "true")
+        .add_attribute("order_idx", order.order_idx)
+        .add_attribute("target_price", target_price.to_string()))
+}
+
+#[cfg(test)]
+mod submit_order_tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_info},
+        Coin, Decimal256, Uint128,
+    };
+
+    use crate::{
+        handlers::submit_order::submit_order_handler,
+        state::orders::get_order,
+        tests::constants::{DCA_CONTRACT_ADDRESS, DENOM_UATOM, DENOM_UOSMO},
+        ContractError,
+    };
+
+    #[test]
+    fn with_no_assets_fails() {
+        assert_eq!(
+            submit_order_handler(
+                mock_dependencies().as_mut(),
+                mock_info(DCA_CONTRACT_ADDRESS, &[]),
+                Decimal256::one(),
+                DENOM_UOSMO.to_string(),
+            )
+            .unwrap_err(),
+            ContractError::AssetCardinality {}
+        )
+    }
+
+    #[test]
+    fn with_same_denoms_fails() {
+        assert_eq!(
+            submit_order_handler(
+                mock_dependencies().as_mut(),
+                mock_info(DCA_CONTRACT_ADDRESS, &[Coin::new(2, DENOM_UOSMO)]),
+                Decimal256::one(),
+                DENOM_UOSMO.to_string(),
+            )
+            .unwrap_err(),
+            ContractError::InvalidFunds {
+                msg: String::from("swap denom and target denom must be different")
+            }
+        )
+    }
+
+    #[test]
+    fn with_zero_target_price_fails() {
+        assert_eq!(
+            submit_order_handler(
+                mock_dependencies().as_mut(),
+                mock_info(DCA_CONTRACT_ADDRESS, &[Coin::new(2, DENOM_UATOM)]),
+                Decimal256::zero(),
+                DENOM_UOSMO.to_string(),
+            )
+            .unwrap_err(),
+            ContractError::TargetPrice {}
+        )
+    }
+
+    #[test]
+    fn saves_order_and_returns_order_idx() {
+        let mut deps = mock_dependencies();
+
+        let response = submit_order_handler(
+            deps.as_mut(),
+            mock_info(DCA_CONTRACT_ADDRESS, &[Coin::new(2, DENOM_UATOM)]),
+            Decimal256::percent(50),
+            DENOM_UOSMO.to_string(),
+        )
+        .unwrap();
+
+        assert!(response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "order_idx" && attribute.value == "1"));
+
+        let order = get_order(deps.as_ref().storage, Uint128::one()).unwrap();
+
+        assert_eq!(order.owner, DCA_CONTRACT_ADDRESS);
+        assert_eq!(order.remaining_offer_amount, Coin::new(2, DENOM_UATOM));
+        assert_eq!(order.filled_amount, Coin::new(0, DENOM_UOSMO));
+        assert_eq!(
+            order.minimum_receive_amount().unwrap(),
+            Coin::new(4, DENOM_UOSMO)
+        );
+    }
+}
@@ -1,10 +1,14 @@
-use astroport::router::ExecuteMsg;
+use astroport::{
+    asset::AssetInfo,
+    router::{ExecuteMsg, SwapOperation},
+};
 use cosmwasm_std::{
     from_json, to_json_binary, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Response, SubMsg,
 };
 use shared::{
+    balance::query_balance,
     coin::subtract,
-    cw20::{into_bank_msg, into_execute_msg},
+    cw20::{into_execute_msg, into_transfer_msg},
 };
 
 use crate::{
@@ -13,9 +17,48 @@ use crate::{
         cache::{SwapCache, SWAP_CACHE},
         config::get_config,
     },
+    types::native_wrapper::NativeWrapper,
     ContractError,
 };
 
+fn is_wrapped_token(asset_info: &AssetInfo, native_wrapper: &NativeWrapper) -> bool {
+    match asset_info {
+        AssetInfo::Token { contract_addr } => *contract_addr == native_wrapper.wrapped_token,
+        _ => false,
+    }
+}
+
+pub fn find_native_wrapper(
+    native_wrappers: &[NativeWrapper],
+    denom: &str,
+    asset_info: Option<&AssetInfo>,
+) -> Option<NativeWrapper> {
+    asset_info.and_then(|asset_info| {
+        native_wrappers
+            .iter()
+            .find(|native_wrapper| {
+                native_wrapper.native_denom == denom && is_wrapped_token(asset_info, native_wrapper)
+            })
+            .cloned()
+    })
+}
+
+pub fn offer_asset_info(operation: &SwapOperation) -> Option<&AssetInfo> {
+    match operation {
+        SwapOperation::AstroSwap {
+            offer_asset_info, ..
+        } => Some(offer_asset_info),
+        _ => None,
+    }
+}
+
+pub fn ask_asset_info(operation: &SwapOperation) -> Option<&AssetInfo> {
+    match operation {
+        SwapOperation::AstroSwap { ask_asset_info, .. } => Some(ask_asset_info),
+        _ => None,
+    }
+}
+
 pub fn swap_handler(
     deps: DepsMut,
     env: Env,
@@ -35,47 +78,82 @@ pub fn swap_handler(
 
     let config = get_config(deps.storage)?;
 
-    let swap_operations = from_json(route)?;
+    let swap_operations = from_json::<Vec<SwapOperation>>(route)?;
+
+    let offer_wrapper = find_native_wrapper(
+        &config.native_wrappers,
+        &swap_amount.denom,
+        swap_operations.first().and_then(offer_asset_info),
+    );
+
+    let ask_wrapper = find_native_wrapper(
+        &config.native_wrappers,
+        &minimum_receive_amount.denom,
+        swap_operations.last().and_then(ask_asset_info),
+    );
+
+    let received_denom = ask_wrapper
+        .as_ref()
+        .map_or(minimum_receive_amount.denom.clone(), |native_wrapper| {
+            native_wrapper.wrapped_denom()
+        });
 
     SWAP_CACHE.save(
         deps.storage,
         &SwapCache {
             sender: info.sender.clone(),
             minimum_receive_amount: minimum_receive_amount.clone(),
-            target_denom_balance: deps.querier.query_balance(
-                env.contract.address.clone(),
-                minimum_receive_amount.denom.clone(),
+            target_denom_balance: query_balance(
+                deps.api,
+                &deps.querier,
+                &received_denom,
+                &env.contract.address,
             )?,
+            native_wrapper: ask_wrapper,
         },
     )?;
 
-    Ok(Response::new()
+    let mut response = Response::new()
         .add_attribute("swap", "true")
         .add_attribute("sender", info.sender)
         .add_attribute("swap_amount", swap_amount.to_string())
-        .add_attribute("minimum_receive_amount", minimum_receive_amount.to_string())
-        .add_submessage(SubMsg::reply_on_success(
-            into_execute_msg(
-                deps.api,
-                config.router_address,
-                to_json_binary(&ExecuteMsg::ExecuteSwapOperations {
-                    operations: swap_operations,
-                    minimum_receive: Some(minimum_receive_amount.amount),
-                    to: None,
-                    max_spread: None,
-                })?,
-                swap_amount,
-            )?,
-            AFTER_SWAP,
-        )))
+        .add_attribute("minimum_receive_amount", minimum_receive_amount.to_string());
+
+    let swap_amount = match offer_wrapper {
+        Some(native_wrapper) => {
+            response = response
+                .add_attribute("wrapped_denom", native_wrapper.wrapped_denom())
+                .add_message(native_wrapper.wrap_msg(swap_amount.amount)?);
+
+            Coin::new(swap_amount.amount.into(), native_wrapper.wrapped_denom())
+        }
+        None => swap_amount,
+    };
+
+    Ok(response.add_submessage(SubMsg::reply_on_success(
+        into_execute_msg(
+            deps.api,
+            config.router_address,
+            to_json_binary(&ExecuteMsg::ExecuteSwapOperations {
+                operations: swap_operations,
+                minimum_receive: Some(minimum_receive_amount.amount),
+                to: None,
+                max_spread: None,
+            })?,
+            swap_amount,
+        )?,
+        AFTER_SWAP,
+    )))
 }
 
 pub fn return_swapped_funds(deps: Deps, env: Env) -> Result<Response, ContractError> {
     let swap_cache = SWAP_CACHE.load(deps.storage)?;
 
-    let updated_target_denom_balance = deps.querier.query_balance(
-        env.contract.address,
-        swap_cache.minimum_receive_amount.denom.clone(),
+    let updated_target_denom_balance = query_balance(
+        deps.api,
+        &deps.querier,
+        &swap_cache.target_denom_balance.denom,
+        &env.contract.address,
     )?;
 
     let return_amount = subtract(
@@ -87,12 +165,25 @@ pub fn return_swapped_funds(deps: Deps, env: Env) -> Result<Response, ContractEr
         return Err(ContractError::ReceiveAmount {});
     }
 
-    Ok(Response::new()
+    let mut response = Response::new();
+
+    let return_amount = match swap_cache.native_wrapper {
+        Some(native_wrapper) => {
+            response = response
+                .add_attribute("unwrapped_denom", native_wrapper.wrapped_denom())
+                .add_message(native_wrapper.unwrap_msg(return_amount.amount)?);
+
+            Coin::new(return_amount.amount.into(), native_wrapper.native_denom)
+        }
+        None => return_amount,
+    };
+
+    Ok(response
         .add_attribute("return_amount", return_amount.to_string())
-        .add_submessage(SubMsg::new(into_bank_msg(
+        .add_submessage(SubMsg::new(into_transfer_msg(
             deps.api,
             swap_cache.sender.as_ref(),
-            vec![return_amount],
+            return_amount,
         )?)))
 }
 
@@ -104,15 +195,18 @@ mod swap_tests {
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        to_json_binary, Addr, Coin, CosmosMsg, ReplyOn, SubMsg, WasmMsg,
+        to_json_binary, Addr, Coin, CosmosMsg, ReplyOn, SubMsg, Uint128, WasmMsg,
     };
 
     use crate::{
         contract::AFTER_SWAP,
         handlers::swap::swap_handler,
         state::{cache::SWAP_CACHE, config::update_config},
-        tests::constants::{ADMIN, DENOM_UATOM, DENOM_UOSMO, DENOM_USDC, ROUTER},
-        types::config::Config,
+        tests::constants::{ADMIN, DENOM_UATOM, DENOM_UOSMO, DENOM_USDC, ROUTER, WRAPPED_UATOM},
+        types::{
+            config::Config,
+            native_wrapper::{NativeWrapper, WrapperExecuteMsg},
+        },
         ContractError,
     };
 
@@ -179,6 +273,7 @@ mod swap_tests {
             Config {
                 admin: Addr::unchecked(ADMIN),
                 router_address: Addr::unchecked(ROUTER),
+                native_wrappers: vec![],
             },
         )
         .unwrap();
@@ -230,6 +325,7 @@ mod swap_tests {
             Config {
                 admin: Addr::unchecked(ADMIN),
                 router_address: Addr::unchecked(ROUTER),
+                native_wrappers: vec![],
             },
         )
         .unwrap();
@@ -273,20 +369,148 @@ mod swap_tests {
             }
         )
     }
+    #[test]
+    fn wraps_native_denom_when_route_requires_wrapped_token() {
+        let mut deps = mock_dependencies();
+
+        let info = mock_info(ADMIN, &[Coin::new(2347631, DENOM_UATOM)]);
+
+        let minimum_receive_amount = Coin::new(3873213, DENOM_USDC);
+
+        let native_wrapper = NativeWrapper {
+            native_denom: DENOM_UATOM.to_string(),
+            wrapped_token: Addr::unchecked(WRAPPED_UATOM),
+        };
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                admin: Addr::unchecked(ADMIN),
+                router_address: Addr::unchecked(ROUTER),
+                native_wrappers: vec![native_wrapper],
+            },
+        )
+        .unwrap();
+
+        let route = vec![SwapOperation::AstroSwap {
+            offer_asset_info: AssetInfo::Token {
+                contract_addr: Addr::unchecked(WRAPPED_UATOM),
+            },
+            ask_asset_info: AssetInfo::NativeToken {
+                denom: DENOM_USDC.to_string(),
+            },
+        }];
+
+        let response = swap_handler(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            minimum_receive_amount.clone(),
+            to_json_binary(&route).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![
+                SubMsg::new(WasmMsg::Execute {
+                    contract_addr: WRAPPED_UATOM.to_string(),
+                    msg: to_json_binary(&WrapperExecuteMsg::Deposit {}).unwrap(),
+                    funds: vec![Coin::new(2347631, DENOM_UATOM)],
+                }),
+                SubMsg::reply_on_success(
+                    WasmMsg::Execute {
+                        contract_addr: WRAPPED_UATOM.to_string(),
+                        msg: to_json_binary(&cw20::Cw20ExecuteMsg::Send {
+                            contract: ROUTER.to_string(),
+                            amount: Uint128::new(2347631),
+                            msg: to_json_binary(&ExecuteMsg::ExecuteSwapOperations {
+                                operations: route,
+                                minimum_receive: Some(minimum_receive_amount.amount),
+                                to: None,
+                                max_spread: None,
+                            })
+                            .unwrap(),
+                        })
+                        .unwrap(),
+                        funds: vec![],
+                    },
+                    AFTER_SWAP,
+                ),
+            ]
+        )
+    }
+
+    #[test]
+    fn caches_native_wrapper_when_route_returns_wrapped_token() {
+        let mut deps = mock_dependencies();
+
+        deps.querier.update_wasm(|_| {
+            cosmwasm_std::SystemResult::Ok(cosmwasm_std::ContractResult::Ok(
+                to_json_binary(&cw20::BalanceResponse {
+                    balance: Uint128::new(122),
+                })
+                .unwrap(),
+            ))
+        });
+
+        let native_wrapper = NativeWrapper {
+            native_denom: DENOM_UATOM.to_string(),
+            wrapped_token: Addr::unchecked(WRAPPED_UATOM),
+        };
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                admin: Addr::unchecked(ADMIN),
+                router_address: Addr::unchecked(ROUTER),
+                native_wrappers: vec![native_wrapper.clone()],
+            },
+        )
+        .unwrap();
+
+        let route = vec![SwapOperation::AstroSwap {
+            offer_asset_info: AssetInfo::NativeToken {
+                denom: DENOM_USDC.to_string(),
+            },
+            ask_asset_info: AssetInfo::Token {
+                contract_addr: Addr::unchecked(WRAPPED_UATOM),
+            },
+        }];
+
+        swap_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[Coin::new(2347631, DENOM_USDC)]),
+            Coin::new(3873213, DENOM_UATOM),
+            to_json_binary(&route).unwrap(),
+        )
+        .unwrap();
+
+        let swap_cache = SWAP_CACHE.load(deps.as_ref().storage).unwrap();
+
+        assert_eq!(swap_cache.native_wrapper, Some(native_wrapper));
+        assert_eq!(
+            swap_cache.target_denom_balance,
+            Coin::new(122, WRAPPED_UATOM)
+        );
+    }
 }
 
 #[cfg(test)]
 mod return_swapped_funds_tests {
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env},
-        Addr, BankMsg, Coin,
+        to_json_binary, Addr, BankMsg, Coin, ContractResult, SubMsg, SystemResult, Uint128,
+        WasmMsg,
     };
     use shared::coin::add;
 
     use crate::{
         handlers::swap::return_swapped_funds,
         state::cache::{SwapCache, SWAP_CACHE},
-        tests::constants::DENOM_UOSMO,
+        tests::constants::{DENOM_UATOM, DENOM_UOSMO, WRAPPED_UATOM},
+        types::native_wrapper::{NativeWrapper, WrapperExecuteMsg},
         ContractError,
     };
 
@@ -300,6 +524,7 @@ mod return_swapped_funds_tests {
             sender: Addr::unchecked("sender"),
             minimum_receive_amount: minimum_receive_amount.clone(),
             target_denom_balance: Coin::new(122, DENOM_UOSMO),
+            native_wrapper: None,
         };
 
         SWAP_CACHE.save(deps.as_mut().storage, &swap_cache).unwrap();
@@ -323,6 +548,7 @@ mod return_swapped_funds_tests {
             sender: Addr::unchecked("sender"),
             minimum_receive_amount,
             target_denom_balance: target_denom_balance.clone(),
+            native_wrapper: None,
         };
 
         SWAP_CACHE.save(deps.as_mut().storage, &swap_cache).unwrap();
@@ -342,4 +568,50 @@ mod return_swapped_funds_tests {
             })
         )
     }
+
+    #[test]
+    fn unwraps_wrapped_token_before_sending_funds_back_to_sender() {
+        let mut deps = mock_dependencies();
+
+        deps.querier.update_wasm(|_| {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&cw20::BalanceResponse {
+                    balance: Uint128::new(275),
+                })
+                .unwrap(),
+            ))
+        });
+
+        let swap_cache = SwapCache {
+            sender: Addr::unchecked("sender"),
+            minimum_receive_amount: Coin::new(123, DENOM_UATOM),
+            target_denom_balance: Coin::new(122, WRAPPED_UATOM),
+            native_wrapper: Some(NativeWrapper {
+                native_denom: DENOM_UATOM.to_string(),
+                wrapped_token: Addr::unchecked(WRAPPED_UATOM),
+            }),
+        };
+
+        SWAP_CACHE.save(deps.as_mut().storage, &swap_cache).unwrap();
+
+        let response = return_swapped_funds(deps.as_ref(), mock_env()).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![
+                SubMsg::new(WasmMsg::Execute {
+                    contract_addr: WRAPPED_UATOM.to_string(),
+                    msg: to_json_binary(&WrapperExecuteMsg::Withdraw {
+                        amount: Uint128::new(153)
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }),
+                SubMsg::new(BankMsg::Send {
+                    to_address: swap_cache.sender.to_string(),
+                    amount: vec![Coin::new(153, DENOM_UATOM)],
+                }),
+            ]
+        )
+    }
 }
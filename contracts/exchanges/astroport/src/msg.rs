@@ -1,12 +1,19 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_std::{Addr, Binary, Uint128};
 use exchange::msg::Pair;
 use exchange_macros::{exchange_execute, exchange_query};
 
+use crate::types::{
+    limit_order::{LimitOrder, OrderRoute},
+    native_wrapper::NativeWrapper,
+};
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub admin: Addr,
     pub router_address: Addr,
+    #[serde(default)]
+    pub native_wrappers: Vec<NativeWrapper>,
 }
 
 pub type MigrateMsg = InstantiateMsg;
@@ -14,8 +21,17 @@ pub type MigrateMsg = InstantiateMsg;
 #[exchange_query]
 #[cw_serde]
 #[derive(QueryResponses)]
-pub enum QueryMsg {}
+pub enum QueryMsg {
+    #[returns(Vec<LimitOrder>)]
+    GetOrders {
+        start_after: Option<Uint128>,
+        limit: Option<u16>,
+    },
+}
 
 #[exchange_execute]
 #[cw_serde]
-pub enum ExecuteMsg {}
+pub enum ExecuteMsg {
+    CheckOrders { orders: Vec<OrderRoute> },
+    FillOrder { order_idx: Uint128, route: Binary },
+}
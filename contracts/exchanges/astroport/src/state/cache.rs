@@ -1,12 +1,27 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Coin, Uint128};
 use cw_storage_plus::Item;
 
+use crate::types::native_wrapper::NativeWrapper;
+
 #[cw_serde]
 pub struct SwapCache {
     pub sender: Addr,
     pub minimum_receive_amount: Coin,
     pub target_denom_balance: Coin,
+    #[serde(default)]
+    pub native_wrapper: Option<NativeWrapper>,
 }
 
 pub const SWAP_CACHE: Item<SwapCache> = Item::new("swap_cache_v1");
+
+#[cw_serde]
+pub struct FillOrderCache {
+    pub order_idx: Uint128,
+    pub minimum_receive_amount: Coin,
+    pub target_denom_balance: Coin,
+    #[serde(default)]
+    pub native_wrapper: Option<NativeWrapper>,
+}
+
+pub const FILL_ORDER_CACHE: Item<FillOrderCache> = Item::new("fill_order_cache_v1");
@@ -0,0 +1,63 @@
+use std::any::type_name;
+
+use crate::types::limit_order::LimitOrder;
+use cosmwasm_std::{Addr, Coin, Decimal256, Order, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+
+const ORDER_COUNTER: Item<u64> = Item::new("order_counter_v1");
+
+const ORDERS: Map<u128, LimitOrder> = Map::new("orders_v1");
+
+pub fn create_order(
+    storage: &mut dyn Storage,
+    owner: Addr,
+    offer_amount: Coin,
+    target_denom: String,
+    target_price: Decimal256,
+) -> StdResult<LimitOrder> {
+    let order_idx = ORDER_COUNTER.may_load(storage)?.unwrap_or_default() + 1;
+    ORDER_COUNTER.save(storage, &order_idx)?;
+
+    let order = LimitOrder {
+        order_idx: order_idx.into(),
+        owner,
+        target_price,
+        filled_amount: Coin::new(0, target_denom.clone()),
+        target_denom,
+        remaining_offer_amount: offer_amount,
+    };
+
+    ORDERS.save(storage, order.order_idx.into(), &order)?;
+    Ok(order)
+}
+
+pub fn get_order(storage: &dyn Storage, order_idx: Uint128) -> StdResult<LimitOrder> {
+    ORDERS
+        .load(storage, order_idx.into())
+        .map_err(|_| StdError::not_found(type_name::<LimitOrder>()))
+}
+
+pub fn get_orders(
+    storage: &dyn Storage,
+    start_after: Option<Uint128>,
+    limit: Option<u16>,
+) -> Vec<LimitOrder> {
+    ORDERS
+        .range(
+            storage,
+            start_after.map(|order_idx| Bound::exclusive(order_idx.u128())),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.unwrap_or(30) as usize)
+        .flat_map(|result| result.map(|(_, order)| order))
+        .collect::<Vec<LimitOrder>>()
+}
+
+pub fn update_order(storage: &mut dyn Storage, order: &LimitOrder) -> StdResult<()> {
+    ORDERS.save(storage, order.order_idx.into(), order)
+}
+
+pub fn delete_order(storage: &mut dyn Storage, order_idx: Uint128) {
+    ORDERS.remove(storage, order_idx.into())
+}
@@ -18,3 +18,5 @@ pub const DENOM_UATOM: &str = "uatom";
 pub const DENOM_UION: &str = "uion";
 pub const DENOM_USDC: &str = "usdc";
 pub const DENOM_STAKE: &str = "ustake";
+
+pub const WRAPPED_UATOM: &str = "wrapped_uatom_token";
@@ -1,8 +1,12 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Addr;
 
+use super::native_wrapper::NativeWrapper;
+
 #[cw_serde]
 pub struct Config {
     pub admin: Addr,
     pub router_address: Addr,
+    #[serde(default)]
+    pub native_wrappers: Vec<NativeWrapper>,
 }
@@ -0,0 +1,49 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary, Coin, Decimal256, StdResult, Uint128};
+
+#[cw_serde]
+pub struct LimitOrder {
+    pub order_idx: Uint128,
+    pub owner: Addr,
+    pub target_price: Decimal256,
+    pub target_denom: String,
+    pub remaining_offer_amount: Coin,
+    pub filled_amount: Coin,
+}
+
+impl LimitOrder {
+    pub fn denoms(&self) -> [String; 2] {
+        [
+            self.remaining_offer_amount.denom.clone(),
+            self.target_denom.clone(),
+        ]
+    }
+
+    pub fn trades(&self, mut denoms: [String; 2]) -> bool {
+        let mut order_denoms = self.denoms();
+        denoms.sort();
+        order_denoms.sort();
+        denoms == order_denoms
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.remaining_offer_amount.amount.is_zero()
+    }
+
+    pub fn minimum_receive_amount(&self) -> StdResult<Coin> {
+        let amount = (Decimal256::from_ratio(self.remaining_offer_amount.amount, 1u128)
+            / self.target_price)
+            .to_uint_floor();
+
+        Ok(Coin::new(
+            Uint128::try_from(amount)?.into(),
+            self.target_denom.clone(),
+        ))
+    }
+}
+
+#[cw_serde]
+pub struct OrderRoute {
+    pub order_idx: Uint128,
+    pub route: Binary,
+}
@@ -1 +1,3 @@
 pub mod config;
+pub mod limit_order;
+pub mod native_wrapper;
@@ -0,0 +1,36 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+#[cw_serde]
+pub struct NativeWrapper {
+    pub native_denom: String,
+    pub wrapped_token: Addr,
+}
+
+#[cw_serde]
+pub enum WrapperExecuteMsg {
+    Deposit {},
+    Withdraw { amount: Uint128 },
+}
+
+impl NativeWrapper {
+    pub fn wrapped_denom(&self) -> String {
+        self.wrapped_token.to_string()
+    }
+
+    pub fn wrap_msg(&self, amount: Uint128) -> StdResult<CosmosMsg> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: self.wrapped_token.to_string(),
+            msg: to_json_binary(&WrapperExecuteMsg::Deposit {})?,
+            funds: vec![Coin::new(amount.into(), self.native_denom.clone())],
+        }))
+    }
+
+    pub fn unwrap_msg(&self, amount: Uint128) -> StdResult<CosmosMsg> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: self.wrapped_token.to_string(),
+            msg: to_json_binary(&WrapperExecuteMsg::Withdraw { amount })?,
+            funds: vec![],
+        }))
+    }
+}
@@ -10,7 +10,7 @@ use cosmwasm_std::{
 use cw20::Cw20ReceiveMsg;
 use shared::{
     coin::subtract,
-    cw20::{into_bank_msg, into_execute_msg},
+    cw20::{into_execute_msg, into_transfer_msg},
 };
 
 use crate::{
@@ -95,10 +95,10 @@ pub fn return_swapped_funds(deps: Deps, env: Env) -> Result<Response, ContractEr
 
     Ok(Response::new()
         .add_attribute("return_amount", return_amount.to_string())
-        .add_submessage(SubMsg::new(into_bank_msg(
+        .add_submessage(SubMsg::new(into_transfer_msg(
             deps.api,
-            &swap_cache.sender.to_string(),
-            vec![return_amount],
+            swap_cache.sender.as_ref(),
+            return_amount,
         )?)))
 }
 
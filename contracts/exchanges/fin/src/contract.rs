@@ -16,7 +16,7 @@ use crate::handlers::get_pairs_internal::get_pairs_internal_handler;
 use crate::handlers::get_twap_to_now::get_twap_to_now_handler;
 use crate::handlers::retract_order::{retract_order_handler, return_retracted_funds};
 use crate::handlers::submit_order::{return_order_idx, submit_order_handler};
-use crate::handlers::swap::{return_swapped_funds, swap_handler};
+use crate::handlers::swap::{after_fin_swap, swap_handler};
 use crate::handlers::withdraw_order::{return_withdrawn_funds, withdraw_order_handler};
 use crate::state::config::update_config;
 use crate::types::config::Config;
@@ -151,7 +151,7 @@ pub const AFTER_WITHDRAW_ORDER: u64 = 4;
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, ContractError> {
     match reply.id {
-        AFTER_SWAP => return_swapped_funds(deps.as_ref(), env),
+        AFTER_SWAP => after_fin_swap(deps.as_ref(), env, reply),
         AFTER_SUBMIT_ORDER => return_order_idx(reply),
         AFTER_RETRACT_ORDER => return_retracted_funds(deps.as_ref(), env),
         AFTER_WITHDRAW_ORDER => return_withdrawn_funds(deps.as_ref(), env),
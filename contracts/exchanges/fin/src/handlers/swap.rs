@@ -1,9 +1,10 @@
-use cosmwasm_std::{BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, SubMsg};
+use cosmwasm_std::{BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Reply, Response, SubMsg};
 use kujira_fin::ExecuteMsg;
 use shared::{balance::query_balance, coin::subtract};
 
 use crate::{
     contract::AFTER_SWAP,
+    helpers::message::get_trade_return_amount,
     state::{
         cache::{SwapCache, SWAP_CACHE},
         pairs::find_pair,
@@ -72,18 +73,32 @@ pub fn swap_handler(
         )))
 }
 
-pub fn return_swapped_funds(deps: Deps, env: Env) -> Result<Response, ContractError> {
+pub fn after_fin_swap(deps: Deps, env: Env, reply: Reply) -> Result<Response, ContractError> {
     let swap_cache = SWAP_CACHE.load(deps.storage)?;
 
-    let updated_target_denom_balance = deps.querier.query_balance(
-        env.contract.address,
-        swap_cache.minimum_receive_amount.denom.clone(),
-    )?;
-
-    let return_amount = subtract(
-        &updated_target_denom_balance,
-        &swap_cache.target_denom_balance,
-    )?;
+    let events = reply
+        .result
+        .into_result()
+        .map_err(|error| ContractError::FailedSwap { msg: error })?
+        .events;
+
+    let return_amount = match get_trade_return_amount(&events)? {
+        Some(amount) => Coin::new(
+            amount.into(),
+            swap_cache.minimum_receive_amount.denom.clone(),
+        ),
+        None => {
+            let updated_target_denom_balance = deps.querier.query_balance(
+                env.contract.address,
+                swap_cache.minimum_receive_amount.denom.clone(),
+            )?;
+
+            subtract(
+                &updated_target_denom_balance,
+                &swap_cache.target_denom_balance,
+            )?
+        }
+    };
 
     if return_amount.amount < swap_cache.minimum_receive_amount.amount {
         return Err(ContractError::FailedSwap {
@@ -261,20 +276,28 @@ mod swap_tests {
 }
 
 #[cfg(test)]
-mod return_swapped_funds_tests {
+mod after_fin_swap_tests {
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env},
-        Addr, BankMsg, Coin,
+        Addr, BankMsg, Coin, Event, Reply, SubMsgResponse, SubMsgResult,
     };
     use shared::coin::{add, empty_of};
 
     use crate::{
-        handlers::swap::return_swapped_funds,
+        contract::AFTER_SWAP,
+        handlers::swap::after_fin_swap,
         state::cache::{SwapCache, SWAP_CACHE},
         tests::constants::DENOM_UKUJI,
         ContractError,
     };
 
+    fn reply_with_events(events: Vec<Event>) -> Reply {
+        Reply {
+            id: AFTER_SWAP,
+            result: SubMsgResult::Ok(SubMsgResponse { events, data: None }),
+        }
+    }
+
     #[test]
     fn with_return_amount_smaller_than_minimum_receive_amount_fails() {
         let mut deps = mock_dependencies();
@@ -290,7 +313,7 @@ mod return_swapped_funds_tests {
         SWAP_CACHE.save(deps.as_mut().storage, &swap_cache).unwrap();
 
         assert_eq!(
-            return_swapped_funds(deps.as_ref(), mock_env()).unwrap_err(),
+            after_fin_swap(deps.as_ref(), mock_env(), reply_with_events(vec![])).unwrap_err(),
             ContractError::FailedSwap {
                 msg: format!(
                     "{} is less than the minimum return amount of {}",
@@ -323,7 +346,7 @@ mod return_swapped_funds_tests {
             vec![add(target_denom_balance, return_amount.clone()).unwrap()],
         );
 
-        let response = return_swapped_funds(deps.as_ref(), env).unwrap();
+        let response = after_fin_swap(deps.as_ref(), env, reply_with_events(vec![])).unwrap();
 
         assert_eq!(
             response.messages.first().unwrap(),
@@ -333,4 +356,66 @@ mod return_swapped_funds_tests {
             })
         )
     }
+
+    #[test]
+    fn with_trade_event_sends_traded_amount_back_to_sender() {
+        let mut deps = mock_dependencies();
+
+        let swap_cache = SwapCache {
+            sender: Addr::unchecked("sender"),
+            minimum_receive_amount: Coin::new(123, DENOM_UKUJI),
+            target_denom_balance: Coin::new(122, DENOM_UKUJI),
+        };
+
+        SWAP_CACHE.save(deps.as_mut().storage, &swap_cache).unwrap();
+
+        let response = after_fin_swap(
+            deps.as_ref(),
+            mock_env(),
+            reply_with_events(vec![Event::new("wasm-trade")
+                .add_attribute("base_amount", "153")
+                .add_attribute("quote_amount", "400")
+                .add_attribute("type", "buy")]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &cosmwasm_std::SubMsg::new(BankMsg::Send {
+                to_address: swap_cache.sender.to_string(),
+                amount: vec![Coin::new(153, DENOM_UKUJI)],
+            })
+        )
+    }
+
+    #[test]
+    fn with_failed_reply_fails() {
+        let mut deps = mock_dependencies();
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                &SwapCache {
+                    sender: Addr::unchecked("sender"),
+                    minimum_receive_amount: Coin::new(123, DENOM_UKUJI),
+                    target_denom_balance: Coin::new(122, DENOM_UKUJI),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            after_fin_swap(
+                deps.as_ref(),
+                mock_env(),
+                Reply {
+                    id: AFTER_SWAP,
+                    result: SubMsgResult::Err("insufficient liquidity".to_string()),
+                },
+            )
+            .unwrap_err(),
+            ContractError::FailedSwap {
+                msg: "insufficient liquidity".to_string()
+            }
+        )
+    }
 }
@@ -1,4 +1,6 @@
-use cosmwasm_std::{Event, StdError, StdResult};
+use std::str::FromStr;
+
+use cosmwasm_std::{Event, StdError, StdResult, Uint128};
 
 pub fn get_attribute_in_event(
     events: &[Event],
@@ -20,3 +22,96 @@ pub fn get_attribute_in_event(
 
     Ok(attribute.value.clone())
 }
+
+fn get_attribute(event: &Event, attribute_key: &str) -> StdResult<String> {
+    event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == attribute_key)
+        .map(|attribute| attribute.value.clone())
+        .ok_or_else(|| {
+            StdError::generic_err(format!(
+                "unable to find {} attribute in {} event",
+                attribute_key, event.ty
+            ))
+        })
+}
+
+pub fn get_trade_return_amount(events: &[Event]) -> StdResult<Option<Uint128>> {
+    let trade_events = events
+        .iter()
+        .filter(|event| event.ty == "wasm-trade")
+        .collect::<Vec<_>>();
+
+    if trade_events.is_empty() {
+        return Ok(None);
+    }
+
+    trade_events
+        .into_iter()
+        .try_fold(Uint128::zero(), |total, event| {
+            let amount_key = match get_attribute(event, "type")?.as_str() {
+                "buy" => "base_amount",
+                "sell" => "quote_amount",
+                trade_type => {
+                    return Err(StdError::generic_err(format!(
+                        "unknown trade type {}",
+                        trade_type
+                    )))
+                }
+            };
+
+            Ok(total.checked_add(Uint128::from_str(&get_attribute(event, amount_key)?)?)?)
+        })
+        .map(Some)
+}
+
+#[cfg(test)]
+mod get_trade_return_amount_tests {
+    use cosmwasm_std::{Event, Uint128};
+
+    use super::get_trade_return_amount;
+
+    fn trade_event(trade_type: &str, base_amount: u128, quote_amount: u128) -> Event {
+        Event::new("wasm-trade")
+            .add_attribute("market", "fin")
+            .add_attribute("base_amount", base_amount.to_string())
+            .add_attribute("quote_amount", quote_amount.to_string())
+            .add_attribute("type", trade_type)
+    }
+
+    #[test]
+    fn with_no_trade_events_returns_none() {
+        assert_eq!(
+            get_trade_return_amount(&[Event::new("wasm").add_attribute("action", "swap")]).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn with_buy_trade_returns_base_amount() {
+        assert_eq!(
+            get_trade_return_amount(&[trade_event("buy", 120, 300)]).unwrap(),
+            Some(Uint128::new(120))
+        );
+    }
+
+    #[test]
+    fn with_sell_trades_returns_summed_quote_amount() {
+        assert_eq!(
+            get_trade_return_amount(&[trade_event("sell", 120, 300), trade_event("sell", 10, 25)])
+                .unwrap(),
+            Some(Uint128::new(325))
+        );
+    }
+
+    #[test]
+    fn with_unknown_trade_type_fails() {
+        assert_eq!(
+            get_trade_return_amount(&[trade_event("swap", 120, 300)])
+                .unwrap_err()
+                .to_string(),
+            "Generic error: unknown trade type swap"
+        );
+    }
+}
@@ -27,8 +27,22 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 */
 
 #[entry_point]
-pub fn migrate(_: DepsMut, _: Env, _: MigrateMsg) -> Result<Response, ContractError> {
-    Ok(Response::new().add_attribute("migrate", "true"))
+pub fn migrate(deps: DepsMut, _: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    deps.api.addr_validate(msg.admin.as_ref())?;
+    deps.api.addr_validate(msg.dca_contract_address.as_ref())?;
+
+    update_config(
+        deps.storage,
+        Config {
+            admin: msg.admin.clone(),
+            dca_contract_address: msg.dca_contract_address.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("migrate", "true")
+        .add_attribute("admin", msg.admin)
+        .add_attribute("dca_contract_address", msg.dca_contract_address))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -39,16 +53,20 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     deps.api.addr_validate(msg.admin.as_ref())?;
+    deps.api.addr_validate(msg.dca_contract_address.as_ref())?;
+
     update_config(
         deps.storage,
         Config {
             admin: msg.admin.clone(),
+            dca_contract_address: msg.dca_contract_address.clone(),
         },
     )?;
 
     Ok(Response::new()
         .add_attribute("instantiate", "true")
-        .add_attribute("admin", msg.admin))
+        .add_attribute("admin", msg.admin)
+        .add_attribute("dca_contract_address", msg.dca_contract_address))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -138,7 +156,7 @@ pub const AFTER_WITHDRAW_ORDER: u64 = 4;
 pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, ContractError> {
     match reply.id {
         AFTER_SWAP => return_swapped_funds(deps.as_ref(), env),
-        AFTER_SUBMIT_ORDER => return_order_idx(reply),
+        AFTER_SUBMIT_ORDER => return_order_idx(deps, reply),
         AFTER_RETRACT_ORDER => return_retracted_funds(deps.as_ref(), env),
         AFTER_WITHDRAW_ORDER => return_withdrawn_funds(deps.as_ref(), env),
         _ => Err(ContractError::MissingReplyId {}),
@@ -1,10 +1,210 @@
-use cosmwasm_std::{Deps, StdResult, Uint128};
+use cosmwasm_std::{Coin, Deps, StdError, StdResult, Uint128};
 use exchange::msg::Order;
 
-pub fn get_order_handler(
-    _deps: Deps,
-    _order_idx: Uint128,
-    _denoms: [String; 2],
-) -> StdResult<Order> {
-    unimplemented!("Limit orders are not supported on osmosis yet")
+use crate::{
+    helpers::orderbook::get_orderbook_address,
+    state::{orders::get_order_tick, pairs::find_pair},
+    types::orderbook::{OrderDirection, OrderbookQueryMsg, OrdersResponse},
+};
+
+pub fn get_order_handler(deps: Deps, order_idx: Uint128, denoms: [String; 2]) -> StdResult<Order> {
+    let pair = find_pair(deps.storage, denoms)?;
+
+    let orderbook_address = get_orderbook_address(&deps.querier, &pair)?;
+    let (order_id, tick_id) = get_order_tick(deps.storage, orderbook_address.clone(), order_idx)?;
+
+    let order = deps
+        .querier
+        .query_wasm_smart::<OrdersResponse>(
+            orderbook_address,
+            &OrderbookQueryMsg::OrdersByTick {
+                tick_id,
+                start_from: Some(order_id),
+                end_at: Some(order_id),
+                limit: Some(1),
+            },
+        )?
+        .orders
+        .into_iter()
+        .find(|order| order.order_id == order_id)
+        .ok_or_else(|| {
+            StdError::generic_err("No orders with the specified information exist".to_string())
+        })?;
+
+    let offer_denom = match order.order_direction {
+        OrderDirection::Bid => pair.quote_denom,
+        OrderDirection::Ask => pair.base_denom,
+    };
+
+    Ok(Order {
+        order_idx,
+        remaining_offer_amount: Coin::new(order.quantity.into(), offer_denom),
+    })
+}
+
+#[cfg(test)]
+mod get_order_handler_tests {
+    use cosmwasm_std::{
+        testing::mock_dependencies, to_json_binary, Addr, Coin, ContractResult, Decimal256,
+        StdError, SystemResult, Uint128,
+    };
+    use exchange::msg::Order;
+
+    use crate::{
+        handlers::get_order::get_order_handler,
+        state::{orders::save_order_tick, pairs::save_pair},
+        tests::{
+            constants::{ORDERBOOK_ADDRESS, ORDERBOOK_POOL_ID},
+            mocks::calc_mock_dependencies,
+        },
+        types::{
+            orderbook::{LimitOrder, OrderDirection, OrdersResponse},
+            pair::Pair,
+        },
+    };
+
+    fn orderbook_pair() -> Pair {
+        Pair {
+            route: vec![ORDERBOOK_POOL_ID],
+            ..Pair::default()
+        }
+    }
+
+    fn orders_response(orders: Vec<LimitOrder>) -> OrdersResponse {
+        OrdersResponse {
+            count: orders.len() as u64,
+            orders,
+        }
+    }
+
+    fn limit_order(order_direction: OrderDirection) -> LimitOrder {
+        LimitOrder {
+            tick_id: -5_000_000,
+            order_id: 18,
+            order_direction,
+            owner: Addr::unchecked("osmosis"),
+            quantity: Uint128::new(2),
+            etas: Decimal256::zero(),
+            claim_bounty: None,
+        }
+    }
+
+    #[test]
+    fn for_missing_pair_fails() {
+        assert_eq!(
+            get_order_handler(
+                mock_dependencies().as_ref(),
+                Uint128::zero(),
+                Pair::default().denoms()
+            )
+            .unwrap_err(),
+            StdError::NotFound {
+                kind: "osmosis::types::pair::Pair".to_string()
+            }
+        )
+    }
+
+    #[test]
+    fn for_unknown_order_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        assert_eq!(
+            get_order_handler(deps.as_ref(), Uint128::new(18), pair.denoms()).unwrap_err(),
+            StdError::generic_err("No orders with the specified information exist")
+        )
+    }
+
+    #[test]
+    fn for_order_no_longer_on_the_orderbook_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        deps.querier.update_wasm(|_| {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&orders_response(vec![])).unwrap(),
+            ))
+        });
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        save_order_tick(
+            deps.as_mut().storage,
+            Addr::unchecked(ORDERBOOK_ADDRESS),
+            18,
+            -5_000_000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_order_handler(deps.as_ref(), Uint128::new(18), pair.denoms()).unwrap_err(),
+            StdError::generic_err("No orders with the specified information exist")
+        )
+    }
+
+    #[test]
+    fn for_bid_returns_remaining_quote_denom() {
+        let mut deps = calc_mock_dependencies();
+
+        deps.querier.update_wasm(|_| {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&orders_response(vec![limit_order(OrderDirection::Bid)])).unwrap(),
+            ))
+        });
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        save_order_tick(
+            deps.as_mut().storage,
+            Addr::unchecked(ORDERBOOK_ADDRESS),
+            18,
+            -5_000_000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_order_handler(deps.as_ref(), Uint128::new(18), pair.denoms()).unwrap(),
+            Order {
+                order_idx: Uint128::new(18),
+                remaining_offer_amount: Coin::new(2, pair.quote_denom),
+            }
+        );
+    }
+
+    #[test]
+    fn for_ask_returns_remaining_base_denom() {
+        let mut deps = calc_mock_dependencies();
+
+        deps.querier.update_wasm(|_| {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&orders_response(vec![limit_order(OrderDirection::Ask)])).unwrap(),
+            ))
+        });
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        save_order_tick(
+            deps.as_mut().storage,
+            Addr::unchecked(ORDERBOOK_ADDRESS),
+            18,
+            -5_000_000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_order_handler(deps.as_ref(), Uint128::new(18), pair.denoms()).unwrap(),
+            Order {
+                order_idx: Uint128::new(18),
+                remaining_offer_amount: Coin::new(2, pair.base_denom),
+            }
+        );
+    }
 }
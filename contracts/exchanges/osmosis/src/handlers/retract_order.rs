@@ -1,17 +1,426 @@
-use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, Uint128};
+use std::collections::HashMap;
 
-use crate::ContractError;
+use cosmwasm_std::{
+    to_json_binary, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128,
+    WasmMsg,
+};
+
+use crate::{
+    contract::AFTER_RETRACT_ORDER,
+    helpers::{balance::get_balance_delta, orderbook::get_orderbook_address},
+    state::{
+        cache::{LimitOrderCache, LIMIT_ORDER_CACHE},
+        config::get_config,
+        orders::get_order_tick,
+        pairs::find_pair,
+    },
+    types::orderbook::OrderbookExecuteMsg,
+    ContractError,
+};
 
 pub fn retract_order_handler(
-    _deps: DepsMut,
-    _env: Env,
-    _info: MessageInfo,
-    _order_idx: Uint128,
-    _denoms: [String; 2],
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    order_idx: Uint128,
+    denoms: [String; 2],
 ) -> Result<Response, ContractError> {
-    unimplemented!("Limit orders are not supported on osmosis yet")
+    if !info.funds.is_empty() {
+        return Err(ContractError::InvalidFunds {
+            msg: "must not provide funds to retract order".to_string(),
+        });
+    }
+
+    let config = get_config(deps.storage)?;
+
+    if info.sender != config.dca_contract_address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pair = find_pair(deps.storage, denoms.clone())?;
+
+    let orderbook_address = get_orderbook_address(&deps.querier, &pair)?;
+    let (order_id, tick_id) = get_order_tick(deps.storage, orderbook_address.clone(), order_idx)?;
+
+    LIMIT_ORDER_CACHE.save(
+        deps.storage,
+        &LimitOrderCache {
+            sender: info.sender,
+            balances: HashMap::from([
+                (
+                    denoms[0].clone(),
+                    deps.querier
+                        .query_balance(env.contract.address.clone(), denoms[0].clone())?,
+                ),
+                (
+                    denoms[1].clone(),
+                    deps.querier
+                        .query_balance(env.contract.address, denoms[1].clone())?,
+                ),
+            ]),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("retract_order", "true")
+        .add_attribute("order_idx", order_idx)
+        .add_submessage(SubMsg::reply_on_success(
+            WasmMsg::Execute {
+                contract_addr: orderbook_address.to_string(),
+                msg: to_json_binary(&OrderbookExecuteMsg::CancelLimit { tick_id, order_id })?,
+                funds: vec![],
+            },
+            AFTER_RETRACT_ORDER,
+        )))
+}
+
+pub fn return_retracted_funds(deps: Deps, env: Env) -> Result<Response, ContractError> {
+    let cache = LIMIT_ORDER_CACHE.load(deps.storage)?;
+
+    let mut funds = cache
+        .balances
+        .values()
+        .map(|old_balance| {
+            get_balance_delta(deps.querier, env.contract.address.clone(), old_balance)
+        })
+        .collect::<Result<Vec<Coin>, _>>()?
+        .into_iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .collect::<Vec<Coin>>();
+
+    let mut response = Response::new().add_attribute("return_retracted_funds", "true");
+
+    if !funds.is_empty() {
+        funds.sort_by(|a, b| a.amount.cmp(&b.amount));
+        response = response.add_submessage(SubMsg::new(BankMsg::Send {
+            to_address: cache.sender.to_string(),
+            amount: funds,
+        }));
+    }
+
+    Ok(response)
 }
 
-pub fn return_retracted_funds(_deps: Deps, _env: Env) -> Result<Response, ContractError> {
-    unimplemented!("Limit orders are not supported on osmosis yet")
+#[cfg(test)]
+mod retract_order_handler_tests {
+    use std::collections::HashMap;
+
+    use cosmwasm_std::{
+        testing::{mock_env, mock_info},
+        to_json_binary, Addr, Coin, StdError, SubMsg, Uint128, WasmMsg,
+    };
+
+    use crate::{
+        contract::AFTER_RETRACT_ORDER,
+        state::{
+            cache::LIMIT_ORDER_CACHE,
+            config::update_config,
+            orders::{get_order_tick, save_order_tick},
+            pairs::save_pair,
+        },
+        tests::{
+            constants::{
+                ADMIN, DCA_CONTRACT_ADDRESS, DENOM_STAKE, DENOM_UOSMO, ORDERBOOK_ADDRESS,
+                ORDERBOOK_POOL_ID,
+            },
+            mocks::calc_mock_dependencies,
+        },
+        types::{config::Config, orderbook::OrderbookExecuteMsg, pair::Pair},
+        ContractError,
+    };
+
+    use super::retract_order_handler;
+
+    fn orderbook_pair() -> Pair {
+        Pair {
+            route: vec![ORDERBOOK_POOL_ID],
+            ..Pair::default()
+        }
+    }
+
+    #[test]
+    fn with_funds_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        assert_eq!(
+            retract_order_handler(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(DCA_CONTRACT_ADDRESS, &[Coin::new(3218312, DENOM_UOSMO)]),
+                Uint128::new(234),
+                Pair::default().denoms(),
+            )
+            .unwrap_err(),
+            ContractError::InvalidFunds {
+                msg: String::from("must not provide funds to retract order")
+            }
+        );
+    }
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        assert_eq!(
+            retract_order_handler(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(ADMIN, &[]),
+                Uint128::new(234),
+                Pair::default().denoms(),
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+    }
+
+    #[test]
+    fn with_unknown_order_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        assert_eq!(
+            retract_order_handler(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(DCA_CONTRACT_ADDRESS, &[]),
+                Uint128::new(234),
+                pair.denoms(),
+            )
+            .unwrap_err(),
+            ContractError::Std(StdError::generic_err(
+                "No orders with the specified information exist"
+            ))
+        );
+    }
+
+    #[test]
+    fn caches_sender_and_pair_balances() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        let uosmo_balance = Coin::new(25423, DENOM_UOSMO);
+        let ustake_balance = Coin::new(12234324343123, DENOM_STAKE);
+
+        deps.querier.update_balance(
+            env.contract.address.clone(),
+            vec![uosmo_balance.clone(), ustake_balance.clone()],
+        );
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        save_order_tick(
+            deps.as_mut().storage,
+            Addr::unchecked(ORDERBOOK_ADDRESS),
+            182374,
+            -5_000_000,
+        )
+        .unwrap();
+
+        retract_order_handler(
+            deps.as_mut(),
+            env,
+            mock_info(DCA_CONTRACT_ADDRESS, &[]),
+            Uint128::new(182374),
+            pair.denoms(),
+        )
+        .unwrap();
+
+        let cache = LIMIT_ORDER_CACHE.load(deps.as_ref().storage).unwrap();
+
+        assert_eq!(cache.sender, DCA_CONTRACT_ADDRESS.to_string());
+        assert_eq!(
+            cache.balances,
+            HashMap::from([
+                (DENOM_UOSMO.to_string(), uosmo_balance),
+                (DENOM_STAKE.to_string(), ustake_balance)
+            ])
+        );
+    }
+
+    #[test]
+    fn sends_cancel_limit_message_to_orderbook() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        save_order_tick(
+            deps.as_mut().storage,
+            Addr::unchecked(ORDERBOOK_ADDRESS),
+            182374,
+            -5_000_000,
+        )
+        .unwrap();
+
+        let response = retract_order_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DCA_CONTRACT_ADDRESS, &[]),
+            Uint128::new(182374),
+            pair.denoms(),
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::reply_on_success(
+                WasmMsg::Execute {
+                    contract_addr: ORDERBOOK_ADDRESS.to_string(),
+                    msg: to_json_binary(&OrderbookExecuteMsg::CancelLimit {
+                        tick_id: -5_000_000,
+                        order_id: 182374,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                },
+                AFTER_RETRACT_ORDER,
+            )
+        );
+    }
+
+    #[test]
+    fn keeps_the_order_tick_for_the_following_withdrawal() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        save_order_tick(
+            deps.as_mut().storage,
+            Addr::unchecked(ORDERBOOK_ADDRESS),
+            182374,
+            -5_000_000,
+        )
+        .unwrap();
+
+        retract_order_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DCA_CONTRACT_ADDRESS, &[]),
+            Uint128::new(182374),
+            pair.denoms(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_order_tick(
+                deps.as_ref().storage,
+                Addr::unchecked(ORDERBOOK_ADDRESS),
+                Uint128::new(182374)
+            )
+            .unwrap(),
+            (182374, -5_000_000)
+        );
+    }
+}
+
+#[cfg(test)]
+mod return_retracted_funds_tests {
+    use std::collections::HashMap;
+
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Addr, BankMsg, Coin, SubMsg, Uint128,
+    };
+    use shared::coin::add_to;
+
+    use crate::{
+        state::cache::{LimitOrderCache, LIMIT_ORDER_CACHE},
+        tests::constants::{DCA_CONTRACT_ADDRESS, DENOM_STAKE, DENOM_UOSMO},
+    };
+
+    use super::return_retracted_funds;
+
+    #[test]
+    fn returns_funds_difference_to_sender() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let old_uosmo_balance = Coin::new(25423, DENOM_UOSMO);
+        let old_ustake_balance = Coin::new(12234324343123, DENOM_STAKE);
+
+        LIMIT_ORDER_CACHE
+            .save(
+                deps.as_mut().storage,
+                &LimitOrderCache {
+                    sender: Addr::unchecked(DCA_CONTRACT_ADDRESS),
+                    balances: HashMap::from([
+                        (DENOM_UOSMO.to_string(), old_uosmo_balance.clone()),
+                        (DENOM_STAKE.to_string(), old_ustake_balance.clone()),
+                    ]),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            env.contract.address.clone(),
+            vec![
+                add_to(&old_uosmo_balance, Uint128::new(1000)),
+                add_to(&old_ustake_balance, Uint128::new(2000)),
+            ],
+        );
+
+        let response = return_retracted_funds(deps.as_ref(), env).unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::new(BankMsg::Send {
+                to_address: DCA_CONTRACT_ADDRESS.to_string(),
+                amount: vec![Coin::new(1000, DENOM_UOSMO), Coin::new(2000, DENOM_STAKE)],
+            })
+        );
+    }
+
+    #[test]
+    fn with_no_differences_drops_bank_send_message() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let old_uosmo_balance = Coin::new(25423, DENOM_UOSMO);
+        let old_ustake_balance = Coin::new(12234324343123, DENOM_STAKE);
+
+        LIMIT_ORDER_CACHE
+            .save(
+                deps.as_mut().storage,
+                &LimitOrderCache {
+                    sender: Addr::unchecked(DCA_CONTRACT_ADDRESS),
+                    balances: HashMap::from([
+                        (DENOM_UOSMO.to_string(), old_uosmo_balance.clone()),
+                        (DENOM_STAKE.to_string(), old_ustake_balance.clone()),
+                    ]),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            env.contract.address.clone(),
+            vec![old_uosmo_balance, old_ustake_balance],
+        );
+
+        let response = return_retracted_funds(deps.as_ref(), env).unwrap();
+
+        assert_eq!(response.messages.len(), 0);
+    }
 }
@@ -1,81 +1,365 @@
-use cosmwasm_std::{Decimal256, Deps, MessageInfo, Reply, Response};
+use cosmwasm_std::{
+    to_json_binary, Addr, Decimal256, Deps, DepsMut, MessageInfo, Reply, Response, SubMsg, Uint128,
+    WasmMsg,
+};
 
-use crate::ContractError;
+use crate::{
+    contract::AFTER_SUBMIT_ORDER,
+    helpers::{
+        message::get_attribute_in_event,
+        orderbook::{get_order_direction, get_orderbook_address, price_to_tick},
+    },
+    state::{config::get_config, orders::save_order_tick, pairs::find_pair},
+    types::{orderbook::OrderbookExecuteMsg, position_type::PositionType},
+    ContractError,
+};
 
 pub fn submit_order_handler(
-    _deps: Deps,
-    _info: MessageInfo,
-    _target_price: Decimal256,
-    _target_denom: String,
+    deps: Deps,
+    info: MessageInfo,
+    target_price: Decimal256,
+    target_denom: String,
 ) -> Result<Response, ContractError> {
-    unimplemented!("limit orders not yet supported")
+    if info.funds.len() != 1 {
+        return Err(ContractError::InvalidFunds {
+            msg: String::from("must send exactly one asset"),
+        });
+    }
+
+    if info.funds[0].denom.clone() == target_denom {
+        return Err(ContractError::InvalidFunds {
+            msg: String::from("swap denom and target denom must be different"),
+        });
+    }
+
+    let config = get_config(deps.storage)?;
+
+    if info.sender != config.dca_contract_address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pair = find_pair(
+        deps.storage,
+        [info.funds[0].denom.clone(), target_denom.clone()],
+    )?;
+
+    let orderbook_address = get_orderbook_address(&deps.querier, &pair)?;
+
+    let price = match pair.position_type(info.funds[0].denom.clone()) {
+        PositionType::Enter => target_price,
+        PositionType::Exit => Decimal256::one() / target_price,
+    };
+
+    let order_direction = get_order_direction(&pair, info.funds[0].denom.clone());
+    let tick_id = price_to_tick(price, &order_direction)?;
+
+    Ok(Response::new()
+        .add_attribute("submit_order", "true")
+        .add_attribute("target_price", price.to_string())
+        .add_attribute("tick_id", tick_id.to_string())
+        .add_submessage(SubMsg::reply_on_success(
+            WasmMsg::Execute {
+                contract_addr: orderbook_address.to_string(),
+                msg: to_json_binary(&OrderbookExecuteMsg::PlaceLimit {
+                    tick_id,
+                    order_direction,
+                    quantity: info.funds[0].amount,
+                    claim_bounty: None,
+                })?,
+                funds: info.funds,
+            },
+            AFTER_SUBMIT_ORDER,
+        )))
 }
 
-pub fn return_order_idx(_reply: Reply) -> Result<Response, ContractError> {
-    unimplemented!("Limit orders are not supported on osmosis yet")
+pub fn return_order_idx(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
+    let events = reply.result.into_result().unwrap().events;
+
+    let orderbook_address = Addr::unchecked(get_attribute_in_event(
+        &events,
+        "wasm",
+        "_contract_address",
+    )?);
+
+    let order_id = get_attribute_in_event(&events, "wasm", "order_id")?
+        .parse::<u64>()
+        .unwrap();
+
+    let tick_id = get_attribute_in_event(&events, "wasm", "tick_id")?
+        .parse::<i64>()
+        .unwrap();
+
+    save_order_tick(deps.storage, orderbook_address, order_id, tick_id)?;
+
+    Ok(Response::new().add_attribute("order_idx", Uint128::from(order_id)))
 }
 
-// use cosmwasm_std::{Decimal256, Deps, MessageInfo, Reply, Response, SubMsg, WasmMsg};
-// use exchange::msg::ExecuteMsg;
-
-// use crate::{contract::AFTER_SUBMIT_ORDER, state::config::get_config, ContractError};
-
-// pub enum TFMExecuteMsg {
-//     SubmitOrder {
-//         ask_asset: AssetInfo;
-//         offer_asset: AssetInfo;
-//         expiration_time: u64;
-//     }
-// }
-
-// pub fn submit_order_handler(
-//     deps: Deps,
-//     info: MessageInfo,
-//     target_price: Decimal256,
-//     target_denom: String,
-// ) -> Result<Response, ContractError> {
-//     if info.funds.len() != 1 {
-//         return Err(ContractError::InvalidFunds {
-//             msg: String::from("must send exactly one asset"),
-//         });
-//     }
-
-//     if info.funds[0].denom.clone() == target_denom {
-//         return Err(ContractError::InvalidFunds {
-//             msg: String::from("swap denom and target denom must be different"),
-//         });
-//     }
-
-//     let config = get_config(deps.storage)?;
-
-//     if info.sender != config.dca_contract_address {
-//         return Err(ContractError::Unauthorized {});
-//     }
-
-//     Ok(Response::new()
-//         .add_attribute("submit_order", "true")
-//         .add_attribute("target_price", target_price.to_string())
-//         .add_submessage(SubMsg::reply_on_success(
-//             WasmMsg::Execute {
-//                 contract_addr: config.limit_order_address.to_string(),
-//                 msg: ExecuteMsg::SubmitOrder {
-//                     target_price,
-//                     target_denom,
-//                 },
-//                 funds: info.funds,
-//             },
-//             AFTER_SUBMIT_ORDER,
-//         )))
-// }
-
-// pub fn return_order_idx(reply: Reply) -> Result<Response, ContractError> {
-//     let order_idx = get_attribute_in_event(
-//         &reply.result.into_result().unwrap().events,
-//         "wasm",
-//         "order_idx",
-//     )?
-//     .parse::<Uint128>()
-//     .unwrap();
-
-//     Ok(Response::new().add_attribute("order_idx", order_idx))
-// }
+#[cfg(test)]
+mod submit_order_tests {
+    use cosmwasm_std::{
+        testing::mock_info, to_json_binary, Coin, Decimal256, StdError, SubMsg, WasmMsg,
+    };
+
+    use crate::{
+        contract::AFTER_SUBMIT_ORDER,
+        state::{config::update_config, pairs::save_pair},
+        tests::{
+            constants::{
+                ADMIN, DCA_CONTRACT_ADDRESS, DENOM_UATOM, DENOM_UOSMO, ORDERBOOK_ADDRESS,
+                ORDERBOOK_POOL_ID,
+            },
+            mocks::calc_mock_dependencies,
+        },
+        types::{
+            config::Config,
+            orderbook::{OrderDirection, OrderbookExecuteMsg},
+            pair::Pair,
+        },
+        ContractError,
+    };
+
+    use super::*;
+
+    fn orderbook_pair() -> Pair {
+        Pair {
+            route: vec![ORDERBOOK_POOL_ID],
+            ..Pair::default()
+        }
+    }
+
+    #[test]
+    fn with_no_assets_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        assert_eq!(
+            submit_order_handler(
+                deps.as_ref(),
+                mock_info(DCA_CONTRACT_ADDRESS, &[]),
+                Decimal256::one(),
+                DENOM_UOSMO.to_string(),
+            )
+            .unwrap_err(),
+            ContractError::InvalidFunds {
+                msg: String::from("must send exactly one asset")
+            }
+        );
+    }
+
+    #[test]
+    fn with_the_same_swap_and_target_denom_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        assert_eq!(
+            submit_order_handler(
+                deps.as_ref(),
+                mock_info(DCA_CONTRACT_ADDRESS, &[Coin::new(43282, DENOM_UOSMO)]),
+                Decimal256::one(),
+                DENOM_UOSMO.to_string(),
+            )
+            .unwrap_err(),
+            ContractError::InvalidFunds {
+                msg: String::from("swap denom and target denom must be different")
+            }
+        );
+    }
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        assert_eq!(
+            submit_order_handler(
+                deps.as_ref(),
+                mock_info(ADMIN, &[Coin::new(43282, DENOM_UOSMO)]),
+                Decimal256::one(),
+                DENOM_UATOM.to_string(),
+            )
+            .unwrap_err(),
+            ContractError::Unauthorized {}
+        );
+    }
+
+    #[test]
+    fn with_no_matching_pair_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        assert_eq!(
+            submit_order_handler(
+                deps.as_ref(),
+                mock_info(DCA_CONTRACT_ADDRESS, &[Coin::new(43282, DENOM_UOSMO)]),
+                Decimal256::one(),
+                DENOM_UATOM.to_string(),
+            )
+            .unwrap_err(),
+            ContractError::Std(StdError::NotFound {
+                kind: "osmosis::types::pair::Pair".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn with_pair_not_routed_through_an_orderbook_fails() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        let pair = Pair::default();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        assert_eq!(
+            submit_order_handler(
+                deps.as_ref(),
+                mock_info(
+                    DCA_CONTRACT_ADDRESS,
+                    &[Coin::new(123123, pair.quote_denom.clone())]
+                ),
+                Decimal256::one(),
+                pair.base_denom,
+            )
+            .unwrap_err(),
+            ContractError::Std(StdError::generic_err(format!(
+                "pool id {} is not an orderbook pool",
+                pair.route[0]
+            )))
+        );
+    }
+
+    #[test]
+    fn sends_bid_to_orderbook_when_buying_the_base_denom() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        let info = mock_info(
+            DCA_CONTRACT_ADDRESS,
+            &[Coin::new(123123, pair.quote_denom.clone())],
+        );
+
+        let response = submit_order_handler(
+            deps.as_ref(),
+            info.clone(),
+            Decimal256::percent(250),
+            pair.base_denom.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::reply_on_success(
+                WasmMsg::Execute {
+                    contract_addr: ORDERBOOK_ADDRESS.to_string(),
+                    msg: to_json_binary(&OrderbookExecuteMsg::PlaceLimit {
+                        tick_id: 1_500_000,
+                        order_direction: OrderDirection::Bid,
+                        quantity: info.funds[0].amount,
+                        claim_bounty: None,
+                    })
+                    .unwrap(),
+                    funds: info.funds
+                },
+                AFTER_SUBMIT_ORDER
+            )
+        );
+    }
+
+    #[test]
+    fn sends_ask_at_inverted_price_to_orderbook_when_selling_the_base_denom() {
+        let mut deps = calc_mock_dependencies();
+
+        update_config(deps.as_mut().storage, Config::default()).unwrap();
+
+        let pair = orderbook_pair();
+
+        save_pair(deps.as_mut().storage, &pair).unwrap();
+
+        let info = mock_info(
+            DCA_CONTRACT_ADDRESS,
+            &[Coin::new(123123, pair.base_denom.clone())],
+        );
+
+        let response = submit_order_handler(
+            deps.as_ref(),
+            info.clone(),
+            Decimal256::percent(200),
+            pair.quote_denom.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::reply_on_success(
+                WasmMsg::Execute {
+                    contract_addr: ORDERBOOK_ADDRESS.to_string(),
+                    msg: to_json_binary(&OrderbookExecuteMsg::PlaceLimit {
+                        tick_id: -5_000_000,
+                        order_direction: OrderDirection::Ask,
+                        quantity: info.funds[0].amount,
+                        claim_bounty: None,
+                    })
+                    .unwrap(),
+                    funds: info.funds
+                },
+                AFTER_SUBMIT_ORDER
+            )
+        );
+    }
+}
+
+#[cfg(test)]
+mod return_order_idx_tests {
+    use cosmwasm_std::{
+        testing::mock_dependencies, Addr, Event, Reply, SubMsgResponse, SubMsgResult, Uint128,
+    };
+
+    use crate::{state::orders::get_order_tick, tests::constants::ORDERBOOK_ADDRESS};
+
+    use super::return_order_idx;
+
+    #[test]
+    fn returns_order_idx_from_orderbook_events_and_saves_its_tick() {
+        let mut deps = mock_dependencies();
+
+        let response = return_order_idx(
+            deps.as_mut(),
+            Reply {
+                id: 0,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![Event::new("wasm")
+                        .add_attribute("_contract_address", ORDERBOOK_ADDRESS)
+                        .add_attribute("method", "placeLimit")
+                        .add_attribute("tick_id", "-5000000")
+                        .add_attribute("order_id", "18")],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.attributes.first().unwrap().value,
+            Uint128::new(18).to_string()
+        );
+        assert_eq!(
+            get_order_tick(
+                deps.as_ref().storage,
+                Addr::unchecked(ORDERBOOK_ADDRESS),
+                Uint128::new(18)
+            )
+            .unwrap(),
+            (18, -5_000_000)
+        );
+    }
+}
@@ -1,3 +1,5 @@
 pub mod balance;
+pub mod message;
+pub mod orderbook;
 pub mod price;
 pub mod routes;
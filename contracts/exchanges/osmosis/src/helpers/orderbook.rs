@@ -0,0 +1,179 @@
+use cosmwasm_std::{Addr, Decimal256, QuerierWrapper, StdError, StdResult, Uint256};
+use osmosis_std::types::osmosis::cosmwasmpool::v1beta1::CosmWasmPool;
+use osmosis_std::types::osmosis::poolmanager::v1beta1::PoolmanagerQuerier;
+use prost::DecodeError;
+
+use crate::types::{orderbook::OrderDirection, pair::Pair, position_type::PositionType};
+
+const EXPONENT_AT_PRICE_ONE: i64 = -6;
+const GEOMETRIC_EXPONENT_INCREMENT_DISTANCE_IN_TICKS: i64 = 9_000_000;
+const MIN_TICK: i64 = -108_000_000;
+const MAX_TICK: i64 = 342_000_000;
+
+pub fn get_orderbook_address(querier: &QuerierWrapper, pair: &Pair) -> StdResult<Addr> {
+    if pair.route.len() != 1 {
+        return Err(StdError::generic_err(
+            "limit orders are only supported on pairs routed through a single orderbook pool",
+        ));
+    }
+
+    let pool_id = pair.route[0];
+
+    PoolmanagerQuerier::new(querier).pool(pool_id)?.pool.map_or(
+        Err(StdError::generic_err("pool not found")),
+        |pool| match pool.type_url.as_str() {
+            CosmWasmPool::TYPE_URL => pool
+                .try_into()
+                .map(|pool: CosmWasmPool| Addr::unchecked(pool.contract_address))
+                .map_err(|e: DecodeError| StdError::ParseErr {
+                    target_type: CosmWasmPool::TYPE_URL.to_string(),
+                    msg: e.to_string(),
+                }),
+            _ => Err(StdError::generic_err(format!(
+                "pool id {} is not an orderbook pool",
+                pool_id
+            ))),
+        },
+    )
+}
+
+pub fn get_order_direction(pair: &Pair, swap_denom: String) -> OrderDirection {
+    match pair.position_type(swap_denom) {
+        PositionType::Enter => OrderDirection::Bid,
+        PositionType::Exit => OrderDirection::Ask,
+    }
+}
+
+fn pow_ten(exponent: i64) -> StdResult<Decimal256> {
+    let power = Uint256::from(10u128).checked_pow(exponent.unsigned_abs() as u32)?;
+
+    if exponent >= 0 {
+        Decimal256::checked_from_ratio(power, 1u128)
+    } else {
+        Decimal256::checked_from_ratio(1u128, power)
+    }
+    .map_err(|err| StdError::generic_err(err.to_string()))
+}
+
+pub fn tick_to_price(tick_id: i64) -> StdResult<Decimal256> {
+    if !(MIN_TICK..=MAX_TICK).contains(&tick_id) {
+        return Err(StdError::generic_err(format!(
+            "tick {} is outside of the supported range",
+            tick_id
+        )));
+    }
+
+    let exponent = tick_id.div_euclid(GEOMETRIC_EXPONENT_INCREMENT_DISTANCE_IN_TICKS);
+    let additive_ticks = tick_id.rem_euclid(GEOMETRIC_EXPONENT_INCREMENT_DISTANCE_IN_TICKS);
+
+    Ok(pow_ten(exponent)?
+        + pow_ten(exponent + EXPONENT_AT_PRICE_ONE)?
+            * Decimal256::from_ratio(additive_ticks as u128, 1u128))
+}
+
+// Rounds bids down and asks up so the order never fills at a worse price than requested
+pub fn price_to_tick(price: Decimal256, order_direction: &OrderDirection) -> StdResult<i64> {
+    if price < tick_to_price(MIN_TICK)? || price > tick_to_price(MAX_TICK)? {
+        return Err(StdError::generic_err(format!(
+            "price {} is outside of the supported range",
+            price
+        )));
+    }
+
+    let mut exponent = 0;
+
+    while pow_ten(exponent)? > price {
+        exponent -= 1;
+    }
+
+    while exponent < MAX_TICK / GEOMETRIC_EXPONENT_INCREMENT_DISTANCE_IN_TICKS
+        && pow_ten(exponent + 1)? <= price
+    {
+        exponent += 1;
+    }
+
+    let additive_ticks = (price - pow_ten(exponent)?) / pow_ten(exponent + EXPONENT_AT_PRICE_ONE)?;
+
+    let additive_ticks = match order_direction {
+        OrderDirection::Bid => additive_ticks.to_uint_floor(),
+        OrderDirection::Ask => additive_ticks.to_uint_ceil(),
+    };
+
+    let additive_ticks = i64::try_from(u128::try_from(additive_ticks)?)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(exponent * GEOMETRIC_EXPONENT_INCREMENT_DISTANCE_IN_TICKS + additive_ticks)
+}
+
+#[cfg(test)]
+mod price_to_tick_tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn converts_price_of_one_to_tick_zero() {
+        assert_eq!(
+            price_to_tick(Decimal256::one(), &OrderDirection::Bid).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn converts_prices_above_one() {
+        assert_eq!(
+            price_to_tick(Decimal256::from_str("2.5").unwrap(), &OrderDirection::Bid).unwrap(),
+            1_500_000
+        );
+        assert_eq!(
+            price_to_tick(Decimal256::from_str("10").unwrap(), &OrderDirection::Bid).unwrap(),
+            9_000_000
+        );
+        assert_eq!(
+            price_to_tick(Decimal256::from_str("25").unwrap(), &OrderDirection::Bid).unwrap(),
+            10_500_000
+        );
+    }
+
+    #[test]
+    fn converts_prices_below_one() {
+        assert_eq!(
+            price_to_tick(Decimal256::from_str("0.5").unwrap(), &OrderDirection::Bid).unwrap(),
+            -5_000_000
+        );
+        assert_eq!(
+            price_to_tick(Decimal256::from_str("0.1").unwrap(), &OrderDirection::Bid).unwrap(),
+            -9_000_000
+        );
+    }
+
+    #[test]
+    fn rounds_bids_down_and_asks_up() {
+        let price = Decimal256::from_str("1.0000015").unwrap();
+
+        assert_eq!(price_to_tick(price, &OrderDirection::Bid).unwrap(), 1);
+        assert_eq!(price_to_tick(price, &OrderDirection::Ask).unwrap(), 2);
+    }
+
+    #[test]
+    fn round_trips_through_tick_to_price() {
+        for tick_id in [-9_000_001, -1, 0, 1, 1_500_000, 38_123_456] {
+            assert_eq!(
+                price_to_tick(tick_to_price(tick_id).unwrap(), &OrderDirection::Bid).unwrap(),
+                tick_id
+            );
+        }
+    }
+
+    #[test]
+    fn with_price_below_min_tick_fails() {
+        assert_eq!(
+            price_to_tick(
+                Decimal256::from_str("0.0000000000001").unwrap(),
+                &OrderDirection::Bid
+            )
+            .unwrap_err(),
+            StdError::generic_err("price 0.0000000000001 is outside of the supported range")
+        );
+    }
+}
@@ -6,10 +6,14 @@ use exchange_macros::{exchange_execute, exchange_query};
 #[cw_serde]
 pub struct InstantiateMsg {
     pub admin: Addr,
+    pub dca_contract_address: Addr,
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    pub admin: Addr,
+    pub dca_contract_address: Addr,
+}
 
 #[exchange_execute]
 #[cw_serde]
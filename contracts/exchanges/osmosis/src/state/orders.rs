@@ -0,0 +1,34 @@
+use cosmwasm_std::{Addr, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+
+// Orderbook orders are addressed by tick and order id, but the DCA contract only tracks the order id
+const ORDER_TICKS: Map<(Addr, u64), i64> = Map::new("order_ticks_v1");
+
+pub fn save_order_tick(
+    storage: &mut dyn Storage,
+    orderbook_address: Addr,
+    order_id: u64,
+    tick_id: i64,
+) -> StdResult<()> {
+    ORDER_TICKS.save(storage, (orderbook_address, order_id), &tick_id)
+}
+
+pub fn get_order_tick(
+    storage: &dyn Storage,
+    orderbook_address: Addr,
+    order_idx: Uint128,
+) -> StdResult<(u64, i64)> {
+    let order_id = u64::try_from(order_idx.u128())
+        .map_err(|_| StdError::generic_err(format!("invalid order idx {}", order_idx)))?;
+
+    ORDER_TICKS
+        .may_load(storage, (orderbook_address, order_id))?
+        .map(|tick_id| (order_id, tick_id))
+        .ok_or_else(|| {
+            StdError::generic_err("No orders with the specified information exist".to_string())
+        })
+}
+
+pub fn delete_order_tick(storage: &mut dyn Storage, orderbook_address: Addr, order_id: u64) {
+    ORDER_TICKS.remove(storage, (orderbook_address, order_id))
+}
@@ -1,7 +1,7 @@
 use crate::types::{config::Config, pair::Pair};
 use cosmwasm_std::Addr;
 
-use super::constants::{ADMIN, DENOM_STAKE, DENOM_UOSMO};
+use super::constants::{ADMIN, DCA_CONTRACT_ADDRESS, DENOM_STAKE, DENOM_UOSMO};
 
 impl Default for Pair {
     fn default() -> Self {
@@ -17,6 +17,7 @@ impl Default for Config {
     fn default() -> Self {
         Config {
             admin: Addr::unchecked(ADMIN),
+            dca_contract_address: Addr::unchecked(DCA_CONTRACT_ADDRESS),
         }
     }
 }
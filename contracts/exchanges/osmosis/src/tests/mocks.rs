@@ -7,6 +7,7 @@ use cosmwasm_std::{
 use osmosis_std::shim::Any;
 use osmosis_std::types::cosmos::base::v1beta1::Coin;
 use osmosis_std::types::osmosis::concentratedliquidity::v1beta1::Pool as ConcentratedLiquidityPool;
+use osmosis_std::types::osmosis::cosmwasmpool::v1beta1::CosmWasmPool;
 use osmosis_std::types::osmosis::gamm::poolmodels::stableswap::v1beta1::Pool as StableSwapPool;
 use osmosis_std::types::osmosis::gamm::v1beta1::{
     Pool as GammPool, PoolAsset, PoolParams, QueryCalcJoinPoolSharesResponse,
@@ -20,7 +21,8 @@ use serde::de::DeserializeOwned;
 use std::marker::PhantomData;
 
 use super::constants::{
-    DENOM_STAKE, DENOM_UATOM, DENOM_UION, DENOM_UOSMO, DENOM_UUSDC, ONE_DECIMAL, SWAP_FEE_RATE, TEN,
+    DENOM_STAKE, DENOM_UATOM, DENOM_UION, DENOM_UOSMO, DENOM_UUSDC, ONE_DECIMAL, ORDERBOOK_ADDRESS,
+    SWAP_FEE_RATE, TEN,
 };
 
 pub type StargateHandler = dyn Fn(&str, &Binary) -> StdResult<Binary>;
@@ -289,7 +291,8 @@ impl<C: DeserializeOwned> CalcMockQuerier<C> {
                             type_url: match pool_id {
                                 0..=4 => GammPool::TYPE_URL.to_string(),
                                 5..=9 => ConcentratedLiquidityPool::TYPE_URL.to_string(),
-                                10.. => StableSwapPool::TYPE_URL.to_string(),
+                                10..=14 => StableSwapPool::TYPE_URL.to_string(),
+                                _ => CosmWasmPool::TYPE_URL.to_string(),
                             },
                             value: match pool_id {
                                 0..=4 => gamm_pools
@@ -304,12 +307,18 @@ impl<C: DeserializeOwned> CalcMockQuerier<C> {
                                     .unwrap()
                                     .clone()
                                     .encode_to_vec(),
-                                10.. => ss_pools
+                                10..=14 => ss_pools
                                     .iter()
                                     .find(|pool| pool.id == pool_id)
                                     .unwrap()
                                     .clone()
                                     .encode_to_vec(),
+                                _ => CosmWasmPool {
+                                    contract_address: ORDERBOOK_ADDRESS.to_string(),
+                                    pool_id,
+                                    ..CosmWasmPool::default()
+                                }
+                                .encode_to_vec(),
                             },
                         }),
                     })
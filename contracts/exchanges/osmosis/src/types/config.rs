@@ -4,4 +4,5 @@ use cosmwasm_std::Addr;
 #[cw_serde]
 pub struct Config {
     pub admin: Addr,
+    pub dca_contract_address: Addr,
 }
@@ -1,3 +1,4 @@
 pub mod config;
+pub mod orderbook;
 pub mod pair;
 pub mod position_type;
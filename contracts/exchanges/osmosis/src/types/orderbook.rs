@@ -0,0 +1,58 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal256, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// Mirrors the subset of the Osmosis orderbook contract interface used by this adapter
+
+#[cw_serde]
+pub enum OrderDirection {
+    Bid,
+    Ask,
+}
+
+#[cw_serde]
+pub enum OrderbookExecuteMsg {
+    PlaceLimit {
+        tick_id: i64,
+        order_direction: OrderDirection,
+        quantity: Uint128,
+        claim_bounty: Option<Decimal256>,
+    },
+    CancelLimit {
+        tick_id: i64,
+        order_id: u64,
+    },
+    ClaimLimit {
+        tick_id: i64,
+        order_id: u64,
+    },
+}
+
+#[cw_serde]
+pub enum OrderbookQueryMsg {
+    OrdersByTick {
+        tick_id: i64,
+        start_from: Option<u64>,
+        end_at: Option<u64>,
+        limit: Option<u64>,
+    },
+}
+
+// Responses skip deny_unknown_fields so fields we don't read can't break deserialisation
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LimitOrder {
+    pub tick_id: i64,
+    pub order_id: u64,
+    pub order_direction: OrderDirection,
+    pub owner: Addr,
+    pub quantity: Uint128,
+    pub etas: Decimal256,
+    pub claim_bounty: Option<Decimal256>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OrdersResponse {
+    pub orders: Vec<LimitOrder>,
+    pub count: u64,
+}
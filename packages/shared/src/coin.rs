@@ -59,6 +59,21 @@ pub fn add_to(coin: &Coin, amount: Uint128) -> Coin {
     }
 }
 
+pub fn add_to_coins(coins: Vec<Coin>, coin: &Coin) -> Vec<Coin> {
+    if coin.amount.is_zero() {
+        return coins;
+    }
+
+    let mut coins = coins;
+
+    match coins.iter_mut().find(|existing| existing.denom == coin.denom) {
+        Some(existing) => existing.amount += coin.amount,
+        None => coins.push(coin.clone()),
+    }
+
+    coins
+}
+
 pub fn subtract_from(coin: Coin, amount: Uint128) -> Coin {
     Coin {
         denom: coin.denom,
@@ -77,7 +92,7 @@ pub fn empty_of(this: Coin) -> Coin {
 mod coin_helpers_tests {
     use cosmwasm_std::{Coin, Uint128};
 
-    use crate::coin::{add, add_to, empty_of, subtract, subtract_from};
+    use crate::coin::{add, add_to, add_to_coins, empty_of, subtract, subtract_from};
 
     #[test]
     fn adds_two_coins_with_same_denom() {
@@ -148,6 +163,21 @@ mod coin_helpers_tests {
         assert_eq!(result.denom, "uusd".to_string());
     }
 
+    #[test]
+    fn adds_coin_to_matching_denom_in_coins() {
+        let coins = add_to_coins(
+            vec![Coin::new(100, "uusd"), Coin::new(100, "ukuj")],
+            &Coin::new(50, "ukuj"),
+        );
+        assert_eq!(coins, vec![Coin::new(100, "uusd"), Coin::new(150, "ukuj")]);
+    }
+
+    #[test]
+    fn appends_coin_with_new_denom_to_coins() {
+        let coins = add_to_coins(vec![Coin::new(100, "uusd")], &Coin::new(50, "ukuj"));
+        assert_eq!(coins, vec![Coin::new(100, "uusd"), Coin::new(50, "ukuj")]);
+    }
+
     #[test]
     fn subtracts_larger_amount_from_coin() {
         let coin = Coin {
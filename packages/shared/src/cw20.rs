@@ -4,8 +4,6 @@ use cosmwasm_std::{
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
-use crate::coin::one_from;
-
 pub fn cw20_token_address(api: &dyn Api, denom: &str) -> StdResult<Addr> {
     if denom.len() > 10 {
         api.addr_validate(denom)
@@ -31,20 +29,34 @@ pub fn from_cw20_receive_msg(
     })
 }
 
-pub fn into_bank_msg(api: &dyn Api, recipient: &str, amount: Vec<Coin>) -> StdResult<CosmosMsg> {
-    let token = one_from(amount.clone())?;
-    Ok(match cw20_token_address(api, token.denom.as_ref()) {
+pub fn into_transfer_msg(api: &dyn Api, recipient: &str, amount: Coin) -> StdResult<CosmosMsg> {
+    Ok(match cw20_token_address(api, amount.denom.as_ref()) {
         Ok(token_address) => CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: token_address.to_string(),
             msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
                 recipient: api.addr_validate(recipient)?.to_string(),
-                amount: token.amount,
+                amount: amount.amount,
             })?,
             funds: vec![],
         }),
         Err(_) => CosmosMsg::Bank(BankMsg::Send {
             to_address: recipient.to_string(),
-            amount,
+            amount: vec![amount],
+        }),
+    })
+}
+
+pub fn into_burn_msg(api: &dyn Api, amount: Coin) -> StdResult<CosmosMsg> {
+    Ok(match cw20_token_address(api, amount.denom.as_ref()) {
+        Ok(token_address) => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: token_address.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Burn {
+                amount: amount.amount,
+            })?,
+            funds: vec![],
+        }),
+        Err(_) => CosmosMsg::Bank(BankMsg::Burn {
+            amount: vec![amount],
         }),
     })
 }
@@ -72,3 +84,45 @@ pub fn into_execute_msg(
         }),
     })
 }
+
+#[cfg(test)]
+mod cw20_helpers_tests {
+    use super::*;
+    use cosmwasm_std::{testing::mock_dependencies, Uint128};
+
+    #[test]
+    fn into_transfer_msg_with_native_denom_sends_bank_msg() {
+        let deps = mock_dependencies();
+
+        let msg = into_transfer_msg(&deps.api, "recipient", Coin::new(100, "ukuji")).unwrap();
+
+        assert_eq!(
+            msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "recipient".to_string(),
+                amount: vec![Coin::new(100, "ukuji")],
+            })
+        );
+    }
+
+    #[test]
+    fn into_transfer_msg_with_cw20_denom_sends_cw20_transfer() {
+        let deps = mock_dependencies();
+
+        let msg =
+            into_transfer_msg(&deps.api, "recipient", Coin::new(100, "cw20tokenaddress")).unwrap();
+
+        assert_eq!(
+            msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "cw20tokenaddress".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "recipient".to_string(),
+                    amount: Uint128::new(100),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+}
@@ -139,7 +139,7 @@ const instantiateDCAContract = async (
       admin: adminWalletAddress,
       executors: [adminWalletAddress],
       automation_fee_percent: `${automationFee}`,
-      fee_collectors: [{ address: feeCollectorAddress, allocation: '1.0' }],
+      fee_collectors: [{ address: { address: feeCollectorAddress, allocation: '1.0' } }],
       default_page_limit: 30,
       paused: false,
       default_slippage_tolerance: '0.05',
@@ -161,7 +161,7 @@ const instantiateDCAContract = async (
       admin: adminWalletAddress,
       executors: [adminWalletAddress],
       automation_fee_percent: `${automationFee}`,
-      fee_collectors: [{ address: feeCollectorAddress, allocation: '1.0' }],
+      fee_collectors: [{ address: { address: feeCollectorAddress, allocation: '1.0' } }],
       default_page_limit: 30,
       paused: false,
       default_slippage_tolerance: '0.05',
@@ -169,7 +169,6 @@ const instantiateDCAContract = async (
       default_swap_fee_percent: `${dexSwapFee}`,
       weighted_scale_swap_fee_percent: '0.01',
       risk_weighted_average_escrow_level: '0.05',
-      old_staking_router_address: adminWalletAddress,
     },
     'dca',
   );
@@ -56,7 +56,6 @@ export type ExecuteMsg =
         default_slippage_tolerance?: Decimal | null;
         default_swap_fee_percent?: Decimal | null;
         exchange_contract_address?: Addr | null;
-        executors?: Addr[] | null;
         fee_collectors?: FeeCollector[] | null;
         paused?: boolean | null;
         risk_weighted_average_escrow_level?: Decimal | null;
@@ -192,7 +191,15 @@ export interface Destination {
   allocation: Decimal;
   msg?: Binary | null;
 }
-export interface FeeCollector {
-  address: string;
-  allocation: Decimal;
-}
+export type FeeCollector =
+  | {
+      address: {
+        address: string;
+        allocation: Decimal;
+      };
+    }
+  | {
+      burn: {
+        allocation: Decimal;
+      };
+    };
@@ -31,13 +31,20 @@ export interface InstantiateMsg {
   exchange_contract_address: Addr;
   executors: Addr[];
   fee_collectors: FeeCollector[];
-  old_staking_router_address: Addr;
   paused: boolean;
   risk_weighted_average_escrow_level: Decimal;
   twap_period: number;
   weighted_scale_swap_fee_percent: Decimal;
 }
-export interface FeeCollector {
-  address: string;
-  allocation: Decimal;
-}
+export type FeeCollector =
+  | {
+      address: {
+        address: string;
+        allocation: Decimal;
+      };
+    }
+  | {
+      burn: {
+        allocation: Decimal;
+      };
+    };